@@ -0,0 +1,7 @@
+-- migrations/002_source_preferences.sql
+
+CREATE TABLE IF NOT EXISTS source_preferences (
+    key        TEXT PRIMARY KEY,  -- DiscoveryItem::favorite_key()
+    url        TEXT NOT NULL,
+    updated_at TEXT NOT NULL DEFAULT (datetime('now'))
+);
@@ -0,0 +1,14 @@
+-- migrations/003_resume_positions.sql
+
+CREATE TABLE IF NOT EXISTS resume_positions (
+    key           TEXT PRIMARY KEY,  -- DiscoveryItem::favorite_key()
+    url           TEXT NOT NULL,
+    position_secs REAL NOT NULL,
+    updated_at    TEXT NOT NULL DEFAULT (datetime('now'))
+);
+
+-- mpv watch_later entries already imported, so cleared positions stay cleared.
+CREATE TABLE IF NOT EXISTS watch_later_imports (
+    entry       TEXT PRIMARY KEY,  -- watch_later file name (MD5 of the URL)
+    imported_at TEXT NOT NULL DEFAULT (datetime('now'))
+);
@@ -0,0 +1,14 @@
+-- migrations/004_favorites.sql
+
+CREATE TABLE IF NOT EXISTS favorites (
+    id            INTEGER PRIMARY KEY AUTOINCREMENT,
+    key           TEXT NOT NULL UNIQUE,  -- DiscoveryItem::favorite_key()
+    source        TEXT NOT NULL,         -- "nts" or "direct"
+    item_type     TEXT NOT NULL,         -- "live", "episode", "url", "genre"
+    title         TEXT NOT NULL,
+    url           TEXT,
+    metadata_json TEXT NOT NULL DEFAULT '{}',  -- serialized DiscoveryItem
+    created_at    TEXT NOT NULL DEFAULT (datetime('now'))
+);
+
+CREATE INDEX IF NOT EXISTS idx_favorites_source ON favorites(source);
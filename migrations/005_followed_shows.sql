@@ -0,0 +1,8 @@
+-- migrations/005_followed_shows.sql
+
+CREATE TABLE IF NOT EXISTS followed_shows (
+    show_alias  TEXT PRIMARY KEY,        -- NTS show alias, e.g. "the-breakfast-show"
+    title       TEXT NOT NULL,           -- episode name it was followed from
+    seen_until  TEXT,                    -- newest broadcast date already seen; NULL until first check
+    followed_at TEXT NOT NULL DEFAULT (datetime('now'))
+);
@@ -0,0 +1,7 @@
+-- migrations/006_metrics.sql
+
+CREATE TABLE IF NOT EXISTS metrics (
+    name       TEXT PRIMARY KEY,        -- counter name, e.g. "play.nts_live" or "key.cycle_sort"
+    count      INTEGER NOT NULL DEFAULT 0,
+    updated_at TEXT NOT NULL DEFAULT (datetime('now'))
+);
@@ -0,0 +1,9 @@
+-- migrations/007_favorite_positions.sql
+
+-- Manual ordering and pinning for the favorites view. Favorites without a row
+-- here sort newest-first after the positioned ones.
+CREATE TABLE IF NOT EXISTS favorite_positions (
+    favorite_id INTEGER PRIMARY KEY,     -- favorites.id
+    position    INTEGER NOT NULL,
+    pinned      INTEGER NOT NULL DEFAULT 0
+);
@@ -0,0 +1,13 @@
+-- migrations/008_history.sql
+
+-- One row per play. The history view groups rows by key.
+CREATE TABLE IF NOT EXISTS history (
+    id            INTEGER PRIMARY KEY AUTOINCREMENT,
+    key           TEXT NOT NULL,         -- DiscoveryItem::favorite_key()
+    title         TEXT NOT NULL,
+    item_json     TEXT NOT NULL,         -- serialized DiscoveryItem
+    played_at     TEXT NOT NULL DEFAULT (datetime('now')),
+    duration_secs REAL NOT NULL DEFAULT 0  -- time actually listened, written when playback ends
+);
+
+CREATE INDEX IF NOT EXISTS idx_history_key ON history(key);
@@ -0,0 +1,18 @@
+-- migrations/009_playlists.sql
+
+-- User-named playlists and their items, kept apart from the single queue.
+CREATE TABLE IF NOT EXISTS playlists (
+    id         INTEGER PRIMARY KEY AUTOINCREMENT,
+    name       TEXT NOT NULL UNIQUE,
+    created_at TEXT NOT NULL DEFAULT (datetime('now'))
+);
+
+CREATE TABLE IF NOT EXISTS playlist_items (
+    id          INTEGER PRIMARY KEY AUTOINCREMENT,
+    playlist_id INTEGER NOT NULL,        -- playlists.id
+    position    INTEGER NOT NULL,
+    key         TEXT NOT NULL,           -- DiscoveryItem::favorite_key()
+    item_json   TEXT NOT NULL,           -- serialized DiscoveryItem
+    added_at    TEXT NOT NULL DEFAULT (datetime('now')),
+    UNIQUE (playlist_id, key)
+);
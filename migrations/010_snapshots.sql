@@ -0,0 +1,8 @@
+-- migrations/010_snapshots.sql
+
+-- Last successful API responses, shown at startup until fresh data arrives.
+CREATE TABLE IF NOT EXISTS snapshots (
+    name       TEXT PRIMARY KEY,         -- e.g. "live"
+    items_json TEXT NOT NULL,            -- serialized Vec<DiscoveryItem>
+    saved_at   TEXT NOT NULL DEFAULT (datetime('now'))
+);
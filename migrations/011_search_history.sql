@@ -0,0 +1,7 @@
+-- migrations/011_search_history.sql
+
+-- Queries typed into the search bar, offered again as suggestions.
+CREATE TABLE IF NOT EXISTS search_history (
+    query       TEXT PRIMARY KEY,        -- as typed, e.g. "ambient jazz"
+    searched_at TEXT NOT NULL DEFAULT (datetime('now'))
+);
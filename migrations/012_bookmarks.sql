@@ -0,0 +1,12 @@
+-- migrations/012_bookmarks.sql
+
+-- Cue points set in long episodes, shown on the seek timeline.
+CREATE TABLE IF NOT EXISTS bookmarks (
+    id            INTEGER PRIMARY KEY AUTOINCREMENT,
+    key           TEXT NOT NULL,     -- DiscoveryItem::favorite_key()
+    position_secs REAL NOT NULL,
+    label         TEXT NOT NULL,     -- e.g. the song playing there
+    created_at    TEXT NOT NULL DEFAULT (datetime('now'))
+);
+
+CREATE INDEX IF NOT EXISTS bookmarks_by_key ON bookmarks (key, position_secs);
@@ -0,0 +1,9 @@
+-- migrations/013_track_lengths.sql
+
+-- Lengths mpv reported for items that have played, for the queue's time
+-- estimates.
+CREATE TABLE IF NOT EXISTS track_lengths (
+    key           TEXT PRIMARY KEY,  -- DiscoveryItem::favorite_key()
+    duration_secs REAL NOT NULL,
+    updated_at    TEXT NOT NULL DEFAULT (datetime('now'))
+);
@@ -0,0 +1,13 @@
+-- migrations/014_podcasts.sql
+
+-- Podcast feeds subscribed to, and which of their episodes have been played.
+CREATE TABLE IF NOT EXISTS podcast_feeds (
+    url      TEXT PRIMARY KEY,               -- RSS or Atom feed URL
+    title    TEXT,                           -- the feed's title; NULL until first fetched
+    added_at TEXT NOT NULL DEFAULT (datetime('now'))
+);
+
+CREATE TABLE IF NOT EXISTS podcast_played (
+    key       TEXT PRIMARY KEY,              -- DiscoveryItem::favorite_key()
+    played_at TEXT NOT NULL DEFAULT (datetime('now'))
+);
@@ -0,0 +1,8 @@
+-- migrations/015_app_state.sql
+
+-- Where the app was left on quit, for `restore_session` to bring back.
+CREATE TABLE IF NOT EXISTS app_state (
+    key        TEXT PRIMARY KEY,         -- e.g. "session"
+    value      TEXT NOT NULL,            -- JSON
+    updated_at TEXT NOT NULL DEFAULT (datetime('now'))
+);
@@ -0,0 +1,190 @@
+// About report: version, build, dependency versions, file locations, API
+// endpoint, and which optional features are on. Shown in the About overlay
+// and copied to the clipboard as plain text for bug reports.
+
+use crate::config::Config;
+use crate::hooks::HookEvent;
+use crate::{db, logging};
+
+/// The manifest, read at compile time for the dependency list.
+const MANIFEST: &str = include_str!("../Cargo.toml");
+
+/// A titled group of label/value rows.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AboutSection {
+    pub title: &'static str,
+    pub rows: Vec<(String, String)>,
+}
+
+impl AboutSection {
+    fn new(title: &'static str, rows: Vec<(&str, String)>) -> Self {
+        Self {
+            title,
+            rows: rows
+                .into_iter()
+                .map(|(label, value)| (label.to_string(), value))
+                .collect(),
+        }
+    }
+}
+
+/// Build the report for the running app.
+pub fn report(config: &Config, api_base: &str) -> Vec<AboutSection> {
+    let profile = if cfg!(debug_assertions) {
+        "debug"
+    } else {
+        "release"
+    };
+    let on_off = |on: bool| if on { "on" } else { "off" }.to_string();
+    let crossfade = match config.player.crossfade_secs() {
+        secs if secs > 0.0 => format!("{secs}s"),
+        _ => "off".to_string(),
+    };
+    let extra_args = match config.extra_mpv_args() {
+        args if args.is_empty() => "none".to_string(),
+        args => args.join(" "),
+    };
+    let remote = match (&config.remote.enabled, &config.remote.http) {
+        (false, _) => "off".to_string(),
+        (true, Some(addr)) => format!("socket + http://{addr}"),
+        (true, None) => "socket".to_string(),
+    };
+    let hooks = match HookEvent::ALL
+        .into_iter()
+        .filter(|event| config.hooks.command(*event).is_some())
+        .map(HookEvent::name)
+        .collect::<Vec<_>>()
+    {
+        events if events.is_empty() => "none".to_string(),
+        events => events.join(", "),
+    };
+    let mut dependencies = dependency_versions(MANIFEST);
+    dependencies.push(("sqlite (bundled)".to_string(), rusqlite::version().into()));
+
+    vec![
+        AboutSection::new(
+            "clisten",
+            vec![
+                ("Version", env!("CARGO_PKG_VERSION").to_string()),
+                (
+                    "Build",
+                    format!(
+                        "{profile} · {}-{}",
+                        std::env::consts::OS,
+                        std::env::consts::ARCH
+                    ),
+                ),
+                ("Repository", env!("CARGO_PKG_REPOSITORY").to_string()),
+            ],
+        ),
+        AboutSection {
+            title: "Dependencies",
+            rows: dependencies,
+        },
+        AboutSection::new(
+            "Tools",
+            vec![
+                ("mpv", tool_path(config.player.mpv_binary())),
+                ("yt-dlp", tool_path("yt-dlp")),
+                ("Extra mpv args", extra_args),
+            ],
+        ),
+        AboutSection::new(
+            "Paths",
+            vec![
+                ("Config", Config::config_path().display().to_string()),
+                ("Database", db::db_path().display().to_string()),
+                ("Log", logging::log_path().display().to_string()),
+            ],
+        ),
+        AboutSection::new("Network", vec![("API base", api_base.to_string())]),
+        AboutSection::new(
+            "Features",
+            vec![
+                ("Metrics", on_off(config.general.metrics)),
+                ("Terminal title", on_off(config.general.terminal_title)),
+                ("Skip NTS intro", on_off(config.general.skip_nts_intro)),
+                ("Radio mode", on_off(config.general.radio_mode)),
+                ("Low data mode", on_off(config.general.low_data)),
+                ("Session restore", on_off(config.general.restore_session)),
+                ("Pause on sleep", on_off(config.general.pause_on_sleep)),
+                (
+                    "Pause on device loss",
+                    on_off(config.general.pause_on_device_loss),
+                ),
+                ("Crossfade", crossfade),
+                (
+                    "Buffer profile",
+                    config.player.buffer_profile.label().to_string(),
+                ),
+                ("Equalizer", config.player.equalizer.label().to_string()),
+                ("Playback health", on_off(config.general.debug_playback)),
+                ("MPRIS", on_off(cfg!(target_os = "linux"))),
+                ("Remote control", remote),
+                ("Hooks", hooks),
+            ],
+        ),
+    ]
+}
+
+/// Requested versions of the direct dependencies in a Cargo manifest,
+/// including platform-specific ones, sorted by name.
+pub fn dependency_versions(manifest: &str) -> Vec<(String, String)> {
+    let Ok(manifest) = manifest.parse::<toml::Table>() else {
+        return Vec::new();
+    };
+    let mut tables: Vec<&toml::Table> = manifest
+        .get("dependencies")
+        .and_then(|d| d.as_table())
+        .into_iter()
+        .collect();
+    if let Some(targets) = manifest.get("target").and_then(|t| t.as_table()) {
+        tables.extend(
+            targets
+                .values()
+                .filter_map(|t| t.get("dependencies")?.as_table()),
+        );
+    }
+    let mut versions: Vec<(String, String)> = tables
+        .into_iter()
+        .flatten()
+        .filter_map(|(name, spec)| {
+            let version = match spec {
+                toml::Value::String(version) => version.as_str(),
+                spec => spec.get("version")?.as_str()?,
+            };
+            Some((name.clone(), version.to_string()))
+        })
+        .collect();
+    versions.sort();
+    versions
+}
+
+fn tool_path(name: &str) -> String {
+    match which::which(name) {
+        Ok(path) => path.display().to_string(),
+        Err(_) if name.contains('/') => format!("{name} not found"),
+        Err(_) => "not found on PATH".to_string(),
+    }
+}
+
+/// Plain-text rendering, as copied to the clipboard.
+pub fn to_text(sections: &[AboutSection]) -> String {
+    let width = sections
+        .iter()
+        .flat_map(|s| s.rows.iter().map(|(label, _)| label.chars().count()))
+        .max()
+        .unwrap_or(0);
+    let mut out = String::new();
+    for (i, section) in sections.iter().enumerate() {
+        if i > 0 {
+            out.push('\n');
+        }
+        out.push_str(section.title);
+        out.push('\n');
+        for (label, value) in &section.rows {
+            out.push_str(&format!("  {label:<width$}  {value}\n"));
+        }
+    }
+    out
+}
@@ -1,22 +1,42 @@
 // Every user interaction, async result, and internal event is represented as an
 // Action variant. The App event loop dispatches these to component handlers.
 
-use crate::api::models::DiscoveryItem;
+use std::collections::BTreeMap;
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+use crate::api::genres::GenreCategory;
+use crate::api::models::{DiscoveryItem, EpisodeTrack, ItemInfo, LiveTrack, ShowSlot, UrlMeta};
+use crate::components::notifications::Notification;
+use crate::components::nts::NtsSubTab;
+use crate::player::equalizer::EqualizerPreset;
+use crate::player::failure::PlaybackFailure;
 use crate::player::StreamMetadata;
 
 /// All events flowing through the app — user actions, async results, and
 /// internal signals. The [`App`](crate::app::App) event loop dispatches
 /// each variant to the appropriate handler.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum Action {
     Quit,
+    /// Reopen where the last session left off, with `restore_session` on.
+    RestoreSession,
     SwitchSubTab(usize),
     Back,
 
     FocusSearch,
     SearchSubmit,
+    /// Filter the list to what the search bar held when it was typed (an
+    /// empty string shows everything). Sent a moment after the last key.
+    FilterList(String),
+    /// Forget a past search query.
+    DeleteSearch(String),
 
     PlayItem(DiscoveryItem),
+    /// Play an item for a few seconds next to the current track, or stop
+    /// the preview if it is the one previewing.
+    Preview(DiscoveryItem),
     TogglePlayPause,
     Stop,
     NextTrack,
@@ -26,36 +46,153 @@ pub enum Action {
     },
     PlaybackLoading,
     PlaybackFinished,
+    /// mpv reported an error ending the current file; PlaybackFinished follows.
+    PlaybackFailed(PlaybackFailure),
     PlaybackPosition(f64),
+    /// mpv's pause state changed, whoever changed it.
+    PauseChanged(bool),
+    /// An audio output went away, named by its description.
+    AudioDeviceRemoved(String),
+    /// The system is about to sleep (or, noticed afterwards, has slept).
+    SystemSleep,
+    /// The system woke up from sleep.
+    SystemWake,
+    /// Position up to which the stream is buffered, in seconds.
+    PlaybackBuffered(f64),
     AudioLevels {
         rms: f64,
         peak: f64,
     },
+    /// Per-band spectrum levels (0.0–1.0), lowest frequency first.
+    AudioBands(Vec<f64>),
     StreamMetadataChanged(StreamMetadata),
 
     AddToQueue(DiscoveryItem),
     AddToQueueNext(DiscoveryItem),
+    /// Queue the items marked in the list and clear the marks.
+    AddManyToQueue {
+        items: Vec<DiscoveryItem>,
+        insert_next: bool,
+    },
     RemoveFromQueue,
     ClearQueue,
+    /// Ask `prompt` before sending `action`, unless confirmations are off.
+    Confirm {
+        prompt: String,
+        action: Box<Action>,
+    },
+    /// Jump to the queue entry at this index.
+    PlayQueueIndex(usize),
+    /// Remove the queue entry at this index.
+    RemoveQueueIndex(usize),
+    /// Insert a URL into the queue at this index, optionally playing it.
+    InsertQueueUrl {
+        url: String,
+        index: usize,
+        play: bool,
+    },
+
+    ToggleFavorite(DiscoveryItem),
+    /// Favorite the items marked in the list and clear the marks.
+    FavoriteItems(Vec<DiscoveryItem>),
+    LoadFavorites,
+    LoadHistory,
+
+    /// Follow or unfollow the show an episode belongs to.
+    ToggleFollow(DiscoveryItem),
+    LoadFollowing,
+    CheckFollowedShows,
+    /// Recent episodes per followed show alias.
+    FollowedEpisodesLoaded(Vec<(String, Vec<DiscoveryItem>)>),
 
     LoadNtsLive,
     NtsLiveLoaded(Vec<DiscoveryItem>),
+    /// When the live shows on air started and end, from the same fetch.
+    LiveScheduleLoaded {
+        /// Unix time the first of them ends.
+        next_change: Option<u64>,
+        slots: BTreeMap<u8, ShowSlot>,
+    },
+    /// Songs recently played on a live channel, newest first.
+    LiveTracksLoaded {
+        channel: u8,
+        tracks: Vec<LiveTrack>,
+    },
     LoadNtsPicks,
     NtsPicksLoaded(Vec<DiscoveryItem>),
+    /// Loading a tab's list failed after retries; `r` tries it again.
+    FetchFailed {
+        tab: NtsSubTab,
+        error: String,
+    },
+    /// A cached list shown because the API couldn't be reached, and its age.
+    StaleListLoaded {
+        tab: NtsSubTab,
+        items: Vec<DiscoveryItem>,
+        age: Duration,
+    },
 
     LoadGenres,
     GenresLoaded(Vec<DiscoveryItem>),
+    /// The genre tree from the API, replacing the built-in list.
+    GenreTreeLoaded(Vec<GenreCategory>),
+    /// Episodes tagged with a genre, shown on its row.
+    GenreCountLoaded {
+        genre_id: String,
+        count: u64,
+    },
+    /// Fold or unfold a group in the grouped genre list.
+    ToggleGenreCategory(String),
+    /// Switch the genre list between flat and grouped.
+    ToggleGenreGroups,
     SearchByGenre {
         genre_id: String,
     },
+    SearchByMood {
+        mood_id: String,
+    },
+    SearchByPlace {
+        place: String,
+    },
+    /// Search for episodes like this one.
+    MoreLikeThis(DiscoveryItem),
+    /// The tags of the episode `key` arrived; search for episodes sharing them.
+    SearchRelated {
+        title: String,
+        key: String,
+        genre_ids: Vec<String>,
+        mood_ids: Vec<String>,
+    },
+    /// Show moods instead of genres on the Search tab, or back.
+    ToggleMoods,
+    MoodsLoaded(Vec<DiscoveryItem>),
+    /// Show places instead of genres on the Search tab, or back.
+    TogglePlaces,
+    PlacesLoaded(Vec<DiscoveryItem>),
     SearchByQuery {
         query: String,
     },
     SearchResultsPartial {
         search_id: u64,
         items: Vec<DiscoveryItem>,
+        /// Total matches reported by the API, once known.
+        total: Option<u64>,
         done: bool,
     },
+    /// Offsets of search pages that failed, and where paging stopped if the
+    /// error budget ran out.
+    SearchPagesFailed {
+        search_id: u64,
+        failed: Vec<u64>,
+        resume_from: Option<u64>,
+    },
+    /// Some search pages came from the cache because the API couldn't be
+    /// reached; `age` is the oldest.
+    SearchResultsStale {
+        search_id: u64,
+        age: Duration,
+    },
+    RetrySearchPages,
 
     VolumeUp,
     VolumeDown,
@@ -66,20 +203,146 @@ pub enum Action {
 
     PlaybackDuration(Option<f64>),
     SeekRelative(f64),
+    SeekAbsolute(f64),
+    /// Where the episode at `url` starts after its intro, if the analysis
+    /// found it.
+    IntroDetected {
+        url: String,
+        start: Option<f64>,
+    },
     OpenSeekModal,
     CloseSeekModal,
+    /// Remove the bookmark with this id from the playing episode.
+    DeleteBookmark(i64),
+    /// Loop between two positions in seconds; None stops looping.
+    SetAbLoop(Option<(f64, f64)>),
 
     CycleVisualizer,
+    CycleBufferProfile,
+    OpenEqualizer,
+    /// Switch equalizer preset; `custom_gains` are the Custom preset's bands.
+    SetEqualizer {
+        preset: EqualizerPreset,
+        custom_gains: [f64; 5],
+    },
+    CycleSort,
     ToggleSkipIntro,
+    ToggleRadio,
+    ToggleLowData,
     OnboardingComplete {
         theme: String,
         completed_screens: Vec<String>,
     },
     ShowOnboarding,
 
-    ShowError(String),
-    ClearError,
+    /// Show a status message in the notification stack.
+    Notify(Notification),
+    ShowNotificationHistory,
+    HideNotificationHistory,
     ShowHelp,
     HideHelp,
+    ShowStats,
+    ShowLogs,
+    ShowAbout,
+    /// Select the playing item in the list, if it's there.
+    JumpToPlaying,
+    ShowTracklist,
+    /// Open the info overlay for an item.
+    ShowInfo(DiscoveryItem),
+    /// Open the menu of what can be done with an item.
+    ShowContextMenu(DiscoveryItem),
+    /// Open an item's nts.live page (or direct URL) in the browser.
+    OpenInBrowser(DiscoveryItem),
+    /// Copy an item's share link, or with `stream` its stream URL.
+    CopyLink {
+        item: DiscoveryItem,
+        stream: bool,
+    },
+    /// Fetched details for the item with this favorite_key().
+    ItemInfoLoaded {
+        key: String,
+        info: ItemInfo,
+    },
+    /// A queued episode's length as estimated from its tracklist, by the
+    /// episode's favorite_key().
+    TrackLengthEstimated {
+        key: String,
+        secs: f64,
+    },
+    /// Episodes sharing a genre (`genre`, by name) with the last queue
+    /// entry, for radio mode to pick from.
+    RadioEpisodesLoaded {
+        genre: String,
+        items: Vec<DiscoveryItem>,
+    },
+    /// What yt-dlp found out about the page of a direct URL.
+    UrlResolved {
+        url: String,
+        title: Option<String>,
+        meta: UrlMeta,
+    },
+    /// An episode's tracklist, by the episode's favorite_key().
+    EpisodeTracklistLoaded {
+        key: String,
+        tracks: Vec<EpisodeTrack>,
+    },
+    /// Copy text to the system clipboard, reporting the result.
+    CopyToClipboard(String),
+    ShowFavorites,
+    DeleteFavorite(i64),
+    PinFavorite {
+        id: i64,
+        pinned: bool,
+    },
+    /// New manual order of all favorites, by id.
+    ReorderFavorites(Vec<i64>),
+    ShowHistory,
+    ShowPlaylists,
+    /// List the open playlist on the Playlists tab.
+    LoadPlaylists,
+    /// Recommend episodes from the listening history on the For You tab.
+    LoadForYou,
+    ForYouLoaded(Vec<DiscoveryItem>),
+    /// Fetch the subscribed podcast feeds for the Podcasts tab.
+    LoadPodcasts,
+    PodcastsLoaded(Vec<DiscoveryItem>),
+    /// Ask for a feed URL to subscribe to.
+    OpenPodcastSubscribe,
+    SubscribePodcast(String),
+    /// Unsubscribe from the feed at this URL.
+    UnsubscribePodcast(String),
+    /// Mark a podcast episode, by favorite key, as played or unplayed.
+    SetPodcastPlayed {
+        key: String,
+        played: bool,
+    },
+    /// Pick a playlist to add these items to.
+    ChoosePlaylist(Vec<DiscoveryItem>),
+    CreatePlaylist(String),
+    RenamePlaylist {
+        id: i64,
+        name: String,
+    },
+    DeletePlaylist(i64),
+    /// Show this playlist on the Playlists tab.
+    OpenPlaylist(i64),
+    /// Replace the queue with this playlist and start playing it.
+    LoadPlaylist(i64),
+    AddToPlaylist {
+        id: i64,
+        items: Vec<DiscoveryItem>,
+    },
+    /// Browse for a path; the picked one is sent as the purpose's action.
+    OpenFilePicker(crate::components::file_picker::PickPurpose),
+    /// Write favorites, history, and the queue to this export file.
+    ExportData(std::path::PathBuf),
+    /// Merge this export file back in.
+    ImportData(std::path::PathBuf),
+    /// Queue the entries of an M3U playlist.
+    ImportPlaylist(std::path::PathBuf),
+    /// Forget every play of the item with this favorite_key().
+    DeleteHistoryEntry(String),
+    ClearHistory,
+    ClearMetrics,
     Tick,
 }
@@ -0,0 +1,85 @@
+// On-disk cache of NTS API responses: one JSON file per request URL, so lists
+// render without the network and a failed fetch can fall back to old data.
+
+use std::fs;
+use std::path::PathBuf;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
+
+/// Directory holding cached API responses.
+pub fn cache_dir() -> PathBuf {
+    dirs::cache_dir()
+        .map(|dir| dir.join("clisten"))
+        .unwrap_or_else(crate::db::data_dir)
+        .join("api")
+}
+
+#[derive(Serialize, Deserialize)]
+struct Entry {
+    /// Unix time the response was fetched.
+    saved_at: u64,
+    body: serde_json::Value,
+}
+
+/// Response bodies keyed by request URL, with the time they were saved.
+#[derive(Debug, Clone)]
+pub struct ResponseCache {
+    dir: PathBuf,
+}
+
+impl ResponseCache {
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self { dir: dir.into() }
+    }
+
+    /// The body saved for `key` and its age. Missing or unreadable entries are
+    /// a miss.
+    pub fn get(&self, key: &str) -> Option<(serde_json::Value, Duration)> {
+        let entry: Entry = serde_json::from_slice(&fs::read(self.path(key)).ok()?).ok()?;
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs();
+        Some((
+            entry.body,
+            Duration::from_secs(now.saturating_sub(entry.saved_at)),
+        ))
+    }
+
+    /// Save `body` as the response for `key`, fetched now.
+    pub fn put(&self, key: &str, body: &serde_json::Value) -> anyhow::Result<()> {
+        self.put_at(key, body, SystemTime::now())
+    }
+
+    /// Save `body` as the response for `key`, fetched at `saved_at`.
+    pub fn put_at(
+        &self,
+        key: &str,
+        body: &serde_json::Value,
+        saved_at: SystemTime,
+    ) -> anyhow::Result<()> {
+        fs::create_dir_all(&self.dir)?;
+        let entry = Entry {
+            saved_at: saved_at.duration_since(UNIX_EPOCH)?.as_secs(),
+            body: body.clone(),
+        };
+        // Write then rename, so a reader never sees half a file.
+        let path = self.path(key);
+        let tmp = path.with_extension("tmp");
+        fs::write(&tmp, serde_json::to_vec(&entry)?)?;
+        fs::rename(tmp, path)?;
+        Ok(())
+    }
+
+    fn path(&self, key: &str) -> PathBuf {
+        self.dir.join(format!("{:016x}.json", fnv1a(key)))
+    }
+}
+
+/// FNV-1a: a file name that stays the same across builds, unlike the std hasher.
+fn fnv1a(key: &str) -> u64 {
+    key.bytes().fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+    })
+}
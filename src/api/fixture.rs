@@ -0,0 +1,316 @@
+// Canned NTS API for tests: answers from data set up beforehand, so app flows
+// run without the network. Anything not set up fails the way an unreachable
+// API would.
+
+use std::collections::{HashMap, HashSet};
+use std::fmt::Display;
+
+use crate::api::genres::GenreCategory;
+use crate::api::models::{DiscoveryItem, EpisodeTrack, LiveTrack, NtsEpisodeDetail};
+use crate::api::nts::{
+    episode_place, places_of, ApiFuture, Fetched, LiveListing, NtsApi, SearchPage,
+};
+use crate::api::podcast::{parse_feed, PodcastFeed};
+
+/// An NtsApi that serves fixtures, built up with the `with_*` methods.
+#[derive(Debug, Clone, Default)]
+pub struct FixtureApi {
+    live: Option<LiveListing>,
+    live_details: HashMap<u8, NtsEpisodeDetail>,
+    live_tracks: HashMap<u8, Vec<LiveTrack>>,
+    episodes: HashMap<(String, String), NtsEpisodeDetail>,
+    tracklists: HashMap<(String, String), Vec<EpisodeTrack>>,
+    picks: Option<Vec<DiscoveryItem>>,
+    show_episodes: HashMap<String, Vec<DiscoveryItem>>,
+    genres: Option<Vec<GenreCategory>>,
+    moods: Option<Vec<DiscoveryItem>>,
+    /// Newest episodes of all shows; places are counted from these.
+    recent: Option<Vec<DiscoveryItem>>,
+    /// Episodes by the genre or mood id they're tagged with.
+    tagged: HashMap<String, Vec<DiscoveryItem>>,
+    queries: HashMap<String, Vec<DiscoveryItem>>,
+    /// Podcast feed documents by URL.
+    feeds: HashMap<String, String>,
+}
+
+impl FixtureApi {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_live(mut self, listing: LiveListing) -> Self {
+        self.live = Some(listing);
+        self
+    }
+
+    pub fn with_live_details(mut self, channel: u8, detail: NtsEpisodeDetail) -> Self {
+        self.live_details.insert(channel, detail);
+        self
+    }
+
+    pub fn with_live_tracks(mut self, channel: u8, tracks: Vec<LiveTrack>) -> Self {
+        self.live_tracks.insert(channel, tracks);
+        self
+    }
+
+    pub fn with_episode(
+        mut self,
+        show_alias: &str,
+        episode_alias: &str,
+        detail: NtsEpisodeDetail,
+    ) -> Self {
+        let key = (show_alias.to_string(), episode_alias.to_string());
+        self.episodes.insert(key, detail);
+        self
+    }
+
+    pub fn with_tracklist(
+        mut self,
+        show_alias: &str,
+        episode_alias: &str,
+        tracks: Vec<EpisodeTrack>,
+    ) -> Self {
+        let key = (show_alias.to_string(), episode_alias.to_string());
+        self.tracklists.insert(key, tracks);
+        self
+    }
+
+    pub fn with_picks(mut self, picks: Vec<DiscoveryItem>) -> Self {
+        self.picks = Some(picks);
+        self
+    }
+
+    /// The episodes of a show, newest first.
+    pub fn with_show_episodes(mut self, show_alias: &str, episodes: Vec<DiscoveryItem>) -> Self {
+        self.show_episodes.insert(show_alias.to_string(), episodes);
+        self
+    }
+
+    pub fn with_genres(mut self, genres: Vec<GenreCategory>) -> Self {
+        self.genres = Some(genres);
+        self
+    }
+
+    pub fn with_moods(mut self, moods: Vec<DiscoveryItem>) -> Self {
+        self.moods = Some(moods);
+        self
+    }
+
+    /// The newest episodes of all shows, which places are counted from.
+    pub fn with_recent_episodes(mut self, episodes: Vec<DiscoveryItem>) -> Self {
+        self.recent = Some(episodes);
+        self
+    }
+
+    /// The episodes a genre or mood search for `tag_id` finds.
+    pub fn with_tagged(mut self, tag_id: &str, episodes: Vec<DiscoveryItem>) -> Self {
+        self.tagged.insert(tag_id.to_string(), episodes);
+        self
+    }
+
+    /// The episodes a free-text search for `query` finds.
+    pub fn with_query(mut self, query: &str, episodes: Vec<DiscoveryItem>) -> Self {
+        self.queries.insert(query.to_string(), episodes);
+        self
+    }
+
+    /// The RSS or Atom document served for `feed_url`.
+    pub fn with_feed(mut self, feed_url: &str, xml: &str) -> Self {
+        self.feeds.insert(feed_url.to_string(), xml.to_string());
+        self
+    }
+
+    /// Episodes tagged with any of `tag_ids`, each once.
+    fn search_tagged<'a>(
+        &self,
+        tag_ids: impl IntoIterator<Item = &'a str>,
+    ) -> Option<Vec<DiscoveryItem>> {
+        let mut seen = HashSet::new();
+        let mut found = None;
+        for id in tag_ids {
+            let Some(episodes) = self.tagged.get(id) else {
+                continue;
+            };
+            let found = found.get_or_insert_with(Vec::new);
+            found.extend(
+                episodes
+                    .iter()
+                    .filter(|episode| seen.insert(episode.favorite_key()))
+                    .cloned(),
+            );
+        }
+        found
+    }
+}
+
+fn ready<'a, T: Send + 'a>(result: anyhow::Result<T>) -> ApiFuture<'a, T> {
+    Box::pin(std::future::ready(result))
+}
+
+fn found<T>(value: Option<T>, what: impl Display) -> anyhow::Result<T> {
+    value.ok_or_else(|| anyhow::anyhow!("no fixture for {what}"))
+}
+
+fn fresh<T>(value: T) -> Fetched<T> {
+    Fetched { value, stale: None }
+}
+
+/// The page of `episodes` at `(offset, limit)`.
+fn page(episodes: Vec<DiscoveryItem>, offset: u64, limit: u64) -> SearchPage {
+    let total = episodes.len() as u64;
+    SearchPage {
+        items: episodes
+            .into_iter()
+            .skip(offset as usize)
+            .take(limit as usize)
+            .collect(),
+        total: Some(total),
+        stale: None,
+    }
+}
+
+impl NtsApi for FixtureApi {
+    fn base(&self) -> &str {
+        "fixture"
+    }
+
+    fn fetch_live_listing(&self) -> ApiFuture<'_, Fetched<LiveListing>> {
+        ready(found(self.live.clone(), "the live channels").map(fresh))
+    }
+
+    fn fetch_live_details(&self, channel: u8) -> ApiFuture<'_, Option<NtsEpisodeDetail>> {
+        ready(match &self.live {
+            Some(_) => Ok(self.live_details.get(&channel).cloned()),
+            None => found(None, "the live channels"),
+        })
+    }
+
+    fn fetch_live_tracks(&self, channel: u8) -> ApiFuture<'_, Vec<LiveTrack>> {
+        ready(found(
+            self.live_tracks.get(&channel).cloned(),
+            format_args!("the tracks on channel {channel}"),
+        ))
+    }
+
+    fn fetch_episode<'a>(
+        &'a self,
+        show_alias: &'a str,
+        episode_alias: &'a str,
+    ) -> ApiFuture<'a, NtsEpisodeDetail> {
+        let key = (show_alias.to_string(), episode_alias.to_string());
+        ready(found(
+            self.episodes.get(&key).cloned(),
+            format_args!("episode {show_alias}/{episode_alias}"),
+        ))
+    }
+
+    fn fetch_episode_tracklist<'a>(
+        &'a self,
+        show_alias: &'a str,
+        episode_alias: &'a str,
+    ) -> ApiFuture<'a, Vec<EpisodeTrack>> {
+        let key = (show_alias.to_string(), episode_alias.to_string());
+        ready(found(
+            self.tracklists.get(&key).cloned(),
+            format_args!("the tracklist of {show_alias}/{episode_alias}"),
+        ))
+    }
+
+    fn fetch_picks(&self) -> ApiFuture<'_, Fetched<Vec<DiscoveryItem>>> {
+        ready(found(self.picks.clone(), "the picks").map(fresh))
+    }
+
+    fn fetch_show_episodes<'a>(
+        &'a self,
+        show_alias: &'a str,
+        limit: u64,
+    ) -> ApiFuture<'a, Vec<DiscoveryItem>> {
+        let episodes = self
+            .show_episodes
+            .get(show_alias)
+            .map(|episodes| episodes.iter().take(limit as usize).cloned().collect());
+        ready(found(episodes, format_args!("show {show_alias}")))
+    }
+
+    fn fetch_genres(&self) -> ApiFuture<'_, Fetched<Vec<GenreCategory>>> {
+        ready(found(self.genres.clone(), "the genres").map(fresh))
+    }
+
+    fn fetch_genre_count<'a>(&'a self, genre_id: &'a str) -> ApiFuture<'a, Option<u64>> {
+        let count = self
+            .tagged
+            .get(genre_id)
+            .map(|episodes| Some(episodes.len() as u64));
+        ready(found(count, format_args!("genre {genre_id}")))
+    }
+
+    fn fetch_moods(&self) -> ApiFuture<'_, Fetched<Vec<DiscoveryItem>>> {
+        ready(found(self.moods.clone(), "the moods").map(fresh))
+    }
+
+    fn fetch_places(&self) -> ApiFuture<'_, Fetched<Vec<DiscoveryItem>>> {
+        let places = self.recent.as_deref().map(places_of);
+        ready(found(places, "the recent episodes").map(fresh))
+    }
+
+    fn fetch_place_episodes<'a>(
+        &'a self,
+        place: &'a str,
+    ) -> ApiFuture<'a, Fetched<Vec<DiscoveryItem>>> {
+        let episodes = self.recent.as_ref().map(|recent| {
+            recent
+                .iter()
+                .filter(|episode| episode_place(episode) == Some(place))
+                .cloned()
+                .collect()
+        });
+        ready(found(episodes, "the recent episodes").map(fresh))
+    }
+
+    fn search_episodes<'a>(
+        &'a self,
+        genre_id: &'a str,
+        offset: u64,
+        limit: u64,
+    ) -> ApiFuture<'a, SearchPage> {
+        let episodes = self.search_tagged([genre_id]);
+        ready(found(episodes, format_args!("genre {genre_id}")).map(|e| page(e, offset, limit)))
+    }
+
+    fn search_episodes_by_mood<'a>(
+        &'a self,
+        mood_id: &'a str,
+        offset: u64,
+        limit: u64,
+    ) -> ApiFuture<'a, SearchPage> {
+        let episodes = self.search_tagged([mood_id]);
+        ready(found(episodes, format_args!("mood {mood_id}")).map(|e| page(e, offset, limit)))
+    }
+
+    fn search_episodes_like<'a>(
+        &'a self,
+        genre_ids: &'a [String],
+        mood_ids: &'a [String],
+        offset: u64,
+        limit: u64,
+    ) -> ApiFuture<'a, SearchPage> {
+        let ids = genre_ids.iter().chain(mood_ids).map(String::as_str);
+        let episodes = self.search_tagged(ids);
+        ready(found(episodes, "any of those tags").map(|e| page(e, offset, limit)))
+    }
+
+    fn search_episodes_by_query<'a>(
+        &'a self,
+        query: &'a str,
+        offset: u64,
+        limit: u64,
+    ) -> ApiFuture<'a, SearchPage> {
+        let episodes = self.queries.get(query).cloned();
+        ready(found(episodes, format_args!("search {query:?}")).map(|e| page(e, offset, limit)))
+    }
+
+    fn fetch_podcast<'a>(&'a self, feed_url: &'a str) -> ApiFuture<'a, PodcastFeed> {
+        let xml = found(self.feeds.get(feed_url), format_args!("feed {feed_url}"));
+        ready(xml.and_then(|xml| parse_feed(xml, feed_url)))
+    }
+}
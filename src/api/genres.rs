@@ -1,5 +1,10 @@
-// Static list of NTS genres with 500+ episodes, sorted by episode count.
-// Used to populate the Search tab's genre picker.
+// Genre tree for the Search tab's picker. The tree normally comes from the
+// API; the static list of NTS genres with 500+ episodes, sorted by episode
+// count, stands in when it can't be fetched.
+
+use serde::{Deserialize, Serialize};
+
+use crate::api::models::NtsGenreNode;
 
 /// (api_id, display_name) pairs for genres with substantial episode counts.
 pub const TOP_GENRES: &[(&str, &str)] = &[
@@ -125,3 +130,75 @@ pub const TOP_GENRES: &[(&str, &str)] = &[
     ("jazz-jazzrock", "Jazz Rock"),
     ("hiphoprandb-ganstarap", "Gangsta Rap"),
 ];
+
+/// A top-level genre and the genres filed under it, e.g. "House / Techno"
+/// with "House", "Techno", and "Detroit House".
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GenreCategory {
+    pub id: String,
+    pub name: String,
+    /// (api_id, display_name) of the sub-genres, parents before their own
+    /// sub-genres.
+    pub genres: Vec<(String, String)>,
+}
+
+impl From<NtsGenreNode> for GenreCategory {
+    fn from(node: NtsGenreNode) -> Self {
+        fn flatten(nodes: Vec<NtsGenreNode>, out: &mut Vec<(String, String)>) {
+            for node in nodes {
+                out.push((node.id, node.value));
+                flatten(node.subgenres, out);
+            }
+        }
+        let mut genres = Vec::new();
+        flatten(node.subgenres, &mut genres);
+        Self {
+            id: node.id,
+            name: node.value,
+            genres,
+        }
+    }
+}
+
+/// Id of the top-level genre `genre_id` belongs to: sub-genre ids extend
+/// their parent's, e.g. "housetechno-house-detroithouse" → "housetechno".
+pub fn category_id(genre_id: &str) -> &str {
+    genre_id.split('-').next().unwrap_or(genre_id)
+}
+
+/// How deep `genre_id` sits under its top-level genre: 0 for "housetechno",
+/// 2 for "housetechno-house-detroithouse".
+pub fn genre_depth(genre_id: &str) -> usize {
+    genre_id.matches('-').count()
+}
+
+/// TOP_GENRES grouped by top-level genre, largest first: the fallback when
+/// the genre tree can't be fetched. Sub-genres whose parent isn't listed go
+/// under "Other".
+pub fn genre_categories() -> Vec<GenreCategory> {
+    let mut categories: Vec<GenreCategory> = TOP_GENRES
+        .iter()
+        .filter(|(id, _)| category_id(id) == *id)
+        .map(|&(id, name)| GenreCategory {
+            id: id.to_string(),
+            name: name.to_string(),
+            genres: Vec::new(),
+        })
+        .collect();
+    for &(id, name) in TOP_GENRES {
+        let parent = category_id(id);
+        if parent == id {
+            continue;
+        }
+        let index = categories
+            .iter()
+            .position(|c| c.id == parent)
+            .or_else(|| categories.iter().position(|c| c.id == "other"));
+        if let Some(index) = index {
+            categories[index]
+                .genres
+                .push((id.to_string(), name.to_string()));
+        }
+    }
+    categories
+}
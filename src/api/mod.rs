@@ -1,5 +1,9 @@
-// NTS Radio API client and response types.
+// NTS Radio API client and response types, and podcast feeds.
 
+pub mod cache;
+#[allow(dead_code)] // used by integration tests
+pub mod fixture;
 pub mod genres;
 pub mod models;
 pub mod nts;
+pub mod podcast;
@@ -5,7 +5,11 @@
 // serde compatibility and are read in tests but not in production code.
 #![allow(dead_code)]
 
+use std::collections::BTreeMap;
+use std::time::{SystemTime, UNIX_EPOCH};
+
 use serde::{Deserialize, Serialize};
+use unicode_normalization::{char::is_combining_mark, UnicodeNormalization};
 
 #[derive(Debug, Clone, Deserialize)]
 pub struct Genre {
@@ -19,6 +23,31 @@ pub struct AudioSource {
     pub source: String,
 }
 
+// ── Genres endpoint (/api/v2/genres) ──
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct NtsGenresResponse {
+    pub results: Vec<NtsGenreNode>,
+}
+
+/// A genre and the sub-genres under it, which nest the same way.
+#[derive(Debug, Clone, Deserialize)]
+pub struct NtsGenreNode {
+    pub id: String,
+    #[serde(alias = "name")]
+    pub value: String,
+    #[serde(default)]
+    pub subgenres: Vec<NtsGenreNode>,
+}
+
+// ── Moods endpoint (/api/v2/moods) ──
+
+/// Moods are tags of the same shape as genres.
+#[derive(Debug, Clone, Deserialize)]
+pub struct NtsMoodsResponse {
+    pub results: Vec<Genre>,
+}
+
 // ── Live endpoint (/api/v2/live) ──
 
 #[derive(Debug, Clone, Deserialize)]
@@ -31,6 +60,28 @@ pub struct NtsChannel {
     pub channel_name: String,
     pub now: NtsBroadcast,
     pub next: Option<NtsBroadcast>,
+    /// Broadcasts after `next`, which the API lists as `next2`, `next3`, ….
+    #[serde(flatten)]
+    pub later: BTreeMap<String, serde_json::Value>,
+}
+
+impl NtsChannel {
+    /// The broadcast on air followed by the scheduled ones, in order.
+    fn broadcasts(&self) -> Vec<NtsBroadcast> {
+        let mut later: Vec<(u32, NtsBroadcast)> = self
+            .later
+            .iter()
+            .filter_map(|(key, value)| {
+                let n = key.strip_prefix("next")?.parse().ok()?;
+                Some((n, serde_json::from_value(value.clone()).ok()?))
+            })
+            .collect();
+        later.sort_by_key(|(n, _)| *n);
+        std::iter::once(self.now.clone())
+            .chain(self.next.clone())
+            .chain(later.into_iter().map(|(_, broadcast)| broadcast))
+            .collect()
+    }
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -41,6 +92,155 @@ pub struct NtsBroadcast {
     pub embeds: Option<BroadcastEmbeds>,
 }
 
+impl NtsLiveResponse {
+    /// Unix time the first of the shows on air ends, when the API says.
+    pub fn next_change(&self) -> Option<u64> {
+        self.results
+            .iter()
+            .filter_map(|channel| parse_timestamp_utc(&channel.now.end_timestamp))
+            .min()
+    }
+
+    /// The start and end of the show on air, per channel number.
+    pub fn slots(&self) -> BTreeMap<u8, ShowSlot> {
+        self.results
+            .iter()
+            .filter_map(|channel| {
+                let start = parse_timestamp_utc(&channel.now.start_timestamp)?;
+                let end = parse_timestamp_utc(&channel.now.end_timestamp)?;
+                let slot = ShowSlot { start, end };
+                Some((channel.channel_name.parse().ok()?, slot)).filter(|_| end > start)
+            })
+            .collect()
+    }
+
+    /// Broadcasts on either channel that haven't ended by `now`, soonest
+    /// first. Only as far ahead as the API lists, typically about a day.
+    pub fn upcoming(&self, now: u64) -> Vec<ScheduledBroadcast> {
+        let mut upcoming: Vec<ScheduledBroadcast> = self
+            .results
+            .iter()
+            .flat_map(|channel| {
+                let number = channel.channel_name.parse().unwrap_or(1);
+                channel
+                    .broadcasts()
+                    .into_iter()
+                    .filter_map(move |broadcast| {
+                        let start = parse_timestamp_utc(&broadcast.start_timestamp)?;
+                        let end = parse_timestamp_utc(&broadcast.end_timestamp)?;
+                        let detail = broadcast.embeds.and_then(|e| e.details);
+                        Some(ScheduledBroadcast {
+                            channel: number,
+                            title: detail
+                                .as_ref()
+                                .map_or(broadcast.broadcast_title, |d| d.name.clone()),
+                            show_alias: detail.and_then(|d| d.show_alias),
+                            start,
+                            end,
+                        })
+                    })
+            })
+            .filter(|broadcast| broadcast.end > now && broadcast.end > broadcast.start)
+            .collect();
+        upcoming.sort_by_key(|broadcast| (broadcast.start, broadcast.channel));
+        upcoming
+    }
+}
+
+/// A broadcast on the live schedule.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScheduledBroadcast {
+    pub channel: u8,
+    pub title: String,
+    /// NTS alias of the show, when the schedule links the broadcast to one.
+    pub show_alias: Option<String>,
+    /// Unix times.
+    pub start: u64,
+    pub end: u64,
+}
+
+/// When the show on a live channel started and ends, as Unix times.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ShowSlot {
+    pub start: u64,
+    pub end: u64,
+}
+
+impl ShowSlot {
+    /// Seconds since the show started and until it ends, at `now`.
+    pub fn progress(&self, now: u64) -> (u64, u64) {
+        let now = now.clamp(self.start, self.end);
+        (now - self.start, self.end - now)
+    }
+}
+
+/// Seconds since the Unix epoch.
+pub fn unix_now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs()
+}
+
+/// `s` as searches compare it: compatibility-decomposed, lowercased, with
+/// combining marks (accents, Arabic harakat, Hebrew points) and bidi
+/// controls dropped, so "Café", "CAFE" and "cafe\u{301}" all become "cafe".
+/// Fold both the query and the text it's matched against.
+pub fn fold_for_search(s: &str) -> String {
+    s.nfkd()
+        .filter(|&c| !is_combining_mark(c) && !is_bidi_control(c))
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+/// Invisible direction marks that copy-pasted RTL text tends to carry.
+fn is_bidi_control(c: char) -> bool {
+    matches!(
+        c,
+        '\u{200e}' | '\u{200f}' | '\u{61c}' | '\u{202a}'..='\u{202e}' | '\u{2066}'..='\u{2069}'
+    )
+}
+
+/// Unix time of an RFC 3339 timestamp like "2026-02-18T16:00:00Z" or
+/// "2026-02-18T17:00:00+01:00". Fractional seconds are dropped.
+pub fn parse_timestamp_utc(timestamp: &str) -> Option<u64> {
+    let (date, time) = timestamp.trim().split_once('T')?;
+    let mut date = date.splitn(3, '-').map(|part| part.parse::<i64>().ok());
+    let (year, month, day) = (date.next()??, date.next()??, date.next()??);
+
+    let (clock, offset_secs) = match time.strip_suffix('Z') {
+        Some(clock) => (clock, 0),
+        None => {
+            let at = time.rfind(['+', '-'])?;
+            let (clock, offset) = time.split_at(at);
+            let (hours, minutes) = offset[1..].split_once(':')?;
+            let secs = hours.parse::<i64>().ok()? * 3600 + minutes.parse::<i64>().ok()? * 60;
+            (clock, if offset.starts_with('-') { -secs } else { secs })
+        }
+    };
+    let clock = clock.split('.').next()?;
+    let mut clock = clock.splitn(3, ':').map(|part| part.parse::<i64>().ok());
+    let (hour, minute, second) = (clock.next()??, clock.next()??, clock.next()??);
+    if !(1..=12).contains(&month)
+        || !(1..=31).contains(&day)
+        || hour > 23
+        || minute > 59
+        || second > 60
+    {
+        return None;
+    }
+
+    // Days since the epoch from a civil date (Howard Hinnant's algorithm).
+    let y = if month <= 2 { year - 1 } else { year };
+    let era = y.div_euclid(400);
+    let year_of_era = y - era * 400;
+    let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    let days = era * 146_097 + day_of_era - 719_468;
+
+    u64::try_from(days * 86_400 + hour * 3600 + minute * 60 + second - offset_secs).ok()
+}
+
 #[derive(Debug, Clone, Deserialize)]
 pub struct BroadcastEmbeds {
     pub details: Option<NtsEpisodeDetail>,
@@ -56,6 +256,118 @@ pub struct NtsEpisodeDetail {
     pub episode_alias: Option<String>,
     pub show_alias: Option<String>,
     pub audio_sources: Option<Vec<AudioSource>>,
+    pub broadcast: Option<String>,
+    #[serde(default)]
+    pub description: Option<String>,
+    /// Kept loose: only the labels or URLs are read, see [`ItemInfo`].
+    #[serde(default)]
+    pub moods: Option<Vec<serde_json::Value>>,
+    #[serde(default)]
+    pub external_links: Option<Vec<serde_json::Value>>,
+}
+
+impl NtsEpisodeDetail {
+    /// Ids of the episode's genres and of its moods.
+    pub fn tag_ids(&self) -> (Vec<String>, Vec<String>) {
+        let genres = self
+            .genres
+            .iter()
+            .flatten()
+            .map(|genre| genre.id.clone())
+            .collect();
+        let moods = self
+            .moods
+            .iter()
+            .flatten()
+            .filter_map(|mood| mood["id"].as_str())
+            .map(String::from)
+            .collect();
+        (genres, moods)
+    }
+}
+
+/// Everything the info overlay shows about an item.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ItemInfo {
+    pub title: String,
+    pub description: Option<String>,
+    pub genres: Vec<String>,
+    pub moods: Vec<String>,
+    pub location: Option<String>,
+    /// Broadcast date (ISO 8601).
+    pub broadcast: Option<String>,
+    /// Stream URL, for direct URLs and podcast episodes.
+    pub url: Option<String>,
+    pub links: Vec<String>,
+}
+
+impl ItemInfo {
+    /// What's known without asking the API.
+    pub fn from_item(item: &DiscoveryItem) -> Self {
+        let mut info = Self {
+            title: item.display_title(),
+            ..Self::default()
+        };
+        match item {
+            DiscoveryItem::NtsLiveChannel { genres, .. } => info.genres = genres.clone(),
+            DiscoveryItem::NtsEpisode {
+                genres,
+                location,
+                broadcast_date,
+                ..
+            } => {
+                info.genres = genres.clone();
+                info.location = location.clone();
+                info.broadcast = broadcast_date.clone();
+            }
+            DiscoveryItem::DirectUrl { url, meta, .. } => {
+                info.url = Some(url.clone());
+                if let Some(meta) = meta {
+                    info.links.extend(meta.thumbnail.clone());
+                }
+            }
+            DiscoveryItem::PodcastEpisode {
+                published,
+                audio_url,
+                link,
+                ..
+            } => {
+                info.broadcast = published.clone();
+                info.url = Some(audio_url.clone());
+                info.links.extend(link.clone());
+            }
+            DiscoveryItem::NtsGenre { .. }
+            | DiscoveryItem::NtsMood { .. }
+            | DiscoveryItem::NtsPlace { .. }
+            | DiscoveryItem::NtsGenreCategory { .. } => {}
+        }
+        info
+    }
+
+    /// Fill in what the episode detail adds; fields it lacks keep their value.
+    pub fn merge_detail(&mut self, detail: NtsEpisodeDetail) {
+        // Moods are objects with a `value` label, links plain URLs or objects
+        // with a `url`; anything else is skipped.
+        let labels = |values: Option<Vec<serde_json::Value>>, field: &str| -> Vec<String> {
+            values
+                .unwrap_or_default()
+                .iter()
+                .filter_map(|v| v.as_str().or_else(|| v[field].as_str()))
+                .map(String::from)
+                .collect()
+        };
+        self.description = detail
+            .description
+            .filter(|d| !d.trim().is_empty())
+            .or(self.description.take());
+        if let Some(genres) = detail.genres.filter(|g| !g.is_empty()) {
+            self.genres = genres.into_iter().map(|g| g.value).collect();
+        }
+        self.moods = labels(detail.moods, "value");
+        self.location = detail.location_long.or(self.location.take());
+        self.broadcast = detail.broadcast.or(self.broadcast.take());
+        self.links = labels(detail.external_links, "url");
+    }
 }
 
 // ── Search episodes endpoint (/api/v2/search/episodes, /api/v2/search) ──
@@ -63,6 +375,19 @@ pub struct NtsEpisodeDetail {
 #[derive(Debug, Clone, Deserialize)]
 pub struct NtsSearchResponse {
     pub results: Vec<NtsSearchEpisode>,
+    #[serde(default)]
+    pub metadata: Option<NtsSearchMetadata>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct NtsSearchMetadata {
+    pub resultset: NtsResultset,
+}
+
+/// Paging info; `count` is the total number of matches.
+#[derive(Debug, Clone, Deserialize)]
+pub struct NtsResultset {
+    pub count: u64,
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -86,20 +411,151 @@ pub struct NtsSearchEpisode {
     pub local_date: Option<String>,
 }
 
-// ── Collection endpoint (/api/v2/collections/nts-picks) ──
+// ── Collection endpoint (/api/v2/collections/nts-picks), also the shape of
+//    /api/v2/shows/{alias}/episodes ──
 
 #[derive(Debug, Clone, Deserialize)]
 pub struct NtsCollectionResponse {
     pub results: Vec<NtsEpisodeDetail>,
 }
 
+// ── Live tracklist (/api/v2/live/{channel}/tracklist) ──
+
+/// Songs recently played on a live channel, newest first.
+#[derive(Debug, Clone, Deserialize)]
+pub struct NtsLiveTracklistResponse {
+    pub results: Vec<LiveTrack>,
+}
+
+/// One song from a live channel's tracklist.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub struct LiveTrack {
+    #[serde(default)]
+    pub artist: String,
+    #[serde(default)]
+    pub title: String,
+}
+
+/// A podcast episode's length, e.g. "42m" or "1h 05m".
+fn episode_length(secs: u64) -> String {
+    match secs {
+        0..=59 => format!("{}s", secs),
+        60..=3599 => format!("{}m", secs / 60),
+        _ => format!("{}h {:02}m", secs / 3600, secs % 3600 / 60),
+    }
+}
+
+/// "Artist - Title", or whichever half is known.
+fn artist_title(artist: &str, title: &str) -> String {
+    match (artist.is_empty(), title.is_empty()) {
+        (false, false) => format!("{artist} - {title}"),
+        (true, _) => title.to_string(),
+        (false, true) => artist.to_string(),
+    }
+}
+
+impl LiveTrack {
+    /// "Artist - Title", or whichever half is known.
+    pub fn display(&self) -> String {
+        artist_title(&self.artist, &self.title)
+    }
+
+    fn is_empty(&self) -> bool {
+        self.artist.is_empty() && self.title.is_empty()
+    }
+}
+
+/// Add the songs from a tracklist response (newest first) that aren't in
+/// `history` yet to its front, keeping at most `limit` entries.
+pub fn merge_live_tracks(history: &mut Vec<LiveTrack>, latest: Vec<LiveTrack>, limit: usize) {
+    for track in latest.into_iter().rev() {
+        if !track.is_empty() && !history.contains(&track) {
+            history.insert(0, track);
+        }
+    }
+    history.truncate(limit);
+}
+
+// ── Episode tracklist (/api/v2/shows/{show}/episodes/{episode}/tracklist) ──
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct NtsTracklistResponse {
+    pub results: Vec<EpisodeTrack>,
+}
+
+/// One song from an episode's tracklist, in broadcast order.
+#[derive(Debug, Clone, Default, PartialEq, Deserialize, Serialize)]
+pub struct EpisodeTrack {
+    #[serde(default)]
+    pub artist: String,
+    #[serde(default)]
+    pub title: String,
+    /// Seconds into the episode where the song starts, when known.
+    #[serde(default)]
+    pub offset: Option<f64>,
+    #[serde(default)]
+    pub duration: Option<f64>,
+}
+
+impl EpisodeTrack {
+    /// "Artist - Title", or whichever half is known.
+    pub fn display(&self) -> String {
+        artist_title(&self.artist, &self.title)
+    }
+}
+
+/// The song probably playing at `position_secs`: the last one starting at or
+/// before it. None when nothing has a timestamp or the first song hasn't
+/// started yet.
+pub fn playing_track_index(tracks: &[EpisodeTrack], position_secs: f64) -> Option<usize> {
+    tracks
+        .iter()
+        .enumerate()
+        .filter(|(_, track)| track.offset.is_some_and(|offset| offset <= position_secs))
+        .max_by(|(_, a), (_, b)| {
+            a.offset
+                .partial_cmp(&b.offset)
+                .unwrap_or(std::cmp::Ordering::Equal)
+        })
+        .map(|(i, _)| i)
+}
+
 // ── DiscoveryItem — the unified type rendered in the discovery list ──
 
-const NTS_STREAM_1: &str = "https://stream-relay-geo.ntslive.net/stream";
-const NTS_STREAM_2: &str = "https://stream-relay-geo.ntslive.net/stream2";
+// Each channel's relay, then the plain-HTTP endpoint of the same relay.
+const NTS_RELAYS_1: &[&str] = &[
+    "https://stream-relay-geo.ntslive.net/stream",
+    "http://stream-relay-geo.ntslive.net/stream",
+];
+const NTS_RELAYS_2: &[&str] = &[
+    "https://stream-relay-geo.ntslive.net/stream2",
+    "http://stream-relay-geo.ntslive.net/stream2",
+];
+
+/// Built-in stream URLs for a live channel, in the order they are tried.
+pub fn default_relays(channel: u8) -> &'static [&'static str] {
+    match channel {
+        1 => NTS_RELAYS_1,
+        2 => NTS_RELAYS_2,
+        _ => &[],
+    }
+}
+const NTS_WEB: &str = "https://www.nts.live";
+
+/// Details of a direct URL's page (a Bandcamp track, a YouTube video, …)
+/// as yt-dlp reports them.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct UrlMeta {
+    pub artist: Option<String>,
+    pub duration_secs: Option<f64>,
+    pub thumbnail: Option<String>,
+    /// The site, e.g. "Bandcamp" or "YouTube".
+    pub site: Option<String>,
+}
 
 /// Unified type for everything that can appear in the discovery list.
-/// Covers live NTS channels, archived episodes, direct URLs, and genre entries.
+/// Covers live NTS channels, archived episodes, direct URLs, podcast
+/// episodes, and genre entries.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum DiscoveryItem {
     NtsLiveChannel {
@@ -114,15 +570,60 @@ pub enum DiscoveryItem {
         genres: Vec<String>,
         location: Option<String>,
         audio_url: Option<String>,
+        /// Original broadcast date (ISO 8601), used for date sorting.
+        #[serde(default)]
+        broadcast_date: Option<String>,
     },
     DirectUrl {
         url: String,
         title: Option<String>,
+        /// What yt-dlp found out about the page, once resolved.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        meta: Option<UrlMeta>,
+    },
+    /// An episode of a subscribed podcast feed.
+    PodcastEpisode {
+        feed_url: String,
+        /// The entry's guid (Atom id), or its audio URL when it has none.
+        guid: String,
+        title: String,
+        /// The feed's title.
+        show: String,
+        /// Publish date (ISO 8601, day only).
+        #[serde(default)]
+        published: Option<String>,
+        #[serde(default)]
+        duration_secs: Option<u64>,
+        /// The enclosure, which is what plays.
+        audio_url: String,
+        /// The episode's web page, when the feed gives one.
+        #[serde(default)]
+        link: Option<String>,
     },
     NtsGenre {
         name: String,
         genre_id: String,
     },
+    NtsMood {
+        name: String,
+        mood_id: String,
+    },
+    /// A city episodes were broadcast from, with how many of the recent
+    /// episodes it was gathered from came from there.
+    NtsPlace {
+        name: String,
+        #[serde(default)]
+        episodes: usize,
+    },
+    /// Header of a group in the grouped genre list; activating it folds or
+    /// unfolds the genres under it.
+    NtsGenreCategory {
+        name: String,
+        category_id: String,
+        /// Genres in the group, shown whether or not it's folded.
+        count: usize,
+        expanded: bool,
+    },
 }
 
 impl DiscoveryItem {
@@ -133,10 +634,26 @@ impl DiscoveryItem {
             Self::NtsEpisode { name, .. } => name,
             Self::DirectUrl { title: Some(t), .. } => t,
             Self::DirectUrl { url, .. } => url,
+            Self::PodcastEpisode { title, .. } => title,
             Self::NtsGenre { name, .. } => name,
+            Self::NtsMood { name, .. } => name,
+            Self::NtsPlace { name, .. } => name,
+            Self::NtsGenreCategory { name, .. } => name,
         }
     }
 
+    /// A genre, genre group, or mood: browsed into rather than played or
+    /// marked.
+    pub fn is_browse_entry(&self) -> bool {
+        matches!(
+            self,
+            Self::NtsGenre { .. }
+                | Self::NtsMood { .. }
+                | Self::NtsPlace { .. }
+                | Self::NtsGenreCategory { .. }
+        )
+    }
+
     /// Formatted title for the now-playing bar and queue display.
     pub fn display_title(&self) -> String {
         match self {
@@ -148,7 +665,11 @@ impl DiscoveryItem {
             Self::NtsEpisode { name, .. } => format!("NTS Radio: {}", name),
             Self::DirectUrl { title: Some(t), .. } => t.clone(),
             Self::DirectUrl { url, .. } => url.clone(),
+            Self::PodcastEpisode { show, title, .. } => format!("{}: {}", show, title),
             Self::NtsGenre { name, .. } => name.clone(),
+            Self::NtsMood { name, .. } => name.clone(),
+            Self::NtsPlace { name, .. } => name.clone(),
+            Self::NtsGenreCategory { name, .. } => name.clone(),
         }
     }
 
@@ -162,21 +683,168 @@ impl DiscoveryItem {
                 Some(loc) => format!("{} · {}", genres.join(", "), loc),
                 None => genres.join(", "),
             },
+            Self::DirectUrl {
+                meta: Some(meta), ..
+            } => {
+                let parts: Vec<&str> = [meta.artist.as_deref(), meta.site.as_deref()]
+                    .into_iter()
+                    .flatten()
+                    .collect();
+                if parts.is_empty() {
+                    "Direct URL".to_string()
+                } else {
+                    parts.join(" · ")
+                }
+            }
             Self::DirectUrl { .. } => "Direct URL".to_string(),
+            Self::PodcastEpisode {
+                show,
+                published,
+                duration_secs,
+                ..
+            } => {
+                let mut parts = vec![show.clone()];
+                parts.extend(published.clone());
+                parts.extend(duration_secs.map(episode_length));
+                parts.join(" · ")
+            }
             Self::NtsGenre { .. } => "Genre".to_string(),
+            Self::NtsMood { .. } => "Mood".to_string(),
+            Self::NtsPlace { episodes: 0, .. } => "Place".to_string(),
+            Self::NtsPlace { episodes: 1, .. } => "Place · 1 recent episode".to_string(),
+            Self::NtsPlace { episodes, .. } => format!("Place · {} recent episodes", episodes),
+            Self::NtsGenreCategory { count: 1, .. } => "1 genre".to_string(),
+            Self::NtsGenreCategory { count, .. } => format!("{} genres", count),
         }
     }
 
     /// The URL to hand to mpv, or None for non-playable items (genres).
     pub fn playback_url(&self) -> Option<String> {
         match self {
-            Self::NtsLiveChannel { channel: 1, .. } => Some(NTS_STREAM_1.to_string()),
-            Self::NtsLiveChannel { channel: 2, .. } => Some(NTS_STREAM_2.to_string()),
-            Self::NtsLiveChannel { .. } => None,
+            Self::NtsLiveChannel { channel, .. } => {
+                default_relays(*channel).first().map(|url| url.to_string())
+            }
             Self::NtsEpisode { audio_url, .. } => audio_url.clone(),
             Self::DirectUrl { url, .. } => Some(url.clone()),
-            Self::NtsGenre { .. } => None,
+            Self::PodcastEpisode { audio_url, .. } => Some(audio_url.clone()),
+            Self::NtsGenre { .. }
+            | Self::NtsMood { .. }
+            | Self::NtsPlace { .. }
+            | Self::NtsGenreCategory { .. } => None,
+        }
+    }
+
+    /// Whether the title or subtitle contains `query`, which must already
+    /// be folded with `fold_for_search`.
+    pub fn matches_search(&self, query: &str) -> bool {
+        fold_for_search(self.title()).contains(query)
+            || fold_for_search(&self.subtitle()).contains(query)
+    }
+
+    /// Stable identity used as the key for per-item records in the database.
+    /// NFC-normalized, so a name the API sends precomposed one day and
+    /// decomposed the next still finds the same rows.
+    pub fn favorite_key(&self) -> String {
+        let key = match self {
+            Self::NtsLiveChannel { channel, .. } => format!("nts:live:{}", channel),
+            Self::NtsEpisode {
+                show_alias,
+                episode_alias,
+                ..
+            } => format!("nts:episode:{}:{}", show_alias, episode_alias),
+            Self::DirectUrl { url, .. } => format!("url:{}", url),
+            Self::PodcastEpisode { feed_url, guid, .. } => {
+                format!("podcast:{}#{}", feed_url, guid)
+            }
+            Self::NtsGenre { genre_id, .. } => format!("nts:genre:{}", genre_id),
+            Self::NtsMood { mood_id, .. } => format!("nts:mood:{}", mood_id),
+            Self::NtsPlace { name, .. } => format!("nts:place:{}", name),
+            Self::NtsGenreCategory { category_id, .. } => {
+                format!("nts:genre-category:{}", category_id)
+            }
+        };
+        if unicode_normalization::is_nfc(&key) {
+            key
+        } else {
+            key.nfc().collect()
+        }
+    }
+
+    /// The item's page on nts.live (or the URL itself for direct URLs, and
+    /// the episode page a podcast feed links to).
+    pub fn web_url(&self) -> Option<String> {
+        match self {
+            Self::NtsLiveChannel { .. } => Some(format!("{}/radio", NTS_WEB)),
+            Self::NtsEpisode {
+                show_alias,
+                episode_alias,
+                ..
+            } if !show_alias.is_empty() && !episode_alias.is_empty() => Some(format!(
+                "{}/shows/{}/episodes/{}",
+                NTS_WEB, show_alias, episode_alias
+            )),
+            Self::NtsEpisode { .. } => None,
+            Self::DirectUrl { url, .. } => Some(url.clone()),
+            Self::PodcastEpisode { link, .. } => link.clone(),
+            Self::NtsGenre { .. }
+            | Self::NtsMood { .. }
+            | Self::NtsPlace { .. }
+            | Self::NtsGenreCategory { .. } => None,
+        }
+    }
+
+    /// All URLs that can play this item, in order of preference. Live
+    /// channels try each built-in relay; episodes fall back to their nts.live
+    /// page, which yt-dlp resolves independently of the SoundCloud/Mixcloud
+    /// source.
+    pub fn source_candidates(&self) -> Vec<String> {
+        let mut candidates: Vec<String> = self.playback_url().into_iter().collect();
+        match self {
+            Self::NtsLiveChannel { channel, .. } => {
+                candidates = default_relays(*channel)
+                    .iter()
+                    .map(|url| url.to_string())
+                    .collect();
+            }
+            Self::NtsEpisode { .. } => {
+                if let Some(page) = self.web_url() {
+                    if !candidates.contains(&page) {
+                        candidates.push(page);
+                    }
+                }
+            }
+            _ => {}
+        }
+        candidates
+    }
+
+    /// Fold what yt-dlp found for `url` into this item if it's that direct
+    /// URL. A title it already has (e.g. from a playlist file) is kept.
+    /// Returns whether anything changed.
+    pub fn apply_url_meta(&mut self, url: &str, title: Option<&str>, meta: &UrlMeta) -> bool {
+        let Self::DirectUrl {
+            url: own_url,
+            title: own_title,
+            meta: own_meta,
+        } = self
+        else {
+            return false;
+        };
+        if own_url != url {
+            return false;
+        }
+        let mut changed = false;
+        if own_title.is_none() {
+            if let Some(title) = title {
+                *own_title = Some(title.to_string());
+                changed = true;
+            }
+        }
+        if own_meta.as_ref() != Some(meta) {
+            *own_meta = Some(meta.clone());
+            changed = true;
         }
+        changed
     }
 
     /// Resolve display title and subtitle, incorporating stream metadata when
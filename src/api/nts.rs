@@ -1,16 +1,230 @@
-// HTTP client for the NTS Radio public API (live streams, picks, genre search).
+// HTTP client for the NTS Radio public API (live streams, picks, genres and
+// moods and searches by them, places of recent episodes, show episodes), and
+// the podcast feeds subscribed to.
+// The app fetches through the NtsApi trait so tests can answer from fixtures.
 
+use std::collections::BTreeMap;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+use serde::de::DeserializeOwned;
+
+use crate::api::cache::ResponseCache;
+use crate::api::genres::GenreCategory;
 use crate::api::models::{
-    DiscoveryItem, NtsCollectionResponse, NtsEpisodeDetail, NtsLiveResponse, NtsSearchEpisode,
-    NtsSearchResponse,
+    unix_now, DiscoveryItem, EpisodeTrack, LiveTrack, NtsCollectionResponse, NtsEpisodeDetail,
+    NtsGenresResponse, NtsLiveResponse, NtsLiveTracklistResponse, NtsMoodsResponse,
+    NtsSearchEpisode, NtsSearchResponse, NtsTracklistResponse, ScheduledBroadcast, ShowSlot,
 };
+use crate::api::podcast::{self, PodcastFeed};
 
 const NTS_BASE: &str = "https://www.nts.live";
 
+// How long cached responses are used without asking the API again. Live data
+// stays short so a refresh sees the next show.
+const LIVE_TTL: Duration = Duration::from_secs(20);
+const PICKS_TTL: Duration = Duration::from_secs(60 * 60);
+const SEARCH_TTL: Duration = Duration::from_secs(10 * 60);
+const SHOW_EPISODES_TTL: Duration = Duration::from_secs(10 * 60);
+const EPISODE_TTL: Duration = Duration::from_secs(24 * 60 * 60);
+const GENRES_TTL: Duration = Duration::from_secs(7 * 24 * 60 * 60);
+const MOODS_TTL: Duration = GENRES_TTL;
+// In low data mode every cached response is kept this many times longer.
+const LOW_DATA_TTL_FACTOR: u32 = 4;
+
+// Places are gathered from the newest episodes: this many pages of the
+// unfiltered episode search, at the API's 12 results per page.
+const PLACES_SCAN_PAGES: u64 = 10;
+const PLACES_PAGE_SIZE: u64 = 12;
+
+/// How transient request failures are retried.
+#[derive(Debug, Clone, Copy)]
+pub struct RetryPolicy {
+    /// Attempts after the first one.
+    pub retries: u32,
+    /// Wait before the first retry; doubles for each one after.
+    pub base_delay: Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            retries: 2,
+            base_delay: Duration::from_millis(500),
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Run `op`, retrying transient failures with exponential backoff.
+    /// Other errors, and the last transient one, are returned as is.
+    pub async fn run<T, F, Fut>(&self, mut op: F) -> anyhow::Result<T>
+    where
+        F: FnMut() -> Fut,
+        Fut: Future<Output = anyhow::Result<T>>,
+    {
+        let mut delay = self.base_delay;
+        let mut attempt = 0;
+        loop {
+            match op().await {
+                Err(e) if attempt < self.retries && is_transient(&e) => {
+                    tokio::time::sleep(delay).await;
+                    delay *= 2;
+                    attempt += 1;
+                }
+                result => return result,
+            }
+        }
+    }
+}
+
+/// Whether a request error may go away on its own: timeouts, connection
+/// failures, and 5xx or 429 responses.
+pub fn is_transient(e: &anyhow::Error) -> bool {
+    let Some(e) = e.downcast_ref::<reqwest::Error>() else {
+        return false;
+    };
+    match e.status() {
+        Some(status) => {
+            status.is_server_error() || status == reqwest::StatusCode::TOO_MANY_REQUESTS
+        }
+        None => e.is_timeout() || e.is_connect() || e.is_request() || e.is_body(),
+    }
+}
+
+/// A response, and its age when the request failed and an expired cache entry
+/// stood in for it.
+#[derive(Debug, Clone, Default)]
+pub struct Fetched<T> {
+    pub value: T,
+    pub stale: Option<Duration>,
+}
+
+/// The live channels and when their schedule next changes.
+#[derive(Debug, Clone, Default)]
+pub struct LiveListing {
+    pub channels: Vec<DiscoveryItem>,
+    /// Unix time the first of the current shows ends.
+    pub next_change: Option<u64>,
+    /// Start and end of the show on air, per channel number.
+    pub slots: BTreeMap<u8, ShowSlot>,
+}
+
+/// One page of search results.
+#[derive(Debug, Clone, Default)]
+pub struct SearchPage {
+    pub items: Vec<DiscoveryItem>,
+    /// Total matches across all pages, when the API reports it.
+    pub total: Option<u64>,
+    /// Age of the cached page shown because the API could not be reached.
+    pub stale: Option<Duration>,
+}
+
+impl SearchPage {
+    fn from_response(resp: Fetched<NtsSearchResponse>) -> Self {
+        let Fetched { value: resp, stale } = resp;
+        Self {
+            stale,
+            total: resp.metadata.map(|m| m.resultset.count),
+            items: resp
+                .results
+                .into_iter()
+                .map(search_episode_to_discovery)
+                .collect(),
+        }
+    }
+}
+
+/// Fetches one page of search results at `(offset, limit)`.
+pub type PageFetcher = Arc<
+    dyn Fn(u64, u64) -> Pin<Box<dyn Future<Output = anyhow::Result<SearchPage>> + Send>>
+        + Send
+        + Sync,
+>;
+
+/// The boxed future an NtsApi method returns.
+pub type ApiFuture<'a, T> = Pin<Box<dyn Future<Output = anyhow::Result<T>> + Send + 'a>>;
+
+/// The NTS API as the app uses it. NtsClient talks to the real one; tests
+/// can stand in a FixtureApi (`api::fixture`) to run app flows offline.
+pub trait NtsApi: Send + Sync {
+    /// Where requests go, shown on the About screen.
+    fn base(&self) -> &str;
+    fn fetch_live_listing(&self) -> ApiFuture<'_, Fetched<LiveListing>>;
+    fn fetch_live_details(&self, channel: u8) -> ApiFuture<'_, Option<NtsEpisodeDetail>>;
+    fn fetch_live_tracks(&self, channel: u8) -> ApiFuture<'_, Vec<LiveTrack>>;
+    fn fetch_episode<'a>(
+        &'a self,
+        show_alias: &'a str,
+        episode_alias: &'a str,
+    ) -> ApiFuture<'a, NtsEpisodeDetail>;
+    fn fetch_episode_tracklist<'a>(
+        &'a self,
+        show_alias: &'a str,
+        episode_alias: &'a str,
+    ) -> ApiFuture<'a, Vec<EpisodeTrack>>;
+    fn fetch_picks(&self) -> ApiFuture<'_, Fetched<Vec<DiscoveryItem>>>;
+    fn fetch_show_episodes<'a>(
+        &'a self,
+        show_alias: &'a str,
+        limit: u64,
+    ) -> ApiFuture<'a, Vec<DiscoveryItem>>;
+    fn fetch_genres(&self) -> ApiFuture<'_, Fetched<Vec<GenreCategory>>>;
+    fn fetch_genre_count<'a>(&'a self, genre_id: &'a str) -> ApiFuture<'a, Option<u64>>;
+    fn fetch_moods(&self) -> ApiFuture<'_, Fetched<Vec<DiscoveryItem>>>;
+    fn fetch_places(&self) -> ApiFuture<'_, Fetched<Vec<DiscoveryItem>>>;
+    fn fetch_place_episodes<'a>(
+        &'a self,
+        place: &'a str,
+    ) -> ApiFuture<'a, Fetched<Vec<DiscoveryItem>>>;
+    fn search_episodes<'a>(
+        &'a self,
+        genre_id: &'a str,
+        offset: u64,
+        limit: u64,
+    ) -> ApiFuture<'a, SearchPage>;
+    fn search_episodes_by_mood<'a>(
+        &'a self,
+        mood_id: &'a str,
+        offset: u64,
+        limit: u64,
+    ) -> ApiFuture<'a, SearchPage>;
+    fn search_episodes_like<'a>(
+        &'a self,
+        genre_ids: &'a [String],
+        mood_ids: &'a [String],
+        offset: u64,
+        limit: u64,
+    ) -> ApiFuture<'a, SearchPage>;
+    fn search_episodes_by_query<'a>(
+        &'a self,
+        query: &'a str,
+        offset: u64,
+        limit: u64,
+    ) -> ApiFuture<'a, SearchPage>;
+    fn fetch_podcast<'a>(&'a self, feed_url: &'a str) -> ApiFuture<'a, PodcastFeed>;
+    /// Go easier on the network while `on`, e.g. by caching for longer.
+    fn set_low_data(&self, _on: bool) {}
+}
+
 /// Async HTTP client for the NTS Radio public API.
-#[derive(Clone, Default)]
+#[derive(Clone)]
 pub struct NtsClient {
     http: reqwest::Client,
+    base: String,
+    cache: Option<ResponseCache>,
+    retry: RetryPolicy,
+    /// Shared by clones, so the app's copies all follow the toggle.
+    low_data: Arc<AtomicBool>,
+}
+
+impl Default for NtsClient {
+    fn default() -> Self {
+        Self::with_base(NTS_BASE)
+    }
 }
 
 impl NtsClient {
@@ -18,15 +232,142 @@ impl NtsClient {
         Self::default()
     }
 
+    /// A client for a mirror of the API, e.g. a local server for testing.
+    pub fn with_base(base: impl Into<String>) -> Self {
+        let base: String = base.into();
+        Self {
+            http: reqwest::Client::new(),
+            base: base.trim_end_matches('/').to_string(),
+            cache: None,
+            retry: RetryPolicy::default(),
+            low_data: Arc::new(AtomicBool::new(false)),
+        }
+    }
+
+    /// Keep responses in `cache`: fresh ones answer repeat requests, and
+    /// expired ones stand in when the API can't be reached.
+    pub fn with_cache(mut self, cache: ResponseCache) -> Self {
+        self.cache = Some(cache);
+        self
+    }
+
+    /// Send requests through `proxy` (an http:// or https:// URL) and with
+    /// `user_agent`, when set.
+    pub fn with_network(
+        mut self,
+        proxy: Option<&str>,
+        user_agent: Option<&str>,
+    ) -> anyhow::Result<Self> {
+        let mut builder = reqwest::Client::builder();
+        if let Some(proxy) = proxy {
+            builder = builder.proxy(reqwest::Proxy::all(proxy)?);
+        }
+        if let Some(user_agent) = user_agent {
+            builder = builder.user_agent(user_agent);
+        }
+        self.http = builder.build()?;
+        Ok(self)
+    }
+
+    /// Retry transient failures `retries` times before giving up.
+    pub fn with_retries(mut self, retries: u32) -> Self {
+        self.retry.retries = retries;
+        self
+    }
+
+    pub fn base(&self) -> &str {
+        &self.base
+    }
+
+    /// Cache responses for longer while `on`.
+    pub fn set_low_data(&self, on: bool) {
+        self.low_data.store(on, Ordering::Relaxed);
+    }
+
+    /// GET a JSON response, going through the cache when there is one.
+    /// Responses younger than `ttl` (longer in low data mode) are served from
+    /// the cache; older ones are only used, marked stale, when the request
+    /// fails.
+    async fn get_json<T: DeserializeOwned>(
+        &self,
+        request: reqwest::RequestBuilder,
+        ttl: Duration,
+    ) -> anyhow::Result<Fetched<T>> {
+        let request = request.build()?;
+        let ttl = if self.low_data.load(Ordering::Relaxed) {
+            ttl * LOW_DATA_TTL_FACTOR
+        } else {
+            ttl
+        };
+        let Some(cache) = &self.cache else {
+            let value = serde_json::from_value(self.fetch_body(&request).await?)?;
+            return Ok(Fetched { value, stale: None });
+        };
+        let key = request.url().to_string();
+        let cached = match cache.get(&key).and_then(|(body, age)| {
+            serde_json::from_value::<T>(body)
+                .ok()
+                .map(|value| (value, age))
+        }) {
+            Some((value, age)) if age < ttl => return Ok(Fetched { value, stale: None }),
+            cached => cached,
+        };
+
+        let body = match (self.fetch_body(&request).await, cached) {
+            (Ok(body), _) => body,
+            (Err(_), Some((value, age))) => {
+                return Ok(Fetched {
+                    value,
+                    stale: Some(age),
+                })
+            }
+            (Err(e), None) => return Err(e),
+        };
+        let value = serde_json::from_value(body.clone())?;
+        let _ = cache.put(&key, &body);
+        Ok(Fetched { value, stale: None })
+    }
+
+    /// Send `request` and read its JSON body, retrying transient failures.
+    async fn fetch_body(&self, request: &reqwest::Request) -> anyhow::Result<serde_json::Value> {
+        self.retry
+            .run(|| async {
+                let request = request
+                    .try_clone()
+                    .ok_or_else(|| anyhow::anyhow!("request can't be retried"))?;
+                Ok(self
+                    .http
+                    .execute(request)
+                    .await?
+                    .error_for_status()?
+                    .json()
+                    .await?)
+            })
+            .await
+    }
+
+    async fn live_response(&self) -> anyhow::Result<Fetched<NtsLiveResponse>> {
+        self.get_json(
+            self.http.get(format!("{}/api/v2/live", self.base)),
+            LIVE_TTL,
+        )
+        .await
+    }
+
     /// Fetch both live NTS channels and return them as discovery items.
-    pub async fn fetch_live(&self) -> anyhow::Result<Vec<DiscoveryItem>> {
-        let resp: NtsLiveResponse = self
-            .http
-            .get(format!("{}/api/v2/live", NTS_BASE))
-            .send()
-            .await?
-            .json()
-            .await?;
+    pub async fn fetch_live(&self) -> anyhow::Result<Fetched<Vec<DiscoveryItem>>> {
+        let Fetched { value, stale } = self.fetch_live_listing().await?;
+        Ok(Fetched {
+            value: value.channels,
+            stale,
+        })
+    }
+
+    /// Fetch both live NTS channels and when the first of their shows ends.
+    pub async fn fetch_live_listing(&self) -> anyhow::Result<Fetched<LiveListing>> {
+        let Fetched { value: resp, stale } = self.live_response().await?;
+        let next_change = resp.next_change();
+        let slots = resp.slots();
 
         let mut items = Vec::new();
         for channel in resp.results {
@@ -43,71 +384,444 @@ impl NtsClient {
                     .map_or_else(Vec::new, |g| g.iter().map(|g| g.value.clone()).collect()),
             });
         }
-        Ok(items)
+        Ok(Fetched {
+            value: LiveListing {
+                channels: items,
+                next_change,
+                slots,
+            },
+            stale,
+        })
     }
 
-    /// Fetch the "NTS Picks" editorial collection.
-    pub async fn fetch_picks(&self) -> anyhow::Result<Vec<DiscoveryItem>> {
-        let resp: NtsCollectionResponse = self
+    /// Fetch the details of the show on air on a live channel, if published.
+    pub async fn fetch_live_details(
+        &self,
+        channel: u8,
+    ) -> anyhow::Result<Option<NtsEpisodeDetail>> {
+        let resp = self.live_response().await?.value;
+        Ok(resp
+            .results
+            .into_iter()
+            .find(|c| c.channel_name == channel.to_string())
+            .and_then(|c| c.now.embeds)
+            .and_then(|e| e.details))
+    }
+
+    /// Fetch the broadcasts on air and coming up on both live channels.
+    pub async fn fetch_schedule(&self) -> anyhow::Result<Vec<ScheduledBroadcast>> {
+        let resp = self.live_response().await?.value;
+        Ok(resp.upcoming(unix_now()))
+    }
+
+    /// Fetch one episode's details, including its description.
+    pub async fn fetch_episode(
+        &self,
+        show_alias: &str,
+        episode_alias: &str,
+    ) -> anyhow::Result<NtsEpisodeDetail> {
+        let request = self.http.get(format!(
+            "{}/api/v2/shows/{show_alias}/episodes/{episode_alias}",
+            self.base
+        ));
+        Ok(self.get_json(request, EPISODE_TTL).await?.value)
+    }
+
+    /// Fetch the songs recently played on a live channel, newest first.
+    /// These come from the broadcast, not the stream's ICY title.
+    pub async fn fetch_live_tracks(&self, channel: u8) -> anyhow::Result<Vec<LiveTrack>> {
+        let resp: NtsLiveTracklistResponse = self
             .http
-            .get(format!("{}/api/v2/collections/nts-picks", NTS_BASE))
+            .get(format!("{}/api/v2/live/{channel}/tracklist", self.base))
             .send()
             .await?
             .json()
             .await?;
 
-        Ok(resp.results.into_iter().map(episode_to_discovery).collect())
+        Ok(resp.results)
     }
 
-    /// Search episodes by genre, returning one page of results.
-    pub async fn search_episodes(
+    /// Fetch the "NTS Picks" editorial collection.
+    pub async fn fetch_picks(&self) -> anyhow::Result<Fetched<Vec<DiscoveryItem>>> {
+        let request = self
+            .http
+            .get(format!("{}/api/v2/collections/nts-picks", self.base));
+        let Fetched { value: resp, stale } = self
+            .get_json::<NtsCollectionResponse>(request, PICKS_TTL)
+            .await?;
+
+        Ok(Fetched {
+            value: resp.results.into_iter().map(episode_to_discovery).collect(),
+            stale,
+        })
+    }
+
+    /// Fetch the most recent episodes of a show, newest first.
+    pub async fn fetch_show_episodes(
         &self,
-        genre_id: &str,
-        offset: u64,
+        show_alias: &str,
         limit: u64,
     ) -> anyhow::Result<Vec<DiscoveryItem>> {
-        let resp: NtsSearchResponse = self
+        let request = self
             .http
-            .get(format!("{}/api/v2/search/episodes", NTS_BASE))
-            .query(&[
-                ("offset", offset.to_string()),
-                ("limit", limit.to_string()),
-                ("genres[]", genre_id.to_string()),
-            ])
-            .send()
-            .await?
-            .json()
-            .await?;
+            .get(format!(
+                "{}/api/v2/shows/{}/episodes",
+                self.base, show_alias
+            ))
+            .query(&[("offset", 0), ("limit", limit)]);
+        let resp: NtsCollectionResponse = self.get_json(request, SHOW_EPISODES_TTL).await?.value;
 
         Ok(resp
             .results
             .into_iter()
-            .map(search_episode_to_discovery)
+            .map(|mut ep| {
+                // Fill in the alias in case the show endpoint leaves it off.
+                ep.show_alias.get_or_insert_with(|| show_alias.to_string());
+                episode_to_discovery(ep)
+            })
             .collect())
     }
 
+    /// Fetch an episode's tracklist; empty when the episode has none.
+    pub async fn fetch_episode_tracklist(
+        &self,
+        show_alias: &str,
+        episode_alias: &str,
+    ) -> anyhow::Result<Vec<EpisodeTrack>> {
+        let request = self.http.get(format!(
+            "{}/api/v2/shows/{show_alias}/episodes/{episode_alias}/tracklist",
+            self.base
+        ));
+        let resp: NtsTracklistResponse = self.get_json(request, EPISODE_TTL).await?.value;
+
+        Ok(resp.results)
+    }
+
+    /// Fetch the genre tree: top-level genres with their sub-genres.
+    pub async fn fetch_genres(&self) -> anyhow::Result<Fetched<Vec<GenreCategory>>> {
+        let request = self.http.get(format!("{}/api/v2/genres", self.base));
+        let Fetched { value: resp, stale } = self
+            .get_json::<NtsGenresResponse>(request, GENRES_TTL)
+            .await?;
+        anyhow::ensure!(!resp.results.is_empty(), "the API listed no genres");
+
+        Ok(Fetched {
+            value: resp.results.into_iter().map(GenreCategory::from).collect(),
+            stale,
+        })
+    }
+
+    /// Fetch the moods episodes can be searched by.
+    pub async fn fetch_moods(&self) -> anyhow::Result<Fetched<Vec<DiscoveryItem>>> {
+        let request = self.http.get(format!("{}/api/v2/moods", self.base));
+        let Fetched { value: resp, stale } = self
+            .get_json::<NtsMoodsResponse>(request, MOODS_TTL)
+            .await?;
+
+        Ok(Fetched {
+            value: resp
+                .results
+                .into_iter()
+                .map(|mood| DiscoveryItem::NtsMood {
+                    name: mood.value,
+                    mood_id: mood.id,
+                })
+                .collect(),
+            stale,
+        })
+    }
+
+    /// The places the newest episodes were broadcast from, most frequent
+    /// first. The API has no list of locations, so they are counted from
+    /// recent episodes.
+    pub async fn fetch_places(&self) -> anyhow::Result<Fetched<Vec<DiscoveryItem>>> {
+        let Fetched {
+            value: episodes,
+            stale,
+        } = self.recent_episodes().await?;
+        Ok(Fetched {
+            value: places_of(&episodes),
+            stale,
+        })
+    }
+
+    /// The recent episodes broadcast from `place`, newest first.
+    pub async fn fetch_place_episodes(
+        &self,
+        place: &str,
+    ) -> anyhow::Result<Fetched<Vec<DiscoveryItem>>> {
+        let Fetched {
+            value: mut episodes,
+            stale,
+        } = self.recent_episodes().await?;
+        episodes.retain(|episode| episode_place(episode) == Some(place));
+        Ok(Fetched {
+            value: episodes,
+            stale,
+        })
+    }
+
+    /// The first PLACES_SCAN_PAGES pages of all episodes. Pages after the
+    /// first that fail are left out.
+    async fn recent_episodes(&self) -> anyhow::Result<Fetched<Vec<DiscoveryItem>>> {
+        let mut episodes = Vec::new();
+        let mut stale = None;
+        for page in 0..PLACES_SCAN_PAGES {
+            let request = self
+                .http
+                .get(format!("{}/api/v2/search/episodes", self.base))
+                .query(&[
+                    ("offset", page * PLACES_PAGE_SIZE),
+                    ("limit", PLACES_PAGE_SIZE),
+                ]);
+            let page = match self.get_json(request, SEARCH_TTL).await {
+                Ok(resp) => SearchPage::from_response(resp),
+                Err(e) if page == 0 => return Err(e),
+                Err(e) => {
+                    tracing::warn!("couldn't fetch recent episodes for places: {e}");
+                    break;
+                }
+            };
+            stale = stale.max(page.stale);
+            let last = (page.items.len() as u64) < PLACES_PAGE_SIZE;
+            episodes.extend(page.items);
+            if last {
+                break;
+            }
+        }
+        Ok(Fetched {
+            value: episodes,
+            stale,
+        })
+    }
+
+    /// Number of episodes tagged with a genre, when the API reports it.
+    pub async fn fetch_genre_count(&self, genre_id: &str) -> anyhow::Result<Option<u64>> {
+        Ok(self.search_episodes(genre_id, 0, 1).await?.total)
+    }
+
+    /// Search episodes by genre, returning one page of results.
+    pub async fn search_episodes(
+        &self,
+        genre_id: &str,
+        offset: u64,
+        limit: u64,
+    ) -> anyhow::Result<SearchPage> {
+        self.search_tagged(&[("genres[]", genre_id)], offset, limit)
+            .await
+    }
+
+    /// Search episodes by mood, returning one page of results.
+    pub async fn search_episodes_by_mood(
+        &self,
+        mood_id: &str,
+        offset: u64,
+        limit: u64,
+    ) -> anyhow::Result<SearchPage> {
+        self.search_tagged(&[("moods[]", mood_id)], offset, limit)
+            .await
+    }
+
+    /// Search episodes sharing genres and moods with another one,
+    /// returning one page of results.
+    pub async fn search_episodes_like(
+        &self,
+        genre_ids: &[String],
+        mood_ids: &[String],
+        offset: u64,
+        limit: u64,
+    ) -> anyhow::Result<SearchPage> {
+        let tags: Vec<(&str, &str)> = genre_ids
+            .iter()
+            .map(|id| ("genres[]", id.as_str()))
+            .chain(mood_ids.iter().map(|id| ("moods[]", id.as_str())))
+            .collect();
+        self.search_tagged(&tags, offset, limit).await
+    }
+
+    /// One page of the episodes matching `tags`, (filter, tag id) pairs.
+    async fn search_tagged(
+        &self,
+        tags: &[(&str, &str)],
+        offset: u64,
+        limit: u64,
+    ) -> anyhow::Result<SearchPage> {
+        let request = self
+            .http
+            .get(format!("{}/api/v2/search/episodes", self.base))
+            .query(&[("offset", offset), ("limit", limit)])
+            .query(tags);
+
+        Ok(SearchPage::from_response(
+            self.get_json(request, SEARCH_TTL).await?,
+        ))
+    }
+
     /// Free-text search for episodes, returning one page of results.
     pub async fn search_episodes_by_query(
         &self,
         query: &str,
         offset: u64,
         limit: u64,
-    ) -> anyhow::Result<Vec<DiscoveryItem>> {
-        let resp: NtsSearchResponse = self
+    ) -> anyhow::Result<SearchPage> {
+        let request = self
             .http
-            .get(format!("{}/api/v2/search", NTS_BASE))
+            .get(format!("{}/api/v2/search", self.base))
             .query(&[("q", query), ("version", "2"), ("types[]", "episode")])
-            .query(&[("offset", offset), ("limit", limit)])
-            .send()
-            .await?
-            .json()
-            .await?;
+            .query(&[("offset", offset), ("limit", limit)]);
 
-        Ok(resp
-            .results
-            .into_iter()
-            .map(search_episode_to_discovery)
-            .collect())
+        Ok(SearchPage::from_response(
+            self.get_json(request, SEARCH_TTL).await?,
+        ))
+    }
+
+    /// A podcast feed, fetched every time: it's a list of what's new.
+    pub async fn fetch_podcast(&self, feed_url: &str) -> anyhow::Result<PodcastFeed> {
+        self.retry
+            .run(|| podcast::fetch_feed(&self.http, feed_url))
+            .await
+    }
+}
+
+impl NtsApi for NtsClient {
+    fn base(&self) -> &str {
+        self.base()
+    }
+
+    fn fetch_live_listing(&self) -> ApiFuture<'_, Fetched<LiveListing>> {
+        Box::pin(self.fetch_live_listing())
+    }
+
+    fn fetch_live_details(&self, channel: u8) -> ApiFuture<'_, Option<NtsEpisodeDetail>> {
+        Box::pin(self.fetch_live_details(channel))
+    }
+
+    fn fetch_live_tracks(&self, channel: u8) -> ApiFuture<'_, Vec<LiveTrack>> {
+        Box::pin(self.fetch_live_tracks(channel))
+    }
+
+    fn fetch_episode<'a>(
+        &'a self,
+        show_alias: &'a str,
+        episode_alias: &'a str,
+    ) -> ApiFuture<'a, NtsEpisodeDetail> {
+        Box::pin(self.fetch_episode(show_alias, episode_alias))
+    }
+
+    fn fetch_episode_tracklist<'a>(
+        &'a self,
+        show_alias: &'a str,
+        episode_alias: &'a str,
+    ) -> ApiFuture<'a, Vec<EpisodeTrack>> {
+        Box::pin(self.fetch_episode_tracklist(show_alias, episode_alias))
+    }
+
+    fn fetch_picks(&self) -> ApiFuture<'_, Fetched<Vec<DiscoveryItem>>> {
+        Box::pin(self.fetch_picks())
+    }
+
+    fn fetch_show_episodes<'a>(
+        &'a self,
+        show_alias: &'a str,
+        limit: u64,
+    ) -> ApiFuture<'a, Vec<DiscoveryItem>> {
+        Box::pin(self.fetch_show_episodes(show_alias, limit))
+    }
+
+    fn fetch_genres(&self) -> ApiFuture<'_, Fetched<Vec<GenreCategory>>> {
+        Box::pin(self.fetch_genres())
+    }
+
+    fn fetch_genre_count<'a>(&'a self, genre_id: &'a str) -> ApiFuture<'a, Option<u64>> {
+        Box::pin(self.fetch_genre_count(genre_id))
+    }
+
+    fn fetch_moods(&self) -> ApiFuture<'_, Fetched<Vec<DiscoveryItem>>> {
+        Box::pin(self.fetch_moods())
+    }
+
+    fn fetch_places(&self) -> ApiFuture<'_, Fetched<Vec<DiscoveryItem>>> {
+        Box::pin(self.fetch_places())
+    }
+
+    fn fetch_place_episodes<'a>(
+        &'a self,
+        place: &'a str,
+    ) -> ApiFuture<'a, Fetched<Vec<DiscoveryItem>>> {
+        Box::pin(self.fetch_place_episodes(place))
+    }
+
+    fn search_episodes<'a>(
+        &'a self,
+        genre_id: &'a str,
+        offset: u64,
+        limit: u64,
+    ) -> ApiFuture<'a, SearchPage> {
+        Box::pin(self.search_episodes(genre_id, offset, limit))
+    }
+
+    fn search_episodes_by_mood<'a>(
+        &'a self,
+        mood_id: &'a str,
+        offset: u64,
+        limit: u64,
+    ) -> ApiFuture<'a, SearchPage> {
+        Box::pin(self.search_episodes_by_mood(mood_id, offset, limit))
+    }
+
+    fn search_episodes_like<'a>(
+        &'a self,
+        genre_ids: &'a [String],
+        mood_ids: &'a [String],
+        offset: u64,
+        limit: u64,
+    ) -> ApiFuture<'a, SearchPage> {
+        Box::pin(self.search_episodes_like(genre_ids, mood_ids, offset, limit))
+    }
+
+    fn search_episodes_by_query<'a>(
+        &'a self,
+        query: &'a str,
+        offset: u64,
+        limit: u64,
+    ) -> ApiFuture<'a, SearchPage> {
+        Box::pin(self.search_episodes_by_query(query, offset, limit))
+    }
+
+    fn fetch_podcast<'a>(&'a self, feed_url: &'a str) -> ApiFuture<'a, PodcastFeed> {
+        Box::pin(self.fetch_podcast(feed_url))
+    }
+
+    fn set_low_data(&self, on: bool) {
+        self.set_low_data(on);
+    }
+}
+
+/// The places `episodes` were broadcast from, as browse entries with their
+/// episode counts, most frequent first.
+pub(crate) fn places_of(episodes: &[DiscoveryItem]) -> Vec<DiscoveryItem> {
+    let mut counts: BTreeMap<String, usize> = BTreeMap::new();
+    for episode in episodes {
+        if let Some(place) = episode_place(episode) {
+            *counts.entry(place.to_string()).or_default() += 1;
+        }
+    }
+    let mut places: Vec<(String, usize)> = counts.into_iter().collect();
+    places.sort_by_key(|(_, episodes)| std::cmp::Reverse(*episodes));
+    places
+        .into_iter()
+        .map(|(name, episodes)| DiscoveryItem::NtsPlace { name, episodes })
+        .collect()
+}
+
+/// Where an episode was broadcast from, if the API said.
+pub(crate) fn episode_place(item: &DiscoveryItem) -> Option<&str> {
+    match item {
+        DiscoveryItem::NtsEpisode {
+            location: Some(location),
+            ..
+        } => Some(location.trim()).filter(|place| !place.is_empty()),
+        _ => None,
     }
 }
 
@@ -127,6 +841,7 @@ fn episode_to_discovery(ep: NtsEpisodeDetail) -> DiscoveryItem {
             .as_ref()
             .and_then(|sources| sources.first())
             .map(|s| s.url.clone()),
+        broadcast_date: ep.broadcast.clone(),
     }
 }
 
@@ -159,5 +874,6 @@ fn search_episode_to_discovery(ep: NtsSearchEpisode) -> DiscoveryItem {
             .as_ref()
             .and_then(|sources| sources.first())
             .map(|s| s.url.clone()),
+        broadcast_date: ep.local_date,
     }
 }
@@ -0,0 +1,152 @@
+// Podcast feeds: RSS 2.0 and Atom documents read into playable episodes.
+// Only entries with an audio enclosure are kept; their enclosure URL goes to
+// mpv like any other stream.
+
+use roxmltree::{Document, Node, ParsingOptions};
+
+use crate::api::models::DiscoveryItem;
+
+/// A feed's title and its playable episodes, in feed order.
+#[derive(Debug, Clone, Default)]
+pub struct PodcastFeed {
+    /// Also carried by each episode as its show.
+    #[allow(dead_code)] // used by integration tests
+    pub title: String,
+    pub episodes: Vec<DiscoveryItem>,
+}
+
+/// Read an RSS or Atom document fetched from `feed_url`.
+pub fn parse_feed(xml: &str, feed_url: &str) -> anyhow::Result<PodcastFeed> {
+    // Some feeds still carry a DOCTYPE.
+    let options = ParsingOptions {
+        allow_dtd: true,
+        ..ParsingOptions::default()
+    };
+    let doc = Document::parse_with_options(xml, options)?;
+    let root = doc.root_element();
+    let (channel, entry) = match root.tag_name().name() {
+        "rss" => (
+            child(root, "channel").ok_or_else(|| anyhow::anyhow!("RSS feed has no <channel>"))?,
+            "item",
+        ),
+        "feed" => (root, "entry"),
+        other => anyhow::bail!("not an RSS or Atom feed (<{other}>)"),
+    };
+    let title = text(channel, "title").unwrap_or_else(|| feed_url.to_string());
+    let episodes = channel
+        .children()
+        .filter(|node| is(*node, entry))
+        .filter_map(|node| episode(node, &title, feed_url))
+        .collect();
+    Ok(PodcastFeed { title, episodes })
+}
+
+/// An RSS `<item>` or Atom `<entry>` as an episode; None without audio.
+fn episode(node: Node, show: &str, feed_url: &str) -> Option<DiscoveryItem> {
+    let links: Vec<Node> = node.children().filter(|n| is(*n, "link")).collect();
+    let audio_url = child(node, "enclosure")
+        .and_then(|enclosure| enclosure.attribute("url"))
+        .or_else(|| {
+            links
+                .iter()
+                .find(|link| link.attribute("rel") == Some("enclosure"))
+                .and_then(|link| link.attribute("href"))
+        })?
+        .trim()
+        .to_string();
+    if audio_url.is_empty() {
+        return None;
+    }
+    // RSS links are text, Atom ones an href on the page link.
+    let link = links.iter().find_map(|link| match link.attribute("href") {
+        Some(href) if matches!(link.attribute("rel"), None | Some("alternate")) => {
+            Some(href.to_string())
+        }
+        Some(_) => None,
+        None => link.text().map(str::trim).map(String::from),
+    });
+    let published = text(node, "pubDate")
+        .and_then(|date| rfc2822_date(&date))
+        .or_else(|| text(node, "published").or_else(|| text(node, "updated")))
+        .and_then(|date| iso_date(&date));
+    Some(DiscoveryItem::PodcastEpisode {
+        feed_url: feed_url.to_string(),
+        guid: text(node, "guid")
+            .or_else(|| text(node, "id"))
+            .unwrap_or_else(|| audio_url.clone()),
+        title: text(node, "title").unwrap_or_else(|| audio_url.clone()),
+        show: show.to_string(),
+        published,
+        duration_secs: text(node, "duration").and_then(|d| parse_duration(&d)),
+        audio_url,
+        link: link.filter(|l| !l.is_empty()),
+    })
+}
+
+/// Whether `node` is an element named `name`, in any namespace.
+fn is(node: Node, name: &str) -> bool {
+    node.is_element() && node.tag_name().name() == name
+}
+
+fn child<'a, 'i>(node: Node<'a, 'i>, name: &str) -> Option<Node<'a, 'i>> {
+    node.children().find(|n| is(*n, name))
+}
+
+/// Trimmed text of the first child element `name`, unless empty.
+fn text(node: Node, name: &str) -> Option<String> {
+    let text = child(node, name)?.text()?.trim();
+    (!text.is_empty()).then(|| text.to_string())
+}
+
+/// "Tue, 10 Jun 2025 04:00:00 +0000" → "2025-06-10". The day is the one
+/// the feed states, without converting time zones.
+fn rfc2822_date(date: &str) -> Option<String> {
+    let date = date.split_once(',').map_or(date, |(_, rest)| rest);
+    let mut parts = date.split_whitespace();
+    let day: u32 = parts.next()?.parse().ok()?;
+    let month = parts.next()?.get(..3)?.to_ascii_lowercase();
+    let month = [
+        "jan", "feb", "mar", "apr", "may", "jun", "jul", "aug", "sep", "oct", "nov", "dec",
+    ]
+    .iter()
+    .position(|m| *m == month)?
+        + 1;
+    let year: u32 = parts.next()?.parse().ok()?;
+    (1..=31)
+        .contains(&day)
+        .then(|| format!("{year:04}-{month:02}-{day:02}"))
+}
+
+/// The date part of an ISO 8601 timestamp, checked to look like one.
+fn iso_date(date: &str) -> Option<String> {
+    let day = date.get(..10)?;
+    let bytes = day.as_bytes();
+    let shaped = bytes.iter().enumerate().all(|(i, b)| match i {
+        4 | 7 => *b == b'-',
+        _ => b.is_ascii_digit(),
+    });
+    shaped.then(|| day.to_string())
+}
+
+/// An `itunes:duration`: plain seconds, "MM:SS", or "HH:MM:SS".
+pub fn parse_duration(duration: &str) -> Option<u64> {
+    let mut secs = 0u64;
+    for part in duration.trim().split(':') {
+        let part: f64 = part.trim().parse().ok()?;
+        // Feeds are untrusted: an absurd value is no length at all.
+        secs = secs.checked_mul(60)?.checked_add(part as u64)?;
+    }
+    (secs > 0).then_some(secs)
+}
+
+/// Download and read the feed at `url`.
+pub async fn fetch_feed(http: &reqwest::Client, url: &str) -> anyhow::Result<PodcastFeed> {
+    let body = http
+        .get(url)
+        .send()
+        .await?
+        .error_for_status()?
+        .text()
+        .await?;
+    parse_feed(&body, url)
+}
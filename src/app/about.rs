@@ -0,0 +1,73 @@
+// About overlay glue: builds the report from the running config, copies text
+// and links to the clipboard, and opens pages in the browser on request.
+
+use crate::about;
+use crate::action::Action;
+use crate::api::models::DiscoveryItem;
+use crate::app::App;
+use crate::components::notifications::Notification;
+use crate::{browser, clipboard};
+
+impl App {
+    pub(super) fn show_about(&mut self) {
+        self.about_view
+            .show(about::report(&self.config, self.nts_client.base()));
+    }
+
+    /// Copy `text` and say where it went; a failure is reported, not raised.
+    pub(super) fn copy_to_clipboard(&mut self, text: &str) {
+        let notification = match clipboard::copy(text) {
+            Ok(via) => Notification::info(format!("Copied to clipboard via {via}")),
+            Err(e) => Notification::error(format!("Couldn't copy to clipboard: {e}")),
+        };
+        let _ = self.action_tx.send(Action::Notify(notification));
+    }
+
+    /// The link `y` copies: the item's web page, or its stream when it has
+    /// none. With `stream`, always the stream: the source playing now for
+    /// the current track, else the first configured one.
+    pub fn link_to_copy(&self, item: &DiscoveryItem, stream: bool) -> Option<String> {
+        let stream_url = || {
+            let key = item.favorite_key();
+            match self.queue.current() {
+                Some(track) if track.item.favorite_key() == key => Some(track.url.clone()),
+                _ => match item {
+                    DiscoveryItem::NtsLiveChannel { channel, .. } => {
+                        self.config.nts.relays(*channel).into_iter().next()
+                    }
+                    _ => item.playback_url(),
+                },
+            }
+        };
+        if stream {
+            stream_url()
+        } else {
+            item.web_url().or_else(stream_url)
+        }
+    }
+
+    /// Copy an item's link and say which; failures are reported, not raised.
+    pub(super) fn copy_link(&mut self, item: &DiscoveryItem, stream: bool) {
+        let notification = match self.link_to_copy(item, stream) {
+            None if stream => Notification::warn("No stream URL for this item"),
+            None => Notification::warn("No link for this item"),
+            Some(link) => match clipboard::copy(&link) {
+                Ok(via) => Notification::info(format!("Copied {link} via {via}")),
+                Err(e) => Notification::error(format!("Couldn't copy to clipboard: {e}")),
+            },
+        };
+        let _ = self.action_tx.send(Action::Notify(notification));
+    }
+
+    /// Open the item's web page; failures are reported, not raised.
+    pub(super) fn open_in_browser(&mut self, item: &DiscoveryItem) {
+        let notification = match item.web_url() {
+            None => Notification::warn("No web page for this item"),
+            Some(url) => match browser::open(&url) {
+                Ok(via) => Notification::info(format!("Opened {url} with {via}")),
+                Err(e) => Notification::error(format!("Couldn't open the browser: {e}")),
+            },
+        };
+        let _ = self.action_tx.send(Action::Notify(notification));
+    }
+}
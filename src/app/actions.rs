@@ -2,33 +2,57 @@
 
 use crate::action::Action;
 use crate::api::models::DiscoveryItem;
-use crate::app::App;
+use crate::app::fetch::{self, SearchGaps};
+use crate::app::genres::Browse;
+use crate::app::registry::ComponentId;
+use crate::app::{following, metrics, App};
+use crate::components::notifications::Notification;
 use crate::components::nts::NtsSubTab;
-use crate::components::Component;
+use crate::components::{format_time, Component};
+use crate::db;
+use crate::player::equalizer;
 use crate::player::queue::Queue;
 use crate::theme::Theme;
 
 impl App {
     pub async fn handle_action(&mut self, action: Action) -> anyhow::Result<()> {
+        self.metrics.record_action();
+        self.frame_action(&action);
+        if let Some(recorder) = &mut self.recorder {
+            recorder.record(&action);
+        }
         match action {
             // Lifecycle
             Action::Quit => {
-                let _ = self.player.stop().await;
+                self.flush_metrics();
+                self.finish_history_entry();
+                self.save_resume_position();
+                self.save_session();
+                let _ = self.player.quit().await;
                 self.running = false;
             }
 
             // Playback
             Action::PlayItem(item) => self.play_item(item).await?,
+            Action::Preview(item) => self.toggle_preview(&item).await?,
             Action::TogglePlayPause => {
                 if !self.now_playing.is_playing() {
                     self.start_current_track().await?;
                 } else {
                     let _ = self.player.toggle_pause().await;
-                    self.now_playing.update(&Action::TogglePlayPause)?;
-                    self.play_controls.update(&Action::TogglePlayPause)?;
+                    self.deliver(&ComponentId::PLAYBACK, &Action::TogglePlayPause)?;
+                    if self.now_playing.is_paused() {
+                        self.save_resume_position();
+                    }
                 }
             }
             Action::Stop => {
+                self.stopped = true;
+                self.cancel_reconnect();
+                self.finish_history_entry();
+                self.hook_track_finished();
+                self.tracklist_view.clear();
+                self.save_resume_position();
                 let _ = self.player.stop().await;
                 self.seek_modal.hide();
                 self.seek.reset();
@@ -43,7 +67,15 @@ impl App {
             // Queue
             Action::AddToQueue(item) => self.enqueue(item, false),
             Action::AddToQueueNext(item) => self.enqueue(item, true),
+            Action::AddManyToQueue { items, insert_next } => {
+                self.enqueue_marked(items, insert_next)
+            }
             Action::RemoveFromQueue => self.remove_current_from_queue().await?,
+            Action::PlayQueueIndex(index) => self.play_queue_index(index).await?,
+            Action::RemoveQueueIndex(index) => self.remove_queue_index(index).await?,
+            Action::InsertQueueUrl { url, index, play } => {
+                self.insert_queue_url(url, index, play).await?
+            }
             Action::ClearQueue => {
                 self.queue.clear();
                 self.play_controls.set_queue_info(None, 0);
@@ -51,10 +83,125 @@ impl App {
                 self.persist_queue();
             }
 
+            Action::Confirm { prompt, action } => {
+                if self.config.general.confirm_destructive {
+                    self.confirm_modal.ask(prompt, *action);
+                } else {
+                    self.action_tx.send(*action)?;
+                }
+            }
+
+            // Favorites
+            Action::ToggleFavorite(item) => {
+                let key = item.favorite_key();
+                if self.db.is_favorite(&key)? {
+                    self.db.remove_favorite(&key)?;
+                } else {
+                    self.db.add_favorite(&item)?;
+                }
+                self.sync_favorites();
+            }
+            Action::ShowFavorites => self.favorites_view.show(self.db.favorites()?),
+            Action::LoadFavorites => self.show_favorites_tab()?,
+            Action::DeleteFavorite(id) => {
+                self.db.delete_favorite(id)?;
+                self.sync_favorites();
+            }
+            Action::PinFavorite { id, pinned } => {
+                self.db.set_favorite_pinned(id, pinned)?;
+                self.sync_favorites();
+            }
+            Action::ReorderFavorites(ids) => {
+                self.db.set_favorite_positions(&ids)?;
+                self.sync_favorites();
+            }
+            Action::FavoriteItems(items) => {
+                self.discovery_list.clear_marks();
+                let mut added = 0;
+                for item in &items {
+                    if self.db.add_favorite(item)? {
+                        added += 1;
+                    }
+                }
+                self.sync_favorites();
+                let total = items.len();
+                let message = if added == total {
+                    format!("Added {total} favorites")
+                } else {
+                    format!("Added {added} of {total} favorites (the rest already were)")
+                };
+                self.notifications.push(Notification::info(message));
+            }
+
+            // Play history
+            Action::ShowHistory => self.show_history()?,
+            Action::LoadHistory => self.show_history_tab()?,
+            Action::DeleteHistoryEntry(key) => {
+                self.db.delete_history(&key)?;
+                self.sync_history()?;
+            }
+            Action::ClearHistory => {
+                self.db.clear_history()?;
+                self.sync_history()?;
+            }
+
+            // Playlists
+            Action::ShowPlaylists => self.playlists_view.show(self.db.playlists()?),
+            Action::ChoosePlaylist(items) => self
+                .playlists_view
+                .show_for_adding(self.db.playlists()?, items),
+            Action::LoadPlaylists => self.show_playlists_tab()?,
+            Action::LoadForYou => self.load_for_you()?,
+            Action::ForYouLoaded(items) => self.for_you_loaded(items),
+            Action::CreatePlaylist(name) => self.create_playlist(&name)?,
+            Action::RenamePlaylist { id, name } => self.rename_playlist(id, &name)?,
+            Action::DeletePlaylist(id) => self.delete_playlist(id)?,
+            Action::OpenPlaylist(id) => self.open_playlist(id)?,
+            Action::AddToPlaylist { id, items } => self.add_to_playlist(id, items)?,
+            Action::LoadPlaylist(id) => self.load_playlist(id)?,
+
+            // Podcasts
+            Action::LoadPodcasts => self.load_podcasts()?,
+            Action::PodcastsLoaded(items) => self.podcasts_loaded(items),
+            Action::OpenPodcastSubscribe => self.direct_play_modal.show_subscribe(),
+            Action::SubscribePodcast(url) => self.subscribe_podcast(url)?,
+            Action::UnsubscribePodcast(url) => self.unsubscribe_podcast(&url)?,
+            Action::SetPodcastPlayed { key, played } => self.set_podcast_played(&key, played)?,
+
+            // Export / import
+            // Start where export.json lives by default.
+            Action::OpenFilePicker(purpose) => self.file_picker.show(purpose, &db::data_dir()),
+            Action::ExportData(path) => self.export_data(&path),
+            Action::ImportData(path) => self.import_data(&path),
+            Action::ImportPlaylist(path) => self.import_playlist(&path),
+
+            // Followed shows
+            Action::ToggleFollow(item) => self.toggle_follow(&item)?,
+            Action::LoadFollowing => {
+                self.show_following();
+                self.spawn_check_followed()?;
+            }
+            Action::CheckFollowedShows => {
+                self.following.check_ticks = 0;
+                self.spawn_check_followed()?;
+            }
+            Action::FollowedEpisodesLoaded(results) => self.apply_followed_episodes(results)?,
+
             // Data loading
-            Action::LoadNtsLive => self.spawn_fetch_live(),
+            Action::LoadNtsLive => {
+                self.show_cached_live();
+                self.spawn_fetch_live();
+            }
+            Action::LiveScheduleLoaded { next_change, slots } => {
+                self.live_schedule_loaded(next_change, slots);
+            }
+            Action::LiveTracksLoaded { channel, tracks } => {
+                self.live_tracks_loaded(channel, tracks);
+            }
             Action::NtsLiveLoaded(items) => {
                 self.live_refresh_ticks = 0;
+                self.fetch_succeeded(NtsSubTab::Live);
+                self.save_live_snapshot(&items);
                 if self.queue.update_live_channels(&items) {
                     self.sync_queue_to_now_playing();
                     self.sync_play_controls();
@@ -62,47 +209,143 @@ impl App {
                 }
                 if self.nts_tab.active_sub() == NtsSubTab::Live {
                     self.discovery_list.set_items(items);
+                    self.discovery_list.set_heading(None);
+                } else if let Some(list) = self.stashed_list(NtsSubTab::Live) {
+                    list.replace_items(items);
+                    list.set_heading(None);
                 }
             }
             Action::LoadNtsPicks => self.spawn_fetch_picks(),
-            Action::NtsPicksLoaded(items) => self.discovery_list.set_items(items),
+            Action::NtsPicksLoaded(items) => {
+                self.fetch_succeeded(NtsSubTab::Picks);
+                if self.nts_tab.active_sub() == NtsSubTab::Picks {
+                    self.discovery_list.set_items(items);
+                    self.discovery_list.set_heading(None);
+                } else if let Some(list) = self.stashed_list(NtsSubTab::Picks) {
+                    list.replace_items(items);
+                    list.set_heading(None);
+                }
+            }
+            Action::FetchFailed { tab, error } => self.fetch_failed(tab, error),
+            Action::StaleListLoaded { tab, items, age } => self.show_stale_list(tab, items, age),
             Action::LoadGenres => self.load_genres()?,
             Action::GenresLoaded(items) => {
                 self.discovery_list.set_items(items);
+                self.discovery_list.set_heading(None);
                 self.viewing_genre_results = false;
                 self.viewing_query_results = false;
+                self.search_gaps = SearchGaps::default();
+            }
+
+            Action::GenreTreeLoaded(tree) => self.genre_tree_loaded(tree),
+            Action::GenreCountLoaded { genre_id, count } => {
+                self.discovery_list.set_genre_count(genre_id, count);
             }
+            Action::ToggleGenreCategory(category_id) => self.toggle_genre_category(category_id),
+            Action::ToggleGenreGroups => self.toggle_genre_groups(),
 
-            // Genre search
+            Action::ToggleMoods => self.toggle_browse(Browse::Moods)?,
+            Action::MoodsLoaded(items) => self.browse_list_loaded(Browse::Moods, items),
+            Action::TogglePlaces => self.toggle_browse(Browse::Places)?,
+            Action::PlacesLoaded(items) => self.browse_list_loaded(Browse::Places, items),
+
+            // Genre, mood and place search
             Action::SearchByGenre { genre_id } => self.search_by_genre(genre_id)?,
+            Action::SearchByMood { mood_id } => self.search_by_mood(mood_id)?,
+            Action::SearchByPlace { place } => self.search_by_place(place)?,
+            Action::MoreLikeThis(item) => self.more_like_this(item),
+            Action::SearchRelated {
+                title,
+                key,
+                genre_ids,
+                mood_ids,
+            } => self.search_related(title, key, genre_ids, mood_ids),
             Action::SearchResultsPartial {
                 search_id,
-                items,
+                mut items,
+                total,
                 done,
             } => {
-                if search_id == self.search_id {
+                items.retain(|item| !self.search_exclude.contains(&item.favorite_key()));
+                if search_id != self.search_id {
+                    // A newer search replaced these results.
+                } else if let Some(list) = self.stashed_list(NtsSubTab::Search) {
+                    list.append_items(items);
+                    if let Some(total) = total {
+                        list.set_search_total(total);
+                    }
+                    if done {
+                        list.set_loading(false);
+                    }
+                } else {
                     if !items.is_empty() {
                         self.discovery_list.append_items(items);
                     }
+                    if let Some(total) = total {
+                        self.discovery_list.set_search_total(total);
+                    }
                     if done {
                         self.discovery_list.set_loading(false);
                     }
                 }
             }
+            Action::SearchPagesFailed {
+                search_id,
+                failed,
+                resume_from,
+            } => {
+                if search_id == self.search_id {
+                    self.record_search_gaps(failed, resume_from);
+                }
+            }
+            Action::SearchResultsStale { search_id, age } => {
+                if search_id == self.search_id {
+                    self.mark_search_stale(age);
+                }
+            }
+            Action::RetrySearchPages => self.retry_search_gaps(),
 
             // Tab switching
             Action::SwitchSubTab(idx) => self.switch_sub_tab(idx)?,
 
             // Search / filter
+            Action::FilterList(query) => {
+                // Stale timers, and ones that fire after Enter or Esc, do nothing.
+                if self.search_bar.is_focused() && self.search_bar.input() == query {
+                    self.discovery_list
+                        .set_filter(Some(query).filter(|q| !q.is_empty()));
+                    let count = self.discovery_list.visible_items().len();
+                    self.search_bar
+                        .set_match_count((!self.search_bar.input().is_empty()).then_some(count));
+                }
+            }
             Action::SearchSubmit => {
                 let query = self.search_bar.input().to_string();
+                self.discovery_list.set_filter(None);
+                self.search_bar.set_match_count(None);
                 if !query.is_empty() {
+                    if let Err(e) = self.db.record_search(&query) {
+                        tracing::warn!("couldn't save search: {e}");
+                    }
+                    self.search_bar.set_history(
+                        self.db
+                            .recent_searches(fetch::SEARCH_HISTORY)
+                            .unwrap_or_default(),
+                    );
                     if self.nts_tab.active_sub() != NtsSubTab::Search {
+                        self.stash_active_tab();
+                        self.search_bar.set_input(query.clone());
                         self.nts_tab.switch_sub_tab(2);
+                        self.forget_tab(NtsSubTab::Search);
                     }
                     self.action_tx.send(Action::SearchByQuery { query })?;
                 }
             }
+            Action::DeleteSearch(query) => {
+                if let Err(e) = self.db.delete_search(&query) {
+                    tracing::warn!("couldn't forget search: {e}");
+                }
+            }
             Action::SearchByQuery { query } => self.search_by_query(query)?,
 
             // Direct play modal
@@ -120,21 +363,37 @@ impl App {
                 let filtered = Action::PlaybackDuration(dur);
                 self.seek.duration_secs = dur;
                 self.seek.is_seekable = dur.is_some();
-                self.now_playing.update(&filtered)?;
-                self.play_controls.update(&filtered)?;
+                self.deliver(&ComponentId::PLAYBACK, &filtered)?;
                 if self.seek_modal.is_visible() {
                     if let Some(d) = dur {
                         self.seek_modal.update_duration(d);
                     }
                 }
-                if self.seek.pending_intro_skip && dur.is_some() {
-                    self.seek.pending_intro_skip = false;
-                    self.action_tx.send(Action::SeekRelative(3.0))?;
+                if dur.is_some() {
+                    if let Some(pos) = self.seek.pending_resume.take() {
+                        self.action_tx.send(Action::SeekAbsolute(pos))?;
+                    }
+                }
+                if dur.is_some() {
+                    if let Some(secs) = self.seek.pending_intro_skip.take() {
+                        self.action_tx.send(Action::SeekRelative(secs))?;
+                    }
+                }
+                if let Some(secs) = dur {
+                    self.record_track_length(secs);
                 }
             }
             Action::SeekRelative(secs) => {
                 let _ = self.player.seek_relative(secs).await;
             }
+            Action::SeekAbsolute(secs) => {
+                let _ = self.player.seek_absolute(secs).await;
+            }
+            Action::IntroDetected { url, start } => self.intro_detected(&url, start)?,
+            Action::PlaybackBuffered(secs) => {
+                self.health.set_buffered(secs);
+                self.play_controls.update(&action)?;
+            }
             Action::OpenSeekModal => {
                 if self.seek.is_seekable {
                     if let Some(dur) = self.seek.duration_secs {
@@ -145,6 +404,20 @@ impl App {
             Action::CloseSeekModal => {
                 self.seek_modal.hide();
             }
+            Action::DeleteBookmark(id) => self.delete_bookmark(id)?,
+            Action::SetAbLoop(ab) => {
+                let was_looping = self.seek.ab_loop.is_some();
+                self.player.set_ab_loop(ab).await?;
+                self.seek.ab_loop = ab;
+                let message = match ab {
+                    Some((a, b)) => Some(format!("Looping {}–{}", format_time(a), format_time(b))),
+                    None if was_looping => Some("Stopped looping".to_string()),
+                    None => None,
+                };
+                if let Some(message) = message {
+                    self.notifications.push(Notification::info(message));
+                }
+            }
 
             // Visualizer
             Action::CycleVisualizer => {
@@ -153,12 +426,53 @@ impl App {
                 self.save_config_async();
             }
 
+            Action::CycleBufferProfile => {
+                self.config.player.buffer_profile = self.config.player.buffer_profile.next();
+                self.player.set_buffer(self.config.player.buffer_settings());
+                if self.now_playing.is_playing() {
+                    let _ = self.player.apply_buffer().await;
+                }
+                self.play_controls
+                    .set_buffer_profile(self.config.player.buffer_profile);
+                self.save_config_async();
+            }
+
+            Action::OpenEqualizer => {
+                let custom = equalizer::custom_gains(&self.config.player.custom_eq_gains);
+                self.equalizer_modal
+                    .show(self.config.player.equalizer, custom);
+            }
+            Action::SetEqualizer {
+                preset,
+                custom_gains,
+            } => {
+                self.config.player.equalizer = preset;
+                self.config.player.custom_eq_gains = if custom_gains.iter().all(|g| *g == 0.0) {
+                    Vec::new()
+                } else {
+                    custom_gains.to_vec()
+                };
+                self.player
+                    .set_equalizer(self.config.player.equalizer_filter());
+                if self.now_playing.is_playing() {
+                    let _ = self.player.apply_equalizer().await;
+                }
+                self.save_config_async();
+            }
+
+            Action::CycleSort => {
+                self.discovery_list.cycle_sort();
+            }
+
             Action::ToggleSkipIntro => {
                 self.config.general.skip_nts_intro = !self.config.general.skip_nts_intro;
                 self.play_controls.update(&action)?;
                 self.save_config_async();
             }
 
+            Action::ToggleRadio => self.toggle_radio(),
+            Action::ToggleLowData => self.toggle_low_data().await,
+
             // Onboarding
             Action::OnboardingComplete {
                 theme,
@@ -175,65 +489,121 @@ impl App {
                 self.save_config_async();
                 self.action_tx.send(Action::LoadNtsLive)?;
             }
+            Action::RestoreSession => self.restore_session().await?,
             Action::ShowOnboarding => {
                 self.onboarding.activate_all();
             }
 
             // Playback state updates (forwarded to display components)
-            Action::AudioLevels { .. } => {
+            Action::AudioLevels { .. } | Action::AudioBands(_) => {
                 self.now_playing.update(&action)?;
             }
             Action::PlaybackStarted { .. } => {
-                self.now_playing.update(&action)?;
-                self.play_controls.update(&action)?;
-                if self.config.general.skip_nts_intro {
-                    if let Some(track) = self.queue.current() {
-                        if matches!(
-                            track.item,
-                            crate::api::models::DiscoveryItem::NtsEpisode { .. }
-                        ) {
-                            self.seek.pending_intro_skip = true;
-                        }
-                    }
+                self.count_play();
+                self.health.track_started();
+                self.begin_history_entry();
+                self.hook_track_started();
+                self.deliver(&ComponentId::PLAYBACK, &action)?;
+                // Starting shows it playing, even when mpv already said
+                // it's paused.
+                if std::mem::take(&mut self.seek.pending_pause) {
+                    self.deliver(&ComponentId::PLAYBACK, &Action::PauseChanged(true))?;
                 }
+                self.queue_resume();
+                self.sync_live_tracks();
+                self.poll_live_tracks();
+                self.load_tracklist();
+                self.load_bookmarks();
+                self.seek_modal.clear_ab_loop();
+                self.skip_intro();
             }
             Action::PlaybackPosition(pos) => {
-                self.now_playing.update(&action)?;
-                self.play_controls.update(&action)?;
+                self.confirm_source(pos);
+                self.confirm_reconnect(pos);
+                self.watchdog_position(pos);
+                self.tracklist_view.set_position(pos);
+                self.tick_crossfade(pos).await?;
+                self.deliver(&ComponentId::PLAYBACK, &action)?;
                 if self.seek_modal.is_visible() {
                     self.seek_modal.update_position(pos);
                 }
             }
+            Action::PauseChanged(paused) => {
+                // Pauses made here already show; this catches the rest.
+                if self.now_playing.is_playing() && paused != self.now_playing.is_paused() {
+                    self.deliver(&ComponentId::PLAYBACK, &action)?;
+                    // A track loaded paused hasn't got anywhere to save yet.
+                    if paused && !self.seek.pending_pause {
+                        self.save_resume_position();
+                    }
+                }
+            }
+            Action::AudioDeviceRemoved(device) => self.audio_device_removed(&device).await?,
+            Action::SystemSleep => self.system_sleep().await?,
+            Action::SystemWake => self.system_wake().await?,
             Action::PlaybackLoading => {
                 self.play_controls.update(&action)?;
             }
             Action::StreamMetadataChanged(metadata) => {
+                self.hook_metadata_changed(&metadata);
                 self.queue.set_current_stream_metadata(metadata.clone());
                 let action = Action::StreamMetadataChanged(metadata);
-                self.now_playing.update(&action)?;
-                self.play_controls.update(&action)?;
+                self.deliver(&ComponentId::PLAYBACK, &action)?;
                 self.sync_queue_to_now_playing();
             }
+            Action::PlaybackFailed(failure) => self.playback_failure = Some(failure),
             Action::PlaybackFinished => {
-                self.now_playing.update(&action)?;
-                self.play_controls.update(&action)?;
+                if self.retry_failed_source().await? || self.schedule_reconnect() {
+                    return Ok(());
+                }
+                self.finish_history_entry();
+                self.hook_track_finished();
+                self.tracklist_view.clear();
+                self.save_resume_position();
+                self.deliver(&ComponentId::PLAYBACK, &action)?;
                 self.seek_modal.hide();
                 self.seek.reset();
+                let last_episode = self
+                    .queue
+                    .current()
+                    .filter(|_| self.queue.current_index() == Some(self.queue.len() - 1))
+                    .map(|track| track.item.clone())
+                    .filter(|item| matches!(item, DiscoveryItem::NtsEpisode { .. }));
                 self.play_queue_track(Queue::advance).await?;
+                if let Some(episode) = last_episode {
+                    self.notifications.push(Notification::info(format!(
+                        "Finished {} — press R for more like it",
+                        episode.title()
+                    )));
+                }
             }
 
-            // Errors & help
-            Action::ShowError(msg) => {
-                self.error_message = Some(msg);
-                let tx = self.action_tx.clone();
-                tokio::spawn(async move {
-                    tokio::time::sleep(tokio::time::Duration::from_secs(5)).await;
-                    tx.send(Action::ClearError).ok();
-                });
-            }
-            Action::ClearError => self.error_message = None,
+            // Notifications & help
+            Action::Notify(notification) => self.notifications.push(notification),
+            Action::ShowNotificationHistory => self.notifications.show_history(),
+            Action::HideNotificationHistory => self.notifications.hide_history(),
             Action::ShowHelp => self.show_help = true,
             Action::HideHelp => self.show_help = false,
+            Action::ShowStats => self.show_stats(),
+            Action::ShowLogs => self.log_view.show(),
+            Action::ShowAbout => self.show_about(),
+            Action::JumpToPlaying => self.jump_to_playing()?,
+            Action::ShowTracklist => self.show_tracklist()?,
+            Action::ShowInfo(item) => self.show_info(&item),
+            Action::ShowContextMenu(item) => self.show_context_menu(&item),
+            Action::ItemInfoLoaded { key, info } => self.info_view.set_info(&key, info),
+            Action::TrackLengthEstimated { key, secs } => self.track_length_estimated(key, secs),
+            Action::UrlResolved { url, title, meta } => self.url_resolved(&url, title, meta),
+            Action::RadioEpisodesLoaded { genre, items } => {
+                self.radio_episodes_loaded(genre, items);
+            }
+            Action::EpisodeTracklistLoaded { key, tracks } => {
+                self.tracklist_view.set_tracks(&key, tracks);
+            }
+            Action::CopyToClipboard(text) => self.copy_to_clipboard(&text),
+            Action::OpenInBrowser(item) => self.open_in_browser(&item),
+            Action::CopyLink { item, stream } => self.copy_link(&item, stream),
+            Action::ClearMetrics => self.clear_metrics()?,
 
             // Volume
             Action::VolumeUp => self.adjust_volume(5.0).await?,
@@ -244,7 +614,14 @@ impl App {
 
             // Navigation
             Action::Back => {
-                if self.nts_tab.active_sub() == NtsSubTab::Search
+                if self.discovery_list.find_query().is_some() && !self.search_bar.is_focused() {
+                    self.discovery_list.clear_find();
+                } else if self.discovery_list.marked_count() > 0 && !self.search_bar.is_focused() {
+                    self.discovery_list.clear_marks();
+                } else if self.search_bar.is_focused() {
+                    // Typing was cancelled: show the whole list again.
+                    self.discovery_list.set_filter(None);
+                } else if self.nts_tab.active_sub() == NtsSubTab::Search
                     && (self.viewing_genre_results || self.viewing_query_results)
                 {
                     self.viewing_query_results = false;
@@ -256,54 +633,60 @@ impl App {
                 self.search_bar.update(&Action::Back)?;
             }
 
-            // Periodic refresh of live channel metadata (~2 minutes)
+            // Periodic refresh of live channel metadata ([nts] live_refresh_secs)
             Action::Tick => {
-                self.live_refresh_ticks += 1;
-                let interval = (self.config.general.frame_rate * 120.0) as u32;
-                if interval > 0 && self.live_refresh_ticks >= interval {
-                    self.live_refresh_ticks = 0;
-                    self.spawn_fetch_live();
+                self.tick_pending_keys()?;
+                self.request_genre_counts();
+                if let Some(refresh) = self.config.nts.live_refresh_interval() {
+                    self.live_refresh_ticks += 1;
+                    let interval = (self.tick_rate() * refresh.as_secs_f64()) as u32;
+                    if interval > 0 && self.live_refresh_ticks >= interval {
+                        self.live_refresh_ticks = 0;
+                        self.spawn_fetch_live();
+                    }
                 }
-                self.nts_tab.update(&Action::Tick)?;
-                self.discovery_list.update(&Action::Tick)?;
-                self.search_bar.update(&Action::Tick)?;
-                self.now_playing.update(&Action::Tick)?;
-                self.play_controls.update(&Action::Tick)?;
-            }
-
-            // Forward anything unhandled to components
-            action => {
-                let results = self.nts_tab.update(&action)?;
-                for a in results {
-                    self.action_tx.send(a)?;
+                self.following.check_ticks += 1;
+                let interval = (self.tick_rate() * following::CHECK_INTERVAL_SECS) as u32;
+                if interval > 0 && self.following.check_ticks >= interval {
+                    self.action_tx.send(Action::CheckFollowedShows)?;
                 }
-                let results = self.discovery_list.update(&action)?;
-                for a in results {
-                    self.action_tx.send(a)?;
+                self.tick_live_schedule();
+                self.tick_reconnect().await?;
+                self.tick_watchdog().await?;
+                self.tick_preview().await;
+                self.tick_history_entry();
+                self.tick_live_tracks();
+                self.tick_resume_position();
+                self.tick_sleep().await?;
+                self.metrics_flush_ticks += 1;
+                let interval = (self.tick_rate() * metrics::FLUSH_INTERVAL_SECS) as u32;
+                if interval > 0 && self.metrics_flush_ticks >= interval {
+                    self.flush_metrics();
                 }
-                self.search_bar.update(&action)?;
-                self.now_playing.update(&action)?;
-                self.play_controls.update(&action)?;
+                self.tick_health();
+                self.broadcast(&Action::Tick)?;
             }
+
+            // Forward anything unhandled to components
+            action => self.broadcast(&action)?,
         }
+        self.apply_pending_session();
         Ok(())
     }
 
-    fn switch_sub_tab(&mut self, idx: usize) -> anyhow::Result<()> {
-        self.discovery_list.set_items(vec![]);
-        self.discovery_list.set_loading(true);
-        self.viewing_genre_results = false;
-        self.viewing_query_results = false;
-        self.discovery_list.set_filter(None);
-        self.search_bar.update(&Action::Back)?;
-
+    /// Open a sub-tab where it was left, or load it if there's nothing kept.
+    pub(super) fn switch_sub_tab(&mut self, idx: usize) -> anyhow::Result<()> {
+        self.stash_active_tab();
         let actions = self.nts_tab.switch_sub_tab(idx);
+        if self.restore_active_tab() {
+            return Ok(());
+        }
+        self.discovery_list.set_loading(true);
+        self.discovery_list
+            .set_empty_message(self.nts_tab.active_sub().empty_message());
         if actions.is_empty() {
-            match self.nts_tab.active_sub() {
-                NtsSubTab::Live => self.action_tx.send(Action::LoadNtsLive)?,
-                NtsSubTab::Picks => self.action_tx.send(Action::LoadNtsPicks)?,
-                NtsSubTab::Search => self.action_tx.send(Action::LoadGenres)?,
-            }
+            self.action_tx
+                .send(fetch::load_action(self.nts_tab.active_sub()))?;
         } else {
             for a in actions {
                 self.action_tx.send(a)?;
@@ -312,6 +695,15 @@ impl App {
         Ok(())
     }
 
+    /// Widen the list panel by `delta` percent (narrow it when negative) and
+    /// remember the new split.
+    pub(super) fn resize_split(&mut self, delta: i16) {
+        let percent = self.config.layout.resize_split(delta);
+        self.notifications
+            .push(Notification::info(format!("List panel {percent}% wide")));
+        self.save_config_async();
+    }
+
     /// Write the current config to disk without blocking the event loop.
     pub(super) fn save_config_async(&self) {
         let config = self.config.clone();
@@ -0,0 +1,104 @@
+// Bookmarks (`b`): cue points in the playing episode, kept in the database
+// under its favorite_key, drawn on the seek timeline, and jumped between
+// with `{` and `}`.
+
+use crate::action::Action;
+use crate::api::models::playing_track_index;
+use crate::app::App;
+use crate::components::format_time;
+use crate::components::notifications::Notification;
+use crate::db::Bookmark;
+
+// A jump skips bookmarks closer than this, so pressing `{` right after
+// landing on one goes to the one before it.
+const JUMP_SLACK: f64 = 2.0;
+
+impl App {
+    /// Load the playing track's bookmarks; called when it starts.
+    pub(super) fn load_bookmarks(&mut self) {
+        let bookmarks = match self.queue.current() {
+            Some(track) => self
+                .db
+                .bookmarks(&track.item.favorite_key())
+                .unwrap_or_else(|e| {
+                    tracing::warn!("couldn't load bookmarks: {e}");
+                    Vec::new()
+                }),
+            None => Vec::new(),
+        };
+        self.seek_modal.set_bookmarks(bookmarks.clone());
+        self.seek.bookmarks = bookmarks;
+    }
+
+    /// Bookmark the current position, labelled with the song playing there
+    /// when the tracklist knows it.
+    pub(super) fn add_bookmark(&mut self) -> anyhow::Result<()> {
+        let Some(track) = self.queue.current().filter(|_| self.seek.is_seekable) else {
+            self.notifications.push(Notification::info(
+                "Bookmarks are for episodes, not live streams",
+            ));
+            return Ok(());
+        };
+        let key = track.item.favorite_key();
+        let position = self.now_playing.position_secs();
+        let song = self
+            .tracklist_view
+            .tracks()
+            .filter(|_| self.tracklist_view.key() == Some(key.as_str()))
+            .and_then(|tracks| Some(tracks[playing_track_index(tracks, position)?].display()));
+        let label = song.unwrap_or_else(|| format!("Bookmark {}", self.seek.bookmarks.len() + 1));
+        self.db.add_bookmark(&key, position, &label)?;
+        self.load_bookmarks();
+        self.notifications.push(Notification::info(format!(
+            "Bookmarked {} — {label}",
+            format_time(position)
+        )));
+        Ok(())
+    }
+
+    /// Seek to the next bookmark after the current position, or the one
+    /// before it.
+    pub(super) fn jump_to_bookmark(&mut self, forward: bool) -> anyhow::Result<()> {
+        if !self.seek.is_seekable {
+            return Ok(());
+        }
+        let position = self.now_playing.position_secs();
+        let target = if forward {
+            self.seek
+                .bookmarks
+                .iter()
+                .find(|b| b.position_secs > position + JUMP_SLACK)
+        } else {
+            self.seek
+                .bookmarks
+                .iter()
+                .rev()
+                .find(|b| b.position_secs < position - JUMP_SLACK)
+        };
+        let message = match target {
+            Some(Bookmark {
+                position_secs,
+                label,
+                ..
+            }) => {
+                self.action_tx.send(Action::SeekAbsolute(*position_secs))?;
+                format!("{} — {label}", format_time(*position_secs))
+            }
+            None if self.seek.bookmarks.is_empty() => {
+                "No bookmarks in this episode (b adds one)".to_string()
+            }
+            None if forward => "No bookmark after this point".to_string(),
+            None => "No bookmark before this point".to_string(),
+        };
+        self.notifications.push(Notification::info(message));
+        Ok(())
+    }
+
+    pub(super) fn delete_bookmark(&mut self, id: i64) -> anyhow::Result<()> {
+        self.db.delete_bookmark(id)?;
+        self.load_bookmarks();
+        self.notifications
+            .push(Notification::info("Removed the bookmark"));
+        Ok(())
+    }
+}
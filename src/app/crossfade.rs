@@ -0,0 +1,68 @@
+// Crossfading between queue tracks: watches the position of the current
+// track, preloads the next one shortly before the fade, then hands playback
+// over to it without waiting for the current mpv to exit.
+
+use crate::action::Action;
+use crate::app::App;
+use crate::player::crossfade::{self, CrossfadePhase};
+
+/// Which queue entry has been preloaded for the upcoming crossfade.
+#[derive(Debug, Default)]
+pub(crate) struct CrossfadeState {
+    preloaded_index: Option<usize>,
+}
+
+impl App {
+    /// Called on each position update of the current track.
+    pub(super) async fn tick_crossfade(&mut self, position: f64) -> anyhow::Result<()> {
+        let secs = self.config.player.crossfade_secs();
+        let Some(duration) = self.seek.duration_secs else {
+            return Ok(());
+        };
+        let Some(next_index) = self.queue.current_index().map(|i| i + 1) else {
+            return Ok(());
+        };
+        let Some(next_url) = self.queue.items().get(next_index).map(|qi| qi.url.clone()) else {
+            return Ok(());
+        };
+        match crossfade::phase(position, duration, secs) {
+            CrossfadePhase::Playing => {}
+            // Low data mode doesn't fetch ahead; the next track starts
+            // when this one ends.
+            CrossfadePhase::Preload if self.config.general.low_data => {}
+            CrossfadePhase::Preload => {
+                if self.crossfade.preloaded_index != Some(next_index) {
+                    self.crossfade.preloaded_index = Some(next_index);
+                    // Without a preload the track just ends and the next one
+                    // starts as usual.
+                    let _ = self.player.preload(&next_url).await;
+                }
+            }
+            CrossfadePhase::Fade => {
+                // The queue may have changed since the preload.
+                if self.crossfade.preloaded_index == Some(next_index)
+                    && self.player.preloaded_url() == Some(next_url)
+                {
+                    self.crossfade_to_next(secs).await?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    async fn crossfade_to_next(&mut self, secs: f64) -> anyhow::Result<()> {
+        self.finish_history_entry();
+        self.save_resume_position();
+        self.seek_modal.hide();
+        self.seek.reset();
+        if self.player.crossfade(secs).await.is_err() {
+            return Ok(());
+        }
+        self.queue.advance();
+        if let Some((_, title)) = self.show_current_track() {
+            self.action_tx.send(Action::PlaybackStarted { title })?;
+        }
+        self.persist_queue();
+        Ok(())
+    }
+}
@@ -0,0 +1,78 @@
+// In-app export and import: writes favorites, history, and the queue to the
+// export file, merges one back in, and queues M3U playlists, reporting the
+// outcome as a notification.
+
+use std::path::Path;
+
+use crate::app::App;
+use crate::components::notifications::Notification;
+use crate::playlist;
+use crate::transfer::{self, ExportFile};
+
+impl App {
+    pub fn export_data(&mut self, path: &Path) {
+        let result = ExportFile::collect(&self.db, self.queue.items()).and_then(|file| {
+            file.write(path)?;
+            Ok(file.counts())
+        });
+        self.notifications.push(match result {
+            Ok(counts) => Notification::info(format!("Exported {} to {}", counts, path.display())),
+            Err(e) => Notification::error(format!("Export failed: {e}")),
+        });
+    }
+
+    /// Merge an export file: new favorites and plays go to the database, new
+    /// queue items are appended to the queue.
+    pub fn import_data(&mut self, path: &Path) {
+        let result =
+            ExportFile::read(path).and_then(|file| file.import_into(&self.db, self.queue.items()));
+        let (counts, new_items) = match result {
+            Ok(imported) => imported,
+            Err(e) => {
+                self.notifications
+                    .push(Notification::error(format!("Import failed: {e}")));
+                return;
+            }
+        };
+        for item in new_items {
+            self.queue.add(item);
+        }
+        self.sync_play_controls();
+        self.sync_queue_to_now_playing();
+        self.persist_queue();
+        self.sync_favorites();
+        let _ = self.sync_history();
+        self.notifications.push(Notification::info(format!(
+            "Imported {} from {}",
+            counts,
+            path.display()
+        )));
+    }
+
+    /// Append an M3U playlist's entries to the queue as direct URLs, skipping
+    /// ones already queued.
+    pub fn import_playlist(&mut self, path: &Path) {
+        let items = match playlist::read_m3u(path) {
+            Ok(items) => items,
+            Err(e) => {
+                self.notifications
+                    .push(Notification::error(format!("Playlist import failed: {e}")));
+                return;
+            }
+        };
+        let mut queue = self.queue.items().to_vec();
+        let before = queue.len();
+        let added = transfer::append_new(&mut queue, items);
+        for item in queue.into_iter().skip(before) {
+            self.queue.add(item);
+        }
+        self.sync_play_controls();
+        self.sync_queue_to_now_playing();
+        self.persist_queue();
+        self.notifications.push(Notification::info(format!(
+            "Queued {} entries from {}",
+            added,
+            path.display()
+        )));
+    }
+}
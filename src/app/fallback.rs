@@ -0,0 +1,175 @@
+// Source fallback: retries items whose audio source keeps failing, switching
+// to alternate sources (episode pages, other relays) and remembering the one
+// that works. Classified failures like geo-blocks skip straight to the next.
+
+use crate::action::Action;
+use crate::api::models::DiscoveryItem;
+use crate::app::App;
+use crate::components::notifications::Notification;
+use crate::player::failure::PlaybackFailure;
+
+// Attempts per source before moving on to the next candidate.
+const ATTEMPTS_PER_SOURCE: u32 = 2;
+// Playback ending this far before the known duration counts as a failure.
+const PREMATURE_END_MARGIN_SECS: f64 = 30.0;
+// Seconds of uninterrupted playback before a source is considered reliable.
+const SOURCE_CONFIRM_SECS: f64 = 30.0;
+
+/// Fallback bookkeeping for the track currently being played.
+pub(crate) struct SourceFallback {
+    key: String,
+    candidates: Vec<String>,
+    index: usize,
+    failures: u32,
+    confirmed: bool,
+    /// Set while restarting after a failure, so the restart keeps this state.
+    retrying: bool,
+}
+
+impl App {
+    /// All URLs that can play an item. Live channels use the relays from the
+    /// `[nts]` config section.
+    pub(super) fn source_candidates(&self, item: &DiscoveryItem) -> Vec<String> {
+        match item {
+            DiscoveryItem::NtsLiveChannel { channel, .. } => self.config.nts.relays(*channel),
+            _ => item.source_candidates(),
+        }
+    }
+
+    /// The preferred URL for an item, or None if it isn't playable.
+    pub(super) fn playback_url(&self, item: &DiscoveryItem) -> Option<String> {
+        match item {
+            DiscoveryItem::NtsLiveChannel { .. } => self.source_candidates(item).into_iter().next(),
+            _ => item.playback_url(),
+        }
+    }
+
+    /// Pick the starting source for the current track, preferring the one
+    /// remembered in the database. Keeps existing state during a retry.
+    pub(super) fn prepare_source_fallback(&mut self, item: &DiscoveryItem) {
+        if let Some(fb) = self.source_fallback.as_mut() {
+            if fb.retrying {
+                fb.retrying = false;
+                return;
+            }
+        }
+
+        let mut candidates = self.source_candidates(item);
+        if candidates.len() < 2 {
+            self.source_fallback = None;
+            // Queued live entries may still hold a relay from an older config.
+            if let Some(url) = self.playback_url(item) {
+                self.queue.set_current_url(url);
+            }
+            return;
+        }
+        let key = item.favorite_key();
+        if let Ok(Some(preferred)) = self.db.preferred_source(&key) {
+            if let Some(pos) = candidates.iter().position(|c| *c == preferred) {
+                let url = candidates.remove(pos);
+                candidates.insert(0, url);
+            }
+        }
+        self.queue.set_current_url(candidates[0].clone());
+        self.source_fallback = Some(SourceFallback {
+            key,
+            candidates,
+            index: 0,
+            failures: 0,
+            confirmed: false,
+            retrying: false,
+        });
+    }
+
+    /// Called on PlaybackFinished. If the track ended prematurely, restart it
+    /// (on the next source after repeated failures, or right away when the
+    /// failure is tied to the source) and return true so the queue does not
+    /// advance. Without a fallback, a classified failure is just reported.
+    pub(super) async fn retry_failed_source(&mut self) -> anyhow::Result<bool> {
+        let failure = self.playback_failure.take();
+        let current_key = self.queue.current().map(|q| q.item.favorite_key());
+        let has_fallback = self
+            .source_fallback
+            .as_ref()
+            .is_some_and(|fb| current_key.as_deref() == Some(fb.key.as_str()));
+        if !has_fallback {
+            self.source_fallback = None;
+            if let Some(failure) = failure {
+                self.action_tx
+                    .send(Action::Notify(Notification::error(failure.message())))?;
+            }
+            return Ok(false);
+        }
+        let Some(fb) = self.source_fallback.as_mut() else {
+            return Ok(false);
+        };
+
+        // Without a known length, only an error from mpv says the end came
+        // early.
+        let pos = self.now_playing.position_secs();
+        let premature = match self.seek.duration_secs {
+            Some(d) => pos < d - PREMATURE_END_MARGIN_SECS,
+            None => failure.is_some(),
+        };
+        if !premature {
+            self.source_fallback = None;
+            return Ok(false);
+        }
+
+        fb.failures += 1;
+        if fb.failures >= ATTEMPTS_PER_SOURCE
+            || failure
+                .as_ref()
+                .is_some_and(PlaybackFailure::is_source_specific)
+        {
+            fb.index += 1;
+            fb.failures = 0;
+            fb.confirmed = false;
+        }
+        let Some(url) = fb.candidates.get(fb.index).cloned() else {
+            self.source_fallback = None;
+            let msg = match failure {
+                Some(f) => format!("{} — no other sources left", f.message()),
+                None => "All sources failed for this item".to_string(),
+            };
+            self.action_tx
+                .send(Action::Notify(Notification::error(msg)))?;
+            return Ok(false);
+        };
+        let switched = fb.failures == 0;
+        fb.retrying = true;
+
+        if switched {
+            let msg = match failure {
+                Some(f) => format!("{} — trying an alternate source", f.message()),
+                None => "Source keeps failing — trying an alternate source".to_string(),
+            };
+            self.action_tx
+                .send(Action::Notify(Notification::warn(msg)))?;
+        }
+        self.health.record_reconnect();
+        // The restart picks up where playback dropped (`queue_resume`).
+        self.save_resume_position();
+        self.queue.set_current_url(url);
+        self.seek.reset();
+        self.start_current_track().await?;
+        Ok(true)
+    }
+
+    /// Once a source has played long enough, remember it if it was a fallback.
+    pub(super) fn confirm_source(&mut self, pos: f64) {
+        let Some(fb) = self.source_fallback.as_mut() else {
+            return;
+        };
+        if fb.confirmed || pos < SOURCE_CONFIRM_SECS {
+            return;
+        }
+        fb.confirmed = true;
+        fb.failures = 0;
+        if fb.index > 0 {
+            if let Some(url) = fb.candidates.get(fb.index) {
+                let _ = self.db.set_preferred_source(&fb.key, url);
+            }
+        }
+    }
+}
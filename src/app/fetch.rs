@@ -1,12 +1,18 @@
 // Data fetching: spawns async tasks that load NTS live/picks/genre data.
 
+use std::collections::BTreeMap;
 use std::future::Future;
-use std::pin::Pin;
+use std::sync::Arc;
+use std::time::Duration;
 
 use crate::action::Action;
-use crate::api::genres::TOP_GENRES;
-use crate::api::models::DiscoveryItem;
+use crate::api::models::{unix_now, DiscoveryItem, ShowSlot};
+use crate::api::nts::{Fetched, PageFetcher};
+use crate::app::genres::Browse;
 use crate::app::App;
+use crate::components::format_age;
+use crate::components::notifications::Notification;
+use crate::components::nts::NtsSubTab;
 
 // NTS search API caps results at 12 per page (server limit).
 const SEARCH_PAGE_SIZE: u64 = 12;
@@ -14,47 +20,230 @@ const SEARCH_PAGE_SIZE: u64 = 12;
 const SEARCH_MAX_OFFSET: u64 = 240;
 // Send partial results to the UI after accumulating this many items.
 const SEARCH_BATCH_SIZE: usize = 48;
+// Failed pages tolerated before a search stops paging.
+const SEARCH_ERROR_BUDGET: usize = 3;
+// Past queries kept as search bar suggestions.
+pub(super) const SEARCH_HISTORY: usize = 50;
+// Snapshot name for the last Live response.
+const LIVE_SNAPSHOT: &str = "live";
+// Wait this long after a show's scheduled end before refetching Live, so the
+// API has moved on to the next show.
+const SHOW_CHANGE_GRACE_SECS: u64 = 15;
+// Retry interval while the API still lists a show past its end.
+const SHOW_CHANGE_RETRY_SECS: u64 = 30;
+
+/// Pages of the current search that failed to load, kept for a targeted retry.
+#[derive(Default)]
+pub(crate) struct SearchGaps {
+    fetch: Option<PageFetcher>,
+    /// Offsets of pages that failed.
+    failed: Vec<u64>,
+    /// Where paging stopped once the error budget ran out.
+    resume_from: Option<u64>,
+}
 
 impl App {
-    /// Spawn a background fetch task that sends the result (or an error) back as an action.
-    fn spawn_fetch<Fut>(&self, fut: Fut, on_ok: fn(Vec<DiscoveryItem>) -> Action)
-    where
-        Fut: Future<Output = anyhow::Result<Vec<DiscoveryItem>>> + Send + 'static,
+    /// Spawn a background fetch task that sends the result (or an error) back
+    /// as an action. Cached results served while offline go to `tab` marked stale.
+    pub(super) fn spawn_fetch<Fut>(
+        &self,
+        fut: Fut,
+        tab: NtsSubTab,
+        on_ok: fn(Vec<DiscoveryItem>) -> Action,
+    ) where
+        Fut: Future<Output = anyhow::Result<Fetched<Vec<DiscoveryItem>>>> + Send + 'static,
     {
         let tx = self.action_tx.clone();
         tokio::spawn(async move {
             match fut.await {
-                Ok(items) => tx.send(on_ok(items)).ok(),
-                Err(e) => tx.send(Action::ShowError(e.to_string())).ok(),
+                Ok(Fetched {
+                    value: items,
+                    stale: None,
+                }) => tx.send(on_ok(items)).ok(),
+                Ok(Fetched {
+                    value: items,
+                    stale: Some(age),
+                }) => tx.send(Action::StaleListLoaded { tab, items, age }).ok(),
+                Err(e) => tx
+                    .send(Action::FetchFailed {
+                        tab,
+                        error: e.to_string(),
+                    })
+                    .ok(),
             };
         });
     }
 
+    /// Fetch Live; fresh results also say when the next show starts.
     pub(super) fn spawn_fetch_live(&self) {
         let client = self.nts_client.clone();
-        self.spawn_fetch(
-            async move { client.fetch_live().await },
-            Action::NtsLiveLoaded,
-        );
+        let tx = self.action_tx.clone();
+        tokio::spawn(async move {
+            match client.fetch_live_listing().await {
+                Ok(Fetched {
+                    value: listing,
+                    stale: None,
+                }) => {
+                    tx.send(Action::NtsLiveLoaded(listing.channels)).ok();
+                    tx.send(Action::LiveScheduleLoaded {
+                        next_change: listing.next_change,
+                        slots: listing.slots,
+                    })
+                    .ok()
+                }
+                Ok(Fetched {
+                    value: listing,
+                    stale: Some(age),
+                }) => tx
+                    .send(Action::StaleListLoaded {
+                        tab: NtsSubTab::Live,
+                        items: listing.channels,
+                        age,
+                    })
+                    .ok(),
+                Err(e) => tx
+                    .send(Action::FetchFailed {
+                        tab: NtsSubTab::Live,
+                        error: e.to_string(),
+                    })
+                    .ok(),
+            };
+        });
+    }
+
+    /// Loading `tab` failed: say so, and remember it for `r`.
+    pub(super) fn fetch_failed(&mut self, tab: NtsSubTab, error: String) {
+        self.record_failed_fetch(tab);
+        self.notifications
+            .push(Notification::error(format!("{error} — press r to retry")));
+    }
+
+    fn record_failed_fetch(&mut self, tab: NtsSubTab) {
+        if !self.failed_fetches.contains(&tab) {
+            self.failed_fetches.push(tab);
+        }
+    }
+
+    /// `tab` loaded fresh data, so there's nothing to retry for it.
+    pub(super) fn fetch_succeeded(&mut self, tab: NtsSubTab) {
+        self.failed_fetches.retain(|failed| *failed != tab);
+    }
+
+    /// Run the loads that failed (or fell back to the cache) again. False
+    /// when none did.
+    pub(super) fn retry_failed_fetches(&mut self) -> anyhow::Result<bool> {
+        if self.failed_fetches.is_empty() {
+            return Ok(false);
+        }
+        for tab in std::mem::take(&mut self.failed_fetches) {
+            self.action_tx.send(load_action(tab))?;
+        }
+        Ok(true)
+    }
+
+    /// Tabs whose last load failed, oldest first.
+    #[allow(dead_code)] // used by integration tests
+    pub fn failed_fetches(&self) -> &[NtsSubTab] {
+        &self.failed_fetches
+    }
+
+    /// New show times from the live endpoint: schedule the next refetch and
+    /// show each channel's progress.
+    pub(super) fn live_schedule_loaded(
+        &mut self,
+        next_change: Option<u64>,
+        slots: BTreeMap<u8, ShowSlot>,
+    ) {
+        self.schedule_live_refresh(next_change);
+        self.discovery_list.set_live_slots(slots.clone());
+        self.now_playing.set_live_slots(slots);
+    }
+
+    /// Refetch Live shortly after `next_change`, when a show on air ends. A
+    /// time already past means the API hasn't caught up yet: try again soon.
+    fn schedule_live_refresh(&mut self, next_change: Option<u64>) {
+        let now = unix_now();
+        self.live_change_at = next_change.map(|at| {
+            if at > now {
+                at + SHOW_CHANGE_GRACE_SECS
+            } else {
+                now + SHOW_CHANGE_RETRY_SECS
+            }
+        });
+    }
+
+    /// Refetch Live once the scheduled show change is due.
+    pub(super) fn tick_live_schedule(&mut self) {
+        if self.live_change_at.is_some_and(|at| unix_now() >= at) {
+            self.live_change_at = None;
+            self.spawn_fetch_live();
+        }
+    }
+
+    /// When Live will next be refetched for a show change.
+    #[allow(dead_code)] // used by integration tests
+    pub fn live_refresh_due(&self) -> Option<u64> {
+        self.live_change_at
+    }
+
+    /// Until the first fetch returns, show the last Live response, marked
+    /// as cached. Only fills an empty Live list.
+    pub(super) fn show_cached_live(&mut self) {
+        if self.nts_tab.active_sub() != NtsSubTab::Live
+            || self.discovery_list.total_item_count() > 0
+        {
+            return;
+        }
+        let Ok(Some((items, age))) = self.db.snapshot(LIVE_SNAPSHOT) else {
+            return;
+        };
+        self.discovery_list.set_items(items);
+        self.discovery_list.set_heading(Some(format!(
+            "Cached from {}",
+            format_age(Duration::from_secs(age))
+        )));
+    }
+
+    /// Show a cached list the API couldn't refresh, with its age in the heading.
+    pub(super) fn show_stale_list(
+        &mut self,
+        tab: NtsSubTab,
+        items: Vec<DiscoveryItem>,
+        age: Duration,
+    ) {
+        self.record_failed_fetch(tab);
+        if self.nts_tab.active_sub() == tab {
+            self.discovery_list.set_items(items);
+            self.discovery_list.set_heading(Some(stale_heading(age)));
+        } else if let Some(list) = self.stashed_list(tab) {
+            list.replace_items(items);
+            list.set_heading(Some(stale_heading(age)));
+        }
+    }
+
+    /// Fresh Live data arrived: remember it for the next start.
+    pub(super) fn save_live_snapshot(&self, items: &[DiscoveryItem]) {
+        let _ = self.db.save_snapshot(LIVE_SNAPSHOT, items);
     }
 
     pub(super) fn spawn_fetch_picks(&self) {
         let client = self.nts_client.clone();
         self.spawn_fetch(
             async move { client.fetch_picks().await },
+            NtsSubTab::Picks,
             Action::NtsPicksLoaded,
         );
     }
 
+    /// Show the Search tab's picker: genres, or the moods or places being
+    /// browsed instead.
     pub(super) fn load_genres(&mut self) -> anyhow::Result<()> {
-        let mut items: Vec<DiscoveryItem> = Vec::with_capacity(TOP_GENRES.len());
-        for &(id, name) in TOP_GENRES {
-            items.push(DiscoveryItem::NtsGenre {
-                name: name.to_string(),
-                genre_id: id.to_string(),
-            });
+        match self.browse {
+            Browse::Genres => {}
+            Browse::Moods => return self.load_moods(),
+            Browse::Places => return self.load_places(),
         }
-
+        let items = self.current_genre_items();
         self.action_tx.send(Action::GenresLoaded(items))?;
         self.viewing_genre_results = false;
         Ok(())
@@ -63,75 +252,327 @@ impl App {
     pub(super) fn search_by_genre(&mut self, genre_id: String) -> anyhow::Result<()> {
         let client = self.nts_client.clone();
         self.viewing_genre_results = true;
-        self.spawn_paginated_search(move |offset, limit| {
+        self.start_paged_search(Arc::new(move |offset, limit| {
             let client = client.clone();
             let genre_id = genre_id.clone();
             Box::pin(async move { client.search_episodes(&genre_id, offset, limit).await })
-        })
+        }));
+        Ok(())
+    }
+
+    fn load_moods(&mut self) -> anyhow::Result<()> {
+        self.viewing_genre_results = false;
+        self.discovery_list.set_items(vec![]);
+        self.discovery_list.set_loading(true);
+        let client = self.nts_client.clone();
+        self.spawn_fetch(
+            async move { client.fetch_moods().await },
+            NtsSubTab::Search,
+            Action::MoodsLoaded,
+        );
+        Ok(())
+    }
+
+    pub(super) fn search_by_mood(&mut self, mood_id: String) -> anyhow::Result<()> {
+        let client = self.nts_client.clone();
+        self.viewing_genre_results = true;
+        self.start_paged_search(Arc::new(move |offset, limit| {
+            let client = client.clone();
+            let mood_id = mood_id.clone();
+            Box::pin(async move {
+                client
+                    .search_episodes_by_mood(&mood_id, offset, limit)
+                    .await
+            })
+        }));
+        Ok(())
+    }
+
+    fn load_places(&mut self) -> anyhow::Result<()> {
+        self.viewing_genre_results = false;
+        self.discovery_list.set_items(vec![]);
+        self.discovery_list.set_loading(true);
+        let client = self.nts_client.clone();
+        self.spawn_fetch(
+            async move { client.fetch_places().await },
+            NtsSubTab::Search,
+            Action::PlacesLoaded,
+        );
+        Ok(())
+    }
+
+    /// List the recent episodes broadcast from `place`. There is no search
+    /// filter for locations, so they're picked out of the pages the places
+    /// were counted from (usually still cached).
+    pub(super) fn search_by_place(&mut self, place: String) -> anyhow::Result<()> {
+        self.viewing_genre_results = true;
+        self.begin_search();
+        self.discovery_list
+            .set_heading(Some(format!("{place} · recent episodes")));
+        let sid = self.search_id;
+        let client = self.nts_client.clone();
+        let tx = self.action_tx.clone();
+        tokio::spawn(async move {
+            match client.fetch_place_episodes(&place).await {
+                Ok(Fetched {
+                    value: items,
+                    stale,
+                }) => {
+                    if let Some(age) = stale {
+                        tx.send(Action::SearchResultsStale {
+                            search_id: sid,
+                            age,
+                        })
+                        .ok();
+                    }
+                    tx.send(Action::SearchResultsPartial {
+                        search_id: sid,
+                        total: Some(items.len() as u64),
+                        items,
+                        done: true,
+                    })
+                    .ok()
+                }
+                Err(e) => tx
+                    .send(Action::FetchFailed {
+                        tab: NtsSubTab::Search,
+                        error: e.to_string(),
+                    })
+                    .ok(),
+            };
+        });
+        Ok(())
+    }
+
+    /// The moods or places arrived; show them unless the Search tab has
+    /// moved on to something else.
+    pub(super) fn browse_list_loaded(&mut self, browse: Browse, items: Vec<DiscoveryItem>) {
+        let heading = Some(match browse {
+            Browse::Places => "Places of the latest episodes".to_string(),
+            _ => "Moods".to_string(),
+        });
+        if self.browse != browse || self.viewing_genre_results || self.viewing_query_results {
+            // Left the list before it arrived.
+        } else if let Some(list) = self.stashed_list(NtsSubTab::Search) {
+            list.replace_items(items);
+            list.set_heading(heading);
+        } else if self.nts_tab.active_sub() == NtsSubTab::Search {
+            self.discovery_list.set_items(items);
+            self.discovery_list.set_heading(heading);
+        }
+    }
+
+    /// Switch the Search tab between listing genres and `browse` (moods or
+    /// places).
+    pub(super) fn toggle_browse(&mut self, browse: Browse) -> anyhow::Result<()> {
+        self.browse = if self.browse == browse {
+            Browse::Genres
+        } else {
+            browse
+        };
+        self.viewing_genre_results = false;
+        self.viewing_query_results = false;
+        self.forget_tab(NtsSubTab::Search);
+        if self.nts_tab.active_sub() == NtsSubTab::Search {
+            self.action_tx.send(Action::LoadGenres)?;
+        } else {
+            let index = NtsSubTab::ALL
+                .iter()
+                .position(|t| *t == NtsSubTab::Search)
+                .unwrap_or_default();
+            self.action_tx.send(Action::SwitchSubTab(index))?;
+        }
+        Ok(())
     }
 
     pub(super) fn search_by_query(&mut self, query: String) -> anyhow::Result<()> {
         let client = self.nts_client.clone();
         self.viewing_query_results = true;
-        self.spawn_paginated_search(move |offset, limit| {
+        self.start_paged_search(Arc::new(move |offset, limit| {
             let client = client.clone();
             let query = query.clone();
             Box::pin(async move { client.search_episodes_by_query(&query, offset, limit).await })
-        })
+        }));
+        Ok(())
     }
 
-    /// Shared paginated search: fetches pages in a background task, sending
-    /// partial results back to the UI as they accumulate.
-    fn spawn_paginated_search<F>(&mut self, make_page: F) -> anyhow::Result<()>
-    where
-        F: Fn(u64, u64) -> Pin<Box<dyn Future<Output = anyhow::Result<Vec<DiscoveryItem>>> + Send>>
-            + Send
-            + 'static,
-    {
+    /// Start a fresh paginated search, replacing the current results.
+    pub fn start_paged_search(&mut self, fetch: PageFetcher) {
+        self.begin_search();
+        self.search_gaps = SearchGaps {
+            fetch: Some(fetch.clone()),
+            ..SearchGaps::default()
+        };
+        self.spawn_search_pages(fetch, vec![], Some(0));
+    }
+
+    /// Start a new search: empty the list and drop the last search's state.
+    fn begin_search(&mut self) {
         self.search_id += 1;
-        let sid = self.search_id;
         self.discovery_list.set_items(vec![]);
         self.discovery_list.set_loading(true);
+        self.discovery_list.set_footer(None);
+        self.discovery_list.set_heading(None);
+        self.discovery_list.start_search_progress();
+        self.search_gaps = SearchGaps::default();
+        self.search_exclude.clear();
+    }
+
+    /// Whether the current search has pages that failed to load.
+    pub(super) fn has_search_gaps(&self) -> bool {
+        !self.search_gaps.failed.is_empty() || self.search_gaps.resume_from.is_some()
+    }
+
+    /// Record pages of the current search that failed, and say so under the
+    /// list. Ignored once the results have been navigated away from.
+    pub(super) fn record_search_gaps(&mut self, failed: Vec<u64>, resume_from: Option<u64>) {
+        if self.search_gaps.fetch.is_none() {
+            return;
+        }
+        self.search_gaps.failed.extend(failed);
+        self.search_gaps.resume_from = resume_from;
+        if !self.has_search_gaps() {
+            return;
+        }
+        let footer = Some("Some results failed to load — press r to fetch remaining".to_string());
+        if let Some(list) = self.stashed_list(NtsSubTab::Search) {
+            list.set_footer(footer);
+        } else {
+            self.discovery_list.set_footer(footer);
+        }
+    }
+
+    /// Some pages of the current search came from the cache: say how old.
+    pub(super) fn mark_search_stale(&mut self, age: Duration) {
+        let heading = Some(stale_heading(age));
+        if let Some(list) = self.stashed_list(NtsSubTab::Search) {
+            list.set_heading(heading);
+        } else {
+            self.discovery_list.set_heading(heading);
+        }
+    }
+
+    /// Fetch only the pages that failed (and, if the error budget ran out,
+    /// the rest of the search), appending to the current results.
+    pub(super) fn retry_search_gaps(&mut self) {
+        let Some(fetch) = self.search_gaps.fetch.clone() else {
+            return;
+        };
+        let failed = std::mem::take(&mut self.search_gaps.failed);
+        let resume_from = self.search_gaps.resume_from.take();
+        self.discovery_list.set_footer(None);
+        self.discovery_list.set_loading(true);
+        self.spawn_search_pages(fetch, failed, resume_from);
+    }
 
+    /// Fetch `pages` by offset, then paginate from `continue_from` until a
+    /// short page or SEARCH_MAX_OFFSET, sending results back in batches. Failed
+    /// pages are skipped and reported; after SEARCH_ERROR_BUDGET failures the
+    /// pagination stops and reports where it got to.
+    fn spawn_search_pages(&self, fetch: PageFetcher, pages: Vec<u64>, continue_from: Option<u64>) {
+        let sid = self.search_id;
         let tx = self.action_tx.clone();
         tokio::spawn(async move {
             let mut buf = Vec::new();
-            let mut offset = 0u64;
+            let mut failed = Vec::new();
+            let mut resume_from = None;
+            let mut total = None;
+            let mut sent_any = false;
+            let mut stale: Option<Duration> = None;
+
+            for offset in pages {
+                match fetch(offset, SEARCH_PAGE_SIZE).await {
+                    Ok(page) => {
+                        total = page.total.or(total);
+                        stale = stale.max(page.stale);
+                        buf.extend(page.items);
+                    }
+                    Err(_) => failed.push(offset),
+                }
+            }
 
+            let mut offset = continue_from.unwrap_or(SEARCH_MAX_OFFSET + 1);
+            let mut errors = 0;
             while offset <= SEARCH_MAX_OFFSET {
-                match make_page(offset, SEARCH_PAGE_SIZE).await {
-                    Ok(items) => {
-                        let got = items.len();
-                        buf.extend(items);
+                match fetch(offset, SEARCH_PAGE_SIZE).await {
+                    Ok(page) => {
+                        let got = page.items.len();
+                        total = page.total.or(total);
+                        stale = stale.max(page.stale);
+                        buf.extend(page.items);
                         if (got as u64) < SEARCH_PAGE_SIZE {
                             break;
                         }
                     }
-                    Err(_) => break,
+                    Err(_) => {
+                        failed.push(offset);
+                        errors += 1;
+                        if errors >= SEARCH_ERROR_BUDGET {
+                            let next = offset + SEARCH_PAGE_SIZE;
+                            resume_from = (next <= SEARCH_MAX_OFFSET).then_some(next);
+                            break;
+                        }
+                    }
                 }
                 offset += SEARCH_PAGE_SIZE;
 
-                if buf.len() >= SEARCH_BATCH_SIZE || offset > SEARCH_MAX_OFFSET {
-                    let batch = std::mem::take(&mut buf);
-                    let done = offset > SEARCH_MAX_OFFSET;
+                // The first page goes out at once so the progress line
+                // (and its total) shows up without waiting for a full batch.
+                if buf.len() >= SEARCH_BATCH_SIZE || !sent_any {
+                    sent_any = true;
                     tx.send(Action::SearchResultsPartial {
                         search_id: sid,
-                        items: batch,
-                        done,
+                        items: std::mem::take(&mut buf),
+                        total,
+                        done: false,
                     })
                     .ok();
                 }
             }
 
+            if !failed.is_empty() || resume_from.is_some() {
+                tx.send(Action::SearchPagesFailed {
+                    search_id: sid,
+                    failed,
+                    resume_from,
+                })
+                .ok();
+            }
+            if let Some(age) = stale {
+                tx.send(Action::SearchResultsStale {
+                    search_id: sid,
+                    age,
+                })
+                .ok();
+            }
             // Flush remaining
             tx.send(Action::SearchResultsPartial {
                 search_id: sid,
                 items: buf,
+                total,
                 done: true,
             })
             .ok();
         });
+    }
+}
 
-        Ok(())
+/// The action that loads `tab`'s list.
+pub(super) fn load_action(tab: NtsSubTab) -> Action {
+    match tab {
+        NtsSubTab::Live => Action::LoadNtsLive,
+        NtsSubTab::Picks => Action::LoadNtsPicks,
+        NtsSubTab::Search => Action::LoadGenres,
+        NtsSubTab::Following => Action::LoadFollowing,
+        NtsSubTab::Favorites => Action::LoadFavorites,
+        NtsSubTab::History => Action::LoadHistory,
+        NtsSubTab::Playlists => Action::LoadPlaylists,
+        NtsSubTab::ForYou => Action::LoadForYou,
+        NtsSubTab::Podcasts => Action::LoadPodcasts,
     }
 }
+
+/// List heading for results served from the cache while offline.
+fn stale_heading(age: Duration) -> String {
+    format!("Offline — cached {}", format_age(age))
+}
@@ -0,0 +1,232 @@
+// Focus: which part of the screen key presses go to. Overlays stack above
+// the list in a fixed order and the topmost open one takes every key; below
+// them the find prompt and the search bar take the keys they use and pass
+// the rest to the list bindings. A new overlay is a Modal variant plus its
+// place in Modal::STACK and a line in `modal_is_open` and `modal_mut`.
+
+use std::time::Duration;
+
+use crossterm::event::{KeyCode, KeyEvent};
+
+use crate::action::Action;
+use crate::app::App;
+use crate::components::Component;
+use crate::keymap::Command;
+
+/// How long typing has to pause before the list is filtered.
+const FILTER_DEBOUNCE: Duration = Duration::from_millis(150);
+
+/// Overlays that take every key while open.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Modal {
+    Confirm,
+    Logs,
+    ContextMenu,
+    Stats,
+    About,
+    Favorites,
+    History,
+    Playlists,
+    Tracklist,
+    Info,
+    FilePicker,
+    DirectPlay,
+    Seek,
+    Equalizer,
+}
+
+impl Modal {
+    /// Top of the stack first: when several are open, keys go to the first.
+    const STACK: [Modal; 14] = [
+        Self::Confirm,
+        Self::Logs,
+        Self::ContextMenu,
+        Self::Stats,
+        Self::About,
+        Self::Favorites,
+        Self::History,
+        Self::Playlists,
+        Self::Tracklist,
+        Self::Info,
+        Self::FilePicker,
+        Self::DirectPlay,
+        Self::Seek,
+        Self::Equalizer,
+    ];
+}
+
+/// Where the next key press goes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Focus {
+    /// The first-run walkthrough.
+    Onboarding,
+    /// Any key closes help; Enter replays onboarding and `a` opens About.
+    Help,
+    /// Any key closes the notification history.
+    NotificationHistory,
+    /// Any key leaves the fullscreen visualizer.
+    Fullscreen,
+    Modal(Modal),
+    /// The in-list find prompt; keys it doesn't use go to the list.
+    Find,
+    /// The search bar; keys it doesn't use go to the keymap.
+    Search,
+    /// List motions and the keymap.
+    List,
+}
+
+impl Focus {
+    /// Whether an overlay covers the list (mouse input is ignored then).
+    pub fn is_overlay(self) -> bool {
+        matches!(
+            self,
+            Self::Onboarding
+                | Self::Help
+                | Self::NotificationHistory
+                | Self::Fullscreen
+                | Self::Modal(_)
+        )
+    }
+}
+
+impl App {
+    /// What has focus right now.
+    pub fn focus(&self) -> Focus {
+        if self.onboarding.is_active() {
+            return Focus::Onboarding;
+        }
+        if self.now_playing.is_fullscreen() {
+            return Focus::Fullscreen;
+        }
+        if self.show_help {
+            return Focus::Help;
+        }
+        if self.notifications.is_history_visible() {
+            return Focus::NotificationHistory;
+        }
+        if let Some(modal) = Modal::STACK
+            .into_iter()
+            .find(|modal| self.modal_is_open(*modal))
+        {
+            return Focus::Modal(modal);
+        }
+        if self.discovery_list.is_finding() {
+            Focus::Find
+        } else if self.search_bar.is_focused() {
+            Focus::Search
+        } else {
+            Focus::List
+        }
+    }
+
+    fn modal_is_open(&self, modal: Modal) -> bool {
+        match modal {
+            Modal::Confirm => self.confirm_modal.is_visible(),
+            Modal::Logs => self.log_view.is_visible(),
+            Modal::ContextMenu => self.context_menu.is_visible(),
+            Modal::Stats => self.stats_view.is_visible(),
+            Modal::About => self.about_view.is_visible(),
+            Modal::Favorites => self.favorites_view.is_visible(),
+            Modal::History => self.history_view.is_visible(),
+            Modal::Playlists => self.playlists_view.is_visible(),
+            Modal::Tracklist => self.tracklist_view.is_visible(),
+            Modal::Info => self.info_view.is_visible(),
+            Modal::FilePicker => self.file_picker.is_visible(),
+            Modal::DirectPlay => self.direct_play_modal.is_visible(),
+            Modal::Seek => self.seek_modal.is_visible(),
+            Modal::Equalizer => self.equalizer_modal.is_visible(),
+        }
+    }
+
+    fn modal_mut(&mut self, modal: Modal) -> &mut dyn Component {
+        match modal {
+            Modal::Confirm => &mut self.confirm_modal,
+            Modal::Logs => &mut self.log_view,
+            Modal::ContextMenu => &mut self.context_menu,
+            Modal::Stats => &mut self.stats_view,
+            Modal::About => &mut self.about_view,
+            Modal::Favorites => &mut self.favorites_view,
+            Modal::History => &mut self.history_view,
+            Modal::Playlists => &mut self.playlists_view,
+            Modal::Tracklist => &mut self.tracklist_view,
+            Modal::Info => &mut self.info_view,
+            Modal::FilePicker => &mut self.file_picker,
+            Modal::DirectPlay => &mut self.direct_play_modal,
+            Modal::Seek => &mut self.seek_modal,
+            Modal::Equalizer => &mut self.equalizer_modal,
+        }
+    }
+
+    /// Send `key` to whatever has focus.
+    pub fn handle_key(&mut self, key: KeyEvent) -> anyhow::Result<()> {
+        match self.focus() {
+            Focus::Onboarding => {
+                self.onboarding.handle_key_event(key)?;
+            }
+            Focus::Help => {
+                self.action_tx.send(Action::HideHelp)?;
+                match key.code {
+                    KeyCode::Enter => self.action_tx.send(Action::ShowOnboarding)?,
+                    KeyCode::Char('a') => self.action_tx.send(Action::ShowAbout)?,
+                    _ => {}
+                }
+            }
+            Focus::NotificationHistory => {
+                self.action_tx.send(Action::HideNotificationHistory)?;
+            }
+            Focus::Fullscreen => self.now_playing.set_fullscreen(false),
+            Focus::Modal(modal) => {
+                self.modal_mut(modal).handle_key_event(key)?;
+            }
+            Focus::Find if self.discovery_list.handle_find_key(key) => {}
+            Focus::Find if self.search_bar.is_focused() => self.handle_search_key(key)?,
+            Focus::Find | Focus::List => self.handle_list_key(key)?,
+            Focus::Search => self.handle_search_key(key)?,
+        }
+        Ok(())
+    }
+
+    /// Keys for the list: motions first, then the keymap.
+    fn handle_list_key(&mut self, key: KeyEvent) -> anyhow::Result<()> {
+        if self.handle_motion_key(key)? {
+            return Ok(());
+        }
+        match self.keymap.resolve(&key) {
+            Some(command) => self.run_command(command),
+            None => Ok(()),
+        }
+    }
+
+    /// Keys while typing a search: tab switching and Back still work, typed
+    /// keys go to the search bar, and what it leaves (e.g. arrows) to the
+    /// keymap.
+    fn handle_search_key(&mut self, key: KeyEvent) -> anyhow::Result<()> {
+        let command = self.keymap.resolve(&key);
+        if let Some(command @ (Command::NextTab | Command::PrevTab | Command::Back)) = command {
+            return self.run_command(command);
+        }
+        let before = self.search_bar.input().to_string();
+        if self.search_bar.handle_key_event(key)? {
+            if self.search_bar.input() != before {
+                self.schedule_filter();
+            }
+            return Ok(());
+        }
+        match command {
+            Some(command) => self.run_command(command),
+            None => Ok(()),
+        }
+    }
+
+    /// Filter the list by the search bar's text once typing pauses. Every
+    /// key starts a timer; FilterList ignores the ones the input has moved
+    /// past.
+    fn schedule_filter(&self) {
+        let query = self.search_bar.input().to_string();
+        let tx = self.action_tx.clone();
+        tokio::spawn(async move {
+            tokio::time::sleep(FILTER_DEBOUNCE).await;
+            tx.send(Action::FilterList(query)).ok();
+        });
+    }
+}
@@ -0,0 +1,200 @@
+// Followed shows: periodically fetches each followed show's recent episodes,
+// tracks which ones aired since the user last opened the Following tab, and
+// says so when a check turns up new ones.
+
+use std::collections::HashSet;
+use std::time::Duration;
+
+use crate::action::Action;
+use crate::api::models::DiscoveryItem;
+use crate::app::App;
+use crate::components::notifications::Notification;
+use crate::components::nts::NtsSubTab;
+
+// Recent episodes fetched per followed show.
+const EPISODES_PER_SHOW: u64 = 12;
+// How often to re-check followed shows while the app is open.
+pub(super) const CHECK_INTERVAL_SECS: f64 = 30.0 * 60.0;
+// How long the "new episodes" notification stays up.
+const NEW_EPISODES_NOTICE: Duration = Duration::from_secs(8);
+
+/// Latest episodes of followed shows, and which of them are new.
+#[derive(Default)]
+pub(crate) struct FollowingState {
+    /// Recent episodes across all followed shows, newest first.
+    pub(crate) episodes: Vec<DiscoveryItem>,
+    /// favorite_key()s of episodes broadcast after the show was last seen.
+    pub(crate) new_keys: HashSet<String>,
+    /// Tick counter for the periodic check.
+    pub(crate) check_ticks: u32,
+}
+
+fn show_alias(item: &DiscoveryItem) -> Option<&str> {
+    match item {
+        DiscoveryItem::NtsEpisode { show_alias, .. } if !show_alias.is_empty() => Some(show_alias),
+        _ => None,
+    }
+}
+
+fn broadcast_date(item: &DiscoveryItem) -> Option<&str> {
+    match item {
+        DiscoveryItem::NtsEpisode { broadcast_date, .. } => broadcast_date.as_deref(),
+        _ => None,
+    }
+}
+
+impl App {
+    /// Follow the show an episode belongs to, or unfollow it if already followed.
+    pub(super) fn toggle_follow(&mut self, item: &DiscoveryItem) -> anyhow::Result<()> {
+        let Some(alias) = show_alias(item) else {
+            return Ok(());
+        };
+        if self.db.follow_show(alias, item.title())? {
+            self.notifications
+                .push(Notification::info(format!("Following {}", item.title())));
+            self.action_tx.send(Action::CheckFollowedShows)?;
+        } else {
+            self.db.unfollow_show(alias)?;
+            self.notifications
+                .push(Notification::info(format!("Unfollowed {}", item.title())));
+            self.following
+                .episodes
+                .retain(|ep| show_alias(ep) != Some(alias));
+            let remaining: HashSet<String> = self
+                .following
+                .episodes
+                .iter()
+                .map(DiscoveryItem::favorite_key)
+                .collect();
+            self.following.new_keys.retain(|k| remaining.contains(k));
+            if self.nts_tab.active_sub() == NtsSubTab::Following {
+                self.show_following();
+            } else {
+                self.forget_tab(NtsSubTab::Following);
+            }
+        }
+        self.sync_following();
+        Ok(())
+    }
+
+    /// Fetch recent episodes of every followed show in the background.
+    pub(super) fn spawn_check_followed(&self) -> anyhow::Result<()> {
+        let aliases: Vec<String> = self
+            .db
+            .followed_shows()?
+            .into_iter()
+            .map(|show| show.show_alias)
+            .collect();
+        if aliases.is_empty() {
+            self.action_tx
+                .send(Action::FollowedEpisodesLoaded(vec![]))?;
+            return Ok(());
+        }
+        let client = self.nts_client.clone();
+        let tx = self.action_tx.clone();
+        tokio::spawn(async move {
+            let mut results = Vec::with_capacity(aliases.len());
+            for alias in aliases {
+                // A show that fails to load keeps its previous episodes.
+                if let Ok(episodes) = client.fetch_show_episodes(&alias, EPISODES_PER_SHOW).await {
+                    results.push((alias, episodes));
+                }
+            }
+            tx.send(Action::FollowedEpisodesLoaded(results)).ok();
+        });
+        Ok(())
+    }
+
+    /// Merge freshly fetched episodes and work out which are new, with a
+    /// notification for any not reported before. The first check after
+    /// following a show only records where "new" starts.
+    pub(super) fn apply_followed_episodes(
+        &mut self,
+        results: Vec<(String, Vec<DiscoveryItem>)>,
+    ) -> anyhow::Result<()> {
+        let shows = self.db.followed_shows()?;
+        let mut fresh: Vec<String> = Vec::new();
+        for (alias, episodes) in results {
+            let Some(show) = shows.iter().find(|s| s.show_alias == alias) else {
+                continue; // unfollowed while the fetch was in flight
+            };
+            let newest = episodes.iter().filter_map(broadcast_date).max();
+            match (&show.seen_until, newest) {
+                (None, Some(newest)) => self.db.set_show_seen_until(&alias, newest)?,
+                (Some(seen), _) => {
+                    for ep in &episodes {
+                        if broadcast_date(ep).is_some_and(|d| d > seen.as_str())
+                            && self.following.new_keys.insert(ep.favorite_key())
+                        {
+                            fresh.push(ep.title().to_string());
+                        }
+                    }
+                }
+                (None, None) => {}
+            }
+            self.following
+                .episodes
+                .retain(|ep| show_alias(ep) != Some(alias.as_str()));
+            self.following.episodes.extend(episodes);
+        }
+        // Newest first; undated episodes last.
+        self.following
+            .episodes
+            .sort_by(|a, b| broadcast_date(b).cmp(&broadcast_date(a)));
+
+        let message = match fresh.as_slice() {
+            [] => None,
+            [title] => Some(format!("New episode: {title}")),
+            several => Some(format!(
+                "{} new episodes from followed shows",
+                several.len()
+            )),
+        };
+        if let Some(message) = message {
+            self.notifications
+                .push(Notification::info(message).with_ttl(NEW_EPISODES_NOTICE));
+        }
+
+        if self.nts_tab.active_sub() == NtsSubTab::Following {
+            self.show_following();
+        } else {
+            self.forget_tab(NtsSubTab::Following);
+        }
+        self.sync_following();
+        Ok(())
+    }
+
+    /// Display the followed episodes and mark them as seen. The NEW markers
+    /// stay until the list is next rebuilt; the tab badge clears right away.
+    pub(super) fn show_following(&mut self) {
+        self.discovery_list
+            .set_items(self.following.episodes.clone());
+        self.discovery_list
+            .set_fresh(std::mem::take(&mut self.following.new_keys));
+        for show in self.db.followed_shows().unwrap_or_default() {
+            let newest = self
+                .following
+                .episodes
+                .iter()
+                .filter(|ep| show_alias(ep) == Some(show.show_alias.as_str()))
+                .filter_map(broadcast_date)
+                .max();
+            if let Some(newest) = newest {
+                if show.seen_until.as_deref().is_none_or(|seen| newest > seen) {
+                    let _ = self.db.set_show_seen_until(&show.show_alias, newest);
+                }
+            }
+        }
+        self.sync_following();
+    }
+
+    /// Refresh the tab badge and the followed-show markers in the list.
+    pub(super) fn sync_following(&mut self) {
+        self.nts_tab
+            .set_following_badge(self.following.new_keys.len());
+        if let Ok(shows) = self.db.followed_shows() {
+            self.discovery_list
+                .set_followed_shows(shows.into_iter().map(|s| s.show_alias).collect());
+        }
+    }
+}
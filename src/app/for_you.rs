@@ -0,0 +1,133 @@
+// For You tab: episodes from the most listened genres and shows that haven't
+// been played yet, mixed so no single source fills the top of the list.
+
+use std::collections::HashSet;
+
+use crate::action::Action;
+use crate::api::genres::genre_categories;
+use crate::api::models::DiscoveryItem;
+use crate::api::nts::Fetched;
+use crate::app::App;
+use crate::components::nts::NtsSubTab;
+use crate::recommend::{genre_id_by_name, taste};
+
+// Plays the listening profile is built from.
+const HISTORY_LOOKBACK: usize = 500;
+// Genres and shows episodes are fetched from.
+const TOP_GENRES: usize = 3;
+const TOP_SHOWS: usize = 2;
+// Episodes fetched per genre or show.
+const EPISODES_PER_SOURCE: u64 = 12;
+
+/// Take one item from each list in turn, skipping repeats and `exclude`.
+fn interleave(lists: Vec<Vec<DiscoveryItem>>, exclude: &HashSet<String>) -> Vec<DiscoveryItem> {
+    let mut seen = exclude.clone();
+    let mut lists: Vec<_> = lists.into_iter().map(Vec::into_iter).collect();
+    let mut items = Vec::new();
+    loop {
+        let mut any = false;
+        for list in &mut lists {
+            if let Some(item) = list.next() {
+                any = true;
+                if seen.insert(item.favorite_key()) {
+                    items.push(item);
+                }
+            }
+        }
+        if !any {
+            return items;
+        }
+    }
+}
+
+impl App {
+    /// Work out the top genres and shows and fetch unplayed episodes of them.
+    pub(super) fn load_for_you(&mut self) -> anyhow::Result<()> {
+        let history = self.db.history(HISTORY_LOOKBACK)?;
+        let taste = taste(&history, &self.db.favorites()?);
+        let fallback;
+        let categories = match &self.genre_tree {
+            Some(tree) => tree,
+            None => {
+                fallback = genre_categories();
+                &fallback
+            }
+        };
+        let genres: Vec<(String, String)> = taste
+            .genres
+            .iter()
+            .filter_map(|name| {
+                let id = genre_id_by_name(categories, name)?;
+                Some((id.to_string(), name.clone()))
+            })
+            .take(TOP_GENRES)
+            .collect();
+        let shows: Vec<String> = taste.shows.into_iter().take(TOP_SHOWS).collect();
+        self.for_you_basis = genres.iter().map(|(_, name)| name.clone()).collect();
+        if genres.is_empty() && shows.is_empty() {
+            self.action_tx.send(Action::ForYouLoaded(vec![]))?;
+            return Ok(());
+        }
+        match shows.len() {
+            0 => {}
+            1 => self.for_you_basis.push("a show you play".to_string()),
+            n => self.for_you_basis.push(format!("{n} shows you play")),
+        }
+
+        let played: HashSet<String> = history.into_iter().map(|entry| entry.key).collect();
+        let client = self.nts_client.clone();
+        self.spawn_fetch(
+            async move {
+                let mut lists = Vec::new();
+                let mut stale = None;
+                let mut error = None;
+                for (genre_id, _) in &genres {
+                    match client
+                        .search_episodes(genre_id, 0, EPISODES_PER_SOURCE)
+                        .await
+                    {
+                        Ok(page) => {
+                            stale = stale.max(page.stale);
+                            lists.push(page.items);
+                        }
+                        Err(e) => error = Some(e),
+                    }
+                }
+                for alias in &shows {
+                    match client.fetch_show_episodes(alias, EPISODES_PER_SOURCE).await {
+                        Ok(episodes) => lists.push(episodes),
+                        Err(e) => error = Some(e),
+                    }
+                }
+                // Whatever loaded is shown; only a complete failure is one.
+                match error {
+                    Some(e) if lists.is_empty() => Err(e),
+                    _ => Ok(Fetched {
+                        value: interleave(lists, &played),
+                        stale,
+                    }),
+                }
+            },
+            NtsSubTab::ForYou,
+            Action::ForYouLoaded,
+        );
+        Ok(())
+    }
+
+    /// Show the recommendations, headed by what they're based on.
+    pub(super) fn for_you_loaded(&mut self, items: Vec<DiscoveryItem>) {
+        self.fetch_succeeded(NtsSubTab::ForYou);
+        let heading = match self.for_you_basis.as_slice() {
+            [] => None,
+            [only] => Some(format!("Based on {only}")),
+            [rest @ .., last] => Some(format!("Based on {} and {last}", rest.join(", "))),
+        };
+        if self.nts_tab.active_sub() == NtsSubTab::ForYou {
+            self.discovery_list.set_items(items);
+            self.discovery_list.set_heading(heading);
+        } else if let Some(list) = self.stashed_list(NtsSubTab::ForYou) {
+            list.replace_items(items);
+            list.set_heading(heading);
+        }
+    }
+}
@@ -0,0 +1,105 @@
+// Frame pacing: draw only when something changed, and tick slowly while
+// idle. Any action other than Tick, and any key, mouse, or resize event,
+// marks the screen dirty; a Tick does only while a component animates (see
+// Component::is_animating) or shortly after the last change, so fades and
+// spinners can finish. Ticks run at the configured frame rate while playing
+// or animating and drop to IDLE_FRAME_RATE otherwise. Tick counters measure
+// time with `tick_rate()` so they keep working at either rate.
+
+use std::time::{Duration, Instant};
+
+use crate::action::Action;
+use crate::app::registry::ComponentId;
+use crate::app::App;
+
+/// Ticks per second while nothing plays or moves.
+pub(crate) const IDLE_FRAME_RATE: f64 = 2.0;
+// Full-rate ticks after the last change, for animations that wind down.
+const SETTLE: Duration = Duration::from_secs(2);
+// Redraw at least this often, for clocks and countdowns on screen.
+const HEARTBEAT: Duration = Duration::from_secs(1);
+
+/// Whether the next frame needs drawing, and how fast ticks come.
+pub(crate) struct FrameState {
+    dirty: bool,
+    last_draw: Option<Instant>,
+    /// Until when to keep ticking at full rate after a change.
+    settle_until: Instant,
+    /// Ticks per second right now.
+    tick_rate: f64,
+}
+
+impl FrameState {
+    pub(crate) fn new(frame_rate: f64) -> Self {
+        Self {
+            dirty: true,
+            last_draw: None,
+            settle_until: Instant::now() + SETTLE,
+            tick_rate: frame_rate,
+        }
+    }
+}
+
+impl App {
+    /// Ticks per second right now; tick counters use it to turn seconds
+    /// into ticks.
+    pub(crate) fn tick_rate(&self) -> f64 {
+        self.frames.tick_rate
+    }
+
+    /// Something on screen may have changed.
+    pub(super) fn mark_dirty(&mut self) {
+        self.frames.dirty = true;
+        self.frames.settle_until = Instant::now() + SETTLE;
+    }
+
+    /// Called for every action before it is handled.
+    pub(super) fn frame_action(&mut self, action: &Action) {
+        if !matches!(action, Action::Tick) {
+            self.mark_dirty();
+        } else if Instant::now() < self.frames.settle_until || self.any_animating() {
+            self.frames.dirty = true;
+        }
+    }
+
+    /// Whether any component changes on every tick right now.
+    fn any_animating(&mut self) -> bool {
+        ComponentId::ALL
+            .into_iter()
+            .any(|id| self.component_mut(id).is_animating())
+    }
+
+    /// Whether the next frame needs drawing.
+    pub fn needs_draw(&self) -> bool {
+        self.frames.dirty
+            || self
+                .frames
+                .last_draw
+                .is_none_or(|at| at.elapsed() >= HEARTBEAT)
+    }
+
+    /// A frame was just drawn.
+    pub fn frame_drawn(&mut self) {
+        self.frames.dirty = false;
+        self.frames.last_draw = Some(Instant::now());
+    }
+
+    /// The tick rate for the current state: full while playing, animating,
+    /// or settling after a change; IDLE_FRAME_RATE otherwise.
+    pub fn wanted_tick_rate(&mut self) -> f64 {
+        let frame_rate = self.config.general.frame_rate;
+        let busy = self.now_playing.is_playing()
+            || Instant::now() < self.frames.settle_until
+            || self.any_animating();
+        if busy {
+            frame_rate
+        } else {
+            IDLE_FRAME_RATE.min(frame_rate)
+        }
+    }
+
+    /// Switch to `rate` ticks per second.
+    pub(super) fn set_tick_rate(&mut self, rate: f64) {
+        self.frames.tick_rate = rate;
+    }
+}
@@ -0,0 +1,207 @@
+// Genre picker on the Search tab (which can list moods or places instead): the
+// genre tree from the API (or the static fallback until it arrives), flat with sub-genres under their parents, or
+// grouped under top-level genres with each group folded until opened.
+// Episode counts are fetched for the rows on screen as they come into view.
+
+use std::collections::HashSet;
+
+use crate::action::Action;
+use crate::api::genres::{category_id, genre_categories, GenreCategory};
+use crate::api::models::DiscoveryItem;
+use crate::app::App;
+use crate::components::nts::NtsSubTab;
+
+/// What the Search tab lists while no search results are shown.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub(crate) enum Browse {
+    #[default]
+    Genres,
+    Moods,
+    /// Cities the newest episodes were broadcast from.
+    Places,
+}
+
+// Episode-count requests started per tick, so a long list fills in gradually.
+const COUNT_REQUESTS_PER_TICK: usize = 2;
+
+fn genre_item(id: &str, name: &str) -> DiscoveryItem {
+    DiscoveryItem::NtsGenre {
+        name: name.to_string(),
+        genre_id: id.to_string(),
+    }
+}
+
+fn category_header(id: &str, name: &str, count: usize, expanded: bool) -> DiscoveryItem {
+    DiscoveryItem::NtsGenreCategory {
+        name: name.to_string(),
+        category_id: id.to_string(),
+        count,
+        expanded,
+    }
+}
+
+/// The genre list rows: every genre with its sub-genres after it, or a
+/// header per group followed by the group's genres when it's in `expanded`.
+pub(super) fn genre_items(
+    categories: &[GenreCategory],
+    grouped: bool,
+    expanded: &HashSet<String>,
+) -> Vec<DiscoveryItem> {
+    let mut items = Vec::new();
+    for category in categories {
+        let open = !grouped || expanded.contains(&category.id);
+        if grouped {
+            items.push(category_header(
+                &category.id,
+                &category.name,
+                category.genres.len() + 1,
+                open,
+            ));
+        }
+        if open {
+            items.push(genre_item(&category.id, &category.name));
+            items.extend(
+                category
+                    .genres
+                    .iter()
+                    .map(|(id, name)| genre_item(id, name)),
+            );
+        }
+    }
+    items
+}
+
+impl App {
+    /// Whether the list shows the genre picker (not a genre's episodes).
+    fn is_showing_genres(&self) -> bool {
+        self.nts_tab.active_sub() == NtsSubTab::Search
+            && !self.viewing_genre_results
+            && !self.viewing_query_results
+    }
+
+    fn genres_grouped(&self) -> bool {
+        self.config.nts.group_genres.unwrap_or(false)
+    }
+
+    /// Rows for the genre picker, fetching the genre tree the first time.
+    pub(super) fn current_genre_items(&mut self) -> Vec<DiscoveryItem> {
+        if self.genre_tree.is_none() && !self.genre_tree_requested {
+            self.genre_tree_requested = true;
+            self.spawn_fetch_genres();
+        }
+        let fallback;
+        let categories = match &self.genre_tree {
+            Some(tree) => tree,
+            None => {
+                fallback = genre_categories();
+                &fallback
+            }
+        };
+        genre_items(categories, self.genres_grouped(), &self.expanded_genres)
+    }
+
+    fn spawn_fetch_genres(&self) {
+        let client = self.nts_client.clone();
+        let tx = self.action_tx.clone();
+        tokio::spawn(async move {
+            match client.fetch_genres().await {
+                Ok(fetched) => tx.send(Action::GenreTreeLoaded(fetched.value)).ok(),
+                // The built-in list stays up; it's only out of date.
+                Err(e) => {
+                    tracing::warn!("couldn't fetch the genre tree: {e}");
+                    None
+                }
+            };
+        });
+    }
+
+    /// The fetched genre tree replaces the built-in list.
+    pub(super) fn genre_tree_loaded(&mut self, tree: Vec<GenreCategory>) {
+        self.genre_tree = Some(tree);
+        if !self.viewing_genre_results && !self.viewing_query_results {
+            self.forget_tab(NtsSubTab::Search);
+        }
+        let keys: Vec<String> = self
+            .discovery_list
+            .selected_item()
+            .map(DiscoveryItem::favorite_key)
+            .into_iter()
+            .collect();
+        self.refresh_genres(&keys);
+    }
+
+    /// Rebuild the shown genre picker, selecting the first of `keys` that
+    /// is still listed.
+    fn refresh_genres(&mut self, keys: &[String]) {
+        if !self.is_showing_genres() {
+            return;
+        }
+        let items = self.current_genre_items();
+        self.discovery_list.set_items(items);
+        for key in keys {
+            if self.discovery_list.select_key(key) {
+                break;
+            }
+        }
+    }
+
+    /// Fold or unfold a group of the grouped genre list.
+    pub(super) fn toggle_genre_category(&mut self, category_id: String) {
+        if !self.expanded_genres.remove(&category_id) {
+            self.expanded_genres.insert(category_id.clone());
+        }
+        let header = category_header(&category_id, "", 0, false);
+        self.refresh_genres(&[header.favorite_key()]);
+    }
+
+    /// Switch the genre list between flat and grouped, keeping the selected
+    /// genre (or its group, when folded) in view. Saved to the config.
+    pub(super) fn toggle_genre_groups(&mut self) {
+        self.config.nts.group_genres = Some(!self.genres_grouped());
+        self.save_config_async();
+        let mut keys = Vec::new();
+        match self.discovery_list.selected_item() {
+            Some(DiscoveryItem::NtsGenre { genre_id, .. }) => {
+                let header = category_header(category_id(genre_id), "", 0, false);
+                keys.push(genre_item(genre_id, "").favorite_key());
+                keys.push(header.favorite_key());
+            }
+            Some(DiscoveryItem::NtsGenreCategory { category_id, .. }) => {
+                keys.push(genre_item(category_id, "").favorite_key());
+            }
+            _ => {}
+        }
+        self.refresh_genres(&keys);
+    }
+
+    /// Called every tick: ask for the episode counts of genres on screen
+    /// that don't have one yet, a few at a time.
+    pub(super) fn request_genre_counts(&mut self) {
+        if !self.is_showing_genres() {
+            return;
+        }
+        let wanted: Vec<String> = self
+            .discovery_list
+            .rows_on_screen()
+            .iter()
+            .filter_map(|item| match item {
+                DiscoveryItem::NtsGenre { genre_id, .. } => Some(genre_id.clone()),
+                DiscoveryItem::NtsGenreCategory { category_id, .. } => Some(category_id.clone()),
+                _ => None,
+            })
+            .filter(|id| !self.genre_counts_requested.contains(id))
+            .take(COUNT_REQUESTS_PER_TICK)
+            .collect();
+        for genre_id in wanted {
+            self.genre_counts_requested.insert(genre_id.clone());
+            let client = self.nts_client.clone();
+            let tx = self.action_tx.clone();
+            tokio::spawn(async move {
+                // A count that can't be fetched is left off the row.
+                if let Ok(Some(count)) = client.fetch_genre_count(&genre_id).await {
+                    tx.send(Action::GenreCountLoaded { genre_id, count }).ok();
+                }
+            });
+        }
+    }
+}
@@ -0,0 +1,28 @@
+// Playback health glue: feeds cache levels and reconnects into the health log
+// and writes a sample every few seconds while something plays.
+
+use crate::app::App;
+use crate::health::SAMPLE_INTERVAL_SECS;
+
+impl App {
+    /// Called every tick; writes a sample once per interval while playing.
+    pub(super) fn tick_health(&mut self) {
+        if !self.health.is_enabled() {
+            return;
+        }
+        self.health_sample_ticks += 1;
+        let interval = (self.tick_rate() * SAMPLE_INTERVAL_SECS) as u32;
+        if interval == 0 || self.health_sample_ticks < interval {
+            return;
+        }
+        self.health_sample_ticks = 0;
+        if self.now_playing.is_playing() {
+            let title = self.playing_title();
+            self.health.sample(
+                title,
+                self.now_playing.position_secs(),
+                self.now_playing.is_paused(),
+            );
+        }
+    }
+}
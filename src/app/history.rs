@@ -0,0 +1,90 @@
+// Play history: records a row when playback starts, counts the time actually
+// listened while it plays, and stores that duration when playback ends.
+
+use crate::app::App;
+use crate::components::nts::NtsSubTab;
+
+// Most items listed in the history view.
+pub(super) const HISTORY_VIEW_LIMIT: usize = 500;
+
+/// The play currently being recorded.
+pub(crate) struct OpenPlay {
+    id: i64,
+    key: String,
+    listened_secs: f64,
+}
+
+impl App {
+    /// Start recording the current track. Restarts of the same item (source
+    /// fallback, reconnects) continue the open play instead of adding one.
+    pub(super) fn begin_history_entry(&mut self) {
+        let Some(item) = self.queue.current().map(|t| t.item.clone()) else {
+            return;
+        };
+        let key = item.favorite_key();
+        if self.open_play.as_ref().is_some_and(|p| p.key == key) {
+            return;
+        }
+        self.finish_history_entry();
+        self.note_podcast_played(&item);
+        if let Ok(id) = self.db.record_play(&item) {
+            self.open_play = Some(OpenPlay {
+                id,
+                key,
+                listened_secs: 0.0,
+            });
+            let _ = self.sync_history();
+        }
+    }
+
+    /// Count one tick of listening time while audio is actually playing.
+    pub(super) fn tick_history_entry(&mut self) {
+        if !self.now_playing.is_playing() || self.now_playing.is_paused() {
+            return;
+        }
+        let tick_secs = 1.0 / self.tick_rate();
+        if let Some(play) = self.open_play.as_mut() {
+            play.listened_secs += tick_secs;
+        }
+    }
+
+    /// Store the listened duration of the open play, if any.
+    pub(super) fn finish_history_entry(&mut self) {
+        if let Some(play) = self.open_play.take() {
+            let _ = self.db.set_play_duration(play.id, play.listened_secs);
+        }
+    }
+
+    pub(super) fn show_history(&mut self) -> anyhow::Result<()> {
+        self.history_view.show(self.db.history(HISTORY_VIEW_LIMIT)?);
+        Ok(())
+    }
+
+    /// List recently played items on the History tab, most recent first.
+    pub(super) fn show_history_tab(&mut self) -> anyhow::Result<()> {
+        let entries = self.db.history(HISTORY_VIEW_LIMIT)?;
+        self.nts_tab
+            .set_count(NtsSubTab::History, self.db.history_count()?);
+        self.discovery_list
+            .set_items(entries.into_iter().map(|e| e.item).collect());
+        Ok(())
+    }
+
+    /// Refresh the History tab count, and the overlay and tab when open.
+    pub(super) fn sync_history(&mut self) -> anyhow::Result<()> {
+        self.nts_tab
+            .set_count(NtsSubTab::History, self.db.history_count()?);
+        if self.history_view.is_visible() {
+            self.history_view
+                .set_entries(self.db.history(HISTORY_VIEW_LIMIT)?);
+        }
+        if self.nts_tab.active_sub() == NtsSubTab::History {
+            self.show_history_tab()?;
+        } else {
+            self.forget_tab(NtsSubTab::History);
+        }
+        // Recommendations are worked out again on the next visit.
+        self.forget_tab(NtsSubTab::ForYou);
+        Ok(())
+    }
+}
@@ -0,0 +1,46 @@
+// Hook glue: runs the `[hooks]` commands as tracks start, end, and change
+// their stream metadata.
+
+use crate::app::App;
+use crate::hooks::{self, HookEvent, HookTrack};
+use crate::player::StreamMetadata;
+
+impl App {
+    /// Run `track_started` for the current track, first closing out the one
+    /// it replaced if that never finished (skipped or changed mid-play).
+    /// Restarts of the same item on another source don't count.
+    pub(super) fn hook_track_started(&mut self) {
+        let Some(current) = self.queue.current() else {
+            return;
+        };
+        let key = current.item.favorite_key();
+        if self.hook_track.as_ref().is_some_and(|(k, _)| *k == key) {
+            return;
+        }
+        let track = HookTrack::new(&current.item, &current.url, None);
+        self.hook_track_finished();
+        hooks::run(&self.config.hooks, HookEvent::TrackStarted, &track);
+        self.hook_track = Some((key, track));
+    }
+
+    /// Run `track_finished` for the track `track_started` last ran for.
+    pub(super) fn hook_track_finished(&mut self) {
+        if let Some((_, track)) = self.hook_track.take() {
+            hooks::run(&self.config.hooks, HookEvent::TrackFinished, &track);
+        }
+    }
+
+    /// Run `metadata_changed` when the stream's song differs from the
+    /// current track's last known metadata.
+    pub(super) fn hook_metadata_changed(&self, metadata: &StreamMetadata) {
+        let Some(track) = self.queue.current() else {
+            return;
+        };
+        let previous = track.stream_metadata.as_ref();
+        if previous.is_some_and(|p| p.title == metadata.title && p.artist == metadata.artist) {
+            return;
+        }
+        let track = HookTrack::new(&track.item, &track.url, Some(metadata));
+        hooks::run(&self.config.hooks, HookEvent::MetadataChanged, &track);
+    }
+}
@@ -0,0 +1,58 @@
+// Info overlay: opens with what the item itself knows and fills in the
+// description and the rest from the NTS API.
+
+use std::future::Future;
+use std::pin::Pin;
+
+use crate::action::Action;
+use crate::api::models::{DiscoveryItem, ItemInfo, NtsEpisodeDetail};
+use crate::app::App;
+
+pub(super) type DetailFetch = Pin<Box<dyn Future<Output = Option<NtsEpisodeDetail>> + Send>>;
+
+impl App {
+    pub(super) fn show_info(&mut self, item: &DiscoveryItem) {
+        let key = item.favorite_key();
+        let info = ItemInfo::from_item(item);
+        let fetch = self.detail_fetch(item);
+        self.info_view
+            .show(key.clone(), info.clone(), fetch.is_some());
+        let Some(fetch) = fetch else {
+            return;
+        };
+        let tx = self.action_tx.clone();
+        // Without details, the overlay keeps what it has and stops loading.
+        tokio::spawn(async move {
+            let mut info = info;
+            if let Some(detail) = fetch.await {
+                info.merge_detail(detail);
+            }
+            let _ = tx.send(Action::ItemInfoLoaded { key, info });
+        });
+    }
+
+    /// Fetch of the API's details on an episode or a live channel's show;
+    /// None for items it has nothing on.
+    pub(super) fn detail_fetch(&self, item: &DiscoveryItem) -> Option<DetailFetch> {
+        let client = self.nts_client.clone();
+        match item {
+            DiscoveryItem::NtsEpisode {
+                show_alias,
+                episode_alias,
+                ..
+            } if !show_alias.is_empty() && !episode_alias.is_empty() => {
+                let (show, episode) = (show_alias.clone(), episode_alias.clone());
+                Some(Box::pin(async move {
+                    client.fetch_episode(&show, &episode).await.ok()
+                }))
+            }
+            DiscoveryItem::NtsLiveChannel { channel, .. } => {
+                let channel = *channel;
+                Some(Box::pin(async move {
+                    client.fetch_live_details(channel).await.ok().flatten()
+                }))
+            }
+            _ => None,
+        }
+    }
+}
@@ -1,119 +1,367 @@
-// Key event handling: maps key presses to actions.
+// Key and mouse handling: resolves key presses through the keymap and turns the
+// resulting commands into actions. Which keys get here is decided in focus.rs.
 
 use crate::action::Action;
+use crate::api::models::DiscoveryItem;
 use crate::app::App;
-use crate::components::Component;
-use crossterm::event::{KeyCode, KeyEvent};
+use crate::components::context_menu::MenuEntry;
+use crate::components::file_picker::PickPurpose;
+use crate::components::notifications::NotifyLevel;
+use crate::components::nts::NtsSubTab;
+use crate::config::SPLIT_STEP;
+use crate::keymap::Command;
+use crossterm::event::MouseEvent;
+use std::time::Instant;
 
-impl App {
-    pub fn handle_key(&mut self, key: KeyEvent) -> anyhow::Result<()> {
-        use KeyCode::{BackTab, Char, Esc, Left, Right, Tab};
+// Fixed steps for the modifier seeks; plain arrows accelerate instead.
+const FINE_SEEK_SECS: f64 = 1.0;
+const COARSE_SEEK_SECS: f64 = 60.0;
 
-        // Onboarding consumes all keys
-        if self.onboarding.is_active() {
-            self.onboarding.handle_key_event(key)?;
-            return Ok(());
+impl App {
+    /// Mouse input only drives the seek bar and the pinned now-playing row,
+    /// and only when no overlay is open.
+    pub fn handle_mouse(&mut self, mouse: MouseEvent) {
+        if self.focus().is_overlay() {
+            return;
         }
+        if !self.discovery_list.handle_mouse_event(mouse) {
+            self.play_controls.handle_mouse_event(mouse);
+        }
+    }
 
-        // Overlays consume all keys
-        if self.show_help {
-            if key.code == KeyCode::Enter {
-                self.action_tx.send(Action::HideHelp)?;
-                self.action_tx.send(Action::ShowOnboarding)?;
-            } else {
-                self.action_tx.send(Action::HideHelp)?;
-            }
-            return Ok(());
+    /// The item `i`, `O`, and `y` act on: the selected one, or the playing one
+    /// when nothing (or a genre) is selected.
+    fn selected_or_playing(&self) -> Option<DiscoveryItem> {
+        let selected = self
+            .discovery_list
+            .selected_item()
+            .filter(|item| !item.is_browse_entry());
+        let playing = self.queue.current().map(|track| &track.item);
+        selected.or(playing).cloned()
+    }
+
+    /// Open the context menu with what applies to `item`, each entry
+    /// labelled with the key that does it directly.
+    pub(super) fn show_context_menu(&mut self, item: &DiscoveryItem) {
+        let mut entries = Vec::new();
+        let mut add = |label: &str, command: Command, action: Action| {
+            entries.push(MenuEntry {
+                label: label.to_string(),
+                shortcut: self.keymap.label(command),
+                action,
+            });
+        };
+        if !item.is_browse_entry() {
+            add("Play now", Command::Select, Action::PlayItem(item.clone()));
+            add(
+                "Play next",
+                Command::AddToQueueNext,
+                Action::AddToQueueNext(item.clone()),
+            );
+            add(
+                "Add to queue",
+                Command::AddToQueue,
+                Action::AddToQueue(item.clone()),
+            );
         }
-        if self.direct_play_modal.is_visible() {
-            self.direct_play_modal.handle_key_event(key)?;
-            return Ok(());
+        let favorite = self.db.is_favorite(&item.favorite_key()).unwrap_or(false);
+        add(
+            if favorite { "Unfavorite" } else { "Favorite" },
+            Command::ToggleFavorite,
+            Action::ToggleFavorite(item.clone()),
+        );
+        add("View info", Command::Info, Action::ShowInfo(item.clone()));
+        if item.web_url().is_some() {
+            add(
+                "Open in browser",
+                Command::OpenInBrowser,
+                Action::OpenInBrowser(item.clone()),
+            );
         }
-        if self.seek_modal.is_visible() {
-            self.seek_modal.handle_key_event(key)?;
-            return Ok(());
+        if self.link_to_copy(item, false).is_some() {
+            add(
+                "Copy link",
+                Command::CopyLink,
+                Action::CopyLink {
+                    item: item.clone(),
+                    stream: false,
+                },
+            );
         }
-
-        // Keys that work regardless of search focus
-        match key.code {
-            Tab => {
-                return self
-                    .action_tx
-                    .send(Action::SwitchSubTab((self.nts_tab.active_index() + 1) % 3))
-                    .map_err(Into::into)
-            }
-            BackTab => {
-                return self
-                    .action_tx
-                    .send(Action::SwitchSubTab((self.nts_tab.active_index() + 2) % 3))
-                    .map_err(Into::into)
-            }
-            Esc => return self.action_tx.send(Action::Back).map_err(Into::into),
-            _ => {}
+        if self.link_to_copy(item, true).is_some() {
+            add(
+                "Copy stream URL",
+                Command::CopyStreamUrl,
+                Action::CopyLink {
+                    item: item.clone(),
+                    stream: true,
+                },
+            );
+        }
+        for (label, action) in self.podcast_menu_actions(item) {
+            entries.push(MenuEntry {
+                label,
+                shortcut: String::new(),
+                action,
+            });
         }
+        self.context_menu.show(item.display_title(), entries);
+    }
+
+    /// Open the context menu for the items marked in the list: the bulk
+    /// actions that take them all at once.
+    fn show_marked_menu(&mut self) {
+        let items = self.discovery_list.marked_items();
+        let title = format!("{} marked items", items.len());
+        let entry = |label: &str, command: Command, action: Action| MenuEntry {
+            label: label.to_string(),
+            shortcut: self.keymap.label(command),
+            action,
+        };
+        let entries = vec![
+            entry(
+                "Play all next",
+                Command::AddToQueueNext,
+                Action::AddManyToQueue {
+                    items: items.clone(),
+                    insert_next: true,
+                },
+            ),
+            entry(
+                "Add all to queue",
+                Command::AddToQueue,
+                Action::AddManyToQueue {
+                    items: items.clone(),
+                    insert_next: false,
+                },
+            ),
+            entry(
+                "Favorite all",
+                Command::ToggleFavorite,
+                Action::FavoriteItems(items),
+            ),
+        ];
+        self.context_menu.show(title, entries);
+    }
 
-        // In search mode, forward to the search bar; if it didn't consume the
-        // key (e.g. arrow keys), fall through to normal-mode bindings.
-        if self.search_bar.is_focused() && self.search_bar.handle_key_event(key)? {
+    /// Turn a resolved keybinding into actions.
+    pub(super) fn run_command(&mut self, command: Command) -> anyhow::Result<()> {
+        if !self.repeat_limiter.allow(command, Instant::now()) {
             return Ok(());
         }
-
-        // Normal-mode keybindings
-        match key.code {
-            Char('q') => self.action_tx.send(Action::Quit)?,
-            Char('?') => self.action_tx.send(Action::ShowHelp)?,
-            Char('o') => self.action_tx.send(Action::OpenDirectPlay)?,
-            Char('v') => self.action_tx.send(Action::CycleVisualizer)?,
-            Char('i') => self.action_tx.send(Action::ToggleSkipIntro)?,
-            Char('t') => {
+        self.count_command(command);
+        match command {
+            Command::Quit => self.action_tx.send(Action::Quit)?,
+            Command::Help => self.action_tx.send(Action::ShowHelp)?,
+            Command::NextTab => self.action_tx.send(Action::SwitchSubTab(
+                (self.nts_tab.active_index() + 1) % NtsSubTab::ALL.len(),
+            ))?,
+            Command::PrevTab => self.action_tx.send(Action::SwitchSubTab(
+                (self.nts_tab.active_index() + NtsSubTab::ALL.len() - 1) % NtsSubTab::ALL.len(),
+            ))?,
+            Command::LiveTab => self.action_tx.send(Action::SwitchSubTab(0))?,
+            Command::PicksTab => self.action_tx.send(Action::SwitchSubTab(1))?,
+            Command::SearchTab => self.action_tx.send(Action::SwitchSubTab(2))?,
+            Command::FollowingTab => self.action_tx.send(Action::SwitchSubTab(3))?,
+            Command::FavoritesTab => self.action_tx.send(Action::SwitchSubTab(4))?,
+            Command::HistoryTab => self.action_tx.send(Action::SwitchSubTab(5))?,
+            Command::PlaylistsTab => self.action_tx.send(Action::SwitchSubTab(6))?,
+            Command::ForYouTab => self.action_tx.send(Action::SwitchSubTab(7))?,
+            Command::PodcastsTab => self.action_tx.send(Action::SwitchSubTab(8))?,
+            Command::Back => self.action_tx.send(Action::Back)?,
+            Command::ScrollDown => self.discovery_list.next(),
+            Command::ScrollUp => self.discovery_list.prev(),
+            Command::ScrollTop
+            | Command::ScrollBottom
+            | Command::HalfPageDown
+            | Command::HalfPageUp => self.run_motion(command, None),
+            Command::FindInList => self.discovery_list.start_find(),
+            Command::JumpToLetter => self.pending_keys.await_letter(),
+            Command::GroupGenres => self.action_tx.send(Action::ToggleGenreGroups)?,
+            Command::ToggleMoods => self.action_tx.send(Action::ToggleMoods)?,
+            Command::TogglePlaces => self.action_tx.send(Action::TogglePlaces)?,
+            Command::Select => self.discovery_list.activate_selected()?,
+            Command::JumpToPlaying => self.action_tx.send(Action::JumpToPlaying)?,
+            Command::DirectPlay if self.nts_tab.active_sub() == NtsSubTab::Podcasts => {
+                self.action_tx.send(Action::OpenPodcastSubscribe)?
+            }
+            Command::DirectPlay => self.action_tx.send(Action::OpenDirectPlay)?,
+            Command::CycleVisualizer => self.action_tx.send(Action::CycleVisualizer)?,
+            Command::ToggleSkipIntro => self.action_tx.send(Action::ToggleSkipIntro)?,
+            Command::RadioMode => self.action_tx.send(Action::ToggleRadio)?,
+            Command::LowDataMode => self.action_tx.send(Action::ToggleLowData)?,
+            Command::CycleBufferProfile => self.action_tx.send(Action::CycleBufferProfile)?,
+            Command::Equalizer => self.action_tx.send(Action::OpenEqualizer)?,
+            Command::Tracklist => self.action_tx.send(Action::ShowTracklist)?,
+            Command::Info => {
+                if let Some(item) = self.selected_or_playing() {
+                    self.action_tx.send(Action::ShowInfo(item))?;
+                }
+            }
+            Command::ContextMenu => {
+                if self.discovery_list.marked_count() > 0 {
+                    self.show_marked_menu();
+                } else if let Some(item) = self.selected_or_playing() {
+                    self.action_tx.send(Action::ShowContextMenu(item))?;
+                }
+            }
+            Command::MoreLikeThis => {
+                if let Some(item) = self.selected_or_playing() {
+                    self.action_tx.send(Action::MoreLikeThis(item))?;
+                }
+            }
+            Command::OpenInBrowser => {
+                if let Some(item) = self.selected_or_playing() {
+                    self.action_tx.send(Action::OpenInBrowser(item))?;
+                }
+            }
+            Command::CopyLink | Command::CopyStreamUrl => {
+                if let Some(item) = self.selected_or_playing() {
+                    let stream = command == Command::CopyStreamUrl;
+                    self.action_tx.send(Action::CopyLink { item, stream })?;
+                }
+            }
+            Command::CycleSort => self.action_tx.send(Action::CycleSort)?,
+            Command::SeekTimeline => {
                 if self.seek.is_seekable {
                     self.action_tx.send(Action::OpenSeekModal)?;
                 }
             }
-            Left => {
+            Command::CompactLayout => self.compact = !self.compact,
+            Command::VisualizerFullscreen => self.now_playing.set_fullscreen(true),
+            Command::ShrinkSplit => self.resize_split(-SPLIT_STEP),
+            Command::GrowSplit => self.resize_split(SPLIT_STEP),
+            Command::Bookmark => self.add_bookmark()?,
+            Command::PrevBookmark => self.jump_to_bookmark(false)?,
+            Command::NextBookmark => self.jump_to_bookmark(true)?,
+            Command::SeekBackward => {
                 if self.seek.is_seekable {
                     let step = self.seek.step();
                     self.action_tx.send(Action::SeekRelative(-step))?;
                 }
             }
-            Right => {
+            Command::SeekForward => {
                 if self.seek.is_seekable {
                     let step = self.seek.step();
                     self.action_tx.send(Action::SeekRelative(step))?;
                 }
             }
-            Char(' ') => self.action_tx.send(Action::TogglePlayPause)?,
-            Char('n') => self.action_tx.send(Action::NextTrack)?,
-            Char('p') => self.action_tx.send(Action::PrevTrack)?,
-            Char('s') => self.action_tx.send(Action::Stop)?,
-            Char('/') => self.action_tx.send(Action::FocusSearch)?,
-            Char('d') => self.action_tx.send(Action::RemoveFromQueue)?,
-            Char('c') => self.action_tx.send(Action::ClearQueue)?,
-            Char(']') => self.action_tx.send(Action::VolumeUp)?,
-            Char('[') => self.action_tx.send(Action::VolumeDown)?,
-            Char('a') => {
-                if let Some(item) = self.discovery_list.selected_item() {
-                    self.action_tx.send(Action::AddToQueue(item.clone()))?;
+            Command::SeekBackwardFine
+            | Command::SeekForwardFine
+            | Command::SeekBackwardCoarse
+            | Command::SeekForwardCoarse => {
+                if self.seek.is_seekable {
+                    let secs = match command {
+                        Command::SeekBackwardFine => -FINE_SEEK_SECS,
+                        Command::SeekForwardFine => FINE_SEEK_SECS,
+                        Command::SeekBackwardCoarse => -COARSE_SEEK_SECS,
+                        _ => COARSE_SEEK_SECS,
+                    };
+                    self.action_tx.send(Action::SeekRelative(secs))?;
                 }
             }
-            Char('A') => {
+            Command::PlayPause => self.action_tx.send(Action::TogglePlayPause)?,
+            Command::NextTrack => self.action_tx.send(Action::NextTrack)?,
+            Command::PrevTrack => self.action_tx.send(Action::PrevTrack)?,
+            Command::Stop => self.action_tx.send(Action::Stop)?,
+            Command::FocusSearch => self.action_tx.send(Action::FocusSearch)?,
+            Command::RemoveFromQueue => self.action_tx.send(Action::RemoveFromQueue)?,
+            Command::ClearQueue if !self.queue.is_empty() => {
+                let n = self.queue.len();
+                let entries = if n == 1 { "entry" } else { "entries" };
+                self.action_tx.send(Action::Confirm {
+                    prompt: format!("Clear the queue ({n} {entries})?"),
+                    action: Box::new(Action::ClearQueue),
+                })?;
+            }
+            Command::ClearQueue => {}
+            Command::VolumeUp => self.action_tx.send(Action::VolumeUp)?,
+            Command::VolumeDown => self.action_tx.send(Action::VolumeDown)?,
+            Command::AddToQueue | Command::AddToQueueNext => {
+                let insert_next = command == Command::AddToQueueNext;
+                if self.discovery_list.marked_count() > 0 {
+                    let items = self.discovery_list.marked_items();
+                    self.action_tx
+                        .send(Action::AddManyToQueue { items, insert_next })?;
+                } else if let Some(item) = self.discovery_list.selected_item() {
+                    let item = item.clone();
+                    self.action_tx.send(if insert_next {
+                        Action::AddToQueueNext(item)
+                    } else {
+                        Action::AddToQueue(item)
+                    })?;
+                }
+            }
+            Command::ToggleMark => self.discovery_list.toggle_mark(),
+            Command::MarkRange => self.discovery_list.mark_range(),
+            Command::ToggleFavorite => {
+                if self.discovery_list.marked_count() > 0 {
+                    let items = self.discovery_list.marked_items();
+                    self.action_tx.send(Action::FavoriteItems(items))?;
+                } else if let Some(item) = self
+                    .discovery_list
+                    .selected_item()
+                    .filter(|item| !matches!(item, DiscoveryItem::NtsGenreCategory { .. }))
+                {
+                    self.action_tx.send(Action::ToggleFavorite(item.clone()))?;
+                }
+            }
+            Command::Preview => {
+                if let Some(item) = self
+                    .discovery_list
+                    .selected_item()
+                    .filter(|item| !item.is_browse_entry())
+                {
+                    self.action_tx.send(Action::Preview(item.clone()))?;
+                }
+            }
+            Command::ToggleFollow => {
                 if let Some(item) = self.discovery_list.selected_item() {
-                    self.action_tx.send(Action::AddToQueueNext(item.clone()))?;
+                    self.action_tx.send(Action::ToggleFollow(item.clone()))?;
                 }
             }
-            Char('r') if self.error_message.is_some() => {
-                self.action_tx.send(Action::LoadNtsLive)?;
-                self.error_message = None;
+            Command::Retry => {
+                if !self.has_stashed_tab(NtsSubTab::Search) && self.has_search_gaps() {
+                    self.action_tx.send(Action::RetrySearchPages)?;
+                } else if self.retry_failed_fetches()? {
+                    self.notifications.dismiss(NotifyLevel::Error);
+                }
             }
-            Char(c) if c.is_ascii_digit() => {
-                let idx = c.to_digit(10).unwrap_or(0) as usize;
-                if (1..=3).contains(&idx) {
-                    self.action_tx.send(Action::SwitchSubTab(idx - 1))?;
+            Command::NotificationHistory => {
+                self.action_tx.send(Action::ShowNotificationHistory)?;
+            }
+            Command::Stats => self.action_tx.send(Action::ShowStats)?,
+            Command::Logs => self.action_tx.send(Action::ShowLogs)?,
+            Command::Favorites => self.action_tx.send(Action::ShowFavorites)?,
+            Command::History => self.action_tx.send(Action::ShowHistory)?,
+            Command::Playlists => self.action_tx.send(Action::ShowPlaylists)?,
+            Command::AddToPlaylist => {
+                if self.discovery_list.marked_count() > 0 {
+                    let items = self.discovery_list.marked_items();
+                    self.discovery_list.clear_marks();
+                    self.action_tx.send(Action::ChoosePlaylist(items))?;
+                } else if let Some(item) = self.discovery_list.selected_item() {
+                    self.action_tx
+                        .send(Action::ChoosePlaylist(vec![item.clone()]))?;
                 }
             }
-            _ => {
-                self.discovery_list.handle_key_event(key)?;
+            Command::QueueToPlaylist => {
+                let items: Vec<DiscoveryItem> = self
+                    .queue
+                    .items()
+                    .iter()
+                    .map(|qi| qi.item.clone())
+                    .collect();
+                if !items.is_empty() {
+                    self.action_tx.send(Action::ChoosePlaylist(items))?;
+                }
             }
+            Command::ExportData => self
+                .action_tx
+                .send(Action::OpenFilePicker(PickPurpose::Export))?,
+            Command::ImportData => self
+                .action_tx
+                .send(Action::OpenFilePicker(PickPurpose::Import))?,
         }
         Ok(())
     }
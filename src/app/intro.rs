@@ -0,0 +1,71 @@
+// Skip NTS intro (`Alt+i`): when an archived episode starts, seek past the
+// show's configured intro (`[nts] intro_secs`), or past the fallback offset
+// right away and then to where the analysis in `player::intro` finds the
+// content starting.
+
+use crate::action::Action;
+use crate::api::models::DiscoveryItem;
+use crate::app::App;
+use crate::player::intro;
+
+impl App {
+    /// Called when a track starts: line up the intro skip for an episode.
+    pub(super) fn skip_intro(&mut self) {
+        self.seek.pending_intro_skip = None;
+        if !self.config.general.skip_nts_intro || self.seek.pending_resume.is_some() {
+            return;
+        }
+        let Some(track) = self.queue.current() else {
+            return;
+        };
+        let DiscoveryItem::NtsEpisode { show_alias, .. } = &track.item else {
+            return;
+        };
+        if let Some(&secs) = self.config.nts.intro_secs.get(show_alias) {
+            self.seek.pending_intro_skip = (secs > 0.0).then_some(secs);
+            return;
+        }
+        self.seek.pending_intro_skip = Some(intro::FALLBACK_INTRO_SECS);
+        if self.config.general.low_data {
+            // The analysis streams the episode a second time.
+            return;
+        }
+
+        let url = track.url.clone();
+        let command = intro::analysis_command(
+            self.config.player.mpv_binary(),
+            &self.config.extra_mpv_args(),
+            &url,
+        );
+        let tx = self.action_tx.clone();
+        tokio::spawn(async move {
+            let start = match intro::detect_content_start(command).await {
+                Ok(start) => start,
+                Err(e) => {
+                    tracing::debug!("intro analysis failed for {url}: {e}");
+                    None
+                }
+            };
+            let _ = tx.send(Action::IntroDetected { url, start });
+        });
+    }
+
+    /// The analysis found where the episode at `url` starts: seek there if
+    /// it is still playing and hasn't got that far yet.
+    pub(super) fn intro_detected(&mut self, url: &str, start: Option<f64>) -> anyhow::Result<()> {
+        let Some(start) = start else {
+            return Ok(());
+        };
+        if self.queue.current().is_none_or(|track| track.url != url) {
+            return Ok(());
+        }
+        tracing::debug!("intro ends at {start:.1}s");
+        if self.seek.pending_intro_skip.is_some() {
+            // The duration isn't known yet; skip the measured intro instead.
+            self.seek.pending_intro_skip = Some(start);
+        } else if self.now_playing.position_secs() < start {
+            self.action_tx.send(Action::SeekAbsolute(start))?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,128 @@
+// Queue time estimates: each queue entry's length and the time left in the
+// queue. Lengths mpv reports while an item plays are kept in the database;
+// queued NTS episodes that haven't played get an estimate from where their
+// tracklist ends, fetched once per session.
+
+use crate::action::Action;
+use crate::api::models::DiscoveryItem;
+use crate::app::App;
+use crate::components::queue_list::QueueRow;
+use crate::player::queue::{QueueTime, TrackLength};
+
+// A measured length this close to the stored one isn't written again.
+const LENGTH_TOLERANCE_SECS: f64 = 1.0;
+
+impl App {
+    /// Hand the queue, with lengths, to the queue panel and the controls.
+    pub(super) fn show_queue(&mut self) {
+        let rows: Vec<QueueRow> = self
+            .queue
+            .items()
+            .iter()
+            .map(|qi| {
+                let m = qi.stream_metadata.as_ref();
+                let (title, subtitle) = qi.item.display_pair(
+                    m.and_then(|m| m.station_name.as_deref()),
+                    m.and_then(|m| m.display_title()).as_deref(),
+                    m.and_then(|m| m.display_subtitle()).as_deref(),
+                );
+                QueueRow {
+                    title,
+                    subtitle,
+                    length: self.track_length(&qi.item),
+                    auto: self.radio_keys.contains(&qi.item.favorite_key()),
+                }
+            })
+            .collect();
+        let lengths: Vec<_> = rows.iter().map(|row| row.length).collect();
+        let current = self.queue.current_index();
+        self.play_controls
+            .set_queue_time(QueueTime::after(&lengths, current));
+        self.now_playing.set_queue(rows, current);
+    }
+
+    /// Live channels run on and have no length. Podcast episodes go by
+    /// their feed until mpv has measured them.
+    fn track_length(&self, item: &DiscoveryItem) -> Option<TrackLength> {
+        if matches!(item, DiscoveryItem::NtsLiveChannel { .. }) {
+            return None;
+        }
+        let stated = match item {
+            DiscoveryItem::PodcastEpisode {
+                duration_secs: Some(secs),
+                ..
+            } => Some(TrackLength::Measured(*secs as f64)),
+            _ => None,
+        };
+        self.track_lengths
+            .get(&item.favorite_key())
+            .copied()
+            .or(stated)
+    }
+
+    /// Keep the length mpv reported for the playing item.
+    pub(super) fn record_track_length(&mut self, secs: f64) {
+        let Some(track) = self.queue.current() else {
+            return;
+        };
+        let key = track.item.favorite_key();
+        if let Some(TrackLength::Measured(known)) = self.track_lengths.get(&key) {
+            if (known - secs).abs() < LENGTH_TOLERANCE_SECS {
+                return;
+            }
+        }
+        let _ = self.db.set_track_length(&key, secs);
+        self.track_lengths.insert(key, TrackLength::Measured(secs));
+        self.show_queue();
+    }
+
+    /// Fetch the tracklists of queued NTS episodes without a length, for an
+    /// estimate of how long they run. Skipped in low data mode.
+    pub(super) fn request_track_lengths(&mut self) {
+        if self.config.general.low_data {
+            return;
+        }
+        for track in self.queue.items() {
+            let DiscoveryItem::NtsEpisode {
+                show_alias,
+                episode_alias,
+                ..
+            } = &track.item
+            else {
+                continue;
+            };
+            let key = track.item.favorite_key();
+            if show_alias.is_empty()
+                || episode_alias.is_empty()
+                || self.track_lengths.contains_key(&key)
+                || !self.length_requests.insert(key.clone())
+            {
+                continue;
+            }
+            let client = self.nts_client.clone();
+            let tx = self.action_tx.clone();
+            let (show, episode) = (show_alias.clone(), episode_alias.clone());
+            tokio::spawn(async move {
+                let Ok(tracks) = client.fetch_episode_tracklist(&show, &episode).await else {
+                    return;
+                };
+                // The end of the last song with a timestamp and a length.
+                let end = tracks
+                    .iter()
+                    .filter_map(|t| Some(t.offset? + t.duration?))
+                    .fold(0.0, f64::max);
+                if end > 0.0 {
+                    let _ = tx.send(Action::TrackLengthEstimated { key, secs: end });
+                }
+            });
+        }
+    }
+
+    /// An estimate never replaces a measured length.
+    pub(super) fn track_length_estimated(&mut self, key: String, secs: f64) {
+        self.track_lengths
+            .entry(key)
+            .or_insert(TrackLength::Estimated(secs));
+        self.show_queue();
+    }
+}
@@ -0,0 +1,84 @@
+// Live tracklist: while a live channel plays, polls NTS for the songs on air
+// and keeps this session's recent songs per channel for Now Playing.
+
+use std::collections::BTreeMap;
+
+use crate::action::Action;
+use crate::api::models::{self, DiscoveryItem, LiveTrack};
+use crate::app::App;
+
+// How often to re-check the tracklist while a live channel plays.
+pub(super) const POLL_INTERVAL_SECS: f64 = 30.0;
+// Songs kept per channel for the session.
+const HISTORY_LEN: usize = 10;
+
+/// Songs heard on each live channel this session, newest first.
+#[derive(Default)]
+pub(crate) struct LiveTracksState {
+    pub(crate) history: BTreeMap<u8, Vec<LiveTrack>>,
+    /// Tick counter for the periodic poll.
+    pub(crate) poll_ticks: u32,
+}
+
+impl App {
+    /// The live channel currently playing, if any.
+    fn playing_live_channel(&self) -> Option<u8> {
+        if !self.now_playing.is_playing() {
+            return None;
+        }
+        match self.queue.current()?.item {
+            DiscoveryItem::NtsLiveChannel { channel, .. } => Some(channel),
+            _ => None,
+        }
+    }
+
+    /// Fetch the tracklist of the live channel playing now, in the background.
+    pub(super) fn poll_live_tracks(&mut self) {
+        self.live_tracks.poll_ticks = 0;
+        let Some(channel) = self.playing_live_channel() else {
+            return;
+        };
+        let client = self.nts_client.clone();
+        let tx = self.action_tx.clone();
+        tokio::spawn(async move {
+            if let Ok(tracks) = client.fetch_live_tracks(channel).await {
+                let _ = tx.send(Action::LiveTracksLoaded { channel, tracks });
+            }
+        });
+    }
+
+    /// Count a tick while a live channel plays and poll when it's time.
+    pub(super) fn tick_live_tracks(&mut self) {
+        if self.playing_live_channel().is_none() {
+            return;
+        }
+        self.live_tracks.poll_ticks += 1;
+        let interval = (self.tick_rate() * POLL_INTERVAL_SECS) as u32;
+        if interval > 0 && self.live_tracks.poll_ticks >= interval {
+            self.poll_live_tracks();
+        }
+    }
+
+    /// Live channel songs shown in Now Playing, newest first.
+    #[allow(dead_code)] // used by integration tests
+    pub fn shown_live_tracks(&self) -> &[LiveTrack] {
+        self.now_playing.live_tracks()
+    }
+
+    /// Merge a fetched tracklist into the channel's history.
+    pub(super) fn live_tracks_loaded(&mut self, channel: u8, tracks: Vec<LiveTrack>) {
+        let history = self.live_tracks.history.entry(channel).or_default();
+        models::merge_live_tracks(history, tracks, HISTORY_LEN);
+        self.sync_live_tracks();
+    }
+
+    /// Show the playing channel's songs in Now Playing; none for anything else.
+    pub(super) fn sync_live_tracks(&mut self) {
+        let tracks = self
+            .playing_live_channel()
+            .and_then(|channel| self.live_tracks.history.get(&channel))
+            .cloned()
+            .unwrap_or_default();
+        self.now_playing.set_live_tracks(tracks);
+    }
+}
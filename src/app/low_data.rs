@@ -0,0 +1,34 @@
+// Low data mode, for metered connections: yt-dlp picks low-bitrate audio,
+// nothing is preloaded for crossfades, queued pages and tracklists aren't
+// looked up ahead of time, episode intros aren't analysed, and API responses
+// stay cached for longer.
+
+use crate::app::App;
+use crate::components::notifications::Notification;
+
+impl App {
+    pub(super) async fn toggle_low_data(&mut self) {
+        let on = !self.config.general.low_data;
+        self.config.general.low_data = on;
+        self.play_controls.set_low_data(on);
+        self.nts_client.set_low_data(on);
+        self.player.set_extra_args(self.config.extra_mpv_args());
+        // Applies from the next file, also in an mpv left idle by Stop;
+        // what's playing keeps its stream. Fails when no mpv is running.
+        let _ = self
+            .player
+            .apply_ytdl_format(self.config.ytdl_format())
+            .await;
+        self.save_config_async();
+        self.notifications.push(Notification::info(if on {
+            "Low data mode on: lower bitrates, no preloading, longer caching"
+        } else {
+            "Low data mode off"
+        }));
+        if !on {
+            // Catch up on the lookups skipped meanwhile.
+            self.request_url_metadata();
+            self.request_track_lengths();
+        }
+    }
+}
@@ -0,0 +1,52 @@
+// Metrics glue: counts plays and commands, flushes the counters to the local
+// database, and fills the Stats overlay.
+
+use crate::app::App;
+use crate::keymap::Command;
+use crate::metrics::play_key;
+
+// How often buffered counters are written while the app is open.
+pub(super) const FLUSH_INTERVAL_SECS: f64 = 60.0;
+
+impl App {
+    /// Count a play of the current track by its source.
+    pub(super) fn count_play(&mut self) {
+        if let Some(current) = self.queue.current() {
+            let key = play_key(&current.item);
+            self.metrics.count(key);
+        }
+    }
+
+    pub(super) fn count_command(&mut self, command: Command) {
+        if self.metrics.is_enabled() {
+            self.metrics.count(&format!("key.{}", command.name()));
+        }
+    }
+
+    /// Write buffered counters to the database. Failures drop the batch
+    /// rather than interrupting playback.
+    pub(super) fn flush_metrics(&mut self) {
+        self.metrics_flush_ticks = 0;
+        let pending = self.metrics.take_pending();
+        if !pending.is_empty() {
+            let _ = self.db.add_metric_counts(&pending);
+        }
+    }
+
+    /// Open (or refresh) the Stats overlay with the latest counters.
+    pub(super) fn show_stats(&mut self) {
+        self.flush_metrics();
+        let counts = self.db.metric_counts().unwrap_or_default();
+        self.stats_view
+            .show(self.metrics.is_enabled(), counts, self.metrics.perf());
+    }
+
+    pub(super) fn clear_metrics(&mut self) -> anyhow::Result<()> {
+        self.metrics.take_pending();
+        self.db.clear_metrics()?;
+        if self.stats_view.is_visible() {
+            self.show_stats();
+        }
+        Ok(())
+    }
+}
@@ -1,42 +1,118 @@
 // Central coordinator: owns all components, the player, and the database.
 // Runs the event loop (key → Action → handle_action → component updates → draw).
 
+mod about;
 mod actions;
+mod bookmarks;
+mod crossfade;
+mod export;
+mod fallback;
 mod fetch;
+mod focus;
+mod following;
+mod for_you;
+mod frames;
+mod genres;
+mod health;
+mod history;
+mod hooks;
+mod info;
 mod input;
+mod intro;
+mod lengths;
+mod live_tracks;
+mod low_data;
+mod metrics;
+mod motions;
+#[cfg(target_os = "linux")]
+mod mpris;
 mod playback;
+mod playlists;
+mod podcasts;
+mod preview;
+mod radio;
+mod reconnect;
+mod registry;
+mod related;
+mod remote;
+mod resume;
+mod session;
+mod sleep;
+mod tabs;
+mod title;
+mod tracklist;
+mod url_meta;
+mod watchdog;
 
+#[allow(unused_imports)] // used by integration tests
+pub use focus::{Focus, Modal};
+
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
 use std::time::Instant;
 
+use ratatui::backend::TestBackend;
+use ratatui::Terminal;
 use tokio::sync::mpsc;
 
 use crate::action::Action;
-use crate::api::nts::NtsClient;
+use crate::api::cache::{self, ResponseCache};
+use crate::api::genres::GenreCategory;
+use crate::api::nts::NtsApi;
+use crate::components::about::AboutView;
+use crate::components::confirm_modal::ConfirmModal;
+use crate::components::context_menu::ContextMenu;
 use crate::components::direct_play_modal::DirectPlayModal;
 use crate::components::discovery_list::DiscoveryList;
+use crate::components::equalizer_modal::EqualizerModal;
+use crate::components::favorites_view::FavoritesView;
+use crate::components::file_picker::FilePicker;
+use crate::components::history_view::HistoryView;
+use crate::components::info_view::InfoView;
+use crate::components::log_view::LogView;
+use crate::components::notifications::Notifications;
 use crate::components::now_playing::NowPlaying;
-use crate::components::nts::NtsTab;
+use crate::components::nts::{NtsSubTab, NtsTab};
 use crate::components::onboarding::Onboarding;
 use crate::components::play_controls::PlayControls;
+use crate::components::playlists_view::PlaylistsView;
 use crate::components::search_bar::SearchBar;
 use crate::components::seek_modal::SeekModal;
-use crate::components::Component;
-use crate::config::Config;
-use crate::db::Database;
-use crate::player::queue::Queue;
+use crate::components::stats::StatsView;
+use crate::components::tracklist_view::TracklistView;
+use crate::config::{Config, QueuePosition};
+use crate::db::{Bookmark, Database};
+use crate::health::HealthLog;
+use crate::keymap::{KeyMap, RepeatLimiter};
+use crate::logging::Logs;
+use crate::metrics::Metrics;
+use crate::player::backend::PlayerBackend;
+use crate::player::failure::PlaybackFailure;
+use crate::player::queue::{Queue, TrackLength};
+use crate::player::watch_later;
 use crate::player::MpvPlayer;
-use crate::theme::Theme;
+use crate::theme::{ColorSupport, Theme};
+use crate::trace::ActionRecorder;
 use crate::tui::{Tui, TuiEvent};
 use crate::ui;
 
-/// Tracks accelerating seek behavior and pending intro skip.
+/// Tracks accelerating seek behavior and pending intro skip / resume.
 #[derive(Default)]
 pub(crate) struct SeekState {
     pub(crate) is_seekable: bool,
     pub(crate) duration_secs: Option<f64>,
     pub(crate) last_seek_time: Option<Instant>,
     pub(crate) seek_streak: u32,
-    pub(crate) pending_intro_skip: bool,
+    /// Seconds of intro to seek past once the duration is known.
+    pub(crate) pending_intro_skip: Option<f64>,
+    /// Stored position to seek to once the duration is known.
+    pub(crate) pending_resume: Option<f64>,
+    /// Show the track paused once it has started, for one loaded paused.
+    pub(crate) pending_pause: bool,
+    /// The playing episode's bookmarks, earliest first.
+    pub(crate) bookmarks: Vec<Bookmark>,
+    /// The section mpv is looping.
+    pub(crate) ab_loop: Option<(f64, f64)>,
 }
 
 impl SeekState {
@@ -75,36 +151,137 @@ pub struct App {
     // Components
     pub nts_tab: NtsTab,
     pub discovery_list: DiscoveryList,
-    pub(crate) search_bar: SearchBar,
-    pub(crate) now_playing: NowPlaying,
+    pub search_bar: SearchBar,
+    pub now_playing: NowPlaying,
     pub(crate) play_controls: PlayControls,
     pub(crate) direct_play_modal: DirectPlayModal,
     pub(crate) seek_modal: SeekModal,
+    pub equalizer_modal: EqualizerModal,
     pub onboarding: Onboarding,
+    pub notifications: Notifications,
+    pub(crate) stats_view: StatsView,
+    pub(crate) log_view: LogView,
+    pub context_menu: ContextMenu,
+    pub confirm_modal: ConfirmModal,
+    pub about_view: AboutView,
+    pub favorites_view: FavoritesView,
+    pub history_view: HistoryView,
+    pub file_picker: FilePicker,
+    pub playlists_view: PlaylistsView,
+    pub tracklist_view: TracklistView,
+    pub info_view: InfoView,
 
     // State
-    pub(crate) nts_client: NtsClient,
-    pub(crate) player: MpvPlayer,
+    pub(crate) nts_client: Arc<dyn NtsApi>,
+    pub(crate) player: Box<dyn PlayerBackend>,
     pub(crate) db: Database,
     pub(crate) config: Config,
+    pub(crate) keymap: KeyMap,
+    pub(crate) repeat_limiter: RepeatLimiter,
     pub queue: Queue,
     pub show_help: bool,
-    pub error_message: Option<String>,
+    /// Draw the one-row player instead of the panels (`Z`).
+    pub compact: bool,
     pub(crate) search_id: u64,
-    /// True when viewing genre search results (not the genre list itself).
+    pub(crate) search_gaps: fetch::SearchGaps,
+    /// Keys of items left out of the current search's results.
+    pub(crate) search_exclude: HashSet<String>,
+    /// Genres and shows the For You list was drawn from, for its heading.
+    pub(crate) for_you_basis: Vec<String>,
+    /// Keys of the listed podcast episodes that haven't been played.
+    pub(crate) podcast_unplayed: HashSet<String>,
+    /// True when viewing genre, mood or place search results (not the list
+    /// of genres, moods or places itself).
     pub(crate) viewing_genre_results: bool,
+    /// Whether the Search tab lists genres, moods or places.
+    pub(crate) browse: genres::Browse,
     /// True when viewing text query search results.
     pub(crate) viewing_query_results: bool,
     pub(crate) theme: Theme,
+    /// Colors the terminal shows; frames are quantized down to them.
+    pub(crate) colors: ColorSupport,
+    /// Lengths of queue entries by favorite key, measured or estimated.
+    pub(crate) track_lengths: HashMap<String, TrackLength>,
+    /// Episodes whose tracklist was fetched for a length estimate.
+    pub(crate) length_requests: HashSet<String>,
+    /// Direct URLs already handed to yt-dlp for their details.
+    pub(crate) url_requests: HashSet<String>,
+    /// Queue entries radio mode added this session, by favorite key.
+    pub(crate) radio_keys: HashSet<String>,
+    /// The entry radio last searched from, so each is searched from once.
+    pub(crate) radio_seed: Option<String>,
     pub(crate) seek: SeekState,
+    pub(crate) source_fallback: Option<fallback::SourceFallback>,
+    /// A dropped live stream waiting to be restarted.
+    pub(crate) reconnect: Option<reconnect::Reconnect>,
+    /// True after the user stopped playback, until a track starts again.
+    pub(crate) stopped: bool,
+    /// How long playback has gone without moving.
+    pub(crate) watchdog: watchdog::Watchdog,
+    pub(crate) frames: frames::FrameState,
+    /// Why the current track failed, if mpv reported an error for it.
+    pub(crate) playback_failure: Option<PlaybackFailure>,
     /// Tick counter for periodic live metadata refresh.
     pub(crate) live_refresh_ticks: u32,
+    /// Lists and search text of the tabs that aren't open.
+    pub(crate) tab_states: tabs::TabStates,
+    /// Selection and filter of the last session, until its tab has loaded.
+    pub(crate) pending_session: Option<session::Session>,
+    pub(crate) pending_keys: motions::PendingKeys,
+    /// Groups of the grouped genre list that are unfolded.
+    pub(crate) expanded_genres: HashSet<String>,
+    /// Genre tree from the API; the built-in list is shown until it arrives.
+    pub(crate) genre_tree: Option<Vec<GenreCategory>>,
+    pub(crate) genre_tree_requested: bool,
+    /// Genres whose episode count has been asked for.
+    pub(crate) genre_counts_requested: HashSet<String>,
+    /// Tabs whose last load failed, for `r` to retry.
+    pub(crate) failed_fetches: Vec<NtsSubTab>,
+    /// Unix time to refetch Live because a show on air is due to end.
+    pub(crate) live_change_at: Option<u64>,
+    pub(crate) following: following::FollowingState,
+    pub(crate) live_tracks: live_tracks::LiveTracksState,
+    pub(crate) resume_writes: resume::ResumeWrites,
+    pub(crate) crossfade: crossfade::CrossfadeState,
+    pub(crate) sleep: sleep::SleepState,
+    /// Ticks since the preview started, while one plays.
+    pub(crate) preview_ticks: Option<u32>,
+    /// Playlist shown on the Playlists tab.
+    pub(crate) open_playlist: Option<i64>,
+    /// The play being recorded in the history, if any.
+    pub(crate) open_play: Option<history::OpenPlay>,
+    pub(crate) metrics: Metrics,
+    /// Tick counter for the periodic metrics flush.
+    pub(crate) metrics_flush_ticks: u32,
+    /// Playback health samples, written only with `debug_playback` on.
+    pub(crate) health: HealthLog,
+    /// Tick counter for the periodic health sample.
+    pub(crate) health_sample_ticks: u32,
+    /// Last title written to the terminal; None until the first write.
+    pub(crate) window_title: Option<String>,
+    #[cfg(target_os = "linux")]
+    pub(crate) mpris: Option<crate::mpris::MprisHandle>,
+    /// Key and details of the track `track_started` last ran for, until
+    /// `track_finished` runs for it.
+    pub(crate) hook_track: Option<(String, crate::hooks::HookTrack)>,
+    /// Control server for `clisten ctl` and HTTP clients, if enabled.
+    pub(crate) remote: Option<crate::remote::RemoteHandle>,
+    /// Writes every handled action to a trace file (`--record`).
+    pub(crate) recorder: Option<ActionRecorder>,
 }
 
 impl App {
     pub fn new(config: Config) -> anyhow::Result<Self> {
         let db = Database::open()?;
-        Self::with_db(config, db)
+        let mut app = Self::with_db(config, db)?;
+        app.nts_client = Arc::new(
+            app.config
+                .nts_client()
+                .with_cache(ResponseCache::new(cache::cache_dir())),
+        );
+        app.nts_client.set_low_data(app.config.general.low_data);
+        app.import_watch_later(&watch_later::default_dirs());
+        Ok(app)
     }
 
     /// Create an App with a custom database (used by integration tests to avoid
@@ -113,43 +290,71 @@ impl App {
         let (action_tx, action_rx) = mpsc::unbounded_channel();
         let queue = Self::restore_queue(&db);
         let theme = Theme::from_name(&config.general.theme);
+        let colors = config.general.colors.unwrap_or_else(ColorSupport::detect);
+        let metrics = Metrics::new(config.general.metrics);
+        let frames = frames::FrameState::new(config.general.frame_rate);
+        let health = if config.general.debug_playback {
+            HealthLog::create(&crate::health::log_path())
+        } else {
+            HealthLog::disabled()
+        };
 
         let mut nts_tab = NtsTab::new();
+        nts_tab.set_count(NtsSubTab::Favorites, db.favorite_count().unwrap_or(0));
+        nts_tab.set_count(NtsSubTab::History, db.history_count().unwrap_or(0));
+        nts_tab.set_count(
+            NtsSubTab::Playlists,
+            db.playlists().map(|p| p.len()).unwrap_or(0),
+        );
         let mut discovery_list = DiscoveryList::new();
+        discovery_list.set_favorites(db.favorite_keys().unwrap_or_default());
         let mut search_bar = SearchBar::new();
+        search_bar.set_history(
+            db.recent_searches(fetch::SEARCH_HISTORY)
+                .unwrap_or_default(),
+        );
         let mut now_playing = NowPlaying::new(config.general.visualizer);
+        now_playing.set_layout(
+            config.layout.queue == QueuePosition::Right,
+            !config.layout.hide_visualizer,
+        );
         let mut play_controls = PlayControls::new();
         play_controls.set_skip_nts_intro(config.general.skip_nts_intro);
-        let mut direct_play_modal = DirectPlayModal::new();
-        let mut seek_modal = SeekModal::new();
-        let mut onboarding = Onboarding::new();
-
-        for component in [
-            &mut nts_tab as &mut dyn Component,
-            &mut discovery_list,
-            &mut search_bar,
-            &mut now_playing,
-            &mut play_controls,
-            &mut direct_play_modal,
-            &mut seek_modal,
-            &mut onboarding,
-        ] {
-            component.register_action_handler(action_tx.clone());
-        }
+        play_controls.set_radio_mode(config.general.radio_mode);
+        play_controls.set_low_data(config.general.low_data);
+        play_controls.set_buffer_profile(config.player.buffer_profile);
+        let direct_play_modal = DirectPlayModal::new();
+        let seek_modal = SeekModal::new();
+        let equalizer_modal = EqualizerModal::new();
+        let onboarding = Onboarding::new();
+        let notifications = Notifications::new();
+        let stats_view = StatsView::new();
+        let log_view = LogView::new();
+        let context_menu = ContextMenu::new();
+        let confirm_modal = ConfirmModal::new();
+        let about_view = AboutView::new();
+        let favorites_view = FavoritesView::new();
+        let history_view = HistoryView::new();
+        let file_picker = FilePicker::new();
+        let playlists_view = PlaylistsView::new();
+        let tracklist_view = TracklistView::new();
+        let info_view = InfoView::new();
 
         let mut player = MpvPlayer::new();
         player.set_action_tx(action_tx.clone());
+        player.set_buffer(config.player.buffer_settings());
+        player.set_equalizer(config.player.equalizer_filter());
+        player.set_extra_args(config.extra_mpv_args());
+        player.set_binary(config.player.mpv_binary());
 
-        // Sync restored queue to UI components
-        play_controls.set_queue_info(queue.current_index(), queue.len());
-        let queue_display: Vec<(String, String)> = queue
-            .items()
-            .iter()
-            .map(|qi| (qi.item.display_title(), qi.item.subtitle()))
+        let track_lengths = db
+            .track_lengths()
+            .unwrap_or_default()
+            .into_iter()
+            .map(|(key, secs)| (key, TrackLength::Measured(secs)))
             .collect();
-        now_playing.set_queue(queue_display, queue.current_index());
 
-        Ok(Self {
+        let mut app = Self {
             running: true,
             action_tx,
             action_rx,
@@ -160,21 +365,165 @@ impl App {
             play_controls,
             direct_play_modal,
             seek_modal,
+            equalizer_modal,
             onboarding,
-            nts_client: NtsClient::new(),
-            player,
+            notifications,
+            stats_view,
+            log_view,
+            context_menu,
+            confirm_modal,
+            about_view,
+            favorites_view,
+            history_view,
+            file_picker,
+            playlists_view,
+            tracklist_view,
+            info_view,
+            nts_client: Arc::new(config.nts_client()),
+            player: Box::new(player),
             db,
+            keymap: KeyMap::new(&config.keys),
+            repeat_limiter: RepeatLimiter::default(),
             config,
             queue,
             show_help: false,
-            error_message: None,
+            compact: false,
             search_id: 0,
+            search_gaps: fetch::SearchGaps::default(),
+            search_exclude: HashSet::new(),
+            for_you_basis: Vec::new(),
+            podcast_unplayed: HashSet::new(),
             viewing_genre_results: false,
+            browse: genres::Browse::default(),
             viewing_query_results: false,
             theme,
+            colors,
+            track_lengths,
+            length_requests: HashSet::new(),
+            url_requests: HashSet::new(),
+            radio_keys: HashSet::new(),
+            radio_seed: None,
             seek: SeekState::default(),
+            source_fallback: None,
+            reconnect: None,
+            stopped: false,
+            watchdog: watchdog::Watchdog::default(),
+            frames,
+            playback_failure: None,
             live_refresh_ticks: 0,
-        })
+            live_change_at: None,
+            failed_fetches: Vec::new(),
+            tab_states: tabs::TabStates::default(),
+            pending_session: None,
+            pending_keys: motions::PendingKeys::default(),
+            expanded_genres: HashSet::new(),
+            genre_tree: None,
+            genre_tree_requested: false,
+            genre_counts_requested: HashSet::new(),
+            following: following::FollowingState::default(),
+            live_tracks: live_tracks::LiveTracksState::default(),
+            resume_writes: resume::ResumeWrites::default(),
+            crossfade: crossfade::CrossfadeState::default(),
+            sleep: sleep::SleepState::default(),
+            preview_ticks: None,
+            open_playlist: None,
+            open_play: None,
+            metrics,
+            metrics_flush_ticks: 0,
+            health,
+            health_sample_ticks: 0,
+            window_title: None,
+            #[cfg(target_os = "linux")]
+            mpris: None,
+            hook_track: None,
+            remote: None,
+            recorder: None,
+        };
+        app.register_components();
+        // Sync restored queue to UI components
+        app.sync_play_controls();
+        app.show_queue();
+        Ok(app)
+    }
+
+    /// Play through `player` instead of mpv, e.g. a FakePlayer in tests.
+    pub fn with_player(mut self, mut player: impl PlayerBackend + 'static) -> Self {
+        player.set_action_tx(self.action_tx.clone());
+        player.set_buffer(self.config.player.buffer_settings());
+        player.set_equalizer(self.config.player.equalizer_filter());
+        self.player = Box::new(player);
+        self
+    }
+
+    /// Record every action handled from now on with `recorder`.
+    pub fn with_recorder(mut self, recorder: ActionRecorder) -> Self {
+        self.recorder = Some(recorder);
+        self
+    }
+
+    /// Show the lines collected in `logs` in the log viewer.
+    pub fn with_logs(mut self, logs: Logs) -> Self {
+        self.log_view.set_logs(logs);
+        self
+    }
+
+    /// Answer NTS requests from `api` instead of the HTTP client, e.g. a
+    /// FixtureApi in tests.
+    pub fn with_nts_api(mut self, api: impl NtsApi + 'static) -> Self {
+        api.set_low_data(self.config.general.low_data);
+        self.nts_client = Arc::new(api);
+        self
+    }
+
+    /// Borrow everything a frame is drawn from.
+    fn draw_state(&self) -> ui::DrawState<'_> {
+        ui::DrawState {
+            nts_tab: &self.nts_tab,
+            discovery_list: &self.discovery_list,
+            search_bar: &self.search_bar,
+            now_playing: &self.now_playing,
+            play_controls: &self.play_controls,
+            direct_play_modal: &self.direct_play_modal,
+            seek_modal: &self.seek_modal,
+            equalizer_modal: &self.equalizer_modal,
+            onboarding: &self.onboarding,
+            notifications: &self.notifications,
+            stats_view: &self.stats_view,
+            log_view: &self.log_view,
+            context_menu: &self.context_menu,
+            confirm_modal: &self.confirm_modal,
+            about_view: &self.about_view,
+            favorites_view: &self.favorites_view,
+            history_view: &self.history_view,
+            file_picker: &self.file_picker,
+            playlists_view: &self.playlists_view,
+            tracklist_view: &self.tracklist_view,
+            info_view: &self.info_view,
+            show_help: self.show_help,
+            compact: self.compact,
+            layout: &self.config.layout,
+            keymap: &self.keymap,
+            theme: &self.theme,
+            colors: self.colors,
+        }
+    }
+
+    /// Draw one frame into an off-screen `width`×`height` buffer and return
+    /// it as text, one line per row with trailing spaces trimmed. Lets tests
+    /// check the layout without a terminal.
+    pub fn render_to_string(&self, width: u16, height: u16) -> anyhow::Result<String> {
+        let mut terminal = Terminal::new(TestBackend::new(width, height))?;
+        let state = self.draw_state();
+        terminal.draw(|frame| ui::draw(frame, &state))?;
+        let buffer = terminal.backend().buffer();
+        let rows = buffer.content().chunks(usize::from(width.max(1)));
+        let lines: Vec<String> = rows
+            .map(|row| {
+                let line: String = row.iter().map(|cell| cell.symbol()).collect();
+                line.trim_end().to_string()
+            })
+            .collect();
+        Ok(lines.join("\n"))
     }
 
     pub async fn run(&mut self) -> anyhow::Result<()> {
@@ -184,30 +533,37 @@ impl App {
         // Only load NTS data if onboarding is not active
         if !self.onboarding.is_active() {
             self.action_tx.send(Action::LoadNtsLive)?;
+            self.action_tx.send(Action::RestoreSession)?;
         }
+        self.action_tx.send(Action::CheckFollowedShows)?;
+        self.request_track_lengths();
+        #[cfg(target_os = "linux")]
+        self.start_mpris().await;
+        #[cfg(target_os = "linux")]
+        self.start_sleep_watch().await;
+        self.start_remote().await;
 
         while self.running {
-            let state = ui::DrawState {
-                nts_tab: &self.nts_tab,
-                discovery_list: &self.discovery_list,
-                search_bar: &self.search_bar,
-                now_playing: &self.now_playing,
-                play_controls: &self.play_controls,
-                direct_play_modal: &self.direct_play_modal,
-                seek_modal: &self.seek_modal,
-                onboarding: &self.onboarding,
-                error_message: &self.error_message,
-                show_help: self.show_help,
-                theme: &self.theme,
-            };
-            tui.draw(|frame| ui::draw(frame, &state))?;
+            #[cfg(target_os = "linux")]
+            self.sync_mpris();
+            self.sync_remote();
+            self.sync_terminal_title(&mut tui);
+            self.sync_pinned_track();
+            if self.needs_draw() {
+                let state = self.draw_state();
+                let render_start = Instant::now();
+                tui.draw(|frame| ui::draw(frame, &state))?;
+                self.metrics.record_render(render_start.elapsed());
+                self.frame_drawn();
+            }
 
             tokio::select! {
                 Some(event) = tui.event_rx.recv() => {
-                    match event {
-                        TuiEvent::Key(key) => self.handle_key(key)?,
-                        TuiEvent::Resize => {} // ratatui redraws at correct size automatically
-                        TuiEvent::Tick => { self.action_tx.send(Action::Tick)?; }
+                    self.handle_tui_event(event)?;
+                    // A held key queues repeats faster than frames are drawn;
+                    // take them all now so they cost one redraw, not one each.
+                    while let Ok(event) = tui.event_rx.try_recv() {
+                        self.handle_tui_event(event)?;
                     }
                 }
                 Some(action) = self.action_rx.recv() => {
@@ -219,8 +575,15 @@ impl App {
             while let Ok(action) = self.action_rx.try_recv() {
                 self.handle_action(action).await?;
             }
+
+            let rate = self.wanted_tick_rate();
+            if rate != self.tick_rate() {
+                self.set_tick_rate(rate);
+                tui.set_frame_rate(rate);
+            }
         }
 
+        self.restore_terminal_title(&mut tui);
         tui.exit()?;
         Ok(())
     }
@@ -244,6 +607,32 @@ impl App {
         queue
     }
 
+    fn handle_tui_event(&mut self, event: TuiEvent) -> anyhow::Result<()> {
+        match event {
+            TuiEvent::Key(key) => {
+                self.mark_dirty();
+                // Whatever the user does first wins over the old selection.
+                self.pending_session = None;
+                self.handle_key(key)?;
+            }
+            TuiEvent::Mouse(mouse) => {
+                self.mark_dirty();
+                self.handle_mouse(mouse);
+            }
+            // ratatui redraws at the new size on the next draw
+            TuiEvent::Resize => self.mark_dirty(),
+            TuiEvent::Tick => self.action_tx.send(Action::Tick)?,
+        }
+        Ok(())
+    }
+
+    /// Handle one action from a trace, dropping whatever the app queued
+    /// since the last one: those were recorded separately, when handled.
+    pub async fn replay_action(&mut self, action: Action) -> anyhow::Result<()> {
+        while self.action_rx.try_recv().is_ok() {}
+        self.handle_action(action).await
+    }
+
     #[allow(dead_code)] // used by integration tests
     pub async fn flush_actions(&mut self) {
         while let Ok(action) = self.action_rx.try_recv() {
@@ -0,0 +1,184 @@
+// Vim-style list motions: count prefixes (`5j`, `12G`), `gg`, n/N after an
+// in-list find, and `'` plus a letter to jump to rows starting with it.
+// Digits and a first `g` are held until the next key shows whether they
+// start a motion; if it doesn't, or nothing follows within PREFIX_TIMEOUT,
+// they run as their own bindings (tab jumps, jump to playing).
+
+use std::time::{Duration, Instant};
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+use crate::app::App;
+use crate::keymap::Command;
+
+/// How long held prefix keys wait for a motion.
+const PREFIX_TIMEOUT: Duration = Duration::from_millis(500);
+
+/// Keys held as a possible motion prefix.
+#[derive(Debug, Default)]
+pub(crate) struct PendingKeys {
+    keys: Vec<KeyEvent>,
+    since: Option<Instant>,
+    /// The next key is a letter to jump to.
+    letter: bool,
+}
+
+impl PendingKeys {
+    pub(super) fn await_letter(&mut self) {
+        self.letter = true;
+    }
+
+    fn push(&mut self, key: KeyEvent) {
+        self.keys.push(key);
+        self.since.get_or_insert_with(Instant::now);
+    }
+
+    fn take(&mut self) -> Vec<KeyEvent> {
+        self.since = None;
+        std::mem::take(&mut self.keys)
+    }
+
+    /// The digits typed so far as a count.
+    fn count(&self) -> Option<usize> {
+        let digits: String = self
+            .keys
+            .iter()
+            .filter_map(|key| match key.code {
+                KeyCode::Char(c) if c.is_ascii_digit() => Some(c),
+                _ => None,
+            })
+            .collect();
+        digits.parse().ok()
+    }
+
+    fn ends_with_g(&self) -> bool {
+        self.keys
+            .last()
+            .is_some_and(|key| key.code == KeyCode::Char('g'))
+    }
+}
+
+fn is_plain(key: &KeyEvent) -> bool {
+    key.modifiers == KeyModifiers::NONE
+}
+
+impl App {
+    /// Handle `key` as part of a list motion. Returns false when the key is
+    /// for the normal bindings; held prefix keys have run by then.
+    pub(super) fn handle_motion_key(&mut self, key: KeyEvent) -> anyhow::Result<bool> {
+        if std::mem::take(&mut self.pending_keys.letter) {
+            if let KeyCode::Char(c) = key.code {
+                if c.is_alphanumeric() {
+                    self.discovery_list.jump_to_letter(c);
+                }
+                return Ok(true);
+            }
+        }
+        let has_count = self.pending_keys.count().is_some();
+        let after_g = self.pending_keys.ends_with_g();
+        match key.code {
+            // A leading 0 isn't a count.
+            KeyCode::Char(c)
+                if is_plain(&key) && c.is_ascii_digit() && !after_g && (c != '0' || has_count) =>
+            {
+                self.pending_keys.push(key);
+                return Ok(true);
+            }
+            KeyCode::Char('g') if is_plain(&key) => {
+                if !after_g {
+                    self.pending_keys.push(key);
+                    return Ok(true);
+                }
+                let count = self.pending_keys.count();
+                self.pending_keys.take();
+                self.run_motion(Command::ScrollTop, count);
+                return Ok(true);
+            }
+            _ => {}
+        }
+
+        let count = self.pending_keys.count();
+        if let Some(query_jump) = self.find_jump(&key) {
+            if !after_g {
+                self.pending_keys.take();
+                // A wrapping find gets no further than once round the list.
+                let times = count
+                    .unwrap_or(1)
+                    .min(self.discovery_list.total_item_count());
+                for _ in 0..times {
+                    self.discovery_list.find_next(query_jump);
+                }
+                return Ok(true);
+            }
+        }
+        let command = self.keymap.resolve(&key);
+        if let Some(
+            cmd @ (Command::ScrollDown
+            | Command::ScrollUp
+            | Command::ScrollTop
+            | Command::ScrollBottom
+            | Command::HalfPageDown
+            | Command::HalfPageUp),
+        ) = command
+        {
+            if count.is_some() && !after_g {
+                self.pending_keys.take();
+                self.count_command(cmd);
+                self.run_motion(cmd, count);
+                return Ok(true);
+            }
+        }
+        self.flush_pending_keys()?;
+        Ok(false)
+    }
+
+    /// n/N jump between find matches once a find is confirmed: Some(true)
+    /// for forward.
+    fn find_jump(&self, key: &KeyEvent) -> Option<bool> {
+        self.discovery_list.find_query()?;
+        match key.code {
+            KeyCode::Char('n') if is_plain(key) => Some(true),
+            KeyCode::Char('N') => Some(false),
+            _ => None,
+        }
+    }
+
+    /// Move the list selection for a motion command, `count` times.
+    pub(super) fn run_motion(&mut self, command: Command, count: Option<usize>) {
+        let times = count.unwrap_or(1) as isize;
+        let half = self.discovery_list.half_page() as isize;
+        match command {
+            Command::ScrollDown => self.discovery_list.move_by(times),
+            Command::ScrollUp => self.discovery_list.move_by(-times),
+            Command::HalfPageDown => self.discovery_list.move_by(half * times),
+            Command::HalfPageUp => self.discovery_list.move_by(-half * times),
+            Command::ScrollTop => match count {
+                Some(number) => self.discovery_list.select_number(number),
+                None => self.discovery_list.select_first(),
+            },
+            Command::ScrollBottom => match count {
+                Some(number) => self.discovery_list.select_number(number),
+                None => self.discovery_list.select_last(),
+            },
+            _ => {}
+        }
+    }
+
+    /// Run held prefix keys as their own bindings.
+    pub(super) fn flush_pending_keys(&mut self) -> anyhow::Result<()> {
+        for key in self.pending_keys.take() {
+            if let Some(command) = self.keymap.resolve(&key) {
+                self.run_command(command)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Called every tick: give up waiting for a motion after the timeout.
+    pub(super) fn tick_pending_keys(&mut self) -> anyhow::Result<()> {
+        match self.pending_keys.since {
+            Some(since) if since.elapsed() >= PREFIX_TIMEOUT => self.flush_pending_keys(),
+            _ => Ok(()),
+        }
+    }
+}
@@ -0,0 +1,54 @@
+// MPRIS glue: publishes the queue and playback state to the desktop service.
+
+use crate::app::App;
+use crate::mpris::{MprisState, MprisStatus, MprisTrack};
+
+impl App {
+    /// Register on the session bus. Without one, the app runs without MPRIS.
+    pub(super) async fn start_mpris(&mut self) {
+        self.mpris = crate::mpris::spawn(self.action_tx.clone()).await.ok();
+    }
+
+    /// Current queue and playback state as seen by MPRIS clients.
+    pub fn mpris_state(&self) -> MprisState {
+        let tracks = self
+            .queue
+            .items()
+            .iter()
+            .map(|qi| {
+                let m = qi.stream_metadata.as_ref();
+                let (title, subtitle) = qi.item.display_pair(
+                    m.and_then(|m| m.station_name.as_deref()),
+                    m.and_then(|m| m.display_title()).as_deref(),
+                    m.and_then(|m| m.display_subtitle()).as_deref(),
+                );
+                MprisTrack {
+                    title,
+                    artist: (!subtitle.is_empty()).then_some(subtitle),
+                    url: qi.url.clone(),
+                }
+            })
+            .collect();
+        let status = if !self.now_playing.is_playing() {
+            MprisStatus::Stopped
+        } else if self.now_playing.is_paused() {
+            MprisStatus::Paused
+        } else {
+            MprisStatus::Playing
+        };
+        MprisState {
+            tracks,
+            current: self.queue.current_index(),
+            status,
+            position_secs: self.now_playing.position_secs(),
+            duration_secs: self.seek.duration_secs,
+        }
+    }
+
+    /// Push the latest state to the bus; unchanged state is not re-sent.
+    pub(super) fn sync_mpris(&self) {
+        if let Some(handle) = &self.mpris {
+            handle.update(self.mpris_state());
+        }
+    }
+}
@@ -2,30 +2,29 @@
 
 use crate::action::Action;
 use crate::api::models::DiscoveryItem;
+use crate::app::registry::ComponentId;
 use crate::app::App;
-use crate::components::Component;
+use crate::components::discovery_list::PinnedTrack;
+use crate::components::notifications::Notification;
+use crate::components::nts::NtsSubTab;
+use crate::player::preflight::MpvUnavailable;
 use crate::player::queue::{Queue, QueueItem};
 
 impl App {
     /// Start playing an item: enqueue it, and if nothing is playing, start playback.
     /// For live channels, reuse the existing queue entry instead of adding a duplicate.
     pub(super) async fn play_item(&mut self, item: DiscoveryItem) -> anyhow::Result<()> {
-        let Some(url) = item.playback_url() else {
+        let Some(url) = self.playback_url(&item) else {
             return Ok(());
         };
 
         // Deduplicate live channels: if the same channel is already queued, jump to it.
         if let DiscoveryItem::NtsLiveChannel { channel, .. } = &item {
             if let Some(existing_idx) = self.queue.find_live_channel(*channel) {
+                self.save_resume_position();
                 self.queue.update_live_channel_at(existing_idx, &item);
                 self.queue.play_at(existing_idx);
-                self.sync_play_controls();
-                self.now_playing.update(&Action::PlayItem(item.clone()))?;
-                self.player.play(&url).await?;
-                self.action_tx.send(Action::PlaybackStarted {
-                    title: item.display_title(),
-                })?;
-                self.sync_queue_to_now_playing();
+                self.start_current_track().await?;
                 self.persist_queue();
                 return Ok(());
             }
@@ -35,8 +34,8 @@ impl App {
         let new_index = self.queue.len();
 
         self.queue.add(QueueItem {
-            item: item.clone(),
-            url: url.clone(),
+            item,
+            url,
             stream_metadata: None,
         });
         self.sync_play_controls();
@@ -44,13 +43,7 @@ impl App {
 
         if nothing_playing {
             self.queue.play_at(new_index);
-            self.sync_play_controls();
-            self.now_playing.update(&Action::PlayItem(item.clone()))?;
-            self.player.play(&url).await?;
-            self.action_tx.send(Action::PlaybackStarted {
-                title: item.display_title(),
-            })?;
-            self.sync_queue_to_now_playing();
+            self.start_current_track().await?;
         }
         self.persist_queue();
         Ok(())
@@ -61,6 +54,7 @@ impl App {
         &mut self,
         advance: fn(&mut Queue) -> Option<&QueueItem>,
     ) -> anyhow::Result<()> {
+        self.save_resume_position();
         if advance(&mut self.queue).is_some() {
             self.start_current_track().await?;
             self.persist_queue();
@@ -74,11 +68,11 @@ impl App {
         let Some(idx) = self.queue.current_index() else {
             return Ok(());
         };
+        self.save_resume_position();
         self.queue.remove(idx);
         if self.queue.is_empty() {
             let _ = self.player.stop().await;
-            self.now_playing.update(&Action::PlaybackFinished)?;
-            self.play_controls.update(&Action::PlaybackFinished)?;
+            self.deliver(&ComponentId::PLAYBACK, &Action::PlaybackFinished)?;
         } else {
             self.start_current_track().await?;
         }
@@ -88,46 +82,172 @@ impl App {
         Ok(())
     }
 
-    /// Set up UI state for the current track and start mpv playback.
-    pub(super) async fn start_current_track(&mut self) -> anyhow::Result<()> {
-        let Some(track) = self.queue.current() else {
+    /// Jump to the queue entry at `index` and play it.
+    pub(super) async fn play_queue_index(&mut self, index: usize) -> anyhow::Result<()> {
+        if index >= self.queue.len() {
             return Ok(());
-        };
-        let url = track.url.clone();
-        let title = track.item.display_title();
-        let item = track.item.clone();
+        }
+        self.save_resume_position();
+        self.queue.play_at(index);
+        self.start_current_track().await?;
+        self.persist_queue();
+        Ok(())
+    }
 
+    /// Remove the queue entry at `index`. Removing the current track behaves
+    /// like RemoveFromQueue.
+    pub(super) async fn remove_queue_index(&mut self, index: usize) -> anyhow::Result<()> {
+        if self.queue.current_index() == Some(index) {
+            return self.remove_current_from_queue().await;
+        }
+        if index >= self.queue.len() {
+            return Ok(());
+        }
+        self.queue.remove(index);
         self.sync_play_controls();
-        self.now_playing.set_buffering(item);
-        self.play_controls.set_buffering(true);
         self.sync_queue_to_now_playing();
+        self.persist_queue();
+        Ok(())
+    }
 
-        if let Err(e) = self.player.play(&url).await {
-            self.action_tx.send(Action::ShowError(e.to_string()))?;
+    /// Insert a URL into the queue at `index`, playing it right away if asked.
+    pub(super) async fn insert_queue_url(
+        &mut self,
+        url: String,
+        index: usize,
+        play: bool,
+    ) -> anyhow::Result<()> {
+        let index = index.min(self.queue.len());
+        let item = DiscoveryItem::DirectUrl {
+            url: url.clone(),
+            title: None,
+            meta: None,
+        };
+        self.queue.insert(
+            index,
+            QueueItem {
+                item,
+                url,
+                stream_metadata: None,
+            },
+        );
+        if play {
+            return self.play_queue_index(index).await;
+        }
+        self.sync_play_controls();
+        self.sync_queue_to_now_playing();
+        self.persist_queue();
+        Ok(())
+    }
+
+    /// Set up UI state for the current track and start mpv playback.
+    pub(super) async fn start_current_track(&mut self) -> anyhow::Result<()> {
+        self.begin_current_track(false).await
+    }
+
+    /// Like `start_current_track`, but load the track paused.
+    pub(super) async fn load_current_track_paused(&mut self) -> anyhow::Result<()> {
+        self.begin_current_track(true).await
+    }
+
+    async fn begin_current_track(&mut self, paused: bool) -> anyhow::Result<()> {
+        let Some((url, title)) = self.show_current_track() else {
+            return Ok(());
+        };
+        self.stopped = false;
+        self.seek.pending_pause = paused;
+        let started = if paused {
+            self.player.play_paused(&url).await
+        } else {
+            self.player.play(&url).await
+        };
+        if let Err(e) = started {
+            self.seek.pending_pause = false;
+            if e.is::<MpvUnavailable>() {
+                // Nothing can play until mpv is back; stop retrying.
+                self.cancel_reconnect();
+                self.source_fallback = None;
+            }
+            self.notifications.push(Notification::error(e.to_string()));
         } else {
             self.action_tx.send(Action::PlaybackStarted { title })?;
         }
         Ok(())
     }
 
+    /// Point the UI at the current track, buffering; returns its URL and
+    /// title for the player.
+    pub(super) fn show_current_track(&mut self) -> Option<(String, String)> {
+        let track = self.queue.current()?;
+        let (item, url) = (track.item.clone(), track.url.clone());
+        self.playback_failure = None;
+        self.crossfade = Default::default();
+        self.prepare_source_fallback(&item);
+        let title = item.display_title();
+
+        self.sync_play_controls();
+        self.now_playing.set_buffering(item);
+        self.play_controls.set_buffering(true);
+        self.sync_queue_to_now_playing();
+        Some((url, title))
+    }
+
+    /// The new level comes back from mpv as VolumeChanged.
     pub(super) async fn adjust_volume(&mut self, delta: f64) -> anyhow::Result<()> {
         let _ = self.player.set_volume(delta).await;
-        if let Ok(vol) = self.player.get_volume().await {
-            self.action_tx
-                .send(Action::VolumeChanged(vol.round().clamp(0.0, 100.0) as u8))?;
-        }
         Ok(())
     }
 
     pub(super) fn enqueue(&mut self, item: DiscoveryItem, insert_next: bool) {
+        self.enqueue_many(vec![item], insert_next);
+    }
+
+    /// Queue the items marked in the list, clear the marks, and say how many
+    /// went in.
+    pub(super) fn enqueue_marked(&mut self, items: Vec<DiscoveryItem>, insert_next: bool) {
+        self.discovery_list.clear_marks();
+        let total = items.len();
+        let added = self.enqueue_many(items, insert_next);
+        let place = if insert_next { " to play next" } else { "" };
+        let message = if added == total {
+            format!("Queued {total} items{place}")
+        } else {
+            format!("Queued {added} of {total} items{place} (the rest already were)")
+        };
+        self.notifications.push(Notification::info(message));
+    }
+
+    /// Add several items in order, either at the end or right after the
+    /// current track. Returns how many were added.
+    pub(super) fn enqueue_many(&mut self, items: Vec<DiscoveryItem>, insert_next: bool) -> usize {
+        // add_next inserts directly after current, so go backwards to keep order.
+        let items: Vec<_> = if insert_next {
+            items.into_iter().rev().collect()
+        } else {
+            items
+        };
+        let mut added = 0;
+        for item in items {
+            if self.push_to_queue(item, insert_next) {
+                added += 1;
+            }
+        }
+        self.sync_play_controls();
+        self.sync_queue_to_now_playing();
+        self.persist_queue();
+        added
+    }
+
+    /// Queue `item`; false if it's a live channel already in the queue.
+    pub(super) fn push_to_queue(&mut self, item: DiscoveryItem, insert_next: bool) -> bool {
         // Skip if this live channel is already in the queue.
         if let DiscoveryItem::NtsLiveChannel { channel, .. } = &item {
             if self.queue.find_live_channel(*channel).is_some() {
-                return;
+                return false;
             }
         }
 
-        let url = item.playback_url().unwrap_or_default();
+        let url = self.playback_url(&item).unwrap_or_default();
         let qi = QueueItem {
             item,
             url,
@@ -138,9 +258,38 @@ impl App {
         } else {
             self.queue.add(qi);
         }
-        self.sync_play_controls();
-        self.sync_queue_to_now_playing();
-        self.persist_queue();
+        true
+    }
+
+    /// Refresh the favorite markers shown in the discovery list, and the
+    /// favorites view if it is open.
+    pub(super) fn sync_favorites(&mut self) {
+        if let Ok(keys) = self.db.favorite_keys() {
+            self.discovery_list.set_favorites(keys);
+        }
+        if let Ok(count) = self.db.favorite_count() {
+            self.nts_tab.set_count(NtsSubTab::Favorites, count);
+        }
+        if self.favorites_view.is_visible() {
+            if let Ok(favorites) = self.db.favorites() {
+                self.favorites_view.set_favorites(favorites);
+            }
+        }
+        if self.nts_tab.active_sub() == NtsSubTab::Favorites {
+            let _ = self.show_favorites_tab();
+        } else {
+            self.forget_tab(NtsSubTab::Favorites);
+        }
+    }
+
+    /// List favorites in their managed order on the Favorites tab.
+    pub(super) fn show_favorites_tab(&mut self) -> anyhow::Result<()> {
+        let favorites = self.db.favorites()?;
+        self.nts_tab
+            .set_count(NtsSubTab::Favorites, favorites.len());
+        self.discovery_list
+            .set_items(favorites.into_iter().map(|f| f.item).collect());
+        Ok(())
     }
 
     pub(super) fn sync_play_controls(&mut self) {
@@ -149,20 +298,36 @@ impl App {
     }
 
     pub(super) fn sync_queue_to_now_playing(&mut self) {
-        let items: Vec<(String, String)> = self
-            .queue
-            .items()
-            .iter()
-            .map(|qi| {
-                let m = qi.stream_metadata.as_ref();
-                qi.item.display_pair(
-                    m.and_then(|m| m.station_name.as_deref()),
-                    m.and_then(|m| m.display_title()).as_deref(),
-                    m.and_then(|m| m.display_subtitle()).as_deref(),
-                )
+        self.show_queue();
+        self.request_track_lengths();
+        self.request_url_metadata();
+        self.top_up_radio();
+    }
+
+    /// Keep the list's pinned now-playing row in step with the player.
+    pub(super) fn sync_pinned_track(&mut self) {
+        let pinned = self.playing_title().and_then(|title| {
+            Some(PinnedTrack {
+                key: self.queue.current()?.item.favorite_key(),
+                title,
+                position_secs: self.now_playing.position_secs(),
             })
-            .collect();
-        self.now_playing
-            .set_queue(items, self.queue.current_index());
+        });
+        self.discovery_list.set_pinned(pinned);
+    }
+
+    /// Select the playing item in the list, or say it isn't there.
+    pub(super) fn jump_to_playing(&mut self) -> anyhow::Result<()> {
+        let Some(title) = self.playing_title() else {
+            return Ok(());
+        };
+        if !self.discovery_list.select_pinned() {
+            self.action_tx
+                .send(Action::Notify(Notification::info(format!(
+                    "{} isn't in this list",
+                    title
+                ))))?;
+        }
+        Ok(())
     }
 }
@@ -0,0 +1,141 @@
+// Named playlists: the manager overlay, the Playlists tab (which shows one
+// playlist at a time), and loading a playlist into the queue.
+
+use crate::action::Action;
+use crate::api::models::DiscoveryItem;
+use crate::app::App;
+use crate::components::notifications::Notification;
+use crate::components::nts::NtsSubTab;
+
+impl App {
+    pub(super) fn create_playlist(&mut self, name: &str) -> anyhow::Result<()> {
+        match self.db.create_playlist(name) {
+            Ok(id) => {
+                self.sync_playlists()?;
+                self.playlists_view.select_id(id);
+            }
+            Err(e) => self.notifications.push(Notification::error(e.to_string())),
+        }
+        Ok(())
+    }
+
+    pub(super) fn rename_playlist(&mut self, id: i64, name: &str) -> anyhow::Result<()> {
+        match self.db.rename_playlist(id, name) {
+            Ok(()) => self.sync_playlists()?,
+            Err(e) => self.notifications.push(Notification::error(e.to_string())),
+        }
+        Ok(())
+    }
+
+    pub(super) fn delete_playlist(&mut self, id: i64) -> anyhow::Result<()> {
+        self.db.delete_playlist(id)?;
+        if self.open_playlist == Some(id) {
+            self.open_playlist = None;
+        }
+        self.sync_playlists()
+    }
+
+    /// Show a playlist on the Playlists tab, switching to it if needed.
+    pub(super) fn open_playlist(&mut self, id: i64) -> anyhow::Result<()> {
+        self.open_playlist = Some(id);
+        if self.nts_tab.active_sub() == NtsSubTab::Playlists {
+            return self.show_playlists_tab();
+        }
+        self.forget_tab(NtsSubTab::Playlists);
+        let index = NtsSubTab::ALL
+            .iter()
+            .position(|t| *t == NtsSubTab::Playlists)
+            .unwrap_or_default();
+        self.action_tx.send(Action::SwitchSubTab(index))?;
+        Ok(())
+    }
+
+    pub(super) fn add_to_playlist(
+        &mut self,
+        id: i64,
+        items: Vec<DiscoveryItem>,
+    ) -> anyhow::Result<()> {
+        let added = self.db.add_to_playlist(id, &items)?;
+        self.sync_playlists()?;
+        let name = self.playlist_name(id)?.unwrap_or_default();
+        self.notifications.push(Notification::info(format!(
+            "Added {} of {} to {}",
+            added,
+            items.len(),
+            name
+        )));
+        Ok(())
+    }
+
+    fn playlist_name(&self, id: i64) -> anyhow::Result<Option<String>> {
+        Ok(self
+            .db
+            .playlists()?
+            .into_iter()
+            .find(|p| p.id == id)
+            .map(|p| p.name))
+    }
+
+    /// List the open playlist (or the first one) on the Playlists tab.
+    pub(super) fn show_playlists_tab(&mut self) -> anyhow::Result<()> {
+        let playlists = self.db.playlists()?;
+        self.nts_tab
+            .set_count(NtsSubTab::Playlists, playlists.len());
+        let open = playlists
+            .iter()
+            .find(|p| Some(p.id) == self.open_playlist)
+            .or(playlists.first());
+        let Some(open) = open else {
+            self.open_playlist = None;
+            self.discovery_list.set_items(vec![]);
+            self.discovery_list.set_heading(None);
+            return Ok(());
+        };
+        self.open_playlist = Some(open.id);
+        let items = self.db.playlist_items(open.id)?;
+        self.discovery_list.set_heading(Some(format!(
+            "{} · {} items · P for all playlists",
+            open.name,
+            items.len()
+        )));
+        self.discovery_list.set_items(items);
+        Ok(())
+    }
+
+    /// Refresh the Playlists tab count, and the overlay and tab when open.
+    fn sync_playlists(&mut self) -> anyhow::Result<()> {
+        let playlists = self.db.playlists()?;
+        self.nts_tab
+            .set_count(NtsSubTab::Playlists, playlists.len());
+        if self.playlists_view.is_visible() {
+            self.playlists_view.set_playlists(playlists);
+        }
+        if self.nts_tab.active_sub() == NtsSubTab::Playlists {
+            self.show_playlists_tab()?;
+        } else {
+            self.forget_tab(NtsSubTab::Playlists);
+        }
+        Ok(())
+    }
+
+    /// Replace the queue with a playlist's items and play from the top.
+    pub(super) fn load_playlist(&mut self, id: i64) -> anyhow::Result<()> {
+        let items: Vec<DiscoveryItem> = self.db.playlist_items(id)?;
+        if items.is_empty() {
+            self.notifications
+                .push(Notification::info("That playlist is empty"));
+            return Ok(());
+        }
+        self.queue.clear();
+        self.enqueue_many(items, false);
+        self.action_tx.send(Action::PlayQueueIndex(0))?;
+        if let Some(name) = self.playlist_name(id)? {
+            self.notifications.push(Notification::info(format!(
+                "Loaded {} ({} items)",
+                name,
+                self.queue.len()
+            )));
+        }
+        Ok(())
+    }
+}
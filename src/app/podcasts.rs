@@ -0,0 +1,217 @@
+// Podcasts tab: episodes of the subscribed RSS and Atom feeds, newest first.
+// Episodes not played yet carry a dot in the list and are counted on the tab;
+// starting one marks it played.
+
+use std::collections::BTreeMap;
+
+use crate::action::Action;
+use crate::api::models::DiscoveryItem;
+use crate::api::nts::Fetched;
+use crate::app::App;
+use crate::components::notifications::Notification;
+use crate::components::nts::NtsSubTab;
+
+// Newest episodes listed from each feed.
+const EPISODES_PER_FEED: usize = 100;
+
+fn published(item: &DiscoveryItem) -> Option<&str> {
+    match item {
+        DiscoveryItem::PodcastEpisode { published, .. } => published.as_deref(),
+        _ => None,
+    }
+}
+
+impl App {
+    /// Fetch every subscribed feed.
+    pub(super) fn load_podcasts(&mut self) -> anyhow::Result<()> {
+        let feeds = self.db.podcast_feeds()?;
+        if feeds.is_empty() {
+            self.action_tx.send(Action::PodcastsLoaded(vec![]))?;
+            return Ok(());
+        }
+        let client = self.nts_client.clone();
+        self.spawn_fetch(
+            async move {
+                let mut episodes = Vec::new();
+                let mut loaded = false;
+                let mut error = None;
+                for feed in &feeds {
+                    match client.fetch_podcast(&feed.url).await {
+                        Ok(podcast) => {
+                            loaded = true;
+                            episodes.extend(podcast.episodes.into_iter().take(EPISODES_PER_FEED));
+                        }
+                        Err(e) => {
+                            tracing::warn!("couldn't load the feed {}: {e}", feed.url);
+                            error = Some(e);
+                        }
+                    }
+                }
+                // Whatever loaded is shown; only a complete failure is one.
+                if let (false, Some(e)) = (loaded, error) {
+                    return Err(e);
+                }
+                // Newest first; undated episodes last.
+                episodes.sort_by(|a, b| published(b).cmp(&published(a)));
+                Ok(Fetched {
+                    value: episodes,
+                    stale: None,
+                })
+            },
+            NtsSubTab::Podcasts,
+            Action::PodcastsLoaded,
+        );
+        Ok(())
+    }
+
+    /// List the episodes, headed by the podcasts they're from, and work out
+    /// which haven't been played.
+    pub(super) fn podcasts_loaded(&mut self, items: Vec<DiscoveryItem>) {
+        self.fetch_succeeded(NtsSubTab::Podcasts);
+        let titles: BTreeMap<&str, &str> = items
+            .iter()
+            .filter_map(|item| match item {
+                DiscoveryItem::PodcastEpisode { feed_url, show, .. } => {
+                    Some((feed_url.as_str(), show.as_str()))
+                }
+                _ => None,
+            })
+            .collect();
+        for (url, title) in titles {
+            let _ = self.db.set_podcast_title(url, title);
+        }
+        let played = self.db.podcast_played_keys().unwrap_or_default();
+        self.podcast_unplayed = items
+            .iter()
+            .map(DiscoveryItem::favorite_key)
+            .filter(|key| !played.contains(key))
+            .collect();
+        self.sync_podcast_markers();
+
+        let names: Vec<String> = self
+            .db
+            .podcast_feeds()
+            .unwrap_or_default()
+            .into_iter()
+            .map(|feed| feed.title.unwrap_or(feed.url))
+            .collect();
+        let heading = match names.as_slice() {
+            [] => None,
+            [only] => Some(format!("Subscribed to {only}")),
+            [rest @ .., last] => Some(format!("Subscribed to {} and {last}", rest.join(", "))),
+        };
+        if self.nts_tab.active_sub() == NtsSubTab::Podcasts {
+            self.discovery_list.set_items(items);
+            self.discovery_list.set_heading(heading);
+        } else if let Some(list) = self.stashed_list(NtsSubTab::Podcasts) {
+            list.replace_items(items);
+            list.set_heading(heading);
+        }
+    }
+
+    pub(super) fn subscribe_podcast(&mut self, url: String) -> anyhow::Result<()> {
+        let message = if self.db.add_podcast_feed(&url)? {
+            format!("Subscribed to {url}")
+        } else {
+            format!("Already subscribed to {url}")
+        };
+        self.notifications.push(Notification::info(message));
+        self.reload_podcasts()
+    }
+
+    pub(super) fn unsubscribe_podcast(&mut self, url: &str) -> anyhow::Result<()> {
+        let title = self
+            .db
+            .podcast_feeds()?
+            .into_iter()
+            .find(|feed| feed.url == url)
+            .and_then(|feed| feed.title)
+            .unwrap_or_else(|| url.to_string());
+        self.db.remove_podcast_feed(url)?;
+        let prefix = format!("podcast:{url}#");
+        self.podcast_unplayed
+            .retain(|key| !key.starts_with(&prefix));
+        self.sync_podcast_markers();
+        self.notifications
+            .push(Notification::info(format!("Unsubscribed from {title}")));
+        self.reload_podcasts()
+    }
+
+    pub(super) fn set_podcast_played(&mut self, key: &str, played: bool) -> anyhow::Result<()> {
+        self.db.set_podcast_played(key, played)?;
+        if played {
+            self.podcast_unplayed.remove(key);
+        } else {
+            self.podcast_unplayed.insert(key.to_string());
+        }
+        self.sync_podcast_markers();
+        Ok(())
+    }
+
+    /// Mark `item` played when it's a podcast episode that starts playing.
+    pub(super) fn note_podcast_played(&mut self, item: &DiscoveryItem) {
+        if !matches!(item, DiscoveryItem::PodcastEpisode { .. }) {
+            return;
+        }
+        if let Err(e) = self.set_podcast_played(&item.favorite_key(), true) {
+            tracing::warn!("couldn't mark {} as played: {e}", item.title());
+        }
+    }
+
+    /// Context menu entries for a podcast episode: played state and the feed.
+    pub(super) fn podcast_menu_actions(&self, item: &DiscoveryItem) -> Vec<(String, Action)> {
+        let DiscoveryItem::PodcastEpisode { feed_url, show, .. } = item else {
+            return vec![];
+        };
+        let key = item.favorite_key();
+        let played = self
+            .db
+            .podcast_played_keys()
+            .is_ok_and(|keys| keys.contains(&key));
+        let subscribed = self
+            .db
+            .podcast_feeds()
+            .is_ok_and(|feeds| feeds.iter().any(|feed| feed.url == *feed_url));
+        let mut actions = vec![(
+            if played {
+                "Mark as unplayed"
+            } else {
+                "Mark as played"
+            }
+            .to_string(),
+            Action::SetPodcastPlayed {
+                key,
+                played: !played,
+            },
+        )];
+        if subscribed {
+            actions.push((
+                format!("Unsubscribe from {show}"),
+                Action::Confirm {
+                    prompt: format!("Unsubscribe from \"{show}\"?"),
+                    action: Box::new(Action::UnsubscribePodcast(feed_url.clone())),
+                },
+            ));
+        }
+        actions
+    }
+
+    /// Refresh the unplayed dots in the list and the count on the tab.
+    fn sync_podcast_markers(&mut self) {
+        self.nts_tab
+            .set_count(NtsSubTab::Podcasts, self.podcast_unplayed.len());
+        self.discovery_list
+            .set_unplayed(self.podcast_unplayed.clone());
+    }
+
+    /// Load the tab again now if it's open, or on the next visit.
+    fn reload_podcasts(&mut self) -> anyhow::Result<()> {
+        if self.nts_tab.active_sub() == NtsSubTab::Podcasts {
+            self.discovery_list.set_loading(true);
+            self.action_tx.send(Action::LoadPodcasts)?;
+        } else {
+            self.forget_tab(NtsSubTab::Podcasts);
+        }
+        Ok(())
+    }
+}
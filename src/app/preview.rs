@@ -0,0 +1,53 @@
+// Preview (`Alt+p`): play the selected item for a few seconds in a second mpv
+// while the current track is turned down, then stop by itself, to peek at the
+// other live channel or an episode without losing your place.
+
+use crate::api::models::DiscoveryItem;
+use crate::app::App;
+use crate::components::notifications::Notification;
+use crate::player::preview::PREVIEW_SECS;
+
+impl App {
+    /// Start previewing `item`, or stop if it is the one previewing.
+    pub(super) async fn toggle_preview(&mut self, item: &DiscoveryItem) -> anyhow::Result<()> {
+        let Some(url) = item.playback_url() else {
+            self.notifications.push(Notification::info(format!(
+                "Nothing to preview for {}",
+                item.title()
+            )));
+            return Ok(());
+        };
+        if self.player.preview_url().as_deref() == Some(url.as_str()) {
+            self.stop_preview().await;
+            return Ok(());
+        }
+        if let Err(e) = self.player.start_preview(&url).await {
+            self.notifications
+                .push(Notification::error(format!("Preview failed: {e}")));
+            return Ok(());
+        }
+        self.preview_ticks = Some(0);
+        self.notifications.push(Notification::info(format!(
+            "Previewing {} for {}s",
+            item.title(),
+            PREVIEW_SECS
+        )));
+        Ok(())
+    }
+
+    /// Called every tick; ends the preview once its time is up.
+    pub(super) async fn tick_preview(&mut self) {
+        let Some(ticks) = self.preview_ticks.as_mut() else {
+            return;
+        };
+        *ticks += 1;
+        if f64::from(*ticks) >= self.tick_rate() * PREVIEW_SECS {
+            self.stop_preview().await;
+        }
+    }
+
+    async fn stop_preview(&mut self) {
+        self.preview_ticks = None;
+        let _ = self.player.stop_preview().await;
+    }
+}
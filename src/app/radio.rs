@@ -0,0 +1,125 @@
+// Radio mode: when the queue reaches its last entry, episodes sharing that
+// entry's genres are appended so playback carries on. Anything already
+// played or queued is left out, and the entries radio added are marked in
+// the queue for the rest of the session.
+
+use std::collections::HashSet;
+
+use crate::action::Action;
+use crate::api::models::DiscoveryItem;
+use crate::app::App;
+use crate::components::notifications::Notification;
+
+// Episodes appended each time the queue runs dry.
+const RADIO_BATCH: usize = 3;
+// Search results looked through for episodes not yet played or queued.
+const RADIO_SEARCH_LIMIT: u64 = 50;
+// Plays checked when leaving already-heard episodes out.
+const PLAYED_LOOKBACK: usize = 1000;
+
+impl App {
+    pub(super) fn toggle_radio(&mut self) {
+        let on = !self.config.general.radio_mode;
+        self.config.general.radio_mode = on;
+        self.play_controls.set_radio_mode(on);
+        self.radio_seed = None;
+        self.save_config_async();
+        self.notifications.push(Notification::info(if on {
+            "Radio on: related episodes are queued when the queue runs out"
+        } else {
+            "Radio off"
+        }));
+        self.top_up_radio();
+    }
+
+    /// With radio on and the last queue entry playing, look up its genres
+    /// and search for episodes to follow it. Asks once per entry.
+    pub(super) fn top_up_radio(&mut self) {
+        if !self.config.general.radio_mode {
+            return;
+        }
+        let Some(current) = self.queue.current_index() else {
+            return;
+        };
+        if current + 1 < self.queue.len() {
+            return;
+        }
+        let Some(item) = self.queue.current().map(|track| track.item.clone()) else {
+            return;
+        };
+        // Live channels run on; the queue never gets past them.
+        if !matches!(item, DiscoveryItem::NtsEpisode { .. }) {
+            return;
+        }
+        let key = item.favorite_key();
+        if self.radio_seed.as_ref() == Some(&key) {
+            return;
+        }
+        let Some(fetch) = self.detail_fetch(&item) else {
+            return;
+        };
+        self.radio_seed = Some(key);
+        let client = self.nts_client.clone();
+        let tx = self.action_tx.clone();
+        tokio::spawn(async move {
+            let Some(detail) = fetch.await else {
+                return;
+            };
+            let Some(genre) = detail.genres.iter().flatten().next() else {
+                return;
+            };
+            let genre_name = genre.value.clone();
+            let (genre_ids, _) = detail.tag_ids();
+            let Ok(page) = client
+                .search_episodes_like(&genre_ids, &[], 0, RADIO_SEARCH_LIMIT)
+                .await
+            else {
+                return;
+            };
+            tx.send(Action::RadioEpisodesLoaded {
+                genre: genre_name,
+                items: page.items,
+            })
+            .ok();
+        });
+    }
+
+    /// Queue the first few of `items` not yet played or queued.
+    pub(super) fn radio_episodes_loaded(&mut self, genre: String, items: Vec<DiscoveryItem>) {
+        if !self.config.general.radio_mode {
+            return;
+        }
+        let mut skip: HashSet<String> = self
+            .db
+            .history(PLAYED_LOOKBACK)
+            .unwrap_or_default()
+            .into_iter()
+            .map(|entry| entry.key)
+            .collect();
+        skip.extend(self.queue.items().iter().map(|qi| qi.item.favorite_key()));
+        let picked: Vec<DiscoveryItem> = items
+            .into_iter()
+            .filter(|item| matches!(item, DiscoveryItem::NtsEpisode { .. }))
+            .filter(|item| skip.insert(item.favorite_key()))
+            .take(RADIO_BATCH)
+            .collect();
+        if picked.is_empty() {
+            self.notifications.push(Notification::info(format!(
+                "Radio: no unplayed {genre} episodes left to queue"
+            )));
+            return;
+        }
+        let count = picked.len();
+        for item in picked {
+            self.radio_keys.insert(item.favorite_key());
+            self.push_to_queue(item, false);
+        }
+        self.sync_play_controls();
+        self.sync_queue_to_now_playing();
+        self.persist_queue();
+        let noun = if count == 1 { "episode" } else { "episodes" };
+        self.notifications.push(Notification::info(format!(
+            "Radio: queued {count} more {genre} {noun}"
+        )));
+    }
+}
@@ -0,0 +1,116 @@
+// Live stream reconnection: a live channel never ends on its own, so when
+// playback finishes anyway (mpv died, the network dropped) the stream is
+// restarted with growing pauses until it plays again or the attempts run out.
+
+use crate::api::models::DiscoveryItem;
+use crate::app::App;
+use crate::components::notifications::Notification;
+
+// Restarts tried before giving up on a dropped stream.
+const MAX_ATTEMPTS: u32 = 6;
+// Pause before the first restart; doubles for each one after, up to the cap.
+const BASE_DELAY_SECS: f64 = 2.0;
+const MAX_DELAY_SECS: f64 = 60.0;
+// Seconds of playback after a restart that count as reconnected.
+const RECONNECTED_AFTER_SECS: f64 = 5.0;
+
+/// A dropped live stream waiting to be restarted.
+pub(crate) struct Reconnect {
+    /// favorite_key() of the channel being reconnected.
+    key: String,
+    attempts: u32,
+    /// Ticks until the next restart; None once it has been started.
+    ticks_left: Option<u32>,
+}
+
+impl App {
+    /// Called on PlaybackFinished. If the current track is a live channel,
+    /// schedule a restart and return true so the queue does not advance.
+    pub(super) fn schedule_reconnect(&mut self) -> bool {
+        if self.stopped {
+            return false;
+        }
+        let Some(key) = self
+            .queue
+            .current()
+            .filter(|q| matches!(q.item, DiscoveryItem::NtsLiveChannel { .. }))
+            .map(|q| q.item.favorite_key())
+        else {
+            self.cancel_reconnect();
+            return false;
+        };
+        let attempts = match &self.reconnect {
+            Some(r) if r.key == key => r.attempts + 1,
+            _ => 1,
+        };
+        if attempts > MAX_ATTEMPTS {
+            self.cancel_reconnect();
+            self.notifications.push(Notification::error(format!(
+                "Live stream lost — gave up after {MAX_ATTEMPTS} reconnection attempts"
+            )));
+            return false;
+        }
+        if attempts == 1 {
+            self.notifications
+                .push(Notification::warn("Live stream dropped — reconnecting…"));
+        }
+        let delay = (BASE_DELAY_SECS * 2f64.powi(attempts as i32 - 1)).min(MAX_DELAY_SECS);
+        self.reconnect = Some(Reconnect {
+            key,
+            attempts,
+            ticks_left: Some((self.tick_rate() * delay) as u32),
+        });
+        self.play_controls.set_reconnecting(Some(attempts));
+        true
+    }
+
+    /// Called every tick: restart the stream once its pause is over. Gives up
+    /// quietly if something else started playing meanwhile.
+    pub(super) async fn tick_reconnect(&mut self) -> anyhow::Result<()> {
+        let current_key = self.queue.current().map(|q| q.item.favorite_key());
+        let Some(reconnect) = self.reconnect.as_mut() else {
+            return Ok(());
+        };
+        if current_key.as_deref() != Some(reconnect.key.as_str()) {
+            self.cancel_reconnect();
+            return Ok(());
+        }
+        match reconnect.ticks_left {
+            Some(0) => {
+                reconnect.ticks_left = None;
+                self.health.record_reconnect();
+                self.source_fallback = None;
+                self.seek.reset();
+                self.start_current_track().await?;
+            }
+            Some(ref mut ticks) => *ticks -= 1,
+            None => {}
+        }
+        Ok(())
+    }
+
+    /// Playback moved: once a restarted stream has played a little, it's back.
+    pub(super) fn confirm_reconnect(&mut self, pos: f64) {
+        let restarted = self
+            .reconnect
+            .as_ref()
+            .is_some_and(|r| r.ticks_left.is_none());
+        if restarted && pos >= RECONNECTED_AFTER_SECS {
+            self.cancel_reconnect();
+            self.notifications.push(Notification::info("Reconnected"));
+        }
+    }
+
+    /// Stop waiting to reconnect, e.g. when the user stops playback.
+    pub(super) fn cancel_reconnect(&mut self) {
+        if self.reconnect.take().is_some() {
+            self.play_controls.set_reconnecting(None);
+        }
+    }
+
+    /// Restart attempts made for the dropped stream, if one is reconnecting.
+    #[allow(dead_code)] // used by integration tests
+    pub fn reconnect_attempts(&self) -> Option<u32> {
+        self.reconnect.as_ref().map(|r| r.attempts)
+    }
+}
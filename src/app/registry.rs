@@ -0,0 +1,125 @@
+// Component registry: every component the App owns, by id, so an action can
+// be broadcast to the components that follow the app's state or delivered to
+// a chosen few without naming each field. Components stay typed fields of
+// App; a new one is a ComponentId variant and a line in `component_mut`.
+
+use crate::action::Action;
+use crate::app::App;
+use crate::components::Component;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ComponentId {
+    NtsTab,
+    DiscoveryList,
+    SearchBar,
+    NowPlaying,
+    PlayControls,
+    Notifications,
+    DirectPlay,
+    Seek,
+    Equalizer,
+    Onboarding,
+    Stats,
+    About,
+    Favorites,
+    History,
+    FilePicker,
+    Playlists,
+    Tracklist,
+    Info,
+    Logs,
+    ContextMenu,
+    Confirm,
+}
+
+impl ComponentId {
+    pub(crate) const ALL: [ComponentId; 21] = [
+        Self::NtsTab,
+        Self::DiscoveryList,
+        Self::SearchBar,
+        Self::NowPlaying,
+        Self::PlayControls,
+        Self::Notifications,
+        Self::DirectPlay,
+        Self::Seek,
+        Self::Equalizer,
+        Self::Onboarding,
+        Self::Stats,
+        Self::About,
+        Self::Favorites,
+        Self::History,
+        Self::FilePicker,
+        Self::Playlists,
+        Self::Tracklist,
+        Self::Info,
+        Self::Logs,
+        Self::ContextMenu,
+        Self::Confirm,
+    ];
+
+    /// Components that get every Tick and every action the App doesn't
+    /// handle itself. Overlays are driven directly instead.
+    pub(crate) const BROADCAST: [ComponentId; 6] = [
+        Self::NtsTab,
+        Self::DiscoveryList,
+        Self::SearchBar,
+        Self::NowPlaying,
+        Self::PlayControls,
+        Self::Notifications,
+    ];
+
+    /// The views of the playing track: the now-playing pane and the controls.
+    pub(crate) const PLAYBACK: [ComponentId; 2] = [Self::NowPlaying, Self::PlayControls];
+}
+
+impl App {
+    pub(super) fn component_mut(&mut self, id: ComponentId) -> &mut dyn Component {
+        match id {
+            ComponentId::NtsTab => &mut self.nts_tab,
+            ComponentId::DiscoveryList => &mut self.discovery_list,
+            ComponentId::SearchBar => &mut self.search_bar,
+            ComponentId::NowPlaying => &mut self.now_playing,
+            ComponentId::PlayControls => &mut self.play_controls,
+            ComponentId::Notifications => &mut self.notifications,
+            ComponentId::DirectPlay => &mut self.direct_play_modal,
+            ComponentId::Seek => &mut self.seek_modal,
+            ComponentId::Equalizer => &mut self.equalizer_modal,
+            ComponentId::Onboarding => &mut self.onboarding,
+            ComponentId::Stats => &mut self.stats_view,
+            ComponentId::About => &mut self.about_view,
+            ComponentId::Favorites => &mut self.favorites_view,
+            ComponentId::History => &mut self.history_view,
+            ComponentId::FilePicker => &mut self.file_picker,
+            ComponentId::Playlists => &mut self.playlists_view,
+            ComponentId::Tracklist => &mut self.tracklist_view,
+            ComponentId::Info => &mut self.info_view,
+            ComponentId::Logs => &mut self.log_view,
+            ComponentId::ContextMenu => &mut self.context_menu,
+            ComponentId::Confirm => &mut self.confirm_modal,
+        }
+    }
+
+    /// Give every component the action sender.
+    pub(super) fn register_components(&mut self) {
+        for id in ComponentId::ALL {
+            let tx = self.action_tx.clone();
+            self.component_mut(id).register_action_handler(tx);
+        }
+    }
+
+    /// Deliver `action` to the components `ids`, queueing the follow-up
+    /// actions they return.
+    pub(super) fn deliver(&mut self, ids: &[ComponentId], action: &Action) -> anyhow::Result<()> {
+        for &id in ids {
+            for follow_up in self.component_mut(id).update(action)? {
+                self.action_tx.send(follow_up)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Deliver `action` to the BROADCAST components.
+    pub(super) fn broadcast(&mut self, action: &Action) -> anyhow::Result<()> {
+        self.deliver(&ComponentId::BROADCAST, action)
+    }
+}
@@ -0,0 +1,89 @@
+// "More like this": episodes sharing genres and moods with an episode or a
+// live show, leaving out that episode and everything already played.
+
+use std::sync::Arc;
+
+use crate::action::Action;
+use crate::api::models::DiscoveryItem;
+use crate::app::App;
+use crate::components::notifications::Notification;
+use crate::components::nts::NtsSubTab;
+
+// Plays checked when leaving already-heard episodes out of the results.
+const PLAYED_LOOKBACK: usize = 1000;
+
+impl App {
+    /// Look up the genres and moods of `item`, then search for episodes
+    /// that share them.
+    pub(super) fn more_like_this(&mut self, item: DiscoveryItem) {
+        let Some(fetch) = self.detail_fetch(&item) else {
+            self.notifications.push(Notification::info(
+                "More like this works on NTS episodes and live shows",
+            ));
+            return;
+        };
+        let title = item.title().to_string();
+        let key = item.favorite_key();
+        let tx = self.action_tx.clone();
+        tokio::spawn(async move {
+            let action = match fetch.await {
+                Some(detail) => {
+                    let (genre_ids, mood_ids) = detail.tag_ids();
+                    Action::SearchRelated {
+                        title,
+                        key,
+                        genre_ids,
+                        mood_ids,
+                    }
+                }
+                None => Action::Notify(Notification::error(format!(
+                    "Couldn't look up the genres of {title}"
+                ))),
+            };
+            tx.send(action).ok();
+        });
+    }
+
+    /// Show episodes tagged like the episode `key` on the Search tab.
+    pub(super) fn search_related(
+        &mut self,
+        title: String,
+        key: String,
+        genre_ids: Vec<String>,
+        mood_ids: Vec<String>,
+    ) {
+        if genre_ids.is_empty() && mood_ids.is_empty() {
+            self.notifications.push(Notification::info(format!(
+                "{title} has no genres or moods to go on"
+            )));
+            return;
+        }
+        if self.nts_tab.active_sub() != NtsSubTab::Search {
+            self.stash_active_tab();
+            self.nts_tab.switch_sub_tab(2);
+        }
+        self.forget_tab(NtsSubTab::Search);
+        self.viewing_genre_results = true;
+        let client = self.nts_client.clone();
+        let (genre_ids, mood_ids) = (Arc::new(genre_ids), Arc::new(mood_ids));
+        self.start_paged_search(Arc::new(move |offset, limit| {
+            let client = client.clone();
+            let (genre_ids, mood_ids) = (genre_ids.clone(), mood_ids.clone());
+            Box::pin(async move {
+                client
+                    .search_episodes_like(&genre_ids, &mood_ids, offset, limit)
+                    .await
+            })
+        }));
+        self.discovery_list
+            .set_heading(Some(format!("More like {title}")));
+        self.search_exclude = self
+            .db
+            .history(PLAYED_LOOKBACK)
+            .unwrap_or_default()
+            .into_iter()
+            .map(|entry| entry.key)
+            .collect();
+        self.search_exclude.insert(key);
+    }
+}
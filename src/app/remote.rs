@@ -0,0 +1,67 @@
+// Remote control glue: starts the control server and publishes the queue and
+// playback state to it.
+
+use crate::app::App;
+use crate::components::notifications::Notification;
+use crate::remote::{RemoteState, RemoteStatus, RemoteTrack};
+
+impl App {
+    /// Start the control server when `[remote]` enables it.
+    pub(super) async fn start_remote(&mut self) {
+        if !self.config.remote.enabled {
+            return;
+        }
+        let socket = crate::remote::socket_path();
+        match crate::remote::spawn(&self.config.remote, &socket, self.action_tx.clone()).await {
+            Ok(handle) => self.remote = Some(handle),
+            Err(e) => {
+                tracing::warn!("remote control: {e}");
+                self.notifications
+                    .push(Notification::warn(format!("Remote control off: {e}")));
+            }
+        }
+    }
+
+    /// Current queue and playback state as seen by remote clients.
+    pub fn remote_state(&self) -> RemoteState {
+        let tracks = self
+            .queue
+            .items()
+            .iter()
+            .map(|qi| {
+                let m = qi.stream_metadata.as_ref();
+                let (title, subtitle) = qi.item.display_pair(
+                    m.and_then(|m| m.station_name.as_deref()),
+                    m.and_then(|m| m.display_title()).as_deref(),
+                    m.and_then(|m| m.display_subtitle()).as_deref(),
+                );
+                RemoteTrack {
+                    title,
+                    subtitle,
+                    url: qi.url.clone(),
+                }
+            })
+            .collect();
+        let status = if !self.now_playing.is_playing() {
+            RemoteStatus::Stopped
+        } else if self.now_playing.is_paused() {
+            RemoteStatus::Paused
+        } else {
+            RemoteStatus::Playing
+        };
+        RemoteState {
+            tracks,
+            current: self.queue.current_index(),
+            status,
+            position_secs: self.now_playing.position_secs(),
+            duration_secs: self.seek.duration_secs,
+        }
+    }
+
+    /// Push the latest state to the server; unchanged state is not re-sent.
+    pub(super) fn sync_remote(&self) {
+        if let Some(handle) = &self.remote {
+            handle.update(self.remote_state());
+        }
+    }
+}
@@ -0,0 +1,104 @@
+// Resume positions: remembers where seekable items were left off, seeks back
+// there on the next play, and seeds positions from mpv's watch_later data.
+// While playing, the position is written every few seconds rather than on
+// every update, plus immediately on pause, stop, track change and quit.
+
+use std::path::PathBuf;
+
+use crate::api::models::DiscoveryItem;
+use crate::app::App;
+use crate::player::watch_later;
+
+// Positions closer than this to either end are not worth resuming.
+const RESUME_MARGIN_SECS: f64 = 30.0;
+// Seconds between periodic writes of the playing position.
+pub(super) const SAVE_INTERVAL_SECS: f64 = 12.0;
+// Moves smaller than this since the last write are not written again.
+const MIN_CHANGE_SECS: f64 = 1.0;
+
+/// What was last written, so periodic saves skip unchanged positions.
+#[derive(Default)]
+pub(crate) struct ResumeWrites {
+    /// Tick counter for the periodic save.
+    pub(crate) ticks: u32,
+    /// Key and position of the last write; None after a clear.
+    last: Option<(String, f64)>,
+}
+
+impl App {
+    /// Seed resume positions for queued items from mpv watch_later files in
+    /// `dirs`. Returns the number of positions imported.
+    pub fn import_watch_later(&self, dirs: &[PathBuf]) -> usize {
+        let mut imported = 0;
+        for qi in self.queue.items() {
+            if matches!(qi.item, DiscoveryItem::NtsLiveChannel { .. }) {
+                continue;
+            }
+            let mut urls = qi.item.source_candidates();
+            if !urls.contains(&qi.url) {
+                urls.insert(0, qi.url.clone());
+            }
+            let key = qi.item.favorite_key();
+            for url in urls {
+                let Some((entry, secs)) = watch_later::find_position(dirs, &url) else {
+                    continue;
+                };
+                if let Ok(true) = self.db.import_resume_position(&entry, &key, &url, secs) {
+                    imported += 1;
+                }
+                break;
+            }
+        }
+        imported
+    }
+
+    /// Record how far into the current track playback got. Near the start or
+    /// end the stored position is cleared instead. Only seekable items count.
+    pub(super) fn save_resume_position(&mut self) {
+        self.resume_writes.ticks = 0;
+        if !self.now_playing.is_playing() {
+            return;
+        }
+        let (Some(track), Some(duration)) = (self.queue.current(), self.seek.duration_secs) else {
+            return;
+        };
+        let key = track.item.favorite_key();
+        let pos = self.now_playing.position_secs();
+        if pos < RESUME_MARGIN_SECS || pos > duration - RESUME_MARGIN_SECS {
+            let _ = self.db.clear_resume_position(&key);
+            self.resume_writes.last = None;
+            return;
+        }
+        let unchanged = self
+            .resume_writes
+            .last
+            .as_ref()
+            .is_some_and(|(k, p)| *k == key && (p - pos).abs() < MIN_CHANGE_SECS);
+        if !unchanged && self.db.set_resume_position(&key, &track.url, pos).is_ok() {
+            self.resume_writes.last = Some((key, pos));
+        }
+    }
+
+    /// Periodic save while playing, every SAVE_INTERVAL_SECS.
+    pub(super) fn tick_resume_position(&mut self) {
+        self.resume_writes.ticks += 1;
+        let interval = (self.tick_rate() * SAVE_INTERVAL_SECS) as u32;
+        if interval > 0 && self.resume_writes.ticks >= interval {
+            self.save_resume_position();
+        }
+    }
+
+    /// On playback start, queue a seek to the stored position (if any); it is
+    /// applied once mpv reports a duration.
+    pub(super) fn queue_resume(&mut self) {
+        let Some(track) = self.queue.current() else {
+            return;
+        };
+        if matches!(track.item, DiscoveryItem::NtsLiveChannel { .. }) {
+            return;
+        }
+        if let Ok(Some(pos)) = self.db.resume_position(&track.item.favorite_key()) {
+            self.seek.pending_resume = Some(pos);
+        }
+    }
+}
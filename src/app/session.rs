@@ -0,0 +1,123 @@
+// Session restore: with `restore_session` on, the open tab, its selection and
+// its filter are saved on quit and brought back on the next start, once the
+// tab has loaded. With `restore_playback` too, the track that was playing is
+// loaded again, paused where it was left. The queue is kept either way (see
+// `persist_queue`).
+
+use serde::{Deserialize, Serialize};
+
+use crate::api::models::DiscoveryItem;
+use crate::app::App;
+use crate::components::nts::NtsSubTab;
+
+// Row of the app_state table the session is kept in.
+const SESSION_KEY: &str = "session";
+
+/// Where the app was left on quit.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub(crate) struct Session {
+    tab: NtsSubTab,
+    /// Favorite key of the selected row.
+    #[serde(default)]
+    selected_key: Option<String>,
+    /// Row number, for when the selected item is gone from the list.
+    #[serde(default)]
+    selected: Option<usize>,
+    #[serde(default)]
+    filter: Option<String>,
+    /// Whether a track was playing or paused.
+    #[serde(default)]
+    playing: bool,
+}
+
+impl App {
+    /// Remember where the app is, for the next start.
+    pub(super) fn save_session(&self) {
+        if !self.config.general.restore_session {
+            return;
+        }
+        let session = Session {
+            tab: self.nts_tab.active_sub(),
+            selected_key: self
+                .discovery_list
+                .selected_item()
+                .map(DiscoveryItem::favorite_key),
+            selected: self.discovery_list.selected_index(),
+            filter: self.discovery_list.filter().map(str::to_string),
+            playing: self.now_playing.is_playing(),
+        };
+        let saved = serde_json::to_string(&session)
+            .map_err(anyhow::Error::from)
+            .and_then(|json| self.db.set_app_state(SESSION_KEY, &json));
+        if let Err(e) = saved {
+            tracing::warn!("couldn't save the session: {e}");
+        }
+    }
+
+    /// Open the tab the last session ended on, and load the track that was
+    /// playing if asked to. The selection and filter follow once the tab's
+    /// list arrives (`apply_pending_session`).
+    pub(super) async fn restore_session(&mut self) -> anyhow::Result<()> {
+        if !self.config.general.restore_session {
+            return Ok(());
+        }
+        let Some(session) = self
+            .db
+            .app_state(SESSION_KEY)?
+            .and_then(|json| serde_json::from_str::<Session>(&json).ok())
+        else {
+            return Ok(());
+        };
+        if session.tab != self.nts_tab.active_sub() {
+            let index = NtsSubTab::ALL
+                .iter()
+                .position(|tab| *tab == session.tab)
+                .unwrap_or_default();
+            self.switch_sub_tab(index)?;
+        }
+        // Live channels aren't picked up again: held paused, they'd fall
+        // behind the broadcast.
+        let resumable = self
+            .queue
+            .current()
+            .is_some_and(|track| !matches!(track.item, DiscoveryItem::NtsLiveChannel { .. }));
+        if self.config.general.restore_playback
+            && session.playing
+            && resumable
+            && !self.now_playing.is_playing()
+        {
+            self.load_current_track_paused().await?;
+        }
+        self.pending_session = Some(session);
+        self.apply_pending_session();
+        Ok(())
+    }
+
+    /// Select and filter as the last session did, once its tab is open and
+    /// has loaded. Dropped if another tab is opened first.
+    pub(super) fn apply_pending_session(&mut self) {
+        let Some(session) = &self.pending_session else {
+            return;
+        };
+        if session.tab != self.nts_tab.active_sub() {
+            self.pending_session = None;
+            return;
+        }
+        if self.discovery_list.is_loading() || self.discovery_list.total_item_count() == 0 {
+            return;
+        }
+        let Some(session) = self.pending_session.take() else {
+            return;
+        };
+        if let Some(filter) = session.filter {
+            self.search_bar.set_input(filter.clone());
+            self.discovery_list.set_filter(Some(filter));
+        }
+        let found = session
+            .selected_key
+            .is_some_and(|key| self.discovery_list.select_key(&key));
+        if let (false, Some(index)) = (found, session.selected) {
+            self.discovery_list.select_number(index + 1);
+        }
+    }
+}
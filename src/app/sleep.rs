@@ -0,0 +1,100 @@
+// Pausing for system sleep and lost audio outputs. Playback pauses as the
+// machine goes to sleep (or, without logind, the moment it wakes), and after
+// waking the paused track is loaded again at its place: streams and mpv's
+// connections don't survive a suspend. With `pause_on_device_loss`, playback
+// also pauses when an audio output goes away, so unplugged headphones don't
+// hand the sound to the speakers.
+
+use std::time::{Duration, Instant};
+
+use crate::action::Action;
+use crate::app::registry::ComponentId;
+use crate::app::App;
+use crate::components::notifications::Notification;
+use crate::power::SleepClock;
+
+// A wake seen again this soon (logind and the clock both noticing it) is
+// the same one.
+const SAME_WAKE: Duration = Duration::from_secs(30);
+
+#[derive(Debug, Default)]
+pub(crate) struct SleepState {
+    clock: SleepClock,
+    /// When the last wake was handled.
+    woke_at: Option<Instant>,
+}
+
+impl App {
+    /// Listen for logind's sleep announcements. Without them, sleeps are
+    /// still noticed on waking (`tick_sleep`).
+    #[cfg(target_os = "linux")]
+    pub(super) async fn start_sleep_watch(&mut self) {
+        if let Err(e) = crate::power::spawn_sleep_watch(self.action_tx.clone()).await {
+            tracing::debug!("no logind sleep signals: {e}");
+        }
+    }
+
+    /// Check on each tick whether the machine slept since the last one.
+    pub(super) async fn tick_sleep(&mut self) -> anyhow::Result<()> {
+        if let Some(asleep) = self.sleep.clock.check() {
+            tracing::info!("the system slept for {}s", asleep.as_secs());
+            self.system_sleep().await?;
+            self.system_wake().await?;
+        }
+        Ok(())
+    }
+
+    /// Pause what's playing before the machine sleeps.
+    pub(super) async fn system_sleep(&mut self) -> anyhow::Result<()> {
+        if !self.config.general.pause_on_sleep {
+            return Ok(());
+        }
+        if self.pause_playback().await? {
+            self.notifications
+                .push(Notification::info("Paused for system sleep"));
+        }
+        Ok(())
+    }
+
+    /// Load the paused track again, fresh, at the place it was left.
+    pub(super) async fn system_wake(&mut self) -> anyhow::Result<()> {
+        if !self.config.general.pause_on_sleep
+            || self
+                .sleep
+                .woke_at
+                .is_some_and(|at| at.elapsed() < SAME_WAKE)
+        {
+            return Ok(());
+        }
+        self.sleep.woke_at = Some(Instant::now());
+        // Whatever the clock saw, this wake is handled.
+        self.sleep.clock = SleepClock::new();
+        if !self.now_playing.is_playing() || !self.now_playing.is_paused() {
+            return Ok(());
+        }
+        self.save_resume_position();
+        self.load_current_track_paused().await
+    }
+
+    pub(super) async fn audio_device_removed(&mut self, device: &str) -> anyhow::Result<()> {
+        if !self.config.general.pause_on_device_loss {
+            return Ok(());
+        }
+        if self.pause_playback().await? {
+            self.notifications
+                .push(Notification::info(format!("Paused: {device} went away")));
+        }
+        Ok(())
+    }
+
+    /// Pause if something is playing; true if it was.
+    async fn pause_playback(&mut self) -> anyhow::Result<bool> {
+        if !self.now_playing.is_playing() || self.now_playing.is_paused() {
+            return Ok(false);
+        }
+        let _ = self.player.toggle_pause().await;
+        self.deliver(&ComponentId::PLAYBACK, &Action::TogglePlayPause)?;
+        self.save_resume_position();
+        Ok(true)
+    }
+}
@@ -0,0 +1,60 @@
+// Per-tab state: each sub-tab's list, scroll position, and search text are
+// put away when another tab opens and brought back on return, so switching
+// tabs doesn't refetch or lose the place.
+
+use std::collections::HashMap;
+
+use crate::app::App;
+use crate::components::discovery_list::ListView;
+use crate::components::nts::NtsSubTab;
+
+/// What a tab showed when it was switched away from.
+pub(crate) struct TabState {
+    list: ListView,
+    /// Text in the search bar, e.g. the query the results came from.
+    search_input: String,
+}
+
+/// Stashed state of the tabs that aren't open.
+pub(crate) type TabStates = HashMap<NtsSubTab, TabState>;
+
+impl App {
+    /// Put the open tab's list and search text away, leaving both empty.
+    pub(super) fn stash_active_tab(&mut self) {
+        let state = TabState {
+            list: self.discovery_list.take_view(),
+            search_input: self.search_bar.input().to_string(),
+        };
+        self.search_bar.set_input(String::new());
+        self.tab_states.insert(self.nts_tab.active_sub(), state);
+    }
+
+    /// Bring back what the open tab showed before; false if nothing was kept.
+    pub(super) fn restore_active_tab(&mut self) -> bool {
+        let Some(state) = self.tab_states.remove(&self.nts_tab.active_sub()) else {
+            return false;
+        };
+        self.discovery_list.restore_view(state.list);
+        self.search_bar.set_input(state.search_input);
+        true
+    }
+
+    /// Drop what `tab` showed, when it's out of date, so the next visit
+    /// loads it afresh.
+    pub(super) fn forget_tab(&mut self, tab: NtsSubTab) {
+        self.tab_states.remove(&tab);
+    }
+
+    /// The put-away list of `tab`, unless it's the open one.
+    pub(super) fn stashed_list(&mut self, tab: NtsSubTab) -> Option<&mut ListView> {
+        if self.nts_tab.active_sub() == tab {
+            return None;
+        }
+        self.tab_states.get_mut(&tab).map(|state| &mut state.list)
+    }
+
+    /// Whether a tab's state is being kept while another one is open.
+    pub fn has_stashed_tab(&self, tab: NtsSubTab) -> bool {
+        self.tab_states.contains_key(&tab)
+    }
+}
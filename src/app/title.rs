@@ -0,0 +1,65 @@
+// Terminal title: mirrors what's playing into the window / tmux pane title so
+// it shows up in the window manager even when the TUI is in a background pane.
+
+use crate::app::App;
+use crate::tui::Tui;
+
+// Title while nothing is playing.
+const IDLE_TITLE: &str = "clisten";
+
+impl App {
+    /// "clisten — <show>" for the current track, or "clisten" when stopped.
+    pub fn terminal_title(&self) -> String {
+        match self.playing_title() {
+            Some(title) => format!("{} — {}", IDLE_TITLE, title),
+            None => IDLE_TITLE.to_string(),
+        }
+    }
+
+    /// The playing track's show, or None when stopped. Streams that report
+    /// their own track add it after the station name.
+    pub(super) fn playing_title(&self) -> Option<String> {
+        if !self.now_playing.is_playing() {
+            return None;
+        }
+        let current = self.queue.current()?;
+        let meta = current.stream_metadata.as_ref();
+        let track = meta.and_then(|m| m.display_title());
+        let (title, _) = current.item.display_pair(
+            meta.and_then(|m| m.station_name.as_deref()),
+            track.as_deref(),
+            meta.and_then(|m| m.display_subtitle()).as_deref(),
+        );
+        let title = match track {
+            Some(track) if track != title => format!("{} · {}", title, track),
+            _ => title,
+        };
+        // Metadata is untrusted; control characters could end the escape early.
+        Some(title.chars().filter(|c| !c.is_control()).collect())
+    }
+
+    /// Write the title when it changed. The terminal's own title is saved
+    /// before the first write so `restore_terminal_title` can put it back.
+    pub(super) fn sync_terminal_title(&mut self, tui: &mut Tui) {
+        if !self.config.general.terminal_title {
+            return;
+        }
+        let title = self.terminal_title();
+        if self.window_title.as_deref() == Some(title.as_str()) {
+            return;
+        }
+        if self.window_title.is_none() {
+            let _ = tui.push_title();
+        }
+        let _ = tui.set_title(&title);
+        self.window_title = Some(title);
+    }
+
+    /// Undo `sync_terminal_title` on exit.
+    pub(super) fn restore_terminal_title(&mut self, tui: &mut Tui) {
+        if self.window_title.take().is_some() {
+            let _ = tui.set_title("");
+            let _ = tui.pop_title();
+        }
+    }
+}
@@ -0,0 +1,57 @@
+// Episode tracklist: fetched when an NTS episode starts playing and shown in
+// the tracklist overlay.
+
+use crate::action::Action;
+use crate::api::models::DiscoveryItem;
+use crate::app::App;
+use crate::components::notifications::Notification;
+
+impl App {
+    /// Fetch the playing episode's tracklist, unless it's already loaded or
+    /// loading (a restart on another source keeps it).
+    pub(super) fn load_tracklist(&mut self) {
+        let Some(track) = self.queue.current() else {
+            return;
+        };
+        let DiscoveryItem::NtsEpisode {
+            name,
+            show_alias,
+            episode_alias,
+            ..
+        } = &track.item
+        else {
+            self.tracklist_view.clear();
+            return;
+        };
+        if show_alias.is_empty() || episode_alias.is_empty() {
+            self.tracklist_view.clear();
+            return;
+        }
+        let key = track.item.favorite_key();
+        if self.tracklist_view.key() == Some(key.as_str()) {
+            return;
+        }
+        self.tracklist_view.begin(key.clone(), name.clone());
+
+        let client = self.nts_client.clone();
+        let tx = self.action_tx.clone();
+        let (show, episode) = (show_alias.clone(), episode_alias.clone());
+        tokio::spawn(async move {
+            // A failed fetch shows as an episode without a tracklist.
+            let tracks = client
+                .fetch_episode_tracklist(&show, &episode)
+                .await
+                .unwrap_or_default();
+            let _ = tx.send(Action::EpisodeTracklistLoaded { key, tracks });
+        });
+    }
+
+    pub(super) fn show_tracklist(&mut self) -> anyhow::Result<()> {
+        if !self.tracklist_view.show() {
+            self.action_tx.send(Action::Notify(Notification::info(
+                "Tracklists are available while an NTS episode plays",
+            )))?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,74 @@
+// Direct URL metadata: queued web pages (Bandcamp, YouTube, …) are looked
+// up with yt-dlp in the background, once per URL a session. What comes back
+// fills in the queue entry, the now-playing pane, and the copies kept in
+// history, favorites, and playlists.
+
+use crate::action::Action;
+use crate::api::models::{DiscoveryItem, UrlMeta};
+use crate::app::App;
+use crate::player::queue::TrackLength;
+use crate::player::ytdl;
+
+impl App {
+    /// Look up queued direct URLs that haven't been resolved yet, unless in
+    /// low data mode.
+    pub(super) fn request_url_metadata(&mut self) {
+        if self.config.general.low_data {
+            return;
+        }
+        for track in self.queue.items() {
+            let DiscoveryItem::DirectUrl {
+                url, meta: None, ..
+            } = &track.item
+            else {
+                continue;
+            };
+            if !(url.starts_with("http://") || url.starts_with("https://"))
+                || !self.url_requests.insert(url.clone())
+            {
+                continue;
+            }
+            let url = url.clone();
+            let command = ytdl::dump_command(&url, &self.config.network);
+            let tx = self.action_tx.clone();
+            tokio::spawn(async move {
+                match ytdl::resolve(command).await {
+                    Ok(resolved) => {
+                        let _ = tx.send(Action::UrlResolved {
+                            url,
+                            title: resolved.title,
+                            meta: resolved.meta,
+                        });
+                    }
+                    Err(e) => tracing::debug!("couldn't resolve {url}: {e}"),
+                }
+            });
+        }
+    }
+
+    /// Fold what yt-dlp found for `url` into everything holding that URL.
+    pub(super) fn url_resolved(&mut self, url: &str, title: Option<String>, meta: UrlMeta) {
+        let title = title.as_deref();
+        if let Err(e) = self.db.apply_url_meta(url, title, &meta) {
+            tracing::warn!("couldn't store the details of {url}: {e}");
+        }
+        let queued = self.queue.update_direct_urls(url, title, &meta);
+        if let (Some(item), Some(secs)) = (&queued, meta.duration_secs) {
+            self.track_lengths
+                .entry(item.favorite_key())
+                .or_insert(TrackLength::Measured(secs));
+        }
+        let playing = self.now_playing.refresh_item(url, title, &meta);
+        if playing {
+            if let Some(track) = self.queue.current() {
+                self.play_controls
+                    .set_current_title(track.item.display_title());
+            }
+        }
+        if queued.is_some() || playing {
+            self.sync_pinned_track();
+            self.show_queue();
+            self.persist_queue();
+        }
+    }
+}
@@ -0,0 +1,90 @@
+// Stuck playback watchdog: mpv sometimes sits buffering forever without
+// failing. When the position hasn't moved for the configured time while
+// playing, warn and restart the track where it was, a few times at most.
+
+use crate::app::App;
+use crate::components::notifications::Notification;
+
+// Restarts in a row, without playback moving in between, before giving up.
+const MAX_RESTARTS: u32 = 3;
+
+/// How long playback has gone without moving.
+#[derive(Default)]
+pub(crate) struct Watchdog {
+    /// Ticks since the position last changed.
+    ticks: u32,
+    last_pos: Option<f64>,
+    /// Restarts since playback last moved.
+    restarts: u32,
+    /// favorite_key() of the track being watched; another track starts over.
+    key: Option<String>,
+}
+
+impl App {
+    /// Called on PlaybackPosition: movement since the last report means
+    /// playback is healthy. The first report after a (re)start only sets
+    /// where it begins.
+    pub(super) fn watchdog_position(&mut self, pos: f64) {
+        if self.watchdog.last_pos.is_some_and(|last| last != pos) {
+            self.watchdog.ticks = 0;
+            self.watchdog.restarts = 0;
+        }
+        self.watchdog.last_pos = Some(pos);
+    }
+
+    /// Called every tick; restarts the track once it has been stuck too long.
+    pub(super) async fn tick_watchdog(&mut self) -> anyhow::Result<()> {
+        let Some(timeout) = self.config.player.stall_timeout() else {
+            return Ok(());
+        };
+        // Paused, stopped, waiting on a reconnect, or without mpv is not stuck.
+        if !self.now_playing.is_playing()
+            || self.now_playing.is_paused()
+            || self.reconnect.is_some()
+            || !self.player.is_available()
+        {
+            self.watchdog.ticks = 0;
+            return Ok(());
+        }
+        let key = self.queue.current().map(|q| q.item.favorite_key());
+        if self.watchdog.key != key {
+            self.watchdog = Watchdog {
+                key,
+                ..Watchdog::default()
+            };
+        }
+        if self.watchdog.restarts > MAX_RESTARTS {
+            return Ok(());
+        }
+        self.watchdog.ticks += 1;
+        let limit = (self.tick_rate() * timeout.as_secs_f64()) as u32;
+        if limit == 0 || self.watchdog.ticks < limit {
+            return Ok(());
+        }
+        self.watchdog.ticks = 0;
+        let secs = timeout.as_secs();
+        if self.watchdog.restarts == MAX_RESTARTS {
+            // Leave it be until it moves or another track plays.
+            self.watchdog.restarts += 1;
+            self.notifications.push(Notification::error(format!(
+                "Playback still stuck after {MAX_RESTARTS} restarts — try another source"
+            )));
+            return Ok(());
+        }
+        self.watchdog.restarts += 1;
+        self.watchdog.last_pos = None;
+        self.notifications.push(Notification::warn(format!(
+            "Playback stuck for {secs}s — restarting"
+        )));
+        self.health.record_reconnect();
+        self.save_resume_position();
+        self.seek.reset();
+        self.start_current_track().await
+    }
+
+    /// Restarts made for the current stall.
+    #[allow(dead_code)] // used by integration tests
+    pub fn stall_restarts(&self) -> u32 {
+        self.watchdog.restarts.min(MAX_RESTARTS)
+    }
+}
@@ -0,0 +1,67 @@
+// Opens web pages in the system browser: `$BROWSER` when set, otherwise the
+// platform opener (`open` on macOS, `start` on Windows, `xdg-open` and friends
+// elsewhere). The browser is left running on its own.
+
+use std::process::{Command, Stdio};
+
+/// Openers to try for `url` on `os` (as in `std::env::consts::OS`), each a
+/// program and its arguments. `browser` is the `$BROWSER` value, a
+/// colon-separated list of commands where `%s` stands for the URL.
+pub fn opener_commands(url: &str, browser: Option<&str>, os: &str) -> Vec<Vec<String>> {
+    let mut commands: Vec<Vec<String>> = browser
+        .unwrap_or_default()
+        .split(':')
+        .filter(|entry| !entry.trim().is_empty())
+        .map(|entry| {
+            let mut parts: Vec<String> = entry.split_whitespace().map(String::from).collect();
+            if parts.iter().any(|p| p.contains("%s")) {
+                for part in &mut parts {
+                    *part = part.replace("%s", url);
+                }
+            } else {
+                parts.push(url.to_string());
+            }
+            parts
+        })
+        .collect();
+    let platform: &[&[&str]] = match os {
+        "macos" => &[&["open"]],
+        "windows" => &[&["cmd", "/C", "start", ""]],
+        _ => &[&["xdg-open"], &["wslview"], &["gio", "open"]],
+    };
+    commands.extend(platform.iter().map(|command| {
+        let mut command: Vec<String> = command.iter().map(|s| s.to_string()).collect();
+        command.push(url.to_string());
+        command
+    }));
+    commands
+}
+
+/// Open `url` in the browser, returning the program that took it. Only
+/// http(s) addresses are opened.
+pub fn open(url: &str) -> anyhow::Result<String> {
+    if !(url.starts_with("http://") || url.starts_with("https://")) {
+        anyhow::bail!("{url} isn't a web address");
+    }
+    let browser = std::env::var("BROWSER").ok();
+    for command in opener_commands(url, browser.as_deref(), std::env::consts::OS) {
+        let Some((program, args)) = command.split_first() else {
+            continue;
+        };
+        if which::which(program).is_err() {
+            continue;
+        }
+        let spawned = Command::new(program)
+            .args(args)
+            .stdin(Stdio::null())
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .spawn();
+        if let Ok(mut child) = spawned {
+            // Reap it in the background; some openers stay until the browser exits.
+            std::thread::spawn(move || child.wait());
+            return Ok(program.clone());
+        }
+    }
+    anyhow::bail!("no browser opener found (set $BROWSER)")
+}
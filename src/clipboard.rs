@@ -0,0 +1,73 @@
+// System clipboard access: pipes text to pbcopy, wl-copy, xclip, or xsel when
+// one is available, otherwise sends an OSC 52 escape, which most terminals
+// (and tmux with `set-clipboard on`) turn into a clipboard write.
+
+use std::io::Write;
+use std::process::{Command, Stdio};
+
+/// Clipboard programs, with their arguments and the environment variable
+/// that must be set for them to apply (None for always).
+const TOOLS: &[(&str, &[&str], Option<&str>)] = &[
+    ("pbcopy", &[], None),
+    ("wl-copy", &[], Some("WAYLAND_DISPLAY")),
+    ("xclip", &["-selection", "clipboard"], Some("DISPLAY")),
+    ("xsel", &["--clipboard", "--input"], Some("DISPLAY")),
+];
+
+/// Copy `text`, returning what it was handed to.
+pub fn copy(text: &str) -> anyhow::Result<&'static str> {
+    for &(tool, args, env) in TOOLS {
+        if env.is_some_and(|var| std::env::var_os(var).is_none()) || which::which(tool).is_err() {
+            continue;
+        }
+        if pipe_to(tool, args, text).is_ok() {
+            return Ok(tool);
+        }
+    }
+    let mut stdout = std::io::stdout();
+    stdout.write_all(osc52(text).as_bytes())?;
+    stdout.flush()?;
+    Ok("terminal (OSC 52)")
+}
+
+fn pipe_to(tool: &str, args: &[&str], text: &str) -> anyhow::Result<()> {
+    let mut child = Command::new(tool)
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()?;
+    if let Some(mut stdin) = child.stdin.take() {
+        stdin.write_all(text.as_bytes())?;
+    }
+    let status = child.wait()?;
+    if !status.success() {
+        anyhow::bail!("{tool} exited with {status}");
+    }
+    Ok(())
+}
+
+/// The OSC 52 sequence that sets the clipboard to `text`.
+pub fn osc52(text: &str) -> String {
+    format!("\x1b]52;c;{}\x07", base64(text.as_bytes()))
+}
+
+/// Standard padded base64.
+pub fn base64(bytes: &[u8]) -> String {
+    const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+    let mut out = String::with_capacity(bytes.len().div_ceil(3) * 4);
+    for chunk in bytes.chunks(3) {
+        let n = chunk
+            .iter()
+            .enumerate()
+            .fold(0u32, |n, (i, b)| n | (*b as u32) << (16 - 8 * i));
+        for i in 0..4 {
+            if i <= chunk.len() {
+                out.push(ALPHABET[(n >> (18 - 6 * i) & 0x3f) as usize] as char);
+            } else {
+                out.push('=');
+            }
+        }
+    }
+    out
+}
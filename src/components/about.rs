@@ -0,0 +1,132 @@
+// About overlay (press `a` in help): version, build, dependencies, paths, and
+// active features. `c` copies the whole report for pasting into a bug report.
+
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::{
+    layout::{Constraint, Layout, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+use tokio::sync::mpsc::UnboundedSender;
+
+use crate::about::{self, AboutSection};
+use crate::action::Action;
+use crate::components::{centered_overlay, Component};
+use crate::theme::Theme;
+
+/// Overlay showing the About report.
+#[derive(Default)]
+pub struct AboutView {
+    action_tx: Option<UnboundedSender<Action>>,
+    visible: bool,
+    sections: Vec<AboutSection>,
+    scroll: u16,
+}
+
+impl AboutView {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn is_visible(&self) -> bool {
+        self.visible
+    }
+
+    pub fn show(&mut self, sections: Vec<AboutSection>) {
+        self.visible = true;
+        self.sections = sections;
+        self.scroll = 0;
+    }
+
+    pub fn hide(&mut self) {
+        self.visible = false;
+    }
+
+    #[allow(dead_code)] // used by integration tests
+    pub fn sections(&self) -> &[AboutSection] {
+        &self.sections
+    }
+
+    fn line_count(&self) -> u16 {
+        self.sections
+            .iter()
+            .map(|s| s.rows.len() + 2)
+            .sum::<usize>() as u16
+    }
+}
+
+impl Component for AboutView {
+    fn register_action_handler(&mut self, tx: UnboundedSender<Action>) {
+        self.action_tx = Some(tx);
+    }
+
+    fn handle_key_event(&mut self, key: KeyEvent) -> anyhow::Result<bool> {
+        if !self.visible {
+            return Ok(false);
+        }
+        match key.code {
+            KeyCode::Esc | KeyCode::Char('q') => self.hide(),
+            KeyCode::Char('j') | KeyCode::Down if self.scroll + 1 < self.line_count() => {
+                self.scroll += 1;
+            }
+            KeyCode::Char('k') | KeyCode::Up => self.scroll = self.scroll.saturating_sub(1),
+            KeyCode::Char('c') => {
+                if let Some(tx) = &self.action_tx {
+                    tx.send(Action::CopyToClipboard(about::to_text(&self.sections)))?;
+                }
+            }
+            _ => {}
+        }
+        Ok(true)
+    }
+
+    fn draw(&self, frame: &mut Frame, area: Rect, theme: &Theme) {
+        if !self.visible {
+            return;
+        }
+        let overlay_area = centered_overlay(area, 72, area.height.saturating_sub(4));
+        frame.render_widget(Clear, overlay_area);
+
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(" About ")
+            .title_style(
+                Style::default()
+                    .fg(theme.primary)
+                    .add_modifier(Modifier::BOLD),
+            );
+        let inner = block.inner(overlay_area);
+        frame.render_widget(block, overlay_area);
+
+        let [body, footer] =
+            Layout::vertical([Constraint::Min(0), Constraint::Length(1)]).areas(inner);
+        let dim = Style::default().fg(theme.text_dim);
+
+        let mut lines = Vec::new();
+        for section in &self.sections {
+            lines.push(Line::from(Span::styled(
+                format!(" {}", section.title),
+                Style::default()
+                    .fg(theme.secondary)
+                    .add_modifier(Modifier::BOLD),
+            )));
+            for (label, value) in &section.rows {
+                lines.push(Line::from(vec![
+                    Span::styled(format!("  {:<18}", label), Style::default().fg(theme.text)),
+                    Span::styled(value.as_str(), dim),
+                ]));
+            }
+            lines.push(Line::from(""));
+        }
+        frame.render_widget(Paragraph::new(lines).scroll((self.scroll, 0)), body);
+        frame.render_widget(
+            Paragraph::new(Line::from(Span::styled(
+                "  c copy report · j/k scroll · Esc close",
+                dim,
+            ))),
+            footer,
+        );
+    }
+}
@@ -0,0 +1,103 @@
+// Confirmation prompt: asks before an action that can't be undone (clearing
+// the queue or history, deleting a playlist) and sends it only on `y` or
+// Enter. Any other key cancels, so a stray keypress loses nothing.
+
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::{
+    layout::{Constraint, Layout, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph, Wrap},
+    Frame,
+};
+use tokio::sync::mpsc::UnboundedSender;
+
+use crate::action::Action;
+use crate::components::{centered_overlay, Component};
+use crate::theme::Theme;
+
+/// Overlay asking a yes/no question before sending an action.
+#[derive(Default)]
+pub struct ConfirmModal {
+    action_tx: Option<UnboundedSender<Action>>,
+    prompt: String,
+    /// Sent when confirmed; None while closed.
+    pending: Option<Action>,
+}
+
+impl ConfirmModal {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn is_visible(&self) -> bool {
+        self.pending.is_some()
+    }
+
+    /// Ask `prompt`, sending `action` if the answer is yes.
+    pub fn ask(&mut self, prompt: String, action: Action) {
+        self.prompt = prompt;
+        self.pending = Some(action);
+    }
+
+    #[allow(dead_code)] // used by integration tests
+    pub fn prompt(&self) -> Option<&str> {
+        self.pending.as_ref().map(|_| self.prompt.as_str())
+    }
+}
+
+impl Component for ConfirmModal {
+    fn register_action_handler(&mut self, tx: UnboundedSender<Action>) {
+        self.action_tx = Some(tx);
+    }
+
+    fn handle_key_event(&mut self, key: KeyEvent) -> anyhow::Result<bool> {
+        let Some(action) = self.pending.take() else {
+            return Ok(false);
+        };
+        if matches!(key.code, KeyCode::Char('y') | KeyCode::Enter) {
+            if let Some(tx) = &self.action_tx {
+                tx.send(action)?;
+            }
+        }
+        Ok(true)
+    }
+
+    fn draw(&self, frame: &mut Frame, area: Rect, theme: &Theme) {
+        if !self.is_visible() {
+            return;
+        }
+        let overlay_area = centered_overlay(area, 48, 6);
+        frame.render_widget(Clear, overlay_area);
+
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(theme.warning))
+            .title(" Confirm ")
+            .title_style(
+                Style::default()
+                    .fg(theme.warning)
+                    .add_modifier(Modifier::BOLD),
+            );
+        let inner = block.inner(overlay_area);
+        frame.render_widget(block, overlay_area);
+
+        let [prompt_area, footer] =
+            Layout::vertical([Constraint::Min(0), Constraint::Length(1)]).areas(inner);
+        frame.render_widget(
+            Paragraph::new(Line::from(Span::styled(
+                format!("  {}", self.prompt),
+                Style::default().fg(theme.text),
+            )))
+            .wrap(Wrap { trim: false }),
+            prompt_area,
+        );
+        frame.render_widget(
+            Paragraph::new(Line::from(Span::styled(
+                "  y / Enter confirm · any other key cancels",
+                Style::default().fg(theme.text_dim),
+            ))),
+            footer,
+        );
+    }
+}
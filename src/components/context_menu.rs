@@ -0,0 +1,156 @@
+// Item context menu (press `.`): what can be done with the selected item (or
+// the playing one), each with the key that does it directly, so the rarer
+// actions are a menu away instead of a keybinding to remember.
+
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::{
+    layout::{Constraint, Layout, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph},
+    Frame,
+};
+use tokio::sync::mpsc::UnboundedSender;
+
+use crate::action::Action;
+use crate::components::text::{display_width, truncate};
+use crate::components::{centered_overlay, Component};
+use crate::theme::Theme;
+
+/// One line of the menu.
+#[derive(Debug, Clone)]
+pub struct MenuEntry {
+    pub label: String,
+    /// The key that does the same from the list, e.g. "A".
+    pub shortcut: String,
+    /// Sent when the entry is chosen.
+    pub action: Action,
+}
+
+/// Overlay listing the operations for one item.
+#[derive(Default)]
+pub struct ContextMenu {
+    action_tx: Option<UnboundedSender<Action>>,
+    visible: bool,
+    title: String,
+    entries: Vec<MenuEntry>,
+    selected: usize,
+}
+
+impl ContextMenu {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn is_visible(&self) -> bool {
+        self.visible
+    }
+
+    /// Show `entries` for the item called `title`, the first one selected.
+    pub fn show(&mut self, title: String, entries: Vec<MenuEntry>) {
+        self.title = title;
+        self.entries = entries;
+        self.selected = 0;
+        self.visible = !self.entries.is_empty();
+    }
+
+    pub fn hide(&mut self) {
+        self.visible = false;
+    }
+
+    #[allow(dead_code)] // used by integration tests
+    pub fn labels(&self) -> Vec<&str> {
+        self.entries
+            .iter()
+            .map(|entry| entry.label.as_str())
+            .collect()
+    }
+
+    /// Send the selected entry's action and close.
+    fn choose(&mut self) -> anyhow::Result<()> {
+        if let (Some(entry), Some(tx)) = (self.entries.get(self.selected), &self.action_tx) {
+            tx.send(entry.action.clone())?;
+        }
+        self.hide();
+        Ok(())
+    }
+}
+
+impl Component for ContextMenu {
+    fn register_action_handler(&mut self, tx: UnboundedSender<Action>) {
+        self.action_tx = Some(tx);
+    }
+
+    fn handle_key_event(&mut self, key: KeyEvent) -> anyhow::Result<bool> {
+        if !self.visible {
+            return Ok(false);
+        }
+        match key.code {
+            KeyCode::Esc | KeyCode::Char('q') | KeyCode::Char('.') => self.hide(),
+            KeyCode::Char('j') | KeyCode::Down if self.selected + 1 < self.entries.len() => {
+                self.selected += 1;
+            }
+            KeyCode::Char('k') | KeyCode::Up => self.selected = self.selected.saturating_sub(1),
+            KeyCode::Enter => self.choose()?,
+            _ => {}
+        }
+        Ok(true)
+    }
+
+    fn draw(&self, frame: &mut Frame, area: Rect, theme: &Theme) {
+        if !self.visible {
+            return;
+        }
+        let height = self.entries.len() as u16 + 3;
+        let overlay_area = centered_overlay(area, 48, height);
+        frame.render_widget(Clear, overlay_area);
+
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(format!(" {} ", self.title))
+            .title_style(
+                Style::default()
+                    .fg(theme.primary)
+                    .add_modifier(Modifier::BOLD),
+            );
+        let inner = block.inner(overlay_area);
+        frame.render_widget(block, overlay_area);
+
+        let [list_area, footer] =
+            Layout::vertical([Constraint::Min(0), Constraint::Length(1)]).areas(inner);
+        let dim = Style::default().fg(theme.text_dim);
+        let width = usize::from(list_area.width);
+        let items: Vec<ListItem> = self
+            .entries
+            .iter()
+            .enumerate()
+            .map(|(i, entry)| {
+                let shortcut_width = display_width(&entry.shortcut) + 2;
+                let label = format!("  {}", entry.label);
+                let label = truncate(&label, width.saturating_sub(shortcut_width)).into_owned();
+                let pad = width.saturating_sub(display_width(&label) + shortcut_width);
+                let mut style = Style::default();
+                if i == self.selected {
+                    style = style.bg(theme.selection_bg);
+                }
+                ListItem::new(Line::from(vec![
+                    Span::styled(label, Style::default().fg(theme.text)),
+                    Span::raw(" ".repeat(pad)),
+                    Span::styled(entry.shortcut.clone(), dim),
+                ]))
+                .style(style)
+            })
+            .collect();
+        let mut state = ListState::default();
+        state.select(Some(self.selected));
+        frame.render_stateful_widget(List::new(items), list_area, &mut state);
+
+        frame.render_widget(
+            Paragraph::new(Line::from(Span::styled(
+                "  Enter choose · j/k move · Esc close",
+                dim,
+            ))),
+            footer,
+        );
+    }
+}
@@ -1,4 +1,8 @@
-// Modal dialog for pasting a URL to play directly (press `o` to open).
+// Modal dialog for pasting a URL to play directly (press `o` to open). A path
+// to a .m3u / .m3u8 file queues its entries instead. Opened from the Podcasts
+// tab, it takes a feed URL to subscribe to.
+
+use std::path::PathBuf;
 
 use crossterm::event::{KeyCode, KeyEvent};
 use ratatui::{
@@ -13,6 +17,7 @@ use tokio::sync::mpsc::UnboundedSender;
 use crate::action::Action;
 use crate::api::models::DiscoveryItem;
 use crate::components::{centered_overlay, Component};
+use crate::playlist::PlaylistFormat;
 use crate::theme::Theme;
 
 /// Modal dialog for pasting and playing an arbitrary URL.
@@ -22,6 +27,8 @@ pub struct DirectPlayModal {
     visible: bool,
     input: String,
     error: Option<String>,
+    /// Asking for a podcast feed rather than something to play.
+    subscribe: bool,
 }
 
 impl DirectPlayModal {
@@ -35,10 +42,17 @@ impl DirectPlayModal {
 
     pub fn show(&mut self) {
         self.visible = true;
+        self.subscribe = false;
         self.input.clear();
         self.error = None;
     }
 
+    /// Open asking for a podcast feed URL.
+    pub fn show_subscribe(&mut self) {
+        self.show();
+        self.subscribe = true;
+    }
+
     pub fn hide(&mut self) {
         self.visible = false;
         self.input.clear();
@@ -52,13 +66,37 @@ impl DirectPlayModal {
             self.input.clear();
             return;
         }
-        if !url.starts_with("http://") && !url.starts_with("https://") {
-            self.error = Some("URL must start with http:// or https://".to_string());
+        let is_url = url.starts_with("http://") || url.starts_with("https://");
+        if self.subscribe {
+            if !is_url {
+                self.error = Some("Enter a feed URL starting with http:// or https://".to_string());
+                return;
+            }
+            if let Some(tx) = &self.action_tx {
+                tx.send(Action::SubscribePodcast(url)).ok();
+            }
+            self.hide();
+            return;
+        }
+        let path = expand_home(&url);
+        let is_playlist = !is_url && PlaylistFormat::from_path(&path) == Some(PlaylistFormat::M3u);
+        if !is_url && !is_playlist {
+            self.error = Some(
+                "Enter a URL starting with http:// or https://, or a .m3u file path".to_string(),
+            );
             return;
         }
         if let Some(tx) = &self.action_tx {
-            let item = DiscoveryItem::DirectUrl { url, title: None };
-            tx.send(Action::PlayItem(item)).ok();
+            if is_playlist {
+                tx.send(Action::ImportPlaylist(path)).ok();
+            } else {
+                let item = DiscoveryItem::DirectUrl {
+                    url,
+                    title: None,
+                    meta: None,
+                };
+                tx.send(Action::PlayItem(item)).ok();
+            }
         }
         self.visible = false;
         self.input.clear();
@@ -66,6 +104,14 @@ impl DirectPlayModal {
     }
 }
 
+/// `~/music.m3u` → `$HOME/music.m3u`.
+fn expand_home(input: &str) -> PathBuf {
+    match (input.strip_prefix("~/"), dirs::home_dir()) {
+        (Some(rest), Some(home)) => home.join(rest),
+        _ => PathBuf::from(input),
+    }
+}
+
 impl Component for DirectPlayModal {
     fn register_action_handler(&mut self, tx: UnboundedSender<Action>) {
         self.action_tx = Some(tx);
@@ -108,9 +154,14 @@ impl Component for DirectPlayModal {
 
         frame.render_widget(Clear, overlay_area);
 
+        let (title, label, verb) = if self.subscribe {
+            (" Subscribe to podcast ", "Feed URL: ", "subscribe")
+        } else {
+            (" Open URL ", "URL: ", "play")
+        };
         let block = Block::default()
             .borders(Borders::ALL)
-            .title(" Open URL ")
+            .title(title)
             .title_style(
                 Style::default()
                     .fg(theme.primary)
@@ -121,12 +172,12 @@ impl Component for DirectPlayModal {
         frame.render_widget(block, overlay_area);
 
         let prompt = Line::from(vec![
-            Span::styled("URL: ", Style::default().fg(theme.accent)),
+            Span::styled(label, Style::default().fg(theme.accent)),
             Span::raw(&self.input),
             Span::styled("█", Style::default().fg(theme.text)),
         ]);
         let hint = Line::from(Span::styled(
-            "  Enter to play · Esc to cancel",
+            format!("  Enter to {verb} · Esc to cancel"),
             Style::default().fg(theme.text_dim),
         ));
         let error_line = if let Some(ref err) = self.error {
@@ -1,9 +1,16 @@
 // Scrollable, filterable list of DiscoveryItems (left panel). Handles
-// keyboard navigation, text filtering, and progressive append for search results.
+// keyboard navigation, text filtering, and progressive append for search
+// results, and pins the playing track above the list once it scrolls away.
+// Also has vim-style motions and an incremental find that jumps between
+// matching rows without hiding the others.
 
-use crossterm::event::{KeyCode, KeyEvent};
+use std::cell::Cell;
+use std::cmp::Ordering;
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+use crossterm::event::{KeyCode, KeyEvent, MouseButton, MouseEvent, MouseEventKind};
 use ratatui::{
-    layout::Rect,
+    layout::{Constraint, Layout, Rect},
     style::{Color, Modifier, Style},
     text::{Line, Span},
     widgets::{List, ListItem, ListState, Paragraph},
@@ -12,21 +19,183 @@ use ratatui::{
 use tokio::sync::mpsc::UnboundedSender;
 
 use crate::action::Action;
-use crate::api::models::DiscoveryItem;
-use crate::components::{Component, BRAILLE_SPINNER};
+use crate::api::genres::genre_depth;
+use crate::api::models::{fold_for_search, unix_now, DiscoveryItem, ShowSlot};
+use crate::components::text::{spans_width, truncate};
+use crate::components::{format_time, live_progress, Component, BRAILLE_SPINNER};
 use crate::theme::Theme;
 
+/// Client-side ordering applied to the loaded items.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SortOrder {
+    /// Keep the order the API returned.
+    #[default]
+    Default,
+    NewestFirst,
+    OldestFirst,
+    Alphabetical,
+}
+
+impl SortOrder {
+    /// Cycle to the next sort order.
+    pub fn next(self) -> Self {
+        match self {
+            Self::Default => Self::NewestFirst,
+            Self::NewestFirst => Self::OldestFirst,
+            Self::OldestFirst => Self::Alphabetical,
+            Self::Alphabetical => Self::Default,
+        }
+    }
+
+    /// Human-readable label for display.
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Default => "Default",
+            Self::NewestFirst => "Newest first",
+            Self::OldestFirst => "Oldest first",
+            Self::Alphabetical => "A–Z",
+        }
+    }
+}
+
+fn broadcast_date(item: &DiscoveryItem) -> Option<&str> {
+    match item {
+        DiscoveryItem::NtsEpisode { broadcast_date, .. } => broadcast_date.as_deref(),
+        _ => None,
+    }
+}
+
+/// Compare two items by broadcast date; items without one always sort last.
+fn compare_dates(a: &DiscoveryItem, b: &DiscoveryItem, newest_first: bool) -> Ordering {
+    match (broadcast_date(a), broadcast_date(b)) {
+        (Some(x), Some(y)) if newest_first => y.cmp(x),
+        (Some(x), Some(y)) => x.cmp(y),
+        (Some(_), None) => Ordering::Less,
+        (None, Some(_)) => Ordering::Greater,
+        (None, None) => Ordering::Equal,
+    }
+}
+
+/// Whether `item` matches a folded filter `query` (anything does without
+/// one).
+fn matches_filter(item: &DiscoveryItem, query: Option<&str>) -> bool {
+    query.is_none_or(|q| item.matches_search(q))
+}
+
+/// The playing track, as pinned above a scrolled list.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PinnedTrack {
+    /// favorite_key() of the item, to find it in the list.
+    pub key: String,
+    pub title: String,
+    pub position_secs: f64,
+}
+
+/// Fallback half-page size before the list has been drawn.
+const DEFAULT_HALF_PAGE: usize = 5;
+
+/// Text typed into the in-list find prompt and where the cursor started.
+#[derive(Debug, Default)]
+struct Find {
+    query: String,
+    /// True while the prompt takes keys; false once confirmed with Enter,
+    /// when n/N jump between matches.
+    typing: bool,
+    /// Selection when the prompt opened, restored by Esc.
+    origin: Option<usize>,
+}
+
+/// A list's contents and scroll position, kept while its tab is switched
+/// away from (see `DiscoveryList::take_view`).
+#[derive(Debug, Default)]
+pub struct ListView {
+    all_items: Vec<DiscoveryItem>,
+    filter_query: Option<String>,
+    selected: Option<usize>,
+    offset: usize,
+    fresh: HashSet<String>,
+    loading: bool,
+    empty_message: Option<&'static str>,
+    heading: Option<String>,
+    footer: Option<String>,
+    searching: bool,
+    search_total: Option<u64>,
+}
+
+impl ListView {
+    /// Swap in refreshed items, keeping the position where it still fits.
+    pub fn replace_items(&mut self, items: Vec<DiscoveryItem>) {
+        self.all_items = items;
+        self.loading = false;
+    }
+
+    /// Add results a search streamed in while its tab was away.
+    pub fn append_items(&mut self, items: Vec<DiscoveryItem>) {
+        self.all_items.extend(items);
+    }
+
+    pub fn set_loading(&mut self, loading: bool) {
+        self.loading = loading;
+    }
+
+    pub fn set_search_total(&mut self, total: u64) {
+        self.search_total = Some(total);
+    }
+
+    pub fn set_heading(&mut self, heading: Option<String>) {
+        self.heading = heading;
+    }
+
+    pub fn set_footer(&mut self, footer: Option<String>) {
+        self.footer = footer;
+    }
+}
+
 /// Scrollable, filterable list of discovery items (left panel).
 #[derive(Default)]
 pub struct DiscoveryList {
     action_tx: Option<UnboundedSender<Action>>,
     /// Full unfiltered dataset
     all_items: Vec<DiscoveryItem>,
-    /// Currently visible items (filtered or full)
-    items: Vec<DiscoveryItem>,
+    /// Indices into all_items of the rows shown, in list order (filtered
+    /// and sorted), so filtering and appending never copy the items.
+    items: Vec<usize>,
     state: ListState,
     filter_query: Option<String>,
+    sort: SortOrder,
+    /// favorite_key()s of items marked for a bulk action.
+    marked: HashSet<String>,
+    /// Most recently marked item, the anchor for range marking.
+    mark_anchor: Option<String>,
+    /// favorite_key()s of favorited items, shown with a star.
+    favorites: HashSet<String>,
+    /// Aliases of followed shows; their episodes get a follow marker.
+    followed_shows: HashSet<String>,
+    /// favorite_key()s of items flagged as new (followed-show episodes).
+    fresh: HashSet<String>,
+    /// favorite_key()s of podcast episodes not played yet, shown with a dot.
+    unplayed: HashSet<String>,
+    /// Start and end of the show on air, per live channel.
+    live_slots: BTreeMap<u8, ShowSlot>,
+    /// Episodes per genre id, for the genre picker.
+    genre_counts: HashMap<String, u64>,
     loading: bool,
+    /// Shown instead of an empty list, e.g. on the Favorites tab.
+    empty_message: Option<&'static str>,
+    /// Title shown above the list, e.g. the open playlist's name.
+    heading: Option<String>,
+    /// Note shown under the list, e.g. when some search pages failed.
+    footer: Option<String>,
+    /// True while the list holds streamed search results.
+    searching: bool,
+    /// Total matches the search API reported.
+    search_total: Option<u64>,
+    pinned: Option<PinnedTrack>,
+    find: Option<Find>,
+    /// First item index and how many items fit, as last drawn.
+    viewport: Cell<(usize, usize)>,
+    /// Where the pinned row was last drawn, for mouse hit-testing.
+    pin_area: Cell<Rect>,
     frame_count: u64,
 }
 
@@ -37,8 +206,55 @@ impl DiscoveryList {
 
     pub fn set_items(&mut self, items: Vec<DiscoveryItem>) {
         self.all_items = items;
+        self.clear_marks();
+        self.fresh.clear();
         self.refilter();
         self.loading = false;
+        self.footer = None;
+        self.searching = false;
+        self.search_total = None;
+    }
+
+    /// Move the contents and position out, leaving the list empty. Marks
+    /// don't carry over.
+    pub fn take_view(&mut self) -> ListView {
+        self.clear_marks();
+        self.find = None;
+        self.items.clear();
+        let view = ListView {
+            all_items: std::mem::take(&mut self.all_items),
+            filter_query: self.filter_query.take(),
+            selected: self.state.selected(),
+            offset: self.state.offset(),
+            fresh: std::mem::take(&mut self.fresh),
+            loading: std::mem::take(&mut self.loading),
+            empty_message: self.empty_message.take(),
+            heading: self.heading.take(),
+            footer: self.footer.take(),
+            searching: std::mem::take(&mut self.searching),
+            search_total: self.search_total.take(),
+        };
+        self.state = ListState::default();
+        view
+    }
+
+    /// Show a view taken earlier, scrolled and selected where it was left.
+    pub fn restore_view(&mut self, view: ListView) {
+        self.clear_marks();
+        self.all_items = view.all_items;
+        self.filter_query = view.filter_query;
+        self.fresh = view.fresh;
+        self.loading = view.loading;
+        self.empty_message = view.empty_message;
+        self.heading = view.heading;
+        self.footer = view.footer;
+        self.searching = view.searching;
+        self.search_total = view.search_total;
+        self.refilter();
+        if let Some(selected) = view.selected.filter(|_| !self.items.is_empty()) {
+            self.state.select(Some(selected.min(self.items.len() - 1)));
+            *self.state.offset_mut() = view.offset.min(selected);
+        }
     }
 
     pub fn set_filter(&mut self, query: Option<String>) {
@@ -46,56 +262,335 @@ impl DiscoveryList {
         self.refilter();
     }
 
+    /// Text the list is filtered by, if any.
+    pub fn filter(&self) -> Option<&str> {
+        self.filter_query.as_deref()
+    }
+
     pub fn append_items(&mut self, new_items: Vec<DiscoveryItem>) {
         let prev_selected = self.state.selected();
+        let first_new = self.all_items.len();
         self.all_items.extend(new_items);
-        self.refilter();
-        // Preserve scroll position when appending
-        if let Some(idx) = prev_selected {
-            if idx < self.items.len() {
-                self.state.select(Some(idx));
+        if self.sort != SortOrder::Default {
+            // Sorted, the new items can land anywhere.
+            self.refilter();
+            // Preserve scroll position when appending
+            if let Some(idx) = prev_selected {
+                if idx < self.items.len() {
+                    self.state.select(Some(idx));
+                }
             }
+            return;
+        }
+        // Unsorted, they go after the rows already shown: only they need
+        // filtering.
+        let query = self.filter_query.as_deref().map(fold_for_search);
+        let all = &self.all_items;
+        self.items
+            .extend((first_new..all.len()).filter(|&i| matches_filter(&all[i], query.as_deref())));
+        if prev_selected.is_none() && !self.items.is_empty() {
+            self.state.select(Some(0));
         }
     }
 
-    /// Rebuild the visible items list from all_items + current filter.
-    fn refilter(&mut self) {
-        match self.filter_query {
-            Some(ref q) => {
-                let q = q.to_lowercase();
-                self.items = self
-                    .all_items
-                    .iter()
-                    .filter(|item| {
-                        item.title().to_lowercase().contains(&q)
-                            || item.subtitle().to_lowercase().contains(&q)
-                    })
-                    .cloned()
-                    .collect();
-            }
-            None => {
-                self.items = self.all_items.clone();
+    // ── Marks ──
+
+    /// Toggle the mark on the selected item and move the cursor down.
+    pub fn toggle_mark(&mut self) {
+        let Some(key) = self.selected_markable_key() else {
+            return;
+        };
+        if !self.marked.remove(&key) {
+            self.marked.insert(key.clone());
+            self.mark_anchor = Some(key);
+        }
+        self.next();
+    }
+
+    /// Mark every item between the last marked item and the cursor. Without
+    /// an anchor, marks just the selected item.
+    pub fn mark_range(&mut self) {
+        let Some(cursor) = self.state.selected() else {
+            return;
+        };
+        let anchor = self
+            .mark_anchor
+            .as_ref()
+            .and_then(|key| self.rows().position(|i| i.favorite_key() == *key))
+            .unwrap_or(cursor);
+        let (start, end) = (anchor.min(cursor), anchor.max(cursor));
+        for &index in &self.items[start..=end] {
+            let item = &self.all_items[index];
+            if !item.is_browse_entry() {
+                self.marked.insert(item.favorite_key());
             }
         }
+        self.mark_anchor = self.selected_markable_key();
+    }
+
+    pub fn clear_marks(&mut self) {
+        self.marked.clear();
+        self.mark_anchor = None;
+    }
+
+    pub fn marked_count(&self) -> usize {
+        self.marked.len()
+    }
+
+    /// Marked items in list order (including ones hidden by the filter).
+    pub fn marked_items(&self) -> Vec<DiscoveryItem> {
+        self.all_items
+            .iter()
+            .filter(|item| self.marked.contains(&item.favorite_key()))
+            .cloned()
+            .collect()
+    }
+
+    fn selected_markable_key(&self) -> Option<String> {
+        self.selected_item()
+            .filter(|item| !item.is_browse_entry())
+            .map(DiscoveryItem::favorite_key)
+    }
+
+    pub fn set_favorites(&mut self, keys: HashSet<String>) {
+        self.favorites = keys;
+    }
+
+    pub fn set_followed_shows(&mut self, aliases: HashSet<String>) {
+        self.followed_shows = aliases;
+    }
+
+    /// Flag items as new until the next `set_items`.
+    pub fn set_fresh(&mut self, keys: HashSet<String>) {
+        self.fresh = keys;
+    }
+
+    pub fn set_unplayed(&mut self, keys: HashSet<String>) {
+        self.unplayed = keys;
+    }
+
+    pub fn set_live_slots(&mut self, slots: BTreeMap<u8, ShowSlot>) {
+        self.live_slots = slots;
+    }
+
+    /// Progress of the show on a live item's channel, if its times are known.
+    pub fn set_genre_count(&mut self, genre_id: String, count: u64) {
+        self.genre_counts.insert(genre_id, count);
+    }
+
+    /// Episode count shown on a genre row, once fetched.
+    pub fn genre_count(&self, item: &DiscoveryItem) -> Option<u64> {
+        let id = match item {
+            DiscoveryItem::NtsGenre { genre_id, .. } => genre_id,
+            DiscoveryItem::NtsGenreCategory { category_id, .. } => category_id,
+            _ => return None,
+        };
+        self.genre_counts.get(id).copied()
+    }
+
+    /// Rows on screen as last drawn (the first rows before the first draw).
+    pub fn rows_on_screen(&self) -> Vec<&DiscoveryItem> {
+        let (offset, fits) = self.viewport.get();
+        let fits = if fits == 0 {
+            DEFAULT_HALF_PAGE * 2
+        } else {
+            fits
+        };
+        self.rows().skip(offset).take(fits).collect()
+    }
+
+    pub fn live_slot(&self, item: &DiscoveryItem) -> Option<&ShowSlot> {
+        match item {
+            DiscoveryItem::NtsLiveChannel { channel, .. } => self.live_slots.get(channel),
+            _ => None,
+        }
+    }
+
+    #[allow(dead_code)] // used by integration tests
+    pub fn is_fresh(&self, item: &DiscoveryItem) -> bool {
+        self.fresh.contains(&item.favorite_key())
+    }
+
+    /// Advance to the next sort order and re-sort the loaded items.
+    pub fn cycle_sort(&mut self) {
+        self.sort = self.sort.next();
+        self.refilter();
+    }
+
+    #[allow(dead_code)] // used by integration tests
+    pub fn sort_order(&self) -> SortOrder {
+        self.sort
+    }
+
+    /// Rebuild the visible rows from all_items + current filter + sort.
+    fn refilter(&mut self) {
+        let query = self.filter_query.as_deref().map(fold_for_search);
+        let all = &self.all_items;
+        self.items = (0..all.len())
+            .filter(|&i| matches_filter(&all[i], query.as_deref()))
+            .collect();
+        // Stable sorts keep API order for ties; undated items sink to the end.
+        match self.sort {
+            SortOrder::Default => {}
+            SortOrder::NewestFirst => self
+                .items
+                .sort_by(|&a, &b| compare_dates(&all[a], &all[b], true)),
+            SortOrder::OldestFirst => self
+                .items
+                .sort_by(|&a, &b| compare_dates(&all[a], &all[b], false)),
+            SortOrder::Alphabetical => self
+                .items
+                .sort_by_cached_key(|&i| fold_for_search(all[i].title())),
+        }
         self.state
             .select(if self.items.is_empty() { None } else { Some(0) });
     }
 
-    #[allow(dead_code)] // used by integration tests
-    pub fn visible_items(&self) -> &[DiscoveryItem] {
-        &self.items
+    /// The item on row `row`.
+    fn row(&self, row: usize) -> &DiscoveryItem {
+        &self.all_items[self.items[row]]
+    }
+
+    /// The visible items, in list order.
+    fn rows(&self) -> impl Iterator<Item = &DiscoveryItem> {
+        self.items.iter().map(|&i| &self.all_items[i])
     }
 
     #[allow(dead_code)] // used by integration tests
+    pub fn visible_items(&self) -> Vec<&DiscoveryItem> {
+        self.rows().collect()
+    }
+
     pub fn total_item_count(&self) -> usize {
         self.all_items.len()
     }
 
-    #[allow(dead_code)] // used by integration tests
     pub fn selected_index(&self) -> Option<usize> {
         self.state.selected()
     }
 
+    pub fn set_heading(&mut self, heading: Option<String>) {
+        self.heading = heading;
+    }
+
+    #[allow(dead_code)] // used by integration tests
+    pub fn heading(&self) -> Option<&str> {
+        self.heading.as_deref()
+    }
+
+    pub fn set_footer(&mut self, footer: Option<String>) {
+        self.footer = footer;
+    }
+
+    #[allow(dead_code)] // used by integration tests
+    pub fn footer(&self) -> Option<&str> {
+        self.footer.as_deref()
+    }
+
+    /// Set the playing track, or None when stopped.
+    pub fn set_pinned(&mut self, pinned: Option<PinnedTrack>) {
+        self.pinned = pinned;
+    }
+
+    /// Whether the pinned row is shown: something is playing, the list is
+    /// scrolled, and the playing item isn't among the rows on screen.
+    pub fn is_pin_shown(&self) -> bool {
+        let Some(pinned) = &self.pinned else {
+            return false;
+        };
+        let (offset, fits) = self.viewport.get();
+        offset > 0
+            && !self
+                .rows()
+                .skip(offset)
+                .take(fits)
+                .any(|item| item.favorite_key() == pinned.key)
+    }
+
+    /// Select the row with `favorite_key()` `key`; false if it isn't shown.
+    pub fn select_key(&mut self, key: &str) -> bool {
+        let found = self.rows().position(|item| item.favorite_key() == key);
+        if let Some(i) = found {
+            self.state.select(Some(i));
+        }
+        found.is_some()
+    }
+
+    /// Select the next row whose title starts with `letter`, after the
+    /// selected one and wrapping, so repeating the letter steps through
+    /// them. Group headers count too. False if no row starts with it.
+    pub fn jump_to_letter(&mut self, letter: char) -> bool {
+        let len = self.items.len();
+        if len == 0 {
+            return false;
+        }
+        let letter = fold_for_search(letter.encode_utf8(&mut [0; 4]));
+        let start = self.state.selected().map_or(0, |i| i + 1);
+        let found = (0..len)
+            .map(|step| (start + step) % len)
+            .find(|&i| fold_for_search(self.row(i).title().trim_start()).starts_with(&letter));
+        if let Some(i) = found {
+            self.state.select(Some(i));
+        }
+        found.is_some()
+    }
+
+    /// Select the playing item; false if it isn't in the list.
+    pub fn select_pinned(&mut self) -> bool {
+        match self.pinned.as_ref().map(|pinned| pinned.key.clone()) {
+            Some(key) => self.select_key(&key),
+            None => false,
+        }
+    }
+
+    /// A click on the pinned row jumps to the playing track. Returns whether
+    /// the event was consumed.
+    pub fn handle_mouse_event(&mut self, mouse: MouseEvent) -> bool {
+        let pin = self.pin_area.get();
+        let hit = mouse.kind == MouseEventKind::Down(MouseButton::Left)
+            && mouse.row == pin.y
+            && (pin.x..pin.x + pin.width).contains(&mouse.column);
+        if !hit {
+            return false;
+        }
+        if let Some(tx) = &self.action_tx {
+            tx.send(Action::JumpToPlaying).ok();
+        }
+        true
+    }
+
+    /// Show a progress line under results streamed in by a search.
+    pub fn start_search_progress(&mut self) {
+        self.searching = true;
+        self.search_total = None;
+    }
+
+    pub fn set_search_total(&mut self, total: u64) {
+        self.search_total = Some(total);
+    }
+
+    /// "Loading more… 96 / ~600" while a search streams in, then how many
+    /// results it ended with.
+    pub fn search_status(&self) -> Option<String> {
+        if !self.searching {
+            return None;
+        }
+        let loaded = self.all_items.len() as u64;
+        Some(match (self.loading, self.search_total) {
+            (true, Some(total)) => format!("Loading more… {} / ~{}", loaded, total),
+            (true, None) => format!("Loading more… {}", loaded),
+            (false, Some(total)) if loaded < total => {
+                format!("Loaded {} of ~{} results", loaded, total)
+            }
+            (false, _) if loaded == 1 => "1 result".to_string(),
+            (false, _) => format!("{} results", loaded),
+        })
+    }
+
+    pub fn set_empty_message(&mut self, message: Option<&'static str>) {
+        self.empty_message = message;
+    }
+
     pub fn set_loading(&mut self, loading: bool) {
         self.loading = loading;
     }
@@ -106,7 +601,189 @@ impl DiscoveryList {
     }
 
     pub fn selected_item(&self) -> Option<&DiscoveryItem> {
-        self.state.selected().and_then(|i| self.items.get(i))
+        self.state
+            .selected()
+            .and_then(|i| self.items.get(i))
+            .map(|&i| &self.all_items[i])
+    }
+
+    /// Play the selected item, or open the selected genre.
+    pub fn activate_selected(&self) -> anyhow::Result<()> {
+        let tx = self.action_tx.as_ref().expect("component not registered");
+        if let Some(item) = self.selected_item() {
+            match item {
+                DiscoveryItem::NtsGenre { genre_id, .. } => {
+                    tx.send(Action::SearchByGenre {
+                        genre_id: genre_id.clone(),
+                    })?;
+                }
+                DiscoveryItem::NtsMood { mood_id, .. } => {
+                    tx.send(Action::SearchByMood {
+                        mood_id: mood_id.clone(),
+                    })?;
+                }
+                DiscoveryItem::NtsPlace { name, .. } => {
+                    tx.send(Action::SearchByPlace {
+                        place: name.clone(),
+                    })?;
+                }
+                DiscoveryItem::NtsGenreCategory { category_id, .. } => {
+                    tx.send(Action::ToggleGenreCategory(category_id.clone()))?;
+                }
+                _ => {
+                    tx.send(Action::PlayItem(item.clone()))?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    // ── Motions ──
+
+    /// Move the selection `delta` rows, stopping at either end.
+    pub fn move_by(&mut self, delta: isize) {
+        if self.items.is_empty() {
+            return;
+        }
+        let current = self.state.selected().unwrap_or(0);
+        let target = current.saturating_add_signed(delta);
+        self.state.select(Some(target.min(self.items.len() - 1)));
+    }
+
+    pub fn select_first(&mut self) {
+        if !self.items.is_empty() {
+            self.state.select(Some(0));
+        }
+    }
+
+    pub fn select_last(&mut self) {
+        if !self.items.is_empty() {
+            self.state.select(Some(self.items.len() - 1));
+        }
+    }
+
+    /// Select row `number` (counted from 1, as shown), or the last row.
+    pub fn select_number(&mut self, number: usize) {
+        if !self.items.is_empty() {
+            let index = number.saturating_sub(1).min(self.items.len() - 1);
+            self.state.select(Some(index));
+        }
+    }
+
+    /// Rows in half a screen, as last drawn.
+    pub fn half_page(&self) -> usize {
+        match self.viewport.get().1 / 2 {
+            0 => DEFAULT_HALF_PAGE,
+            rows => rows,
+        }
+    }
+
+    // ── Find ──
+
+    /// Open the find prompt at the current selection.
+    pub fn start_find(&mut self) {
+        self.find = Some(Find {
+            query: String::new(),
+            typing: true,
+            origin: self.state.selected(),
+        });
+    }
+
+    /// True while the find prompt is taking keys.
+    pub fn is_finding(&self) -> bool {
+        self.find.as_ref().is_some_and(|find| find.typing)
+    }
+
+    /// The confirmed find query n/N jump to, if any.
+    pub fn find_query(&self) -> Option<&str> {
+        self.find
+            .as_ref()
+            .filter(|find| !find.typing)
+            .map(|find| find.query.as_str())
+    }
+
+    /// Close the prompt and forget the query; false if there was none.
+    pub fn clear_find(&mut self) -> bool {
+        self.find.take().is_some()
+    }
+
+    /// Feed a key to the open find prompt, jumping to the first match from
+    /// where it opened as the query grows. Returns whether the key was used.
+    pub fn handle_find_key(&mut self, key: KeyEvent) -> bool {
+        let Some(find) = self.find.as_mut().filter(|find| find.typing) else {
+            return false;
+        };
+        match key.code {
+            KeyCode::Char(c) => find.query.push(c),
+            KeyCode::Backspace => {
+                find.query.pop();
+            }
+            KeyCode::Enter => {
+                if find.query.is_empty() {
+                    self.find = None;
+                } else {
+                    find.typing = false;
+                }
+                return true;
+            }
+            KeyCode::Esc => {
+                let origin = find.origin;
+                self.find = None;
+                self.state.select(origin.filter(|i| *i < self.items.len()));
+                return true;
+            }
+            _ => return false,
+        }
+        let origin = find.origin;
+        let query = fold_for_search(&find.query);
+        match self.find_from(&query, origin.unwrap_or(0), true) {
+            Some(i) => self.state.select(Some(i)),
+            None => self.state.select(origin),
+        }
+        true
+    }
+
+    /// Jump to the next (or previous) row matching the confirmed query,
+    /// wrapping around. False if nothing matches.
+    pub fn find_next(&mut self, forward: bool) -> bool {
+        let Some(query) = self.find_query().map(fold_for_search) else {
+            return false;
+        };
+        let len = self.items.len();
+        if len == 0 {
+            return false;
+        }
+        let current = self.state.selected().unwrap_or(0);
+        let start = if forward {
+            (current + 1) % len
+        } else {
+            (current + len - 1) % len
+        };
+        match self.find_from(&query, start, forward) {
+            Some(i) => {
+                self.state.select(Some(i));
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// First row matching `query` (folded) starting at `start` and
+    /// wrapping, searching down or up.
+    fn find_from(&self, query: &str, start: usize, forward: bool) -> Option<usize> {
+        let len = self.items.len();
+        if query.is_empty() || len == 0 {
+            return None;
+        }
+        (0..len)
+            .map(|step| {
+                if forward {
+                    (start + step) % len
+                } else {
+                    (start + len - step) % len
+                }
+            })
+            .find(|&i| self.row(i).matches_search(query))
     }
 
     pub fn next(&mut self) {
@@ -138,7 +815,6 @@ impl Component for DiscoveryList {
     }
 
     fn handle_key_event(&mut self, key: KeyEvent) -> anyhow::Result<bool> {
-        let tx = self.action_tx.as_ref().expect("component not registered");
         match key.code {
             KeyCode::Down | KeyCode::Char('j') => {
                 self.next();
@@ -149,18 +825,7 @@ impl Component for DiscoveryList {
                 Ok(true)
             }
             KeyCode::Enter => {
-                if let Some(item) = self.selected_item() {
-                    match item {
-                        DiscoveryItem::NtsGenre { genre_id, .. } => {
-                            tx.send(Action::SearchByGenre {
-                                genre_id: genre_id.clone(),
-                            })?;
-                        }
-                        _ => {
-                            tx.send(Action::PlayItem(item.clone()))?;
-                        }
-                    }
-                }
+                self.activate_selected()?;
                 Ok(true)
             }
             _ => Ok(false),
@@ -174,8 +839,18 @@ impl Component for DiscoveryList {
         Ok(vec![])
     }
 
+    /// The loading spinner turns.
+    fn is_animating(&self) -> bool {
+        self.loading
+    }
+
     fn draw(&self, frame: &mut Frame, area: Rect, theme: &Theme) {
-        if self.loading {
+        self.pin_area.set(Rect::default());
+        if self.all_items.is_empty() {
+            self.viewport.set((0, 0));
+        }
+        // Streamed results stay visible while more load; the footer says so.
+        if self.loading && self.all_items.is_empty() {
             let idx = (self.frame_count / 3) as usize % BRAILLE_SPINNER.len();
             let spinner = BRAILLE_SPINNER[idx];
             let paragraph = Paragraph::new(Line::from(vec![
@@ -189,14 +864,145 @@ impl Component for DiscoveryList {
             return;
         }
 
+        if let (true, Some(message)) = (self.all_items.is_empty(), self.empty_message) {
+            frame.render_widget(
+                Paragraph::new(Line::from(Span::styled(
+                    format!("  {}", message),
+                    Style::default().fg(theme.text_dim),
+                ))),
+                area,
+            );
+            return;
+        }
+
+        // Reserve a header row for the heading, active sort order, and marked count.
+        let mut header_spans = Vec::new();
+        if let Some(heading) = &self.heading {
+            header_spans.push(Span::styled(
+                format!("  {}", heading),
+                Style::default()
+                    .fg(theme.secondary)
+                    .add_modifier(Modifier::BOLD),
+            ));
+        }
+        if self.sort != SortOrder::Default {
+            header_spans.push(Span::styled(
+                "  Sort: ",
+                Style::default().fg(theme.text_dim),
+            ));
+            header_spans.push(Span::styled(
+                self.sort.label(),
+                Style::default().fg(theme.secondary),
+            ));
+        }
+        if !self.marked.is_empty() {
+            header_spans.push(Span::styled(
+                format!("  {} marked", self.marked.len()),
+                Style::default().fg(theme.accent),
+            ));
+            header_spans.push(Span::styled(
+                " (Esc clears)",
+                Style::default().fg(theme.text_dim),
+            ));
+        }
+        let area = if header_spans.is_empty() {
+            area
+        } else {
+            let [header, rest] =
+                Layout::vertical([Constraint::Length(1), Constraint::Min(0)]).areas(area);
+            frame.render_widget(Paragraph::new(Line::from(header_spans)), header);
+            rest
+        };
+        let mut footer_spans = Vec::new();
+        if let Some(status) = self.search_status() {
+            if self.loading {
+                let idx = (self.frame_count / 3) as usize % BRAILLE_SPINNER.len();
+                footer_spans.push(Span::styled(
+                    format!("  {}", BRAILLE_SPINNER[idx]),
+                    Style::default().fg(theme.primary),
+                ));
+            }
+            footer_spans.push(Span::styled(
+                format!("  {}", status),
+                Style::default().fg(theme.text_dim),
+            ));
+        }
+        if let Some(find) = &self.find {
+            let cursor = if find.typing {
+                "_"
+            } else {
+                "  (n/N next/prev)"
+            };
+            footer_spans.push(Span::styled(
+                format!("  Find: {}{}", find.query, cursor),
+                Style::default().fg(theme.accent),
+            ));
+        }
+        if let Some(footer) = &self.footer {
+            footer_spans.push(Span::styled(
+                format!("  {}", footer),
+                Style::default().fg(theme.warning),
+            ));
+        }
+        let area = if footer_spans.is_empty() {
+            area
+        } else {
+            let [rest, footer_area] =
+                Layout::vertical([Constraint::Min(0), Constraint::Length(1)]).areas(area);
+            frame.render_widget(Paragraph::new(Line::from(footer_spans)), footer_area);
+            rest
+        };
+        let area = match self.pinned.as_ref().filter(|_| self.is_pin_shown()) {
+            Some(pinned) => {
+                let [pin_area, rest] =
+                    Layout::vertical([Constraint::Length(1), Constraint::Min(0)]).areas(area);
+                frame.render_widget(
+                    Paragraph::new(Line::from(vec![
+                        Span::styled(
+                            "  ▶ Now: ",
+                            Style::default()
+                                .fg(theme.accent)
+                                .add_modifier(Modifier::BOLD),
+                        ),
+                        Span::styled(pinned.title.as_str(), Style::default().fg(theme.text)),
+                        Span::styled(
+                            format!(" — {}", format_time(pinned.position_secs)),
+                            Style::default().fg(theme.text_dim),
+                        ),
+                    ])),
+                    pin_area,
+                );
+                self.pin_area.set(pin_area);
+                rest
+            }
+            None => area,
+        };
+
+        // Only the rows on screen become ListItems, so long result lists
+        // draw as fast as short ones. Each item takes two rows; the window
+        // scrolls just far enough to keep the selection in it.
+        let fits = (area.height / 2) as usize;
         let selected = self.state.selected();
-        let items: Vec<ListItem> = self
-            .items
-            .iter()
-            .enumerate()
+        let mut offset = self.state.offset().min(self.items.len().saturating_sub(1));
+        if let Some(sel) = selected {
+            offset = offset.min(sel).max((sel + 1).saturating_sub(fits.max(1)));
+        }
+        let window = offset..(offset + fits.max(1)).min(self.items.len());
+        let now = unix_now();
+        // Less the column the highlight symbol takes.
+        let row_width = usize::from(area.width).saturating_sub(1);
+        let items: Vec<ListItem> = window
+            .clone()
+            .map(|i| (i, self.row(i)))
             .map(|(i, item)| {
                 let is_selected = selected == Some(i);
-                let num = format!("{:02} ", i + 1);
+                let key = item.favorite_key();
+                let num = format!("{:02}", i + 1);
+                let mark = if self.marked.contains(&key) {
+                    Span::styled("●", Style::default().fg(theme.accent))
+                } else {
+                    Span::raw(" ")
+                };
 
                 let title_style = if is_selected {
                     Style::default()
@@ -219,16 +1025,74 @@ impl Component for DiscoveryList {
                     None
                 };
 
-                let line_spans = vec![
-                    Span::styled(num, Style::default().fg(theme.text_dim)),
-                    Span::styled(item.title(), title_style),
-                ];
+                let mut line_spans =
+                    vec![Span::styled(num, Style::default().fg(theme.text_dim)), mark];
+                match item {
+                    DiscoveryItem::NtsGenreCategory { expanded, .. } => {
+                        let fold = if *expanded { "▾ " } else { "▸ " };
+                        line_spans.push(Span::styled(fold, Style::default().fg(theme.secondary)));
+                    }
+                    // Sub-genres sit under their parent.
+                    DiscoveryItem::NtsGenre { genre_id, .. } if genre_depth(genre_id) > 0 => {
+                        line_spans.push(Span::raw("  ".repeat(genre_depth(genre_id))));
+                    }
+                    _ => {}
+                }
+                let mut badges = Vec::new();
+                if self.favorites.contains(&key) {
+                    badges.push(Span::styled(" ★", Style::default().fg(theme.warning)));
+                }
+                if matches!(item, DiscoveryItem::NtsEpisode { show_alias, .. }
+                    if self.followed_shows.contains(show_alias))
+                {
+                    badges.push(Span::styled(" ♥", Style::default().fg(theme.secondary)));
+                }
+                if self.fresh.contains(&key) {
+                    badges.push(Span::styled(
+                        " NEW",
+                        Style::default()
+                            .fg(theme.success)
+                            .add_modifier(Modifier::BOLD),
+                    ));
+                }
+                if self.unplayed.contains(&key) {
+                    badges.push(Span::styled(" ●", Style::default().fg(theme.accent)));
+                }
+                // The title gives way so the badges stay visible.
+                let title = truncate(
+                    item.title(),
+                    row_width.saturating_sub(spans_width(&line_spans) + spans_width(&badges)),
+                );
+                line_spans.push(Span::styled(title.into_owned(), title_style));
+                line_spans.extend(badges);
 
                 let title_line = Line::from(line_spans);
-                let sub_line = Line::from(vec![
+                let mut extras = Vec::new();
+                if let Some(count) = self.genre_count(item) {
+                    extras.push(Span::styled(
+                        format!(" · {} episodes", count),
+                        Style::default().fg(theme.text_dim),
+                    ));
+                }
+                if let Some(slot) = self.live_slot(item) {
+                    let (bar, label) = live_progress(slot, now, 8);
+                    extras.push(Span::styled(
+                        format!("  {bar} "),
+                        Style::default().fg(theme.accent),
+                    ));
+                    extras.push(Span::styled(label, Style::default().fg(theme.text_dim)));
+                }
+                let subtitle = item.subtitle();
+                let subtitle = truncate(
+                    &subtitle,
+                    row_width.saturating_sub(3 + spans_width(&extras)),
+                );
+                let mut sub_spans = vec![
                     Span::styled("   ", Style::default().fg(theme.text_dim)),
-                    Span::styled(item.subtitle(), Style::default().fg(subtitle_color)),
-                ]);
+                    Span::styled(subtitle.into_owned(), Style::default().fg(subtitle_color)),
+                ];
+                sub_spans.extend(extras);
+                let sub_line = Line::from(sub_spans);
 
                 let mut list_item = ListItem::new(vec![title_line, sub_line]);
                 if let Some(bg_color) = bg {
@@ -246,6 +1110,12 @@ impl Component for DiscoveryList {
             )
             .highlight_symbol("▌");
 
-        frame.render_stateful_widget(list, area, &mut self.state.clone());
+        let mut state = ListState::default().with_selected(
+            selected
+                .filter(|sel| window.contains(sel))
+                .map(|sel| sel - offset),
+        );
+        frame.render_stateful_widget(list, area, &mut state);
+        self.viewport.set((offset, fits));
     }
 }
@@ -0,0 +1,217 @@
+// Equalizer modal (press `e`): pick a preset or shape the five custom bands.
+// Every change is sent straight away, so it can be heard while adjusting.
+
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::{
+    layout::{Constraint, Layout, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+use tokio::sync::mpsc::UnboundedSender;
+
+use crate::action::Action;
+use crate::components::{centered_overlay, Component};
+use crate::player::equalizer::{clamp_gain, EqualizerPreset, BANDS_HZ, MAX_GAIN_DB};
+use crate::theme::Theme;
+
+// dB added or removed per `+` / `-` press.
+const GAIN_STEP_DB: f64 = 1.0;
+
+/// Overlay for choosing and tuning the equalizer.
+#[derive(Default)]
+pub struct EqualizerModal {
+    action_tx: Option<UnboundedSender<Action>>,
+    visible: bool,
+    preset: EqualizerPreset,
+    custom: [f64; 5],
+    /// Band being adjusted.
+    band: usize,
+}
+
+impl EqualizerModal {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn is_visible(&self) -> bool {
+        self.visible
+    }
+
+    pub fn show(&mut self, preset: EqualizerPreset, custom: [f64; 5]) {
+        self.visible = true;
+        self.preset = preset;
+        self.custom = custom;
+    }
+
+    pub fn hide(&mut self) {
+        self.visible = false;
+    }
+
+    #[allow(dead_code)] // used by integration tests
+    pub fn preset(&self) -> EqualizerPreset {
+        self.preset
+    }
+
+    fn select_preset(&mut self, step: isize) {
+        let all = EqualizerPreset::ALL;
+        let i = all.iter().position(|p| *p == self.preset).unwrap_or(0);
+        let next = (i as isize + step).clamp(0, all.len() as isize - 1) as usize;
+        if all[next] != self.preset {
+            self.preset = all[next];
+            self.send();
+        }
+    }
+
+    /// Change the selected band. Adjusting a built-in preset starts a custom
+    /// curve from its gains.
+    fn set_band_gain(&mut self, gain: impl FnOnce(f64) -> f64) {
+        if self.preset != EqualizerPreset::Custom {
+            self.custom = self.preset.gains(self.custom);
+            self.preset = EqualizerPreset::Custom;
+        }
+        self.custom[self.band] = clamp_gain(gain(self.custom[self.band]));
+        self.send();
+    }
+
+    fn send(&self) {
+        if let Some(tx) = &self.action_tx {
+            tx.send(Action::SetEqualizer {
+                preset: self.preset,
+                custom_gains: self.custom,
+            })
+            .ok();
+        }
+    }
+
+    /// `  910 Hz  ──────────┼████──────  +4 dB`, one cell per dB.
+    fn band_line(&self, band: usize, gain: f64, theme: &Theme) -> Line<'static> {
+        let half = MAX_GAIN_DB as usize;
+        let cells = gain.abs().round() as usize;
+        let (left, right) = if gain < 0.0 {
+            (
+                format!("{}{}", "─".repeat(half - cells), "█".repeat(cells)),
+                "─".repeat(half),
+            )
+        } else {
+            (
+                "─".repeat(half),
+                format!("{}{}", "█".repeat(cells), "─".repeat(half - cells)),
+            )
+        };
+        let editing = self.preset == EqualizerPreset::Custom && band == self.band;
+        let label_style = if editing {
+            Style::default()
+                .fg(theme.primary)
+                .add_modifier(Modifier::BOLD)
+        } else {
+            Style::default().fg(theme.text)
+        };
+        let hz = BANDS_HZ[band];
+        let label = match hz {
+            hz if hz % 1000 == 0 => format!("{}k", hz / 1000),
+            hz if hz > 1000 => format!("{:.1}k", hz as f64 / 1000.0),
+            hz => hz.to_string(),
+        };
+        Line::from(vec![
+            Span::styled(format!("  {:>5} Hz  ", label), label_style),
+            Span::styled(left, Style::default().fg(theme.accent)),
+            Span::styled("┼", Style::default().fg(theme.text_dim)),
+            Span::styled(right, Style::default().fg(theme.accent)),
+            Span::styled(format!("  {:+.0} dB", gain), label_style),
+        ])
+    }
+}
+
+impl Component for EqualizerModal {
+    fn register_action_handler(&mut self, tx: UnboundedSender<Action>) {
+        self.action_tx = Some(tx);
+    }
+
+    fn handle_key_event(&mut self, key: KeyEvent) -> anyhow::Result<bool> {
+        if !self.visible {
+            return Ok(false);
+        }
+        match key.code {
+            KeyCode::Esc | KeyCode::Char('q') | KeyCode::Char('e') | KeyCode::Enter => self.hide(),
+            KeyCode::Char('j') | KeyCode::Down => self.select_preset(1),
+            KeyCode::Char('k') | KeyCode::Up => self.select_preset(-1),
+            KeyCode::Char('h') | KeyCode::Left => self.band = self.band.saturating_sub(1),
+            KeyCode::Char('l') | KeyCode::Right => {
+                self.band = (self.band + 1).min(BANDS_HZ.len() - 1);
+            }
+            KeyCode::Char('+') | KeyCode::Char('=') => {
+                self.set_band_gain(|gain| gain + GAIN_STEP_DB)
+            }
+            KeyCode::Char('-') => self.set_band_gain(|gain| gain - GAIN_STEP_DB),
+            KeyCode::Char('0') => self.set_band_gain(|_| 0.0),
+            _ => {}
+        }
+        Ok(true)
+    }
+
+    fn draw(&self, frame: &mut Frame, area: Rect, theme: &Theme) {
+        if !self.visible {
+            return;
+        }
+        let overlay_area = centered_overlay(area, 66, 14);
+        frame.render_widget(Clear, overlay_area);
+
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(" Equalizer ")
+            .title_style(
+                Style::default()
+                    .fg(theme.primary)
+                    .add_modifier(Modifier::BOLD),
+            );
+        let inner = block.inner(overlay_area);
+        frame.render_widget(block, overlay_area);
+
+        let [presets, bands, footer] = Layout::vertical([
+            Constraint::Length(EqualizerPreset::ALL.len() as u16 + 1),
+            Constraint::Min(0),
+            Constraint::Length(1),
+        ])
+        .areas(inner);
+        let dim = Style::default().fg(theme.text_dim);
+
+        let preset_lines: Vec<Line> = EqualizerPreset::ALL
+            .iter()
+            .map(|preset| {
+                if *preset == self.preset {
+                    Line::from(Span::styled(
+                        format!("  ▸ {}", preset.label()),
+                        Style::default()
+                            .fg(theme.primary)
+                            .add_modifier(Modifier::BOLD),
+                    ))
+                } else {
+                    Line::from(Span::styled(
+                        format!("    {}", preset.label()),
+                        Style::default().fg(theme.text),
+                    ))
+                }
+            })
+            .collect();
+        frame.render_widget(Paragraph::new(preset_lines), presets);
+
+        let band_lines: Vec<Line> = self
+            .preset
+            .gains(self.custom)
+            .iter()
+            .enumerate()
+            .map(|(band, gain)| self.band_line(band, *gain, theme))
+            .collect();
+        frame.render_widget(Paragraph::new(band_lines), bands);
+
+        frame.render_widget(
+            Paragraph::new(Line::from(Span::styled(
+                "  j/k preset · h/l band · +/- gain · 0 reset band · Esc close",
+                dim,
+            ))),
+            footer,
+        );
+    }
+}
@@ -0,0 +1,362 @@
+// Favorites manager (press `*`): lists every favorite with when it was added,
+// filters by type or text, and deletes, pins, or reorders entries.
+
+use std::time::Duration;
+
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::{
+    layout::{Constraint, Layout, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph},
+    Frame,
+};
+use tokio::sync::mpsc::UnboundedSender;
+
+use crate::action::Action;
+use crate::api::models::{fold_for_search, DiscoveryItem};
+use crate::components::{centered_overlay, format_age, Component};
+use crate::db::Favorite;
+use crate::theme::Theme;
+
+/// Item types the view can be narrowed to, in cycling order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TypeFilter {
+    #[default]
+    All,
+    Live,
+    Episodes,
+    Urls,
+    Genres,
+}
+
+impl TypeFilter {
+    pub fn next(self) -> Self {
+        match self {
+            Self::All => Self::Live,
+            Self::Live => Self::Episodes,
+            Self::Episodes => Self::Urls,
+            Self::Urls => Self::Genres,
+            Self::Genres => Self::All,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::All => "All types",
+            Self::Live => "Live",
+            Self::Episodes => "Episodes",
+            Self::Urls => "URLs",
+            Self::Genres => "Genres",
+        }
+    }
+
+    fn matches(self, favorite: &Favorite) -> bool {
+        match self {
+            Self::All => true,
+            Self::Live => favorite.item_type == "live",
+            Self::Episodes => favorite.item_type == "episode",
+            Self::Urls => favorite.item_type == "url",
+            Self::Genres => favorite.item_type == "genre",
+        }
+    }
+}
+
+/// Overlay for browsing and managing favorites.
+#[derive(Default)]
+pub struct FavoritesView {
+    action_tx: Option<UnboundedSender<Action>>,
+    visible: bool,
+    /// All favorites in stored order.
+    favorites: Vec<Favorite>,
+    type_filter: TypeFilter,
+    query: String,
+    /// True while typing into the text filter.
+    typing: bool,
+    /// Selection within the filtered rows.
+    selected: usize,
+}
+
+impl FavoritesView {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn is_visible(&self) -> bool {
+        self.visible
+    }
+
+    pub fn show(&mut self, favorites: Vec<Favorite>) {
+        self.visible = true;
+        self.type_filter = TypeFilter::All;
+        self.query.clear();
+        self.typing = false;
+        self.selected = 0;
+        self.set_favorites(favorites);
+    }
+
+    pub fn hide(&mut self) {
+        self.visible = false;
+        self.typing = false;
+    }
+
+    /// Replace the list (after a delete or pin), keeping the selection in range.
+    pub fn set_favorites(&mut self, favorites: Vec<Favorite>) {
+        self.favorites = favorites;
+        self.clamp_selection();
+    }
+
+    /// Indices into `favorites` of the rows passing both filters.
+    fn visible_indices(&self) -> Vec<usize> {
+        let query = fold_for_search(&self.query);
+        self.favorites
+            .iter()
+            .enumerate()
+            .filter(|(_, f)| self.type_filter.matches(f))
+            .filter(|(_, f)| query.is_empty() || f.item.matches_search(&query))
+            .map(|(i, _)| i)
+            .collect()
+    }
+
+    /// Favorites currently listed, in display order.
+    pub fn visible(&self) -> Vec<&Favorite> {
+        self.visible_indices()
+            .into_iter()
+            .map(|i| &self.favorites[i])
+            .collect()
+    }
+
+    pub fn selected(&self) -> Option<&Favorite> {
+        let i = *self.visible_indices().get(self.selected)?;
+        self.favorites.get(i)
+    }
+
+    fn clamp_selection(&mut self) {
+        let len = self.visible_indices().len();
+        self.selected = self.selected.min(len.saturating_sub(1));
+    }
+
+    fn is_filtered(&self) -> bool {
+        self.type_filter != TypeFilter::All || !self.query.is_empty()
+    }
+
+    /// Swap the selected favorite with its neighbour. Only in the unfiltered
+    /// list, and never across the pinned / unpinned boundary.
+    fn move_selected(&mut self, down: bool) {
+        if self.is_filtered() {
+            return;
+        }
+        let i = self.selected;
+        let Some(j) = (if down {
+            i.checked_add(1)
+        } else {
+            i.checked_sub(1)
+        }) else {
+            return;
+        };
+        let (Some(a), Some(b)) = (self.favorites.get(i), self.favorites.get(j)) else {
+            return;
+        };
+        if a.pinned != b.pinned {
+            return;
+        }
+        self.favorites.swap(i, j);
+        self.selected = j;
+        self.send(Action::ReorderFavorites(
+            self.favorites.iter().map(|f| f.id).collect(),
+        ));
+    }
+
+    fn send(&self, action: Action) {
+        if let Some(tx) = &self.action_tx {
+            tx.send(action).ok();
+        }
+    }
+
+    fn handle_typing(&mut self, key: KeyEvent) {
+        match key.code {
+            KeyCode::Esc => {
+                self.query.clear();
+                self.typing = false;
+            }
+            KeyCode::Enter => self.typing = false,
+            KeyCode::Backspace => {
+                self.query.pop();
+            }
+            KeyCode::Char(c) => self.query.push(c),
+            _ => {}
+        }
+        self.selected = 0;
+        self.clamp_selection();
+    }
+}
+
+impl Component for FavoritesView {
+    fn register_action_handler(&mut self, tx: UnboundedSender<Action>) {
+        self.action_tx = Some(tx);
+    }
+
+    fn handle_key_event(&mut self, key: KeyEvent) -> anyhow::Result<bool> {
+        if !self.visible {
+            return Ok(false);
+        }
+        if self.typing {
+            self.handle_typing(key);
+            return Ok(true);
+        }
+        let count = self.visible_indices().len();
+        match key.code {
+            KeyCode::Esc | KeyCode::Char('q') => self.hide(),
+            KeyCode::Char('/') => self.typing = true,
+            KeyCode::Char('t') => {
+                self.type_filter = self.type_filter.next();
+                self.selected = 0;
+                self.clamp_selection();
+            }
+            KeyCode::Char('j') | KeyCode::Down if self.selected + 1 < count => {
+                self.selected += 1;
+            }
+            KeyCode::Char('k') | KeyCode::Up => self.selected = self.selected.saturating_sub(1),
+            KeyCode::Char('J') => self.move_selected(true),
+            KeyCode::Char('K') => self.move_selected(false),
+            KeyCode::Char('x') => {
+                if let Some(id) = self.selected().map(|f| f.id) {
+                    self.send(Action::DeleteFavorite(id));
+                }
+            }
+            KeyCode::Char('p') => {
+                if let Some(f) = self.selected() {
+                    let (id, pinned) = (f.id, !f.pinned);
+                    self.send(Action::PinFavorite { id, pinned });
+                }
+            }
+            KeyCode::Enter => {
+                if let Some(item) = self.selected().map(|f| f.item.clone()) {
+                    let action = match item {
+                        DiscoveryItem::NtsGenre { genre_id, .. } => {
+                            Action::SearchByGenre { genre_id }
+                        }
+                        DiscoveryItem::NtsMood { mood_id, .. } => Action::SearchByMood { mood_id },
+                        DiscoveryItem::NtsPlace { name, .. } => {
+                            Action::SearchByPlace { place: name }
+                        }
+                        item => Action::PlayItem(item),
+                    };
+                    self.send(action);
+                    self.hide();
+                }
+            }
+            _ => {}
+        }
+        Ok(true)
+    }
+
+    fn draw(&self, frame: &mut Frame, area: Rect, theme: &Theme) {
+        if !self.visible {
+            return;
+        }
+        let overlay_area = centered_overlay(area, 80, area.height.saturating_sub(4));
+        frame.render_widget(Clear, overlay_area);
+
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(" Favorites ")
+            .title_style(
+                Style::default()
+                    .fg(theme.primary)
+                    .add_modifier(Modifier::BOLD),
+            );
+        let inner = block.inner(overlay_area);
+        frame.render_widget(block, overlay_area);
+
+        let [header, list_area, footer] = Layout::vertical([
+            Constraint::Length(2),
+            Constraint::Min(0),
+            Constraint::Length(1),
+        ])
+        .areas(inner);
+
+        let dim = Style::default().fg(theme.text_dim);
+        let rows = self.visible();
+        let mut header_spans = vec![
+            Span::styled(" Type: ", dim),
+            Span::styled(
+                self.type_filter.label(),
+                Style::default().fg(theme.secondary),
+            ),
+            Span::styled("  Filter: ", dim),
+        ];
+        if self.typing || !self.query.is_empty() {
+            header_spans.push(Span::styled(
+                self.query.as_str(),
+                Style::default().fg(theme.text),
+            ));
+            if self.typing {
+                header_spans.push(Span::styled("█", Style::default().fg(theme.text)));
+            }
+        } else {
+            header_spans.push(Span::styled("none", dim));
+        }
+        header_spans.push(Span::styled(
+            format!("  {} of {}", rows.len(), self.favorites.len()),
+            dim,
+        ));
+        frame.render_widget(Paragraph::new(Line::from(header_spans)), header);
+
+        if rows.is_empty() {
+            let msg = if self.favorites.is_empty() {
+                "  No favorites yet — press f on an item to add it"
+            } else {
+                "  Nothing matches the filter"
+            };
+            frame.render_widget(
+                Paragraph::new(Line::from(Span::styled(msg, dim))),
+                list_area,
+            );
+        } else {
+            let items: Vec<ListItem> = rows
+                .iter()
+                .enumerate()
+                .map(|(i, f)| {
+                    let is_selected = i == self.selected;
+                    let title_style = if is_selected {
+                        Style::default()
+                            .fg(theme.primary)
+                            .add_modifier(Modifier::BOLD)
+                    } else {
+                        Style::default().fg(theme.text)
+                    };
+                    let pin = if f.pinned {
+                        Span::styled(" ◆ ", Style::default().fg(theme.accent))
+                    } else {
+                        Span::raw("   ")
+                    };
+                    let mut style = Style::default();
+                    if is_selected {
+                        style = style.bg(theme.selection_bg);
+                    }
+                    ListItem::new(Line::from(vec![
+                        pin,
+                        Span::styled(f.item.title().to_string(), title_style),
+                        Span::styled(format!("  {} {}", f.source, f.item_type), dim),
+                        Span::styled(
+                            format!("  added {}", format_age(Duration::from_secs(f.age_secs))),
+                            dim,
+                        ),
+                    ]))
+                    .style(style)
+                })
+                .collect();
+            let mut state = ListState::default();
+            state.select(Some(self.selected));
+            frame.render_stateful_widget(List::new(items), list_area, &mut state);
+        }
+
+        let hints = if self.typing {
+            "  Type to filter · Enter to keep · Esc to clear"
+        } else {
+            "  Enter play · x delete · p pin · J/K move · t type · / filter · Esc close"
+        };
+        frame.render_widget(Paragraph::new(Line::from(Span::styled(hints, dim))), footer);
+    }
+}
@@ -0,0 +1,403 @@
+// File and directory picker overlay, shared by features that need a path
+// (export, import). Browses the filesystem from a starting directory instead
+// of making the user type an absolute path.
+
+use std::path::{Path, PathBuf};
+
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::{
+    layout::{Constraint, Layout, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph},
+    Frame,
+};
+use serde::{Deserialize, Serialize};
+use tokio::sync::mpsc::UnboundedSender;
+
+use crate::action::Action;
+use crate::components::{centered_overlay, Component};
+use crate::playlist::PlaylistFormat;
+use crate::theme::Theme;
+
+/// What a picked path is for; decides whether files or directories are
+/// picked and which action the choice sends.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PickPurpose {
+    /// A directory to write export.json into.
+    Export,
+    /// An export file or M3U playlist to read.
+    Import,
+}
+
+impl PickPurpose {
+    fn title(self) -> &'static str {
+        match self {
+            PickPurpose::Export => " Export to… ",
+            PickPurpose::Import => " Import from… ",
+        }
+    }
+
+    /// Whether this purpose picks a directory rather than a file.
+    pub fn picks_directory(self) -> bool {
+        matches!(self, PickPurpose::Export)
+    }
+
+    /// Files worth listing when picking a file.
+    fn accepts(self, path: &Path) -> bool {
+        match self {
+            PickPurpose::Export => false,
+            PickPurpose::Import => {
+                path.extension().and_then(|e| e.to_str()) == Some("json")
+                    || PlaylistFormat::from_path(path) == Some(PlaylistFormat::M3u)
+            }
+        }
+    }
+
+    /// The action sent once `path` is chosen.
+    pub fn action(self, path: PathBuf) -> Action {
+        match self {
+            PickPurpose::Export => Action::ExportData(path.join("export.json")),
+            PickPurpose::Import if PlaylistFormat::from_path(&path).is_some() => {
+                Action::ImportPlaylist(path)
+            }
+            PickPurpose::Import => Action::ImportData(path),
+        }
+    }
+}
+
+/// One row of the listing.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PickerEntry {
+    pub name: String,
+    pub is_dir: bool,
+}
+
+/// Overlay for browsing to a file or directory.
+pub struct FilePicker {
+    action_tx: Option<UnboundedSender<Action>>,
+    visible: bool,
+    purpose: PickPurpose,
+    dir: PathBuf,
+    entries: Vec<PickerEntry>,
+    selected: usize,
+    show_hidden: bool,
+    /// Name being typed for a new directory, while creating one.
+    new_dir: Option<String>,
+    error: Option<String>,
+}
+
+impl Default for FilePicker {
+    fn default() -> Self {
+        Self {
+            action_tx: None,
+            visible: false,
+            purpose: PickPurpose::Export,
+            dir: PathBuf::new(),
+            entries: Vec::new(),
+            selected: 0,
+            show_hidden: false,
+            new_dir: None,
+            error: None,
+        }
+    }
+}
+
+impl FilePicker {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn is_visible(&self) -> bool {
+        self.visible
+    }
+
+    /// Open at `dir`, or at the nearest ancestor that exists.
+    pub fn show(&mut self, purpose: PickPurpose, dir: &Path) {
+        let mut dir = dir.to_path_buf();
+        while !dir.is_dir() {
+            if !dir.pop() {
+                dir = dirs::home_dir().unwrap_or_else(|| PathBuf::from("/"));
+                break;
+            }
+        }
+        self.visible = true;
+        self.purpose = purpose;
+        self.new_dir = None;
+        self.enter(dir);
+    }
+
+    pub fn hide(&mut self) {
+        self.visible = false;
+        self.new_dir = None;
+    }
+
+    #[allow(dead_code)] // used by integration tests
+    pub fn current_dir(&self) -> &Path {
+        &self.dir
+    }
+
+    #[allow(dead_code)] // used by integration tests
+    pub fn entries(&self) -> &[PickerEntry] {
+        &self.entries
+    }
+
+    #[allow(dead_code)] // used by integration tests
+    pub fn error(&self) -> Option<&str> {
+        self.error.as_deref()
+    }
+
+    /// Switch to `dir` and list it, selecting the first entry.
+    fn enter(&mut self, dir: PathBuf) {
+        self.dir = dir;
+        self.selected = 0;
+        self.refresh();
+    }
+
+    /// Re-read the current directory: directories first, then the files this
+    /// purpose accepts, each sorted case-insensitively.
+    fn refresh(&mut self) {
+        self.error = None;
+        let read = match std::fs::read_dir(&self.dir) {
+            Ok(read) => read,
+            Err(e) => {
+                self.entries.clear();
+                self.error = Some(format!("Can't read {}: {e}", self.dir.display()));
+                return;
+            }
+        };
+        let mut entries: Vec<PickerEntry> = read
+            .filter_map(Result::ok)
+            .filter_map(|entry| {
+                let name = entry.file_name().to_string_lossy().into_owned();
+                if name.starts_with('.') && !self.show_hidden {
+                    return None;
+                }
+                let path = entry.path();
+                let is_dir = path.is_dir();
+                (is_dir || self.purpose.accepts(&path)).then_some(PickerEntry { name, is_dir })
+            })
+            .collect();
+        entries.sort_by_key(|e| (!e.is_dir, e.name.to_lowercase()));
+        self.entries = entries;
+        self.selected = self.selected.min(self.entries.len().saturating_sub(1));
+    }
+
+    fn go_up(&mut self) {
+        let Some(parent) = self.dir.parent().map(Path::to_path_buf) else {
+            return;
+        };
+        let from = self
+            .dir
+            .file_name()
+            .map(|n| n.to_string_lossy().into_owned());
+        self.enter(parent);
+        // Keep the directory we came from selected.
+        if let Some(i) = from.and_then(|from| self.entries.iter().position(|e| e.name == from)) {
+            self.selected = i;
+        }
+    }
+
+    fn open_selected(&mut self) {
+        let Some(entry) = self.entries.get(self.selected).cloned() else {
+            return;
+        };
+        let path = self.dir.join(&entry.name);
+        if entry.is_dir {
+            self.enter(path);
+        } else {
+            self.pick(path);
+        }
+    }
+
+    fn pick(&mut self, path: PathBuf) {
+        if let Some(tx) = &self.action_tx {
+            tx.send(self.purpose.action(path)).ok();
+        }
+        self.hide();
+    }
+
+    fn create_dir(&mut self, name: &str) {
+        let name = name.trim();
+        if name.is_empty()
+            || name.contains(std::path::MAIN_SEPARATOR)
+            || name == "."
+            || name == ".."
+        {
+            self.error = Some("Not a valid directory name".to_string());
+            return;
+        }
+        let path = self.dir.join(name);
+        match std::fs::create_dir_all(&path) {
+            Ok(()) => self.enter(path),
+            Err(e) => self.error = Some(format!("Can't create {name}: {e}")),
+        }
+    }
+
+    fn handle_new_dir_key(&mut self, key: KeyEvent) {
+        let Some(name) = self.new_dir.as_mut() else {
+            return;
+        };
+        match key.code {
+            KeyCode::Esc => self.new_dir = None,
+            KeyCode::Enter => {
+                let name = self.new_dir.take().unwrap_or_default();
+                self.create_dir(&name);
+            }
+            KeyCode::Backspace => {
+                name.pop();
+            }
+            KeyCode::Char(c) => name.push(c),
+            _ => {}
+        }
+    }
+}
+
+impl Component for FilePicker {
+    fn register_action_handler(&mut self, tx: UnboundedSender<Action>) {
+        self.action_tx = Some(tx);
+    }
+
+    fn handle_key_event(&mut self, key: KeyEvent) -> anyhow::Result<bool> {
+        if !self.visible {
+            return Ok(false);
+        }
+        if self.new_dir.is_some() {
+            self.handle_new_dir_key(key);
+            return Ok(true);
+        }
+        match key.code {
+            KeyCode::Esc | KeyCode::Char('q') => self.hide(),
+            KeyCode::Char('j') | KeyCode::Down if self.selected + 1 < self.entries.len() => {
+                self.selected += 1;
+            }
+            KeyCode::Char('k') | KeyCode::Up => self.selected = self.selected.saturating_sub(1),
+            KeyCode::Char('h') | KeyCode::Left | KeyCode::Backspace => self.go_up(),
+            KeyCode::Char('l') | KeyCode::Right
+                if self.entries.get(self.selected).is_some_and(|e| e.is_dir) =>
+            {
+                self.open_selected();
+            }
+            KeyCode::Enter => self.open_selected(),
+            KeyCode::Char('~') => {
+                if let Some(home) = dirs::home_dir() {
+                    self.enter(home);
+                }
+            }
+            KeyCode::Char('.') => {
+                self.show_hidden = !self.show_hidden;
+                self.refresh();
+            }
+            KeyCode::Char('n') => {
+                self.error = None;
+                self.new_dir = Some(String::new());
+            }
+            KeyCode::Char('s') if self.purpose.picks_directory() => {
+                self.pick(self.dir.clone());
+            }
+            _ => {}
+        }
+        Ok(true)
+    }
+
+    fn draw(&self, frame: &mut Frame, area: Rect, theme: &Theme) {
+        if !self.visible {
+            return;
+        }
+        let overlay_area = centered_overlay(area, 70, area.height.saturating_sub(6));
+        frame.render_widget(Clear, overlay_area);
+
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(self.purpose.title())
+            .title_style(
+                Style::default()
+                    .fg(theme.primary)
+                    .add_modifier(Modifier::BOLD),
+            );
+        let inner = block.inner(overlay_area);
+        frame.render_widget(block, overlay_area);
+
+        let [header, list_area, status, footer] = Layout::vertical([
+            Constraint::Length(1),
+            Constraint::Min(0),
+            Constraint::Length(1),
+            Constraint::Length(1),
+        ])
+        .areas(inner);
+        let dim = Style::default().fg(theme.text_dim);
+
+        frame.render_widget(
+            Paragraph::new(Line::from(Span::styled(
+                format!(" {}", self.dir.display()),
+                Style::default().fg(theme.accent),
+            ))),
+            header,
+        );
+
+        if self.entries.is_empty() {
+            let empty = if self.purpose.picks_directory() {
+                "  No subdirectories"
+            } else {
+                "  No .json or .m3u files here"
+            };
+            frame.render_widget(
+                Paragraph::new(Line::from(Span::styled(empty, dim))),
+                list_area,
+            );
+        } else {
+            let items: Vec<ListItem> = self
+                .entries
+                .iter()
+                .enumerate()
+                .map(|(i, entry)| {
+                    let is_selected = i == self.selected;
+                    let mut name_style = if entry.is_dir {
+                        Style::default().fg(theme.secondary)
+                    } else {
+                        Style::default().fg(theme.text)
+                    };
+                    let mut style = Style::default();
+                    if is_selected {
+                        name_style = name_style.add_modifier(Modifier::BOLD);
+                        style = style.bg(theme.selection_bg);
+                    }
+                    let suffix = if entry.is_dir { "/" } else { "" };
+                    ListItem::new(Line::from(Span::styled(
+                        format!("  {}{}", entry.name, suffix),
+                        name_style,
+                    )))
+                    .style(style)
+                })
+                .collect();
+            let mut state = ListState::default();
+            state.select(Some(self.selected));
+            frame.render_stateful_widget(List::new(items), list_area, &mut state);
+        }
+
+        let status_line = if let Some(name) = &self.new_dir {
+            Line::from(vec![
+                Span::styled("  New directory: ", Style::default().fg(theme.accent)),
+                Span::raw(name.as_str()),
+                Span::styled("█", Style::default().fg(theme.text)),
+            ])
+        } else if let Some(err) = &self.error {
+            Line::from(Span::styled(
+                format!("  {err}"),
+                Style::default().fg(theme.error),
+            ))
+        } else {
+            Line::from("")
+        };
+        frame.render_widget(Paragraph::new(status_line), status);
+
+        let hint = if self.new_dir.is_some() {
+            "  Enter create · Esc cancel"
+        } else if self.purpose.picks_directory() {
+            "  Enter open · s use this directory · h up · . hidden · n new dir · Esc cancel"
+        } else {
+            "  Enter open/pick · h up · . hidden · n new dir · Esc cancel"
+        };
+        frame.render_widget(Paragraph::new(Line::from(Span::styled(hint, dim))), footer);
+    }
+}
@@ -0,0 +1,202 @@
+// Play history overlay (press `H`): what was played, when, how often, and for
+// how long. Entries can be replayed, deleted one by one, or cleared.
+
+use std::time::Duration;
+
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::{
+    layout::{Constraint, Layout, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph},
+    Frame,
+};
+use tokio::sync::mpsc::UnboundedSender;
+
+use crate::action::Action;
+use crate::api::models::DiscoveryItem;
+use crate::components::{centered_overlay, format_age, Component};
+use crate::db::HistoryEntry;
+use crate::theme::Theme;
+
+/// Overlay listing the play history.
+#[derive(Default)]
+pub struct HistoryView {
+    action_tx: Option<UnboundedSender<Action>>,
+    visible: bool,
+    entries: Vec<HistoryEntry>,
+    selected: usize,
+}
+
+impl HistoryView {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn is_visible(&self) -> bool {
+        self.visible
+    }
+
+    pub fn show(&mut self, entries: Vec<HistoryEntry>) {
+        self.visible = true;
+        self.selected = 0;
+        self.set_entries(entries);
+    }
+
+    pub fn hide(&mut self) {
+        self.visible = false;
+    }
+
+    /// Replace the entries (after a delete), keeping the selection in range.
+    pub fn set_entries(&mut self, entries: Vec<HistoryEntry>) {
+        self.entries = entries;
+        self.selected = self.selected.min(self.entries.len().saturating_sub(1));
+    }
+
+    #[allow(dead_code)] // used by integration tests
+    pub fn entries(&self) -> &[HistoryEntry] {
+        &self.entries
+    }
+
+    fn send(&self, action: Action) {
+        if let Some(tx) = &self.action_tx {
+            tx.send(action).ok();
+        }
+    }
+}
+
+/// Listening time, e.g. "45s", "12m", "1h 05m".
+pub fn format_listened(secs: f64) -> String {
+    let secs = secs.max(0.0) as u64;
+    match secs {
+        0..=59 => format!("{}s", secs),
+        60..=3599 => format!("{}m", secs / 60),
+        _ => format!("{}h {:02}m", secs / 3600, secs % 3600 / 60),
+    }
+}
+
+impl Component for HistoryView {
+    fn register_action_handler(&mut self, tx: UnboundedSender<Action>) {
+        self.action_tx = Some(tx);
+    }
+
+    fn handle_key_event(&mut self, key: KeyEvent) -> anyhow::Result<bool> {
+        if !self.visible {
+            return Ok(false);
+        }
+        match key.code {
+            KeyCode::Esc | KeyCode::Char('q') => self.hide(),
+            KeyCode::Char('j') | KeyCode::Down if self.selected + 1 < self.entries.len() => {
+                self.selected += 1;
+            }
+            KeyCode::Char('k') | KeyCode::Up => self.selected = self.selected.saturating_sub(1),
+            KeyCode::Char('x') => {
+                if let Some(entry) = self.entries.get(self.selected) {
+                    self.send(Action::DeleteHistoryEntry(entry.key.clone()));
+                }
+            }
+            KeyCode::Char('C') if !self.entries.is_empty() => self.send(Action::Confirm {
+                prompt: "Clear all play history?".to_string(),
+                action: Box::new(Action::ClearHistory),
+            }),
+            KeyCode::Enter => {
+                if let Some(item) = self.entries.get(self.selected).map(|e| e.item.clone()) {
+                    let action = match item {
+                        DiscoveryItem::NtsGenre { genre_id, .. } => {
+                            Action::SearchByGenre { genre_id }
+                        }
+                        DiscoveryItem::NtsMood { mood_id, .. } => Action::SearchByMood { mood_id },
+                        DiscoveryItem::NtsPlace { name, .. } => {
+                            Action::SearchByPlace { place: name }
+                        }
+                        item => Action::PlayItem(item),
+                    };
+                    self.send(action);
+                    self.hide();
+                }
+            }
+            _ => {}
+        }
+        Ok(true)
+    }
+
+    fn draw(&self, frame: &mut Frame, area: Rect, theme: &Theme) {
+        if !self.visible {
+            return;
+        }
+        let overlay_area = centered_overlay(area, 80, area.height.saturating_sub(4));
+        frame.render_widget(Clear, overlay_area);
+
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(" History ")
+            .title_style(
+                Style::default()
+                    .fg(theme.primary)
+                    .add_modifier(Modifier::BOLD),
+            );
+        let inner = block.inner(overlay_area);
+        frame.render_widget(block, overlay_area);
+
+        let [list_area, footer] =
+            Layout::vertical([Constraint::Min(0), Constraint::Length(1)]).areas(inner);
+        let dim = Style::default().fg(theme.text_dim);
+
+        if self.entries.is_empty() {
+            frame.render_widget(
+                Paragraph::new(Line::from(Span::styled("  Nothing played yet", dim))),
+                list_area,
+            );
+        } else {
+            let items: Vec<ListItem> = self
+                .entries
+                .iter()
+                .enumerate()
+                .map(|(i, entry)| {
+                    let is_selected = i == self.selected;
+                    let title_style = if is_selected {
+                        Style::default()
+                            .fg(theme.primary)
+                            .add_modifier(Modifier::BOLD)
+                    } else {
+                        Style::default().fg(theme.text)
+                    };
+                    let plays = if entry.play_count == 1 {
+                        "1 play".to_string()
+                    } else {
+                        format!("{} plays", entry.play_count)
+                    };
+                    let mut style = Style::default();
+                    if is_selected {
+                        style = style.bg(theme.selection_bg);
+                    }
+                    ListItem::new(Line::from(vec![
+                        Span::styled(
+                            format!(" {:>8}  ", format_age(Duration::from_secs(entry.age_secs))),
+                            dim,
+                        ),
+                        Span::styled(entry.item.title().to_string(), title_style),
+                        Span::styled(
+                            format!(
+                                "  {} · {} listened",
+                                plays,
+                                format_listened(entry.listened_secs)
+                            ),
+                            dim,
+                        ),
+                    ]))
+                    .style(style)
+                })
+                .collect();
+            let mut state = ListState::default();
+            state.select(Some(self.selected));
+            frame.render_stateful_widget(List::new(items), list_area, &mut state);
+        }
+
+        let footer_line = Line::from(Span::styled(
+            "  Enter play · x delete · C clear all · Esc close",
+            dim,
+        ));
+        frame.render_widget(Paragraph::new(footer_line), footer);
+    }
+}
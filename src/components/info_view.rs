@@ -0,0 +1,205 @@
+// Info overlay (press `i`): the full description of the selected or playing
+// item with its genres, moods, location, broadcast date, and links.
+
+use std::cell::Cell;
+
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::{
+    layout::{Constraint, Layout, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph, Wrap},
+    Frame,
+};
+use tokio::sync::mpsc::UnboundedSender;
+
+use crate::action::Action;
+use crate::api::models::ItemInfo;
+use crate::components::{centered_overlay, Component};
+use crate::theme::Theme;
+
+/// Overlay showing an item's details.
+#[derive(Default)]
+pub struct InfoView {
+    action_tx: Option<UnboundedSender<Action>>,
+    visible: bool,
+    /// favorite_key() of the item shown.
+    key: String,
+    info: ItemInfo,
+    /// True until the API answers for items that have details to fetch.
+    loading: bool,
+    scroll: u16,
+    /// Last scroll offset that still shows text, from the latest draw.
+    max_scroll: Cell<u16>,
+}
+
+impl InfoView {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn is_visible(&self) -> bool {
+        self.visible
+    }
+
+    /// Open with what's known now; `loading` marks details still to come.
+    pub fn show(&mut self, key: String, info: ItemInfo, loading: bool) {
+        self.visible = true;
+        self.key = key;
+        self.info = info;
+        self.loading = loading;
+        self.scroll = 0;
+    }
+
+    /// Fill in fetched details, unless another item was opened meanwhile.
+    pub fn set_info(&mut self, key: &str, info: ItemInfo) {
+        if key != self.key {
+            return;
+        }
+        self.info = info;
+        self.loading = false;
+    }
+
+    pub fn hide(&mut self) {
+        self.visible = false;
+    }
+
+    #[allow(dead_code)] // used by integration tests
+    pub fn info(&self) -> &ItemInfo {
+        &self.info
+    }
+
+    #[allow(dead_code)] // used by integration tests
+    pub fn is_loading(&self) -> bool {
+        self.loading
+    }
+
+    fn lines(&self, theme: &Theme) -> Vec<Line<'static>> {
+        let heading = Style::default()
+            .fg(theme.secondary)
+            .add_modifier(Modifier::BOLD);
+        let dim = Style::default().fg(theme.text_dim);
+        let text = Style::default().fg(theme.text);
+        let row = |label: &str, value: String| {
+            Line::from(vec![
+                Span::styled(format!(" {label:<10}"), dim),
+                Span::styled(value, text),
+            ])
+        };
+
+        let mut lines = Vec::new();
+        if !self.info.genres.is_empty() {
+            lines.push(row("Genres", self.info.genres.join(", ")));
+        }
+        if !self.info.moods.is_empty() {
+            lines.push(row("Moods", self.info.moods.join(", ")));
+        }
+        if let Some(location) = &self.info.location {
+            lines.push(row("Location", location.clone()));
+        }
+        if let Some(broadcast) = &self.info.broadcast {
+            // Dates only; the time is the UTC start of the slot.
+            lines.push(row("Broadcast", broadcast.chars().take(10).collect()));
+        }
+        if let Some(url) = &self.info.url {
+            lines.push(row("URL", url.clone()));
+        }
+        if !lines.is_empty() {
+            lines.push(Line::from(""));
+        }
+
+        match &self.info.description {
+            Some(description) => {
+                lines.extend(
+                    description
+                        .lines()
+                        .map(|line| Line::from(Span::styled(format!(" {}", line.trim()), text))),
+                );
+            }
+            None if self.loading => lines.push(Line::from(Span::styled(" Loading…", dim))),
+            None => lines.push(Line::from(Span::styled(" No description", dim))),
+        }
+
+        if !self.info.links.is_empty() {
+            lines.push(Line::from(""));
+            lines.push(Line::from(Span::styled(" Links", heading)));
+            lines.extend(
+                self.info
+                    .links
+                    .iter()
+                    .map(|link| Line::from(Span::styled(format!("  {link}"), dim))),
+            );
+        }
+        lines
+    }
+}
+
+/// Rows `lines` take when wrapped to `width` columns.
+fn wrapped_height(lines: &[Line], width: u16) -> u16 {
+    let width = usize::from(width.max(1));
+    lines
+        .iter()
+        .map(|line| line.width().max(1).div_ceil(width))
+        .sum::<usize>()
+        .try_into()
+        .unwrap_or(u16::MAX)
+}
+
+impl Component for InfoView {
+    fn register_action_handler(&mut self, tx: UnboundedSender<Action>) {
+        self.action_tx = Some(tx);
+    }
+
+    fn handle_key_event(&mut self, key: KeyEvent) -> anyhow::Result<bool> {
+        if !self.visible {
+            return Ok(false);
+        }
+        match key.code {
+            KeyCode::Esc | KeyCode::Char('q') | KeyCode::Char('i') => self.hide(),
+            KeyCode::Char('j') | KeyCode::Down if self.scroll < self.max_scroll.get() => {
+                self.scroll += 1;
+            }
+            KeyCode::Char('k') | KeyCode::Up => self.scroll = self.scroll.saturating_sub(1),
+            _ => {}
+        }
+        Ok(true)
+    }
+
+    fn draw(&self, frame: &mut Frame, area: Rect, theme: &Theme) {
+        if !self.visible {
+            return;
+        }
+        let overlay_area = centered_overlay(area, 76, area.height.saturating_sub(4));
+        frame.render_widget(Clear, overlay_area);
+
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(format!(" {} ", self.info.title))
+            .title_style(
+                Style::default()
+                    .fg(theme.primary)
+                    .add_modifier(Modifier::BOLD),
+            );
+        let inner = block.inner(overlay_area);
+        frame.render_widget(block, overlay_area);
+
+        let [body, footer] =
+            Layout::vertical([Constraint::Min(0), Constraint::Length(1)]).areas(inner);
+        let lines = self.lines(theme);
+        self.max_scroll
+            .set(wrapped_height(&lines, body.width).saturating_sub(body.height));
+        frame.render_widget(
+            Paragraph::new(lines)
+                .wrap(Wrap { trim: false })
+                .scroll((self.scroll, 0)),
+            body,
+        );
+        frame.render_widget(
+            Paragraph::new(Line::from(Span::styled(
+                "  j/k scroll · Esc close",
+                Style::default().fg(theme.text_dim),
+            ))),
+            footer,
+        );
+    }
+}
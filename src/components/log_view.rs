@@ -0,0 +1,211 @@
+// Log viewer overlay (press `D`): the newest lines of the app's log, filtered
+// by level, for watching what the app is doing while it seems stuck. `v`
+// switches debug logging on and off without a restart.
+
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::{
+    layout::Rect,
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+use tokio::sync::mpsc::UnboundedSender;
+use tracing::Level;
+
+use crate::action::Action;
+use crate::components::{centered_overlay, Component};
+use crate::logging::{LogLine, Logs};
+use crate::theme::Theme;
+
+/// Levels `l` steps through, most verbose first.
+const LEVELS: [Level; 5] = [
+    Level::TRACE,
+    Level::DEBUG,
+    Level::INFO,
+    Level::WARN,
+    Level::ERROR,
+];
+
+/// Overlay showing the in-memory log.
+pub struct LogView {
+    action_tx: Option<UnboundedSender<Action>>,
+    visible: bool,
+    logs: Logs,
+    /// The most verbose level shown.
+    level: Level,
+    /// Lines scrolled up from the newest; 0 follows the log as it grows.
+    scroll: usize,
+}
+
+impl Default for LogView {
+    fn default() -> Self {
+        Self {
+            action_tx: None,
+            visible: false,
+            logs: Logs::default(),
+            level: Level::DEBUG,
+            scroll: 0,
+        }
+    }
+}
+
+impl LogView {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Show the lines collected in `logs`.
+    pub fn set_logs(&mut self, logs: Logs) {
+        self.logs = logs;
+    }
+
+    pub fn is_visible(&self) -> bool {
+        self.visible
+    }
+
+    pub fn show(&mut self) {
+        self.visible = true;
+        self.scroll = 0;
+    }
+
+    pub fn hide(&mut self) {
+        self.visible = false;
+    }
+
+    #[allow(dead_code)] // used by integration tests
+    pub fn level(&self) -> Level {
+        self.level
+    }
+
+    /// The buffered lines at or above the chosen level, oldest first.
+    pub fn visible_lines(&self) -> Vec<LogLine> {
+        self.logs
+            .lines()
+            .into_iter()
+            .filter(|line| line.level <= self.level)
+            .collect()
+    }
+
+    /// Step to the next less verbose level, wrapping round to trace.
+    fn cycle_level(&mut self) {
+        let index = LEVELS.iter().position(|l| *l == self.level).unwrap_or(0);
+        self.level = LEVELS[(index + 1) % LEVELS.len()];
+        self.scroll = 0;
+    }
+
+    fn toggle_debug(&self) {
+        if let Err(e) = self.logs.set_debug(!self.logs.is_debug()) {
+            tracing::warn!("couldn't switch debug logging: {e}");
+        }
+    }
+
+    fn level_style(level: Level, theme: &Theme) -> Style {
+        match level {
+            Level::ERROR => Style::default().fg(theme.error),
+            Level::WARN => Style::default().fg(theme.warning),
+            Level::INFO => Style::default().fg(theme.text),
+            _ => Style::default().fg(theme.text_dim),
+        }
+    }
+}
+
+/// Wall-clock time of day in UTC, as "HH:MM:SS".
+fn clock(time: SystemTime) -> String {
+    let secs = time
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+        % 86_400;
+    format!(
+        "{:02}:{:02}:{:02}",
+        secs / 3600,
+        secs % 3600 / 60,
+        secs % 60
+    )
+}
+
+impl Component for LogView {
+    fn register_action_handler(&mut self, tx: UnboundedSender<Action>) {
+        self.action_tx = Some(tx);
+    }
+
+    fn handle_key_event(&mut self, key: KeyEvent) -> anyhow::Result<bool> {
+        if !self.visible {
+            return Ok(false);
+        }
+        match key.code {
+            KeyCode::Esc | KeyCode::Char('q') | KeyCode::Char('D') => self.hide(),
+            KeyCode::Char('k') | KeyCode::Up => {
+                let max = self.visible_lines().len().saturating_sub(1);
+                self.scroll = (self.scroll + 1).min(max);
+            }
+            KeyCode::Char('j') | KeyCode::Down => self.scroll = self.scroll.saturating_sub(1),
+            KeyCode::Char('g') | KeyCode::Home => {
+                self.scroll = self.visible_lines().len().saturating_sub(1);
+            }
+            KeyCode::Char('G') | KeyCode::End => self.scroll = 0,
+            KeyCode::Char('l') => self.cycle_level(),
+            KeyCode::Char('v') => self.toggle_debug(),
+            _ => {}
+        }
+        Ok(true)
+    }
+
+    fn draw(&self, frame: &mut Frame, area: Rect, theme: &Theme) {
+        if !self.visible {
+            return;
+        }
+        let overlay_area = centered_overlay(area, 120, 40);
+        frame.render_widget(Clear, overlay_area);
+
+        let debug = if self.logs.is_debug() {
+            ", debug on"
+        } else {
+            ""
+        };
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(format!(" Log ({} and above{debug}) ", self.level.as_str()))
+            .title_style(
+                Style::default()
+                    .fg(theme.primary)
+                    .add_modifier(Modifier::BOLD),
+            );
+        let inner = block.inner(overlay_area);
+        frame.render_widget(block, overlay_area);
+
+        let dim = Style::default().fg(theme.text_dim);
+        let rows = usize::from(inner.height.saturating_sub(1));
+        let lines = self.visible_lines();
+        let end = lines.len().saturating_sub(self.scroll);
+        let start = end.saturating_sub(rows);
+
+        let mut text: Vec<Line> = if lines.is_empty() {
+            vec![Line::from(Span::styled("  Nothing logged yet", dim))]
+        } else {
+            lines[start..end]
+                .iter()
+                .map(|line| {
+                    Line::from(vec![
+                        Span::styled(format!("{} ", clock(line.time)), dim),
+                        Span::styled(
+                            format!("{:<5} ", line.level.as_str()),
+                            Self::level_style(line.level, theme),
+                        ),
+                        Span::styled(format!("{}: ", line.target), dim),
+                        Span::styled(line.message.clone(), Style::default().fg(theme.text)),
+                    ])
+                })
+                .collect()
+        };
+        text.resize(rows, Line::from(""));
+        text.push(Line::from(Span::styled(
+            "j/k scroll  g/G oldest/newest  l level  v debug logging  Esc close",
+            dim,
+        )));
+        frame.render_widget(Paragraph::new(text), inner);
+    }
+}
@@ -1,15 +1,29 @@
 // UI components. Each implements the Component trait: register for actions,
 // handle key events, update state, and draw into a ratatui frame.
 
+pub mod about;
+pub mod confirm_modal;
+pub mod context_menu;
 pub mod direct_play_modal;
 pub mod discovery_list;
+pub mod equalizer_modal;
+pub mod favorites_view;
+pub mod file_picker;
+pub mod history_view;
+pub mod info_view;
+pub mod log_view;
+pub mod notifications;
 pub mod now_playing;
 pub mod nts;
 pub mod onboarding;
 pub mod play_controls;
+pub mod playlists_view;
 pub mod queue_list;
 pub mod search_bar;
 pub mod seek_modal;
+pub mod stats;
+pub mod text;
+pub mod tracklist_view;
 pub mod visualizers;
 
 use crossterm::event::KeyEvent;
@@ -18,6 +32,7 @@ use ratatui::Frame;
 use tokio::sync::mpsc::UnboundedSender;
 
 use crate::action::Action;
+use crate::api::models::ShowSlot;
 use crate::theme::Theme;
 
 /// Braille dot spinner frames, shared by loading indicators.
@@ -29,6 +44,58 @@ pub fn format_time(secs: f64) -> String {
     format!("{}:{:02}", total / 60, total % 60)
 }
 
+/// Format a length as "M:SS", or "H:MM:SS" from an hour up.
+pub fn format_duration(secs: f64) -> String {
+    let total = secs.max(0.0) as u64;
+    if total < 3600 {
+        format_time(secs.max(0.0))
+    } else {
+        format!("{}:{:02}:{:02}", total / 3600, total / 60 % 60, total % 60)
+    }
+}
+
+/// A live show's progress at `now`: a `width`-cell bar, and
+/// "M:SS elapsed · M:SS left".
+pub fn live_progress(slot: &ShowSlot, now: u64, width: usize) -> (String, String) {
+    let (elapsed, remaining) = slot.progress(now);
+    let filled = (elapsed * width as u64 / (elapsed + remaining).max(1)) as usize;
+    (
+        format!("{}{}", "━".repeat(filled), "─".repeat(width - filled)),
+        format!(
+            "{} elapsed · {} left",
+            format_time(elapsed as f64),
+            format_time(remaining as f64)
+        ),
+    )
+}
+
+/// Compact relative age, e.g. "12s ago", "3m ago", "1h ago", "2d ago".
+pub fn format_age(age: std::time::Duration) -> String {
+    let secs = age.as_secs();
+    match secs {
+        0..=59 => format!("{}s ago", secs),
+        60..=3599 => format!("{}m ago", secs / 60),
+        3600..=86_399 => format!("{}h ago", secs / 3600),
+        _ => format!("{}d ago", secs / 86_400),
+    }
+}
+
+/// Parse a typed timestamp: "M:SS", "H:MM:SS", or bare minutes ("45").
+pub fn parse_timestamp(input: &str) -> Option<f64> {
+    let parts: Vec<u64> = input
+        .trim()
+        .split(':')
+        .map(|p| p.parse().ok())
+        .collect::<Option<_>>()?;
+    let secs = match parts.as_slice() {
+        [m] => m * 60,
+        [m, s] if *s < 60 => m * 60 + s,
+        [h, m, s] if *m < 60 && *s < 60 => h * 3600 + m * 60 + s,
+        _ => return None,
+    };
+    Some(secs as f64)
+}
+
 /// Compute a centered overlay rectangle within `area`, clamped to fit.
 pub fn centered_overlay(area: Rect, width: u16, height: u16) -> Rect {
     let w = width.min(area.width.saturating_sub(4));
@@ -57,6 +124,12 @@ pub trait Component {
         Ok(vec![])
     }
 
+    /// Whether the next Tick changes what this component draws (a spinner,
+    /// the visualizer), so the App keeps drawing at full rate.
+    fn is_animating(&self) -> bool {
+        false
+    }
+
     /// Render this component into the given area.
     fn draw(&self, frame: &mut Frame, area: Rect, theme: &Theme);
 }
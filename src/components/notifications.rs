@@ -0,0 +1,269 @@
+// Stacked status notifications above the controls bar, each with a level and
+// its own lifetime, plus a history overlay of past messages (press `N`).
+
+use std::collections::VecDeque;
+use std::time::{Duration, Instant};
+
+use ratatui::{
+    layout::Rect,
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+use serde::{Deserialize, Serialize};
+use tokio::sync::mpsc::UnboundedSender;
+
+use crate::action::Action;
+use crate::components::{centered_overlay, format_age, Component};
+use crate::theme::Theme;
+
+// Most notifications shown at once; older ones drop off the stack early.
+const MAX_VISIBLE: usize = 3;
+// Past notifications kept for the history overlay.
+const HISTORY_LIMIT: usize = 100;
+
+/// Severity of a notification; decides its colour, icon, and default lifetime.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum NotifyLevel {
+    Info,
+    Warn,
+    Error,
+}
+
+impl NotifyLevel {
+    fn default_ttl(self) -> Duration {
+        match self {
+            Self::Info => Duration::from_secs(3),
+            Self::Warn => Duration::from_secs(5),
+            Self::Error => Duration::from_secs(8),
+        }
+    }
+
+    fn icon(self) -> &'static str {
+        match self {
+            Self::Info => " ℹ ",
+            Self::Warn | Self::Error => " ⚠ ",
+        }
+    }
+}
+
+/// A single message for the user.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Notification {
+    pub level: NotifyLevel,
+    pub message: String,
+    /// How long the message stays on screen.
+    pub ttl: Duration,
+}
+
+impl Notification {
+    pub fn new(level: NotifyLevel, message: impl Into<String>) -> Self {
+        Self {
+            level,
+            message: message.into(),
+            ttl: level.default_ttl(),
+        }
+    }
+
+    pub fn info(message: impl Into<String>) -> Self {
+        Self::new(NotifyLevel::Info, message)
+    }
+
+    pub fn warn(message: impl Into<String>) -> Self {
+        Self::new(NotifyLevel::Warn, message)
+    }
+
+    pub fn error(message: impl Into<String>) -> Self {
+        Self::new(NotifyLevel::Error, message)
+    }
+
+    #[allow(dead_code)] // used by integration tests
+    pub fn with_ttl(mut self, ttl: Duration) -> Self {
+        self.ttl = ttl;
+        self
+    }
+}
+
+struct Entry {
+    notification: Notification,
+    shown_at: Instant,
+}
+
+impl Entry {
+    fn expired(&self, now: Instant) -> bool {
+        now.duration_since(self.shown_at) >= self.notification.ttl
+    }
+}
+
+/// Active notification stack and the history overlay.
+#[derive(Default)]
+pub struct Notifications {
+    /// On-screen notifications, oldest first.
+    active: Vec<Entry>,
+    /// Everything shown this session, newest first.
+    history: VecDeque<Entry>,
+    history_visible: bool,
+}
+
+impl Notifications {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Show a notification. Repeating the newest message restarts its timer
+    /// instead of stacking a duplicate.
+    pub fn push(&mut self, notification: Notification) {
+        let now = Instant::now();
+        if let Some(last) = self.active.last_mut() {
+            if last.notification == notification {
+                last.shown_at = now;
+                return;
+            }
+        }
+        self.history.push_front(Entry {
+            notification: notification.clone(),
+            shown_at: now,
+        });
+        self.history.truncate(HISTORY_LIMIT);
+        self.active.push(Entry {
+            notification,
+            shown_at: now,
+        });
+        if self.active.len() > MAX_VISIBLE {
+            self.active.remove(0);
+        }
+    }
+
+    /// Drop notifications whose lifetime has run out by `now`.
+    pub fn expire(&mut self, now: Instant) {
+        self.active.retain(|e| !e.expired(now));
+    }
+
+    /// Remove every on-screen notification of `level`.
+    pub fn dismiss(&mut self, level: NotifyLevel) {
+        self.active.retain(|e| e.notification.level != level);
+    }
+
+    /// On-screen notifications, oldest first.
+    pub fn active(&self) -> impl Iterator<Item = &Notification> {
+        self.active.iter().map(|e| &e.notification)
+    }
+
+    #[allow(dead_code)] // used by integration tests
+    pub fn has_error(&self) -> bool {
+        self.active().any(|n| n.level == NotifyLevel::Error)
+    }
+
+    /// Past notifications, newest first.
+    #[allow(dead_code)] // used by integration tests
+    pub fn history(&self) -> impl Iterator<Item = &Notification> {
+        self.history.iter().map(|e| &e.notification)
+    }
+
+    /// Rows the stack needs below the main panels.
+    pub fn height(&self) -> u16 {
+        self.active.len() as u16
+    }
+
+    pub fn is_history_visible(&self) -> bool {
+        self.history_visible
+    }
+
+    pub fn show_history(&mut self) {
+        self.history_visible = true;
+    }
+
+    pub fn hide_history(&mut self) {
+        self.history_visible = false;
+    }
+
+    fn line<'a>(notification: &'a Notification, theme: &Theme) -> Vec<Span<'a>> {
+        let (icon_color, text_color) = match notification.level {
+            NotifyLevel::Info => (theme.accent, theme.text),
+            NotifyLevel::Warn => (theme.warning, theme.warning),
+            NotifyLevel::Error => (theme.error, theme.warning),
+        };
+        vec![
+            Span::styled(notification.level.icon(), Style::default().fg(icon_color)),
+            Span::styled(
+                notification.message.as_str(),
+                Style::default().fg(text_color),
+            ),
+        ]
+    }
+
+    /// Draw the history overlay centered in `area`.
+    pub fn draw_history(&self, frame: &mut Frame, area: Rect, theme: &Theme) {
+        let overlay_area = centered_overlay(area, 72, 20);
+        frame.render_widget(Clear, overlay_area);
+
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(" Notifications ")
+            .title_style(
+                Style::default()
+                    .fg(theme.primary)
+                    .add_modifier(Modifier::BOLD),
+            );
+        let inner = block.inner(overlay_area);
+        frame.render_widget(block, overlay_area);
+
+        let now = Instant::now();
+        let mut lines: Vec<Line> = self
+            .history
+            .iter()
+            .take(inner.height.saturating_sub(2) as usize)
+            .map(|e| {
+                let mut spans = vec![Span::styled(
+                    format!("{:>8}", format_age(now.duration_since(e.shown_at))),
+                    Style::default().fg(theme.text_dim),
+                )];
+                spans.extend(Self::line(&e.notification, theme));
+                Line::from(spans)
+            })
+            .collect();
+        if lines.is_empty() {
+            lines.push(Line::from(Span::styled(
+                "  No notifications yet",
+                Style::default().fg(theme.text_dim),
+            )));
+        }
+        lines.push(Line::from(""));
+        lines.push(Line::from(Span::styled(
+            "  Press any key to close",
+            Style::default().fg(theme.text_dim),
+        )));
+        frame.render_widget(Paragraph::new(lines), inner);
+    }
+}
+
+impl Component for Notifications {
+    fn register_action_handler(&mut self, _tx: UnboundedSender<Action>) {}
+
+    fn update(&mut self, action: &Action) -> anyhow::Result<Vec<Action>> {
+        if let Action::Tick = action {
+            self.expire(Instant::now());
+        }
+        Ok(vec![])
+    }
+
+    /// Draw the active stack, one notification per row. Errors carry the
+    /// retry hint.
+    fn draw(&self, frame: &mut Frame, area: Rect, theme: &Theme) {
+        let lines: Vec<Line> = self
+            .active()
+            .map(|n| {
+                let mut spans = Self::line(n, theme);
+                if n.level == NotifyLevel::Error {
+                    spans.push(Span::styled(
+                        "  Press r to retry.",
+                        Style::default().fg(theme.text_dim),
+                    ));
+                }
+                Line::from(spans)
+            })
+            .collect();
+        frame.render_widget(Paragraph::new(lines), area);
+    }
+}
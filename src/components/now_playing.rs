@@ -1,5 +1,7 @@
 // Now-playing panel: track info, visualizer, and queue display.
 
+use std::collections::BTreeMap;
+
 use ratatui::{
     layout::{Constraint, Layout, Rect},
     style::{Modifier, Style},
@@ -10,13 +12,18 @@ use ratatui::{
 use tokio::sync::mpsc::UnboundedSender;
 
 use crate::action::Action;
-use crate::api::models::DiscoveryItem;
+use crate::api::models::{unix_now, DiscoveryItem, LiveTrack, ShowSlot, UrlMeta};
+use crate::components::queue_list::{self, QueueRow};
+use crate::components::text::truncate;
 use crate::components::visualizers::{create_visualizer, Visualizer, VisualizerKind};
 use crate::components::Component;
-use crate::components::{format_time, queue_list};
+use crate::components::{format_time, live_progress};
 use crate::player::StreamMetadata;
 use crate::theme::Theme;
 
+// Most earlier live songs listed under the visualizer.
+const MAX_EARLIER_ROWS: usize = 5;
+
 /// Right panel: track info, visualizer animation, and queue list.
 pub struct NowPlaying {
     action_tx: Option<UnboundedSender<Action>>,
@@ -26,14 +33,25 @@ pub struct NowPlaying {
     paused: bool,
     buffering: bool,
     stream_metadata: Option<StreamMetadata>,
-    queue_items: Vec<(String, String)>,
+    /// Songs from the live tracklist, newest (on air) first.
+    live_tracks: Vec<LiveTrack>,
+    /// Start and end of the show on air, per live channel.
+    live_slots: BTreeMap<u8, ShowSlot>,
+    queue_items: Vec<QueueRow>,
     queue_current: Option<usize>,
     visualizer: Box<dyn Visualizer>,
     visualizer_kind: VisualizerKind,
     audio_rms: f64,
     audio_peak: f64,
+    /// Latest spectrum bands from mpv; empty until the analysis filter reports.
+    audio_bands: Vec<f64>,
     /// Countdown ticks to show the visualizer label after switching.
     visualizer_label_ticks: u16,
+    /// Whether the queue goes under the track (it may be drawn elsewhere).
+    show_queue: bool,
+    show_visualizer: bool,
+    /// The visualizer covers the whole terminal (`Alt+v`).
+    fullscreen: bool,
 }
 
 impl Default for NowPlaying {
@@ -46,13 +64,19 @@ impl Default for NowPlaying {
             paused: false,
             buffering: false,
             stream_metadata: None,
+            live_tracks: Vec::new(),
+            live_slots: BTreeMap::new(),
             queue_items: Vec::new(),
             queue_current: None,
             visualizer: create_visualizer(VisualizerKind::Blob),
             visualizer_kind: VisualizerKind::Blob,
             audio_rms: 0.0,
             audio_peak: 0.0,
+            audio_bands: Vec::new(),
             visualizer_label_ticks: 0,
+            show_queue: true,
+            show_visualizer: true,
+            fullscreen: false,
         }
     }
 }
@@ -66,6 +90,64 @@ impl NowPlaying {
         }
     }
 
+    /// Whether the panel draws the queue under the track, and the visualizer.
+    pub fn set_layout(&mut self, show_queue: bool, show_visualizer: bool) {
+        self.show_queue = show_queue;
+        self.show_visualizer = show_visualizer;
+    }
+
+    /// Expand the visualizer over the whole terminal, or bring the panels
+    /// back.
+    pub fn set_fullscreen(&mut self, fullscreen: bool) {
+        self.fullscreen = fullscreen;
+    }
+
+    pub fn is_fullscreen(&self) -> bool {
+        self.fullscreen
+    }
+
+    /// Draw the visualizer alone into `area`, for the fullscreen view.
+    pub fn draw_visualizer(&self, frame: &mut Frame, area: Rect, theme: &Theme) {
+        self.visualizer.draw(frame, area, &theme.visualizer);
+    }
+
+    pub fn has_queue(&self) -> bool {
+        !self.queue_items.is_empty()
+    }
+
+    /// The queue, wherever the layout puts it.
+    pub fn draw_queue(&self, frame: &mut Frame, area: Rect, theme: &Theme) {
+        let current_left = self
+            .duration_secs
+            .map_or(0.0, |duration| duration - self.position_secs);
+        queue_list::draw(
+            frame,
+            area,
+            &self.queue_items,
+            self.queue_current,
+            current_left,
+            theme,
+        );
+    }
+
+    /// Rows for the earlier live songs (under a heading) and for the tags
+    /// or URL.
+    fn detail_heights(&self, item: &DiscoveryItem) -> (u16, u16) {
+        let has_tags = matches!(
+            item,
+            DiscoveryItem::NtsEpisode { genres, .. }
+            | DiscoveryItem::NtsLiveChannel { genres, .. }
+            if !genres.is_empty()
+        );
+        let has_url = matches!(item, DiscoveryItem::DirectUrl { .. });
+        let tag_height = if has_tags || has_url { 2 } else { 0 };
+        let earlier_height = match self.live_tracks.len() {
+            0 | 1 => 0,
+            n => n.min(MAX_EARLIER_ROWS + 1) as u16,
+        };
+        (earlier_height, tag_height)
+    }
+
     /// Prepare for a new track: set the item, reset playback state, clear old metadata.
     pub fn set_buffering(&mut self, item: DiscoveryItem) {
         self.current_item = Some(item);
@@ -74,6 +156,15 @@ impl NowPlaying {
         self.paused = false;
         self.buffering = true;
         self.stream_metadata = None;
+        self.live_tracks.clear();
+    }
+
+    /// Fold what yt-dlp found for `url` into the playing item if it's that
+    /// direct URL, keeping the playback state. Returns whether it changed.
+    pub fn refresh_item(&mut self, url: &str, title: Option<&str>, meta: &UrlMeta) -> bool {
+        self.current_item
+            .as_mut()
+            .is_some_and(|item| item.apply_url_meta(url, title, meta))
     }
 
     /// Clear all playback state (called on stop / playback finished).
@@ -83,11 +174,35 @@ impl NowPlaying {
         self.duration_secs = None;
         self.buffering = false;
         self.stream_metadata = None;
+        self.live_tracks.clear();
         self.audio_rms = 0.0;
         self.audio_peak = 0.0;
+        self.audio_bands.clear();
+    }
+
+    /// Live channel songs to show, newest first.
+    pub fn set_live_tracks(&mut self, tracks: Vec<LiveTrack>) {
+        self.live_tracks = tracks;
+    }
+
+    pub fn set_live_slots(&mut self, slots: BTreeMap<u8, ShowSlot>) {
+        self.live_slots = slots;
+    }
+
+    /// Start and end of the show on the playing live channel, if known.
+    pub fn live_slot(&self) -> Option<&ShowSlot> {
+        match self.current_item.as_ref()? {
+            DiscoveryItem::NtsLiveChannel { channel, .. } => self.live_slots.get(channel),
+            _ => None,
+        }
     }
 
-    pub fn set_queue(&mut self, items: Vec<(String, String)>, current_index: Option<usize>) {
+    #[allow(dead_code)] // used by integration tests
+    pub fn live_tracks(&self) -> &[LiveTrack] {
+        &self.live_tracks
+    }
+
+    pub fn set_queue(&mut self, items: Vec<QueueRow>, current_index: Option<usize>) {
         self.queue_items = items;
         self.queue_current = current_index;
     }
@@ -112,7 +227,6 @@ impl NowPlaying {
     pub fn position_secs(&self) -> f64 {
         self.position_secs
     }
-    #[allow(dead_code)] // used by integration tests
     pub fn is_paused(&self) -> bool {
         self.paused
     }
@@ -126,6 +240,10 @@ impl Component for NowPlaying {
     fn update(&mut self, action: &Action) -> anyhow::Result<Vec<Action>> {
         match action {
             Action::Tick => {
+                self.visualizer.set_bands(&self.audio_bands);
+                if self.visualizer_kind == VisualizerKind::Marquee {
+                    self.visualizer.set_title(&self.marquee_title());
+                }
                 self.visualizer.tick(
                     self.current_item.is_some(),
                     self.paused,
@@ -136,11 +254,12 @@ impl Component for NowPlaying {
                 );
                 self.visualizer_label_ticks = self.visualizer_label_ticks.saturating_sub(1);
             }
-            Action::AudioLevels { rms, peak } => {
-                if !self.paused {
-                    self.audio_rms = *rms;
-                    self.audio_peak = *peak;
-                }
+            Action::AudioLevels { rms, peak } if !self.paused => {
+                self.audio_rms = *rms;
+                self.audio_peak = *peak;
+            }
+            Action::AudioBands(bands) if !self.paused => {
+                self.audio_bands.clone_from(bands);
             }
             Action::PlayItem(item) => {
                 self.set_buffering(item.clone());
@@ -155,11 +274,15 @@ impl Component for NowPlaying {
             Action::StreamMetadataChanged(metadata) => {
                 self.stream_metadata = Some(metadata.clone());
             }
-            Action::TogglePlayPause => {
-                self.paused = !self.paused;
+            Action::TogglePlayPause | Action::PauseChanged(_) => {
+                self.paused = match action {
+                    Action::PauseChanged(paused) => *paused,
+                    _ => !self.paused,
+                };
                 if self.paused {
                     self.audio_rms = 0.0;
                     self.audio_peak = 0.0;
+                    self.audio_bands.clear();
                 }
             }
             Action::Stop | Action::PlaybackFinished => {
@@ -170,9 +293,26 @@ impl Component for NowPlaying {
         Ok(vec![])
     }
 
+    /// The visualizer moves while something plays, and its name shows for a
+    /// moment after switching.
+    fn is_animating(&self) -> bool {
+        (self.current_item.is_some() && !self.paused && (self.show_visualizer || self.fullscreen))
+            || self.visualizer_label_ticks > 0
+    }
+
     fn draw(&self, frame: &mut Frame, area: Rect, theme: &Theme) {
-        let has_queue = !self.queue_items.is_empty();
-        let chunks = if has_queue {
+        let has_queue = self.show_queue && self.has_queue();
+        let chunks = if has_queue && !self.show_visualizer {
+            // Just the track's rows, the rest for the queue.
+            let rows = match &self.current_item {
+                Some(item) => {
+                    let (earlier, tags) = self.detail_heights(item);
+                    4 + earlier + tags
+                }
+                None => 1,
+            };
+            Layout::vertical([Constraint::Length(rows + 1), Constraint::Min(0)]).split(area)
+        } else if has_queue {
             Layout::vertical([Constraint::Min(7), Constraint::Percentage(50)]).split(area)
         } else {
             Layout::vertical([Constraint::Min(0)]).split(area)
@@ -220,30 +360,18 @@ impl Component for NowPlaying {
                 inner,
             );
             if has_queue {
-                queue_list::draw(
-                    frame,
-                    chunks[1],
-                    &self.queue_items,
-                    self.queue_current,
-                    theme,
-                );
+                self.draw_queue(frame, chunks[1], theme);
             }
             return;
         };
 
         // Layout: track info | visualizer | tags
-        let has_tags = matches!(
-            item,
-            DiscoveryItem::NtsEpisode { genres, .. }
-            | DiscoveryItem::NtsLiveChannel { genres, .. }
-            if !genres.is_empty()
-        );
-        let has_url = matches!(item, DiscoveryItem::DirectUrl { .. });
-        let tag_height = if has_tags || has_url { 2 } else { 0 };
-
+        let (earlier_height, tag_height) = self.detail_heights(item);
+        let visualizer_height = if self.show_visualizer { 4 } else { 0 };
         let inner_chunks = Layout::vertical([
             Constraint::Length(4),
-            Constraint::Min(4),
+            Constraint::Min(visualizer_height),
+            Constraint::Length(earlier_height),
             Constraint::Length(tag_height),
         ])
         .split(inner);
@@ -252,64 +380,97 @@ impl Component for NowPlaying {
         self.draw_track_info(frame, inner_chunks[0], item, theme);
 
         // Visualizer
-        self.visualizer.draw(frame, inner_chunks[1]);
+        if self.show_visualizer {
+            self.visualizer
+                .draw(frame, inner_chunks[1], &theme.visualizer);
+        }
+
+        // Earlier live songs
+        self.draw_earlier_tracks(frame, inner_chunks[2], theme);
 
         // Tags / URL
-        self.draw_tags(frame, inner_chunks[2], item, theme);
+        self.draw_tags(frame, inner_chunks[3], item, theme);
 
         if has_queue {
-            queue_list::draw(
-                frame,
-                chunks[1],
-                &self.queue_items,
-                self.queue_current,
-                theme,
-            );
+            self.draw_queue(frame, chunks[1], theme);
         }
     }
 }
 
 impl NowPlaying {
-    fn draw_track_info(&self, frame: &mut Frame, area: Rect, item: &DiscoveryItem, theme: &Theme) {
-        let status = if self.buffering {
-            "⟳ Loading..."
-        } else if self.paused {
-            "⏸"
-        } else {
-            "▶"
-        };
-
+    /// Title and subtitle of `item`, with stream metadata folded in.
+    fn display_pair(&self, item: &DiscoveryItem) -> (String, String) {
         let m = self.stream_metadata.as_ref();
-        let (title_text, subtitle_text) = item.display_pair(
+        item.display_pair(
             m.and_then(|m| m.station_name.as_deref()),
             m.and_then(|m| m.display_title()).as_deref(),
             m.and_then(|m| m.display_subtitle()).as_deref(),
-        );
+        )
+    }
 
-        // NTS items: show stream metadata as a third line (DirectUrl items
-        // fold metadata into title/subtitle via display_pair instead).
-        let meta_line = if !matches!(item, DiscoveryItem::DirectUrl { .. }) {
+    /// NTS items: stream metadata shown as a third line (DirectUrl items
+    /// fold metadata into title/subtitle via display_pair instead). Live
+    /// channels prefer the song from the NTS tracklist.
+    fn meta_line(&self, item: &DiscoveryItem) -> Option<String> {
+        if let Some(track) = self.live_tracks.first() {
+            Some(track.display())
+        } else if !matches!(item, DiscoveryItem::DirectUrl { .. }) {
             self.stream_metadata
                 .as_ref()
                 .and_then(|m| m.display_title())
         } else {
             None
+        }
+    }
+
+    /// What the marquee visualizer spells out: the title, then the song on
+    /// air or the stream's own title when there is one.
+    fn marquee_title(&self) -> String {
+        let Some(item) = &self.current_item else {
+            return String::new();
         };
+        let (title, _) = self.display_pair(item);
+        match self.meta_line(item) {
+            Some(meta) if meta != title => format!("{title} - {meta}"),
+            _ => title,
+        }
+    }
 
+    fn draw_track_info(&self, frame: &mut Frame, area: Rect, item: &DiscoveryItem, theme: &Theme) {
+        let status = if self.buffering {
+            "⟳ Loading..."
+        } else if self.paused {
+            "⏸"
+        } else {
+            "▶"
+        };
+
+        let (title_text, subtitle_text) = self.display_pair(item);
+
+        let meta_line = self.meta_line(item).map(|meta| {
+            if self.live_tracks.is_empty() {
+                meta
+            } else {
+                format!("♪ {meta}")
+            }
+        });
+
+        // One row each, so the progress line below always shows.
+        let width = usize::from(area.width);
         let mut lines = vec![
             Line::from(Span::styled(
-                title_text,
+                truncate(&title_text, width).into_owned(),
                 Style::default().fg(theme.text).add_modifier(Modifier::BOLD),
             )),
             Line::from(Span::styled(
-                subtitle_text,
+                truncate(&subtitle_text, width).into_owned(),
                 Style::default().fg(theme.primary),
             )),
         ];
 
         if let Some(meta) = meta_line {
             lines.push(Line::from(Span::styled(
-                meta,
+                truncate(&meta, width).into_owned(),
                 Style::default().fg(theme.secondary),
             )));
         } else {
@@ -323,6 +484,13 @@ impl NowPlaying {
                 status,
                 Style::default().fg(theme.buffering),
             )));
+        } else if let Some(slot) = self.live_slot() {
+            let (bar, label) = live_progress(slot, unix_now(), 12);
+            lines.push(Line::from(vec![
+                Span::raw(format!("{status} ")),
+                Span::styled(format!("{bar} "), Style::default().fg(theme.accent)),
+                Span::raw(label),
+            ]));
         } else if let Some(dur) = self.duration_secs.filter(|_| !is_live) {
             lines.push(Line::from(format!(
                 "{} {} / {}",
@@ -341,6 +509,28 @@ impl NowPlaying {
         frame.render_widget(Paragraph::new(lines).wrap(Wrap { trim: true }), area);
     }
 
+    fn draw_earlier_tracks(&self, frame: &mut Frame, area: Rect, theme: &Theme) {
+        if area.height == 0 {
+            return;
+        }
+        let dim = Style::default().fg(theme.text_dim);
+        let mut lines = vec![Line::from(Span::styled("Earlier", dim))];
+        lines.extend(
+            self.live_tracks
+                .iter()
+                .skip(1)
+                .take(MAX_EARLIER_ROWS)
+                .map(|track| {
+                    let row = format!("  {}", track.display());
+                    Line::from(Span::styled(
+                        truncate(&row, usize::from(area.width)).into_owned(),
+                        dim,
+                    ))
+                }),
+        );
+        frame.render_widget(Paragraph::new(lines), area);
+    }
+
     fn draw_tags(&self, frame: &mut Frame, area: Rect, item: &DiscoveryItem, theme: &Theme) {
         let text: Option<String> = match item {
             DiscoveryItem::NtsEpisode { genres, .. }
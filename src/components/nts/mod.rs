@@ -1,6 +1,7 @@
-// Sub-tab bar (Live / Picks / Search) and lazy-load coordinator.
+// Sub-tab bar (Live / Picks / Search / Following / Favorites / History /
+// Playlists / For You / Podcasts) and lazy-load coordinator.
 
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 use std::fmt;
 
 use ratatui::{
@@ -10,22 +11,52 @@ use ratatui::{
     widgets::{Block, Borders, Paragraph},
     Frame,
 };
+use serde::{Deserialize, Serialize};
 use tokio::sync::mpsc::UnboundedSender;
 
 use crate::action::Action;
 use crate::components::Component;
 use crate::theme::Theme;
 
-#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum NtsSubTab {
     #[default]
     Live,
     Picks,
     Search,
+    Following,
+    Favorites,
+    History,
+    Playlists,
+    ForYou,
+    Podcasts,
 }
 
 impl NtsSubTab {
-    pub const ALL: [NtsSubTab; 3] = [Self::Live, Self::Picks, Self::Search];
+    pub const ALL: [NtsSubTab; 9] = [
+        Self::Live,
+        Self::Picks,
+        Self::Search,
+        Self::Following,
+        Self::Favorites,
+        Self::History,
+        Self::Playlists,
+        Self::ForYou,
+        Self::Podcasts,
+    ];
+
+    /// What the list shows when this tab has nothing to list.
+    pub fn empty_message(self) -> Option<&'static str> {
+        match self {
+            Self::Following => Some("Not following any shows yet — press F on an episode"),
+            Self::Favorites => Some("No favorites yet — press f on an item to add it"),
+            Self::History => Some("Nothing played yet"),
+            Self::Playlists => Some("No playlists yet — press P to create one"),
+            Self::ForYou => Some("Play or favorite a few episodes to get recommendations"),
+            Self::Podcasts => Some("No podcasts yet — press o here to subscribe to a feed URL"),
+            Self::Live | Self::Picks | Self::Search => None,
+        }
+    }
 }
 
 impl fmt::Display for NtsSubTab {
@@ -34,6 +65,12 @@ impl fmt::Display for NtsSubTab {
             Self::Live => write!(f, "Live"),
             Self::Picks => write!(f, "Picks"),
             Self::Search => write!(f, "Search"),
+            Self::Following => write!(f, "Following"),
+            Self::Favorites => write!(f, "Favorites"),
+            Self::History => write!(f, "History"),
+            Self::Playlists => write!(f, "Playlists"),
+            Self::ForYou => write!(f, "For You"),
+            Self::Podcasts => write!(f, "Podcasts"),
         }
     }
 }
@@ -43,6 +80,11 @@ pub struct NtsTab {
     action_tx: Option<UnboundedSender<Action>>,
     active_sub: NtsSubTab,
     loaded: HashSet<NtsSubTab>,
+    /// New episodes from followed shows, shown next to the Following tab.
+    following_badge: usize,
+    /// Item counts shown next to the Favorites, History, and Playlists tabs,
+    /// and unplayed episodes next to Podcasts.
+    counts: HashMap<NtsSubTab, usize>,
 }
 
 impl NtsTab {
@@ -69,6 +111,12 @@ impl NtsTab {
             NtsSubTab::Live => vec![Action::LoadNtsLive],
             NtsSubTab::Picks => vec![Action::LoadNtsPicks],
             NtsSubTab::Search => vec![Action::LoadGenres],
+            NtsSubTab::Following => vec![Action::LoadFollowing],
+            NtsSubTab::Favorites => vec![Action::LoadFavorites],
+            NtsSubTab::History => vec![Action::LoadHistory],
+            NtsSubTab::Playlists => vec![Action::LoadPlaylists],
+            NtsSubTab::ForYou => vec![Action::LoadForYou],
+            NtsSubTab::Podcasts => vec![Action::LoadPodcasts],
         }
     }
 
@@ -79,11 +127,28 @@ impl NtsTab {
 
     /// Get the current sub-tab index (0-based).
     pub fn active_index(&self) -> usize {
-        match self.active_sub {
-            NtsSubTab::Live => 0,
-            NtsSubTab::Picks => 1,
-            NtsSubTab::Search => 2,
-        }
+        NtsSubTab::ALL
+            .iter()
+            .position(|t| *t == self.active_sub)
+            .unwrap_or(0)
+    }
+
+    pub fn set_following_badge(&mut self, count: usize) {
+        self.following_badge = count;
+    }
+
+    #[allow(dead_code)] // used by integration tests
+    pub fn following_badge(&self) -> usize {
+        self.following_badge
+    }
+
+    pub fn set_count(&mut self, tab: NtsSubTab, count: usize) {
+        self.counts.insert(tab, count);
+    }
+
+    #[allow(dead_code)] // used by integration tests
+    pub fn count(&self, tab: NtsSubTab) -> usize {
+        self.counts.get(&tab).copied().unwrap_or(0)
     }
 
     /// Force a sub-tab to be re-fetched on next visit.
@@ -108,6 +173,13 @@ impl Component for NtsTab {
                 spans.push(Span::styled(" │ ", Style::default().fg(theme.border)));
             }
             let label = tab.to_string();
+            let badge = (*tab == NtsSubTab::Following && self.following_badge > 0)
+                .then(|| format!(" ({})", self.following_badge));
+            let count = self
+                .counts
+                .get(tab)
+                .filter(|n| **n > 0)
+                .map(|n| format!(" {}", n));
             if i == active_idx {
                 spans.push(Span::styled(
                     label,
@@ -118,6 +190,17 @@ impl Component for NtsTab {
             } else {
                 spans.push(Span::styled(label, Style::default().fg(theme.text_dim)));
             }
+            if let Some(badge) = badge {
+                spans.push(Span::styled(
+                    badge,
+                    Style::default()
+                        .fg(theme.accent)
+                        .add_modifier(Modifier::BOLD),
+                ));
+            }
+            if let Some(count) = count {
+                spans.push(Span::styled(count, Style::default().fg(theme.text_dim)));
+            }
         }
 
         let line = Line::from(spans);
@@ -14,7 +14,7 @@ use tokio::sync::mpsc::UnboundedSender;
 
 use crate::action::Action;
 use crate::components::{centered_overlay, Component};
-use crate::theme::{Theme, THEME_DARK, THEME_LIGHT};
+use crate::theme::{Theme, THEME_PRESETS};
 
 pub const SCREEN_WELCOME: &str = "welcome";
 pub const SCREEN_BROWSE: &str = "browse_nts";
@@ -36,7 +36,7 @@ pub struct Onboarding {
     active: bool,
     screens: Vec<&'static str>,
     current_index: usize,
-    selected_theme: usize, // 0 = dark, 1 = light
+    selected_theme: usize, // index into THEME_PRESETS
 }
 
 impl Onboarding {
@@ -80,11 +80,7 @@ impl Onboarding {
     }
 
     fn complete(&mut self) {
-        let theme = if self.selected_theme == 0 {
-            THEME_DARK.to_string()
-        } else {
-            THEME_LIGHT.to_string()
-        };
+        let theme = THEME_PRESETS[self.selected_theme].0.to_string();
         let completed_screens = self.screens.iter().map(|s| s.to_string()).collect();
         if let Some(tx) = &self.action_tx {
             tx.send(Action::OnboardingComplete {
@@ -129,15 +125,11 @@ impl Component for Onboarding {
             KeyCode::Left => {
                 self.prev_screen();
             }
-            KeyCode::Char('j') | KeyCode::Down => {
-                if current_screen == SCREEN_THEME {
-                    self.selected_theme = 1;
-                }
+            KeyCode::Char('j') | KeyCode::Down if current_screen == SCREEN_THEME => {
+                self.selected_theme = (self.selected_theme + 1).min(THEME_PRESETS.len() - 1);
             }
-            KeyCode::Char('k') | KeyCode::Up => {
-                if current_screen == SCREEN_THEME {
-                    self.selected_theme = 0;
-                }
+            KeyCode::Char('k') | KeyCode::Up if current_screen == SCREEN_THEME => {
+                self.selected_theme = self.selected_theme.saturating_sub(1);
             }
             KeyCode::Char('q') => {
                 if let Some(tx) = &self.action_tx {
@@ -273,51 +265,34 @@ impl Onboarding {
 
     fn screen_theme(&self, theme: &Theme) -> Vec<Line<'static>> {
         let dim = Style::default().fg(theme.text_dim);
-        let dark_theme = Theme::dark();
-        let light_theme = Theme::light();
-
-        let (dark_marker, light_marker) = if self.selected_theme == 0 {
-            ("> ", "  ")
-        } else {
-            ("  ", "> ")
-        };
         let selected = Style::default().fg(theme.text).add_modifier(Modifier::BOLD);
-        let dark_style = if self.selected_theme == 0 {
-            selected
-        } else {
-            dim
-        };
-        let light_style = if self.selected_theme == 1 {
-            selected
-        } else {
-            dim
-        };
 
-        vec![
+        let mut lines = vec![
             Line::from(""),
             screen_title("Choose Your Theme", theme),
             Line::from(""),
-            Line::from(vec![
-                Span::styled(dark_marker, dark_style),
-                Span::styled("Dark    ", dark_style),
-                Span::styled("████ ", Style::default().fg(dark_theme.primary)),
-                Span::styled("████ ", Style::default().fg(dark_theme.secondary)),
-                Span::styled("████ ", Style::default().fg(dark_theme.accent)),
-                Span::styled("████", Style::default().fg(dark_theme.success)),
-            ]),
-            Line::from(""),
-            Line::from(vec![
-                Span::styled(light_marker, light_style),
-                Span::styled("Light   ", light_style),
-                Span::styled("████ ", Style::default().fg(light_theme.primary)),
-                Span::styled("████ ", Style::default().fg(light_theme.secondary)),
-                Span::styled("████ ", Style::default().fg(light_theme.accent)),
-                Span::styled("████", Style::default().fg(light_theme.success)),
-            ]),
-            Line::from(""),
+        ];
+        for (i, (name, label)) in THEME_PRESETS.iter().enumerate() {
+            let preset = Theme::from_name(name);
+            let (marker, style) = if i == self.selected_theme {
+                ("> ", selected)
+            } else {
+                ("  ", dim)
+            };
+            lines.push(Line::from(vec![
+                Span::styled(marker, style),
+                Span::styled(format!("{:<13}", label), style),
+                Span::styled("███ ", Style::default().fg(preset.primary)),
+                Span::styled("███ ", Style::default().fg(preset.secondary)),
+                Span::styled("███ ", Style::default().fg(preset.accent)),
+                Span::styled("███", Style::default().fg(preset.success)),
+            ]));
+        }
+        lines.extend([
             Line::from(""),
             Line::from(Span::styled("Use j/k to select, Enter to finish", dim)),
             Line::from(""),
-        ]
+        ]);
+        lines
     }
 }
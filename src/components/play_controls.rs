@@ -1,8 +1,12 @@
-// Bottom status bar: playback state, keybinding hints, volume, and queue position.
+// Bottom status bar: playback state, seek bar, keybinding hints, volume, and
+// queue position with the time left in the queue.
 
+use std::cell::Cell;
+
+use crossterm::event::{MouseButton, MouseEvent, MouseEventKind};
 use ratatui::{
     layout::Rect,
-    style::{Modifier, Style},
+    style::{Color, Modifier, Style},
     text::{Line, Span},
     widgets::{Block, Borders, Paragraph},
     Frame,
@@ -10,9 +14,16 @@ use ratatui::{
 use tokio::sync::mpsc::UnboundedSender;
 
 use crate::action::Action;
-use crate::components::{Component, BRAILLE_SPINNER};
+use crate::components::queue_list::time_left;
+use crate::components::text::{display_width, spans_width, truncate};
+use crate::components::{format_time, Component, BRAILLE_SPINNER};
+use crate::player::buffer::BufferProfile;
+use crate::player::queue::QueueTime;
 use crate::theme::Theme;
 
+// Cells of the seek bar in the compact layout.
+const COMPACT_BAR_WIDTH: usize = 20;
+
 /// Bottom status bar showing playback state, keybinding hints, and queue info.
 #[derive(Default)]
 pub struct PlayControls {
@@ -22,11 +33,25 @@ pub struct PlayControls {
     buffering: bool,
     queue_pos: Option<usize>,
     queue_len: usize,
+    /// Length of what's queued after the current track.
+    queue_time: QueueTime,
     volume: Option<u8>,
     current_title: Option<String>,
     frame_count: u64,
     is_seekable: bool,
+    position: f64,
+    duration: f64,
+    buffered: Option<f64>,
+    /// Position under the mouse while the seek bar is being dragged.
+    scrub: Option<f64>,
+    /// Where the seek bar was last drawn, for mouse hit-testing.
+    bar_area: Cell<Rect>,
     skip_nts_intro: bool,
+    radio_mode: bool,
+    low_data: bool,
+    buffer_profile: BufferProfile,
+    /// Attempt number while a dropped live stream is being reconnected.
+    reconnecting: Option<u32>,
 }
 
 impl PlayControls {
@@ -39,6 +64,10 @@ impl PlayControls {
         self.queue_len = len;
     }
 
+    pub fn set_queue_time(&mut self, queue_time: QueueTime) {
+        self.queue_time = queue_time;
+    }
+
     pub fn set_buffering(&mut self, buffering: bool) {
         self.buffering = buffering;
     }
@@ -47,6 +76,89 @@ impl PlayControls {
         self.skip_nts_intro = val;
     }
 
+    /// Replace the title of the playing track (e.g. once a page resolves).
+    pub fn set_current_title(&mut self, title: String) {
+        if self.current_title.is_some() {
+            self.current_title = Some(title);
+        }
+    }
+
+    pub fn set_radio_mode(&mut self, val: bool) {
+        self.radio_mode = val;
+    }
+
+    pub fn set_low_data(&mut self, val: bool) {
+        self.low_data = val;
+    }
+
+    pub fn set_reconnecting(&mut self, attempt: Option<u32>) {
+        self.reconnecting = attempt;
+    }
+
+    pub fn set_buffer_profile(&mut self, profile: BufferProfile) {
+        self.buffer_profile = profile;
+    }
+
+    /// Rows needed to draw the bar, including borders.
+    pub fn height(&self) -> u16 {
+        if self.is_seekable {
+            5
+        } else {
+            4
+        }
+    }
+
+    /// Click or drag on the seek bar to scrub; releasing seeks there.
+    /// Returns whether the event was consumed.
+    pub fn handle_mouse_event(&mut self, mouse: MouseEvent) -> bool {
+        let bar = self.bar_area.get();
+        if !self.is_seekable || bar.width == 0 {
+            return false;
+        }
+        match mouse.kind {
+            MouseEventKind::Down(MouseButton::Left)
+                if mouse.row == bar.y && (bar.x..bar.x + bar.width).contains(&mouse.column) =>
+            {
+                self.scrub = Some(self.position_at(mouse.column));
+                true
+            }
+            MouseEventKind::Drag(MouseButton::Left) if self.scrub.is_some() => {
+                self.scrub = Some(self.position_at(mouse.column));
+                true
+            }
+            MouseEventKind::Up(MouseButton::Left) => {
+                let Some(target) = self.scrub.take() else {
+                    return false;
+                };
+                if let Some(tx) = &self.action_tx {
+                    tx.send(Action::SeekAbsolute(target)).ok();
+                }
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Track position for a terminal column on the seek bar, clamped to its ends.
+    fn position_at(&self, column: u16) -> f64 {
+        let bar = self.bar_area.get();
+        let offset = column
+            .saturating_sub(bar.x)
+            .min(bar.width.saturating_sub(1));
+        let frac = offset as f64 / bar.width.saturating_sub(1).max(1) as f64;
+        frac * self.duration
+    }
+
+    #[allow(dead_code)] // used by integration tests
+    pub fn scrub_position(&self) -> Option<f64> {
+        self.scrub
+    }
+
+    #[allow(dead_code)] // used by integration tests
+    pub fn buffer_profile(&self) -> BufferProfile {
+        self.buffer_profile
+    }
+
     #[allow(dead_code)] // used by integration tests
     pub fn is_playing(&self) -> bool {
         self.playing
@@ -65,6 +177,187 @@ impl PlayControls {
     }
 }
 
+impl PlayControls {
+    /// `12:34 ━━━━━●━━━━──────── 58:00  21%` — played, buffered, and remaining
+    /// segments. Records the bar's columns for mouse seeking.
+    /// The playback state symbol and its color.
+    fn status(&self, theme: &Theme) -> (&'static str, Color) {
+        let status = if self.buffering || self.reconnecting.is_some() {
+            let idx = (self.frame_count / 3) as usize % BRAILLE_SPINNER.len();
+            BRAILLE_SPINNER[idx]
+        } else if self.paused {
+            "⏸"
+        } else if self.playing {
+            if self.frame_count % 30 < 15 {
+                "♪ ▶"
+            } else {
+                "♫ ▶"
+            }
+        } else {
+            "■"
+        };
+
+        let color = if self.reconnecting.is_some() {
+            theme.warning
+        } else if self.buffering {
+            theme.buffering
+        } else if self.playing && !self.paused {
+            theme.success
+        } else {
+            theme.text_dim
+        };
+        (status, color)
+    }
+
+    /// The whole player on one row, for the compact layout and under the
+    /// fullscreen visualizer: state, title, a short seek bar, volume, and
+    /// `keys`, the key hints that matter most there.
+    pub fn draw_compact(
+        &self,
+        frame: &mut Frame,
+        area: Rect,
+        keys: &[(&str, &str)],
+        theme: &Theme,
+    ) {
+        let (status, status_color) = self.status(theme);
+        let dim = Style::default().fg(theme.text_dim);
+        let key_style = Style::default().fg(theme.text);
+
+        let mut right = Vec::new();
+        if let Some(volume) = self.volume {
+            right.push(Span::styled(
+                format!("  Vol {volume}%"),
+                Style::default().fg(theme.primary),
+            ));
+        }
+        for (key, desc) in keys {
+            right.push(Span::styled(format!("  {key}"), key_style));
+            right.push(Span::styled(desc.to_string(), dim));
+        }
+        right.push(Span::raw(" "));
+
+        let status = format!(" {status} ");
+        let width = usize::from(area.width);
+        let right_width = spans_width(&right);
+        let mut free = width.saturating_sub(display_width(&status) + right_width);
+
+        // A seek bar when there's room for it and a bit of the title.
+        let mut bar = Vec::new();
+        self.bar_area.set(Rect::default());
+        if self.is_seekable && free >= COMPACT_BAR_WIDTH + 36 {
+            let position = self.scrub.unwrap_or(self.position);
+            let elapsed = format!("  {} ", format_time(position));
+            let total = format!(" {}", format_time(self.duration));
+            let frac = if self.duration > 0.0 {
+                (position / self.duration).clamp(0.0, 1.0)
+            } else {
+                0.0
+            };
+            let head = (frac * (COMPACT_BAR_WIDTH - 1) as f64).round() as usize;
+            free -= elapsed.chars().count() + COMPACT_BAR_WIDTH + total.chars().count();
+            // The bar sits just left of the volume and keys.
+            let bar_x = width - right_width - total.chars().count() - COMPACT_BAR_WIDTH;
+            self.bar_area.set(Rect::new(
+                area.x + bar_x as u16,
+                area.y,
+                COMPACT_BAR_WIDTH as u16,
+                1,
+            ));
+            bar.push(Span::styled(elapsed, Style::default().fg(theme.text)));
+            bar.push(Span::styled(
+                "━".repeat(head),
+                Style::default().fg(theme.primary),
+            ));
+            bar.push(Span::styled("●", Style::default().fg(theme.primary)));
+            bar.push(Span::styled(
+                "─".repeat(COMPACT_BAR_WIDTH - head - 1),
+                Style::default().fg(theme.border),
+            ));
+            bar.push(Span::styled(total, dim));
+        }
+
+        let title = match (self.reconnecting, self.current_title.as_deref()) {
+            (Some(attempt), _) => format!("Reconnecting… (attempt {attempt})"),
+            (None, Some(title)) => title.to_string(),
+            (None, None) => "Nothing playing".to_string(),
+        };
+        let title = truncate(&title, free).into_owned();
+        let pad = free.saturating_sub(display_width(&title));
+
+        let mut spans = vec![
+            Span::styled(
+                status,
+                Style::default()
+                    .fg(status_color)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(title, Style::default().fg(theme.primary)),
+            Span::raw(" ".repeat(pad)),
+        ];
+        spans.extend(bar);
+        spans.extend(right);
+        frame.render_widget(Paragraph::new(Line::from(spans)), area);
+    }
+
+    fn seek_bar_line(&self, inner: Rect, theme: &Theme) -> Line<'static> {
+        let position = self.scrub.unwrap_or(self.position);
+        let percent = if self.duration > 0.0 {
+            (position / self.duration * 100.0).clamp(0.0, 100.0).round() as u32
+        } else {
+            0
+        };
+        let elapsed = format!(" {} ", format_time(position));
+        let total = format!(" {}  {:>3}%", format_time(self.duration), percent);
+
+        let reserved = (elapsed.chars().count() + total.chars().count()) as u16;
+        let width = inner.width.saturating_sub(reserved);
+        self.bar_area.set(Rect::new(
+            inner.x + elapsed.chars().count() as u16,
+            inner.y,
+            width,
+            1,
+        ));
+
+        let width = width as usize;
+        let frac = |secs: f64| {
+            if self.duration > 0.0 {
+                (secs / self.duration).clamp(0.0, 1.0)
+            } else {
+                0.0
+            }
+        };
+        let head = (frac(position) * width.saturating_sub(1) as f64).round() as usize;
+        let buffered = self.buffered.map_or(head, |b| {
+            (frac(b) * width.saturating_sub(1) as f64).round() as usize
+        });
+
+        let head_color = if self.scrub.is_some() {
+            theme.accent
+        } else {
+            theme.primary
+        };
+        let mut spans = vec![Span::styled(elapsed, Style::default().fg(theme.text))];
+        spans.push(Span::styled(
+            "━".repeat(head),
+            Style::default().fg(theme.primary),
+        ));
+        if width > 0 {
+            spans.push(Span::styled("●", Style::default().fg(head_color)));
+        }
+        let ahead = buffered.saturating_sub(head);
+        spans.push(Span::styled(
+            "━".repeat(ahead),
+            Style::default().fg(theme.text_dim),
+        ));
+        spans.push(Span::styled(
+            "─".repeat(width.saturating_sub(head + 1 + ahead)),
+            Style::default().fg(theme.border),
+        ));
+        spans.push(Span::styled(total, Style::default().fg(theme.text_dim)));
+        Line::from(spans)
+    }
+}
+
 impl Component for PlayControls {
     fn register_action_handler(&mut self, tx: UnboundedSender<Action>) {
         self.action_tx = Some(tx);
@@ -84,8 +377,12 @@ impl Component for PlayControls {
                 self.buffering = false;
                 self.current_title = Some(title.clone());
             }
-            Action::PlaybackPosition(_) => {
+            Action::PlaybackPosition(pos) => {
                 self.buffering = false;
+                self.position = *pos;
+            }
+            Action::PlaybackBuffered(cached) => {
+                self.buffered = Some(*cached);
             }
             Action::StreamMetadataChanged(ref metadata) => {
                 if let Some(title) = metadata.display_title() {
@@ -94,6 +391,7 @@ impl Component for PlayControls {
             }
             Action::PlaybackDuration(dur) => {
                 self.is_seekable = dur.is_some();
+                self.duration = dur.unwrap_or(0.0);
             }
             Action::PlaybackFinished | Action::Stop => {
                 self.playing = false;
@@ -101,10 +399,17 @@ impl Component for PlayControls {
                 self.buffering = false;
                 self.current_title = None;
                 self.is_seekable = false;
+                self.position = 0.0;
+                self.duration = 0.0;
+                self.buffered = None;
+                self.scrub = None;
             }
             Action::TogglePlayPause => {
                 self.paused = !self.paused;
             }
+            Action::PauseChanged(paused) => {
+                self.paused = *paused;
+            }
             Action::VolumeChanged(vol) => {
                 self.volume = Some(*vol);
             }
@@ -116,29 +421,14 @@ impl Component for PlayControls {
         Ok(vec![])
     }
 
-    fn draw(&self, frame: &mut Frame, area: Rect, theme: &Theme) {
-        let status = if self.buffering {
-            let idx = (self.frame_count / 3) as usize % BRAILLE_SPINNER.len();
-            BRAILLE_SPINNER[idx]
-        } else if self.paused {
-            "⏸"
-        } else if self.playing {
-            if self.frame_count % 30 < 15 {
-                "♪ ▶"
-            } else {
-                "♫ ▶"
-            }
-        } else {
-            "■"
-        };
+    /// The spinner turns while loading or reconnecting and the note blinks
+    /// while playing.
+    fn is_animating(&self) -> bool {
+        self.buffering || self.reconnecting.is_some() || (self.playing && !self.paused)
+    }
 
-        let status_color = if self.buffering {
-            theme.buffering
-        } else if self.playing && !self.paused {
-            theme.success
-        } else {
-            theme.text_dim
-        };
+    fn draw(&self, frame: &mut Frame, area: Rect, theme: &Theme) {
+        let (status, status_color) = self.status(theme);
 
         let border_color = if self.playing && !self.paused {
             theme.primary
@@ -147,11 +437,20 @@ impl Component for PlayControls {
         };
 
         let queue_info = if self.queue_len > 0 {
-            format!(
+            let current_left = if self.is_seekable {
+                self.duration - self.position
+            } else {
+                0.0
+            };
+            let mut info = format!(
                 "Track {}/{}",
                 self.queue_pos.unwrap_or(0) + 1,
                 self.queue_len
-            )
+            );
+            if let Some(left) = time_left(self.queue_time, current_left) {
+                info.push_str(&format!(" · {left}"));
+            }
+            info
         } else {
             String::new()
         };
@@ -190,12 +489,18 @@ impl Component for PlayControls {
             line1_spans.push(Span::styled(" Timeline", desc_style));
         }
 
-        if !track_display.is_empty() {
+        if let Some(attempt) = self.reconnecting {
+            line1_spans.push(Span::raw("  "));
+            line1_spans.push(Span::styled(
+                format!("Reconnecting… (attempt {attempt})"),
+                Style::default().fg(theme.warning),
+            ));
+        } else if !track_display.is_empty() {
             // Calculate used width so far
-            let used: usize = line1_spans.iter().map(|s| s.content.len()).sum();
+            let used = spans_width(&line1_spans);
             let available = (area.width as usize).saturating_sub(used + 4);
             if available > 5 {
-                let truncated: String = track_display.chars().take(available).collect();
+                let truncated = truncate(track_display, available).into_owned();
                 line1_spans.push(Span::raw("  "));
                 line1_spans.push(Span::styled(truncated, Style::default().fg(theme.primary)));
             }
@@ -238,13 +543,42 @@ impl Component for PlayControls {
             ));
         }
 
+        if self.radio_mode {
+            line2_spans.push(Span::raw("  "));
+            line2_spans.push(Span::styled("↻ Radio", Style::default().fg(theme.accent)));
+        }
+
+        if self.low_data {
+            line2_spans.push(Span::raw("  "));
+            line2_spans.push(Span::styled(
+                "◌ Low data",
+                Style::default().fg(theme.accent),
+            ));
+        }
+
+        if self.buffer_profile != BufferProfile::Default {
+            line2_spans.push(Span::raw("  "));
+            line2_spans.push(Span::styled(
+                format!("◔ {}", self.buffer_profile.label()),
+                Style::default().fg(theme.accent),
+            ));
+        }
+
         let line2 = Line::from(line2_spans);
 
         let block = Block::default()
             .borders(Borders::ALL)
             .border_style(Style::default().fg(border_color));
+        let inner = block.inner(area);
+
+        let mut lines = vec![line1, line2];
+        if self.is_seekable && inner.height > 2 {
+            lines.insert(0, self.seek_bar_line(inner, theme));
+        } else {
+            self.bar_area.set(Rect::default());
+        }
 
-        let paragraph = Paragraph::new(vec![line1, line2]).block(block);
+        let paragraph = Paragraph::new(lines).block(block);
         frame.render_widget(paragraph, area);
     }
 }
@@ -0,0 +1,287 @@
+// Playlist manager (press `P`): lists named playlists, creates, renames, and
+// deletes them, opens one in the Playlists tab, or loads it into the queue.
+// `+` / `=` open it to pick where the selected items or the queue go.
+
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::{
+    layout::{Constraint, Layout, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph},
+    Frame,
+};
+use tokio::sync::mpsc::UnboundedSender;
+
+use crate::action::Action;
+use crate::api::models::DiscoveryItem;
+use crate::components::{centered_overlay, Component};
+use crate::db::PlaylistSummary;
+use crate::theme::Theme;
+
+/// A playlist name being typed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Naming {
+    /// The playlist being renamed, or None for a new one.
+    id: Option<i64>,
+    text: String,
+}
+
+/// Overlay for browsing and managing playlists.
+#[derive(Default)]
+pub struct PlaylistsView {
+    action_tx: Option<UnboundedSender<Action>>,
+    visible: bool,
+    playlists: Vec<PlaylistSummary>,
+    selected: usize,
+    naming: Option<Naming>,
+    /// Items waiting for a playlist to be picked; empty when just browsing.
+    adding: Vec<DiscoveryItem>,
+}
+
+impl PlaylistsView {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn is_visible(&self) -> bool {
+        self.visible
+    }
+
+    pub fn show(&mut self, playlists: Vec<PlaylistSummary>) {
+        self.show_for_adding(playlists, Vec::new());
+    }
+
+    /// Open to pick the playlist `items` are added to.
+    pub fn show_for_adding(&mut self, playlists: Vec<PlaylistSummary>, items: Vec<DiscoveryItem>) {
+        self.visible = true;
+        self.selected = 0;
+        self.naming = None;
+        self.adding = items;
+        self.set_playlists(playlists);
+        // With nothing to pick from, start naming a new playlist.
+        if self.playlists.is_empty() && !self.adding.is_empty() {
+            self.start_naming(None);
+        }
+    }
+
+    pub fn hide(&mut self) {
+        self.visible = false;
+        self.naming = None;
+        self.adding.clear();
+    }
+
+    /// Replace the list (after a change), keeping the selection in range.
+    pub fn set_playlists(&mut self, playlists: Vec<PlaylistSummary>) {
+        self.playlists = playlists;
+        self.selected = self.selected.min(self.playlists.len().saturating_sub(1));
+    }
+
+    /// Move the selection to the playlist with this id, if listed.
+    pub fn select_id(&mut self, id: i64) {
+        if let Some(i) = self.playlists.iter().position(|p| p.id == id) {
+            self.selected = i;
+        }
+    }
+
+    #[allow(dead_code)] // used by integration tests
+    pub fn playlists(&self) -> &[PlaylistSummary] {
+        &self.playlists
+    }
+
+    #[allow(dead_code)] // used by integration tests
+    pub fn is_adding(&self) -> bool {
+        !self.adding.is_empty()
+    }
+
+    fn selected_id(&self) -> Option<i64> {
+        self.playlists.get(self.selected).map(|p| p.id)
+    }
+
+    fn start_naming(&mut self, id: Option<i64>) {
+        let text = id
+            .and_then(|id| self.playlists.iter().find(|p| p.id == id))
+            .map(|p| p.name.clone())
+            .unwrap_or_default();
+        self.naming = Some(Naming { id, text });
+    }
+
+    fn send(&self, action: Action) {
+        if let Some(tx) = &self.action_tx {
+            tx.send(action).ok();
+        }
+    }
+
+    fn handle_naming(&mut self, key: KeyEvent) {
+        let Some(naming) = self.naming.as_mut() else {
+            return;
+        };
+        match key.code {
+            KeyCode::Esc => self.naming = None,
+            KeyCode::Enter => {
+                let Naming { id, text } = self.naming.take().unwrap_or(Naming {
+                    id: None,
+                    text: String::new(),
+                });
+                self.send(match id {
+                    Some(id) => Action::RenamePlaylist { id, name: text },
+                    None => Action::CreatePlaylist(text),
+                });
+            }
+            KeyCode::Backspace => {
+                naming.text.pop();
+            }
+            KeyCode::Char(c) => naming.text.push(c),
+            _ => {}
+        }
+    }
+}
+
+impl Component for PlaylistsView {
+    fn register_action_handler(&mut self, tx: UnboundedSender<Action>) {
+        self.action_tx = Some(tx);
+    }
+
+    fn handle_key_event(&mut self, key: KeyEvent) -> anyhow::Result<bool> {
+        if !self.visible {
+            return Ok(false);
+        }
+        if self.naming.is_some() {
+            self.handle_naming(key);
+            return Ok(true);
+        }
+        match key.code {
+            KeyCode::Esc | KeyCode::Char('q') => self.hide(),
+            KeyCode::Char('j') | KeyCode::Down if self.selected + 1 < self.playlists.len() => {
+                self.selected += 1;
+            }
+            KeyCode::Char('k') | KeyCode::Up => self.selected = self.selected.saturating_sub(1),
+            KeyCode::Char('n') => self.start_naming(None),
+            KeyCode::Char('r') => {
+                if let Some(id) = self.selected_id() {
+                    self.start_naming(Some(id));
+                }
+            }
+            KeyCode::Char('x') => {
+                if let Some(playlist) = self.playlists.get(self.selected) {
+                    self.send(Action::Confirm {
+                        prompt: format!("Delete the playlist \"{}\"?", playlist.name),
+                        action: Box::new(Action::DeletePlaylist(playlist.id)),
+                    });
+                }
+            }
+            KeyCode::Char('l') => {
+                if let Some(id) = self.selected_id() {
+                    self.send(Action::LoadPlaylist(id));
+                    self.hide();
+                }
+            }
+            KeyCode::Enter => {
+                if let Some(id) = self.selected_id() {
+                    let action = if self.adding.is_empty() {
+                        Action::OpenPlaylist(id)
+                    } else {
+                        Action::AddToPlaylist {
+                            id,
+                            items: std::mem::take(&mut self.adding),
+                        }
+                    };
+                    self.send(action);
+                    self.hide();
+                }
+            }
+            _ => {}
+        }
+        Ok(true)
+    }
+
+    fn draw(&self, frame: &mut Frame, area: Rect, theme: &Theme) {
+        if !self.visible {
+            return;
+        }
+        let overlay_area = centered_overlay(area, 60, area.height.saturating_sub(8));
+        frame.render_widget(Clear, overlay_area);
+
+        let title = match self.adding.len() {
+            0 => " Playlists ".to_string(),
+            1 => " Add 1 item to… ".to_string(),
+            n => format!(" Add {} items to… ", n),
+        };
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(title)
+            .title_style(
+                Style::default()
+                    .fg(theme.primary)
+                    .add_modifier(Modifier::BOLD),
+            );
+        let inner = block.inner(overlay_area);
+        frame.render_widget(block, overlay_area);
+
+        let [list_area, footer] =
+            Layout::vertical([Constraint::Min(0), Constraint::Length(1)]).areas(inner);
+        let dim = Style::default().fg(theme.text_dim);
+
+        if self.playlists.is_empty() {
+            frame.render_widget(
+                Paragraph::new(Line::from(Span::styled(
+                    "  No playlists yet — press n to create one",
+                    dim,
+                ))),
+                list_area,
+            );
+        } else {
+            let items: Vec<ListItem> = self
+                .playlists
+                .iter()
+                .enumerate()
+                .map(|(i, playlist)| {
+                    let is_selected = i == self.selected;
+                    let name_style = if is_selected {
+                        Style::default()
+                            .fg(theme.primary)
+                            .add_modifier(Modifier::BOLD)
+                    } else {
+                        Style::default().fg(theme.text)
+                    };
+                    let mut style = Style::default();
+                    if is_selected {
+                        style = style.bg(theme.selection_bg);
+                    }
+                    let count = match playlist.item_count {
+                        1 => "1 item".to_string(),
+                        n => format!("{} items", n),
+                    };
+                    ListItem::new(Line::from(vec![
+                        Span::styled(format!("  {}", playlist.name), name_style),
+                        Span::styled(format!("  {}", count), dim),
+                    ]))
+                    .style(style)
+                })
+                .collect();
+            let mut state = ListState::default();
+            state.select(Some(self.selected));
+            frame.render_stateful_widget(List::new(items), list_area, &mut state);
+        }
+
+        let footer_line = if let Some(naming) = &self.naming {
+            let label = if naming.id.is_some() {
+                "  Rename to: "
+            } else {
+                "  New playlist: "
+            };
+            Line::from(vec![
+                Span::styled(label, Style::default().fg(theme.accent)),
+                Span::raw(naming.text.as_str()),
+                Span::styled("█", Style::default().fg(theme.text)),
+            ])
+        } else if self.adding.is_empty() {
+            Line::from(Span::styled(
+                "  Enter open · l load into queue · n new · r rename · x delete · Esc close",
+                dim,
+            ))
+        } else {
+            Line::from(Span::styled("  Enter add here · n new · Esc cancel", dim))
+        };
+        frame.render_widget(Paragraph::new(footer_line), footer);
+    }
+}
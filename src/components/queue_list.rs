@@ -1,4 +1,5 @@
-// Queue list: renders the playback queue below the now-playing panel.
+// Queue list: renders the playback queue below the now-playing panel, with
+// each entry's length where known and the time left in the whole queue.
 
 use ratatui::{
     layout::Rect,
@@ -8,14 +9,46 @@ use ratatui::{
     Frame,
 };
 
+use crate::components::format_duration;
+use crate::player::queue::{QueueTime, TrackLength};
 use crate::theme::Theme;
 
+/// One queue entry as listed.
+#[derive(Debug, Clone, PartialEq)]
+pub struct QueueRow {
+    pub title: String,
+    pub subtitle: String,
+    pub length: Option<TrackLength>,
+    /// Added by radio mode rather than by hand.
+    pub auto: bool,
+}
+
+/// A length as listed: estimates are marked with "~".
+pub fn length_label(length: TrackLength) -> String {
+    let prefix = if length.is_estimate() { "~" } else { "" };
+    format!("{prefix}{}", format_duration(length.secs()))
+}
+
+/// "1:23:45 left" for the rest of the current track (`current_left`
+/// seconds) and everything after it; "~" when part of it is estimated or
+/// unknown. None when there's nothing to count.
+pub fn time_left(upcoming: QueueTime, current_left: f64) -> Option<String> {
+    let secs = upcoming.secs + current_left.max(0.0);
+    if secs < 1.0 {
+        return None;
+    }
+    let prefix = if upcoming.approximate { "~" } else { "" };
+    Some(format!("{prefix}{} left", format_duration(secs)))
+}
+
 /// Render the playback queue as a styled list with key hints at the bottom.
+/// `current_left` is how much of the current track remains.
 pub fn draw(
     frame: &mut Frame,
     area: Rect,
-    items: &[(String, String)],
+    items: &[QueueRow],
     current: Option<usize>,
+    current_left: f64,
     theme: &Theme,
 ) {
     // Horizontal separator
@@ -27,10 +60,12 @@ pub fn draw(
         }
     }
 
-    let title = Line::from(Span::styled(
-        format!(" Queue ({})", items.len()),
-        Style::default().fg(theme.text_dim),
-    ));
+    let lengths: Vec<_> = items.iter().map(|row| row.length).collect();
+    let mut heading = format!(" Queue ({})", items.len());
+    if let Some(left) = time_left(QueueTime::after(&lengths, current), current_left) {
+        heading.push_str(&format!(" · {left}"));
+    }
+    let title = Line::from(Span::styled(heading, Style::default().fg(theme.text_dim)));
     let title_area = Rect {
         x: area.x,
         y: area.y + 1,
@@ -49,9 +84,15 @@ pub fn draw(
     let lines: Vec<Line> = items
         .iter()
         .enumerate()
-        .map(|(i, (title, subtitle))| {
+        .map(|(i, row)| {
             let is_current = current == Some(i);
-            let marker = if is_current { "▶ " } else { "  " };
+            let marker = if is_current {
+                "▶ "
+            } else if row.auto {
+                "↻ "
+            } else {
+                "  "
+            };
             let style = if is_current {
                 Style::default()
                     .fg(theme.primary)
@@ -65,16 +106,29 @@ pub fn draw(
                 Style::default().fg(theme.text_dim)
             };
             Line::from(vec![
-                Span::styled(marker, style),
-                Span::styled(title.as_str(), style),
                 Span::styled(
-                    if subtitle.is_empty() {
+                    marker,
+                    if row.auto && !is_current {
+                        Style::default().fg(theme.accent)
+                    } else {
+                        style
+                    },
+                ),
+                Span::styled(row.title.as_str(), style),
+                Span::styled(
+                    if row.subtitle.is_empty() {
                         String::new()
                     } else {
-                        format!(" - {}", subtitle)
+                        format!(" - {}", row.subtitle)
                     },
                     sub_style,
                 ),
+                Span::styled(
+                    row.length
+                        .map(|length| format!("  {}", length_label(length)))
+                        .unwrap_or_default(),
+                    Style::default().fg(theme.text_dim),
+                ),
             ])
         })
         .collect();
@@ -1,19 +1,40 @@
-// Text input for filtering the discovery list. Activated with `/`.
+// Text input for filtering the discovery list. Activated with `/`. The list
+// filters as you type (the app debounces the keys into FilterList actions)
+// and the number of matching rows shows after the input; Enter searches NTS
+// for the text instead. While focused, past searches that contain the text
+// drop down above the bar: Up/Down pick one, Enter searches it again, and
+// Ctrl+D forgets it.
 
-use crossterm::event::{KeyCode, KeyEvent};
-use ratatui::{layout::Rect, style::Style, widgets::Paragraph, Frame};
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use ratatui::{
+    layout::Rect,
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
 use tokio::sync::mpsc::UnboundedSender;
 
 use crate::action::Action;
+use crate::api::models::fold_for_search;
 use crate::components::Component;
 use crate::theme::Theme;
 
+/// Most suggestions shown at once.
+const MAX_SUGGESTIONS: usize = 6;
+
 /// Text input for live-filtering the discovery list (activated with `/`).
 #[derive(Default)]
 pub struct SearchBar {
     action_tx: Option<UnboundedSender<Action>>,
     input: String,
     focused: bool,
+    /// Rows the typed filter leaves in the list, once applied.
+    match_count: Option<usize>,
+    /// Past queries, most recent first.
+    history: Vec<String>,
+    /// Index into `suggestions()` of the highlighted one.
+    suggestion: Option<usize>,
 }
 
 impl SearchBar {
@@ -28,6 +49,124 @@ impl SearchBar {
     pub fn input(&self) -> &str {
         &self.input
     }
+
+    /// Show `input` unfocused, e.g. a tab's query when switching back to it.
+    pub fn set_input(&mut self, input: String) {
+        self.input = input;
+        self.focused = false;
+        self.match_count = None;
+    }
+
+    /// Show how many rows match what's typed; None hides the count.
+    pub fn set_match_count(&mut self, count: Option<usize>) {
+        self.match_count = count;
+    }
+
+    #[allow(dead_code)] // used by integration tests
+    pub fn match_count(&self) -> Option<usize> {
+        self.match_count
+    }
+
+    /// Past queries to suggest, most recent first.
+    pub fn set_history(&mut self, history: Vec<String>) {
+        self.history = history;
+        self.suggestion = None;
+    }
+
+    /// Past queries containing what's typed (all of them before anything
+    /// is), most recent first. Empty unless focused.
+    pub fn suggestions(&self) -> Vec<&str> {
+        if !self.focused {
+            return Vec::new();
+        }
+        let typed = fold_for_search(&self.input);
+        self.history
+            .iter()
+            .filter(|query| **query != self.input && fold_for_search(query).contains(&typed))
+            .take(MAX_SUGGESTIONS)
+            .map(String::as_str)
+            .collect()
+    }
+
+    #[allow(dead_code)] // used by integration tests
+    pub fn selected_suggestion(&self) -> Option<&str> {
+        self.suggestion
+            .and_then(|i| self.suggestions().get(i).copied())
+    }
+
+    /// Move the highlight down (or up) the suggestions, wrapping; false if
+    /// there are none.
+    fn move_suggestion(&mut self, down: bool) -> bool {
+        let count = self.suggestions().len();
+        if count == 0 {
+            return false;
+        }
+        self.suggestion = Some(match (self.suggestion, down) {
+            (None, true) => 0,
+            (None, false) => count - 1,
+            (Some(i), true) => (i + 1) % count,
+            (Some(i), false) => (i + count - 1) % count,
+        });
+        true
+    }
+
+    /// Forget the highlighted suggestion, here and (via the app) on disk.
+    fn delete_suggestion(&mut self) -> anyhow::Result<()> {
+        let Some(query) = self.selected_suggestion().map(str::to_string) else {
+            return Ok(());
+        };
+        self.history.retain(|q| *q != query);
+        let count = self.suggestions().len();
+        self.suggestion = self
+            .suggestion
+            .filter(|_| count > 0)
+            .map(|i| i.min(count - 1));
+        let tx = self.action_tx.as_ref().expect("component not registered");
+        tx.send(Action::DeleteSearch(query))?;
+        Ok(())
+    }
+
+    /// Draw the suggestions just above the bar at `bar`, over the bottom of
+    /// `list`.
+    pub fn draw_suggestions(&self, frame: &mut Frame, list: Rect, bar: Rect, theme: &Theme) {
+        let suggestions = self.suggestions();
+        if suggestions.is_empty() {
+            return;
+        }
+        let height = (suggestions.len() as u16 + 2).min(list.height);
+        let area = Rect {
+            x: list.x,
+            y: bar.y.saturating_sub(height).max(list.y),
+            width: list.width,
+            height,
+        };
+        frame.render_widget(Clear, area);
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(theme.text_dim))
+            .title(" Recent searches (Ctrl+D forgets) ");
+        let lines: Vec<Line> = suggestions
+            .iter()
+            .enumerate()
+            .map(|(i, query)| {
+                if self.suggestion == Some(i) {
+                    Line::from(Span::styled(
+                        format!("▌{query}"),
+                        Style::default()
+                            .fg(theme.primary)
+                            .bg(theme.selection_bg)
+                            .add_modifier(Modifier::BOLD),
+                    ))
+                } else {
+                    Line::from(Span::styled(
+                        format!(" {query}"),
+                        Style::default().fg(theme.text),
+                    ))
+                }
+            })
+            .collect();
+        frame.render_widget(Paragraph::new(lines).block(block), area);
+    }
 }
 
 impl Component for SearchBar {
@@ -41,15 +180,29 @@ impl Component for SearchBar {
         }
         let tx = self.action_tx.as_ref().expect("component not registered");
         match key.code {
+            KeyCode::Char('d')
+                if key.modifiers.contains(KeyModifiers::CONTROL) && self.suggestion.is_some() =>
+            {
+                self.delete_suggestion()?;
+                Ok(true)
+            }
+            KeyCode::Down => Ok(self.move_suggestion(true)),
+            KeyCode::Up => Ok(self.move_suggestion(false)),
             KeyCode::Char(c) => {
                 self.input.push(c);
+                self.suggestion = None;
                 Ok(true)
             }
             KeyCode::Backspace => {
                 self.input.pop();
+                self.suggestion = None;
                 Ok(true)
             }
             KeyCode::Enter => {
+                if let Some(query) = self.selected_suggestion().map(str::to_string) {
+                    self.input = query;
+                    self.suggestion = None;
+                }
                 if !self.input.is_empty() {
                     self.focused = false;
                     tx.send(Action::SearchSubmit)?;
@@ -59,6 +212,7 @@ impl Component for SearchBar {
             KeyCode::Esc => {
                 self.focused = false;
                 self.input.clear();
+                self.suggestion = None;
                 Ok(true)
             }
             _ => Ok(false),
@@ -69,10 +223,13 @@ impl Component for SearchBar {
         match action {
             Action::FocusSearch => {
                 self.focused = true;
+                self.suggestion = None;
             }
             Action::Back => {
                 self.focused = false;
                 self.input.clear();
+                self.match_count = None;
+                self.suggestion = None;
             }
             Action::SearchSubmit => {
                 // Keep input visible so user can see what they searched for
@@ -98,7 +255,14 @@ impl Component for SearchBar {
             format!("/ {}", self.input)
         };
 
-        let paragraph = Paragraph::new(display).style(style);
-        frame.render_widget(paragraph, area);
+        let mut spans = vec![Span::styled(display, style)];
+        if let Some(count) = self.match_count {
+            let noun = if count == 1 { "match" } else { "matches" };
+            spans.push(Span::styled(
+                format!("   {count} {noun}"),
+                Style::default().fg(theme.text_dim),
+            ));
+        }
+        frame.render_widget(Paragraph::new(Line::from(spans)), area);
     }
 }
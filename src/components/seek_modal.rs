@@ -1,4 +1,6 @@
-// Modal overlay for precise seeking within a track (press `t` to open).
+// Modal overlay for precise seeking within a track (press `t` to open), with a
+// typed "jump to mm:ss" input (press `g` inside it), the episode's
+// bookmarks marked on the bar, and an A-B loop set from the cursor.
 
 use crossterm::event::{KeyCode, KeyEvent};
 use ratatui::{
@@ -11,7 +13,8 @@ use ratatui::{
 use tokio::sync::mpsc::UnboundedSender;
 
 use crate::action::Action;
-use crate::components::{centered_overlay, format_time, Component};
+use crate::components::{centered_overlay, format_time, parse_timestamp, Component};
+use crate::db::Bookmark;
 use crate::theme::Theme;
 
 /// Modal overlay for precise seeking within an on-demand track.
@@ -21,6 +24,13 @@ pub struct SeekModal {
     position: f64,
     duration: f64,
     cursor_position: f64,
+    /// Timestamp being typed after `g`; None when not in jump mode.
+    jump_input: Option<String>,
+    /// The episode's bookmarks, earliest first.
+    bookmarks: Vec<Bookmark>,
+    /// Loop start and end; playback loops once both are set.
+    loop_a: Option<f64>,
+    loop_b: Option<f64>,
 }
 
 impl Default for SeekModal {
@@ -31,6 +41,10 @@ impl Default for SeekModal {
             position: 0.0,
             duration: 0.0,
             cursor_position: 0.0,
+            jump_input: None,
+            bookmarks: Vec::new(),
+            loop_a: None,
+            loop_b: None,
         }
     }
 }
@@ -49,10 +63,17 @@ impl SeekModal {
         self.position = position;
         self.duration = duration;
         self.cursor_position = position;
+        self.jump_input = None;
     }
 
     pub fn hide(&mut self) {
         self.visible = false;
+        self.jump_input = None;
+    }
+
+    #[allow(dead_code)] // used by integration tests
+    pub fn jump_input(&self) -> Option<&str> {
+        self.jump_input.as_deref()
     }
 
     pub fn update_position(&mut self, position: f64) {
@@ -63,9 +84,101 @@ impl SeekModal {
         self.duration = duration;
     }
 
+    pub fn set_bookmarks(&mut self, bookmarks: Vec<Bookmark>) {
+        self.bookmarks = bookmarks;
+    }
+
+    #[allow(dead_code)] // used by integration tests
+    pub fn cursor_position(&self) -> f64 {
+        self.cursor_position
+    }
+
+    /// The section being looped, once both ends are set.
+    pub fn ab_loop(&self) -> Option<(f64, f64)> {
+        self.loop_a.zip(self.loop_b)
+    }
+
+    /// Forget the loop points, e.g. when another track starts.
+    pub fn clear_ab_loop(&mut self) {
+        self.loop_a = None;
+        self.loop_b = None;
+    }
+
+    /// Put loop start (`a`) or end (`b`) at the cursor, dropping the other
+    /// end if it's now on the wrong side, and tell the app what to loop.
+    fn set_loop_point(&mut self, start: bool) {
+        let at = self.cursor_position;
+        if start {
+            self.loop_a = Some(at);
+            self.loop_b = self.loop_b.filter(|&b| b > at);
+        } else {
+            self.loop_b = Some(at);
+            self.loop_a = self.loop_a.filter(|&a| a < at);
+        }
+        self.send_loop();
+    }
+
+    fn send_loop(&self) {
+        if let Some(tx) = &self.action_tx {
+            tx.send(Action::SetAbLoop(self.ab_loop())).ok();
+        }
+    }
+
+    /// The bookmark under the cursor: the last one at or before it.
+    fn bookmark_at_cursor(&self) -> Option<&Bookmark> {
+        self.bookmarks
+            .iter()
+            .rev()
+            .find(|b| b.position_secs <= self.cursor_position + 0.5)
+    }
+
+    /// Put the cursor on the next bookmark after it, or the one before.
+    fn cursor_to_bookmark(&mut self, forward: bool) {
+        let cursor = self.cursor_position;
+        let target = if forward {
+            self.bookmarks
+                .iter()
+                .find(|b| b.position_secs > cursor + 0.5)
+        } else {
+            self.bookmarks
+                .iter()
+                .rev()
+                .find(|b| b.position_secs < cursor - 0.5)
+        };
+        if let Some(bookmark) = target {
+            self.cursor_position = bookmark.position_secs.clamp(0.0, self.duration);
+        }
+    }
+
     fn move_cursor(&mut self, delta: f64) {
         self.cursor_position = (self.cursor_position + delta).clamp(0.0, self.duration);
     }
+
+    /// Keys while typing a timestamp. Enter jumps straight to it; an invalid
+    /// entry keeps the input open so it can be corrected.
+    fn handle_jump_key(&mut self, key: KeyEvent) {
+        let Some(input) = self.jump_input.as_mut() else {
+            return;
+        };
+        match key.code {
+            KeyCode::Esc => self.jump_input = None,
+            KeyCode::Backspace => {
+                input.pop();
+            }
+            KeyCode::Char(c) if c.is_ascii_digit() || c == ':' => input.push(c),
+            KeyCode::Enter => {
+                if let Some(target) = parse_timestamp(input) {
+                    let target = target.clamp(0.0, self.duration);
+                    self.jump_input = None;
+                    if let Some(tx) = &self.action_tx {
+                        tx.send(Action::SeekAbsolute(target)).ok();
+                        tx.send(Action::CloseSeekModal).ok();
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
 }
 
 impl Component for SeekModal {
@@ -77,6 +190,10 @@ impl Component for SeekModal {
         if !self.visible {
             return Ok(false);
         }
+        if self.jump_input.is_some() {
+            self.handle_jump_key(key);
+            return Ok(true);
+        }
 
         match key.code {
             KeyCode::Esc | KeyCode::Char('t') => {
@@ -97,6 +214,20 @@ impl Component for SeekModal {
             KeyCode::Char('l') => self.move_cursor(30.0),
             KeyCode::Char('0') => self.cursor_position = 0.0,
             KeyCode::Char('$') => self.cursor_position = self.duration,
+            KeyCode::Char('g') => self.jump_input = Some(String::new()),
+            KeyCode::Char('{') => self.cursor_to_bookmark(false),
+            KeyCode::Char('}') => self.cursor_to_bookmark(true),
+            KeyCode::Char('a') => self.set_loop_point(true),
+            KeyCode::Char('b') => self.set_loop_point(false),
+            KeyCode::Char('c') => {
+                self.clear_ab_loop();
+                self.send_loop();
+            }
+            KeyCode::Char('x') => {
+                if let (Some(bookmark), Some(tx)) = (self.bookmark_at_cursor(), &self.action_tx) {
+                    tx.send(Action::DeleteBookmark(bookmark.id)).ok();
+                }
+            }
             _ => {}
         }
 
@@ -108,7 +239,8 @@ impl Component for SeekModal {
             return;
         }
 
-        let overlay_area = centered_overlay(area, 60, 7);
+        let height = if self.bookmarks.is_empty() { 8 } else { 9 };
+        let overlay_area = centered_overlay(area, 72, height);
 
         frame.render_widget(Clear, overlay_area);
 
@@ -138,7 +270,15 @@ impl Component for SeekModal {
                 Style::default().fg(theme.text),
             ),
             Span::styled(
-                format!("  (now: {})", format_time(self.position)),
+                format!(
+                    "  {:.0}%  (now: {})",
+                    if self.duration > 0.0 {
+                        self.cursor_position / self.duration * 100.0
+                    } else {
+                        0.0
+                    },
+                    format_time(self.position)
+                ),
                 Style::default().fg(theme.text_dim),
             ),
         ]);
@@ -150,6 +290,18 @@ impl Component for SeekModal {
             let cursor_frac = (self.cursor_position / self.duration).clamp(0.0, 1.0);
             let pos_idx = (pos_frac * (bar_width - 1) as f64).round() as usize;
             let cursor_idx = (cursor_frac * (bar_width - 1) as f64).round() as usize;
+            let idx = |secs: f64| {
+                let frac = (secs / self.duration).clamp(0.0, 1.0);
+                (frac * (bar_width - 1) as f64).round() as usize
+            };
+            let bookmark_idxs: Vec<usize> = self
+                .bookmarks
+                .iter()
+                .map(|b| idx(b.position_secs))
+                .collect();
+            let a_idx = self.loop_a.map(idx);
+            let b_idx = self.loop_b.map(idx);
+            let looped = |i: usize| a_idx.zip(b_idx).is_some_and(|(a, b)| a < i && i < b);
 
             let mut chars: Vec<(&str, ratatui::style::Color)> = Vec::with_capacity(bar_width);
             for i in 0..bar_width {
@@ -160,8 +312,23 @@ impl Component for SeekModal {
                     chars.push(("┃", theme.accent));
                 } else if i == pos_idx {
                     chars.push(("▶", theme.primary));
+                } else if a_idx == Some(i) {
+                    chars.push(("[", theme.warning));
+                } else if b_idx == Some(i) {
+                    chars.push(("]", theme.warning));
+                } else if bookmark_idxs.contains(&i) {
+                    chars.push(("◆", theme.secondary));
                 } else if i < pos_idx {
-                    chars.push(("━", theme.primary));
+                    chars.push((
+                        "━",
+                        if looped(i) {
+                            theme.warning
+                        } else {
+                            theme.primary
+                        },
+                    ));
+                } else if looped(i) {
+                    chars.push(("─", theme.warning));
                 } else {
                     chars.push(("─", theme.text_dim));
                 }
@@ -176,13 +343,71 @@ impl Component for SeekModal {
             Line::from("")
         };
 
-        // Hint line
-        let hint = Line::from(Span::styled(
-            "  ←→ ±5s · h/l ±30s · 0/$ start/end · Enter seek · Esc close",
-            Style::default().fg(theme.text_dim),
-        ));
+        // Hint line, or the jump input while typing
+        let hint = match self.jump_input {
+            Some(ref input) => {
+                let valid = input.is_empty() || parse_timestamp(input).is_some();
+                Line::from(vec![
+                    Span::styled("  Jump to: ", Style::default().fg(theme.text)),
+                    Span::styled(
+                        format!("{}▏", input),
+                        Style::default().fg(if valid { theme.accent } else { theme.error }),
+                    ),
+                    Span::styled(
+                        "  mm:ss · Enter jump · Esc cancel",
+                        Style::default().fg(theme.text_dim),
+                    ),
+                ])
+            }
+            None => Line::from(Span::styled(
+                "  ←→ ±5s · h/l ±30s · 0/$ ends · g mm:ss · Enter seek · Esc close",
+                Style::default().fg(theme.text_dim),
+            )),
+        };
 
-        let paragraph = Paragraph::new(vec![time_line, Line::from(""), bar, hint]);
+        let mut lines = vec![time_line, Line::from(""), bar];
+        if !self.bookmarks.is_empty() {
+            let marked = match self.bookmark_at_cursor() {
+                Some(bookmark) => Line::from(vec![
+                    Span::styled("  ◆ ", Style::default().fg(theme.secondary)),
+                    Span::styled(
+                        format!("{} {}", format_time(bookmark.position_secs), bookmark.label),
+                        Style::default().fg(theme.text),
+                    ),
+                    Span::styled(
+                        "  { } bookmarks · x remove",
+                        Style::default().fg(theme.text_dim),
+                    ),
+                ]),
+                None => Line::from(Span::styled(
+                    "  { } bookmarks",
+                    Style::default().fg(theme.text_dim),
+                )),
+            };
+            lines.push(marked);
+        }
+        let dim = Style::default().fg(theme.text_dim);
+        let point = |secs: Option<f64>| secs.map_or("—".to_string(), format_time);
+        lines.push(match self.ab_loop() {
+            Some((a, b)) => Line::from(vec![
+                Span::styled("  Looping ", Style::default().fg(theme.warning)),
+                Span::styled(
+                    format!("{}–{}", format_time(a), format_time(b)),
+                    Style::default().fg(theme.text),
+                ),
+                Span::styled("  a/b move the ends · c stop looping", dim),
+            ]),
+            None => Line::from(Span::styled(
+                format!(
+                    "  A-B loop: a start ({}) · b end ({}) · c clear",
+                    point(self.loop_a),
+                    point(self.loop_b)
+                ),
+                dim,
+            )),
+        });
+        lines.push(hint);
+        let paragraph = Paragraph::new(lines);
         frame.render_widget(paragraph, inner);
     }
 }
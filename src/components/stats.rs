@@ -0,0 +1,209 @@
+// Stats overlay (press `U`): plays per source, most-used commands, and this
+// session's performance, all from the opt-in local metrics.
+
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::{
+    layout::Rect,
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+use tokio::sync::mpsc::UnboundedSender;
+
+use crate::action::Action;
+use crate::components::text::fit;
+use crate::components::{centered_overlay, Component};
+use crate::metrics::PerfSnapshot;
+use crate::theme::Theme;
+
+// Commands listed under "Most used".
+const TOP_COMMANDS: usize = 8;
+// Width of the longest bar in cells.
+const BAR_WIDTH: usize = 24;
+// Width of the label column in front of the bars.
+const LABEL_WIDTH: usize = 22;
+
+/// Overlay showing the locally collected usage metrics.
+#[derive(Default)]
+pub struct StatsView {
+    action_tx: Option<UnboundedSender<Action>>,
+    visible: bool,
+    enabled: bool,
+    /// Persisted counters, highest first.
+    counts: Vec<(String, u64)>,
+    perf: PerfSnapshot,
+}
+
+impl StatsView {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn is_visible(&self) -> bool {
+        self.visible
+    }
+
+    pub fn show(&mut self, enabled: bool, counts: Vec<(String, u64)>, perf: PerfSnapshot) {
+        self.visible = true;
+        self.enabled = enabled;
+        self.counts = counts;
+        self.perf = perf;
+    }
+
+    pub fn hide(&mut self) {
+        self.visible = false;
+    }
+
+    /// Counters under `prefix`, with the prefix stripped, highest first.
+    fn group(&self, prefix: &str) -> Vec<(&str, u64)> {
+        self.counts
+            .iter()
+            .filter_map(|(name, n)| name.strip_prefix(prefix).map(|rest| (rest, *n)))
+            .collect()
+    }
+
+    fn bar_lines<'a>(rows: &[(&'a str, u64)], theme: &Theme) -> Vec<Line<'a>> {
+        let max = rows.iter().map(|(_, n)| *n).max().unwrap_or(0).max(1);
+        rows.iter()
+            .map(|(label, n)| {
+                let width = ((*n as f64 / max as f64) * BAR_WIDTH as f64).ceil() as usize;
+                Line::from(vec![
+                    Span::styled(
+                        format!("  {}", fit(label, LABEL_WIDTH)),
+                        Style::default().fg(theme.text),
+                    ),
+                    Span::styled("█".repeat(width.max(1)), Style::default().fg(theme.accent)),
+                    Span::styled(format!(" {}", n), Style::default().fg(theme.text_dim)),
+                ])
+            })
+            .collect()
+    }
+}
+
+fn source_label(key: &str) -> &str {
+    match key {
+        "nts_live" => "NTS live",
+        "nts_episode" => "NTS episodes",
+        "direct_url" => "Direct URLs",
+        "nts_genre" => "Genres",
+        other => other,
+    }
+}
+
+impl Component for StatsView {
+    fn register_action_handler(&mut self, tx: UnboundedSender<Action>) {
+        self.action_tx = Some(tx);
+    }
+
+    fn handle_key_event(&mut self, key: KeyEvent) -> anyhow::Result<bool> {
+        if !self.visible {
+            return Ok(false);
+        }
+        if key.code == KeyCode::Char('c') && self.enabled {
+            if let Some(tx) = &self.action_tx {
+                tx.send(Action::ClearMetrics)?;
+            }
+        } else {
+            self.hide();
+        }
+        Ok(true)
+    }
+
+    fn draw(&self, frame: &mut Frame, area: Rect, theme: &Theme) {
+        if !self.visible {
+            return;
+        }
+        let overlay_area = centered_overlay(area, 64, 30);
+        frame.render_widget(Clear, overlay_area);
+
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(" Stats ")
+            .title_style(
+                Style::default()
+                    .fg(theme.primary)
+                    .add_modifier(Modifier::BOLD),
+            );
+        let inner = block.inner(overlay_area);
+        frame.render_widget(block, overlay_area);
+
+        let dim = Style::default().fg(theme.text_dim);
+        let heading = |text: &'static str| {
+            Line::from(Span::styled(
+                text,
+                Style::default()
+                    .fg(theme.secondary)
+                    .add_modifier(Modifier::BOLD),
+            ))
+        };
+
+        if !self.enabled {
+            let lines = vec![
+                Line::from(""),
+                Line::from(Span::styled("  Usage metrics are off.", dim)),
+                Line::from(""),
+                Line::from(Span::styled(
+                    "  Set `metrics = true` under [general] in config.toml",
+                    dim,
+                )),
+                Line::from(Span::styled(
+                    "  to count plays and key usage locally. Nothing is sent anywhere.",
+                    dim,
+                )),
+                Line::from(""),
+                Line::from(Span::styled("  Press any key to close", dim)),
+            ];
+            frame.render_widget(Paragraph::new(lines), inner);
+            return;
+        }
+
+        let plays: Vec<(&str, u64)> = self
+            .group("play.")
+            .into_iter()
+            .map(|(k, n)| (source_label(k), n))
+            .collect();
+        let mut commands = self.group("key.");
+        commands.truncate(TOP_COMMANDS);
+
+        let mut lines = vec![heading(" Plays by source")];
+        if plays.is_empty() {
+            lines.push(Line::from(Span::styled("  Nothing played yet", dim)));
+        }
+        lines.extend(Self::bar_lines(&plays, theme));
+        lines.push(Line::from(""));
+        lines.push(heading(" Most used commands"));
+        if commands.is_empty() {
+            lines.push(Line::from(Span::styled("  No commands recorded yet", dim)));
+        }
+        lines.extend(Self::bar_lines(&commands, theme));
+        lines.push(Line::from(""));
+        lines.push(heading(" This session"));
+        let perf = self.perf;
+        for (label, value) in [
+            (
+                "Actions",
+                format!("{} ({:.1}/s)", perf.actions, perf.actions_per_sec),
+            ),
+            ("Frames drawn", perf.frames.to_string()),
+            (
+                "Render time",
+                format!(
+                    "{:.2} ms avg · {:.2} ms max",
+                    perf.avg_render_ms, perf.max_render_ms
+                ),
+            ),
+        ] {
+            lines.push(Line::from(vec![
+                Span::styled(format!("  {:<22}", label), Style::default().fg(theme.text)),
+                Span::styled(value, dim),
+            ]));
+        }
+        lines.push(Line::from(""));
+        lines.push(Line::from(Span::styled(
+            "  c to reset counters · any other key to close",
+            dim,
+        )));
+        frame.render_widget(Paragraph::new(lines), inner);
+    }
+}
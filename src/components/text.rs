@@ -0,0 +1,56 @@
+// Display-width text helpers. A terminal cell isn't a char: CJK and most
+// emoji take two, combining marks none, so anything that lines text up in
+// columns or cuts it to fit measures with these rather than `len()` or
+// `chars().count()`.
+
+use std::borrow::Cow;
+
+use ratatui::text::Span;
+
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
+
+/// Marks text that was cut short.
+pub const ELLIPSIS: char = '…';
+
+/// How many terminal cells `s` takes.
+pub fn display_width(s: &str) -> usize {
+    s.width()
+}
+
+/// How many terminal cells a row of spans takes.
+pub fn spans_width(spans: &[Span]) -> usize {
+    spans.iter().map(|span| span.content.width()).sum()
+}
+
+/// `s` cut to at most `width` cells, ending in "…" when anything was cut.
+/// A wide character that would straddle the edge is dropped, so the result
+/// can come out a cell short.
+pub fn truncate(s: &str, width: usize) -> Cow<'_, str> {
+    if s.width() <= width {
+        return Cow::Borrowed(s);
+    }
+    let Some(room) = width.checked_sub(1) else {
+        return Cow::Borrowed("");
+    };
+    let mut out = String::new();
+    let mut used = 0;
+    for c in s.chars() {
+        let w = c.width().unwrap_or(0);
+        if used + w > room {
+            break;
+        }
+        used += w;
+        out.push(c);
+    }
+    out.push(ELLIPSIS);
+    Cow::Owned(out)
+}
+
+/// `s` truncated to `width` cells and padded with spaces to exactly that,
+/// for text laid out in columns.
+pub fn fit(s: &str, width: usize) -> String {
+    let mut out = truncate(s, width).into_owned();
+    let pad = width.saturating_sub(out.width());
+    out.extend(std::iter::repeat_n(' ', pad));
+    out
+}
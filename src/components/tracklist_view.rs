@@ -0,0 +1,216 @@
+// Episode tracklist overlay (press `T`): the songs in the playing NTS episode,
+// with the one probably on now marked from the playback position. Enter seeks
+// to the selected song when it has a timestamp.
+
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::{
+    layout::{Constraint, Layout, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph},
+    Frame,
+};
+use tokio::sync::mpsc::UnboundedSender;
+
+use crate::action::Action;
+use crate::api::models::{playing_track_index, EpisodeTrack};
+use crate::components::{centered_overlay, format_time, Component};
+use crate::theme::Theme;
+
+/// Overlay listing the playing episode's tracklist.
+#[derive(Default)]
+pub struct TracklistView {
+    action_tx: Option<UnboundedSender<Action>>,
+    visible: bool,
+    /// favorite_key() of the episode the tracklist belongs to.
+    key: Option<String>,
+    title: String,
+    /// None while the tracklist is loading.
+    tracks: Option<Vec<EpisodeTrack>>,
+    position_secs: f64,
+    selected: usize,
+}
+
+impl TracklistView {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn is_visible(&self) -> bool {
+        self.visible
+    }
+
+    /// Start loading the tracklist of the episode `key`.
+    pub fn begin(&mut self, key: String, title: String) {
+        self.key = Some(key);
+        self.title = title;
+        self.tracks = None;
+        self.position_secs = 0.0;
+        self.selected = 0;
+    }
+
+    /// Forget the tracklist, e.g. when playback stops.
+    pub fn clear(&mut self) {
+        self.key = None;
+        self.tracks = None;
+        self.hide();
+    }
+
+    /// The episode the tracklist is for, if any.
+    pub fn key(&self) -> Option<&str> {
+        self.key.as_deref()
+    }
+
+    /// Fill in the tracklist, unless another episode started meanwhile.
+    pub fn set_tracks(&mut self, key: &str, tracks: Vec<EpisodeTrack>) {
+        if self.key.as_deref() != Some(key) {
+            return;
+        }
+        self.tracks = Some(tracks);
+        self.selected = self.playing_index().unwrap_or(0);
+    }
+
+    pub fn set_position(&mut self, secs: f64) {
+        self.position_secs = secs;
+    }
+
+    /// Show the overlay, if an episode is playing, with the current song selected.
+    pub fn show(&mut self) -> bool {
+        if self.key.is_none() {
+            return false;
+        }
+        self.visible = true;
+        self.selected = self.playing_index().unwrap_or(0);
+        true
+    }
+
+    pub fn hide(&mut self) {
+        self.visible = false;
+    }
+
+    pub fn tracks(&self) -> Option<&[EpisodeTrack]> {
+        self.tracks.as_deref()
+    }
+
+    /// Index of the song probably playing now.
+    pub fn playing_index(&self) -> Option<usize> {
+        playing_track_index(self.tracks.as_deref()?, self.position_secs)
+    }
+
+    fn track_count(&self) -> usize {
+        self.tracks.as_ref().map_or(0, Vec::len)
+    }
+}
+
+impl Component for TracklistView {
+    fn register_action_handler(&mut self, tx: UnboundedSender<Action>) {
+        self.action_tx = Some(tx);
+    }
+
+    fn handle_key_event(&mut self, key: KeyEvent) -> anyhow::Result<bool> {
+        if !self.visible {
+            return Ok(false);
+        }
+        match key.code {
+            KeyCode::Esc | KeyCode::Char('q') | KeyCode::Char('T') => self.hide(),
+            KeyCode::Char('j') | KeyCode::Down if self.selected + 1 < self.track_count() => {
+                self.selected += 1;
+            }
+            KeyCode::Char('k') | KeyCode::Up => self.selected = self.selected.saturating_sub(1),
+            KeyCode::Char('g') => {
+                if let Some(index) = self.playing_index() {
+                    self.selected = index;
+                }
+            }
+            KeyCode::Enter => {
+                let offset = self
+                    .tracks
+                    .as_ref()
+                    .and_then(|tracks| tracks.get(self.selected))
+                    .and_then(|track| track.offset);
+                if let (Some(offset), Some(tx)) = (offset, &self.action_tx) {
+                    tx.send(Action::SeekAbsolute(offset))?;
+                    self.hide();
+                }
+            }
+            _ => {}
+        }
+        Ok(true)
+    }
+
+    fn draw(&self, frame: &mut Frame, area: Rect, theme: &Theme) {
+        if !self.visible {
+            return;
+        }
+        let overlay_area = centered_overlay(area, 72, area.height.saturating_sub(4));
+        frame.render_widget(Clear, overlay_area);
+
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(format!(" Tracklist · {} ", self.title))
+            .title_style(
+                Style::default()
+                    .fg(theme.primary)
+                    .add_modifier(Modifier::BOLD),
+            );
+        let inner = block.inner(overlay_area);
+        frame.render_widget(block, overlay_area);
+
+        let [list_area, footer] =
+            Layout::vertical([Constraint::Min(0), Constraint::Length(1)]).areas(inner);
+        let dim = Style::default().fg(theme.text_dim);
+
+        match self.tracks.as_deref() {
+            None => frame.render_widget(
+                Paragraph::new(Line::from(Span::styled("  Loading tracklist…", dim))),
+                list_area,
+            ),
+            Some([]) => frame.render_widget(
+                Paragraph::new(Line::from(Span::styled(
+                    "  No tracklist for this episode",
+                    dim,
+                ))),
+                list_area,
+            ),
+            Some(tracks) => {
+                let playing = self.playing_index();
+                let items: Vec<ListItem> = tracks
+                    .iter()
+                    .enumerate()
+                    .map(|(i, track)| {
+                        let is_playing = Some(i) == playing;
+                        let title_style = if is_playing {
+                            Style::default()
+                                .fg(theme.primary)
+                                .add_modifier(Modifier::BOLD)
+                        } else {
+                            Style::default().fg(theme.text)
+                        };
+                        let offset = track.offset.map(format_time).unwrap_or_default();
+                        let marker = if is_playing { "▶" } else { " " };
+                        let mut style = Style::default();
+                        if i == self.selected {
+                            style = style.bg(theme.selection_bg);
+                        }
+                        ListItem::new(Line::from(vec![
+                            Span::styled(format!(" {marker} {offset:>7}  "), dim),
+                            Span::styled(track.display(), title_style),
+                        ]))
+                        .style(style)
+                    })
+                    .collect();
+                let mut state = ListState::default();
+                state.select(Some(self.selected));
+                frame.render_stateful_widget(List::new(items), list_area, &mut state);
+            }
+        }
+
+        frame.render_widget(
+            Paragraph::new(Line::from(Span::styled(
+                "  Enter seek to song · g current · j/k move · Esc close",
+                dim,
+            ))),
+            footer,
+        );
+    }
+}
@@ -8,18 +8,17 @@
 use ratatui::{layout::Rect, style::Color, Frame};
 
 use super::{blend_colors, Visualizer};
-
-/// Three color palettes the visualizer cycles through over time.
-const PALETTES: &[&[Color]; 3] = &[
-    &[
-        Color::Cyan,
-        Color::Blue,
-        Color::Magenta,
-        Color::LightMagenta,
-    ],
-    &[Color::Yellow, Color::Red, Color::Magenta, Color::LightRed],
-    &[Color::Green, Color::Cyan, Color::White, Color::LightGreen],
-];
+use crate::theme::VisualizerPalette;
+
+/// The three 4-zone palettes the visualizer cycles through over time.
+fn zone_palettes(p: &VisualizerPalette) -> [[Color; 4]; 3] {
+    let a = p.accents;
+    [
+        [a[0], a[5], a[1], a[3]],
+        p.warm,
+        [p.levels[0], a[0], p.highlight, a[4]],
+    ]
+}
 
 /// Animated blob state. Call `tick()` each frame, then `draw()` to render.
 #[derive(Default)]
@@ -88,7 +87,7 @@ impl Visualizer for BlobVisualizer {
         self.intensity += (target - self.intensity) * 0.15;
     }
 
-    fn draw(&self, frame: &mut Frame, area: Rect) {
+    fn draw(&self, frame: &mut Frame, area: Rect, palette: &VisualizerPalette) {
         if area.width == 0 || area.height == 0 || self.intensity < 0.01 {
             return;
         }
@@ -111,6 +110,7 @@ impl Visualizer for BlobVisualizer {
         let cx = dot_cols as f64 / 2.0;
         let cy = dot_rows as f64 / 2.0;
         let scale = cx.min(cy).max(1.0);
+        let palettes = zone_palettes(palette);
 
         let buf = frame.buffer_mut();
 
@@ -153,7 +153,7 @@ impl Visualizer for BlobVisualizer {
 
                 if any_inside {
                     let ch = char::from_u32(0x2800 + dots as u32).unwrap_or(' ');
-                    let color = self.color_at(best_dr);
+                    let color = self.color_at(best_dr, &palettes);
                     let x = area.x + col as u16;
                     let y = area.y + row as u16;
                     if let Some(cell) = buf.cell_mut((x, y)) {
@@ -202,10 +202,10 @@ impl BlobVisualizer {
             + amps[3] * (7.0 * theta + t * 2.0).sin()
     }
 
-    fn color_at(&self, dr: f64) -> Color {
-        let palette_f = self.color_phase % (PALETTES.len() as f64);
-        let idx = palette_f as usize % PALETTES.len();
-        let next = (idx + 1) % PALETTES.len();
+    fn color_at(&self, dr: f64, palettes: &[[Color; 4]; 3]) -> Color {
+        let palette_f = self.color_phase % (palettes.len() as f64);
+        let idx = palette_f as usize % palettes.len();
+        let next = (idx + 1) % palettes.len();
         let blend = palette_f.fract() as f32;
 
         let zone = if dr < 0.4 {
@@ -218,6 +218,6 @@ impl BlobVisualizer {
             3
         };
 
-        blend_colors(PALETTES[idx][zone], PALETTES[next][zone], blend)
+        blend_colors(palettes[idx][zone], palettes[next][zone], blend)
     }
 }
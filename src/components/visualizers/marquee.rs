@@ -0,0 +1,261 @@
+// Marquee visualizer: the playing title in big block letters.
+//
+// Letters come from a built-in 3x5 pixel font, scaled to fill the area's
+// height and drawn with braille dots. Titles wider than the area scroll
+// right to left, faster with loudness; a beat (peak jumping over its
+// running average) flashes the letters and gives the scroll a push. Made
+// for running fullscreen on a spare monitor.
+
+use ratatui::{layout::Rect, Frame};
+
+use super::{blend_colors, Visualizer};
+use crate::api::models::fold_for_search;
+use crate::theme::VisualizerPalette;
+
+const GLYPH_WIDTH: usize = 3;
+const GLYPH_HEIGHT: usize = 5;
+/// Glyph plus the blank column after it, in font pixels.
+const ADVANCE: usize = GLYPH_WIDTH + 1;
+/// Blank font pixels between the end of a scrolling title and its repeat.
+const LOOP_GAP: usize = 8;
+// Ticks after a beat before another can count.
+const BEAT_HOLDOFF: u32 = 6;
+
+pub struct MarqueeVisualizer {
+    title: String,
+    /// Uppercase, accent-free letters of the title, as drawn.
+    text: Vec<char>,
+    /// Scroll position in braille dots.
+    offset: f64,
+    color_phase: f64,
+    intensity: f32,
+    prev_rms: f64,
+    peak_average: f64,
+    pulse: f64,
+    ticks_since_beat: u32,
+}
+
+impl Default for MarqueeVisualizer {
+    fn default() -> Self {
+        Self {
+            title: String::new(),
+            text: Vec::new(),
+            offset: 0.0,
+            color_phase: 0.0,
+            intensity: 0.0,
+            prev_rms: 0.0,
+            peak_average: 0.0,
+            pulse: 0.0,
+            ticks_since_beat: 0,
+        }
+    }
+}
+
+impl Visualizer for MarqueeVisualizer {
+    fn tick(
+        &mut self,
+        playing: bool,
+        paused: bool,
+        buffering: bool,
+        _position_secs: f64,
+        audio_rms: f64,
+        audio_peak: f64,
+    ) {
+        let target_intensity = if !playing || paused {
+            0.0
+        } else if buffering {
+            0.3
+        } else {
+            1.0
+        };
+        self.intensity += (target_intensity - self.intensity) * 0.15;
+
+        let smoothed = self.prev_rms * 0.3 + audio_rms * 0.7;
+        self.prev_rms = smoothed;
+
+        self.ticks_since_beat = self.ticks_since_beat.saturating_add(1);
+        if audio_peak > 0.1
+            && audio_peak > self.peak_average * 1.3
+            && self.ticks_since_beat >= BEAT_HOLDOFF
+        {
+            self.pulse = 1.0;
+            self.ticks_since_beat = 0;
+        } else {
+            self.pulse *= 0.85;
+        }
+        self.peak_average = self.peak_average * 0.9 + audio_peak * 0.1;
+
+        if playing && !paused {
+            self.offset += 0.5 + smoothed * 1.5 + self.pulse * 2.0;
+        }
+        self.color_phase += 0.005;
+    }
+
+    fn set_title(&mut self, title: &str) {
+        if title == self.title {
+            return;
+        }
+        self.title = title.to_string();
+        self.text = fold_for_search(title)
+            .to_uppercase()
+            .chars()
+            .map(|c| if glyph(c).is_some() { c } else { ' ' })
+            .collect();
+        let trimmed = self
+            .text
+            .iter()
+            .rposition(|c| *c != ' ')
+            .map_or(0, |i| i + 1);
+        self.text.truncate(trimmed);
+        self.offset = 0.0;
+    }
+
+    fn draw(&self, frame: &mut Frame, area: Rect, palette: &VisualizerPalette) {
+        if area.width < 2 || area.height < 2 || self.intensity < 0.01 || self.text.is_empty() {
+            return;
+        }
+
+        let cols = area.width as usize;
+        let rows = area.height as usize;
+        let dot_cols = cols * 2;
+        let dot_rows = rows * 4;
+        // One font pixel is `scale` by `scale` dots.
+        let scale = (dot_rows / GLYPH_HEIGHT).max(1);
+        let top = dot_rows.saturating_sub(GLYPH_HEIGHT * scale) / 2;
+
+        // Text width in dots, leaving off the last glyph's trailing column.
+        let text_width = (self.text.len() * ADVANCE - 1) * scale;
+        let scrolls = text_width > dot_cols;
+        let period = (self.text.len() * ADVANCE + LOOP_GAP) * scale;
+        // The dot column of the text under screen column 0.
+        let start = if scrolls {
+            self.offset as usize % period
+        } else {
+            0
+        };
+        let left = if scrolls {
+            0
+        } else {
+            (dot_cols - text_width) / 2
+        };
+
+        let lit = |dx: usize, dy: usize| -> bool {
+            if dx < left || dy < top {
+                return false;
+            }
+            let (px, py) = (
+                ((dx - left + start) % period.max(1)) / scale,
+                (dy - top) / scale,
+            );
+            if py >= GLYPH_HEIGHT || !scrolls && dx - left >= text_width {
+                return false;
+            }
+            let (index, column) = (px / ADVANCE, px % ADVANCE);
+            if column >= GLYPH_WIDTH {
+                return false;
+            }
+            let Some(rows) = self.text.get(index).and_then(|&c| glyph(c)) else {
+                return false;
+            };
+            rows[py] & (0b100 >> column) != 0
+        };
+
+        let dot_bits: [[u8; 4]; 2] = [[0x01, 0x02, 0x04, 0x40], [0x08, 0x10, 0x20, 0x80]];
+
+        let a = palette.accents;
+        let colors = [a[1], a[4], a[2], a[5]];
+        let idx = (self.color_phase as usize) % colors.len();
+        let next = (idx + 1) % colors.len();
+        let blend = self.color_phase.fract() as f32;
+        let flash = (self.pulse * 0.8) as f32 * self.intensity;
+
+        let buf = frame.buffer_mut();
+        for row in 0..rows {
+            for col in 0..cols {
+                let mut dots = 0u8;
+                for (dx, bits) in dot_bits.iter().enumerate() {
+                    for (dy, bit) in bits.iter().enumerate() {
+                        if lit(col * 2 + dx, row * 4 + dy) {
+                            dots |= bit;
+                        }
+                    }
+                }
+                if dots == 0 {
+                    continue;
+                }
+                let ch = char::from_u32(0x2800 + dots as u32).unwrap_or(' ');
+                let h_frac = col as f32 / cols.max(1) as f32;
+                let base = blend_colors(
+                    blend_colors(colors[idx], colors[next], blend),
+                    blend_colors(colors[next], colors[(next + 1) % colors.len()], blend),
+                    h_frac,
+                );
+                let faded = blend_colors(palette.dim, base, self.intensity);
+                let color = blend_colors(faded, palette.highlight, flash);
+                let x = area.x + col as u16;
+                let y = area.y + row as u16;
+                if let Some(cell) = buf.cell_mut((x, y)) {
+                    cell.set_char(ch);
+                    cell.set_fg(color);
+                }
+            }
+        }
+    }
+}
+
+/// Rows of a 3x5 letter, top first, leftmost pixel in the high bit.
+fn glyph(c: char) -> Option<[u8; GLYPH_HEIGHT]> {
+    Some(match c {
+        'A' => [0b010, 0b101, 0b111, 0b101, 0b101],
+        'B' => [0b110, 0b101, 0b110, 0b101, 0b110],
+        'C' => [0b011, 0b100, 0b100, 0b100, 0b011],
+        'D' => [0b110, 0b101, 0b101, 0b101, 0b110],
+        'E' => [0b111, 0b100, 0b110, 0b100, 0b111],
+        'F' => [0b111, 0b100, 0b110, 0b100, 0b100],
+        'G' => [0b011, 0b100, 0b101, 0b101, 0b011],
+        'H' => [0b101, 0b101, 0b111, 0b101, 0b101],
+        'I' => [0b111, 0b010, 0b010, 0b010, 0b111],
+        'J' => [0b001, 0b001, 0b001, 0b101, 0b010],
+        'K' => [0b101, 0b101, 0b110, 0b101, 0b101],
+        'L' => [0b100, 0b100, 0b100, 0b100, 0b111],
+        'M' => [0b101, 0b111, 0b111, 0b101, 0b101],
+        'N' => [0b110, 0b101, 0b101, 0b101, 0b101],
+        'O' => [0b010, 0b101, 0b101, 0b101, 0b010],
+        'P' => [0b110, 0b101, 0b110, 0b100, 0b100],
+        'Q' => [0b010, 0b101, 0b101, 0b110, 0b011],
+        'R' => [0b110, 0b101, 0b110, 0b101, 0b101],
+        'S' => [0b011, 0b100, 0b010, 0b001, 0b110],
+        'T' => [0b111, 0b010, 0b010, 0b010, 0b010],
+        'U' => [0b101, 0b101, 0b101, 0b101, 0b111],
+        'V' => [0b101, 0b101, 0b101, 0b101, 0b010],
+        'W' => [0b101, 0b101, 0b111, 0b111, 0b101],
+        'X' => [0b101, 0b101, 0b010, 0b101, 0b101],
+        'Y' => [0b101, 0b101, 0b010, 0b010, 0b010],
+        'Z' => [0b111, 0b001, 0b010, 0b100, 0b111],
+        '0' => [0b111, 0b101, 0b101, 0b101, 0b111],
+        '1' => [0b010, 0b110, 0b010, 0b010, 0b111],
+        '2' => [0b110, 0b001, 0b010, 0b100, 0b111],
+        '3' => [0b110, 0b001, 0b010, 0b001, 0b110],
+        '4' => [0b101, 0b101, 0b111, 0b001, 0b001],
+        '5' => [0b111, 0b100, 0b110, 0b001, 0b110],
+        '6' => [0b011, 0b100, 0b111, 0b101, 0b111],
+        '7' => [0b111, 0b001, 0b010, 0b010, 0b010],
+        '8' => [0b111, 0b101, 0b111, 0b101, 0b111],
+        '9' => [0b111, 0b101, 0b111, 0b001, 0b110],
+        ' ' => [0; GLYPH_HEIGHT],
+        '-' => [0b000, 0b000, 0b111, 0b000, 0b000],
+        '+' => [0b000, 0b010, 0b111, 0b010, 0b000],
+        '.' => [0b000, 0b000, 0b000, 0b000, 0b010],
+        ',' => [0b000, 0b000, 0b000, 0b010, 0b100],
+        ':' => [0b000, 0b010, 0b000, 0b010, 0b000],
+        '\'' => [0b010, 0b010, 0b000, 0b000, 0b000],
+        '!' => [0b010, 0b010, 0b010, 0b000, 0b010],
+        '?' => [0b110, 0b001, 0b010, 0b000, 0b010],
+        '&' => [0b010, 0b101, 0b010, 0b101, 0b011],
+        '/' => [0b001, 0b001, 0b010, 0b100, 0b100],
+        '(' => [0b001, 0b010, 0b010, 0b010, 0b001],
+        ')' => [0b100, 0b010, 0b010, 0b010, 0b100],
+        '#' => [0b101, 0b111, 0b101, 0b111, 0b101],
+        _ => return None,
+    })
+}
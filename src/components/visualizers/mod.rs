@@ -1,6 +1,8 @@
 // Visualizer system: trait, kind enum, shared color utilities, and factory.
 
 pub mod blob;
+pub mod marquee;
+pub mod oscilloscope;
 pub mod rings;
 pub mod spectrum;
 pub mod starfield;
@@ -9,6 +11,8 @@ pub mod waveform;
 use ratatui::{layout::Rect, style::Color, Frame};
 use serde::{Deserialize, Serialize};
 
+use crate::theme::{color_to_rgb, VisualizerPalette};
+
 /// Common interface for all visualizers.
 pub trait Visualizer {
     /// Advance animation state by one frame, given the current playback status.
@@ -21,8 +25,14 @@ pub trait Visualizer {
         audio_rms: f64,
         audio_peak: f64,
     );
-    /// Render the visualizer into the given area.
-    fn draw(&self, frame: &mut Frame, area: Rect);
+    /// Receive per-band spectrum levels (0.0–1.0, lowest frequency first).
+    /// An empty slice means no band data; most visualizers ignore this.
+    fn set_bands(&mut self, _bands: &[f64]) {}
+    /// Receive the title of what's playing (empty when nothing is). Only the
+    /// marquee shows it.
+    fn set_title(&mut self, _title: &str) {}
+    /// Render the visualizer into the given area with the theme's palette.
+    fn draw(&self, frame: &mut Frame, area: Rect, palette: &VisualizerPalette);
 }
 
 /// Identifies which visualizer is active. Persisted in config.
@@ -34,6 +44,8 @@ pub enum VisualizerKind {
     Waveform,
     Starfield,
     Rings,
+    Oscilloscope,
+    Marquee,
 }
 
 impl VisualizerKind {
@@ -44,7 +56,9 @@ impl VisualizerKind {
             Self::Spectrum => Self::Waveform,
             Self::Waveform => Self::Starfield,
             Self::Starfield => Self::Rings,
-            Self::Rings => Self::Blob,
+            Self::Rings => Self::Oscilloscope,
+            Self::Oscilloscope => Self::Marquee,
+            Self::Marquee => Self::Blob,
         }
     }
 
@@ -56,6 +70,8 @@ impl VisualizerKind {
             Self::Waveform => "Waveform",
             Self::Starfield => "Starfield",
             Self::Rings => "Rings",
+            Self::Oscilloscope => "Oscilloscope",
+            Self::Marquee => "Marquee",
         }
     }
 }
@@ -68,6 +84,8 @@ pub fn create_visualizer(kind: VisualizerKind) -> Box<dyn Visualizer> {
         VisualizerKind::Waveform => Box::new(waveform::WaveformVisualizer::default()),
         VisualizerKind::Starfield => Box::new(starfield::StarfieldVisualizer::default()),
         VisualizerKind::Rings => Box::new(rings::RingsVisualizer::default()),
+        VisualizerKind::Oscilloscope => Box::new(oscilloscope::OscilloscopeVisualizer::default()),
+        VisualizerKind::Marquee => Box::new(marquee::MarqueeVisualizer::default()),
     }
 }
 
@@ -81,26 +99,3 @@ pub fn blend_colors(c1: Color, c2: Color, t: f32) -> Color {
         (b1 as f32 * (1.0 - t) + b2 as f32 * t) as u8,
     )
 }
-
-/// Extract RGB components from a ratatui Color, with fallback for indexed colors.
-pub fn color_to_rgb(c: Color) -> (u8, u8, u8) {
-    match c {
-        Color::Rgb(r, g, b) => (r, g, b),
-        Color::Black => (0, 0, 0),
-        Color::Red => (205, 49, 49),
-        Color::Green => (13, 188, 121),
-        Color::Yellow => (229, 229, 16),
-        Color::Blue => (36, 114, 200),
-        Color::Magenta => (188, 63, 188),
-        Color::Cyan => (17, 168, 205),
-        Color::White => (229, 229, 229),
-        Color::DarkGray => (118, 118, 118),
-        Color::LightRed => (241, 76, 76),
-        Color::LightGreen => (35, 209, 139),
-        Color::LightYellow => (245, 245, 67),
-        Color::LightBlue => (59, 142, 234),
-        Color::LightMagenta => (214, 112, 214),
-        Color::LightCyan => (41, 184, 219),
-        _ => (180, 180, 180),
-    }
-}
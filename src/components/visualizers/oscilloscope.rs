@@ -0,0 +1,161 @@
+// Oscilloscope visualizer: phase-scope style Lissajous figure.
+//
+// Traces x = sin(3t + phase), y = sin(2t) with braille dots, like an X/Y
+// scope fed two related tones. Size follows RMS; the peak's lead over RMS
+// detunes the ratio so transients twist the figure, and the phase drifts
+// with loudness so it slowly rotates. A dimmer ghost trails one step behind.
+
+use std::cell::RefCell;
+use std::f64::consts::TAU;
+
+use ratatui::{layout::Rect, Frame};
+
+use super::{blend_colors, Visualizer};
+use crate::theme::VisualizerPalette;
+
+const X_FREQ: f64 = 3.0;
+const Y_FREQ: f64 = 2.0;
+const GHOST_LAG: f64 = 0.35;
+
+pub struct OscilloscopeVisualizer {
+    phase: f64,
+    color_phase: f64,
+    intensity: f32,
+    amplitude: f64,
+    detune: f64,
+    prev_rms: f64,
+    /// Per cell braille dots of the main trace and of the ghost.
+    cached_cells: RefCell<Vec<(u8, u8)>>,
+}
+
+impl Default for OscilloscopeVisualizer {
+    fn default() -> Self {
+        Self {
+            phase: 0.0,
+            color_phase: 0.0,
+            intensity: 0.0,
+            amplitude: 0.0,
+            detune: 0.0,
+            prev_rms: 0.0,
+            cached_cells: RefCell::new(Vec::new()),
+        }
+    }
+}
+
+impl Visualizer for OscilloscopeVisualizer {
+    fn tick(
+        &mut self,
+        playing: bool,
+        paused: bool,
+        buffering: bool,
+        _position_secs: f64,
+        audio_rms: f64,
+        audio_peak: f64,
+    ) {
+        let target_intensity = if !playing || paused {
+            0.0
+        } else if buffering {
+            0.3
+        } else {
+            1.0
+        };
+        self.intensity += (target_intensity - self.intensity) * 0.15;
+
+        let smoothed = self.prev_rms * 0.3 + audio_rms * 0.7;
+        self.prev_rms = smoothed;
+
+        let target_amp = 0.25 + smoothed * 0.75;
+        self.amplitude = self.amplitude * 0.85 + target_amp * 0.15;
+
+        // Crest (peak above RMS) bends the figure away from a clean 3:2.
+        let target_detune = (audio_peak - smoothed).clamp(0.0, 1.0) * 0.5;
+        self.detune = self.detune * 0.9 + target_detune * 0.1;
+
+        if playing && !paused {
+            self.phase += 0.02 + smoothed * 0.06;
+        }
+        self.color_phase += 0.005;
+    }
+
+    fn draw(&self, frame: &mut Frame, area: Rect, palette: &VisualizerPalette) {
+        if area.width < 2 || area.height < 2 || self.intensity < 0.01 {
+            return;
+        }
+
+        let cols = area.width as usize;
+        let rows = area.height as usize;
+        let dot_cols = cols * 2;
+        let dot_rows = rows * 4;
+        let center_x = dot_cols as f64 / 2.0;
+        let center_y = dot_rows as f64 / 2.0;
+        // Braille dots are about square, so one radius keeps the figure round.
+        let radius = center_x.min(center_y) * 0.9 * self.amplitude * self.intensity as f64;
+
+        let dot_bits: [[u8; 4]; 2] = [[0x01, 0x02, 0x04, 0x40], [0x08, 0x10, 0x20, 0x80]];
+
+        let mut cells = self.cached_cells.borrow_mut();
+        cells.clear();
+        cells.resize(cols * rows, (0, 0));
+
+        let samples = (dot_cols + dot_rows) * 8;
+        let x_freq = X_FREQ + self.detune;
+        for (lag, ghost) in [(GHOST_LAG, true), (0.0, false)] {
+            for i in 0..samples {
+                let t = i as f64 / samples as f64 * TAU;
+                let x = center_x + radius * (x_freq * t + self.phase - lag).sin();
+                let y = center_y - radius * (Y_FREQ * t).sin();
+                if x < 0.0 || y < 0.0 {
+                    continue;
+                }
+                let (dx, dy) = (x as usize, y as usize);
+                if dx >= dot_cols || dy >= dot_rows {
+                    continue;
+                }
+                let bit = dot_bits[dx % 2][dy % 4];
+                let cell = &mut cells[(dy / 4) * cols + dx / 2];
+                if ghost {
+                    cell.1 |= bit;
+                } else {
+                    cell.0 |= bit;
+                }
+            }
+        }
+
+        let a = palette.accents;
+        let main_colors = [a[2], a[4], a[3]];
+        let idx = (self.color_phase as usize) % main_colors.len();
+        let next = (idx + 1) % main_colors.len();
+        let inner = blend_colors(
+            main_colors[idx],
+            main_colors[next],
+            self.color_phase.fract() as f32,
+        );
+        let ghost_color = blend_colors(palette.dim, a[0], 0.5);
+
+        let buf = frame.buffer_mut();
+        for row in 0..rows {
+            for col in 0..cols {
+                let (main, ghost) = cells[row * cols + col];
+                if main | ghost == 0 {
+                    continue;
+                }
+                let ch = char::from_u32(0x2800 + (main | ghost) as u32).unwrap_or(' ');
+                let color = if main != 0 {
+                    // Brighter toward the rim, where the beam slows and turns.
+                    let dx = (col as f64 * 2.0 + 1.0 - center_x) / center_x.max(1.0);
+                    let dy = (row as f64 * 4.0 + 2.0 - center_y) / center_y.max(1.0);
+                    let rim = (dx * dx + dy * dy).sqrt().min(1.0) as f32;
+                    blend_colors(inner, palette.highlight, rim * 0.6)
+                } else {
+                    ghost_color
+                };
+                let x = area.x + col as u16;
+                let y = area.y + row as u16;
+                if let Some(cell) = buf.cell_mut((x, y)) {
+                    cell.set_char(ch);
+                    cell.set_fg(color);
+                }
+            }
+        }
+    }
+}
@@ -10,17 +10,11 @@ use std::cell::{Cell, RefCell};
 use ratatui::{layout::Rect, style::Color, Frame};
 
 use super::{blend_colors, Visualizer};
+use crate::theme::VisualizerPalette;
 
 const MAX_RINGS: usize = 8;
-
-const RING_COLORS: &[Color] = &[
-    Color::Cyan,
-    Color::Magenta,
-    Color::LightBlue,
-    Color::LightMagenta,
-    Color::LightCyan,
-    Color::Blue,
-];
+// Ring colors cycle through the palette's accents.
+const RING_COLOR_COUNT: usize = 6;
 
 struct Ring {
     radius: f64,
@@ -79,7 +73,7 @@ impl RingsVisualizer {
         self.rings.push(Ring {
             radius: 0.02,
             alpha: 1.0,
-            color_idx: self.ring_counter % RING_COLORS.len(),
+            color_idx: self.ring_counter % RING_COLOR_COUNT,
         });
         self.ring_counter += 1;
     }
@@ -140,7 +134,7 @@ impl Visualizer for RingsVisualizer {
         }
     }
 
-    fn draw(&self, frame: &mut Frame, area: Rect) {
+    fn draw(&self, frame: &mut Frame, area: Rect, palette: &VisualizerPalette) {
         if area.width < 2 || area.height < 2 || self.intensity < 0.01 {
             return;
         }
@@ -184,8 +178,8 @@ impl Visualizer for RingsVisualizer {
             let thickness = (2.0 - ring.radius * 1.5).max(0.5);
 
             let color = blend_colors(
-                RING_COLORS[ring.color_idx],
-                Color::Black,
+                palette.accents[ring.color_idx],
+                palette.background,
                 1.0 - (ring.alpha * self.intensity as f64) as f32,
             );
 
@@ -217,7 +211,7 @@ impl Visualizer for RingsVisualizer {
                 }
 
                 let ch = char::from_u32(0x2800 + *dots as u32).unwrap_or(' ');
-                let color = color_opt.unwrap_or(Color::DarkGray);
+                let color = color_opt.unwrap_or(palette.dim);
 
                 let x = area.x + col as u16;
                 let y = area.y + row as u16;
@@ -1,13 +1,15 @@
 // Spectrum visualizer: classic EQ-style vertical bars with peak-hold dots.
 //
-// 12 bars across the width, heights derived from pseudo-frequency-band
+// 12 bars across the width, heights from mpv's per-band levels when the
+// analysis filter reports them, otherwise from a pseudo-frequency-band
 // decomposition of the RMS signal. Filled bottom-up with braille dots.
 // Gradient: green (bottom) -> yellow (mid) -> magenta (top).
 // Bars decay smoothly; beat transients cause jumps.
 
-use ratatui::{layout::Rect, style::Color, Frame};
+use ratatui::{layout::Rect, Frame};
 
 use super::{blend_colors, Visualizer};
+use crate::theme::VisualizerPalette;
 
 const NUM_BARS: usize = 12;
 
@@ -19,9 +21,16 @@ pub struct SpectrumVisualizer {
     peak_decay: [f64; NUM_BARS],
     intensity: f32,
     prev_rms: f64,
+    /// Real band levels from mpv; empty falls back to the RMS approximation.
+    bands: Vec<f64>,
 }
 
 impl Visualizer for SpectrumVisualizer {
+    fn set_bands(&mut self, bands: &[f64]) {
+        self.bands.clear();
+        self.bands.extend_from_slice(bands);
+    }
+
     fn tick(
         &mut self,
         playing: bool,
@@ -54,11 +63,14 @@ impl Visualizer for SpectrumVisualizer {
         };
 
         for i in 0..NUM_BARS {
-            // Pseudo-frequency distribution: each bar gets a different sine
-            // combination to simulate frequency bands
-            let freq = 1.0 + i as f64 * 0.7;
-            let band_energy =
-                smoothed * (0.6 + 0.4 * ((self.phase * freq + i as f64 * 0.9).sin() * 0.5 + 0.5));
+            let band_energy = if self.bands.is_empty() {
+                // Pseudo-frequency distribution: each bar gets a different sine
+                // combination to simulate frequency bands
+                let freq = 1.0 + i as f64 * 0.7;
+                smoothed * (0.6 + 0.4 * ((self.phase * freq + i as f64 * 0.9).sin() * 0.5 + 0.5))
+            } else {
+                self.bands[i * self.bands.len() / NUM_BARS]
+            };
 
             let target = (band_energy + transient * 0.3) * self.intensity as f64;
             // Bars jump up fast but decay slowly
@@ -82,7 +94,7 @@ impl Visualizer for SpectrumVisualizer {
         }
     }
 
-    fn draw(&self, frame: &mut Frame, area: Rect) {
+    fn draw(&self, frame: &mut Frame, area: Rect, palette: &VisualizerPalette) {
         if area.width < 2 || area.height < 2 || self.intensity < 0.01 {
             return;
         }
@@ -157,18 +169,15 @@ impl Visualizer for SpectrumVisualizer {
 
                     if has_dot {
                         let ch = char::from_u32(0x2800 + dots as u32).unwrap_or(' ');
+                        let [low, mid, high] = palette.levels;
                         let color = if is_peak && max_frac < 0.01 {
-                            Color::White
+                            palette.highlight
                         } else {
-                            // Gradient: green -> yellow -> magenta
+                            // Gradient: low -> mid -> high
                             if max_frac < 0.5 {
-                                blend_colors(Color::Green, Color::Yellow, (max_frac * 2.0) as f32)
+                                blend_colors(low, mid, (max_frac * 2.0) as f32)
                             } else {
-                                blend_colors(
-                                    Color::Yellow,
-                                    Color::Magenta,
-                                    ((max_frac - 0.5) * 2.0) as f32,
-                                )
+                                blend_colors(mid, high, ((max_frac - 0.5) * 2.0) as f32)
                             }
                         };
 
@@ -7,10 +7,11 @@
 
 use std::cell::{Cell, RefCell};
 
-use ratatui::{layout::Rect, style::Color, Frame};
+use ratatui::{layout::Rect, Frame};
 
 use super::blend_colors;
 use super::Visualizer;
+use crate::theme::VisualizerPalette;
 
 const NUM_PARTICLES: usize = 150;
 
@@ -130,7 +131,7 @@ impl Visualizer for StarfieldVisualizer {
         }
     }
 
-    fn draw(&self, frame: &mut Frame, area: Rect) {
+    fn draw(&self, frame: &mut Frame, area: Rect, palette: &VisualizerPalette) {
         if area.width < 2 || area.height < 2 || self.intensity < 0.01 {
             return;
         }
@@ -214,8 +215,8 @@ impl Visualizer for StarfieldVisualizer {
                 // Brightness increases with distance from center
                 let brightness = max_radius.clamp(0.0, 1.0);
                 let color = blend_colors(
-                    Color::Blue,
-                    Color::White,
+                    palette.accents[5],
+                    palette.highlight,
                     (brightness * self.intensity as f64) as f32,
                 );
 
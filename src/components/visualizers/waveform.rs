@@ -7,12 +7,10 @@
 
 use std::cell::RefCell;
 
-use ratatui::{layout::Rect, style::Color, Frame};
+use ratatui::{layout::Rect, Frame};
 
 use super::{blend_colors, Visualizer};
-
-const TRAIL_COLORS: &[Color] = &[Color::Cyan, Color::Blue, Color::Magenta];
-const MAIN_COLORS: &[Color] = &[Color::LightCyan, Color::LightMagenta, Color::LightGreen];
+use crate::theme::VisualizerPalette;
 
 pub struct WaveformVisualizer {
     phase: f64,
@@ -77,7 +75,7 @@ impl Visualizer for WaveformVisualizer {
         self.color_phase += 0.005;
     }
 
-    fn draw(&self, frame: &mut Frame, area: Rect) {
+    fn draw(&self, frame: &mut Frame, area: Rect, palette: &VisualizerPalette) {
         if area.width < 2 || area.height < 2 || self.intensity < 0.01 {
             return;
         }
@@ -122,10 +120,13 @@ impl Visualizer for WaveformVisualizer {
         }
 
         // Color gradient along horizontal axis
-        let main_palette_idx = (self.color_phase as usize) % MAIN_COLORS.len();
-        let main_palette_next = (main_palette_idx + 1) % MAIN_COLORS.len();
-        let trail_palette_idx = (self.color_phase as usize) % TRAIL_COLORS.len();
-        let trail_palette_next = (trail_palette_idx + 1) % TRAIL_COLORS.len();
+        let a = palette.accents;
+        let trail_colors = [a[0], a[5], a[1]];
+        let main_colors = [a[4], a[3], palette.levels[0]];
+        let main_palette_idx = (self.color_phase as usize) % main_colors.len();
+        let main_palette_next = (main_palette_idx + 1) % main_colors.len();
+        let trail_palette_idx = (self.color_phase as usize) % trail_colors.len();
+        let trail_palette_next = (trail_palette_idx + 1) % trail_colors.len();
         let palette_blend = self.color_phase.fract() as f32;
 
         for row in 0..rows {
@@ -160,30 +161,30 @@ impl Visualizer for WaveformVisualizer {
                     let h_frac = col as f32 / cols.max(1) as f32;
                     let color = if has_main {
                         let base = blend_colors(
-                            MAIN_COLORS[main_palette_idx],
-                            MAIN_COLORS[main_palette_next],
+                            main_colors[main_palette_idx],
+                            main_colors[main_palette_next],
                             palette_blend,
                         );
                         let end = blend_colors(
-                            MAIN_COLORS[(main_palette_idx + 1) % MAIN_COLORS.len()],
-                            MAIN_COLORS[(main_palette_next + 1) % MAIN_COLORS.len()],
+                            main_colors[(main_palette_idx + 1) % main_colors.len()],
+                            main_colors[(main_palette_next + 1) % main_colors.len()],
                             palette_blend,
                         );
                         blend_colors(base, end, h_frac)
                     } else if has_trail {
                         let base = blend_colors(
-                            TRAIL_COLORS[trail_palette_idx],
-                            TRAIL_COLORS[trail_palette_next],
+                            trail_colors[trail_palette_idx],
+                            trail_colors[trail_palette_next],
                             palette_blend,
                         );
                         let end = blend_colors(
-                            TRAIL_COLORS[(trail_palette_idx + 1) % TRAIL_COLORS.len()],
-                            TRAIL_COLORS[(trail_palette_next + 1) % TRAIL_COLORS.len()],
+                            trail_colors[(trail_palette_idx + 1) % trail_colors.len()],
+                            trail_colors[(trail_palette_next + 1) % trail_colors.len()],
                             palette_blend,
                         );
                         blend_colors(base, end, h_frac)
                     } else {
-                        Color::DarkGray
+                        palette.dim
                     };
 
                     let x = area.x + col as u16;
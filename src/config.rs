@@ -2,15 +2,41 @@
 // Falls back to sensible defaults when the file is missing.
 
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::path::PathBuf;
+use std::time::Duration;
 
+use crate::api::models::default_relays;
+use crate::api::nts::NtsClient;
 use crate::components::visualizers::VisualizerKind;
+use crate::hooks::HookEvent;
+use crate::keymap::{Command, KeySpec};
+use crate::player::args;
+use crate::player::buffer::{BufferProfile, BufferSettings};
+use crate::player::crossfade::MAX_CROSSFADE_SECS;
+use crate::player::equalizer::{self, EqualizerPreset};
+use crate::theme::ColorSupport;
 
 /// Application configuration, deserialized from `~/.config/clisten/config.toml`.
 #[derive(Debug, Clone, Default, Deserialize, Serialize)]
 pub struct Config {
     #[serde(default)]
     pub general: GeneralConfig,
+    #[serde(default)]
+    pub player: PlayerConfig,
+    #[serde(default, skip_serializing_if = "NtsConfig::is_empty")]
+    pub nts: NtsConfig,
+    #[serde(default, skip_serializing_if = "RemoteConfig::is_disabled")]
+    pub remote: RemoteConfig,
+    #[serde(default, skip_serializing_if = "HooksConfig::is_empty")]
+    pub hooks: HooksConfig,
+    #[serde(default, skip_serializing_if = "NetworkConfig::is_empty")]
+    pub network: NetworkConfig,
+    #[serde(default, skip_serializing_if = "LayoutConfig::is_default")]
+    pub layout: LayoutConfig,
+    /// Keybinding overrides: command name → key chord(s).
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub keys: BTreeMap<Command, KeySpec>,
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
@@ -19,10 +45,16 @@ pub struct GeneralConfig {
     #[serde(default = "default_frame_rate")]
     pub frame_rate: f64,
 
-    /// Color theme: "dark" or "light" (default: "dark").
+    /// Color theme preset: "dark", "light", "tokyo-night", "gruvbox", or
+    /// "catppuccin" (default: "dark").
     #[serde(default = "default_theme")]
     pub theme: String,
 
+    /// Colors the terminal can show: "truecolor", "256", or "16". Unset,
+    /// it's detected from COLORTERM and TERM.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub colors: Option<ColorSupport>,
+
     /// Active visualizer kind (default: Blob).
     #[serde(default)]
     pub visualizer: VisualizerKind,
@@ -31,15 +63,76 @@ pub struct GeneralConfig {
     #[serde(default)]
     pub completed_onboarding: Vec<String>,
 
-    /// Automatically skip the ~3s NTS intro jingle on archived episodes.
+    /// Automatically skip the NTS intro jingle on archived episodes, up to
+    /// the silence that follows it (3s when that can't be found).
     #[serde(default)]
     pub skip_nts_intro: bool,
+
+    /// Ask before clearing the queue or history and before deleting a
+    /// playlist (default: true).
+    #[serde(default = "default_confirm_destructive")]
+    pub confirm_destructive: bool,
+
+    /// Radio mode: when the last queue entry starts, queue a few unplayed
+    /// episodes sharing its genres so playback keeps going.
+    #[serde(default)]
+    pub radio_mode: bool,
+
+    /// Low data mode, for metered connections: lower-bitrate audio, no
+    /// preloading or page lookups, and API responses kept for longer.
+    #[serde(default)]
+    pub low_data: bool,
+
+    /// Reopen where the last session left off: the open tab, its selection
+    /// and its filter. The queue is kept either way.
+    #[serde(default)]
+    pub restore_session: bool,
+
+    /// With `restore_session`, also load the track that was playing, paused
+    /// where it was left.
+    #[serde(default)]
+    pub restore_playback: bool,
+
+    /// Pause when the system goes to sleep, and load the track again fresh
+    /// after waking (default: true).
+    #[serde(default = "default_pause_on_sleep")]
+    pub pause_on_sleep: bool,
+
+    /// Pause when an audio output goes away, e.g. headphones unplugged.
+    #[serde(default)]
+    pub pause_on_device_loss: bool,
+
+    /// Show what's playing in the terminal window / tmux pane title (default: true).
+    #[serde(default = "default_terminal_title")]
+    pub terminal_title: bool,
+
+    /// Count plays and command usage in the local database for the Stats
+    /// view (default: false). Nothing is ever sent over the network.
+    #[serde(default)]
+    pub metrics: bool,
+
+    /// Write playback health samples to playback-health.log for
+    /// `clisten doctor --playback` (default: false).
+    #[serde(default)]
+    pub debug_playback: bool,
 }
 
 fn default_frame_rate() -> f64 {
     30.0
 }
 
+fn default_confirm_destructive() -> bool {
+    true
+}
+
+fn default_terminal_title() -> bool {
+    true
+}
+
+fn default_pause_on_sleep() -> bool {
+    true
+}
+
 fn default_theme() -> String {
     crate::theme::THEME_DARK.to_string()
 }
@@ -49,14 +142,496 @@ impl Default for GeneralConfig {
         Self {
             frame_rate: default_frame_rate(),
             theme: default_theme(),
+            colors: None,
             visualizer: VisualizerKind::default(),
             completed_onboarding: Vec::new(),
             skip_nts_intro: false,
+            confirm_destructive: default_confirm_destructive(),
+            radio_mode: false,
+            low_data: false,
+            restore_session: false,
+            restore_playback: false,
+            pause_on_sleep: default_pause_on_sleep(),
+            pause_on_device_loss: false,
+            terminal_title: default_terminal_title(),
+            metrics: false,
+            debug_playback: false,
+        }
+    }
+}
+
+/// mpv playback tuning (`[player]` section).
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct PlayerConfig {
+    /// Buffer preset: "default", "low-latency", or "stable" (default: "default").
+    #[serde(default)]
+    pub buffer_profile: BufferProfile,
+
+    /// Seconds of audio to cache ahead; overrides the profile's value.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cache_secs: Option<u32>,
+
+    /// Maximum demuxer cache size, e.g. "32MiB"; overrides the profile's value.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub demuxer_max_bytes: Option<String>,
+
+    /// Seconds to crossfade between queue tracks; unset or 0 plays them
+    /// back to back.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub crossfade_secs: Option<f64>,
+
+    /// Equalizer preset: "flat", "bass-boost", "vocal", or "custom"
+    /// (default: "flat").
+    #[serde(default)]
+    pub equalizer: EqualizerPreset,
+
+    /// Gains in dB for the custom preset's five bands (60 Hz to 14 kHz),
+    /// each within ±12.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub custom_eq_gains: Vec<f64>,
+
+    /// Extra mpv flags for every spawn, e.g. `["--http-proxy=http://host:3128"]`.
+    /// They come last, so they override the buffer profile.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub mpv_args: Vec<String>,
+
+    /// yt-dlp format selector for resolved pages, e.g. "bestaudio[abr<=128]".
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ytdl_format: Option<String>,
+
+    /// mpv executable to run, for when it isn't on PATH (default: "mpv").
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mpv_path: Option<String>,
+
+    /// Seconds playback may sit without moving before it is restarted
+    /// (default: 20; 0 turns the watchdog off).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub stall_timeout_secs: Option<u64>,
+}
+
+fn default_stall_timeout_secs() -> u64 {
+    20
+}
+
+impl PlayerConfig {
+    /// Effective cache settings for the active profile and overrides.
+    pub fn buffer_settings(&self) -> BufferSettings {
+        BufferSettings::resolve(
+            self.buffer_profile,
+            self.cache_secs,
+            self.demuxer_max_bytes.as_deref(),
+        )
+    }
+
+    /// The mpv executable: the configured path, or "mpv" from PATH.
+    pub fn mpv_binary(&self) -> &str {
+        self.mpv_path
+            .as_deref()
+            .filter(|path| !path.trim().is_empty())
+            .unwrap_or("mpv")
+    }
+
+    /// How long playback may be stuck before it is restarted, or None when
+    /// the watchdog is off.
+    pub fn stall_timeout(&self) -> Option<Duration> {
+        match self
+            .stall_timeout_secs
+            .unwrap_or_else(default_stall_timeout_secs)
+        {
+            0 => None,
+            secs => Some(Duration::from_secs(secs)),
+        }
+    }
+
+    /// Crossfade length in seconds, clamped to 0–30; 0 means off.
+    pub fn crossfade_secs(&self) -> f64 {
+        self.crossfade_secs
+            .filter(|secs| secs.is_finite())
+            .unwrap_or(0.0)
+            .clamp(0.0, MAX_CROSSFADE_SECS)
+    }
+
+    /// Band gains for the active equalizer preset.
+    pub fn equalizer_gains(&self) -> [f64; 5] {
+        self.equalizer
+            .gains(equalizer::custom_gains(&self.custom_eq_gains))
+    }
+
+    /// mpv filter for the active equalizer preset, None when flat.
+    pub fn equalizer_filter(&self) -> Option<String> {
+        equalizer::mpv_filter(self.equalizer_gains())
+    }
+
+    /// Drop custom mpv args and a yt-dlp format that can't be used,
+    /// returning a message for each.
+    pub fn sanitize(&mut self) -> Vec<String> {
+        let mut problems = Vec::new();
+        self.mpv_args.retain(|arg| match args::check_arg(arg) {
+            Ok(()) => true,
+            Err(problem) => {
+                problems.push(problem);
+                false
+            }
+        });
+        if let Some(Err(problem)) = self.ytdl_format.as_deref().map(args::check_ytdl_format) {
+            problems.push(problem);
+            self.ytdl_format = None;
+        }
+        problems
+    }
+
+    /// Flags appended to every mpv spawn: the custom args, then the yt-dlp
+    /// format. Assumes `sanitize` has run.
+    pub fn extra_mpv_args(&self) -> Vec<String> {
+        let mut extra = self.mpv_args.clone();
+        extra.extend(self.ytdl_format.as_deref().map(args::ytdl_format_arg));
+        extra
+    }
+}
+
+/// NTS endpoints (`[nts]` section). Empty relay lists use the built-in relays.
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct NtsConfig {
+    /// Stream URLs for live channel 1, tried in order.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub channel_1_relays: Vec<String>,
+
+    /// Stream URLs for live channel 2, tried in order.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub channel_2_relays: Vec<String>,
+
+    /// Base URL of an NTS API mirror, for testing against a local server.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub api_base: Option<String>,
+
+    /// Seconds between refreshes of the Live tab (default: 120, 0 = never).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub live_refresh_secs: Option<u64>,
+
+    /// Times a request that timed out or hit a server error is retried,
+    /// with growing pauses, before it fails (default: 2).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub retries: Option<u32>,
+
+    /// Show the Search tab's genres grouped under their top-level genre,
+    /// folded until opened (toggled with `z`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub group_genres: Option<bool>,
+
+    /// Seconds of intro to skip per show alias, in place of detecting it
+    /// (0 = play that show's intro).
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub intro_secs: BTreeMap<String, f64>,
+}
+
+fn default_live_refresh_secs() -> u64 {
+    120
+}
+
+impl NtsConfig {
+    /// Stream URLs for a live channel: the configured list, or the built-ins.
+    pub fn relays(&self, channel: u8) -> Vec<String> {
+        let configured = match channel {
+            1 => &self.channel_1_relays,
+            2 => &self.channel_2_relays,
+            _ => return Vec::new(),
+        };
+        if configured.is_empty() {
+            default_relays(channel)
+                .iter()
+                .map(|url| url.to_string())
+                .collect()
+        } else {
+            configured.clone()
+        }
+    }
+
+    /// How often the Live tab refreshes, or None when it doesn't.
+    pub fn live_refresh_interval(&self) -> Option<Duration> {
+        match self
+            .live_refresh_secs
+            .unwrap_or_else(default_live_refresh_secs)
+        {
+            0 => None,
+            secs => Some(Duration::from_secs(secs)),
+        }
+    }
+
+    /// API client for the configured base URL.
+    pub fn client(&self) -> NtsClient {
+        let client = match &self.api_base {
+            Some(base) => NtsClient::with_base(base.as_str()),
+            None => NtsClient::new(),
+        };
+        match self.retries {
+            Some(retries) => client.with_retries(retries),
+            None => client,
         }
     }
+
+    fn is_empty(&self) -> bool {
+        self.channel_1_relays.is_empty()
+            && self.channel_2_relays.is_empty()
+            && self.api_base.is_none()
+            && self.live_refresh_secs.is_none()
+            && self.retries.is_none()
+            && self.group_genres.is_none()
+            && self.intro_secs.is_empty()
+    }
+}
+
+/// Remote control server (`[remote]` section), off by default.
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct RemoteConfig {
+    /// Listen for `clisten ctl` commands on a Unix socket in the data dir.
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// Also serve the commands over HTTP on this address, e.g.
+    /// "127.0.0.1:8137" (or "0.0.0.0:8137" to reach it from the LAN).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub http: Option<String>,
+
+    /// Token HTTP clients must send as `?token=` or a bearer token.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub token: Option<String>,
+}
+
+impl RemoteConfig {
+    fn is_disabled(&self) -> bool {
+        !self.enabled && self.http.is_none() && self.token.is_none()
+    }
+}
+
+/// Shell commands run on playback events (`[hooks]` section). Each gets
+/// CLISTEN_EVENT, CLISTEN_TITLE, CLISTEN_ARTIST, and CLISTEN_URL.
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct HooksConfig {
+    /// Run when a track starts playing.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub track_started: Option<String>,
+
+    /// Run when a track ends, whether it finished or was skipped.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub track_finished: Option<String>,
+
+    /// Run when a stream reports a new song.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub metadata_changed: Option<String>,
+}
+
+impl HooksConfig {
+    /// The command for `event`, unless unset or blank.
+    pub fn command(&self, event: HookEvent) -> Option<&str> {
+        let command = match event {
+            HookEvent::TrackStarted => &self.track_started,
+            HookEvent::TrackFinished => &self.track_finished,
+            HookEvent::MetadataChanged => &self.metadata_changed,
+        };
+        command.as_deref().filter(|c| !c.trim().is_empty())
+    }
+
+    fn is_empty(&self) -> bool {
+        self.track_started.is_none()
+            && self.track_finished.is_none()
+            && self.metadata_changed.is_none()
+    }
+}
+
+/// Where the queue is drawn.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum QueuePosition {
+    /// Under Now Playing.
+    #[default]
+    Right,
+    /// Under the list.
+    Left,
+    Hidden,
+}
+
+/// Panel placement and sizes (`[layout]` section); `<` and `>` change the
+/// split while the app runs.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct LayoutConfig {
+    /// Width of the list panel, in percent of the screen (default: 60).
+    #[serde(default = "default_split")]
+    pub split: u16,
+
+    /// Put Now Playing on the left and the list on the right.
+    #[serde(default)]
+    pub swap_panels: bool,
+
+    #[serde(default)]
+    pub queue: QueuePosition,
+
+    /// Leave the visualizer out of Now Playing, giving its rows to the queue.
+    #[serde(default)]
+    pub hide_visualizer: bool,
+
+    /// Leave out the controls bar along the bottom.
+    #[serde(default)]
+    pub hide_play_controls: bool,
+}
+
+/// Narrowest and widest the list panel can be, in percent.
+pub const SPLIT_RANGE: (u16, u16) = (30, 80);
+/// Percent `<` and `>` change the split by.
+pub const SPLIT_STEP: i16 = 5;
+
+fn default_split() -> u16 {
+    60
+}
+
+impl Default for LayoutConfig {
+    fn default() -> Self {
+        Self {
+            split: default_split(),
+            swap_panels: false,
+            queue: QueuePosition::default(),
+            hide_visualizer: false,
+            hide_play_controls: false,
+        }
+    }
+}
+
+impl LayoutConfig {
+    /// The list panel's width in percent, within SPLIT_RANGE.
+    pub fn list_percent(&self) -> u16 {
+        self.split.clamp(SPLIT_RANGE.0, SPLIT_RANGE.1)
+    }
+
+    /// Widen the list panel by `delta` percent (narrow it when negative);
+    /// returns the new width.
+    pub fn resize_split(&mut self, delta: i16) -> u16 {
+        self.split = self
+            .list_percent()
+            .saturating_add_signed(delta)
+            .clamp(SPLIT_RANGE.0, SPLIT_RANGE.1);
+        self.split
+    }
+
+    fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+/// Proxy and User-Agent for API requests and mpv (`[network]` section).
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct NetworkConfig {
+    /// HTTP proxy for the NTS API, mpv, and yt-dlp, e.g. "http://proxy:3128".
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub proxy: Option<String>,
+
+    /// User-Agent sent with API requests and stream requests.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user_agent: Option<String>,
+}
+
+impl NetworkConfig {
+    /// Drop a proxy or User-Agent that can't be used, returning a message
+    /// for each.
+    pub fn sanitize(&mut self) -> Vec<String> {
+        let mut problems = Vec::new();
+        if let Some(Err(problem)) = self.proxy.as_deref().map(check_proxy) {
+            problems.push(problem);
+            self.proxy = None;
+        }
+        if let Some(user_agent) = &self.user_agent {
+            if user_agent.trim().is_empty() || user_agent.chars().any(char::is_control) {
+                problems.push(format!(
+                    "user_agent `{user_agent}` is empty or not one line"
+                ));
+                self.user_agent = None;
+            }
+        }
+        problems
+    }
+
+    /// mpv flags applying the proxy and User-Agent to streams and to the
+    /// pages its yt-dlp hook resolves.
+    pub fn mpv_args(&self) -> Vec<String> {
+        let mut args = Vec::new();
+        if let Some(proxy) = &self.proxy {
+            args.push(format!("--http-proxy={proxy}"));
+            args.push(format!("--ytdl-raw-options-append=proxy={proxy}"));
+        }
+        if let Some(user_agent) = &self.user_agent {
+            args.push(format!("--user-agent={user_agent}"));
+            args.push(format!("--ytdl-raw-options-append=user-agent={user_agent}"));
+        }
+        args
+    }
+
+    fn is_empty(&self) -> bool {
+        self.proxy.is_none() && self.user_agent.is_none()
+    }
+}
+
+/// Why `proxy` can't be used, or Ok if it can. mpv only speaks HTTP
+/// proxies, so SOCKS ones are turned away rather than half applied.
+fn check_proxy(proxy: &str) -> Result<(), String> {
+    match reqwest::Url::parse(proxy) {
+        Ok(url) if matches!(url.scheme(), "http" | "https") && url.host().is_some() => Ok(()),
+        Ok(url) if url.scheme().starts_with("socks") => Err(format!(
+            "proxy `{proxy}`: SOCKS proxies aren't supported by mpv, use an http:// proxy"
+        )),
+        _ => Err(format!(
+            "proxy `{proxy}` must be an http:// or https:// URL"
+        )),
+    }
 }
 
 impl Config {
+    /// Drop settings that can't be used, returning a message for each.
+    pub fn sanitize(&mut self) -> Vec<String> {
+        let mut problems = self.player.sanitize();
+        problems.extend(self.network.sanitize());
+        problems
+    }
+
+    /// API client for the `[nts]` settings, through the configured proxy.
+    /// Assumes `sanitize` has run.
+    pub fn nts_client(&self) -> NtsClient {
+        let client = self.nts.client();
+        let network = &self.network;
+        if network.is_empty() {
+            return client;
+        }
+        match client
+            .clone()
+            .with_network(network.proxy.as_deref(), network.user_agent.as_deref())
+        {
+            Ok(client) => client,
+            Err(e) => {
+                tracing::warn!("network config: {e}");
+                client
+            }
+        }
+    }
+
+    /// Flags appended to every mpv spawn: the network settings, then the
+    /// `[player]` ones, so custom args can still override them. Low data
+    /// mode's yt-dlp format comes last and wins over `ytdl_format`.
+    pub fn extra_mpv_args(&self) -> Vec<String> {
+        let mut args = self.network.mpv_args();
+        args.extend(self.player.extra_mpv_args());
+        if self.general.low_data {
+            args.push(args::ytdl_format_arg(args::LOW_DATA_YTDL_FORMAT));
+        }
+        args
+    }
+
+    /// The yt-dlp format mpv should use now: low data mode's, the
+    /// configured one, or "" for mpv's own default.
+    pub fn ytdl_format(&self) -> &str {
+        if self.general.low_data {
+            args::LOW_DATA_YTDL_FORMAT
+        } else {
+            self.player.ytdl_format.as_deref().unwrap_or_default()
+        }
+    }
+
     /// Read config from disk, or return defaults if the file doesn't exist.
     pub fn load() -> anyhow::Result<Self> {
         let config_path = Self::config_path();
@@ -1,12 +1,137 @@
-// SQLite persistence for queue state.
+// SQLite persistence for queue state, favorites, play history, playlists,
+// followed shows, podcast subscriptions, resume positions, per-item playback preferences, cached API
+// snapshots, search history, and opt-in usage metrics.
 // Data lives in ~/.local/share/clisten/clisten.db.
 
-use rusqlite::{params, Connection};
+use rusqlite::{
+    params, Connection, ErrorCode, OptionalExtension, Transaction, TransactionBehavior,
+};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::path::PathBuf;
+use std::time::Duration;
 
-use crate::api::models::DiscoveryItem;
+use crate::api::models::{DiscoveryItem, UrlMeta};
 use crate::player::queue::QueueItem;
 
+/// Schema migrations, applied in order on every open. Each script must be
+/// idempotent (`CREATE ... IF NOT EXISTS`).
+const MIGRATIONS: &[&str] = &[
+    include_str!("../migrations/001_init.sql"),
+    include_str!("../migrations/002_source_preferences.sql"),
+    include_str!("../migrations/003_resume_positions.sql"),
+    include_str!("../migrations/004_favorites.sql"),
+    include_str!("../migrations/005_followed_shows.sql"),
+    include_str!("../migrations/006_metrics.sql"),
+    include_str!("../migrations/007_favorite_positions.sql"),
+    include_str!("../migrations/008_history.sql"),
+    include_str!("../migrations/009_playlists.sql"),
+    include_str!("../migrations/010_snapshots.sql"),
+    include_str!("../migrations/011_search_history.sql"),
+    include_str!("../migrations/012_bookmarks.sql"),
+    include_str!("../migrations/013_track_lengths.sql"),
+    include_str!("../migrations/014_podcasts.sql"),
+    include_str!("../migrations/015_app_state.sql"),
+];
+
+// How long a statement waits on another connection's lock before failing.
+const BUSY_TIMEOUT: Duration = Duration::from_secs(5);
+// Extra attempts for a write transaction that still comes back SQLITE_BUSY.
+const BUSY_RETRIES: u32 = 3;
+const BUSY_RETRY_DELAY: Duration = Duration::from_millis(200);
+
+/// A show followed from one of its episodes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FollowedShow {
+    pub show_alias: String,
+    pub title: String,
+    /// Newest broadcast date already seen; episodes after it are new.
+    pub seen_until: Option<String>,
+}
+
+/// A subscribed podcast feed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PodcastSubscription {
+    pub url: String,
+    /// The feed's title, once it has been fetched.
+    pub title: Option<String>,
+}
+
+/// A stored favorite, as listed in the favorites view.
+#[derive(Debug, Clone)]
+pub struct Favorite {
+    pub id: i64,
+    pub item: DiscoveryItem,
+    /// "nts" or "direct".
+    pub source: String,
+    /// "live", "episode", "url", or "genre".
+    pub item_type: String,
+    /// Seconds since the favorite was added.
+    pub age_secs: u64,
+    pub pinned: bool,
+}
+
+/// Plays of one item, as listed in the history view.
+#[derive(Debug, Clone)]
+pub struct HistoryEntry {
+    pub key: String,
+    /// The item as of its most recent play.
+    pub item: DiscoveryItem,
+    /// Seconds since the most recent play started.
+    pub age_secs: u64,
+    pub play_count: u32,
+    /// Total time listened across all plays.
+    pub listened_secs: f64,
+}
+
+/// A named playlist, as listed in the playlists view.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PlaylistSummary {
+    pub id: i64,
+    pub name: String,
+    pub item_count: usize,
+}
+
+/// A position marked in an episode (press `b`).
+#[derive(Debug, Clone, PartialEq)]
+pub struct Bookmark {
+    pub id: i64,
+    pub position_secs: f64,
+    pub label: String,
+}
+
+/// A favorite as written to an export file.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExportedFavorite {
+    pub item: DiscoveryItem,
+    /// SQLite datetime the favorite was added.
+    pub created_at: String,
+    #[serde(default)]
+    pub pinned: bool,
+}
+
+/// One play as written to an export file.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExportedPlay {
+    pub item: DiscoveryItem,
+    /// SQLite datetime the play started.
+    pub played_at: String,
+    #[serde(default)]
+    pub duration_secs: f64,
+}
+
+/// Directory holding the database and exports.
+pub fn data_dir() -> PathBuf {
+    dirs::data_dir()
+        .unwrap_or_else(|| PathBuf::from("."))
+        .join("clisten")
+}
+
+/// The SQLite database file.
+pub fn db_path() -> PathBuf {
+    data_dir().join("clisten.db")
+}
+
 /// SQLite-backed store for queue persistence.
 /// Data is persisted at `~/.local/share/clisten/clisten.db`.
 pub struct Database {
@@ -16,34 +141,67 @@ pub struct Database {
 impl Database {
     /// Open (or create) the SQLite database.
     pub fn open() -> anyhow::Result<Self> {
-        let data_dir = dirs::data_dir()
-            .unwrap_or_else(|| PathBuf::from("."))
-            .join("clisten");
-        std::fs::create_dir_all(&data_dir)?;
-        let db_path = data_dir.join("clisten.db");
-        let conn = Connection::open(db_path)?;
-        let db = Self { conn };
-        db.run_migrations()?;
-        Ok(db)
+        std::fs::create_dir_all(data_dir())?;
+        Self::open_at(&db_path())
     }
 
-    #[allow(dead_code)] // used by integration tests
     pub fn open_at(path: &std::path::Path) -> anyhow::Result<Self> {
         if let Some(parent) = path.parent() {
             std::fs::create_dir_all(parent)?;
         }
         let conn = Connection::open(path)?;
+        // WAL lets readers and a writer work concurrently; the busy timeout
+        // makes lock contention wait instead of failing immediately.
+        conn.pragma_update(None, "journal_mode", "WAL")?;
+        conn.pragma_update(None, "synchronous", "NORMAL")?;
+        conn.busy_timeout(BUSY_TIMEOUT)?;
         let db = Self { conn };
         db.run_migrations()?;
         Ok(db)
     }
 
+    /// A database that lives only as long as the handle, e.g. for a replay.
+    pub fn open_in_memory() -> anyhow::Result<Self> {
+        let db = Self {
+            conn: Connection::open_in_memory()?,
+        };
+        db.run_migrations()?;
+        Ok(db)
+    }
+
     fn run_migrations(&self) -> anyhow::Result<()> {
-        let sql = include_str!("../migrations/001_init.sql");
-        self.conn.execute_batch(sql)?;
+        for sql in MIGRATIONS {
+            self.conn.execute_batch(sql)?;
+        }
         Ok(())
     }
 
+    /// Run `f` inside an immediate (write-locking) transaction, retrying a few
+    /// times if the database is still busy after the busy timeout.
+    fn write_transaction<T>(
+        &self,
+        mut f: impl FnMut(&Transaction) -> rusqlite::Result<T>,
+    ) -> anyhow::Result<T> {
+        let mut attempt = 0;
+        loop {
+            let result = Transaction::new_unchecked(&self.conn, TransactionBehavior::Immediate)
+                .and_then(|tx| {
+                    let value = f(&tx)?;
+                    tx.commit()?;
+                    Ok(value)
+                });
+            match result {
+                Err(rusqlite::Error::SqliteFailure(e, _))
+                    if e.code == ErrorCode::DatabaseBusy && attempt < BUSY_RETRIES =>
+                {
+                    attempt += 1;
+                    std::thread::sleep(BUSY_RETRY_DELAY);
+                }
+                other => return other.map_err(Into::into),
+            }
+        }
+    }
+
     // ── Queue persistence ──
 
     pub fn save_queue(
@@ -51,28 +209,29 @@ impl Database {
         items: &[QueueItem],
         current_index: Option<usize>,
     ) -> anyhow::Result<()> {
-        let tx = self.conn.unchecked_transaction()?;
-        tx.execute("DELETE FROM queue", [])?;
-        tx.execute("DELETE FROM queue_state", [])?;
+        let rows = items
+            .iter()
+            .map(|qi| Ok((serde_json::to_string(&qi.item)?, qi.url.as_str())))
+            .collect::<anyhow::Result<Vec<_>>>()?;
+
+        self.write_transaction(|tx| {
+            tx.execute("DELETE FROM queue", [])?;
+            tx.execute("DELETE FROM queue_state", [])?;
 
-        {
             let mut stmt =
                 tx.prepare("INSERT INTO queue (position, item_json, url) VALUES (?1, ?2, ?3)")?;
-            for (i, qi) in items.iter().enumerate() {
-                let json = serde_json::to_string(&qi.item)?;
-                stmt.execute(params![i as i64, json, qi.url])?;
+            for (i, (json, url)) in rows.iter().enumerate() {
+                stmt.execute(params![i as i64, json, url])?;
             }
-        }
 
-        if let Some(idx) = current_index {
-            tx.execute(
-                "INSERT INTO queue_state (key, value) VALUES ('current_index', ?1)",
-                params![idx.to_string()],
-            )?;
-        }
-
-        tx.commit()?;
-        Ok(())
+            if let Some(idx) = current_index {
+                tx.execute(
+                    "INSERT INTO queue_state (key, value) VALUES ('current_index', ?1)",
+                    params![idx.to_string()],
+                )?;
+            }
+            Ok(())
+        })
     }
 
     pub fn load_queue(&self) -> anyhow::Result<(Vec<QueueItem>, Option<usize>)> {
@@ -111,4 +270,878 @@ impl Database {
 
         Ok((items, current_index))
     }
+
+    // ── Favorites ──
+
+    /// Add an item to favorites. Returns false if it was already a favorite.
+    pub fn add_favorite(&self, item: &DiscoveryItem) -> anyhow::Result<bool> {
+        let (source, item_type) = match item {
+            DiscoveryItem::NtsLiveChannel { .. } => ("nts", "live"),
+            DiscoveryItem::NtsEpisode { .. } => ("nts", "episode"),
+            DiscoveryItem::DirectUrl { .. } => ("direct", "url"),
+            DiscoveryItem::PodcastEpisode { .. } => ("podcast", "episode"),
+            DiscoveryItem::NtsGenre { .. } => ("nts", "genre"),
+            DiscoveryItem::NtsMood { .. } => ("nts", "mood"),
+            DiscoveryItem::NtsPlace { .. } => ("nts", "place"),
+            DiscoveryItem::NtsGenreCategory { .. } => ("nts", "genre_category"),
+        };
+        let added = self.conn.execute(
+            "INSERT OR IGNORE INTO favorites (key, source, item_type, title, url, metadata_json)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+            params![
+                item.favorite_key(),
+                source,
+                item_type,
+                item.title(),
+                item.playback_url(),
+                serde_json::to_string(item)?,
+            ],
+        )?;
+        Ok(added > 0)
+    }
+
+    pub fn remove_favorite(&self, key: &str) -> anyhow::Result<()> {
+        self.write_transaction(|tx| {
+            tx.execute(
+                "DELETE FROM favorite_positions
+                 WHERE favorite_id IN (SELECT id FROM favorites WHERE key = ?1)",
+                params![key],
+            )?;
+            tx.execute("DELETE FROM favorites WHERE key = ?1", params![key])?;
+            Ok(())
+        })
+    }
+
+    pub fn delete_favorite(&self, id: i64) -> anyhow::Result<()> {
+        self.write_transaction(|tx| {
+            tx.execute(
+                "DELETE FROM favorite_positions WHERE favorite_id = ?1",
+                params![id],
+            )?;
+            tx.execute("DELETE FROM favorites WHERE id = ?1", params![id])?;
+            Ok(())
+        })
+    }
+
+    pub fn favorite_count(&self) -> anyhow::Result<usize> {
+        let count: i64 = self
+            .conn
+            .query_row("SELECT COUNT(*) FROM favorites", [], |row| row.get(0))?;
+        Ok(count as usize)
+    }
+
+    /// All favorites in display order: pinned first, then manually ordered
+    /// ones, then the rest newest first. Rows that no longer deserialize are
+    /// skipped.
+    pub fn favorites(&self) -> anyhow::Result<Vec<Favorite>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT f.id, f.metadata_json, f.source, f.item_type,
+                    MAX(0, CAST(strftime('%s', 'now') AS INTEGER)
+                           - CAST(strftime('%s', f.created_at) AS INTEGER)),
+                    COALESCE(p.pinned, 0)
+             FROM favorites f
+             LEFT JOIN favorite_positions p ON p.favorite_id = f.id
+             ORDER BY COALESCE(p.pinned, 0) DESC, p.position IS NULL DESC, p.position,
+                      f.created_at DESC, f.id DESC",
+        )?;
+        let rows = stmt.query_map([], |row| {
+            Ok((
+                row.get::<_, i64>(0)?,
+                row.get::<_, String>(1)?,
+                row.get::<_, String>(2)?,
+                row.get::<_, String>(3)?,
+                row.get::<_, i64>(4)?,
+                row.get::<_, bool>(5)?,
+            ))
+        })?;
+        let mut favorites = Vec::new();
+        for row in rows {
+            let (id, json, source, item_type, age, pinned) = row?;
+            if let Ok(item) = serde_json::from_str(&json) {
+                favorites.push(Favorite {
+                    id,
+                    item,
+                    source,
+                    item_type,
+                    age_secs: age as u64,
+                    pinned,
+                });
+            }
+        }
+        Ok(favorites)
+    }
+
+    /// Store a manual order: each id's position is its index in `ids`.
+    /// Pin state is kept.
+    pub fn set_favorite_positions(&self, ids: &[i64]) -> anyhow::Result<()> {
+        self.write_transaction(|tx| {
+            let mut stmt = tx.prepare(
+                "INSERT INTO favorite_positions (favorite_id, position) VALUES (?1, ?2)
+                 ON CONFLICT(favorite_id) DO UPDATE SET position = excluded.position",
+            )?;
+            for (i, id) in ids.iter().enumerate() {
+                stmt.execute(params![id, i as i64])?;
+            }
+            Ok(())
+        })
+    }
+
+    pub fn set_favorite_pinned(&self, id: i64, pinned: bool) -> anyhow::Result<()> {
+        self.conn.execute(
+            "INSERT INTO favorite_positions (favorite_id, position, pinned)
+             VALUES (?1, (SELECT COALESCE(MIN(position), 0) - 1 FROM favorite_positions), ?2)
+             ON CONFLICT(favorite_id) DO UPDATE SET pinned = excluded.pinned",
+            params![id, pinned],
+        )?;
+        Ok(())
+    }
+
+    pub fn is_favorite(&self, key: &str) -> anyhow::Result<bool> {
+        let count: i64 = self.conn.query_row(
+            "SELECT COUNT(*) FROM favorites WHERE key = ?1",
+            params![key],
+            |row| row.get(0),
+        )?;
+        Ok(count > 0)
+    }
+
+    /// Keys of all favorites, for marking them in lists.
+    pub fn favorite_keys(&self) -> anyhow::Result<HashSet<String>> {
+        let mut stmt = self.conn.prepare("SELECT key FROM favorites")?;
+        let keys = stmt
+            .query_map([], |row| row.get(0))?
+            .collect::<Result<HashSet<String>, _>>()?;
+        Ok(keys)
+    }
+
+    // ── Play history ──
+
+    /// Record the start of a play; returns the row id for `set_play_duration`.
+    pub fn record_play(&self, item: &DiscoveryItem) -> anyhow::Result<i64> {
+        self.conn.execute(
+            "INSERT INTO history (key, title, item_json) VALUES (?1, ?2, ?3)",
+            params![
+                item.favorite_key(),
+                item.title(),
+                serde_json::to_string(item)?
+            ],
+        )?;
+        Ok(self.conn.last_insert_rowid())
+    }
+
+    /// Number of distinct items in the history.
+    pub fn history_count(&self) -> anyhow::Result<usize> {
+        let count: i64 =
+            self.conn
+                .query_row("SELECT COUNT(DISTINCT key) FROM history", [], |row| {
+                    row.get(0)
+                })?;
+        Ok(count as usize)
+    }
+
+    /// Store how long a play lasted once it ends.
+    pub fn set_play_duration(&self, id: i64, secs: f64) -> anyhow::Result<()> {
+        self.conn.execute(
+            "UPDATE history SET duration_secs = ?2 WHERE id = ?1",
+            params![id, secs],
+        )?;
+        Ok(())
+    }
+
+    /// Played items, most recently played first, at most `limit` of them.
+    pub fn history(&self, limit: usize) -> anyhow::Result<Vec<HistoryEntry>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT h.key,
+                    (SELECT item_json FROM history WHERE key = h.key ORDER BY id DESC LIMIT 1),
+                    MAX(0, CAST(strftime('%s', 'now') AS INTEGER)
+                           - CAST(strftime('%s', MAX(h.played_at)) AS INTEGER)),
+                    COUNT(*),
+                    SUM(h.duration_secs)
+             FROM history h
+             GROUP BY h.key
+             ORDER BY MAX(h.played_at) DESC, MAX(h.id) DESC
+             LIMIT ?1",
+        )?;
+        let rows = stmt.query_map(params![limit as i64], |row| {
+            Ok((
+                row.get::<_, String>(0)?,
+                row.get::<_, String>(1)?,
+                row.get::<_, i64>(2)?,
+                row.get::<_, i64>(3)?,
+                row.get::<_, f64>(4)?,
+            ))
+        })?;
+        let mut entries = Vec::new();
+        for row in rows {
+            let (key, json, age, count, listened) = row?;
+            if let Ok(item) = serde_json::from_str(&json) {
+                entries.push(HistoryEntry {
+                    key,
+                    item,
+                    age_secs: age as u64,
+                    play_count: count as u32,
+                    listened_secs: listened,
+                });
+            }
+        }
+        Ok(entries)
+    }
+
+    /// Forget every play of one item.
+    pub fn delete_history(&self, key: &str) -> anyhow::Result<()> {
+        self.conn
+            .execute("DELETE FROM history WHERE key = ?1", params![key])?;
+        Ok(())
+    }
+
+    pub fn clear_history(&self) -> anyhow::Result<()> {
+        self.conn.execute("DELETE FROM history", [])?;
+        Ok(())
+    }
+
+    // ── Playlists ──
+
+    /// Create an empty playlist; names must be unique. Returns its id.
+    pub fn create_playlist(&self, name: &str) -> anyhow::Result<i64> {
+        let name = self.check_playlist_name(name)?;
+        self.conn
+            .execute("INSERT INTO playlists (name) VALUES (?1)", params![name])?;
+        Ok(self.conn.last_insert_rowid())
+    }
+
+    pub fn rename_playlist(&self, id: i64, name: &str) -> anyhow::Result<()> {
+        let name = self.check_playlist_name(name)?;
+        self.conn.execute(
+            "UPDATE playlists SET name = ?2 WHERE id = ?1",
+            params![id, name],
+        )?;
+        Ok(())
+    }
+
+    /// Trimmed `name`, or an error if it is empty or already taken.
+    fn check_playlist_name<'a>(&self, name: &'a str) -> anyhow::Result<&'a str> {
+        let name = name.trim();
+        if name.is_empty() {
+            anyhow::bail!("Playlist name can't be empty");
+        }
+        let taken: i64 = self.conn.query_row(
+            "SELECT COUNT(*) FROM playlists WHERE name = ?1",
+            params![name],
+            |row| row.get(0),
+        )?;
+        if taken > 0 {
+            anyhow::bail!("A playlist named \"{name}\" already exists");
+        }
+        Ok(name)
+    }
+
+    pub fn delete_playlist(&self, id: i64) -> anyhow::Result<()> {
+        self.write_transaction(|tx| {
+            tx.execute(
+                "DELETE FROM playlist_items WHERE playlist_id = ?1",
+                params![id],
+            )?;
+            tx.execute("DELETE FROM playlists WHERE id = ?1", params![id])?;
+            Ok(())
+        })
+    }
+
+    /// All playlists by name, with how many items each holds.
+    pub fn playlists(&self) -> anyhow::Result<Vec<PlaylistSummary>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT p.id, p.name, COUNT(i.id)
+             FROM playlists p
+             LEFT JOIN playlist_items i ON i.playlist_id = p.id
+             GROUP BY p.id
+             ORDER BY p.name COLLATE NOCASE, p.id",
+        )?;
+        let playlists = stmt
+            .query_map([], |row| {
+                Ok(PlaylistSummary {
+                    id: row.get(0)?,
+                    name: row.get(1)?,
+                    item_count: row.get::<_, i64>(2)? as usize,
+                })
+            })?
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(playlists)
+    }
+
+    /// Append items to a playlist, skipping genres, moods, and items it
+    /// already holds. Returns how many were added.
+    pub fn add_to_playlist(&self, id: i64, items: &[DiscoveryItem]) -> anyhow::Result<usize> {
+        let rows = items
+            .iter()
+            .filter(|item| !item.is_browse_entry())
+            .map(|item| Ok((item.favorite_key(), serde_json::to_string(item)?)))
+            .collect::<anyhow::Result<Vec<_>>>()?;
+        self.write_transaction(|tx| {
+            let mut stmt = tx.prepare(
+                "INSERT OR IGNORE INTO playlist_items (playlist_id, position, key, item_json)
+                 VALUES (?1, (SELECT COALESCE(MAX(position), -1) + 1
+                              FROM playlist_items WHERE playlist_id = ?1), ?2, ?3)",
+            )?;
+            let mut added = 0;
+            for (key, json) in &rows {
+                added += stmt.execute(params![id, key, json])?;
+            }
+            Ok(added)
+        })
+    }
+
+    /// A playlist's items in order. Rows that no longer deserialize are
+    /// skipped.
+    pub fn playlist_items(&self, id: i64) -> anyhow::Result<Vec<DiscoveryItem>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT item_json FROM playlist_items WHERE playlist_id = ?1 ORDER BY position, id",
+        )?;
+        let rows = stmt.query_map(params![id], |row| row.get::<_, String>(0))?;
+        let mut items = Vec::new();
+        for row in rows {
+            if let Ok(item) = serde_json::from_str(&row?) {
+                items.push(item);
+            }
+        }
+        Ok(items)
+    }
+
+    // ── Followed shows ──
+
+    /// Follow a show. Returns false if it was already followed.
+    pub fn follow_show(&self, show_alias: &str, title: &str) -> anyhow::Result<bool> {
+        let added = self.conn.execute(
+            "INSERT OR IGNORE INTO followed_shows (show_alias, title) VALUES (?1, ?2)",
+            params![show_alias, title],
+        )?;
+        Ok(added > 0)
+    }
+
+    pub fn unfollow_show(&self, show_alias: &str) -> anyhow::Result<()> {
+        self.conn.execute(
+            "DELETE FROM followed_shows WHERE show_alias = ?1",
+            params![show_alias],
+        )?;
+        Ok(())
+    }
+
+    /// All followed shows, oldest follow first.
+    pub fn followed_shows(&self) -> anyhow::Result<Vec<FollowedShow>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT show_alias, title, seen_until FROM followed_shows ORDER BY followed_at, rowid",
+        )?;
+        let shows = stmt
+            .query_map([], |row| {
+                Ok(FollowedShow {
+                    show_alias: row.get(0)?,
+                    title: row.get(1)?,
+                    seen_until: row.get(2)?,
+                })
+            })?
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(shows)
+    }
+
+    /// Mark a show's episodes up to `broadcast_date` as seen.
+    pub fn set_show_seen_until(
+        &self,
+        show_alias: &str,
+        broadcast_date: &str,
+    ) -> anyhow::Result<()> {
+        self.conn.execute(
+            "UPDATE followed_shows SET seen_until = ?2 WHERE show_alias = ?1",
+            params![show_alias, broadcast_date],
+        )?;
+        Ok(())
+    }
+
+    // ── Podcasts ──
+
+    /// Subscribe to a feed. Returns false if already subscribed.
+    pub fn add_podcast_feed(&self, url: &str) -> anyhow::Result<bool> {
+        let added = self.conn.execute(
+            "INSERT OR IGNORE INTO podcast_feeds (url) VALUES (?1)",
+            params![url],
+        )?;
+        Ok(added > 0)
+    }
+
+    pub fn remove_podcast_feed(&self, url: &str) -> anyhow::Result<()> {
+        self.conn
+            .execute("DELETE FROM podcast_feeds WHERE url = ?1", params![url])?;
+        Ok(())
+    }
+
+    pub fn set_podcast_title(&self, url: &str, title: &str) -> anyhow::Result<()> {
+        self.conn.execute(
+            "UPDATE podcast_feeds SET title = ?2 WHERE url = ?1",
+            params![url, title],
+        )?;
+        Ok(())
+    }
+
+    /// All subscribed feeds, oldest subscription first.
+    pub fn podcast_feeds(&self) -> anyhow::Result<Vec<PodcastSubscription>> {
+        let mut stmt = self
+            .conn
+            .prepare("SELECT url, title FROM podcast_feeds ORDER BY added_at, rowid")?;
+        let feeds = stmt
+            .query_map([], |row| {
+                Ok(PodcastSubscription {
+                    url: row.get(0)?,
+                    title: row.get(1)?,
+                })
+            })?
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(feeds)
+    }
+
+    /// Mark a podcast episode (by favorite key) as played or unplayed.
+    pub fn set_podcast_played(&self, key: &str, played: bool) -> anyhow::Result<()> {
+        if played {
+            self.conn.execute(
+                "INSERT OR IGNORE INTO podcast_played (key) VALUES (?1)",
+                params![key],
+            )?;
+        } else {
+            self.conn
+                .execute("DELETE FROM podcast_played WHERE key = ?1", params![key])?;
+        }
+        Ok(())
+    }
+
+    /// Keys of the podcast episodes marked as played.
+    pub fn podcast_played_keys(&self) -> anyhow::Result<HashSet<String>> {
+        let mut stmt = self.conn.prepare("SELECT key FROM podcast_played")?;
+        let keys = stmt
+            .query_map([], |row| row.get(0))?
+            .collect::<Result<HashSet<String>, _>>()?;
+        Ok(keys)
+    }
+
+    // ── Source preferences ──
+
+    /// The source URL that last played reliably for this item, if any.
+    pub fn preferred_source(&self, key: &str) -> anyhow::Result<Option<String>> {
+        let url = self
+            .conn
+            .query_row(
+                "SELECT url FROM source_preferences WHERE key = ?1",
+                params![key],
+                |row| row.get(0),
+            )
+            .optional()?;
+        Ok(url)
+    }
+
+    /// Remember a working source URL so future plays start with it.
+    pub fn set_preferred_source(&self, key: &str, url: &str) -> anyhow::Result<()> {
+        self.conn.execute(
+            "INSERT INTO source_preferences (key, url) VALUES (?1, ?2)
+             ON CONFLICT(key) DO UPDATE SET url = excluded.url, updated_at = datetime('now')",
+            params![key, url],
+        )?;
+        Ok(())
+    }
+
+    // ── Resume positions ──
+
+    /// Where playback of this item was last left off, if recorded.
+    pub fn resume_position(&self, key: &str) -> anyhow::Result<Option<f64>> {
+        let pos = self
+            .conn
+            .query_row(
+                "SELECT position_secs FROM resume_positions WHERE key = ?1",
+                params![key],
+                |row| row.get(0),
+            )
+            .optional()?;
+        Ok(pos)
+    }
+
+    pub fn set_resume_position(&self, key: &str, url: &str, secs: f64) -> anyhow::Result<()> {
+        self.conn.execute(
+            "INSERT INTO resume_positions (key, url, position_secs) VALUES (?1, ?2, ?3)
+             ON CONFLICT(key) DO UPDATE SET url = excluded.url,
+                 position_secs = excluded.position_secs, updated_at = datetime('now')",
+            params![key, url, secs],
+        )?;
+        Ok(())
+    }
+
+    pub fn clear_resume_position(&self, key: &str) -> anyhow::Result<()> {
+        self.conn
+            .execute("DELETE FROM resume_positions WHERE key = ?1", params![key])?;
+        Ok(())
+    }
+
+    /// Record a position imported from mpv's watch_later directory. Each entry
+    /// is imported once, and never overrides a position clisten already has.
+    /// Returns true if the position was stored.
+    pub fn import_resume_position(
+        &self,
+        entry: &str,
+        key: &str,
+        url: &str,
+        secs: f64,
+    ) -> anyhow::Result<bool> {
+        self.write_transaction(|tx| {
+            let fresh = tx.execute(
+                "INSERT OR IGNORE INTO watch_later_imports (entry) VALUES (?1)",
+                params![entry],
+            )? > 0;
+            Ok(fresh
+                && tx.execute(
+                    "INSERT OR IGNORE INTO resume_positions (key, url, position_secs)
+                     VALUES (?1, ?2, ?3)",
+                    params![key, url, secs],
+                )? > 0)
+        })
+    }
+
+    // ── Track lengths ──
+
+    /// Lengths of every item that has played, by favorite key.
+    pub fn track_lengths(&self) -> anyhow::Result<HashMap<String, f64>> {
+        let mut stmt = self
+            .conn
+            .prepare("SELECT key, duration_secs FROM track_lengths")?;
+        let rows = stmt.query_map([], |row| Ok((row.get(0)?, row.get(1)?)))?;
+        Ok(rows.collect::<Result<_, _>>()?)
+    }
+
+    pub fn set_track_length(&self, key: &str, secs: f64) -> anyhow::Result<()> {
+        self.conn.execute(
+            "INSERT INTO track_lengths (key, duration_secs) VALUES (?1, ?2)
+             ON CONFLICT(key) DO UPDATE SET duration_secs = excluded.duration_secs,
+                 updated_at = datetime('now')",
+            params![key, secs],
+        )?;
+        Ok(())
+    }
+
+    // ── Direct URL details ──
+
+    /// Fold what yt-dlp found for `url` into the copies of that direct URL
+    /// kept in history, favorites, and playlists.
+    pub fn apply_url_meta(
+        &self,
+        url: &str,
+        title: Option<&str>,
+        meta: &UrlMeta,
+    ) -> anyhow::Result<()> {
+        let key = DiscoveryItem::DirectUrl {
+            url: url.to_string(),
+            title: None,
+            meta: None,
+        }
+        .favorite_key();
+        // (table, column holding the item, whether the table has a title)
+        let tables = [
+            ("history", "item_json", true),
+            ("favorites", "metadata_json", true),
+            ("playlist_items", "item_json", false),
+        ];
+        let mut updates = Vec::new();
+        for (table, column, titled) in tables {
+            let mut stmt = self
+                .conn
+                .prepare(&format!("SELECT id, {column} FROM {table} WHERE key = ?1"))?;
+            let rows = stmt.query_map(params![key], |row| {
+                Ok((row.get::<_, i64>(0)?, row.get::<_, String>(1)?))
+            })?;
+            for row in rows {
+                let (id, json) = row?;
+                let Ok(mut item) = serde_json::from_str::<DiscoveryItem>(&json) else {
+                    continue;
+                };
+                if item.apply_url_meta(url, title, meta) {
+                    let title = titled.then(|| item.title().to_string());
+                    updates.push((table, column, id, serde_json::to_string(&item)?, title));
+                }
+            }
+        }
+        self.write_transaction(|tx| {
+            for (table, column, id, json, title) in &updates {
+                match title {
+                    Some(title) => tx.execute(
+                        &format!("UPDATE {table} SET {column} = ?1, title = ?2 WHERE id = ?3"),
+                        params![json, title, id],
+                    )?,
+                    None => tx.execute(
+                        &format!("UPDATE {table} SET {column} = ?1 WHERE id = ?2"),
+                        params![json, id],
+                    )?,
+                };
+            }
+            Ok(())
+        })
+    }
+
+    // ── Usage metrics ──
+
+    /// Add counter increments collected since the last flush.
+    pub fn add_metric_counts(&self, counts: &BTreeMap<String, u64>) -> anyhow::Result<()> {
+        self.write_transaction(|tx| {
+            let mut stmt = tx.prepare(
+                "INSERT INTO metrics (name, count) VALUES (?1, ?2)
+                 ON CONFLICT(name) DO UPDATE SET count = count + excluded.count,
+                     updated_at = datetime('now')",
+            )?;
+            for (name, n) in counts {
+                stmt.execute(params![name, *n as i64])?;
+            }
+            Ok(())
+        })
+    }
+
+    /// All counters, highest first.
+    pub fn metric_counts(&self) -> anyhow::Result<Vec<(String, u64)>> {
+        let mut stmt = self
+            .conn
+            .prepare("SELECT name, count FROM metrics ORDER BY count DESC, name")?;
+        let counts = stmt
+            .query_map([], |row| Ok((row.get(0)?, row.get::<_, i64>(1)? as u64)))?
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(counts)
+    }
+
+    pub fn clear_metrics(&self) -> anyhow::Result<()> {
+        self.conn.execute("DELETE FROM metrics", [])?;
+        Ok(())
+    }
+
+    // ── Snapshots ──
+
+    /// Keep `items` as the latest snapshot called `name`.
+    pub fn save_snapshot(&self, name: &str, items: &[DiscoveryItem]) -> anyhow::Result<()> {
+        self.conn.execute(
+            "INSERT INTO snapshots (name, items_json) VALUES (?1, ?2)
+             ON CONFLICT(name) DO UPDATE SET items_json = excluded.items_json,
+                                             saved_at = datetime('now')",
+            params![name, serde_json::to_string(items)?],
+        )?;
+        Ok(())
+    }
+
+    /// The snapshot called `name` and its age in seconds, if one was saved
+    /// and still deserializes.
+    pub fn snapshot(&self, name: &str) -> anyhow::Result<Option<(Vec<DiscoveryItem>, u64)>> {
+        let row = self
+            .conn
+            .query_row(
+                "SELECT items_json,
+                        MAX(0, CAST(strftime('%s', 'now') AS INTEGER)
+                               - CAST(strftime('%s', saved_at) AS INTEGER))
+                 FROM snapshots WHERE name = ?1",
+                params![name],
+                |row| Ok((row.get::<_, String>(0)?, row.get::<_, i64>(1)?)),
+            )
+            .optional()?;
+        Ok(row.and_then(|(json, age)| {
+            serde_json::from_str(&json)
+                .ok()
+                .map(|items| (items, age as u64))
+        }))
+    }
+
+    // ── App state ──
+
+    /// Store `value` (JSON) as the app state called `key`.
+    pub fn set_app_state(&self, key: &str, value: &str) -> anyhow::Result<()> {
+        self.conn.execute(
+            "INSERT INTO app_state (key, value) VALUES (?1, ?2)
+             ON CONFLICT(key) DO UPDATE SET value = excluded.value,
+                                            updated_at = datetime('now')",
+            params![key, value],
+        )?;
+        Ok(())
+    }
+
+    /// The app state called `key`, if any was stored.
+    pub fn app_state(&self, key: &str) -> anyhow::Result<Option<String>> {
+        Ok(self
+            .conn
+            .query_row(
+                "SELECT value FROM app_state WHERE key = ?1",
+                params![key],
+                |row| row.get(0),
+            )
+            .optional()?)
+    }
+
+    // ── Search history ──
+
+    /// Remember a search query, moving it to the front if it was used before.
+    pub fn record_search(&self, query: &str) -> anyhow::Result<()> {
+        // A fresh row, so the newest rowid breaks ties within a second.
+        self.write_transaction(|tx| {
+            tx.execute(
+                "DELETE FROM search_history WHERE query = ?1",
+                params![query],
+            )?;
+            tx.execute(
+                "INSERT INTO search_history (query) VALUES (?1)",
+                params![query],
+            )?;
+            Ok(())
+        })
+    }
+
+    /// Up to `limit` past queries, most recent first.
+    pub fn recent_searches(&self, limit: usize) -> anyhow::Result<Vec<String>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT query FROM search_history ORDER BY searched_at DESC, rowid DESC LIMIT ?1",
+        )?;
+        let queries = stmt
+            .query_map(params![limit as i64], |row| row.get(0))?
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(queries)
+    }
+
+    pub fn delete_search(&self, query: &str) -> anyhow::Result<()> {
+        self.conn.execute(
+            "DELETE FROM search_history WHERE query = ?1",
+            params![query],
+        )?;
+        Ok(())
+    }
+
+    // ── Bookmarks ──
+
+    /// Mark `position_secs` in the episode with favorite_key `key`; returns
+    /// the new bookmark's id.
+    pub fn add_bookmark(&self, key: &str, position_secs: f64, label: &str) -> anyhow::Result<i64> {
+        self.conn.execute(
+            "INSERT INTO bookmarks (key, position_secs, label) VALUES (?1, ?2, ?3)",
+            params![key, position_secs, label],
+        )?;
+        Ok(self.conn.last_insert_rowid())
+    }
+
+    /// The episode's bookmarks, earliest position first.
+    pub fn bookmarks(&self, key: &str) -> anyhow::Result<Vec<Bookmark>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT id, position_secs, label FROM bookmarks WHERE key = ?1
+             ORDER BY position_secs, id",
+        )?;
+        let bookmarks = stmt
+            .query_map(params![key], |row| {
+                Ok(Bookmark {
+                    id: row.get(0)?,
+                    position_secs: row.get(1)?,
+                    label: row.get(2)?,
+                })
+            })?
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(bookmarks)
+    }
+
+    pub fn delete_bookmark(&self, id: i64) -> anyhow::Result<()> {
+        self.conn
+            .execute("DELETE FROM bookmarks WHERE id = ?1", params![id])?;
+        Ok(())
+    }
+
+    // ── Export / import ──
+
+    /// Every favorite, in display order, for an export file.
+    pub fn export_favorites(&self) -> anyhow::Result<Vec<ExportedFavorite>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT f.metadata_json, f.created_at, COALESCE(p.pinned, 0)
+             FROM favorites f
+             LEFT JOIN favorite_positions p ON p.favorite_id = f.id
+             ORDER BY COALESCE(p.pinned, 0) DESC, p.position IS NULL DESC, p.position,
+                      f.created_at DESC, f.id DESC",
+        )?;
+        let rows = stmt.query_map([], |row| {
+            Ok((
+                row.get::<_, String>(0)?,
+                row.get::<_, String>(1)?,
+                row.get::<_, bool>(2)?,
+            ))
+        })?;
+        let mut favorites = Vec::new();
+        for row in rows {
+            let (json, created_at, pinned) = row?;
+            if let Ok(item) = serde_json::from_str(&json) {
+                favorites.push(ExportedFavorite {
+                    item,
+                    created_at,
+                    pinned,
+                });
+            }
+        }
+        Ok(favorites)
+    }
+
+    /// Add an exported favorite unless one with the same key exists.
+    /// Returns whether it was added.
+    pub fn import_favorite(&self, favorite: &ExportedFavorite) -> anyhow::Result<bool> {
+        if !self.add_favorite(&favorite.item)? {
+            return Ok(false);
+        }
+        let id = self.conn.last_insert_rowid();
+        self.conn.execute(
+            "UPDATE favorites SET created_at = ?2 WHERE id = ?1",
+            params![id, favorite.created_at],
+        )?;
+        if favorite.pinned {
+            self.set_favorite_pinned(id, true)?;
+        }
+        Ok(true)
+    }
+
+    /// Every play, oldest first, for an export file.
+    pub fn export_history(&self) -> anyhow::Result<Vec<ExportedPlay>> {
+        let mut stmt = self
+            .conn
+            .prepare("SELECT item_json, played_at, duration_secs FROM history ORDER BY id")?;
+        let rows = stmt.query_map([], |row| {
+            Ok((
+                row.get::<_, String>(0)?,
+                row.get::<_, String>(1)?,
+                row.get::<_, f64>(2)?,
+            ))
+        })?;
+        let mut plays = Vec::new();
+        for row in rows {
+            let (json, played_at, duration_secs) = row?;
+            if let Ok(item) = serde_json::from_str(&json) {
+                plays.push(ExportedPlay {
+                    item,
+                    played_at,
+                    duration_secs,
+                });
+            }
+        }
+        Ok(plays)
+    }
+
+    /// Add an exported play unless the same item already has a play starting
+    /// at the same time. Returns whether it was added.
+    pub fn import_play(&self, play: &ExportedPlay) -> anyhow::Result<bool> {
+        let added = self.conn.execute(
+            "INSERT INTO history (key, title, item_json, played_at, duration_secs)
+             SELECT ?1, ?2, ?3, ?4, ?5
+             WHERE NOT EXISTS (SELECT 1 FROM history WHERE key = ?1 AND played_at = ?4)",
+            params![
+                play.item.favorite_key(),
+                play.item.title(),
+                serde_json::to_string(&play.item)?,
+                play.played_at,
+                play.duration_secs,
+            ],
+        )?;
+        Ok(added > 0)
+    }
+
+    // ── Diagnostics ──
+
+    /// Run `PRAGMA integrity_check`; returns "ok" for a healthy database.
+    pub fn integrity_check(&self) -> anyhow::Result<String> {
+        let mut stmt = self.conn.prepare("PRAGMA integrity_check")?;
+        let rows = stmt.query_map([], |row| row.get::<_, String>(0))?;
+        let problems = rows.collect::<Result<Vec<_>, _>>()?;
+        Ok(problems.join("; "))
+    }
 }
@@ -0,0 +1,317 @@
+// `clisten doctor`: non-interactive health checks for runtime dependencies,
+// mpv IPC, the NTS API, the database, and the config file. `--playback`
+// summarizes the last session's playback health log instead.
+
+use std::path::Path;
+use std::time::Duration;
+
+use crate::api::nts::NtsClient;
+use crate::config::Config;
+use crate::db::Database;
+use crate::health::{self, HealthSummary};
+use crate::player::{instance, ipc, preflight};
+
+// How long to wait on the NTS API before calling it unreachable.
+const API_TIMEOUT_SECS: u64 = 10;
+const DB_RESET_FIX: &str =
+    "Reset it with: rm ~/.local/share/clisten/clisten.db (clears your queue)";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    Pass,
+    /// Degraded but usable (e.g. yt-dlp missing).
+    Warn,
+    Fail,
+}
+
+/// Result of a single diagnostic check.
+#[derive(Debug, Clone)]
+pub struct Check {
+    pub name: &'static str,
+    pub status: Status,
+    pub detail: String,
+    /// Suggested fix, shown for warnings and failures.
+    pub fix: Option<String>,
+}
+
+impl Check {
+    fn pass(name: &'static str, detail: impl Into<String>) -> Self {
+        Self {
+            name,
+            status: Status::Pass,
+            detail: detail.into(),
+            fix: None,
+        }
+    }
+
+    fn warn(name: &'static str, detail: impl Into<String>, fix: impl Into<String>) -> Self {
+        Self {
+            name,
+            status: Status::Warn,
+            detail: detail.into(),
+            fix: Some(fix.into()),
+        }
+    }
+
+    fn fail(name: &'static str, detail: impl Into<String>, fix: impl Into<String>) -> Self {
+        Self {
+            name,
+            status: Status::Fail,
+            detail: detail.into(),
+            fix: Some(fix.into()),
+        }
+    }
+}
+
+/// Run every check, print the report, and return true if nothing failed.
+pub async fn run() -> bool {
+    let mut config = Config::load().unwrap_or_default();
+    config.sanitize();
+    let mpv = config.player.mpv_binary();
+    let mpv_fix = format!(
+        "{}, or set mpv_path under [player] in the config",
+        install_fix("mpv")
+    );
+    let checks = vec![
+        check_binary("mpv", mpv, Status::Fail, &mpv_fix).await,
+        check_binary("yt-dlp", "yt-dlp", Status::Warn, &install_fix("yt-dlp")).await,
+        check_ipc(mpv).await,
+        check_api(&config.nts_client()).await,
+        check_database(),
+        check_config_at(&Config::config_path()),
+    ];
+    print_report(&checks);
+    checks.iter().all(|c| c.status != Status::Fail)
+}
+
+fn print_report(checks: &[Check]) {
+    println!("clisten doctor\n");
+    for check in checks {
+        let mark = match check.status {
+            Status::Pass => "✓",
+            Status::Warn => "!",
+            Status::Fail => "✗",
+        };
+        println!("  {} {:<10} {}", mark, check.name, check.detail);
+        if let Some(ref fix) = check.fix {
+            println!("    {:<10} → {}", "", fix);
+        }
+    }
+    let failed = checks.iter().filter(|c| c.status == Status::Fail).count();
+    let warned = checks.iter().filter(|c| c.status == Status::Warn).count();
+    println!();
+    match (failed, warned) {
+        (0, 0) => println!("All checks passed."),
+        (0, w) => println!("All checks passed with {} warning(s).", w),
+        (f, _) => println!("{} check(s) failed.", f),
+    }
+}
+
+/// Summarize the playback health log; false if there is none to read.
+pub fn run_playback() -> bool {
+    let path = health::log_path();
+    println!("clisten doctor --playback\n");
+    match health::read(&path) {
+        Ok(samples) if !samples.is_empty() => {
+            print!("{}", playback_report(&health::summarize(&samples)));
+            true
+        }
+        _ => {
+            println!("  No playback health log at {}", path.display());
+            println!("  → Set `debug_playback = true` under [general] in your config, play until");
+            println!("    the dropout happens, then run this again.");
+            false
+        }
+    }
+}
+
+/// The `--playback` report body for a summarized health log.
+pub fn playback_report(summary: &HealthSummary) -> String {
+    let seconds = |secs: f64| format!("{secs:.1}s");
+    let mut out = format!(
+        "  {:<12} {} ({} samples, {} tracks)\n",
+        "Session",
+        health::format_span(summary.span_secs),
+        summary.samples,
+        summary.tracks
+    );
+    let cache = match (summary.avg_cache_secs, summary.min_cache_secs) {
+        (Some(avg), Some(min)) => format!("avg {} ahead, min {}", seconds(avg), seconds(min)),
+        _ => "not reported".to_string(),
+    };
+    out.push_str(&format!("  {:<12} {}\n", "Cache", cache));
+    out.push_str(&format!("  {:<12} {}\n", "Reconnects", summary.reconnects));
+    out.push_str(&format!("  {:<12} {}\n", "Stalls", summary.stalls.len()));
+    for stall in &summary.stalls {
+        let cache = stall
+            .cache_secs
+            .map(|c| format!(", cache {}", seconds(c)))
+            .unwrap_or_default();
+        out.push_str(&format!(
+            "    at {:>10}  stuck {}{}  {}\n",
+            health::format_span(stall.elapsed_secs),
+            health::format_span(stall.duration_secs),
+            cache,
+            stall.title.as_deref().unwrap_or("(untitled)")
+        ));
+    }
+    out.push('\n');
+    out.push_str(match (summary.stalls.is_empty(), summary.reconnects) {
+        (true, 0) => "No dropouts recorded.\n",
+        _ if summary.min_cache_secs.is_some_and(|min| min < 1.0) => {
+            "The cache ran dry: try a larger buffer profile (B) or check your connection.\n"
+        }
+        _ => "Dropouts recorded with cache to spare: the stream itself may be unstable.\n",
+    });
+    out
+}
+
+/// Fix line for a missing `tool`.
+fn install_fix(tool: &str) -> String {
+    format!("Install with: {}", preflight::install_hint(tool))
+}
+
+/// Check that `binary` (a name on PATH or a path) exists and report its
+/// version line under `name`.
+async fn check_binary(name: &'static str, binary: &str, missing: Status, fix: &str) -> Check {
+    if which::which(binary).is_err() {
+        return Check {
+            name,
+            status: missing,
+            detail: if binary.contains('/') {
+                format!("{binary} not found")
+            } else {
+                "not found on PATH".to_string()
+            },
+            fix: Some(fix.to_string()),
+        };
+    }
+    let version = tokio::process::Command::new(binary)
+        .arg("--version")
+        .output()
+        .await
+        .ok()
+        .and_then(|out| {
+            String::from_utf8_lossy(&out.stdout)
+                .lines()
+                .next()
+                .map(|l| l.trim().to_string())
+        })
+        .filter(|l| !l.is_empty());
+    match version {
+        Some(v) => Check::pass(name, v),
+        None => Check::warn(
+            name,
+            "found, but `--version` gave no output",
+            format!("Try running `{} --version` manually", binary),
+        ),
+    }
+}
+
+/// Spawn an idle mpv (`binary`) with an IPC socket and query it once.
+async fn check_ipc(binary: &str) -> Check {
+    const NAME: &str = "mpv IPC";
+    if which::which(binary).is_err() {
+        return Check::fail(NAME, "skipped (mpv not found)", "Install mpv first");
+    }
+    // Distinct prefix so a running clisten doesn't treat it as an orphan.
+    let dir = instance::runtime_dir();
+    let _ = instance::create_private_dir(&dir);
+    let socket = dir.join(format!("doctor-{}.sock", std::process::id()));
+    let child = tokio::process::Command::new(binary)
+        .args(["--idle=yes", "--no-video", "--no-terminal"])
+        .arg(format!("--input-ipc-server={}", socket.display()))
+        .stdout(std::process::Stdio::null())
+        .stderr(std::process::Stdio::null())
+        .kill_on_drop(true)
+        .spawn();
+    let mut child = match child {
+        Ok(c) => c,
+        Err(e) => return Check::fail(NAME, format!("failed to spawn mpv: {}", e), "Reinstall mpv"),
+    };
+
+    ipc::wait_for_socket(&socket).await;
+    let result = ipc::send_command(&socket, r#"{"command":["get_property","mpv-version"]}"#).await;
+    let _ = ipc::send_command(&socket, r#"{"command":["quit"]}"#).await;
+    let _ = child.kill().await;
+    let _ = std::fs::remove_file(&socket);
+
+    match result {
+        Ok(_) => Check::pass(NAME, format!("socket OK ({})", socket.display())),
+        Err(e) => Check::fail(
+            NAME,
+            e.to_string(),
+            format!(
+                "Make sure {} is writable and mpv supports --input-ipc-server",
+                dir.display()
+            ),
+        ),
+    }
+}
+
+/// Fetch the live endpoint as a reachability probe.
+async fn check_api(client: &NtsClient) -> Check {
+    const NAME: &str = "NTS API";
+    match tokio::time::timeout(Duration::from_secs(API_TIMEOUT_SECS), client.fetch_live()).await {
+        Ok(Ok(items)) => Check::pass(
+            NAME,
+            format!("reachable ({} live channels)", items.value.len()),
+        ),
+        Ok(Err(e)) => Check::fail(
+            NAME,
+            e.to_string(),
+            "Check your internet connection or try again later",
+        ),
+        Err(_) => Check::fail(
+            NAME,
+            format!("no response within {}s", API_TIMEOUT_SECS),
+            "Check your internet connection or proxy settings",
+        ),
+    }
+}
+
+fn check_database() -> Check {
+    match Database::open() {
+        Ok(db) => check_database_with(&db),
+        Err(e) => Check::fail("database", format!("failed to open: {}", e), DB_RESET_FIX),
+    }
+}
+
+/// Run SQLite's integrity check on an open database.
+pub fn check_database_with(db: &Database) -> Check {
+    const NAME: &str = "database";
+    match db.integrity_check() {
+        Ok(result) if result == "ok" => Check::pass(NAME, "integrity check passed"),
+        Ok(result) => Check::fail(NAME, result, DB_RESET_FIX),
+        Err(e) => Check::fail(NAME, e.to_string(), DB_RESET_FIX),
+    }
+}
+
+/// Validate the config file at `path`. A missing file is fine (defaults apply).
+pub fn check_config_at(path: &Path) -> Check {
+    const NAME: &str = "config";
+    if !path.exists() {
+        return Check::pass(NAME, "no config file, using defaults");
+    }
+    let content = match std::fs::read_to_string(path) {
+        Ok(c) => c,
+        Err(e) => {
+            return Check::fail(
+                NAME,
+                format!("cannot read {}: {}", path.display(), e),
+                "Check the file's permissions",
+            )
+        }
+    };
+    match toml::from_str::<Config>(&content) {
+        Ok(mut config) => match config.sanitize().as_slice() {
+            [] => Check::pass(NAME, format!("{} is valid", path.display())),
+            problems => Check::warn(NAME, problems.join("; "), format!("Fix {}", path.display())),
+        },
+        Err(e) => Check::fail(
+            NAME,
+            format!("invalid: {}", e.message()),
+            format!("Fix or remove {}", path.display()),
+        ),
+    }
+}
@@ -0,0 +1,196 @@
+// Headless playback: `clisten play <url|favorite>` and `clisten live <1|2>`
+// play through the same mpv player, config, and database as the TUI, printing
+// short status lines instead of drawing, until the track ends or Ctrl-C.
+
+use std::time::{Duration, Instant};
+
+use tokio::sync::mpsc;
+
+use crate::action::Action;
+use crate::api::models::{fold_for_search, DiscoveryItem};
+use crate::config::Config;
+use crate::db::Database;
+use crate::hooks::{self, HookEvent, HookTrack};
+use crate::player::MpvPlayer;
+
+// How long `live` waits for the schedule before playing without a show name.
+const LIVE_INFO_TIMEOUT_SECS: u64 = 5;
+const PLAY_USAGE: &str = "usage: clisten play <url|favorite>";
+const LIVE_USAGE: &str = "usage: clisten live <1|2>";
+
+/// `clisten play` and `clisten live`. Returns whether playback ended
+/// normally (finished or interrupted) rather than failing.
+pub async fn run_cli(args: &[String], config: &Config) -> bool {
+    let result = match args.first().map(String::as_str) {
+        Some("play") => play_cli(&args[1..], config).await,
+        Some("live") => live_cli(&args[1..], config).await,
+        _ => Err(anyhow::anyhow!("expected `play` or `live`")),
+    };
+    match result {
+        Ok(()) => true,
+        Err(e) => {
+            eprintln!("Error: {e}");
+            false
+        }
+    }
+}
+
+async fn play_cli(args: &[String], config: &Config) -> anyhow::Result<()> {
+    let [target] = args else {
+        anyhow::bail!(PLAY_USAGE);
+    };
+    let db = Database::open()?;
+    let item = resolve_target(&db, target)?;
+    play(config, &db, item).await
+}
+
+async fn live_cli(args: &[String], config: &Config) -> anyhow::Result<()> {
+    let channel = match args {
+        [channel] => channel.parse::<u8>().ok().filter(|c| matches!(c, 1 | 2)),
+        _ => None,
+    };
+    let Some(channel) = channel else {
+        anyhow::bail!(LIVE_USAGE);
+    };
+    let db = Database::open()?;
+    let item = live_item(config, channel).await;
+    play(config, &db, item).await
+}
+
+/// A URL becomes a direct item; anything else names a favorite, by key
+/// (as in `clisten export`) or by a part of its title, ignoring case and accents.
+pub fn resolve_target(db: &Database, target: &str) -> anyhow::Result<DiscoveryItem> {
+    if target.contains("://") {
+        return Ok(DiscoveryItem::DirectUrl {
+            url: target.to_string(),
+            title: None,
+            meta: None,
+        });
+    }
+    let favorites = db.favorites()?;
+    if let Some(favorite) = favorites.iter().find(|f| f.item.favorite_key() == target) {
+        return Ok(favorite.item.clone());
+    }
+    let needle = fold_for_search(target);
+    let matches: Vec<&DiscoveryItem> = favorites
+        .iter()
+        .map(|f| &f.item)
+        .filter(|item| fold_for_search(item.title()).contains(&needle))
+        .collect();
+    match matches.as_slice() {
+        [item] => Ok((*item).clone()),
+        [] => anyhow::bail!("no favorite matches `{target}`"),
+        several => {
+            let names: Vec<String> = several
+                .iter()
+                .map(|item| format!("  {}  ({})", item.title(), item.favorite_key()))
+                .collect();
+            anyhow::bail!(
+                "`{target}` matches {} favorites:\n{}",
+                several.len(),
+                names.join("\n")
+            )
+        }
+    }
+}
+
+/// The live channel with its current show, if the schedule answers in time.
+async fn live_item(config: &Config, channel: u8) -> DiscoveryItem {
+    let client = config.nts_client();
+    let timeout = Duration::from_secs(LIVE_INFO_TIMEOUT_SECS);
+    if let Ok(Ok(items)) = tokio::time::timeout(timeout, client.fetch_live()).await {
+        let live = items.value.into_iter().find(
+            |item| matches!(item, DiscoveryItem::NtsLiveChannel { channel: c, .. } if *c == channel),
+        );
+        if let Some(item) = live {
+            return item;
+        }
+    }
+    DiscoveryItem::NtsLiveChannel {
+        channel,
+        show_name: "Live".to_string(),
+        genres: Vec::new(),
+    }
+}
+
+/// Play `item` until it ends, moving to its next source when one fails.
+/// Live streams that end are treated as dropouts and reconnected.
+async fn play(config: &Config, db: &Database, item: DiscoveryItem) -> anyhow::Result<()> {
+    let candidates = match &item {
+        DiscoveryItem::NtsLiveChannel { channel, .. } => config.nts.relays(*channel),
+        _ => item.source_candidates(),
+    };
+    let mut sources = candidates.into_iter();
+    let Some(url) = sources.next() else {
+        anyhow::bail!("{} has nothing to play", item.title());
+    };
+    let is_live = matches!(item, DiscoveryItem::NtsLiveChannel { .. });
+
+    let (tx, mut rx) = mpsc::unbounded_channel();
+    let mut player = MpvPlayer::new();
+    player.set_action_tx(tx);
+    player.set_buffer(config.player.buffer_settings());
+    player.set_equalizer(config.player.equalizer_filter());
+    player.set_extra_args(config.extra_mpv_args());
+    player.set_binary(config.player.mpv_binary());
+
+    println!("▶ {}", item.display_title());
+    let play_id = db.record_play(&item).ok();
+    let started = Instant::now();
+    player.play(&url).await?;
+    let track = HookTrack::new(&item, &url, None);
+    hooks::run(&config.hooks, HookEvent::TrackStarted, &track);
+
+    let mut interrupt = std::pin::pin!(tokio::signal::ctrl_c());
+    let mut failure = None;
+    let mut now_playing: Option<String> = None;
+    let result = loop {
+        let action = tokio::select! {
+            _ = &mut interrupt => {
+                println!("■ Stopped");
+                break Ok(());
+            }
+            action = rx.recv() => action,
+        };
+        match action {
+            Some(Action::StreamMetadataChanged(metadata)) => {
+                let title = metadata.display_title();
+                if title.is_some() && title != now_playing {
+                    println!("  ♪ {}", title.as_deref().unwrap_or_default());
+                    now_playing = title;
+                    let track = HookTrack::new(&item, &track.url, Some(&metadata));
+                    hooks::run(&config.hooks, HookEvent::MetadataChanged, &track);
+                }
+            }
+            Some(Action::PlaybackFailed(f)) => failure = Some(f),
+            Some(Action::PlaybackFinished) => {
+                let problem = match failure.take() {
+                    Some(f) => f.message(),
+                    None if is_live => "Stream ended".to_string(),
+                    None => {
+                        println!("■ Finished");
+                        break Ok(());
+                    }
+                };
+                match sources.next() {
+                    Some(url) => {
+                        eprintln!("  ! {problem} — trying an alternate source");
+                        if let Err(e) = player.play(&url).await {
+                            break Err(e);
+                        }
+                    }
+                    None => break Err(anyhow::anyhow!("{problem} — no other sources left")),
+                }
+            }
+            None => break Ok(()),
+            Some(_) => {}
+        }
+    };
+
+    player.quit().await;
+    hooks::run(&config.hooks, HookEvent::TrackFinished, &track);
+    if let Some(id) = play_id {
+        let _ = db.set_play_duration(id, started.elapsed().as_secs_f64());
+    }
+    result
+}
@@ -0,0 +1,212 @@
+// Playback health log for diagnosing dropouts. With `debug_playback` on, a
+// sample (position advance, cache ahead, reconnects) is appended every few
+// seconds to playback-health.log; `clisten doctor --playback` summarizes it.
+
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::{Path, PathBuf};
+use std::time::Instant;
+
+use serde::{Deserialize, Serialize};
+
+use crate::db;
+
+const HEALTH_LOG_FILE: &str = "playback-health.log";
+/// How often a sample is written while something plays.
+pub const SAMPLE_INTERVAL_SECS: f64 = 5.0;
+// A sample counts as stalled when the position advanced less than this
+// fraction of the wall-clock time since the previous one.
+const STALL_RATIO: f64 = 0.5;
+
+/// The log written during the most recent session with `debug_playback` on.
+pub fn log_path() -> PathBuf {
+    db::data_dir().join(HEALTH_LOG_FILE)
+}
+
+/// One line of the health log.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HealthSample {
+    /// Seconds since the session started.
+    pub elapsed_secs: f64,
+    pub title: Option<String>,
+    pub position_secs: f64,
+    /// Position advance since the previous sample of the same track; None
+    /// for a track's first sample.
+    pub position_delta: Option<f64>,
+    /// Seconds of audio buffered ahead of the position, when mpv reports it.
+    pub cache_secs: Option<f64>,
+    pub paused: bool,
+    /// Restarts after a dropout so far this session.
+    pub reconnects: u32,
+}
+
+/// Writes samples for the running session; every method is a no-op when
+/// disabled.
+pub struct HealthLog {
+    writer: Option<BufWriter<File>>,
+    started: Instant,
+    /// Position at the previous sample of the current track.
+    last_position: Option<f64>,
+    buffered_until: Option<f64>,
+    reconnects: u32,
+}
+
+impl HealthLog {
+    pub fn disabled() -> Self {
+        Self {
+            writer: None,
+            started: Instant::now(),
+            last_position: None,
+            buffered_until: None,
+            reconnects: 0,
+        }
+    }
+
+    /// Start a fresh log at `path`, replacing the previous session's. Falls
+    /// back to disabled if the file can't be created.
+    pub fn create(path: &Path) -> Self {
+        let mut log = Self::disabled();
+        if let Some(dir) = path.parent() {
+            let _ = std::fs::create_dir_all(dir);
+        }
+        log.writer = File::create(path).ok().map(BufWriter::new);
+        log
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.writer.is_some()
+    }
+
+    /// A new track started: the next sample has no delta to compare against.
+    pub fn track_started(&mut self) {
+        self.last_position = None;
+        self.buffered_until = None;
+    }
+
+    /// mpv's demuxer cache now reaches `secs` into the track.
+    pub fn set_buffered(&mut self, secs: f64) {
+        self.buffered_until = Some(secs);
+    }
+
+    pub fn record_reconnect(&mut self) {
+        self.reconnects += 1;
+    }
+
+    /// Write a sample for the current playback state.
+    pub fn sample(&mut self, title: Option<String>, position_secs: f64, paused: bool) {
+        let Some(writer) = self.writer.as_mut() else {
+            return;
+        };
+        let elapsed_secs = self.started.elapsed().as_secs_f64();
+        let sample = HealthSample {
+            elapsed_secs,
+            title,
+            position_secs,
+            position_delta: self.last_position.map(|pos| position_secs - pos),
+            cache_secs: self
+                .buffered_until
+                .map(|until| (until - position_secs).max(0.0)),
+            paused,
+            reconnects: self.reconnects,
+        };
+        self.last_position = Some(position_secs);
+        if let Ok(line) = serde_json::to_string(&sample) {
+            let _ = writeln!(writer, "{line}");
+            let _ = writer.flush();
+        }
+    }
+}
+
+/// Read a health log, skipping lines that don't parse (e.g. a line cut off
+/// by a crash).
+pub fn read(path: &Path) -> std::io::Result<Vec<HealthSample>> {
+    Ok(std::fs::read_to_string(path)?
+        .lines()
+        .filter_map(|line| serde_json::from_str(line).ok())
+        .collect())
+}
+
+/// A run of consecutive stalled samples.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Stall {
+    pub elapsed_secs: f64,
+    pub title: Option<String>,
+    pub duration_secs: f64,
+    /// Lowest cache level seen during the stall.
+    pub cache_secs: Option<f64>,
+}
+
+/// What a health log says about a session.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct HealthSummary {
+    pub samples: usize,
+    /// Seconds between the first and last sample.
+    pub span_secs: f64,
+    pub tracks: usize,
+    pub stalls: Vec<Stall>,
+    pub avg_cache_secs: Option<f64>,
+    pub min_cache_secs: Option<f64>,
+    pub reconnects: u32,
+}
+
+/// Summarize samples in log order. Pauses, track changes, and backward
+/// seeks never count as stalls.
+pub fn summarize(samples: &[HealthSample]) -> HealthSummary {
+    let mut summary = HealthSummary {
+        samples: samples.len(),
+        span_secs: match (samples.first(), samples.last()) {
+            (Some(first), Some(last)) => last.elapsed_secs - first.elapsed_secs,
+            _ => 0.0,
+        },
+        reconnects: samples.iter().map(|s| s.reconnects).max().unwrap_or(0),
+        ..Default::default()
+    };
+
+    let caches: Vec<f64> = samples.iter().filter_map(|s| s.cache_secs).collect();
+    if !caches.is_empty() {
+        summary.avg_cache_secs = Some(caches.iter().sum::<f64>() / caches.len() as f64);
+        summary.min_cache_secs = caches.iter().copied().reduce(f64::min);
+    }
+
+    let mut open: Option<Stall> = None;
+    let mut prev: Option<&HealthSample> = None;
+    for sample in samples {
+        if prev.is_none_or(|p| p.title != sample.title) {
+            summary.tracks += 1;
+        }
+        let wall = prev.map_or(0.0, |p| sample.elapsed_secs - p.elapsed_secs);
+        let stalled = !sample.paused
+            && prev.is_some_and(|p| !p.paused)
+            && sample
+                .position_delta
+                .is_some_and(|delta| (0.0..wall * STALL_RATIO).contains(&delta));
+        if stalled {
+            let stall = open.get_or_insert_with(|| Stall {
+                elapsed_secs: prev.map_or(sample.elapsed_secs, |p| p.elapsed_secs),
+                title: sample.title.clone(),
+                duration_secs: 0.0,
+                cache_secs: None,
+            });
+            stall.duration_secs += wall;
+            if let Some(cache) = sample.cache_secs {
+                stall.cache_secs = Some(stall.cache_secs.map_or(cache, |c| c.min(cache)));
+            }
+        } else if let Some(stall) = open.take() {
+            summary.stalls.push(stall);
+        }
+        prev = Some(sample);
+    }
+    summary.stalls.extend(open);
+    summary
+}
+
+/// `1h 02m 05s`, `2m 05s`, or `5s`.
+pub fn format_span(secs: f64) -> String {
+    let total = secs.max(0.0).round() as u64;
+    let (h, m, s) = (total / 3600, total / 60 % 60, total % 60);
+    match (h, m) {
+        (0, 0) => format!("{s}s"),
+        (0, m) => format!("{m}m {s:02}s"),
+        (h, m) => format!("{h}h {m:02}m {s:02}s"),
+    }
+}
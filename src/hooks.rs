@@ -0,0 +1,92 @@
+// User hooks: shell commands from the `[hooks]` config run on playback events,
+// with the track in CLISTEN_* environment variables. They run in the
+// background and their output is discarded.
+
+use std::process::Stdio;
+
+use tokio::process::Command;
+
+use crate::api::models::DiscoveryItem;
+use crate::config::HooksConfig;
+use crate::player::StreamMetadata;
+
+/// Playback events a hook can be attached to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HookEvent {
+    TrackStarted,
+    TrackFinished,
+    MetadataChanged,
+}
+
+impl HookEvent {
+    pub const ALL: [HookEvent; 3] = [
+        HookEvent::TrackStarted,
+        HookEvent::TrackFinished,
+        HookEvent::MetadataChanged,
+    ];
+
+    /// Config key and CLISTEN_EVENT value.
+    pub fn name(self) -> &'static str {
+        match self {
+            HookEvent::TrackStarted => "track_started",
+            HookEvent::TrackFinished => "track_finished",
+            HookEvent::MetadataChanged => "metadata_changed",
+        }
+    }
+}
+
+/// What a hook is told about the track.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct HookTrack {
+    pub title: String,
+    pub artist: String,
+    pub url: String,
+}
+
+impl HookTrack {
+    /// Stream tags win over the item's own title; the artist is only known
+    /// from tags.
+    pub fn new(item: &DiscoveryItem, url: &str, metadata: Option<&StreamMetadata>) -> Self {
+        Self {
+            title: metadata
+                .and_then(|m| m.title.clone())
+                .unwrap_or_else(|| item.display_title()),
+            artist: metadata.and_then(|m| m.artist.clone()).unwrap_or_default(),
+            url: url.to_string(),
+        }
+    }
+}
+
+/// Environment passed to a hook command.
+pub fn env(event: HookEvent, track: &HookTrack) -> Vec<(&'static str, String)> {
+    vec![
+        ("CLISTEN_EVENT", event.name().to_string()),
+        ("CLISTEN_TITLE", track.title.clone()),
+        ("CLISTEN_ARTIST", track.artist.clone()),
+        ("CLISTEN_URL", track.url.clone()),
+    ]
+}
+
+/// Run the command configured for `event`, if any, without waiting for it.
+pub fn run(hooks: &HooksConfig, event: HookEvent, track: &HookTrack) {
+    let Some(command) = hooks.command(event) else {
+        return;
+    };
+    let spawned = Command::new("sh")
+        .arg("-c")
+        .arg(command)
+        .envs(env(event, track))
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn();
+    match spawned {
+        // Reap it so finished hooks don't linger as zombies.
+        Ok(mut child) => {
+            tokio::spawn(async move {
+                let _ = child.wait().await;
+            });
+        }
+        Err(e) => tracing::warn!("{} hook failed to start: {e}", event.name()),
+    }
+}
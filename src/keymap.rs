@@ -0,0 +1,452 @@
+// Configurable keybindings: maps key chords to named commands. The built-in
+// layout is the default; the `[keys]` config section overrides single commands.
+
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use serde::{Deserialize, Serialize};
+
+/// A remappable command. Names are the snake_case keys of the `[keys]` section.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Command {
+    Quit,
+    Help,
+    NextTab,
+    PrevTab,
+    LiveTab,
+    PicksTab,
+    SearchTab,
+    FollowingTab,
+    FavoritesTab,
+    HistoryTab,
+    PlaylistsTab,
+    ForYouTab,
+    PodcastsTab,
+    Back,
+    ScrollDown,
+    ScrollUp,
+    ScrollTop,
+    ScrollBottom,
+    HalfPageDown,
+    HalfPageUp,
+    FindInList,
+    JumpToLetter,
+    GroupGenres,
+    ToggleMoods,
+    TogglePlaces,
+    MoreLikeThis,
+    Select,
+    PlayPause,
+    NextTrack,
+    PrevTrack,
+    Stop,
+    AddToQueue,
+    AddToQueueNext,
+    RemoveFromQueue,
+    ClearQueue,
+    SeekBackward,
+    SeekForward,
+    SeekBackwardFine,
+    SeekForwardFine,
+    SeekBackwardCoarse,
+    SeekForwardCoarse,
+    SeekTimeline,
+    CompactLayout,
+    ShrinkSplit,
+    GrowSplit,
+    Bookmark,
+    PrevBookmark,
+    NextBookmark,
+    Tracklist,
+    Info,
+    OpenInBrowser,
+    CopyLink,
+    CopyStreamUrl,
+    VolumeDown,
+    VolumeUp,
+    DirectPlay,
+    CycleVisualizer,
+    VisualizerFullscreen,
+    ToggleSkipIntro,
+    RadioMode,
+    LowDataMode,
+    CycleBufferProfile,
+    Equalizer,
+    CycleSort,
+    FocusSearch,
+    Retry,
+    ToggleMark,
+    MarkRange,
+    ToggleFavorite,
+    ToggleFollow,
+    NotificationHistory,
+    Stats,
+    Logs,
+    Favorites,
+    History,
+    ExportData,
+    ImportData,
+    Playlists,
+    AddToPlaylist,
+    QueueToPlaylist,
+    JumpToPlaying,
+    ContextMenu,
+    Preview,
+}
+
+impl Command {
+    /// The snake_case name used in the `[keys]` section.
+    pub fn name(self) -> String {
+        serde_json::to_value(self)
+            .ok()
+            .and_then(|v| v.as_str().map(String::from))
+            .unwrap_or_default()
+    }
+}
+
+/// Built-in bindings, in the order they were historically defined.
+const DEFAULT_BINDINGS: &[(Command, &[&str])] = &[
+    (Command::Quit, &["q"]),
+    (Command::Help, &["?"]),
+    (Command::NextTab, &["tab"]),
+    (Command::PrevTab, &["shift+tab"]),
+    (Command::LiveTab, &["1"]),
+    (Command::PicksTab, &["2"]),
+    (Command::SearchTab, &["3"]),
+    (Command::FollowingTab, &["4"]),
+    (Command::FavoritesTab, &["5"]),
+    (Command::HistoryTab, &["6"]),
+    (Command::PlaylistsTab, &["7"]),
+    (Command::ForYouTab, &["8"]),
+    (Command::PodcastsTab, &["9"]),
+    (Command::Back, &["esc"]),
+    (Command::ScrollDown, &["j", "down"]),
+    (Command::ScrollUp, &["k", "up"]),
+    (Command::Select, &["enter"]),
+    (Command::PlayPause, &["space"]),
+    (Command::NextTrack, &["n"]),
+    (Command::PrevTrack, &["p"]),
+    (Command::Stop, &["s"]),
+    (Command::AddToQueue, &["a"]),
+    (Command::AddToQueueNext, &["A"]),
+    (Command::RemoveFromQueue, &["d"]),
+    (Command::ClearQueue, &["c"]),
+    (Command::SeekBackward, &["left"]),
+    (Command::SeekForward, &["right"]),
+    (Command::SeekBackwardFine, &["shift+left"]),
+    (Command::SeekForwardFine, &["shift+right"]),
+    (Command::SeekBackwardCoarse, &["ctrl+left"]),
+    (Command::SeekForwardCoarse, &["ctrl+right"]),
+    (Command::SeekTimeline, &["t"]),
+    (Command::Tracklist, &["T"]),
+    (Command::Info, &["i"]),
+    (Command::OpenInBrowser, &["O"]),
+    (Command::CopyLink, &["y"]),
+    (Command::CopyStreamUrl, &["Y"]),
+    (Command::VolumeDown, &["["]),
+    (Command::VolumeUp, &["]"]),
+    (Command::DirectPlay, &["o"]),
+    (Command::CycleVisualizer, &["v"]),
+    (Command::VisualizerFullscreen, &["alt+v"]),
+    (Command::ToggleSkipIntro, &["alt+i"]),
+    (Command::RadioMode, &["alt+r"]),
+    (Command::LowDataMode, &["alt+d"]),
+    (Command::CycleBufferProfile, &["B"]),
+    (Command::Equalizer, &["e"]),
+    (Command::CycleSort, &["S"]),
+    (Command::FocusSearch, &["/"]),
+    (Command::Retry, &["r"]),
+    (Command::ToggleMark, &["m"]),
+    (Command::MarkRange, &["V"]),
+    (Command::ToggleFavorite, &["f"]),
+    (Command::ToggleFollow, &["F"]),
+    (Command::NotificationHistory, &["N"]),
+    (Command::Stats, &["U"]),
+    (Command::Logs, &["D"]),
+    (Command::Favorites, &["*"]),
+    (Command::History, &["H"]),
+    (Command::ExportData, &["E"]),
+    (Command::ImportData, &["I"]),
+    (Command::Playlists, &["P"]),
+    (Command::AddToPlaylist, &["+"]),
+    (Command::QueueToPlaylist, &["="]),
+    (Command::JumpToPlaying, &["g"]),
+    (Command::ScrollTop, &["home"]),
+    (Command::ScrollBottom, &["G", "end"]),
+    (Command::HalfPageDown, &["ctrl+d"]),
+    (Command::HalfPageUp, &["ctrl+u"]),
+    (Command::FindInList, &["ctrl+f"]),
+    (Command::JumpToLetter, &["'"]),
+    (Command::GroupGenres, &["z"]),
+    (Command::ToggleMoods, &["M"]),
+    (Command::TogglePlaces, &["L"]),
+    (Command::MoreLikeThis, &["R"]),
+    (Command::ContextMenu, &["."]),
+    (Command::Bookmark, &["b"]),
+    (Command::PrevBookmark, &["{"]),
+    (Command::NextBookmark, &["}"]),
+    (Command::CompactLayout, &["Z"]),
+    (Command::ShrinkSplit, &["<"]),
+    (Command::GrowSplit, &[">"]),
+    (Command::Preview, &["alt+p"]),
+];
+
+/// Minimum spacing between repeats of commands that are expensive to run
+/// back to back: each volume step is two mpv IPC round trips, and each track
+/// change reloads mpv's stream. Commands not listed are never limited.
+const REPEAT_LIMITS: &[(Command, Duration)] = &[
+    (Command::VolumeUp, Duration::from_millis(80)),
+    (Command::VolumeDown, Duration::from_millis(80)),
+    (Command::NextTrack, Duration::from_millis(250)),
+    (Command::PrevTrack, Duration::from_millis(250)),
+];
+
+/// Drops repeats of a command that arrive faster than its limit, so a held
+/// key can't queue up more work than the player keeps up with.
+#[derive(Debug, Default)]
+pub struct RepeatLimiter {
+    last_run: HashMap<Command, Instant>,
+}
+
+impl RepeatLimiter {
+    /// True if `command` may run at `now`; records the run if so.
+    pub fn allow(&mut self, command: Command, now: Instant) -> bool {
+        let Some((_, min_gap)) = REPEAT_LIMITS.iter().find(|(c, _)| *c == command) else {
+            return true;
+        };
+        if let Some(last) = self.last_run.get(&command) {
+            if now.duration_since(*last) < *min_gap {
+                return false;
+            }
+        }
+        self.last_run.insert(command, now);
+        true
+    }
+}
+
+/// A key plus modifiers, written in config as e.g. `"q"`, `"ctrl+d"`, `"shift+tab"`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct KeyChord {
+    pub code: KeyCode,
+    pub modifiers: KeyModifiers,
+}
+
+impl KeyChord {
+    /// Normalize so chords compare equal regardless of how the terminal
+    /// reports them: Shift is implied by a character's case, and Shift+Tab
+    /// arrives as BackTab.
+    pub fn new(code: KeyCode, modifiers: KeyModifiers) -> Self {
+        let mut modifiers =
+            modifiers & (KeyModifiers::CONTROL | KeyModifiers::ALT | KeyModifiers::SHIFT);
+        let code = match code {
+            KeyCode::Tab if modifiers.contains(KeyModifiers::SHIFT) => KeyCode::BackTab,
+            other => other,
+        };
+        if matches!(code, KeyCode::Char(_) | KeyCode::BackTab) {
+            modifiers.remove(KeyModifiers::SHIFT);
+        }
+        Self { code, modifiers }
+    }
+
+    pub fn from_event(key: &KeyEvent) -> Self {
+        Self::new(key.code, key.modifiers)
+    }
+}
+
+impl FromStr for KeyChord {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        // "+" on its own (or as the final part, e.g. "ctrl++") is the plus key.
+        let (mods, key) = match s.strip_suffix("++") {
+            Some(prefix) => (prefix, "+"),
+            None if s == "+" => ("", "+"),
+            None => s.rsplit_once('+').unwrap_or(("", s)),
+        };
+        let mut modifiers = KeyModifiers::NONE;
+        for m in mods.split('+').filter(|m| !m.is_empty()) {
+            modifiers |= match m.to_ascii_lowercase().as_str() {
+                "ctrl" | "control" => KeyModifiers::CONTROL,
+                "alt" | "meta" => KeyModifiers::ALT,
+                "shift" => KeyModifiers::SHIFT,
+                _ => return Err(format!("unknown modifier '{}' in key '{}'", m, s)),
+            };
+        }
+        let mut chars = key.chars();
+        let code = match (chars.next(), chars.next()) {
+            (Some(c), None) => KeyCode::Char(c),
+            _ => match key.to_ascii_lowercase().as_str() {
+                "space" => KeyCode::Char(' '),
+                "enter" | "return" => KeyCode::Enter,
+                "esc" | "escape" => KeyCode::Esc,
+                "tab" => KeyCode::Tab,
+                "backtab" => KeyCode::BackTab,
+                "backspace" => KeyCode::Backspace,
+                "delete" | "del" => KeyCode::Delete,
+                "insert" => KeyCode::Insert,
+                "up" => KeyCode::Up,
+                "down" => KeyCode::Down,
+                "left" => KeyCode::Left,
+                "right" => KeyCode::Right,
+                "home" => KeyCode::Home,
+                "end" => KeyCode::End,
+                "pageup" => KeyCode::PageUp,
+                "pagedown" => KeyCode::PageDown,
+                f if f.starts_with('f') => match f[1..].parse::<u8>() {
+                    Ok(n) if (1..=12).contains(&n) => KeyCode::F(n),
+                    _ => return Err(format!("unknown key '{}'", s)),
+                },
+                _ => return Err(format!("unknown key '{}'", s)),
+            },
+        };
+        Ok(Self::new(code, modifiers))
+    }
+}
+
+impl TryFrom<String> for KeyChord {
+    type Error = String;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+impl From<KeyChord> for String {
+    /// The config spelling, which round-trips through `FromStr`.
+    fn from(chord: KeyChord) -> Self {
+        let mut out = String::new();
+        for (flag, name) in [
+            (KeyModifiers::CONTROL, "ctrl+"),
+            (KeyModifiers::ALT, "alt+"),
+            (KeyModifiers::SHIFT, "shift+"),
+        ] {
+            if chord.modifiers.contains(flag) {
+                out.push_str(name);
+            }
+        }
+        match chord.code {
+            KeyCode::Char(' ') => out.push_str("space"),
+            KeyCode::Char(c) => out.push(c),
+            KeyCode::BackTab => out.push_str("shift+tab"),
+            KeyCode::F(n) => out.push_str(&format!("f{}", n)),
+            other => out.push_str(&format!("{:?}", other).to_ascii_lowercase()),
+        }
+        out
+    }
+}
+
+impl fmt::Display for KeyChord {
+    /// Human-readable label for the help overlay.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.modifiers.contains(KeyModifiers::CONTROL) {
+            f.write_str("Ctrl+")?;
+        }
+        if self.modifiers.contains(KeyModifiers::ALT) {
+            f.write_str("Alt+")?;
+        }
+        if self.modifiers.contains(KeyModifiers::SHIFT) {
+            f.write_str("Shift+")?;
+        }
+        match self.code {
+            KeyCode::Char(' ') => f.write_str("Space"),
+            KeyCode::Char(c) => write!(f, "{}", c),
+            KeyCode::BackTab => f.write_str("Shift+Tab"),
+            KeyCode::Left => f.write_str("←"),
+            KeyCode::Right => f.write_str("→"),
+            KeyCode::Up => f.write_str("Up"),
+            KeyCode::Down => f.write_str("Down"),
+            KeyCode::Esc => f.write_str("Escape"),
+            other => write!(f, "{}", other),
+        }
+    }
+}
+
+/// One chord or a list of chords for a command in the `[keys]` section.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum KeySpec {
+    One(KeyChord),
+    Many(Vec<KeyChord>),
+}
+
+impl KeySpec {
+    pub fn chords(&self) -> &[KeyChord] {
+        match self {
+            Self::One(chord) => std::slice::from_ref(chord),
+            Self::Many(chords) => chords,
+        }
+    }
+}
+
+/// Resolves key events to commands.
+#[derive(Debug, Clone)]
+pub struct KeyMap {
+    by_chord: HashMap<KeyChord, Command>,
+    by_command: BTreeMap<Command, Vec<KeyChord>>,
+}
+
+impl Default for KeyMap {
+    fn default() -> Self {
+        Self::new(&BTreeMap::new())
+    }
+}
+
+impl KeyMap {
+    /// Build the map from the defaults plus user overrides. An override
+    /// replaces all default chords of its command, and takes its chords away
+    /// from any other command that had them by default.
+    pub fn new(overrides: &BTreeMap<Command, KeySpec>) -> Self {
+        let mut by_command: BTreeMap<Command, Vec<KeyChord>> = DEFAULT_BINDINGS
+            .iter()
+            .map(|(cmd, keys)| {
+                let chords = keys
+                    .iter()
+                    .map(|k| k.parse().expect("valid default key chord"))
+                    .collect();
+                (*cmd, chords)
+            })
+            .collect();
+
+        for (cmd, spec) in overrides {
+            for chords in by_command.values_mut() {
+                chords.retain(|c| !spec.chords().contains(c));
+            }
+            by_command.insert(*cmd, spec.chords().to_vec());
+        }
+
+        let by_chord = by_command
+            .iter()
+            .flat_map(|(cmd, chords)| chords.iter().map(move |c| (*c, *cmd)))
+            .collect();
+        Self {
+            by_chord,
+            by_command,
+        }
+    }
+
+    pub fn resolve(&self, key: &KeyEvent) -> Option<Command> {
+        self.by_chord.get(&KeyChord::from_event(key)).copied()
+    }
+
+    pub fn chords(&self, command: Command) -> &[KeyChord] {
+        self.by_command.get(&command).map_or(&[], Vec::as_slice)
+    }
+
+    /// Display label for a command's chords, e.g. "j / Down".
+    pub fn label(&self, command: Command) -> String {
+        let chords = self.chords(command);
+        if chords.is_empty() {
+            return "—".to_string();
+        }
+        chords
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>()
+            .join(" / ")
+    }
+}
@@ -1,13 +1,31 @@
 // Library root — re-exports all modules so integration tests can `use clisten::*`.
 
+pub mod about;
 pub mod action;
 pub mod api;
 pub mod app;
+pub mod browser;
+pub mod clipboard;
 pub mod components;
 pub mod config;
 pub mod db;
+pub mod doctor;
+pub mod headless;
+pub mod health;
+pub mod hooks;
+pub mod keymap;
 pub mod logging;
+pub mod metrics;
+#[cfg(target_os = "linux")]
+pub mod mpris;
 pub mod player;
+pub mod playlist;
+pub mod power;
+pub mod recommend;
+pub mod remote;
+pub mod schedule;
 pub mod theme;
+pub mod trace;
+pub mod transfer;
 pub mod tui;
 pub mod ui;
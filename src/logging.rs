@@ -1,24 +1,171 @@
-// File-based logging via tracing. Writes to ~/.local/share/clisten/clisten.log.
+// Logging via tracing. Writes to ~/.local/share/clisten/clisten.log and keeps
+// the newest lines in memory for the log viewer (press `D`); `--debug`, or `v`
+// in the viewer, turns the verbosity up while the app runs.
 
+use std::collections::VecDeque;
+use std::fmt::Write as _;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::time::SystemTime;
+
+use tracing::field::{Field, Visit};
+use tracing::{Event, Level, Subscriber};
 use tracing_appender::non_blocking::WorkerGuard;
 use tracing_appender::rolling;
-use tracing_subscriber::{fmt, prelude::*, EnvFilter};
-
-/// Initialize file logging. The returned guard must be held for the program's
-/// lifetime — dropping it flushes and closes the log file writer.
-pub fn init() -> anyhow::Result<WorkerGuard> {
-    let data_dir = dirs::data_dir()
-        .unwrap_or_else(|| std::path::PathBuf::from("."))
-        .join("clisten");
+use tracing_subscriber::layer::Context;
+use tracing_subscriber::{fmt, prelude::*, reload, EnvFilter, Layer, Registry};
+
+use crate::db;
+
+const LOG_FILE: &str = "clisten.log";
+/// Lines kept for the log viewer; older ones are dropped.
+const BUFFER_LINES: usize = 1000;
+
+/// The log file written by `init`.
+pub fn log_path() -> PathBuf {
+    db::data_dir().join(LOG_FILE)
+}
+
+/// One logged event, as the log viewer shows it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LogLine {
+    pub time: SystemTime,
+    pub level: Level,
+    pub target: String,
+    /// The message followed by any other fields, as `key=value`.
+    pub message: String,
+}
+
+/// The in-memory end of the log, plus the switch for debug verbosity.
+/// Clones share both; a default one isn't hooked up to tracing and only
+/// holds what is pushed into it.
+#[derive(Debug, Clone, Default)]
+pub struct Logs {
+    lines: Arc<Mutex<VecDeque<LogLine>>>,
+    filter: Option<reload::Handle<EnvFilter, Registry>>,
+    debug: Arc<Mutex<bool>>,
+}
+
+impl Logs {
+    #[allow(dead_code)] // used by integration tests
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Keep `line`, dropping the oldest once the buffer is full.
+    pub fn push(&self, line: LogLine) {
+        let mut lines = self.lock();
+        if lines.len() == BUFFER_LINES {
+            lines.pop_front();
+        }
+        lines.push_back(line);
+    }
+
+    /// The buffered lines, oldest first.
+    pub fn lines(&self) -> Vec<LogLine> {
+        self.lock().iter().cloned().collect()
+    }
+
+    pub fn is_debug(&self) -> bool {
+        *self.debug.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Switch between the normal and the debug filter.
+    pub fn set_debug(&self, debug: bool) -> anyhow::Result<()> {
+        if let Some(handle) = &self.filter {
+            handle.reload(filter(debug)?)?;
+        }
+        *self.debug.lock().unwrap_or_else(|e| e.into_inner()) = debug;
+        let state = if debug { "on" } else { "off" };
+        tracing::info!("debug logging {state}");
+        Ok(())
+    }
+
+    /// A tracing layer that pushes every event it sees into this buffer.
+    pub fn layer(&self) -> BufferLayer {
+        BufferLayer { logs: self.clone() }
+    }
+
+    fn lock(&self) -> MutexGuard<'_, VecDeque<LogLine>> {
+        self.lines.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+/// Records events into a `Logs` buffer.
+pub struct BufferLayer {
+    logs: Logs,
+}
+
+impl<S: Subscriber> Layer<S> for BufferLayer {
+    fn on_event(&self, event: &Event<'_>, _ctx: Context<'_, S>) {
+        let mut message = MessageVisitor::default();
+        event.record(&mut message);
+        let metadata = event.metadata();
+        self.logs.push(LogLine {
+            time: SystemTime::now(),
+            level: *metadata.level(),
+            target: metadata.target().to_string(),
+            message: message.0,
+        });
+    }
+}
+
+/// Collects an event's fields into one line, the message first.
+#[derive(Default)]
+struct MessageVisitor(String);
+
+impl Visit for MessageVisitor {
+    fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+        if field.name() == "message" {
+            let fields = std::mem::take(&mut self.0);
+            let _ = write!(self.0, "{value:?}");
+            if !fields.is_empty() {
+                self.0.push(' ');
+                self.0.push_str(&fields);
+            }
+        } else {
+            if !self.0.is_empty() {
+                self.0.push(' ');
+            }
+            let _ = write!(self.0, "{}={value:?}", field.name());
+        }
+    }
+}
+
+/// RUST_LOG, with clisten's own events at debug (at trace, and everything
+/// else at debug, when `debug` is set).
+fn filter(debug: bool) -> anyhow::Result<EnvFilter> {
+    let filter = EnvFilter::from_default_env();
+    Ok(if debug {
+        filter
+            .add_directive("debug".parse()?)
+            .add_directive("clisten=trace".parse()?)
+    } else {
+        filter.add_directive("clisten=debug".parse()?)
+    })
+}
+
+/// Initialize logging to the file and the in-memory buffer, at debug
+/// verbosity when `debug` is set. The returned guard must be held for the
+/// program's lifetime — dropping it flushes and closes the log file writer.
+pub fn init(debug: bool) -> anyhow::Result<(WorkerGuard, Logs)> {
+    let data_dir = db::data_dir();
     std::fs::create_dir_all(&data_dir)?;
 
-    let file_appender = rolling::never(&data_dir, "clisten.log");
+    let file_appender = rolling::never(&data_dir, LOG_FILE);
     let (non_blocking, guard) = tracing_appender::non_blocking(file_appender);
 
+    let (filter, handle) = reload::Layer::new(filter(debug)?);
+    let logs = Logs {
+        filter: Some(handle),
+        debug: Arc::new(Mutex::new(debug)),
+        ..Logs::default()
+    };
     tracing_subscriber::registry()
+        .with(filter)
         .with(fmt::layer().with_writer(non_blocking).with_ansi(false))
-        .with(EnvFilter::from_default_env().add_directive("clisten=debug".parse()?))
+        .with(logs.layer())
         .init();
 
-    Ok(guard)
+    Ok((guard, logs))
 }
@@ -1,56 +1,74 @@
 // Entry point: checks runtime deps (mpv, yt-dlp), loads config, and runs the TUI.
+// `clisten doctor [--playback]` runs diagnostics instead; `clisten export` /
+// `clisten import` move favorites, history, and the queue to and from a JSON file;
+// `clisten play` / `clisten live` play without the TUI; `clisten ctl` controls
+// a running instance; `clisten schedule export` writes upcoming shows to an
+// .ics file; `clisten --replay FILE` replays an action trace written with
+// `--record FILE`.
 
+mod about;
 mod action;
 mod api;
 mod app;
+mod browser;
+mod clipboard;
 mod components;
 mod config;
 mod db;
+mod doctor;
+mod headless;
+mod health;
+mod hooks;
+mod keymap;
 mod logging;
+mod metrics;
+#[cfg(target_os = "linux")]
+mod mpris;
 mod player;
+mod playlist;
+mod power;
+mod recommend;
+mod remote;
+mod schedule;
 mod theme;
+mod trace;
+mod transfer;
 mod tui;
 mod ui;
 
+use crate::components::notifications::Notification;
 use crate::config::Config;
+use crate::player::preflight::install_hint;
 
-/// Kill mpv instances left behind by previous clisten sessions.
-/// Scans the temp dir for stale `clisten-mpv-*.sock` files and sends quit via IPC.
+/// Kill mpv instances left behind by clisten sessions that are gone, leaving
+/// those of other running instances alone.
 async fn kill_orphaned_mpv() {
-    let tmp = std::env::temp_dir();
-    let own_socket = format!("clisten-mpv-{}.sock", std::process::id());
+    use player::instance;
 
-    let Ok(entries) = std::fs::read_dir(&tmp) else {
-        return;
-    };
-
-    for entry in entries.flatten() {
-        let name = entry.file_name();
-        let Some(name_str) = name.to_str() else {
-            continue;
-        };
-        if !name_str.starts_with("clisten-mpv-") || !name_str.ends_with(".sock") {
-            continue;
-        }
-        // Don't kill our own socket
-        if name_str == own_socket {
-            continue;
-        }
-        let path = entry.path();
+    // Taken first, so an instance starting alongside sees this one as alive.
+    instance::claim_instance();
+    let dir = instance::runtime_dir();
+    for path in instance::orphaned_mpv_sockets_in(&dir) {
         // Best-effort quit + cleanup
         let _ = player::ipc::send_command(&path, r#"{"command":["quit"]}"#).await;
         let _ = std::fs::remove_file(&path);
     }
+    for path in instance::stale_instance_locks_in(&dir) {
+        let _ = std::fs::remove_file(&path);
+    }
 }
 
-fn check_dependencies() {
-    if which::which("mpv").is_err() {
-        eprintln!("Error: mpv is required but not found. Install with: brew install mpv");
+fn check_dependencies(config: &Config) {
+    let mpv = config.player.mpv_binary();
+    if which::which(mpv).is_err() {
+        eprintln!("Error: mpv is required but not found ({mpv}).");
+        eprintln!("Install with: {}", install_hint("mpv"));
+        eprintln!("Or set mpv_path under [player] in the config.");
         std::process::exit(1);
     }
     if which::which("yt-dlp").is_err() {
         eprintln!("Warning: yt-dlp not found. Some playback may not work.");
-        eprintln!("Install with: brew install yt-dlp");
+        eprintln!("Install with: {}", install_hint("yt-dlp"));
     }
 }
 
@@ -60,21 +78,87 @@ async fn main() -> anyhow::Result<()> {
         println!("clisten {}", env!("CARGO_PKG_VERSION"));
         return Ok(());
     }
+    if std::env::args().nth(1).as_deref() == Some("doctor") {
+        let healthy = if std::env::args().nth(2).as_deref() == Some("--playback") {
+            doctor::run_playback()
+        } else {
+            doctor::run().await
+        };
+        std::process::exit(if healthy { 0 } else { 1 });
+    }
+    let mut args: Vec<String> = std::env::args().skip(1).collect();
+    let debug = args.iter().any(|a| a == "--debug");
+    args.retain(|a| a != "--debug");
+    if let Some(i) = args.iter().position(|a| a == "--replay") {
+        std::process::exit(if trace::run_replay(args.get(i + 1)).await {
+            0
+        } else {
+            1
+        });
+    }
+    let record = match args.iter().position(|a| a == "--record") {
+        Some(i) if i + 1 < args.len() => {
+            let path = args.remove(i + 1);
+            args.remove(i);
+            Some(std::path::PathBuf::from(path))
+        }
+        Some(_) => {
+            eprintln!("Error: usage: clisten --record FILE");
+            std::process::exit(1);
+        }
+        None => None,
+    };
+    if matches!(args.first().map(String::as_str), Some("export" | "import")) {
+        std::process::exit(if transfer::run_cli(&args) { 0 } else { 1 });
+    }
+    if args.first().map(String::as_str) == Some("ctl") {
+        std::process::exit(if remote::run_ctl(&args[1..]).await {
+            0
+        } else {
+            1
+        });
+    }
 
-    check_dependencies();
-    kill_orphaned_mpv().await;
-
-    let config = Config::load().unwrap_or_else(|e| {
+    let mut config = Config::load().unwrap_or_else(|e| {
         eprintln!("Warning: failed to load config: {e}. Using defaults.");
         Config::default()
     });
-    let _log_guard = logging::init()?;
+    if args.first().map(String::as_str) == Some("schedule") {
+        std::process::exit(if schedule::run_cli(&args[1..], &config).await {
+            0
+        } else {
+            1
+        });
+    }
+    check_dependencies(&config);
+    let (_log_guard, logs) = logging::init(debug)?;
+    let config_problems = config.sanitize();
+
+    if matches!(args.first().map(String::as_str), Some("play" | "live")) {
+        for problem in &config_problems {
+            eprintln!("Warning: config: {problem} (ignored)");
+        }
+        std::process::exit(if headless::run_cli(&args, &config).await {
+            0
+        } else {
+            1
+        });
+    }
+    kill_orphaned_mpv().await;
 
     let pending = config.pending_onboarding_screens();
-    let mut app = app::App::new(config)?;
+    let mut app = app::App::new(config)?.with_logs(logs);
+    if let Some(path) = record {
+        app = app.with_recorder(trace::ActionRecorder::create(&path)?);
+    }
     if !pending.is_empty() {
         app.onboarding.activate(pending);
     }
+    for problem in config_problems {
+        tracing::warn!("config: {problem}");
+        app.notifications
+            .push(Notification::warn(format!("Config: {problem} — ignored")));
+    }
     app.run().await?;
 
     Ok(())
@@ -0,0 +1,106 @@
+// Opt-in local usage metrics: play and feature-usage counters buffered in
+// memory and flushed to the database, plus this session's performance numbers.
+// Nothing here ever leaves the machine.
+
+use std::collections::BTreeMap;
+use std::time::{Duration, Instant};
+
+use crate::api::models::DiscoveryItem;
+
+/// Counter name for a play of `item`, grouped by source.
+pub fn play_key(item: &DiscoveryItem) -> &'static str {
+    match item {
+        DiscoveryItem::NtsLiveChannel { .. } => "play.nts_live",
+        DiscoveryItem::NtsEpisode { .. } => "play.nts_episode",
+        DiscoveryItem::DirectUrl { .. } => "play.direct_url",
+        DiscoveryItem::PodcastEpisode { .. } => "play.podcast",
+        DiscoveryItem::NtsGenre { .. } | DiscoveryItem::NtsGenreCategory { .. } => "play.nts_genre",
+        DiscoveryItem::NtsMood { .. } => "play.nts_mood",
+        DiscoveryItem::NtsPlace { .. } => "play.nts_place",
+    }
+}
+
+/// Performance figures for the running session.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct PerfSnapshot {
+    pub actions: u64,
+    pub actions_per_sec: f64,
+    pub frames: u64,
+    pub avg_render_ms: f64,
+    pub max_render_ms: f64,
+}
+
+/// Collects counters while enabled; every method is a no-op otherwise.
+pub struct Metrics {
+    enabled: bool,
+    /// Increments not yet written to the database.
+    pending: BTreeMap<String, u64>,
+    started: Instant,
+    actions: u64,
+    frames: u64,
+    render_total: Duration,
+    render_max: Duration,
+}
+
+impl Metrics {
+    pub fn new(enabled: bool) -> Self {
+        Self {
+            enabled,
+            pending: BTreeMap::new(),
+            started: Instant::now(),
+            actions: 0,
+            frames: 0,
+            render_total: Duration::ZERO,
+            render_max: Duration::ZERO,
+        }
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    /// Bump a named counter by one.
+    pub fn count(&mut self, name: &str) {
+        if self.enabled {
+            *self.pending.entry(name.to_string()).or_default() += 1;
+        }
+    }
+
+    pub fn record_action(&mut self) {
+        if self.enabled {
+            self.actions += 1;
+        }
+    }
+
+    pub fn record_render(&mut self, elapsed: Duration) {
+        if self.enabled {
+            self.frames += 1;
+            self.render_total += elapsed;
+            self.render_max = self.render_max.max(elapsed);
+        }
+    }
+
+    /// Increments collected since the last call, for writing to the database.
+    pub fn take_pending(&mut self) -> BTreeMap<String, u64> {
+        std::mem::take(&mut self.pending)
+    }
+
+    pub fn perf(&self) -> PerfSnapshot {
+        let elapsed = self.started.elapsed().as_secs_f64();
+        PerfSnapshot {
+            actions: self.actions,
+            actions_per_sec: if elapsed > 0.0 {
+                self.actions as f64 / elapsed
+            } else {
+                0.0
+            },
+            frames: self.frames,
+            avg_render_ms: if self.frames > 0 {
+                self.render_total.as_secs_f64() * 1000.0 / self.frames as f64
+            } else {
+                0.0
+            },
+            max_render_ms: self.render_max.as_secs_f64() * 1000.0,
+        }
+    }
+}
@@ -0,0 +1,463 @@
+// MPRIS D-Bus service (Linux): exposes playback controls and the play queue
+// as a TrackList, so desktop widgets can show and pick upcoming tracks.
+
+use std::collections::HashMap;
+
+use tokio::sync::{mpsc, watch};
+use zbus::object_server::SignalEmitter;
+use zbus::zvariant::{ObjectPath, OwnedObjectPath, Value};
+use zbus::{fdo, interface, Connection};
+
+use crate::action::Action;
+
+const BUS_NAME: &str = "org.mpris.MediaPlayer2.clisten";
+const OBJECT_PATH: &str = "/org/mpris/MediaPlayer2";
+const TRACK_PATH_PREFIX: &str = "/org/clisten/track/";
+const NO_TRACK: &str = "/org/mpris/MediaPlayer2/TrackList/NoTrack";
+
+/// One queue entry as shown to MPRIS clients.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct MprisTrack {
+    pub title: String,
+    pub artist: Option<String>,
+    pub url: String,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum MprisStatus {
+    #[default]
+    Stopped,
+    Playing,
+    Paused,
+}
+
+impl MprisStatus {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Stopped => "Stopped",
+            Self::Playing => "Playing",
+            Self::Paused => "Paused",
+        }
+    }
+}
+
+/// Snapshot of the queue and playback state published on the bus.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct MprisState {
+    pub tracks: Vec<MprisTrack>,
+    pub current: Option<usize>,
+    pub status: MprisStatus,
+    pub position_secs: f64,
+    pub duration_secs: Option<f64>,
+}
+
+/// Object path identifying the queue entry at `index`. Ids are positional,
+/// so every queue change is announced with TrackListReplaced.
+pub fn track_id(index: usize) -> String {
+    format!("{}{}", TRACK_PATH_PREFIX, index)
+}
+
+/// Queue index for a track id, or None for NoTrack and foreign paths.
+pub fn track_index(id: &str) -> Option<usize> {
+    id.strip_prefix(TRACK_PATH_PREFIX)?.parse().ok()
+}
+
+fn object_path(path: String) -> OwnedObjectPath {
+    ObjectPath::try_from(path)
+        .map(Into::into)
+        .expect("valid object path")
+}
+
+fn metadata(state: &MprisState, index: usize) -> HashMap<String, Value<'static>> {
+    let mut map = HashMap::new();
+    map.insert(
+        "mpris:trackid".to_string(),
+        Value::from(object_path(track_id(index))),
+    );
+    let Some(track) = state.tracks.get(index) else {
+        return map;
+    };
+    map.insert("xesam:title".to_string(), Value::from(track.title.clone()));
+    map.insert("xesam:url".to_string(), Value::from(track.url.clone()));
+    if let Some(artist) = &track.artist {
+        map.insert(
+            "xesam:artist".to_string(),
+            Value::from(vec![artist.clone()]),
+        );
+    }
+    if state.current == Some(index) {
+        if let Some(secs) = state.duration_secs {
+            map.insert("mpris:length".to_string(), Value::from((secs * 1e6) as i64));
+        }
+    }
+    map
+}
+
+/// Publishes app state to the bus; dropping it shuts the service down.
+pub struct MprisHandle {
+    state_tx: watch::Sender<MprisState>,
+    _connection: Connection,
+}
+
+impl MprisHandle {
+    /// Publish a new snapshot. Identical snapshots are ignored.
+    pub fn update(&self, state: MprisState) {
+        self.state_tx.send_if_modified(|current| {
+            if *current == state {
+                return false;
+            }
+            *current = state;
+            true
+        });
+    }
+}
+
+/// Register the service on the session bus. Fails when there is no session
+/// bus (e.g. over SSH), in which case the app simply runs without MPRIS.
+pub async fn spawn(action_tx: mpsc::UnboundedSender<Action>) -> anyhow::Result<MprisHandle> {
+    let (state_tx, state_rx) = watch::channel(MprisState::default());
+    let connection = zbus::connection::Builder::session()?
+        .name(BUS_NAME)?
+        .serve_at(
+            OBJECT_PATH,
+            Root {
+                action_tx: action_tx.clone(),
+            },
+        )?
+        .serve_at(
+            OBJECT_PATH,
+            Player {
+                action_tx: action_tx.clone(),
+                state: state_rx.clone(),
+            },
+        )?
+        .serve_at(
+            OBJECT_PATH,
+            TrackList {
+                action_tx,
+                state: state_rx.clone(),
+            },
+        )?
+        .build()
+        .await?;
+    tokio::spawn(emit_changes(connection.clone(), state_rx));
+    Ok(MprisHandle {
+        state_tx,
+        _connection: connection,
+    })
+}
+
+/// Signal property changes (except Position, which clients poll) and queue
+/// replacements whenever a new snapshot arrives.
+async fn emit_changes(connection: Connection, mut state_rx: watch::Receiver<MprisState>) {
+    let server = connection.object_server();
+    let (Ok(player), Ok(track_list)) = (
+        server.interface::<_, Player>(OBJECT_PATH).await,
+        server.interface::<_, TrackList>(OBJECT_PATH).await,
+    ) else {
+        return;
+    };
+    let mut prev = MprisState::default();
+    while state_rx.changed().await.is_ok() {
+        let state = state_rx.borrow_and_update().clone();
+        let emitter = player.signal_emitter();
+        {
+            let iface = player.get().await;
+            if state.status != prev.status {
+                let _ = iface.playback_status_changed(emitter).await;
+            }
+            if state.current != prev.current
+                || state.duration_secs != prev.duration_secs
+                || state.current.and_then(|i| state.tracks.get(i))
+                    != prev.current.and_then(|i| prev.tracks.get(i))
+            {
+                let _ = iface.metadata_changed(emitter).await;
+            }
+            if state.tracks.len() != prev.tracks.len() || state.current != prev.current {
+                let _ = iface.can_go_next_changed(emitter).await;
+                let _ = iface.can_go_previous_changed(emitter).await;
+            }
+            if state.duration_secs.is_some() != prev.duration_secs.is_some() {
+                let _ = iface.can_seek_changed(emitter).await;
+            }
+        }
+        if state.tracks != prev.tracks || state.current != prev.current {
+            let tracks: Vec<OwnedObjectPath> = (0..state.tracks.len())
+                .map(|i| object_path(track_id(i)))
+                .collect();
+            let current = object_path(state.current.map_or(NO_TRACK.to_string(), track_id));
+            let _ =
+                TrackList::track_list_replaced(track_list.signal_emitter(), tracks, current).await;
+        }
+        prev = state;
+    }
+}
+
+/// `org.mpris.MediaPlayer2`
+struct Root {
+    action_tx: mpsc::UnboundedSender<Action>,
+}
+
+#[interface(name = "org.mpris.MediaPlayer2")]
+impl Root {
+    fn raise(&self) {}
+
+    fn quit(&self) {
+        let _ = self.action_tx.send(Action::Quit);
+    }
+
+    #[zbus(property)]
+    fn can_quit(&self) -> bool {
+        true
+    }
+
+    #[zbus(property)]
+    fn can_raise(&self) -> bool {
+        false
+    }
+
+    #[zbus(property)]
+    fn has_track_list(&self) -> bool {
+        true
+    }
+
+    #[zbus(property)]
+    fn identity(&self) -> &str {
+        "clisten"
+    }
+
+    #[zbus(property)]
+    fn supported_uri_schemes(&self) -> Vec<String> {
+        vec!["http".to_string(), "https".to_string()]
+    }
+
+    #[zbus(property)]
+    fn supported_mime_types(&self) -> Vec<String> {
+        vec![]
+    }
+}
+
+/// `org.mpris.MediaPlayer2.Player`
+struct Player {
+    action_tx: mpsc::UnboundedSender<Action>,
+    state: watch::Receiver<MprisState>,
+}
+
+impl Player {
+    fn send(&self, action: Action) -> fdo::Result<()> {
+        self.action_tx
+            .send(action)
+            .map_err(|_| fdo::Error::Failed("clisten is shutting down".to_string()))
+    }
+
+    fn status(&self) -> MprisStatus {
+        self.state.borrow().status
+    }
+}
+
+#[interface(name = "org.mpris.MediaPlayer2.Player")]
+impl Player {
+    fn next(&self) -> fdo::Result<()> {
+        self.send(Action::NextTrack)
+    }
+
+    fn previous(&self) -> fdo::Result<()> {
+        self.send(Action::PrevTrack)
+    }
+
+    fn pause(&self) -> fdo::Result<()> {
+        match self.status() {
+            MprisStatus::Playing => self.send(Action::TogglePlayPause),
+            _ => Ok(()),
+        }
+    }
+
+    fn play_pause(&self) -> fdo::Result<()> {
+        self.send(Action::TogglePlayPause)
+    }
+
+    fn stop(&self) -> fdo::Result<()> {
+        self.send(Action::Stop)
+    }
+
+    fn play(&self) -> fdo::Result<()> {
+        let (status, current) = {
+            let state = self.state.borrow();
+            (state.status, state.current)
+        };
+        match (status, current) {
+            (MprisStatus::Paused, _) => self.send(Action::TogglePlayPause),
+            (MprisStatus::Stopped, Some(index)) => self.send(Action::PlayQueueIndex(index)),
+            _ => Ok(()),
+        }
+    }
+
+    fn seek(&self, offset: i64) -> fdo::Result<()> {
+        self.send(Action::SeekRelative(offset as f64 / 1e6))
+    }
+
+    fn set_position(&self, track_id: ObjectPath<'_>, position: i64) -> fdo::Result<()> {
+        if track_index(track_id.as_str()) != self.state.borrow().current || position < 0 {
+            return Ok(());
+        }
+        self.send(Action::SeekAbsolute(position as f64 / 1e6))
+    }
+
+    fn open_uri(&self, uri: String) -> fdo::Result<()> {
+        self.send(Action::PlayItem(
+            crate::api::models::DiscoveryItem::DirectUrl {
+                url: uri,
+                title: None,
+                meta: None,
+            },
+        ))
+    }
+
+    #[zbus(property)]
+    fn playback_status(&self) -> &str {
+        self.status().as_str()
+    }
+
+    #[zbus(property)]
+    fn rate(&self) -> f64 {
+        1.0
+    }
+
+    #[zbus(property)]
+    fn minimum_rate(&self) -> f64 {
+        1.0
+    }
+
+    #[zbus(property)]
+    fn maximum_rate(&self) -> f64 {
+        1.0
+    }
+
+    #[zbus(property)]
+    fn metadata(&self) -> HashMap<String, Value<'static>> {
+        let state = self.state.borrow();
+        state
+            .current
+            .map(|i| metadata(&state, i))
+            .unwrap_or_default()
+    }
+
+    #[zbus(property(emits_changed_signal = "false"))]
+    fn position(&self) -> i64 {
+        (self.state.borrow().position_secs * 1e6) as i64
+    }
+
+    #[zbus(property)]
+    fn can_go_next(&self) -> bool {
+        let state = self.state.borrow();
+        state.current.is_some_and(|i| i + 1 < state.tracks.len())
+    }
+
+    #[zbus(property)]
+    fn can_go_previous(&self) -> bool {
+        self.state.borrow().current.is_some_and(|i| i > 0)
+    }
+
+    #[zbus(property)]
+    fn can_play(&self) -> bool {
+        self.state.borrow().current.is_some()
+    }
+
+    #[zbus(property)]
+    fn can_pause(&self) -> bool {
+        true
+    }
+
+    #[zbus(property)]
+    fn can_seek(&self) -> bool {
+        self.state.borrow().duration_secs.is_some()
+    }
+
+    #[zbus(property(emits_changed_signal = "const"))]
+    fn can_control(&self) -> bool {
+        true
+    }
+}
+
+/// `org.mpris.MediaPlayer2.TrackList`, backed by the play queue.
+struct TrackList {
+    action_tx: mpsc::UnboundedSender<Action>,
+    state: watch::Receiver<MprisState>,
+}
+
+impl TrackList {
+    fn send(&self, action: Action) -> fdo::Result<()> {
+        self.action_tx
+            .send(action)
+            .map_err(|_| fdo::Error::Failed("clisten is shutting down".to_string()))
+    }
+
+    fn index_of(&self, id: &ObjectPath<'_>) -> fdo::Result<usize> {
+        track_index(id.as_str())
+            .filter(|i| *i < self.state.borrow().tracks.len())
+            .ok_or_else(|| fdo::Error::InvalidArgs(format!("unknown track {}", id.as_str())))
+    }
+}
+
+#[interface(name = "org.mpris.MediaPlayer2.TrackList")]
+impl TrackList {
+    fn get_tracks_metadata(
+        &self,
+        track_ids: Vec<ObjectPath<'_>>,
+    ) -> Vec<HashMap<String, Value<'static>>> {
+        let state = self.state.borrow();
+        track_ids
+            .iter()
+            .filter_map(|id| track_index(id.as_str()))
+            .filter(|i| *i < state.tracks.len())
+            .map(|i| metadata(&state, i))
+            .collect()
+    }
+
+    /// Insert `uri` after `after_track` (NoTrack inserts at the start).
+    fn add_track(
+        &self,
+        uri: String,
+        after_track: ObjectPath<'_>,
+        set_as_current: bool,
+    ) -> fdo::Result<()> {
+        let index = match after_track.as_str() {
+            NO_TRACK => 0,
+            _ => self.index_of(&after_track)? + 1,
+        };
+        self.send(Action::InsertQueueUrl {
+            url: uri,
+            index,
+            play: set_as_current,
+        })
+    }
+
+    fn remove_track(&self, track_id: ObjectPath<'_>) -> fdo::Result<()> {
+        let index = self.index_of(&track_id)?;
+        self.send(Action::RemoveQueueIndex(index))
+    }
+
+    fn go_to(&self, track_id: ObjectPath<'_>) -> fdo::Result<()> {
+        let index = self.index_of(&track_id)?;
+        self.send(Action::PlayQueueIndex(index))
+    }
+
+    #[zbus(property(emits_changed_signal = "invalidates"))]
+    fn tracks(&self) -> Vec<OwnedObjectPath> {
+        (0..self.state.borrow().tracks.len())
+            .map(|i| object_path(track_id(i)))
+            .collect()
+    }
+
+    #[zbus(property(emits_changed_signal = "const"))]
+    fn can_edit_tracks(&self) -> bool {
+        true
+    }
+
+    #[zbus(signal)]
+    async fn track_list_replaced(
+        emitter: &SignalEmitter<'_>,
+        tracks: Vec<OwnedObjectPath>,
+        current_track: OwnedObjectPath,
+    ) -> zbus::Result<()>;
+}
@@ -0,0 +1,55 @@
+// User-supplied mpv flags from the `[player]` config: checks that they don't
+// clash with the options clisten relies on, plus the yt-dlp format flag.
+
+// Options clisten sets itself; overriding them breaks IPC, the idle player,
+// or the level and spectrum filters the visualizers read.
+const RESERVED_OPTIONS: &[&str] = &[
+    "input-ipc-server",
+    "idle",
+    "terminal",
+    "no-terminal",
+    "video",
+    "no-video",
+    "af",
+    "ytdl-format",
+];
+
+/// Why `arg` can't be passed to mpv, or Ok if it can.
+pub fn check_arg(arg: &str) -> Result<(), String> {
+    let Some(option) = arg.strip_prefix("--") else {
+        return Err(format!("mpv arg `{arg}` must start with --"));
+    };
+    let name = option.split('=').next().unwrap_or_default();
+    if name.is_empty() {
+        return Err(format!("mpv arg `{arg}` has no option name"));
+    }
+    if RESERVED_OPTIONS.contains(&name) {
+        let hint = if name == "ytdl-format" {
+            " (use ytdl_format instead)"
+        } else {
+            ""
+        };
+        return Err(format!("mpv arg `{arg}` is managed by clisten{hint}"));
+    }
+    Ok(())
+}
+
+/// Why `format` isn't a usable yt-dlp format selector, or Ok if it is.
+pub fn check_ytdl_format(format: &str) -> Result<(), String> {
+    if format.trim().is_empty() {
+        return Err("ytdl_format is empty".to_string());
+    }
+    if format.chars().any(char::is_whitespace) {
+        return Err(format!("ytdl_format `{format}` contains whitespace"));
+    }
+    Ok(())
+}
+
+/// yt-dlp format used in low data mode: audio around 64 kbit/s, or the
+/// smallest there is.
+pub const LOW_DATA_YTDL_FORMAT: &str = "bestaudio[abr<=64]/worstaudio/worst";
+
+/// The mpv flag selecting `format` in its yt-dlp hook.
+pub fn ytdl_format_arg(format: &str) -> String {
+    format!("--ytdl-format={format}")
+}
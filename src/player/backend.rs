@@ -0,0 +1,158 @@
+// What the app plays through: mpv, or a FakePlayer (`player::fake`) in tests.
+// Backends report back as actions on the sender they're given, the way the
+// mpv watchers send PlaybackPosition, PauseChanged and PlaybackFinished.
+
+use std::future::Future;
+use std::pin::Pin;
+
+use tokio::sync::mpsc;
+
+use crate::action::Action;
+use crate::player::buffer::BufferSettings;
+use crate::player::MpvPlayer;
+
+/// The boxed future a PlayerBackend method returns.
+pub type PlayerFuture<'a> = Pin<Box<dyn Future<Output = anyhow::Result<()>> + Send + 'a>>;
+
+/// Playback as the app drives it.
+pub trait PlayerBackend: Send {
+    /// Where playback events go.
+    fn set_action_tx(&mut self, tx: mpsc::UnboundedSender<Action>);
+    /// False when the last attempt to start playing found the player missing.
+    fn is_available(&self) -> bool;
+    fn set_buffer(&mut self, buffer: BufferSettings);
+    fn apply_buffer(&self) -> PlayerFuture<'_>;
+    fn set_equalizer(&mut self, filter: Option<String>);
+    fn apply_equalizer(&self) -> PlayerFuture<'_>;
+    /// Flags added to every player started from now on.
+    fn set_extra_args(&mut self, args: Vec<String>);
+    /// Have the running player pick streams with yt-dlp `format` from the
+    /// next file on; "" goes back to the player's default.
+    fn apply_ytdl_format<'a>(&'a self, format: &'a str) -> PlayerFuture<'a>;
+    /// Start playing `url` in place of whatever is playing.
+    fn play<'a>(&'a mut self, url: &'a str) -> PlayerFuture<'a>;
+    /// Load `url` in place of whatever is playing, paused at its start.
+    fn play_paused<'a>(&'a mut self, url: &'a str) -> PlayerFuture<'a>;
+    /// Get `url` ready to crossfade into.
+    fn preload<'a>(&'a mut self, url: &'a str) -> PlayerFuture<'a>;
+    /// URL of the track waiting to be crossfaded into.
+    fn preloaded_url(&self) -> Option<String>;
+    /// Fade over `secs` into the preloaded track; fails when there is none.
+    fn crossfade(&mut self, secs: f64) -> PlayerFuture<'_>;
+    /// Play `url` next to the current track, turning that down meanwhile.
+    fn start_preview<'a>(&'a mut self, url: &'a str) -> PlayerFuture<'a>;
+    /// URL of the preview playing, if any.
+    fn preview_url(&self) -> Option<String>;
+    /// Stop the preview and turn the current track back up.
+    fn stop_preview(&mut self) -> PlayerFuture<'_>;
+    fn seek_relative(&self, seconds: f64) -> PlayerFuture<'_>;
+    fn seek_absolute(&self, seconds: f64) -> PlayerFuture<'_>;
+    /// Loop between two positions in seconds; None stops looping.
+    fn set_ab_loop(&self, ab: Option<(f64, f64)>) -> PlayerFuture<'_>;
+    fn toggle_pause(&self) -> PlayerFuture<'_>;
+    /// Change the volume by `delta`, within 0–100.
+    fn set_volume(&self, delta: f64) -> PlayerFuture<'_>;
+    fn stop(&mut self) -> PlayerFuture<'_>;
+    /// Stop for good, on the way out of the app.
+    fn quit(&mut self) -> PlayerFuture<'_>;
+}
+
+impl PlayerBackend for MpvPlayer {
+    fn set_action_tx(&mut self, tx: mpsc::UnboundedSender<Action>) {
+        self.set_action_tx(tx);
+    }
+
+    fn is_available(&self) -> bool {
+        self.is_available()
+    }
+
+    fn set_buffer(&mut self, buffer: BufferSettings) {
+        self.set_buffer(buffer);
+    }
+
+    fn apply_buffer(&self) -> PlayerFuture<'_> {
+        Box::pin(self.apply_buffer())
+    }
+
+    fn set_equalizer(&mut self, filter: Option<String>) {
+        self.set_equalizer(filter);
+    }
+
+    fn apply_equalizer(&self) -> PlayerFuture<'_> {
+        Box::pin(self.apply_equalizer())
+    }
+
+    fn set_extra_args(&mut self, args: Vec<String>) {
+        self.set_extra_args(args);
+    }
+
+    fn apply_ytdl_format<'a>(&'a self, format: &'a str) -> PlayerFuture<'a> {
+        Box::pin(self.apply_ytdl_format(format))
+    }
+
+    fn play<'a>(&'a mut self, url: &'a str) -> PlayerFuture<'a> {
+        Box::pin(self.play(url))
+    }
+
+    fn play_paused<'a>(&'a mut self, url: &'a str) -> PlayerFuture<'a> {
+        Box::pin(self.play_paused(url))
+    }
+
+    fn preload<'a>(&'a mut self, url: &'a str) -> PlayerFuture<'a> {
+        Box::pin(self.preload(url))
+    }
+
+    fn preloaded_url(&self) -> Option<String> {
+        self.preloaded_url().map(str::to_string)
+    }
+
+    fn crossfade(&mut self, secs: f64) -> PlayerFuture<'_> {
+        Box::pin(self.crossfade(secs))
+    }
+
+    fn start_preview<'a>(&'a mut self, url: &'a str) -> PlayerFuture<'a> {
+        Box::pin(self.start_preview(url))
+    }
+
+    fn preview_url(&self) -> Option<String> {
+        self.preview_url().map(str::to_string)
+    }
+
+    fn stop_preview(&mut self) -> PlayerFuture<'_> {
+        Box::pin(async {
+            self.stop_preview().await;
+            Ok(())
+        })
+    }
+
+    fn seek_relative(&self, seconds: f64) -> PlayerFuture<'_> {
+        Box::pin(self.seek_relative(seconds))
+    }
+
+    fn seek_absolute(&self, seconds: f64) -> PlayerFuture<'_> {
+        Box::pin(self.seek_absolute(seconds))
+    }
+
+    fn set_ab_loop(&self, ab: Option<(f64, f64)>) -> PlayerFuture<'_> {
+        Box::pin(self.set_ab_loop(ab))
+    }
+
+    fn toggle_pause(&self) -> PlayerFuture<'_> {
+        Box::pin(self.toggle_pause())
+    }
+
+    fn set_volume(&self, delta: f64) -> PlayerFuture<'_> {
+        Box::pin(self.set_volume(delta))
+    }
+
+    fn stop(&mut self) -> PlayerFuture<'_> {
+        Box::pin(self.stop())
+    }
+
+    fn quit(&mut self) -> PlayerFuture<'_> {
+        Box::pin(async {
+            self.quit().await;
+            Ok(())
+        })
+    }
+}
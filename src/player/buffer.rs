@@ -0,0 +1,98 @@
+// Network buffer ("pre-roll") settings for mpv. A profile picks sensible
+// cache-secs / demuxer-max-bytes values; explicit config values override it.
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// Named buffer presets, trading start-up latency for dropout resistance.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "kebab-case")]
+pub enum BufferProfile {
+    /// Leave mpv's own cache defaults untouched.
+    #[default]
+    Default,
+    /// Small cache: streams start fast but drop out on flaky connections.
+    LowLatency,
+    /// Large cache: slower start, survives longer network hiccups.
+    Stable,
+}
+
+impl BufferProfile {
+    /// Cycle to the next profile.
+    pub fn next(self) -> Self {
+        match self {
+            Self::Default => Self::LowLatency,
+            Self::LowLatency => Self::Stable,
+            Self::Stable => Self::Default,
+        }
+    }
+
+    /// Human-readable label for display.
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Default => "Default",
+            Self::LowLatency => "Low latency",
+            Self::Stable => "Stable",
+        }
+    }
+
+    /// (cache-secs, demuxer-max-bytes) for this profile, or None for mpv defaults.
+    fn values(self) -> Option<(u32, &'static str)> {
+        match self {
+            Self::Default => None,
+            Self::LowLatency => Some((2, "2MiB")),
+            Self::Stable => Some((60, "64MiB")),
+        }
+    }
+}
+
+/// Effective buffer settings handed to mpv at spawn time and over IPC.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct BufferSettings {
+    pub cache_secs: Option<u32>,
+    pub demuxer_max_bytes: Option<String>,
+}
+
+impl BufferSettings {
+    /// Resolve a profile, letting explicit values take precedence.
+    pub fn resolve(
+        profile: BufferProfile,
+        cache_secs: Option<u32>,
+        demuxer_max_bytes: Option<&str>,
+    ) -> Self {
+        let preset = profile.values();
+        Self {
+            cache_secs: cache_secs.or(preset.map(|(secs, _)| secs)),
+            demuxer_max_bytes: demuxer_max_bytes
+                .map(String::from)
+                .or(preset.map(|(_, bytes)| bytes.to_string())),
+        }
+    }
+
+    /// mpv command-line flags for these settings.
+    pub fn mpv_args(&self) -> Vec<String> {
+        let mut args = Vec::new();
+        if let Some(secs) = self.cache_secs {
+            args.push("--cache=yes".to_string());
+            args.push(format!("--cache-secs={}", secs));
+        }
+        if let Some(ref bytes) = self.demuxer_max_bytes {
+            args.push(format!("--demuxer-max-bytes={}", bytes));
+        }
+        args
+    }
+
+    /// mpv properties to set on a running player for these settings. None
+    /// stands for mpv's own default, put back so an earlier profile's value
+    /// doesn't linger.
+    pub fn ipc_properties(&self) -> Vec<(&'static str, Option<Value>)> {
+        vec![
+            ("cache", self.cache_secs.map(|_| "yes".into())),
+            ("cache-secs", self.cache_secs.map(Value::from)),
+            (
+                "demuxer-max-bytes",
+                self.demuxer_max_bytes.as_deref().map(Value::from),
+            ),
+        ]
+    }
+}
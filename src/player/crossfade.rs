@@ -0,0 +1,45 @@
+// Crossfade timing between queue tracks: when to spawn the next track's mpv
+// (paused and silent) and when to start ramping the two volumes.
+
+/// Longest crossfade the `[player]` setting accepts.
+pub const MAX_CROSSFADE_SECS: f64 = 30.0;
+/// How long before the fade the next track is spawned, so it has buffered by
+/// the time it becomes audible.
+pub const PRELOAD_LEAD_SECS: f64 = 8.0;
+/// Volume updates per second while fading.
+pub const FADE_STEPS_PER_SEC: f64 = 10.0;
+
+/// Where the current track is relative to its crossfade.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CrossfadePhase {
+    /// Too early to do anything.
+    Playing,
+    /// Time to spawn the next track.
+    Preload,
+    /// Time to fade over to the next track.
+    Fade,
+}
+
+/// Phase at `position` into a track of `duration`, for a crossfade of
+/// `crossfade_secs`. Tracks too short to fade both in and out, with time to
+/// preload in between, never crossfade.
+pub fn phase(position: f64, duration: f64, crossfade_secs: f64) -> CrossfadePhase {
+    if crossfade_secs <= 0.0 || duration <= crossfade_secs * 2.0 + PRELOAD_LEAD_SECS {
+        return CrossfadePhase::Playing;
+    }
+    let remaining = duration - position;
+    if remaining <= crossfade_secs {
+        CrossfadePhase::Fade
+    } else if remaining <= crossfade_secs + PRELOAD_LEAD_SECS {
+        CrossfadePhase::Preload
+    } else {
+        CrossfadePhase::Playing
+    }
+}
+
+/// Volumes of the outgoing and incoming track at `t` (0–1) through a fade
+/// towards `volume`. Equal-power curves keep the combined loudness steady.
+pub fn fade_volumes(volume: f64, t: f64) -> (f64, f64) {
+    let angle = t.clamp(0.0, 1.0) * std::f64::consts::FRAC_PI_2;
+    (volume * angle.cos(), volume * angle.sin())
+}
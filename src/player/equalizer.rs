@@ -0,0 +1,86 @@
+// Equalizer presets: five peaking bands built from lavfi's `equalizer` filter
+// and handed to mpv as a labelled audio filter, so it can be swapped at runtime.
+
+use serde::{Deserialize, Serialize};
+
+/// Band centre frequencies in Hz, low to high.
+pub const BANDS_HZ: [u32; 5] = [60, 230, 910, 3600, 14000];
+/// Gains are clamped to ±this many dB.
+pub const MAX_GAIN_DB: f64 = 12.0;
+/// Label for the filter in mpv's chain, used to replace or remove it.
+pub const FILTER_LABEL: &str = "@eq";
+
+/// Named equalizer settings.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "kebab-case")]
+pub enum EqualizerPreset {
+    /// No filter at all.
+    #[default]
+    Flat,
+    BassBoost,
+    Vocal,
+    /// The gains from `custom_eq_gains` in the config.
+    Custom,
+}
+
+impl EqualizerPreset {
+    pub const ALL: [EqualizerPreset; 4] = [
+        EqualizerPreset::Flat,
+        EqualizerPreset::BassBoost,
+        EqualizerPreset::Vocal,
+        EqualizerPreset::Custom,
+    ];
+
+    /// Human-readable label for display.
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Flat => "Flat",
+            Self::BassBoost => "Bass boost",
+            Self::Vocal => "Vocal",
+            Self::Custom => "Custom",
+        }
+    }
+
+    /// Per-band gains in dB; `custom` is only used by the Custom preset.
+    pub fn gains(self, custom: [f64; 5]) -> [f64; 5] {
+        match self {
+            Self::Flat => [0.0; 5],
+            Self::BassBoost => [6.0, 4.0, 0.0, 0.0, 0.0],
+            Self::Vocal => [-2.0, -1.0, 3.0, 4.0, 1.0],
+            Self::Custom => custom.map(clamp_gain),
+        }
+    }
+}
+
+/// Limit a gain to ±MAX_GAIN_DB, treating NaN as 0.
+pub fn clamp_gain(db: f64) -> f64 {
+    if db.is_nan() {
+        0.0
+    } else {
+        db.clamp(-MAX_GAIN_DB, MAX_GAIN_DB)
+    }
+}
+
+/// Five custom gains from a config list: missing bands are 0, extras ignored.
+pub fn custom_gains(configured: &[f64]) -> [f64; 5] {
+    let mut gains = [0.0; 5];
+    for (gain, value) in gains.iter_mut().zip(configured) {
+        *gain = clamp_gain(*value);
+    }
+    gains
+}
+
+/// The labelled mpv audio filter for `gains`, or None when every band is
+/// flat and no filter is needed.
+pub fn mpv_filter(gains: [f64; 5]) -> Option<String> {
+    if gains.iter().all(|g| *g == 0.0) {
+        return None;
+    }
+    let bands: Vec<String> = BANDS_HZ
+        .iter()
+        .zip(gains)
+        .filter(|(_, gain)| *gain != 0.0)
+        .map(|(hz, gain)| format!("equalizer=f={hz}:t=o:w=1.5:g={gain}"))
+        .collect();
+    Some(format!("{FILTER_LABEL}:lavfi=[{}]", bands.join(",")))
+}
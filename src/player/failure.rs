@@ -0,0 +1,94 @@
+// Playback failure classification: turns mpv / yt-dlp error output into a
+// cause the user can act on (geo-blocking, DNS, missing stream, ...).
+
+use serde::{Deserialize, Serialize};
+
+/// Why a stream failed to play, as far as the error output tells.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PlaybackFailure {
+    /// HTTP 451 or an explicit geo-restriction notice.
+    GeoBlocked,
+    /// HTTP 403. On NTS relays this is usually a region/CDN restriction.
+    Forbidden,
+    /// HTTP 404 / 410.
+    NotFound,
+    /// The stream host name could not be resolved.
+    Dns,
+    /// Connection refused, reset, or timed out.
+    Network,
+    /// Anything else, with mpv's own error text.
+    Other(String),
+}
+
+/// Lower-cased substrings mapped to the failure they indicate, most specific first.
+const SIGNATURES: &[(&str, PlaybackFailure)] = &[
+    ("http error 451", PlaybackFailure::GeoBlocked),
+    ("server returned 451", PlaybackFailure::GeoBlocked),
+    ("unavailable for legal reasons", PlaybackFailure::GeoBlocked),
+    ("geo restrict", PlaybackFailure::GeoBlocked),
+    ("geo-restrict", PlaybackFailure::GeoBlocked),
+    ("geo-block", PlaybackFailure::GeoBlocked),
+    ("available in your country", PlaybackFailure::GeoBlocked),
+    ("available in your region", PlaybackFailure::GeoBlocked),
+    ("http error 403", PlaybackFailure::Forbidden),
+    ("403 forbidden", PlaybackFailure::Forbidden),
+    ("server returned 403", PlaybackFailure::Forbidden),
+    ("http error 404", PlaybackFailure::NotFound),
+    ("404 not found", PlaybackFailure::NotFound),
+    ("server returned 404", PlaybackFailure::NotFound),
+    ("http error 410", PlaybackFailure::NotFound),
+    ("failed to resolve hostname", PlaybackFailure::Dns),
+    ("could not resolve host", PlaybackFailure::Dns),
+    ("name or service not known", PlaybackFailure::Dns),
+    ("nodename nor servname", PlaybackFailure::Dns),
+    ("temporary failure in name resolution", PlaybackFailure::Dns),
+    ("no address associated with hostname", PlaybackFailure::Dns),
+    ("connection refused", PlaybackFailure::Network),
+    ("connection reset", PlaybackFailure::Network),
+    ("connection timed out", PlaybackFailure::Network),
+    ("network is unreachable", PlaybackFailure::Network),
+];
+
+impl PlaybackFailure {
+    /// Classify from recent error/warning log lines plus mpv's end-file
+    /// error, falling back to `Other` with the end-file error text.
+    pub fn classify(log: &[String], file_error: Option<&str>) -> Self {
+        let haystack = log
+            .iter()
+            .map(String::as_str)
+            .chain(file_error)
+            .collect::<Vec<_>>()
+            .join("\n")
+            .to_lowercase();
+        SIGNATURES
+            .iter()
+            .find(|(sig, _)| haystack.contains(sig))
+            .map(|(_, failure)| failure.clone())
+            .unwrap_or_else(|| Self::Other(file_error.unwrap_or("unknown error").to_string()))
+    }
+
+    /// True when retrying the same URL won't help, so fallback should move
+    /// straight to the next source.
+    pub fn is_source_specific(&self) -> bool {
+        matches!(
+            self,
+            Self::GeoBlocked | Self::Forbidden | Self::NotFound | Self::Dns
+        )
+    }
+
+    /// Short user-facing explanation.
+    pub fn message(&self) -> String {
+        match self {
+            Self::GeoBlocked => "Stream geo-blocked in your region".to_string(),
+            Self::Forbidden => {
+                "Stream refused access (HTTP 403), likely a region or CDN restriction".to_string()
+            }
+            Self::NotFound => "Stream not found (HTTP 404)".to_string(),
+            Self::Dns => {
+                "Couldn't resolve the stream host — check your connection or DNS".to_string()
+            }
+            Self::Network => "Network error while connecting to the stream".to_string(),
+            Self::Other(err) => format!("Playback failed: {}", err),
+        }
+    }
+}
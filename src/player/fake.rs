@@ -0,0 +1,275 @@
+// A stand-in player for tests: plays nothing, keeps track of what it was
+// asked to do, and reports back with the actions mpv would send, so queue
+// advance, seeking and crossfades can be exercised without mpv installed.
+
+use std::sync::{Arc, Mutex, MutexGuard};
+
+use tokio::sync::mpsc;
+
+use crate::action::Action;
+use crate::player::backend::{PlayerBackend, PlayerFuture};
+use crate::player::buffer::BufferSettings;
+use crate::player::preview;
+
+/// What the FakePlayer has been told so far.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FakeState {
+    /// Every URL played or crossfaded into, oldest first.
+    pub played: Vec<String>,
+    /// The URL playing now; None when stopped.
+    pub current: Option<String>,
+    pub position: f64,
+    pub paused: bool,
+    pub volume: f64,
+    pub preloaded: Option<String>,
+    /// The URL previewing next to the current track.
+    pub preview: Option<String>,
+    /// The A-B loop, in seconds.
+    pub ab_loop: Option<(f64, f64)>,
+    /// The yt-dlp format last applied to the running player.
+    pub ytdl_format: Option<String>,
+    pub quit: bool,
+}
+
+impl Default for FakeState {
+    fn default() -> Self {
+        Self {
+            played: Vec::new(),
+            current: None,
+            position: 0.0,
+            paused: false,
+            volume: 100.0,
+            preloaded: None,
+            preview: None,
+            ab_loop: None,
+            ytdl_format: None,
+            quit: false,
+        }
+    }
+}
+
+/// A PlayerBackend that only pretends. Clones share their state and action
+/// sender, so a test can keep one to inspect the player and to drive
+/// playback from the "mpv" side (`play_to`, `finish_track`) after handing
+/// another to the App.
+#[derive(Debug, Clone, Default)]
+pub struct FakePlayer {
+    state: Arc<Mutex<FakeState>>,
+    action_tx: Arc<Mutex<Option<mpsc::UnboundedSender<Action>>>>,
+    /// Length reported for every track; None plays them as live streams.
+    duration: Option<f64>,
+    /// Play fails with this message, as when mpv can't start.
+    failure: Option<String>,
+}
+
+impl FakePlayer {
+    /// Tracks are ten minutes long.
+    pub fn new() -> Self {
+        Self {
+            duration: Some(600.0),
+            ..Self::default()
+        }
+    }
+
+    /// Report every track as `duration` seconds long (None: a live stream).
+    pub fn with_duration(mut self, duration: Option<f64>) -> Self {
+        self.duration = duration;
+        self
+    }
+
+    /// Fail every play with `message`.
+    pub fn failing(mut self, message: impl Into<String>) -> Self {
+        self.failure = Some(message.into());
+        self
+    }
+
+    /// A copy of the player's state.
+    pub fn state(&self) -> FakeState {
+        self.lock().clone()
+    }
+
+    /// The track has played up to `position` seconds.
+    pub fn play_to(&self, position: f64) {
+        self.lock().position = position;
+        self.send(Action::PlaybackPosition(position));
+    }
+
+    /// The track has played to its end.
+    pub fn finish_track(&self) {
+        self.lock().current = None;
+        self.send(Action::PlaybackFinished);
+    }
+
+    fn lock(&self) -> MutexGuard<'_, FakeState> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    fn send(&self, action: Action) {
+        let tx = self.action_tx.lock().unwrap_or_else(|e| e.into_inner());
+        if let Some(tx) = tx.as_ref() {
+            tx.send(action).ok();
+        }
+    }
+
+    /// Move to `position`, within the track, and report it.
+    fn seek_to(&self, position: f64) {
+        let end = self.duration.unwrap_or(f64::MAX);
+        self.play_to(position.clamp(0.0, end));
+    }
+
+    fn start(&self, url: &str) {
+        let mut state = self.lock();
+        state.played.push(url.to_string());
+        state.current = Some(url.to_string());
+        state.position = 0.0;
+        state.paused = false;
+        state.ab_loop = None;
+        drop(state);
+        self.send(Action::PlaybackDuration(self.duration));
+        self.send(Action::PlaybackPosition(0.0));
+    }
+}
+
+fn done<'a>() -> PlayerFuture<'a> {
+    Box::pin(std::future::ready(Ok(())))
+}
+
+impl PlayerBackend for FakePlayer {
+    fn set_action_tx(&mut self, tx: mpsc::UnboundedSender<Action>) {
+        *self.action_tx.lock().unwrap_or_else(|e| e.into_inner()) = Some(tx);
+    }
+
+    fn is_available(&self) -> bool {
+        self.failure.is_none()
+    }
+
+    fn set_buffer(&mut self, _buffer: BufferSettings) {}
+
+    fn apply_buffer(&self) -> PlayerFuture<'_> {
+        done()
+    }
+
+    fn set_equalizer(&mut self, _filter: Option<String>) {}
+
+    fn apply_equalizer(&self) -> PlayerFuture<'_> {
+        done()
+    }
+
+    fn set_extra_args(&mut self, _args: Vec<String>) {}
+
+    fn apply_ytdl_format<'a>(&'a self, format: &'a str) -> PlayerFuture<'a> {
+        self.lock().ytdl_format = Some(format.to_string());
+        done()
+    }
+
+    fn play<'a>(&'a mut self, url: &'a str) -> PlayerFuture<'a> {
+        self.send(Action::PlaybackLoading);
+        self.lock().preloaded = None;
+        if let Some(message) = &self.failure {
+            return Box::pin(std::future::ready(Err(anyhow::anyhow!("{message}"))));
+        }
+        self.start(url);
+        done()
+    }
+
+    fn play_paused<'a>(&'a mut self, url: &'a str) -> PlayerFuture<'a> {
+        self.send(Action::PlaybackLoading);
+        self.lock().preloaded = None;
+        if let Some(message) = &self.failure {
+            return Box::pin(std::future::ready(Err(anyhow::anyhow!("{message}"))));
+        }
+        self.start(url);
+        self.lock().paused = true;
+        self.send(Action::PauseChanged(true));
+        done()
+    }
+
+    fn preload<'a>(&'a mut self, url: &'a str) -> PlayerFuture<'a> {
+        self.lock().preloaded = Some(url.to_string());
+        done()
+    }
+
+    fn preloaded_url(&self) -> Option<String> {
+        self.lock().preloaded.clone()
+    }
+
+    fn crossfade(&mut self, _secs: f64) -> PlayerFuture<'_> {
+        let Some(url) = self.lock().preloaded.take() else {
+            return Box::pin(std::future::ready(Err(anyhow::anyhow!(
+                "no track preloaded"
+            ))));
+        };
+        self.start(&url);
+        done()
+    }
+
+    fn start_preview<'a>(&'a mut self, url: &'a str) -> PlayerFuture<'a> {
+        let mut state = self.lock();
+        if state.preview.replace(url.to_string()).is_none() {
+            state.volume = preview::ducked(state.volume);
+        }
+        done()
+    }
+
+    fn preview_url(&self) -> Option<String> {
+        self.lock().preview.clone()
+    }
+
+    fn stop_preview(&mut self) -> PlayerFuture<'_> {
+        let mut state = self.lock();
+        if state.preview.take().is_some() {
+            state.volume = preview::unducked(state.volume);
+        }
+        done()
+    }
+
+    fn seek_relative(&self, seconds: f64) -> PlayerFuture<'_> {
+        let position = self.lock().position + seconds;
+        self.seek_to(position);
+        done()
+    }
+
+    fn seek_absolute(&self, seconds: f64) -> PlayerFuture<'_> {
+        self.seek_to(seconds);
+        done()
+    }
+
+    fn set_ab_loop(&self, ab: Option<(f64, f64)>) -> PlayerFuture<'_> {
+        self.lock().ab_loop = ab;
+        done()
+    }
+
+    fn toggle_pause(&self) -> PlayerFuture<'_> {
+        let paused = {
+            let mut state = self.lock();
+            state.paused = !state.paused;
+            state.paused
+        };
+        self.send(Action::PauseChanged(paused));
+        done()
+    }
+
+    fn set_volume(&self, delta: f64) -> PlayerFuture<'_> {
+        let volume = {
+            let mut state = self.lock();
+            state.volume = (state.volume + delta).clamp(0.0, 100.0);
+            state.volume
+        };
+        self.send(Action::VolumeChanged(volume.round() as u8));
+        done()
+    }
+
+    fn stop(&mut self) -> PlayerFuture<'_> {
+        let mut state = self.lock();
+        state.current = None;
+        state.preloaded = None;
+        done()
+    }
+
+    fn quit(&mut self) -> PlayerFuture<'_> {
+        let mut state = self.lock();
+        state.current = None;
+        state.quit = true;
+        done()
+    }
+}
@@ -0,0 +1,191 @@
+// Per-instance runtime files: mpv IPC sockets live in a private directory
+// under XDG_RUNTIME_DIR, named after the clisten instance that owns them, so
+// several clisten processes can run at once and a new one only cleans up
+// after instances that are gone. Each instance also holds a lock on a
+// `<instance>.lock` file for as long as it runs, which is what tells a live
+// owner from a dead one.
+
+use std::fs::{File, TryLockError};
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+// File name prefix of mpv sockets owned by a clisten instance.
+const MPV_SOCKET_PREFIX: &str = "mpv-";
+
+/// Directory for sockets: `$XDG_RUNTIME_DIR/clisten`, or a per-user
+/// directory in the temp dir where there is no runtime dir (macOS).
+pub fn runtime_dir() -> PathBuf {
+    match dirs::runtime_dir() {
+        Some(dir) => dir.join("clisten"),
+        None => {
+            let user = std::env::var("USER").unwrap_or_default();
+            std::env::temp_dir().join(format!("clisten-{user}"))
+        }
+    }
+}
+
+/// Create `dir` readable only by the current user, if it doesn't exist.
+pub fn create_private_dir(dir: &Path) -> std::io::Result<()> {
+    use std::os::unix::fs::{DirBuilderExt, PermissionsExt};
+    if dir.is_dir() {
+        return Ok(());
+    }
+    std::fs::DirBuilder::new()
+        .recursive(true)
+        .mode(0o700)
+        .create(dir)?;
+    std::fs::set_permissions(dir, std::fs::Permissions::from_mode(0o700))
+}
+
+/// This process's instance id: its pid plus a start stamp, so files left by
+/// an earlier process with a recycled pid never look like ours.
+pub fn instance_id() -> &'static str {
+    static ID: OnceLock<String> = OnceLock::new();
+    ID.get_or_init(|| {
+        let stamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_micros();
+        format!("{}-{:x}", std::process::id(), stamp & 0xff_ffff)
+    })
+}
+
+/// Lock file held by the instance `id` while it runs.
+pub fn instance_lock(dir: &Path, id: &str) -> PathBuf {
+    dir.join(format!("{id}.lock"))
+}
+
+/// Take this instance's lock in the runtime dir, once; later calls only
+/// report whether it's held. Without it, other instances fall back to
+/// checking the pid.
+pub fn claim_instance() -> bool {
+    static LOCK: OnceLock<Option<File>> = OnceLock::new();
+    LOCK.get_or_init(|| {
+        let dir = runtime_dir();
+        create_private_dir(&dir)
+            .and_then(|()| hold_instance_lock(&dir, instance_id()))
+            .inspect_err(|e| tracing::warn!("couldn't take the instance lock: {e}"))
+            .ok()
+    })
+    .is_some()
+}
+
+/// Create and lock the lock file for instance `id` in `dir`; the lock holds
+/// until the file is dropped. It's locked under a temporary name and then
+/// moved into place, so no one sees it unlocked.
+pub fn hold_instance_lock(dir: &Path, id: &str) -> std::io::Result<File> {
+    let path = instance_lock(dir, id);
+    let temp = dir.join(format!(".{id}.lock.new"));
+    let file = File::create(&temp)?;
+    file.lock()?;
+    std::fs::write(&temp, format!("{}\n", std::process::id()))?;
+    std::fs::rename(&temp, &path)?;
+    Ok(file)
+}
+
+/// Socket for one of this instance's mpv processes; `suffix` tells them apart.
+pub fn mpv_socket(suffix: &str) -> PathBuf {
+    runtime_dir().join(format!("{MPV_SOCKET_PREFIX}{}{suffix}.sock", instance_id()))
+}
+
+/// Pid of the instance that owns the mpv socket named `file_name`.
+pub fn socket_owner(file_name: &str) -> Option<u32> {
+    socket_instance(file_name)?.split('-').next()?.parse().ok()
+}
+
+/// Id of the instance that owns the mpv socket named `file_name`: its pid
+/// and start stamp, without the suffix.
+pub fn socket_instance(file_name: &str) -> Option<&str> {
+    let rest = file_name
+        .strip_prefix(MPV_SOCKET_PREFIX)?
+        .strip_suffix(".sock")?;
+    let pid_len = rest.find('-')?;
+    let stamp_len = rest[pid_len + 1..]
+        .find('-')
+        .unwrap_or(rest.len() - pid_len - 1);
+    Some(&rest[..pid_len + 1 + stamp_len])
+}
+
+/// True when the instance that owns the mpv socket named `file_name` in
+/// `dir` is still running: it holds its lock file. Sockets from instances
+/// that never took one are judged by their pid.
+pub fn socket_owner_alive(dir: &Path, file_name: &str) -> bool {
+    let Some(id) = socket_instance(file_name) else {
+        return false;
+    };
+    match File::open(instance_lock(dir, id)) {
+        Ok(file) => is_locked(&file),
+        Err(_) => socket_owner(file_name).is_some_and(|pid| {
+            // Our pid on someone else's socket means the pid was recycled.
+            pid != std::process::id() && is_clisten_running(pid)
+        }),
+    }
+}
+
+// Whether another open file holds a lock on `file`. Taking it is harmless:
+// it goes again when `file` is dropped.
+fn is_locked(file: &File) -> bool {
+    match file.try_lock() {
+        Ok(()) => false,
+        Err(TryLockError::WouldBlock) => true,
+        // Locks unsupported here: assume the owner may be alive.
+        Err(TryLockError::Error(_)) => true,
+    }
+}
+
+/// True when `pid` is a running clisten process. A pid taken over by some
+/// other program counts as gone.
+pub fn is_clisten_running(pid: u32) -> bool {
+    process_name(pid).is_some_and(|name| name.trim().starts_with("clisten"))
+}
+
+#[cfg(target_os = "linux")]
+fn process_name(pid: u32) -> Option<String> {
+    std::fs::read_to_string(format!("/proc/{pid}/comm")).ok()
+}
+
+#[cfg(not(target_os = "linux"))]
+fn process_name(pid: u32) -> Option<String> {
+    let output = std::process::Command::new("ps")
+        .args(["-p", &pid.to_string(), "-o", "comm="])
+        .output()
+        .ok()?;
+    let name = String::from_utf8_lossy(&output.stdout);
+    // ps prints the full path of the executable on macOS.
+    let name = name.trim().rsplit('/').next()?.to_string();
+    (!name.is_empty()).then_some(name)
+}
+
+/// mpv sockets in `dir` (the runtime dir) whose owning instance is no
+/// longer running.
+pub fn orphaned_mpv_sockets_in(dir: &Path) -> Vec<PathBuf> {
+    file_names(dir)
+        .into_iter()
+        .filter(|name| {
+            socket_instance(name).is_some_and(|id| id != instance_id())
+                && !socket_owner_alive(dir, name)
+        })
+        .map(|name| dir.join(name))
+        .collect()
+}
+
+/// Lock files in `dir` left by instances that are no longer running.
+pub fn stale_instance_locks_in(dir: &Path) -> Vec<PathBuf> {
+    file_names(dir)
+        .into_iter()
+        .filter(|name| name.ends_with(".lock") && !name.starts_with('.'))
+        .map(|name| dir.join(name))
+        .filter(|path| File::open(path).is_ok_and(|file| !is_locked(&file)))
+        .collect()
+}
+
+fn file_names(dir: &Path) -> Vec<String> {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return Vec::new();
+    };
+    entries
+        .flatten()
+        .filter_map(|entry| entry.file_name().into_string().ok())
+        .collect()
+}
@@ -0,0 +1,68 @@
+// Intro skipping for archived NTS episodes. Most open on the station jingle
+// followed by a short silence, and the jingle's length varies, so a separate
+// mpv decodes the first INTRO_WINDOW_SECS through ffmpeg's silencedetect and
+// the episode is sought to where the first silence ends. mpv resolves the
+// Mixcloud/SoundCloud page the same way the playing instance does. Until the
+// analysis answers (or when it finds nothing), FALLBACK_INTRO_SECS is skipped.
+
+use std::process::Stdio;
+use std::time::Duration;
+
+use tokio::process::Command;
+
+/// Seconds skipped when the intro can't be measured.
+pub const FALLBACK_INTRO_SECS: f64 = 3.0;
+/// How much of the episode the analysis decodes.
+pub const INTRO_WINDOW_SECS: f64 = 15.0;
+// Silence ending sooner than this is the gap before the jingle, not after it.
+const MIN_INTRO_SECS: f64 = 1.0;
+// The analysis is given up on after this long; resolving the page is most of it.
+const ANALYSIS_TIMEOUT: Duration = Duration::from_secs(20);
+// Quieter than -40 dB for a quarter second counts as silence.
+const SILENCE_FILTER: &str = "lavfi=[silencedetect=noise=-40dB:d=0.25]";
+
+/// Where the content starts in silencedetect's log output: the end of the
+/// first silence between MIN_INTRO_SECS and INTRO_WINDOW_SECS in, e.g. from
+/// "[silencedetect @ 0x…] silence_end: 4.213 | silence_duration: 0.61".
+pub fn content_start(log: &str) -> Option<f64> {
+    log.lines()
+        .filter_map(|line| {
+            let rest = &line[line.find("silence_end:")? + "silence_end:".len()..];
+            rest.split_whitespace().next()?.parse::<f64>().ok()
+        })
+        .find(|end| (MIN_INTRO_SECS..=INTRO_WINDOW_SECS).contains(end))
+}
+
+/// The mpv command that decodes the start of `url` as fast as it can,
+/// without output, logging what silencedetect finds.
+pub fn analysis_command(binary: &str, extra_args: &[String], url: &str) -> Command {
+    let mut command = Command::new(binary);
+    command
+        .arg("--no-config")
+        .arg("--no-video")
+        .arg("--ao=null")
+        .arg("--untimed")
+        .arg(format!("--length={INTRO_WINDOW_SECS}"))
+        .arg(format!("--af={SILENCE_FILTER}"))
+        // libavfilter logs at info, which mpv shows at its "v" level.
+        .arg("--msg-level=all=no,ffmpeg=v")
+        .args(extra_args)
+        .arg("--")
+        .arg(url)
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .kill_on_drop(true);
+    command
+}
+
+/// Run `command` (see `analysis_command`) and return where the content
+/// starts, or None when no silence ends inside the window.
+pub async fn detect_content_start(mut command: Command) -> anyhow::Result<Option<f64>> {
+    let output = tokio::time::timeout(ANALYSIS_TIMEOUT, command.output())
+        .await
+        .map_err(|_| anyhow::anyhow!("intro analysis timed out"))??;
+    let mut log = String::from_utf8_lossy(&output.stdout).into_owned();
+    log.push_str(&String::from_utf8_lossy(&output.stderr));
+    Ok(content_start(&log))
+}
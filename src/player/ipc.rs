@@ -1,5 +1,6 @@
-// Low-level mpv IPC: socket communication, and background tasks for monitoring
-// playback state (exit, position, metadata, audio levels).
+// Low-level mpv IPC: socket communication, background tasks watching each
+// mpv instance (its exit; position, pause, volume, metadata, and file ends as
+// they change; audio levels and bands), and the volume ramp of a crossfade.
 
 use std::path::{Path, PathBuf};
 use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
@@ -7,7 +8,12 @@ use tokio::net::UnixStream;
 use tokio::process::Child;
 use tokio::sync::mpsc;
 
-use super::StreamMetadata;
+use std::collections::VecDeque;
+
+use super::crossfade::{fade_volumes, FADE_STEPS_PER_SEC};
+use super::failure::PlaybackFailure;
+use super::levels::{self, LevelMeter};
+use super::{spectrum, StreamMetadata};
 use crate::action::Action;
 
 pub type MpvProcess = std::sync::Arc<tokio::sync::Mutex<Option<Child>>>;
@@ -15,8 +21,8 @@ pub type MpvProcess = std::sync::Arc<tokio::sync::Mutex<Option<Child>>>;
 // How long to wait for mpv's IPC socket to appear (20 * 100ms = 2s).
 const SOCKET_POLL_ATTEMPTS: u32 = 20;
 const SOCKET_POLL_INTERVAL_MS: u64 = 100;
-// Silence floor for dB-to-linear conversion.
-const SILENCE_FLOOR_DB: f64 = -60.0;
+// Recent warning/error log lines kept for classifying a failure.
+const ERROR_LOG_LINES: usize = 20;
 
 /// Wait for the IPC socket to appear on disk (up to 2 seconds).
 pub async fn wait_for_socket(path: &Path) {
@@ -41,7 +47,40 @@ pub async fn send_command(socket_path: &Path, cmd: &str) -> anyhow::Result<Strin
     Ok(response)
 }
 
-/// Poll the child process and send PlaybackFinished when it exits.
+fn set_volume_command(volume: f64) -> String {
+    format!(r#"{{"command":["set_property","volume",{:.1}]}}"#, volume)
+}
+
+/// Ramp `outgoing` down and `incoming` up to `volume` over `secs`, then quit
+/// the outgoing mpv and clear `fading`.
+pub fn spawn_crossfade(
+    outgoing: PathBuf,
+    incoming: PathBuf,
+    fading: MpvProcess,
+    volume: f64,
+    secs: f64,
+) -> tokio::task::JoinHandle<()> {
+    tokio::spawn(async move {
+        let steps = (secs * FADE_STEPS_PER_SEC).ceil().max(1.0) as u32;
+        let interval = tokio::time::Duration::from_secs_f64(1.0 / FADE_STEPS_PER_SEC);
+        for step in 1..=steps {
+            let (out_volume, in_volume) = fade_volumes(volume, step as f64 / steps as f64);
+            let _ = send_command(&outgoing, &set_volume_command(out_volume)).await;
+            let _ = send_command(&incoming, &set_volume_command(in_volume)).await;
+            tokio::time::sleep(interval).await;
+        }
+        let _ = send_command(&outgoing, r#"{"command":["quit"]}"#).await;
+        let mut guard = fading.lock().await;
+        if let Some(ref mut child) = *guard {
+            let _ = child.kill().await;
+        }
+        *guard = None;
+        let _ = std::fs::remove_file(&outgoing);
+    })
+}
+
+/// Poll the child process and send PlaybackFinished if it exits, which only
+/// happens when mpv crashes or is killed from outside.
 pub fn spawn_exit_monitor(
     child: MpvProcess,
     tx: mpsc::UnboundedSender<Action>,
@@ -63,56 +102,25 @@ pub fn spawn_exit_monitor(
     })
 }
 
-/// Poll playback-time once per second and forward it as PlaybackPosition.
-pub fn spawn_position_poller(
-    socket_path: PathBuf,
-    tx: mpsc::UnboundedSender<Action>,
-) -> tokio::task::JoinHandle<()> {
-    tokio::spawn(async move {
-        wait_for_socket(&socket_path).await;
-        loop {
-            tokio::time::sleep(tokio::time::Duration::from_secs(1)).await;
-            let Ok(response) = send_command(
-                &socket_path,
-                r#"{"command":["get_property","playback-time"]}"#,
-            )
-            .await
-            else {
-                break;
-            };
+// Properties watched on every mpv instance. `path` comes first: mpv reports
+// changes in this order, so a new file is seen before its metadata.
+const OBSERVED_PROPERTIES: &[&str] = &[
+    "path",
+    "playback-time",
+    "demuxer-cache-time",
+    "duration",
+    "pause",
+    "volume",
+    "media-title",
+    "metadata/by-key/icy-name",
+    "metadata/by-key/artist",
+    "metadata/by-key/album",
+    "audio-device-list",
+];
 
-            if let Ok(val) = serde_json::from_str::<serde_json::Value>(&response) {
-                if let Some(pos) = val.get("data").and_then(|d| d.as_f64()) {
-                    tx.send(Action::PlaybackPosition(pos)).ok();
-                }
-            }
-        }
-    })
-}
-
-/// Poll duration once per second and forward it as PlaybackDuration.
-/// For live streams mpv returns null → we send None.
-pub fn spawn_duration_poller(
-    socket_path: PathBuf,
-    tx: mpsc::UnboundedSender<Action>,
-) -> tokio::task::JoinHandle<()> {
-    tokio::spawn(async move {
-        wait_for_socket(&socket_path).await;
-        loop {
-            tokio::time::sleep(tokio::time::Duration::from_secs(1)).await;
-            let Ok(response) =
-                send_command(&socket_path, r#"{"command":["get_property","duration"]}"#).await
-            else {
-                break;
-            };
-
-            if let Ok(val) = serde_json::from_str::<serde_json::Value>(&response) {
-                let duration = val.get("data").and_then(|d| d.as_f64());
-                tx.send(Action::PlaybackDuration(duration)).ok();
-            }
-        }
-    })
-}
+// Smallest change in position or buffered time worth reporting. mpv updates
+// playback-time many times a second; jumps (seeks) always go through.
+const POSITION_STEP_SECS: f64 = 0.5;
 
 /// Filter out junk metadata values (empty, "stream", raw URLs).
 fn is_junk_metadata(val: &str, url: &str) -> bool {
@@ -124,11 +132,153 @@ fn is_junk_metadata(val: &str, url: &str) -> bool {
         || trimmed == url
 }
 
-/// Observe multiple metadata properties from mpv (media-title, icy-name, artist, album).
-pub fn spawn_metadata_observer(
+/// Turns the messages mpv sends an observing client into actions.
+#[derive(Debug, Default)]
+pub struct MpvEvents {
+    /// The loaded file, as given to mpv.
+    path: Option<String>,
+    meta: StreamMetadata,
+    last_position: Option<f64>,
+    last_buffered: Option<f64>,
+    /// Recent warning/error log lines, for classifying a failure.
+    log: VecDeque<String>,
+    /// Audio outputs mpv last listed, as (name, description).
+    audio_devices: Option<Vec<(String, String)>>,
+}
+
+impl MpvEvents {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Actions for one message (a parsed line) from mpv.
+    pub fn handle(&mut self, message: &serde_json::Value) -> Vec<Action> {
+        match message.get("event").and_then(|e| e.as_str()) {
+            Some("property-change") => {
+                let name = message.get("name").and_then(|n| n.as_str()).unwrap_or("");
+                self.property_changed(name, message.get("data"))
+                    .into_iter()
+                    .collect()
+            }
+            Some("log-message") => {
+                let prefix = message.get("prefix").and_then(|p| p.as_str()).unwrap_or("");
+                let text = message.get("text").and_then(|t| t.as_str()).unwrap_or("");
+                if self.log.len() == ERROR_LOG_LINES {
+                    self.log.pop_front();
+                }
+                self.log
+                    .push_back(format!("[{}] {}", prefix, text.trim_end()));
+                Vec::new()
+            }
+            // Only the current file's log explains its failure.
+            Some("start-file") => {
+                self.log.clear();
+                Vec::new()
+            }
+            // Files stopped or replaced on purpose report nothing.
+            Some("end-file") => match message.get("reason").and_then(|r| r.as_str()) {
+                Some("eof") => vec![Action::PlaybackFinished],
+                Some("error") => {
+                    let file_error = message.get("file_error").and_then(|e| e.as_str());
+                    let log: Vec<String> = self.log.drain(..).collect();
+                    let failure = PlaybackFailure::classify(&log, file_error);
+                    vec![Action::PlaybackFailed(failure), Action::PlaybackFinished]
+                }
+                _ => Vec::new(),
+            },
+            _ => Vec::new(),
+        }
+    }
+
+    fn property_changed(&mut self, name: &str, data: Option<&serde_json::Value>) -> Option<Action> {
+        let number = data.and_then(|d| d.as_f64());
+        let text = data.and_then(|d| d.as_str()).map(|s| s.trim().to_string());
+        match name {
+            "path" => {
+                if text != self.path {
+                    self.path = text;
+                    self.meta = StreamMetadata::default();
+                    self.last_position = None;
+                    self.last_buffered = None;
+                }
+                None
+            }
+            "playback-time" => {
+                let pos = number?;
+                if self
+                    .last_position
+                    .is_some_and(|last| (pos - last).abs() < POSITION_STEP_SECS)
+                {
+                    return None;
+                }
+                self.last_position = Some(pos);
+                Some(Action::PlaybackPosition(pos))
+            }
+            // How far the demuxer has read ahead; absent for some streams.
+            "demuxer-cache-time" => {
+                let cached = number?;
+                if self
+                    .last_buffered
+                    .is_some_and(|last| (cached - last).abs() < POSITION_STEP_SECS)
+                {
+                    return None;
+                }
+                self.last_buffered = Some(cached);
+                Some(Action::PlaybackBuffered(cached))
+            }
+            // For live streams mpv reports no duration → None.
+            "duration" => Some(Action::PlaybackDuration(number)),
+            "pause" => Some(Action::PauseChanged(data?.as_bool()?)),
+            "volume" => Some(Action::VolumeChanged(
+                number?.round().clamp(0.0, 100.0) as u8
+            )),
+            // Changes as outputs come and go, e.g. headphones unplugged.
+            "audio-device-list" => {
+                let devices: Vec<(String, String)> = data?
+                    .as_array()?
+                    .iter()
+                    .filter_map(|device| {
+                        let name = device.get("name")?.as_str()?.to_string();
+                        let description = device
+                            .get("description")
+                            .and_then(|d| d.as_str())
+                            .unwrap_or(&name)
+                            .to_string();
+                        Some((name, description))
+                    })
+                    .collect();
+                let previous = self.audio_devices.replace(devices.clone())?;
+                previous
+                    .into_iter()
+                    .find(|(name, _)| !devices.iter().any(|(n, _)| n == name))
+                    .map(|(_, description)| Action::AudioDeviceRemoved(description))
+            }
+            _ => {
+                let url = self.path.as_deref().unwrap_or("");
+                let clean = text.filter(|s| !is_junk_metadata(s, url));
+                let field = match name {
+                    "media-title" => &mut self.meta.title,
+                    "metadata/by-key/icy-name" => &mut self.meta.station_name,
+                    "metadata/by-key/artist" => &mut self.meta.artist,
+                    "metadata/by-key/album" => &mut self.meta.album,
+                    _ => return None,
+                };
+                if *field == clean {
+                    return None;
+                }
+                *field = clean;
+                (!self.meta.is_empty()).then(|| Action::StreamMetadataChanged(self.meta.clone()))
+            }
+        }
+    }
+}
+
+/// Watch one mpv instance over a single long-lived connection: observed
+/// properties become actions as they change, and end-file events report
+/// each file ending (see `MpvEvents`).
+pub fn spawn_observer(
     socket_path: PathBuf,
     tx: mpsc::UnboundedSender<Action>,
-    url: String,
 ) -> tokio::task::JoinHandle<()> {
     tokio::spawn(async move {
         wait_for_socket(&socket_path).await;
@@ -139,12 +289,10 @@ pub fn spawn_metadata_observer(
         let (reader, mut writer) = stream.into_split();
         let mut lines = BufReader::new(reader).lines();
 
-        let commands = [
-            r#"{"command":["observe_property",1,"media-title"]}"#,
-            r#"{"command":["observe_property",2,"metadata/by-key/icy-name"]}"#,
-            r#"{"command":["observe_property",3,"metadata/by-key/artist"]}"#,
-            r#"{"command":["observe_property",4,"metadata/by-key/album"]}"#,
-        ];
+        let mut commands = vec![r#"{"command":["request_log_messages","warn"]}"#.to_string()];
+        commands.extend(OBSERVED_PROPERTIES.iter().enumerate().map(|(id, name)| {
+            serde_json::json!({ "command": ["observe_property", id + 1, name] }).to_string()
+        }));
         for cmd in commands {
             if writer
                 .write_all(format!("{}\n", cmd).as_bytes())
@@ -155,69 +303,85 @@ pub fn spawn_metadata_observer(
             }
         }
 
-        let mut meta = StreamMetadata::default();
-
+        let mut events = MpvEvents::new();
         while let Ok(Some(line)) = lines.next_line().await {
             let Ok(val) = serde_json::from_str::<serde_json::Value>(&line) else {
                 continue;
             };
-            if val.get("event").and_then(|e| e.as_str()) != Some("property-change") {
-                continue;
+            for action in events.handle(&val) {
+                tx.send(action).ok();
             }
+        }
+    })
+}
 
-            let Some(id) = val.get("id").and_then(|i| i.as_u64()) else {
-                continue;
-            };
-            let data_str = val
-                .get("data")
-                .and_then(|d| d.as_str())
-                .map(|s| s.trim().to_string());
+/// A long-lived connection for commands that expect a reply. mpv also sends
+/// events to every client, so replies are matched by request_id.
+struct Requester {
+    lines: tokio::io::Lines<BufReader<tokio::net::unix::OwnedReadHalf>>,
+    writer: tokio::net::unix::OwnedWriteHalf,
+    next_id: u64,
+}
 
-            let clean = data_str.filter(|s| !is_junk_metadata(s, &url));
+impl Requester {
+    async fn connect(socket_path: &Path) -> anyhow::Result<Self> {
+        let (reader, writer) = UnixStream::connect(socket_path).await?.into_split();
+        let mut requester = Self {
+            lines: BufReader::new(reader).lines(),
+            writer,
+            next_id: 0,
+        };
+        // Events aren't needed here; the reply is skipped like any other.
+        requester
+            .request(serde_json::json!(["disable_event", "all"]))
+            .await?;
+        Ok(requester)
+    }
 
-            let field = match id {
-                1 => &mut meta.title,
-                2 => &mut meta.station_name,
-                3 => &mut meta.artist,
-                4 => &mut meta.album,
-                _ => continue,
+    /// Send `command` and return its reply. Errs only if the connection is
+    /// gone.
+    async fn request(&mut self, command: serde_json::Value) -> anyhow::Result<serde_json::Value> {
+        self.next_id += 1;
+        let msg = serde_json::json!({ "command": command, "request_id": self.next_id });
+        self.writer
+            .write_all(format!("{}\n", msg).as_bytes())
+            .await?;
+        while let Some(line) = self.lines.next_line().await? {
+            let Ok(val) = serde_json::from_str::<serde_json::Value>(&line) else {
+                continue;
             };
-            let changed = *field != clean;
-            *field = clean;
-
-            if changed && !meta.is_empty() {
-                tx.send(Action::StreamMetadataChanged(meta.clone())).ok();
+            if val.get("request_id").and_then(|id| id.as_u64()) == Some(self.next_id) {
+                return Ok(val);
             }
         }
-    })
+        anyhow::bail!("mpv closed the IPC connection")
+    }
 }
 
-/// Poll audio levels at ~20 Hz via the astats lavfi filter.
+/// Poll audio levels and spectrum bands at ~20 Hz via the astats lavfi
+/// filters, over one connection for the life of the mpv instance. The
+/// levels go through a LevelMeter, so they keep the loudest moment since the
+/// last poll in view.
 pub fn spawn_audio_level_poller(
     socket_path: PathBuf,
     tx: mpsc::UnboundedSender<Action>,
 ) -> tokio::task::JoinHandle<()> {
     tokio::spawn(async move {
         wait_for_socket(&socket_path).await;
-        let mut consecutive_errors: u32 = 0;
+        let Ok(mut mpv) = Requester::connect(&socket_path).await else {
+            return;
+        };
+        let bands_property = format!("af-metadata/{}", spectrum::FILTER_LABEL);
+        let mut meter = LevelMeter::default();
+        let mut last_read = tokio::time::Instant::now();
         loop {
-            tokio::time::sleep(tokio::time::Duration::from_millis(50)).await;
-            let Ok(response) = send_command(
-                &socket_path,
-                r#"{"command":["get_property","af-metadata/astats"]}"#,
-            )
-            .await
+            tokio::time::sleep(levels::POLL_INTERVAL).await;
+            // Between files there are no levels; the request still succeeds.
+            let Ok(val) = mpv
+                .request(serde_json::json!(["get_property", "af-metadata/astats"]))
+                .await
             else {
-                consecutive_errors += 1;
-                if consecutive_errors > 40 {
-                    break;
-                }
-                continue;
-            };
-            consecutive_errors = 0;
-
-            let Ok(val) = serde_json::from_str::<serde_json::Value>(&response) else {
-                continue;
+                break;
             };
             let Some(data) = val.get("data").and_then(|d| d.as_object()) else {
                 continue;
@@ -233,19 +397,25 @@ pub fn spawn_audio_level_poller(
                 .and_then(|s| s.parse::<f64>().ok());
 
             if let (Some(rms_db), Some(peak_db)) = (rms_db, peak_db) {
-                let rms = db_to_linear(rms_db);
-                let peak = db_to_linear(peak_db);
+                let now = tokio::time::Instant::now();
+                let (rms, peak) = meter.update(rms_db, peak_db, now - last_read);
+                last_read = now;
                 tx.send(Action::AudioLevels { rms, peak }).ok();
             }
+
+            let Ok(val) = mpv
+                .request(serde_json::json!(["get_property", bands_property]))
+                .await
+            else {
+                break;
+            };
+            if let Some(bands) = val
+                .get("data")
+                .and_then(|d| d.as_object())
+                .and_then(spectrum::parse_bands)
+            {
+                tx.send(Action::AudioBands(bands)).ok();
+            }
         }
     })
 }
-
-/// Convert decibels to a 0.0–1.0 linear amplitude. Silence floor at -60 dB.
-fn db_to_linear(db: f64) -> f64 {
-    if db <= SILENCE_FLOOR_DB {
-        0.0
-    } else {
-        10.0_f64.powf(db / 20.0).clamp(0.0, 1.0)
-    }
-}
@@ -0,0 +1,60 @@
+// Level meter for the visualizers. astats (reset every audio frame) only
+// describes the last ~20ms of audio when it's polled, so a kick between two
+// polls would go unseen and quiet passages flicker. The meter gives readings
+// instant attack and a steady release in dB, like a hardware meter.
+
+use std::time::Duration;
+
+/// How often the levels are read from mpv.
+pub const POLL_INTERVAL: Duration = Duration::from_millis(50);
+/// Levels at or below this read as silence.
+pub const SILENCE_FLOOR_DB: f64 = -60.0;
+// How fast the readings fall back once the audio gets quieter.
+const RMS_RELEASE_DB_PER_SEC: f64 = 24.0;
+const PEAK_RELEASE_DB_PER_SEC: f64 = 12.0;
+
+/// RMS and peak levels in dBFS, between polls.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LevelMeter {
+    rms_db: f64,
+    peak_db: f64,
+}
+
+impl Default for LevelMeter {
+    fn default() -> Self {
+        Self {
+            rms_db: SILENCE_FLOOR_DB,
+            peak_db: SILENCE_FLOOR_DB,
+        }
+    }
+}
+
+impl LevelMeter {
+    /// Take a reading `elapsed` after the last and return the metered RMS
+    /// and peak as 0.0–1.0 amplitudes. A louder reading shows at once; a
+    /// quieter one only as fast as the release allows.
+    pub fn update(&mut self, rms_db: f64, peak_db: f64, elapsed: Duration) -> (f64, f64) {
+        let secs = elapsed.as_secs_f64();
+        self.rms_db = meter(self.rms_db, rms_db, RMS_RELEASE_DB_PER_SEC * secs);
+        self.peak_db = meter(self.peak_db, peak_db, PEAK_RELEASE_DB_PER_SEC * secs);
+        (db_to_linear(self.rms_db), db_to_linear(self.peak_db))
+    }
+}
+
+fn meter(held: f64, reading: f64, max_fall: f64) -> f64 {
+    let reading = if reading.is_nan() {
+        SILENCE_FLOOR_DB
+    } else {
+        reading.max(SILENCE_FLOOR_DB)
+    };
+    reading.max(held - max_fall)
+}
+
+/// Convert decibels to a 0.0–1.0 linear amplitude. Silence floor at -60 dB.
+pub fn db_to_linear(db: f64) -> f64 {
+    if db <= SILENCE_FLOOR_DB {
+        0.0
+    } else {
+        10.0_f64.powf(db / 20.0).clamp(0.0, 1.0)
+    }
+}
@@ -1,20 +1,42 @@
-// mpv wrapper: spawns mpv with an IPC socket for play/pause/stop/volume.
-// Low-level IPC communication and background pollers live in the ipc submodule.
+// mpv wrapper: keeps one idle mpv running and loads each track into it over
+// the IPC socket, crossfades into a preloaded second instance between queue
+// tracks, and plays previews in a third next to the current track.
+// Low-level IPC communication and background watchers live in the ipc submodule.
+// The app drives it through the PlayerBackend trait (backend), which a
+// FakePlayer (fake) also implements for tests.
 
+pub mod args;
+pub mod backend;
+pub mod buffer;
+pub mod crossfade;
+pub mod equalizer;
+pub mod failure;
+#[allow(dead_code)] // used by integration tests
+pub mod fake;
+pub mod instance;
+pub mod intro;
 pub mod ipc;
+pub mod levels;
+pub mod preflight;
+pub mod preview;
 pub mod queue;
+pub mod spectrum;
+pub mod watch_later;
+pub mod ytdl;
 
 use std::path::{Path, PathBuf};
 use std::process::Stdio;
-use tokio::process::Command;
+use tokio::process::{Child, Command};
 use tokio::sync::mpsc;
 
 use crate::action::Action;
 use anyhow::Context;
+use buffer::BufferSettings;
 use ipc::MpvProcess;
+use serde::{Deserialize, Serialize};
 
 /// Metadata gleaned from an active stream (ICY headers, ID3 tags, etc.).
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct StreamMetadata {
     pub station_name: Option<String>, // icy-name
     pub title: Option<String>,        // media-title / ICY track
@@ -47,22 +69,68 @@ impl StreamMetadata {
     }
 }
 
-/// Wraps an mpv child process, communicating over a Unix IPC socket.
+/// The next track's mpv, spawned paused and silent ahead of a crossfade.
+struct Preloaded {
+    url: String,
+    child: Child,
+}
+
+/// Wraps a long-lived mpv child process (`--idle=yes`), communicating over a
+/// Unix IPC socket. Tracks are swapped with `loadfile`; mpv is only respawned
+/// if it has exited.
 pub struct MpvPlayer {
     socket_path: PathBuf,
+    /// Socket for the preloaded instance; swapped with `socket_path` when a
+    /// crossfade hands playback over to it.
+    spare_socket_path: PathBuf,
     action_tx: Option<mpsc::UnboundedSender<Action>>,
     child: MpvProcess,
-    poller_handles: Vec<tokio::task::JoinHandle<()>>,
+    /// Tasks watching the current mpv instance.
+    process_handles: Vec<tokio::task::JoinHandle<()>>,
+    buffer: BufferSettings,
+    /// Equalizer filter for mpv's audio chain; None when flat.
+    equalizer: Option<String>,
+    /// User flags from the config, appended to every spawn.
+    extra_args: Vec<String>,
+    /// The mpv executable: "mpv" on PATH, or a configured path.
+    binary: String,
+    /// True once the binary's version has passed the preflight check.
+    version_checked: bool,
+    /// False after the last spawn found mpv missing or too old.
+    available: bool,
+    preloaded: Option<Preloaded>,
+    /// Socket for the preview instance.
+    preview_socket_path: PathBuf,
+    /// Another item playing next to the current track; shares Preloaded's
+    /// shape, though it plays straight away.
+    preview: Option<Preloaded>,
+    /// True while the current track is turned down for the preview.
+    ducked: bool,
+    /// The outgoing mpv while it fades out.
+    fading: MpvProcess,
+    fade_handle: Option<tokio::task::JoinHandle<()>>,
 }
 
 impl Default for MpvPlayer {
     fn default() -> Self {
-        let pid = std::process::id();
         Self {
-            socket_path: std::env::temp_dir().join(format!("clisten-mpv-{}.sock", pid)),
+            socket_path: instance::mpv_socket(""),
+            spare_socket_path: instance::mpv_socket("-next"),
             action_tx: None,
             child: std::sync::Arc::new(tokio::sync::Mutex::new(None)),
-            poller_handles: Vec::new(),
+            process_handles: Vec::new(),
+            buffer: BufferSettings::default(),
+            equalizer: None,
+            extra_args: Vec::new(),
+            binary: "mpv".to_string(),
+            version_checked: false,
+            available: true,
+            preloaded: None,
+            preview_socket_path: instance::mpv_socket("-preview"),
+            preview: None,
+            ducked: false,
+            fading: std::sync::Arc::new(tokio::sync::Mutex::new(None)),
+            fade_handle: None,
         }
     }
 }
@@ -81,42 +149,345 @@ impl MpvPlayer {
         self.action_tx = Some(tx);
     }
 
-    /// Spawn mpv with IPC socket for the given URL.
+    /// OS process id of the running mpv, if any.
+    #[allow(dead_code)] // used by integration tests
+    pub async fn process_id(&self) -> Option<u32> {
+        self.child.lock().await.as_ref().and_then(Child::id)
+    }
+
+    /// Set the cache settings used for subsequent spawns.
+    pub fn set_buffer(&mut self, buffer: BufferSettings) {
+        self.buffer = buffer;
+    }
+
+    /// Push the current cache settings to the running mpv instance,
+    /// restoring mpv's defaults for those left unset.
+    pub async fn apply_buffer(&self) -> anyhow::Result<()> {
+        for (property, value) in self.buffer.ipc_properties() {
+            let value = match value {
+                Some(value) => value,
+                None => self.option_default(property).await?,
+            };
+            let cmd = serde_json::json!({
+                "command": ["set_property", property, value]
+            });
+            ipc::send_command(&self.socket_path, &cmd.to_string()).await?;
+        }
+        Ok(())
+    }
+
+    /// The running mpv's default value for the option `name`.
+    async fn option_default(&self, name: &str) -> anyhow::Result<serde_json::Value> {
+        let cmd = serde_json::json!({
+            "command": ["get_property", format!("option-info/{name}/default-value")]
+        });
+        let response = ipc::send_command(&self.socket_path, &cmd.to_string()).await?;
+        let mut val: serde_json::Value = serde_json::from_str(&response)?;
+        match val.get_mut("data").map(serde_json::Value::take) {
+            Some(data) if !data.is_null() => Ok(data),
+            _ => Err(anyhow::anyhow!("No default for {name}")),
+        }
+    }
+
+    /// Set the user flags appended to every spawn (see
+    /// `PlayerConfig::extra_mpv_args`).
+    pub fn set_extra_args(&mut self, args: Vec<String>) {
+        self.extra_args = args;
+    }
+
+    /// Switch the running mpv's yt-dlp format, used for the files it loads
+    /// from now on. An empty `format` is mpv's default.
+    pub async fn apply_ytdl_format(&self, format: &str) -> anyhow::Result<()> {
+        let cmd = serde_json::json!({
+            "command": ["set_property", "ytdl-format", format]
+        });
+        ipc::send_command(&self.socket_path, &cmd.to_string()).await?;
+        Ok(())
+    }
+
+    /// Run `binary` instead of the mpv on PATH.
+    pub fn set_binary(&mut self, binary: impl Into<String>) {
+        self.binary = binary.into();
+        self.version_checked = false;
+    }
+
+    /// False when the last attempt to start mpv found it missing or too old.
+    pub fn is_available(&self) -> bool {
+        self.available
+    }
+
+    /// Set the equalizer filter (see `equalizer::mpv_filter`) used from the
+    /// next play or spawn on.
+    pub fn set_equalizer(&mut self, filter: Option<String>) {
+        self.equalizer = filter;
+    }
+
+    /// Swap the running mpv's equalizer for the current one, ahead of the
+    /// level and spectrum filters so the visualizer shows its effect.
+    pub async fn apply_equalizer(&self) -> anyhow::Result<()> {
+        let remove = serde_json::json!({
+            "command": ["af", "remove", equalizer::FILTER_LABEL]
+        });
+        // Fails harmlessly when no equalizer is in the chain yet.
+        let _ = ipc::send_command(&self.socket_path, &remove.to_string()).await;
+        if let Some(ref filter) = self.equalizer {
+            let add = serde_json::json!({ "command": ["af", "pre", filter] });
+            ipc::send_command(&self.socket_path, &add.to_string()).await?;
+        }
+        Ok(())
+    }
+
+    /// Play `url`: load it into the running mpv, or start mpv with it if
+    /// none is running or it stopped answering. Either way the current
+    /// equalizer applies.
     pub async fn play(&mut self, url: &str) -> anyhow::Result<()> {
+        self.start(url, false).await
+    }
+
+    /// Like `play`, but hold `url` paused at its start.
+    pub async fn play_paused(&mut self, url: &str) -> anyhow::Result<()> {
+        self.start(url, true).await
+    }
+
+    async fn start(&mut self, url: &str, paused: bool) -> anyhow::Result<()> {
         let tx = self
             .action_tx
             .clone()
             .expect("action_tx must be set before play()");
 
         tx.send(Action::PlaybackLoading).ok();
-        self.stop().await?;
-        // Remove stale socket from a previous mpv instance, if any.
-        let _ = std::fs::remove_file(&self.socket_path);
+        self.stop_track().await;
+        if self.load(url, paused).await {
+            let _ = self.apply_equalizer().await;
+        } else {
+            self.respawn(url, paused, tx).await?;
+        }
+        Ok(())
+    }
+
+    /// Replace the running mpv's file with `url`. Returns false when there's
+    /// no live mpv to load into.
+    async fn load(&self, url: &str, paused: bool) -> bool {
+        let running = match self.child.lock().await.as_mut() {
+            Some(child) => matches!(child.try_wait(), Ok(None)),
+            None => false,
+        };
+        if !running {
+            return false;
+        }
+        // Pause and an A-B loop carry over between files; a new track
+        // starts playing (unless asked not to), start to end.
+        let reset = [
+            serde_json::json!({ "command": ["set_property", "pause", paused] }),
+            serde_json::json!({ "command": ["set_property", "ab-loop-a", "no"] }),
+            serde_json::json!({ "command": ["set_property", "ab-loop-b", "no"] }),
+        ];
+        for command in reset {
+            if ipc::send_command(&self.socket_path, &command.to_string())
+                .await
+                .is_err()
+            {
+                return false;
+            }
+        }
+        let load = serde_json::json!({ "command": ["loadfile", url, "replace"] });
+        ipc::send_command(&self.socket_path, &load.to_string())
+            .await
+            .is_ok()
+    }
+
+    /// Kill any mpv left on the main socket and start a fresh one on `url`.
+    async fn respawn(
+        &mut self,
+        url: &str,
+        paused: bool,
+        tx: mpsc::UnboundedSender<Action>,
+    ) -> anyhow::Result<()> {
+        self.quit_process().await;
+        // mpv can vanish from PATH mid-session, so look for it on every
+        // spawn; the version only needs checking once.
+        let check = preflight::check_mpv(&self.binary, !self.version_checked).await;
+        self.available = check.is_ok();
+        check?;
+        self.version_checked = true;
+        let mut command = self.mpv_command(&self.socket_path, url);
+        if paused {
+            command.arg("--pause");
+        }
+        let child = command
+            .kill_on_drop(true)
+            .spawn()
+            .context("failed to spawn mpv — is it installed?")?;
+        *self.child.lock().await = Some(child);
+        self.spawn_process_watchers(tx);
+        Ok(())
+    }
 
-        let child = Command::new("mpv")
+    /// mpv invocation for `url`, listening for IPC on `socket_path` and
+    /// staying open once the file ends. Logged in full for debugging.
+    fn mpv_command(&self, socket_path: &Path, url: &str) -> Command {
+        if let Some(dir) = socket_path.parent() {
+            // mpv doesn't create it; a failure shows up as mpv not answering.
+            let _ = instance::create_private_dir(dir);
+        }
+        instance::claim_instance();
+        let mut command = Command::new(&self.binary);
+        command
             .arg("--no-video")
             .arg("--no-terminal")
-            .arg(format!("--input-ipc-server={}", self.socket_path.display()))
+            .arg("--idle=yes")
+            // Resume positions are clisten's to keep (watch_later is only
+            // imported from), or a resume would be applied twice.
+            .arg("--no-resume-playback")
+            .arg(format!("--input-ipc-server={}", socket_path.display()))
             .arg("--af=@astats:lavfi=[astats=metadata=1:reset=1:measure_perchannel=none:measure_overall=RMS_level+Peak_level]")
+            .arg(format!("--af-append={}", spectrum::mpv_filter_arg()))
+            .args(self.buffer.mpv_args())
+            .args(self.equalizer.iter().map(|f| format!("--af-pre={f}")))
+            .args(&self.extra_args)
             .arg(url)
             .stdout(Stdio::null())
-            .stderr(Stdio::null())
-            .spawn()
-            .context("failed to spawn mpv — is it installed?")?;
-
-        *self.child.lock().await = Some(child);
+            .stderr(Stdio::null());
+        tracing::info!("mpv command: {}", command_line(&command));
+        command
+    }
 
-        self.poller_handles = vec![
+    /// Start the background tasks that watch the current mpv instance.
+    fn spawn_process_watchers(&mut self, tx: mpsc::UnboundedSender<Action>) {
+        for handle in self.process_handles.drain(..) {
+            handle.abort();
+        }
+        self.process_handles = vec![
             ipc::spawn_exit_monitor(self.child.clone(), tx.clone()),
-            ipc::spawn_position_poller(self.socket_path.clone(), tx.clone()),
-            ipc::spawn_duration_poller(self.socket_path.clone(), tx.clone()),
-            ipc::spawn_metadata_observer(self.socket_path.clone(), tx.clone(), url.to_string()),
+            ipc::spawn_observer(self.socket_path.clone(), tx.clone()),
             ipc::spawn_audio_level_poller(self.socket_path.clone(), tx),
         ];
+    }
 
+    /// Spawn `url` paused and muted on the spare socket, ready to crossfade
+    /// into. Replaces any earlier preload; skipped while a fade is running.
+    pub async fn preload(&mut self, url: &str) -> anyhow::Result<()> {
+        if self.fade_handle.as_ref().is_some_and(|h| !h.is_finished()) {
+            return Ok(());
+        }
+        self.discard_preloaded().await;
+        let child = self
+            .mpv_command(&self.spare_socket_path, url)
+            .arg("--pause")
+            .arg("--volume=0")
+            .kill_on_drop(true)
+            .spawn()
+            .context("failed to spawn mpv — is it installed?")?;
+        self.preloaded = Some(Preloaded {
+            url: url.to_string(),
+            child,
+        });
         Ok(())
     }
 
+    /// URL of the preloaded track, if one is waiting.
+    pub fn preloaded_url(&self) -> Option<&str> {
+        self.preloaded.as_ref().map(|p| p.url.as_str())
+    }
+
+    async fn discard_preloaded(&mut self) {
+        if let Some(mut preloaded) = self.preloaded.take() {
+            let _ = preloaded.child.kill().await;
+        }
+        let _ = std::fs::remove_file(&self.spare_socket_path);
+    }
+
+    /// Fade from the current track to the preloaded one over `secs`. The
+    /// preloaded instance becomes the current one straight away; the old one
+    /// is quit once it has faded out. Fails only when nothing is preloaded.
+    pub async fn crossfade(&mut self, secs: f64) -> anyhow::Result<()> {
+        let Some(next) = self.preloaded.take() else {
+            anyhow::bail!("no track preloaded");
+        };
+        let tx = self
+            .action_tx
+            .clone()
+            .expect("action_tx must be set before crossfade()");
+        let volume = self.get_volume().await.unwrap_or(100.0);
+
+        // Stop watching the outgoing instance, so its end isn't taken for
+        // the end of the new track.
+        for handle in self.process_handles.drain(..) {
+            handle.abort();
+        }
+        self.finish_fade().await;
+        *self.fading.lock().await = self.child.lock().await.take();
+        std::mem::swap(&mut self.socket_path, &mut self.spare_socket_path);
+        *self.child.lock().await = Some(next.child);
+
+        ipc::wait_for_socket(&self.socket_path).await;
+        let _ = ipc::send_command(
+            &self.socket_path,
+            r#"{"command":["set_property","pause",false]}"#,
+        )
+        .await;
+        self.fade_handle = Some(ipc::spawn_crossfade(
+            self.spare_socket_path.clone(),
+            self.socket_path.clone(),
+            self.fading.clone(),
+            volume,
+            secs,
+        ));
+        self.spawn_process_watchers(tx);
+        Ok(())
+    }
+
+    /// Cut a running fade short and kill the outgoing instance.
+    async fn finish_fade(&mut self) {
+        if let Some(handle) = self.fade_handle.take() {
+            handle.abort();
+        }
+        let mut guard = self.fading.lock().await;
+        if let Some(ref mut child) = *guard {
+            let _ = child.kill().await;
+        }
+        *guard = None;
+    }
+
+    /// Play `url` in a separate mpv next to the current track, which is
+    /// turned down until the preview stops. Replaces any earlier preview.
+    pub async fn start_preview(&mut self, url: &str) -> anyhow::Result<()> {
+        self.stop_preview().await;
+        let child = self
+            .mpv_command(&self.preview_socket_path, url)
+            .arg(format!("--volume={}", preview::PREVIEW_VOLUME))
+            .kill_on_drop(true)
+            .spawn()
+            .context("failed to spawn mpv — is it installed?")?;
+        self.preview = Some(Preloaded {
+            url: url.to_string(),
+            child,
+        });
+        // Nothing to turn down when no mpv is running.
+        if let Ok(volume) = self.get_volume().await {
+            self.ducked = self.set_volume_to(preview::ducked(volume)).await.is_ok();
+        }
+        Ok(())
+    }
+
+    /// URL of the preview playing, if any.
+    pub fn preview_url(&self) -> Option<&str> {
+        self.preview.as_ref().map(|p| p.url.as_str())
+    }
+
+    /// Stop the preview and turn the current track back up.
+    pub async fn stop_preview(&mut self) {
+        if let Some(mut preview) = self.preview.take() {
+            let _ = preview.child.kill().await;
+        }
+        let _ = std::fs::remove_file(&self.preview_socket_path);
+        if std::mem::take(&mut self.ducked) {
+            if let Ok(volume) = self.get_volume().await {
+                let _ = self.set_volume_to(preview::unducked(volume)).await;
+            }
+        }
+    }
+
     /// Seek by the given number of seconds (negative = backward).
     pub async fn seek_relative(&self, seconds: f64) -> anyhow::Result<()> {
         ipc::send_command(
@@ -127,38 +498,87 @@ impl MpvPlayer {
         Ok(())
     }
 
+    /// Seek to an absolute position in seconds.
+    pub async fn seek_absolute(&self, seconds: f64) -> anyhow::Result<()> {
+        ipc::send_command(
+            &self.socket_path,
+            &format!(r#"{{"command":["seek",{},"absolute"]}}"#, seconds),
+        )
+        .await?;
+        Ok(())
+    }
+
+    /// Loop playback between the two positions, or stop looping.
+    pub async fn set_ab_loop(&self, ab: Option<(f64, f64)>) -> anyhow::Result<()> {
+        let (a, b) = match ab {
+            Some((a, b)) => (serde_json::json!(a), serde_json::json!(b)),
+            None => (serde_json::json!("no"), serde_json::json!("no")),
+        };
+        for (property, value) in [("ab-loop-a", a), ("ab-loop-b", b)] {
+            let command = serde_json::json!({ "command": ["set_property", property, value] });
+            ipc::send_command(&self.socket_path, &command.to_string()).await?;
+        }
+        Ok(())
+    }
+
     /// Toggle pause on the running mpv instance.
     pub async fn toggle_pause(&self) -> anyhow::Result<()> {
         ipc::send_command(&self.socket_path, r#"{"command":["cycle","pause"]}"#).await?;
         Ok(())
     }
 
-    /// Quit mpv and clean up the IPC socket.
+    /// Stop playback, leaving mpv idle for the next track.
     pub async fn stop(&mut self) -> anyhow::Result<()> {
-        // Abort all background pollers before killing mpv, so they don't
-        // reconnect to a new instance on the same socket path.
-        for handle in self.poller_handles.drain(..) {
+        self.stop_track().await;
+        // Best-effort: mpv may not be running. Either way nothing is playing.
+        let _ = ipc::send_command(&self.socket_path, r#"{"command":["stop"]}"#).await;
+        Ok(())
+    }
+
+    /// Stop playback and quit mpv, on the way out of the app.
+    pub async fn quit(&mut self) {
+        self.stop_preview().await;
+        self.stop_track().await;
+        self.quit_process().await;
+    }
+
+    /// Drop any fade or preload tied to the current track.
+    async fn stop_track(&mut self) {
+        self.finish_fade().await;
+        self.discard_preloaded().await;
+    }
+
+    /// Quit the main mpv instance and clean up its IPC socket.
+    async fn quit_process(&mut self) {
+        // Abort the watchers first so the exit isn't reported as the track
+        // finishing.
+        for handle in self.process_handles.drain(..) {
             handle.abort();
         }
         // Best-effort shutdown: mpv may have already exited or the socket may
         // not exist. Errors are harmless — we just need to ensure cleanup.
         let _ = ipc::send_command(&self.socket_path, r#"{"command":["quit"]}"#).await;
-        let _ = std::fs::remove_file(&self.socket_path);
-        let mut guard = self.child.lock().await;
-        if let Some(ref mut child) = *guard {
-            let _ = child.kill().await;
+        {
+            let mut guard = self.child.lock().await;
+            if let Some(ref mut child) = *guard {
+                let _ = child.kill().await;
+            }
+            *guard = None;
         }
-        *guard = None;
-        Ok(())
+        let _ = std::fs::remove_file(&self.socket_path);
     }
 
     /// Adjust volume by delta (positive = up, negative = down), clamped to 0-100.
     pub async fn set_volume(&self, delta: f64) -> anyhow::Result<()> {
         let current = self.get_volume().await.unwrap_or(50.0);
-        let target = (current + delta).clamp(0.0, 100.0);
+        self.set_volume_to((current + delta).clamp(0.0, 100.0))
+            .await
+    }
+
+    async fn set_volume_to(&self, volume: f64) -> anyhow::Result<()> {
         ipc::send_command(
             &self.socket_path,
-            &format!(r#"{{"command":["set_property","volume",{}]}}"#, target),
+            &format!(r#"{{"command":["set_property","volume",{}]}}"#, volume),
         )
         .await?;
         Ok(())
@@ -180,8 +600,8 @@ impl MpvPlayer {
 
 impl Drop for MpvPlayer {
     fn drop(&mut self) {
-        // Abort all background pollers.
-        for handle in self.poller_handles.drain(..) {
+        // Abort all background watchers.
+        for handle in self.process_handles.drain(..) {
             handle.abort();
         }
         // Best-effort cleanup on drop — try_lock because we can't await.
@@ -191,6 +611,37 @@ impl Drop for MpvPlayer {
             }
             *guard = None;
         }
+        if let Some(handle) = self.fade_handle.take() {
+            handle.abort();
+        }
+        if let Ok(mut guard) = self.fading.try_lock() {
+            if let Some(ref mut child) = *guard {
+                let _ = child.start_kill();
+            }
+            *guard = None;
+        }
+        // Preloaded and preview instances are killed when their Child drops.
+        self.preloaded = None;
+        self.preview = None;
         let _ = std::fs::remove_file(&self.socket_path);
+        let _ = std::fs::remove_file(&self.spare_socket_path);
+        let _ = std::fs::remove_file(&self.preview_socket_path);
     }
 }
+
+/// `command` as a shell-like line, quoting arguments with spaces.
+fn command_line(command: &Command) -> String {
+    let command = command.as_std();
+    std::iter::once(command.get_program())
+        .chain(command.get_args())
+        .map(|arg| {
+            let arg = arg.to_string_lossy();
+            if arg.contains(' ') {
+                format!("'{arg}'")
+            } else {
+                arg.into_owned()
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
@@ -0,0 +1,87 @@
+// mpv preflight: before spawning mpv, make sure the binary is still there and
+// new enough, and turn a missing one into an error that says how to install
+// it instead of a bare spawn failure.
+
+use std::fmt;
+
+use tokio::process::Command;
+
+/// Oldest mpv whose IPC and filter options clisten relies on.
+pub const MIN_MPV_VERSION: (u32, u32) = (0, 33);
+
+/// Why mpv can't be used.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MpvUnavailable {
+    /// The binary isn't on PATH (or at the configured path).
+    Missing { binary: String },
+    /// The binary is older than MIN_MPV_VERSION.
+    TooOld { version: String },
+}
+
+impl fmt::Display for MpvUnavailable {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Missing { binary } => write!(
+                f,
+                "mpv not found ({binary}) — install with: {}, or set mpv_path under [player]",
+                install_hint("mpv")
+            ),
+            Self::TooOld { version } => write!(
+                f,
+                "mpv {version} is too old (need {}.{}+) — install a newer one with: {}",
+                MIN_MPV_VERSION.0,
+                MIN_MPV_VERSION.1,
+                install_hint("mpv")
+            ),
+        }
+    }
+}
+
+impl std::error::Error for MpvUnavailable {}
+
+/// How to install `tool` ("mpv" or "yt-dlp") on this OS, to follow
+/// "Install with: ".
+pub fn install_hint(tool: &str) -> String {
+    if cfg!(target_os = "macos") {
+        format!("brew install {tool}")
+    } else if cfg!(target_os = "linux") {
+        format!("your package manager, e.g. sudo apt install {tool}")
+    } else {
+        format!("the official {tool} release, added to PATH")
+    }
+}
+
+/// (major, minor) from the first line of `mpv --version`, e.g.
+/// "mpv 0.38.0 Copyright …" or "mpv v0.36.0-573-g1a2b3c …".
+pub fn parse_mpv_version(output: &str) -> Option<(u32, u32)> {
+    let word = output.lines().next()?.split_whitespace().nth(1)?;
+    let mut parts = word.trim_start_matches('v').split(['.', '-']);
+    let major = parts.next()?.parse().ok()?;
+    let minor = parts.next()?.parse().ok()?;
+    Some((major, minor))
+}
+
+/// Check that `binary` can be run, and when `check_version` is set that it
+/// is new enough. A version that can't be read is given the benefit of the
+/// doubt.
+pub async fn check_mpv(binary: &str, check_version: bool) -> Result<(), MpvUnavailable> {
+    let missing = || MpvUnavailable::Missing {
+        binary: binary.to_string(),
+    };
+    which::which(binary).map_err(|_| missing())?;
+    if !check_version {
+        return Ok(());
+    }
+    let output = Command::new(binary)
+        .arg("--version")
+        .output()
+        .await
+        .map_err(|_| missing())?;
+    let text = String::from_utf8_lossy(&output.stdout);
+    match parse_mpv_version(&text) {
+        Some(version) if version < MIN_MPV_VERSION => Err(MpvUnavailable::TooOld {
+            version: format!("{}.{}", version.0, version.1),
+        }),
+        _ => Ok(()),
+    }
+}
@@ -0,0 +1,20 @@
+// Previews: another item played in its own mpv next to the current track for
+// a few seconds, with the current track turned down ("ducked") meanwhile.
+
+/// How long a preview plays before it stops by itself.
+pub const PREVIEW_SECS: f64 = 15.0;
+/// Volume of the preview's mpv.
+pub const PREVIEW_VOLUME: f64 = 60.0;
+/// Share of its volume the current track keeps while a preview plays.
+pub const DUCK_FACTOR: f64 = 0.25;
+
+/// The current track's volume while ducked from `volume`.
+pub fn ducked(volume: f64) -> f64 {
+    volume * DUCK_FACTOR
+}
+
+/// The volume to bring a ducked track back up to, within 0–100. Changes
+/// made while ducked carry over, scaled up the same way.
+pub fn unducked(volume: f64) -> f64 {
+    (volume / DUCK_FACTOR).clamp(0.0, 100.0)
+}
@@ -1,7 +1,7 @@
 // Ordered playback queue with a cursor pointing at the current track.
 
 use super::StreamMetadata;
-use crate::api::models::DiscoveryItem;
+use crate::api::models::{DiscoveryItem, UrlMeta};
 
 /// A single entry in the playback queue.
 #[derive(Debug, Clone)]
@@ -11,6 +11,49 @@ pub struct QueueItem {
     pub stream_metadata: Option<StreamMetadata>,
 }
 
+/// How long a queue entry runs: as mpv measured it when it last played, or
+/// estimated from where its tracklist ends.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TrackLength {
+    Measured(f64),
+    Estimated(f64),
+}
+
+impl TrackLength {
+    pub fn secs(self) -> f64 {
+        match self {
+            Self::Measured(secs) | Self::Estimated(secs) => secs,
+        }
+    }
+
+    pub fn is_estimate(self) -> bool {
+        matches!(self, Self::Estimated(_))
+    }
+}
+
+/// The summed length of the entries after the current one.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct QueueTime {
+    pub secs: f64,
+    /// Some lengths were estimated or are not known at all.
+    pub approximate: bool,
+}
+
+impl QueueTime {
+    /// Add up the lengths after `current` (all of them when nothing is
+    /// current).
+    pub fn after(lengths: &[Option<TrackLength>], current: Option<usize>) -> Self {
+        let start = current.map_or(0, |i| i + 1);
+        lengths
+            .iter()
+            .skip(start)
+            .fold(Self::default(), |total, length| Self {
+                secs: total.secs + length.map_or(0.0, TrackLength::secs),
+                approximate: total.approximate || length.is_none_or(TrackLength::is_estimate),
+            })
+    }
+}
+
 /// Ordered playback queue with a cursor pointing at the current track.
 #[derive(Default)]
 pub struct Queue {
@@ -40,6 +83,17 @@ impl Queue {
         }
     }
 
+    /// Insert item at `index` (clamped to the end), keeping the cursor on the same track.
+    pub fn insert(&mut self, index: usize, item: QueueItem) {
+        let index = index.min(self.items.len());
+        self.items.insert(index, item);
+        match self.current_index {
+            Some(curr) if index <= curr => self.current_index = Some(curr + 1),
+            None => self.current_index = Some(0),
+            _ => {}
+        }
+    }
+
     pub fn remove(&mut self, index: usize) {
         if index < self.items.len() {
             self.items.remove(index);
@@ -168,6 +222,32 @@ impl Queue {
         changed
     }
 
+    /// Fold what yt-dlp found for `url` into the direct URL entries with
+    /// that URL. Returns the updated item, if any entry changed.
+    pub fn update_direct_urls(
+        &mut self,
+        url: &str,
+        title: Option<&str>,
+        meta: &UrlMeta,
+    ) -> Option<DiscoveryItem> {
+        let mut updated = None;
+        for qi in &mut self.items {
+            if qi.item.apply_url_meta(url, title, meta) {
+                updated = Some(qi.item.clone());
+            }
+        }
+        updated
+    }
+
+    /// Replace the playback URL of the current item (e.g. after a source fallback).
+    pub fn set_current_url(&mut self, url: String) {
+        if let Some(i) = self.current_index {
+            if let Some(item) = self.items.get_mut(i) {
+                item.url = url;
+            }
+        }
+    }
+
     /// Update the stream metadata of the current item (e.g. from ICY metadata).
     pub fn set_current_stream_metadata(&mut self, metadata: StreamMetadata) {
         if let Some(i) = self.current_index {
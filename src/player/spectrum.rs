@@ -0,0 +1,74 @@
+// Frequency-band analysis inside mpv: a lavfi graph splits the audio into
+// band-passed copies, measures each with astats, and passes the original through.
+
+use serde_json::{Map, Value};
+
+/// Band centre frequencies in Hz, roughly log-spaced across the audible range.
+pub const BAND_CENTERS_HZ: [u32; 12] = [
+    40, 70, 120, 200, 350, 600, 1000, 1700, 2900, 5000, 8500, 14000,
+];
+
+/// mpv filter label; the band levels are read from `af-metadata/<label>`.
+pub const FILTER_LABEL: &str = "spectrum";
+
+// Band levels are scaled linearly in dB between this floor and 0 dBFS.
+const BAND_FLOOR_DB: f64 = -70.0;
+// Bandwidth of each band-pass filter, in octaves.
+const BAND_WIDTH_OCTAVES: f64 = 0.8;
+
+/// The lavfi graph. The analysis branch is downmixed to mono, split per band,
+/// and merged into one channel per band so a single astats reports them all.
+/// amerge copies frame metadata from its first input, so the stats branch
+/// goes first and the pan keeps only the original stereo channels.
+pub fn filter_graph() -> String {
+    let n = BAND_CENTERS_HZ.len();
+    let mut graph = format!(
+        "aformat=channel_layouts=stereo,asplit=2[orig][ana];\
+         [ana]pan=mono|c0=0.5*c0+0.5*c1,asplit={}",
+        n
+    );
+    for i in 0..n {
+        graph.push_str(&format!("[s{}]", i));
+    }
+    graph.push(';');
+    for (i, hz) in BAND_CENTERS_HZ.iter().enumerate() {
+        graph.push_str(&format!(
+            "[s{i}]bandpass=f={hz}:width_type=o:w={BAND_WIDTH_OCTAVES}[b{i}];"
+        ));
+    }
+    for i in 0..n {
+        graph.push_str(&format!("[b{}]", i));
+    }
+    graph.push_str(&format!(
+        "amerge=inputs={n},astats=metadata=1:reset=1:measure_perchannel=RMS_level:measure_overall=none[bands];\
+         [bands][orig]amerge=inputs=2,pan=stereo|c0=c{n}|c1=c{}",
+        n + 1
+    ));
+    graph
+}
+
+/// The `--af-append` value. The graph is length-quoted (`%N%`) because it
+/// contains brackets, commas, and colons that mpv's option parser would split on.
+pub fn mpv_filter_arg() -> String {
+    let graph = filter_graph();
+    format!("@{}:lavfi=graph=%{}%{}", FILTER_LABEL, graph.len(), graph)
+}
+
+/// Per-band levels (0.0–1.0) from an `af-metadata/spectrum` object, or None
+/// if any band is missing.
+pub fn parse_bands(data: &Map<String, Value>) -> Option<Vec<f64>> {
+    (1..=BAND_CENTERS_HZ.len())
+        .map(|ch| {
+            let db = data
+                .get(&format!("lavfi.astats.{}.RMS_level", ch))?
+                .as_str()?
+                .parse::<f64>()
+                .ok()?;
+            // astats reports "-inf" for digital silence, which parses to -∞.
+            if db.is_nan() {
+                return Some(0.0);
+            }
+            Some(((db - BAND_FLOOR_DB) / -BAND_FLOOR_DB).clamp(0.0, 1.0))
+        })
+        .collect()
+}
@@ -0,0 +1,52 @@
+// Reader for mpv's watch_later directory, so positions saved by plain mpv
+// sessions (quit-watch-later / save-position-on-quit) can be carried over.
+
+use std::path::{Path, PathBuf};
+
+/// Directories mpv may keep watch_later files in, current layout first.
+pub fn default_dirs() -> Vec<PathBuf> {
+    let mut dirs = Vec::new();
+    if let Some(state) = dirs::state_dir() {
+        dirs.push(state.join("mpv").join("watch_later"));
+    }
+    if let Some(home) = dirs::home_dir() {
+        for legacy in [".local/state/mpv", ".config/mpv", ".mpv"] {
+            let dir = home.join(legacy).join("watch_later");
+            if !dirs.contains(&dir) {
+                dirs.push(dir);
+            }
+        }
+    }
+    dirs
+}
+
+/// File name mpv uses for a path or URL: the uppercase hex MD5 of it.
+pub fn entry_name(url: &str) -> String {
+    format!("{:X}", md5::compute(url.as_bytes()))
+}
+
+/// Extract the saved `start=` position from a watch_later file.
+pub fn parse_start(contents: &str) -> Option<f64> {
+    contents
+        .lines()
+        .find_map(|line| line.trim().strip_prefix("start="))
+        .and_then(|v| v.parse::<f64>().ok())
+        .filter(|secs| secs.is_finite() && *secs > 0.0)
+}
+
+/// Look up a saved position for `url` in any of `dirs`.
+/// Returns the entry name alongside the position in seconds.
+pub fn find_position(dirs: &[PathBuf], url: &str) -> Option<(String, f64)> {
+    let name = entry_name(url);
+    dirs.iter().find_map(|dir| {
+        let secs = read_start(&dir.join(&name))?;
+        Some((name.clone(), secs))
+    })
+}
+
+fn read_start(path: &Path) -> Option<f64> {
+    std::fs::read_to_string(path)
+        .ok()
+        .as_deref()
+        .and_then(parse_start)
+}
@@ -0,0 +1,93 @@
+// Page metadata for direct URLs. mpv plays Bandcamp, YouTube, and other
+// pages through yt-dlp but only hands back the stream, so a separate
+// `yt-dlp --dump-json` run asks for the title, artist, length, and
+// thumbnail, without downloading anything.
+
+use std::process::Stdio;
+use std::time::Duration;
+
+use serde_json::Value;
+use tokio::process::Command;
+
+use crate::api::models::UrlMeta;
+use crate::config::NetworkConfig;
+
+// The lookup is given up on after this long.
+const RESOLVE_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// What a page resolved to.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ResolvedUrl {
+    pub title: Option<String>,
+    pub meta: UrlMeta,
+}
+
+/// Read the fields clisten uses out of yt-dlp's `--dump-json` output.
+/// Music sites fill in `track` and `artist`; elsewhere the page title and
+/// the uploader stand in for them. None when it isn't a JSON object.
+pub fn parse_dump(json: &str) -> Option<ResolvedUrl> {
+    let value: Value = serde_json::from_str(json.trim()).ok()?;
+    let text = |key: &str| {
+        value[key]
+            .as_str()
+            .map(str::trim)
+            .filter(|s| !s.is_empty())
+            .map(String::from)
+    };
+    if !value.is_object() {
+        return None;
+    }
+    let site = text("extractor_key").and_then(|key| match key.as_str() {
+        "Generic" => None,
+        "Youtube" => Some("YouTube".to_string()),
+        _ => Some(key),
+    });
+    Some(ResolvedUrl {
+        title: text("track").or_else(|| text("title")),
+        meta: UrlMeta {
+            artist: text("artist")
+                .or_else(|| text("creator"))
+                .or_else(|| text("uploader"))
+                .or_else(|| text("channel")),
+            duration_secs: value["duration"].as_f64().filter(|d| *d > 0.0),
+            thumbnail: text("thumbnail"),
+            site,
+        },
+    })
+}
+
+/// The yt-dlp command that prints `url`'s metadata as one JSON object,
+/// going through `network`'s proxy and User-Agent like playback does.
+pub fn dump_command(url: &str, network: &NetworkConfig) -> Command {
+    let mut command = Command::new("yt-dlp");
+    if let Some(proxy) = &network.proxy {
+        command.arg("--proxy").arg(proxy);
+    }
+    if let Some(user_agent) = &network.user_agent {
+        command.arg("--user-agent").arg(user_agent);
+    }
+    command
+        .arg("--dump-json")
+        .arg("--no-playlist")
+        .arg("--skip-download")
+        .arg("--no-warnings")
+        .arg("--")
+        .arg(url)
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null())
+        .kill_on_drop(true);
+    command
+}
+
+/// Run `command` (see `dump_command`) and parse what it prints.
+pub async fn resolve(mut command: Command) -> anyhow::Result<ResolvedUrl> {
+    let output = tokio::time::timeout(RESOLVE_TIMEOUT, command.output())
+        .await
+        .map_err(|_| anyhow::anyhow!("yt-dlp timed out"))??;
+    if !output.status.success() {
+        anyhow::bail!("yt-dlp exited with {}", output.status);
+    }
+    parse_dump(&String::from_utf8_lossy(&output.stdout))
+        .ok_or_else(|| anyhow::anyhow!("yt-dlp printed no metadata"))
+}
@@ -0,0 +1,160 @@
+// Playlist files for other players: writes the queue or favorites as M3U
+// (.m3u / .m3u8) or OPML (radio streams only), and reads M3U files back as
+// direct-URL items.
+
+use std::path::Path;
+
+use crate::api::models::DiscoveryItem;
+
+/// One playable line of a playlist.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PlaylistEntry {
+    pub title: String,
+    pub url: String,
+}
+
+/// Playlist formats, chosen by file extension.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PlaylistFormat {
+    M3u,
+    Opml,
+}
+
+impl PlaylistFormat {
+    pub fn from_path(path: &Path) -> Option<Self> {
+        let ext = path.extension()?.to_str()?.to_ascii_lowercase();
+        match ext.as_str() {
+            "m3u" | "m3u8" => Some(Self::M3u),
+            "opml" => Some(Self::Opml),
+            _ => None,
+        }
+    }
+}
+
+/// Entries for every item with a playback URL; genres are skipped.
+pub fn entries_for(items: &[DiscoveryItem]) -> Vec<PlaylistEntry> {
+    items
+        .iter()
+        .filter_map(|item| {
+            Some(PlaylistEntry {
+                title: item.display_title(),
+                url: item.playback_url()?,
+            })
+        })
+        .collect()
+}
+
+/// Extended M3U, one `#EXTINF` line per entry.
+pub fn to_m3u(entries: &[PlaylistEntry]) -> String {
+    let mut out = String::from("#EXTM3U\n");
+    for entry in entries {
+        // A newline in a title would end the #EXTINF line early.
+        let title = entry.title.replace(['\r', '\n'], " ");
+        out.push_str(&format!("#EXTINF:-1,{}\n{}\n", title, entry.url));
+    }
+    out
+}
+
+/// OPML 2.0 outline of audio streams, as read by radio apps.
+pub fn to_opml(entries: &[PlaylistEntry], title: &str) -> String {
+    let mut out = format!(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<opml version=\"2.0\">\n  <head>\n    <title>{}</title>\n  </head>\n  <body>\n",
+        xml_escape(title)
+    );
+    for entry in entries {
+        out.push_str(&format!(
+            "    <outline type=\"audio\" text=\"{}\" URL=\"{}\"/>\n",
+            xml_escape(&entry.title),
+            xml_escape(&entry.url)
+        ));
+    }
+    out.push_str("  </body>\n</opml>\n");
+    out
+}
+
+fn xml_escape(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// Parse an M3U (plain or extended). Only http(s) URLs are kept; titles come
+/// from the preceding `#EXTINF` line, if any.
+pub fn parse_m3u(text: &str) -> Vec<PlaylistEntry> {
+    let mut entries = Vec::new();
+    let mut title = None;
+    for line in text.trim_start_matches('\u{feff}').lines() {
+        let line = line.trim();
+        if let Some(info) = line.strip_prefix("#EXTINF:") {
+            title = info
+                .split_once(',')
+                .map(|(_, t)| t.trim().to_string())
+                .filter(|t| !t.is_empty());
+        } else if line.is_empty() || line.starts_with('#') {
+            continue;
+        } else {
+            if line.starts_with("http://") || line.starts_with("https://") {
+                entries.push(PlaylistEntry {
+                    title: title.clone().unwrap_or_else(|| line.to_string()),
+                    url: line.to_string(),
+                });
+            }
+            title = None;
+        }
+    }
+    entries
+}
+
+/// Write `items` to `path` in the format its extension names. OPML only
+/// lists radio streams (live channels and direct URLs). Returns how many
+/// entries were written.
+pub fn write(path: &Path, items: &[DiscoveryItem], title: &str) -> anyhow::Result<usize> {
+    let Some(format) = PlaylistFormat::from_path(path) else {
+        anyhow::bail!("{} is not a .m3u, .m3u8, or .opml file", path.display());
+    };
+    let (contents, count) = match format {
+        PlaylistFormat::M3u => {
+            let entries = entries_for(items);
+            (to_m3u(&entries), entries.len())
+        }
+        PlaylistFormat::Opml => {
+            let streams: Vec<DiscoveryItem> = items
+                .iter()
+                .filter(|item| {
+                    matches!(
+                        item,
+                        DiscoveryItem::NtsLiveChannel { .. } | DiscoveryItem::DirectUrl { .. }
+                    )
+                })
+                .cloned()
+                .collect();
+            let entries = entries_for(&streams);
+            (to_opml(&entries, title), entries.len())
+        }
+    };
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    std::fs::write(path, contents)?;
+    Ok(count)
+}
+
+/// Read an M3U file as direct-URL items.
+pub fn read_m3u(path: &Path) -> anyhow::Result<Vec<DiscoveryItem>> {
+    if PlaylistFormat::from_path(path) != Some(PlaylistFormat::M3u) {
+        anyhow::bail!("{} is not a .m3u or .m3u8 file", path.display());
+    }
+    let text = std::fs::read_to_string(path)?;
+    Ok(parse_m3u(&text)
+        .into_iter()
+        .map(|entry| {
+            let title = (entry.title != entry.url).then_some(entry.title);
+            DiscoveryItem::DirectUrl {
+                url: entry.url,
+                title,
+                meta: None,
+            }
+        })
+        .collect())
+}
@@ -0,0 +1,111 @@
+// System sleep and wake. On Linux, logind announces both and holds off the
+// suspend for a moment so playback can pause first. Everywhere, a sleep also
+// shows afterwards as the wall clock having jumped ahead of the monotonic one,
+// which doesn't advance while the machine is suspended.
+
+use std::time::{Duration, Instant, SystemTime};
+
+// Clock gaps shorter than this are scheduling noise or small time fixes.
+const MIN_SLEEP: Duration = Duration::from_secs(10);
+
+/// Notices sleeps by comparing the two clocks between checks.
+#[derive(Debug, Clone, Copy)]
+pub struct SleepClock {
+    wall: SystemTime,
+    monotonic: Instant,
+}
+
+impl Default for SleepClock {
+    fn default() -> Self {
+        Self {
+            wall: SystemTime::now(),
+            monotonic: Instant::now(),
+        }
+    }
+}
+
+impl SleepClock {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// How long the machine slept since the last check, if it did.
+    pub fn check(&mut self) -> Option<Duration> {
+        self.check_at(SystemTime::now(), Instant::now())
+    }
+
+    /// `check` with the clocks reading `wall` and `monotonic`.
+    pub fn check_at(&mut self, wall: SystemTime, monotonic: Instant) -> Option<Duration> {
+        let wall_elapsed = wall.duration_since(self.wall).unwrap_or_default();
+        let awake = monotonic.saturating_duration_since(self.monotonic);
+        self.wall = wall;
+        self.monotonic = monotonic;
+        wall_elapsed
+            .checked_sub(awake)
+            .filter(|asleep| *asleep >= MIN_SLEEP)
+    }
+}
+
+#[cfg(target_os = "linux")]
+pub use logind::spawn_sleep_watch;
+
+#[cfg(target_os = "linux")]
+mod logind {
+    use std::time::Duration;
+
+    use futures_util::StreamExt;
+    use tokio::sync::mpsc;
+    use zbus::zvariant::OwnedFd;
+    use zbus::{Connection, Proxy};
+
+    use crate::action::Action;
+
+    // Time given to pause before the delay lock is let go. logind waits at
+    // most InhibitDelayMaxSec (5s by default) either way.
+    const PAUSE_GRACE: Duration = Duration::from_millis(500);
+
+    /// Send SystemSleep and SystemWake as logind announces them. Fails when
+    /// there's no system bus or no logind on it.
+    pub async fn spawn_sleep_watch(tx: mpsc::UnboundedSender<Action>) -> anyhow::Result<()> {
+        let connection = Connection::system().await?;
+        let manager = Proxy::new(
+            &connection,
+            "org.freedesktop.login1",
+            "/org/freedesktop/login1",
+            "org.freedesktop.login1.Manager",
+        )
+        .await?;
+        let mut signals = manager.receive_signal("PrepareForSleep").await?;
+        let mut lock = inhibit(&manager).await;
+        tokio::spawn(async move {
+            while let Some(signal) = signals.next().await {
+                let Ok(going_to_sleep) = signal.body().deserialize::<bool>() else {
+                    continue;
+                };
+                if going_to_sleep {
+                    tx.send(Action::SystemSleep).ok();
+                    tokio::time::sleep(PAUSE_GRACE).await;
+                    // Closing the lock's descriptor lets the suspend go ahead.
+                    lock = None;
+                } else {
+                    tx.send(Action::SystemWake).ok();
+                    lock = inhibit(&manager).await;
+                }
+            }
+            drop(lock);
+        });
+        Ok(())
+    }
+
+    /// A delay lock on sleep, held until dropped; None if logind refused.
+    async fn inhibit(manager: &Proxy<'_>) -> Option<OwnedFd> {
+        manager
+            .call(
+                "Inhibit",
+                &("sleep", "clisten", "Pausing playback", "delay"),
+            )
+            .await
+            .inspect_err(|e| tracing::debug!("no sleep delay lock: {e}"))
+            .ok()
+    }
+}
@@ -0,0 +1,91 @@
+// Listening profile behind the For You tab: how often genres and shows come
+// up in the play history and favorites, and which genre ids to search for.
+
+use std::collections::HashMap;
+
+use crate::api::genres::GenreCategory;
+use crate::api::models::DiscoveryItem;
+use crate::db::{Favorite, HistoryEntry};
+
+/// A favorite counts as this many plays.
+const FAVORITE_WEIGHT: u32 = 3;
+
+/// Genres and shows ranked by how much they're listened to.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Taste {
+    /// Genre names, most listened first.
+    pub genres: Vec<String>,
+    /// Show aliases, most listened first.
+    pub shows: Vec<String>,
+}
+
+/// Rank the genres and shows of everything played (by play count) and
+/// favorited. Ties keep the order they were first seen in, newest first.
+pub fn taste(history: &[HistoryEntry], favorites: &[Favorite]) -> Taste {
+    let mut genres = Tally::default();
+    let mut shows = Tally::default();
+    let weighted = history
+        .iter()
+        .map(|entry| (&entry.item, entry.play_count))
+        .chain(favorites.iter().map(|f| (&f.item, FAVORITE_WEIGHT)));
+    for (item, weight) in weighted {
+        match item {
+            DiscoveryItem::NtsEpisode {
+                genres: names,
+                show_alias,
+                ..
+            } => {
+                for name in names {
+                    genres.add(name, weight);
+                }
+                if !show_alias.is_empty() {
+                    shows.add(show_alias, weight);
+                }
+            }
+            DiscoveryItem::NtsLiveChannel { genres: names, .. } => {
+                for name in names {
+                    genres.add(name, weight);
+                }
+            }
+            DiscoveryItem::NtsGenre { name, .. } => genres.add(name, weight),
+            _ => {}
+        }
+    }
+    Taste {
+        genres: genres.ranked(),
+        shows: shows.ranked(),
+    }
+}
+
+/// Id of the genre called `name` (any case) in the genre tree.
+pub fn genre_id_by_name<'a>(categories: &'a [GenreCategory], name: &str) -> Option<&'a str> {
+    categories.iter().find_map(|category| {
+        if category.name.eq_ignore_ascii_case(name) {
+            return Some(category.id.as_str());
+        }
+        category
+            .genres
+            .iter()
+            .find(|(_, genre)| genre.eq_ignore_ascii_case(name))
+            .map(|(id, _)| id.as_str())
+    })
+}
+
+/// Scores keyed by name, remembering when each name was first seen.
+#[derive(Default)]
+struct Tally {
+    scores: HashMap<String, (u32, usize)>,
+}
+
+impl Tally {
+    fn add(&mut self, name: &str, weight: u32) {
+        let seen = self.scores.len();
+        self.scores.entry(name.to_string()).or_insert((0, seen)).0 += weight;
+    }
+
+    fn ranked(self) -> Vec<String> {
+        let mut ranked: Vec<(String, (u32, usize))> = self.scores.into_iter().collect();
+        ranked.sort_by_key(|(_, (score, seen))| (std::cmp::Reverse(*score), *seen));
+        ranked.into_iter().map(|(name, _)| name).collect()
+    }
+}
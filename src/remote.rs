@@ -0,0 +1,401 @@
+// Remote control server: accepts play/pause/next/stop/status/queue commands on
+// a Unix socket (one command per line, one JSON reply per line) and, when
+// configured, over plain HTTP for phones and scripts on the network.
+// `clisten ctl <command>` is the matching client.
+
+use std::path::{Path, PathBuf};
+
+use serde::Serialize;
+use serde_json::{json, Value};
+use tokio::io::{AsyncBufReadExt, AsyncRead, AsyncReadExt, AsyncWriteExt, BufReader};
+use tokio::net::{TcpListener, UnixListener, UnixStream};
+use tokio::sync::{mpsc, watch};
+
+use crate::action::Action;
+use crate::config::RemoteConfig;
+use crate::db;
+
+const SOCKET_FILE: &str = "remote.sock";
+/// Commands that only read state; everything else changes playback.
+const QUERIES: &[&str] = &["status", "queue"];
+/// Every command the server understands.
+pub const COMMANDS: &[&str] = &[
+    "status", "queue", "play", "pause", "toggle", "next", "prev", "stop",
+];
+// Longest HTTP request head read before giving up on a client.
+const MAX_REQUEST_BYTES: u64 = 8192;
+
+/// The control socket of the running app.
+pub fn socket_path() -> PathBuf {
+    db::data_dir().join(SOCKET_FILE)
+}
+
+/// One queue entry as shown to remote clients.
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct RemoteTrack {
+    pub title: String,
+    pub subtitle: String,
+    pub url: String,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RemoteStatus {
+    #[default]
+    Stopped,
+    Playing,
+    Paused,
+}
+
+/// Snapshot of the queue and playback state served to clients.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RemoteState {
+    pub tracks: Vec<RemoteTrack>,
+    pub current: Option<usize>,
+    pub status: RemoteStatus,
+    pub position_secs: f64,
+    pub duration_secs: Option<f64>,
+}
+
+/// Handle kept by the app to publish state to the server tasks.
+pub struct RemoteHandle {
+    state_tx: watch::Sender<RemoteState>,
+    socket: PathBuf,
+}
+
+impl RemoteHandle {
+    /// Publish new state; unchanged state is not re-sent.
+    pub fn update(&self, state: RemoteState) {
+        self.state_tx.send_if_modified(|current| {
+            if *current == state {
+                false
+            } else {
+                *current = state;
+                true
+            }
+        });
+    }
+}
+
+impl Drop for RemoteHandle {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_file(&self.socket);
+    }
+}
+
+/// Run `command` against `state`, sending any resulting action. The reply
+/// always has an `ok` field; failures carry an `error` message.
+pub fn handle_command(
+    command: &str,
+    state: &RemoteState,
+    action_tx: &mpsc::UnboundedSender<Action>,
+) -> Value {
+    let action = match command {
+        "status" => {
+            return json!({
+                "ok": true,
+                "status": state.status,
+                "track": state.current.and_then(|i| state.tracks.get(i)),
+                "position_secs": state.position_secs,
+                "duration_secs": state.duration_secs,
+            })
+        }
+        "queue" => {
+            return json!({
+                "ok": true,
+                "current": state.current,
+                "tracks": state.tracks,
+            })
+        }
+        "toggle" => Some(Action::TogglePlayPause),
+        "next" => Some(Action::NextTrack),
+        "prev" => Some(Action::PrevTrack),
+        "stop" => Some(Action::Stop),
+        "pause" => (state.status == RemoteStatus::Playing).then_some(Action::TogglePlayPause),
+        "play" => match (state.status, state.current) {
+            (RemoteStatus::Paused, _) => Some(Action::TogglePlayPause),
+            (RemoteStatus::Stopped, Some(index)) => Some(Action::PlayQueueIndex(index)),
+            _ => None,
+        },
+        other => {
+            return json!({
+                "ok": false,
+                "error": format!("unknown command `{other}`; try one of: {}", COMMANDS.join(", ")),
+            })
+        }
+    };
+    if let Some(action) = action {
+        if action_tx.send(action).is_err() {
+            return json!({ "ok": false, "error": "clisten is shutting down" });
+        }
+    }
+    json!({ "ok": true })
+}
+
+/// Start listening on the control socket, and on `config.http` if set.
+/// Fails if another instance already owns the socket.
+pub async fn spawn(
+    config: &RemoteConfig,
+    socket: &Path,
+    action_tx: mpsc::UnboundedSender<Action>,
+) -> anyhow::Result<RemoteHandle> {
+    if UnixStream::connect(socket).await.is_ok() {
+        anyhow::bail!(
+            "another clisten is already listening on {}",
+            socket.display()
+        );
+    }
+    let _ = std::fs::remove_file(socket);
+    if let Some(dir) = socket.parent() {
+        std::fs::create_dir_all(dir)?;
+    }
+    let unix = UnixListener::bind(socket)?;
+    let http = match config.http.as_deref() {
+        Some(addr) => Some(TcpListener::bind(addr).await?),
+        None => None,
+    };
+
+    let (state_tx, state_rx) = watch::channel(RemoteState::default());
+    tokio::spawn(serve_unix(unix, state_rx.clone(), action_tx.clone()));
+    if let Some(http) = http {
+        tokio::spawn(serve_http(http, config.token.clone(), state_rx, action_tx));
+    }
+    Ok(RemoteHandle {
+        state_tx,
+        socket: socket.to_path_buf(),
+    })
+}
+
+async fn serve_unix(
+    listener: UnixListener,
+    state_rx: watch::Receiver<RemoteState>,
+    action_tx: mpsc::UnboundedSender<Action>,
+) {
+    while let Ok((stream, _)) = listener.accept().await {
+        let state_rx = state_rx.clone();
+        let action_tx = action_tx.clone();
+        tokio::spawn(async move {
+            let (read, mut write) = stream.into_split();
+            let mut lines = BufReader::new(read).lines();
+            while let Ok(Some(line)) = lines.next_line().await {
+                let reply = handle_command(line.trim(), &state_rx.borrow(), &action_tx);
+                if write
+                    .write_all(format!("{reply}\n").as_bytes())
+                    .await
+                    .is_err()
+                {
+                    break;
+                }
+            }
+        });
+    }
+}
+
+async fn serve_http(
+    listener: TcpListener,
+    token: Option<String>,
+    state_rx: watch::Receiver<RemoteState>,
+    action_tx: mpsc::UnboundedSender<Action>,
+) {
+    while let Ok((mut stream, _)) = listener.accept().await {
+        let state_rx = state_rx.clone();
+        let action_tx = action_tx.clone();
+        let token = token.clone();
+        tokio::spawn(async move {
+            let (read, mut write) = stream.split();
+            let response = match read_request(read).await {
+                Some(request) => {
+                    http_response(&request, token.as_deref(), &state_rx.borrow(), &action_tx)
+                }
+                None => (400, json!({ "ok": false, "error": "bad request" })),
+            };
+            let _ = write.write_all(encode_response(response).as_bytes()).await;
+        });
+    }
+}
+
+/// The parts of an HTTP request the server looks at.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct HttpRequest {
+    pub method: String,
+    /// Path without the query string.
+    pub path: String,
+    /// `token` from the query string or an `Authorization: Bearer` header.
+    pub token: Option<String>,
+}
+
+/// Parse a request head (request line and headers); None if malformed.
+pub fn parse_request(head: &str) -> Option<HttpRequest> {
+    let mut lines = head.lines();
+    let mut parts = lines.next()?.split_whitespace();
+    let method = parts.next()?.to_string();
+    let target = parts.next()?;
+    let (path, query) = target.split_once('?').unwrap_or((target, ""));
+    let mut token = query
+        .split('&')
+        .find_map(|pair| pair.strip_prefix("token="))
+        .map(String::from);
+    for line in lines {
+        if let Some((name, value)) = line.split_once(':') {
+            if name.trim().eq_ignore_ascii_case("authorization") {
+                if let Some(bearer) = value.trim().strip_prefix("Bearer ") {
+                    token = Some(bearer.trim().to_string());
+                }
+            }
+        }
+    }
+    Some(HttpRequest {
+        method,
+        path: path.to_string(),
+        token,
+    })
+}
+
+async fn read_request(read: impl AsyncRead + Unpin) -> Option<HttpRequest> {
+    let mut reader = BufReader::new(read.take(MAX_REQUEST_BYTES));
+    let mut head = String::new();
+    loop {
+        let mut line = String::new();
+        if reader.read_line(&mut line).await.ok()? == 0 {
+            return None;
+        }
+        if line.trim().is_empty() {
+            break;
+        }
+        head.push_str(&line);
+    }
+    parse_request(&head)
+}
+
+/// Status code and JSON body for a request. `/` is the status; queries take
+/// GET, commands POST, so link previews and prefetchers can't change playback.
+pub fn http_response(
+    request: &HttpRequest,
+    token: Option<&str>,
+    state: &RemoteState,
+    action_tx: &mpsc::UnboundedSender<Action>,
+) -> (u16, Value) {
+    if token.is_some() && request.token.as_deref() != token {
+        return (
+            401,
+            json!({ "ok": false, "error": "missing or wrong token" }),
+        );
+    }
+    let command = match request.path.trim_matches('/') {
+        "" => "status",
+        command => command,
+    };
+    if !COMMANDS.contains(&command) {
+        return (404, handle_command(command, state, action_tx));
+    }
+    let method = if QUERIES.contains(&command) {
+        "GET"
+    } else {
+        "POST"
+    };
+    if request.method != method {
+        let error = format!("use {method} for `{command}`");
+        return (405, json!({ "ok": false, "error": error }));
+    }
+    (200, handle_command(command, state, action_tx))
+}
+
+fn encode_response((status, body): (u16, Value)) -> String {
+    let reason = match status {
+        200 => "OK",
+        400 => "Bad Request",
+        401 => "Unauthorized",
+        404 => "Not Found",
+        _ => "Method Not Allowed",
+    };
+    let body = body.to_string();
+    format!(
+        "HTTP/1.1 {status} {reason}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+        body.len()
+    )
+}
+
+/// `clisten ctl <command> [--json]`: send one command to the running app
+/// and print the reply. Returns whether it succeeded.
+pub async fn run_ctl(args: &[String]) -> bool {
+    let raw = args.iter().any(|a| a == "--json");
+    let command: Vec<&str> = args
+        .iter()
+        .map(String::as_str)
+        .filter(|a| *a != "--json")
+        .collect();
+    let [command] = command.as_slice() else {
+        eprintln!(
+            "usage: clisten ctl <command> [--json]\ncommands: {}",
+            COMMANDS.join(", ")
+        );
+        return false;
+    };
+    let reply = match request(&socket_path(), command).await {
+        Ok(reply) => reply,
+        Err(e) => {
+            eprintln!("Error: {e}");
+            return false;
+        }
+    };
+    let ok = reply["ok"].as_bool().unwrap_or(false);
+    if raw {
+        println!("{reply}");
+    } else if let Some(error) = reply["error"].as_str() {
+        eprintln!("Error: {error}");
+    } else {
+        print!("{}", describe(command, &reply));
+    }
+    ok
+}
+
+/// Send `command` over the control socket and read the reply.
+pub async fn request(socket: &Path, command: &str) -> anyhow::Result<Value> {
+    let stream = UnixStream::connect(socket).await.map_err(|_| {
+        anyhow::anyhow!(
+            "no clisten is listening on {} — set `enabled = true` under [remote] and start clisten",
+            socket.display()
+        )
+    })?;
+    let (read, mut write) = stream.into_split();
+    write.write_all(format!("{command}\n").as_bytes()).await?;
+    let mut line = String::new();
+    BufReader::new(read).read_line(&mut line).await?;
+    Ok(serde_json::from_str(&line)?)
+}
+
+/// Human-readable form of a reply, for `clisten ctl` without `--json`.
+pub fn describe(command: &str, reply: &Value) -> String {
+    let time = |secs: &Value| secs.as_f64().map(crate::components::format_time);
+    match command {
+        "status" => {
+            let status = reply["status"].as_str().unwrap_or("stopped");
+            let Some(title) = reply["track"]["title"].as_str() else {
+                return format!("{status}\n");
+            };
+            let position = match (time(&reply["position_secs"]), time(&reply["duration_secs"])) {
+                (Some(pos), Some(dur)) => format!("  {pos} / {dur}"),
+                (Some(pos), None) => format!("  {pos}"),
+                _ => String::new(),
+            };
+            format!("{status}: {title}{position}\n")
+        }
+        "queue" => {
+            let current = reply["current"].as_u64().map(|i| i as usize);
+            let tracks = reply["tracks"].as_array().cloned().unwrap_or_default();
+            if tracks.is_empty() {
+                return "queue is empty\n".to_string();
+            }
+            tracks
+                .iter()
+                .enumerate()
+                .map(|(i, track)| {
+                    let marker = if Some(i) == current { "▶" } else { " " };
+                    let title = track["title"].as_str().unwrap_or_default();
+                    format!("{marker} {:>2}. {title}\n", i + 1)
+                })
+                .collect()
+        }
+        _ => String::new(),
+    }
+}
@@ -0,0 +1,158 @@
+// `clisten schedule export`: writes the upcoming NTS live schedule as an
+// iCalendar (.ics) file so the shows turn up in a calendar app. Only
+// broadcasts of followed shows by default; `--all` includes every one.
+
+use std::collections::HashSet;
+use std::path::PathBuf;
+
+use crate::api::models::{unix_now, ScheduledBroadcast};
+use crate::config::Config;
+use crate::db::{self, Database};
+
+const USAGE: &str = "usage: clisten schedule export [--all] [--out FILE]";
+// Longest content line in octets before RFC 5545 folds it.
+const FOLD_AT: usize = 75;
+
+/// Where the schedule is written without `--out`.
+pub fn default_path() -> PathBuf {
+    db::data_dir().join("schedule.ics")
+}
+
+/// `clisten schedule ...`. Returns whether the command succeeded.
+pub async fn run_cli(args: &[String], config: &Config) -> bool {
+    let result = match args.first().map(String::as_str) {
+        Some("export") => export_cli(&args[1..], config).await,
+        _ => Err(anyhow::anyhow!(USAGE)),
+    };
+    match result {
+        Ok(message) => {
+            println!("{message}");
+            true
+        }
+        Err(e) => {
+            eprintln!("Error: {e}");
+            false
+        }
+    }
+}
+
+async fn export_cli(args: &[String], config: &Config) -> anyhow::Result<String> {
+    let mut path = default_path();
+    let mut all = false;
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--out" | "-o" => match args.next() {
+                Some(p) => path = PathBuf::from(p),
+                None => anyhow::bail!(USAGE),
+            },
+            "--all" => all = true,
+            _ => anyhow::bail!(USAGE),
+        }
+    }
+    let mut broadcasts = config.nts_client().fetch_schedule().await?;
+    if !all {
+        let followed: HashSet<String> = Database::open()?
+            .followed_shows()?
+            .into_iter()
+            .map(|show| show.show_alias)
+            .collect();
+        if followed.is_empty() {
+            anyhow::bail!("not following any shows yet: press F on an episode, or pass --all");
+        }
+        broadcasts.retain(|b| b.show_alias.as_ref().is_some_and(|a| followed.contains(a)));
+    }
+    std::fs::write(&path, to_ics(&broadcasts, unix_now()))?;
+    Ok(format!(
+        "Wrote {} broadcasts to {}",
+        broadcasts.len(),
+        path.display()
+    ))
+}
+
+/// `broadcasts` as an iCalendar file, one event each. UIDs come from the
+/// channel and start time, so importing a newer export updates the events
+/// rather than duplicating them.
+pub fn to_ics(broadcasts: &[ScheduledBroadcast], now: u64) -> String {
+    let mut lines = vec![
+        "BEGIN:VCALENDAR".to_string(),
+        "VERSION:2.0".to_string(),
+        "PRODID:-//clisten//NTS schedule//EN".to_string(),
+        "X-WR-CALNAME:NTS".to_string(),
+    ];
+    for broadcast in broadcasts {
+        lines.push("BEGIN:VEVENT".to_string());
+        lines.push(format!(
+            "UID:nts-{}-{}@clisten",
+            broadcast.channel, broadcast.start
+        ));
+        lines.push(format!("DTSTAMP:{}", ics_time(now)));
+        lines.push(format!("DTSTART:{}", ics_time(broadcast.start)));
+        lines.push(format!("DTEND:{}", ics_time(broadcast.end)));
+        lines.push(format!("SUMMARY:{}", escape(&broadcast.title)));
+        lines.push(format!("LOCATION:NTS {}", broadcast.channel));
+        if let Some(alias) = &broadcast.show_alias {
+            lines.push(format!("URL:https://www.nts.live/shows/{alias}"));
+        }
+        lines.push("END:VEVENT".to_string());
+    }
+    lines.push("END:VCALENDAR".to_string());
+    lines.iter().map(|line| fold(line) + "\r\n").collect()
+}
+
+/// A Unix time as an iCalendar UTC date-time, e.g. "20260218T100000Z".
+fn ics_time(unix: u64) -> String {
+    let days = (unix / 86_400) as i64;
+    let secs = unix % 86_400;
+    // Civil date from days since the epoch (Howard Hinnant's algorithm).
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let day_of_era = z - era * 146_097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let mp = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+    format!(
+        "{year:04}{month:02}{day:02}T{:02}{:02}{:02}Z",
+        secs / 3600,
+        secs / 60 % 60,
+        secs % 60
+    )
+}
+
+/// Text escaped for an iCalendar property value.
+fn escape(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '\\' | ';' | ',' => {
+                out.push('\\');
+                out.push(c);
+            }
+            '\n' => out.push_str("\\n"),
+            '\r' => {}
+            _ => out.push(c),
+        }
+    }
+    out
+}
+
+/// A content line folded to at most `FOLD_AT` octets per line, never
+/// splitting a UTF-8 character.
+fn fold(line: &str) -> String {
+    let mut out = String::with_capacity(line.len());
+    let mut used = 0;
+    for c in line.chars() {
+        if used + c.len_utf8() > FOLD_AT {
+            out.push_str("\r\n ");
+            // The leading space counts towards the next line.
+            used = 1;
+        }
+        used += c.len_utf8();
+        out.push(c);
+    }
+    out
+}
@@ -1,10 +1,68 @@
-// Color theme definitions for the TUI chrome. Visualizer palettes are
-// self-contained and unaffected by theme choice.
+// Color theme definitions: the TUI chrome plus the palette the visualizers
+// draw with. Presets are selected by name from config or onboarding. On
+// terminals without truecolor, every finished frame has its RGB colors
+// swapped for the nearest the terminal has (see ColorSupport).
 
+use ratatui::buffer::Buffer;
 use ratatui::style::Color;
+use serde::{Deserialize, Serialize};
 
 pub const THEME_DARK: &str = "dark";
 pub const THEME_LIGHT: &str = "light";
+pub const THEME_TOKYO_NIGHT: &str = "tokyo-night";
+pub const THEME_GRUVBOX: &str = "gruvbox";
+pub const THEME_CATPPUCCIN: &str = "catppuccin";
+
+/// Built-in presets as (config name, display label), in menu order.
+pub const THEME_PRESETS: &[(&str, &str)] = &[
+    (THEME_DARK, "Dark"),
+    (THEME_LIGHT, "Light"),
+    (THEME_TOKYO_NIGHT, "Tokyo Night"),
+    (THEME_GRUVBOX, "Gruvbox"),
+    (THEME_CATPPUCCIN, "Catppuccin"),
+];
+
+const fn rgb(hex: u32) -> Color {
+    Color::from_u32(hex)
+}
+
+/// Colors the visualizers draw with.
+#[derive(Debug, Clone)]
+pub struct VisualizerPalette {
+    /// Cool accents cycled by the rings, waveform, and blob.
+    pub accents: [Color; 6],
+    /// Warm colors for the blob's second phase.
+    pub warm: [Color; 4],
+    /// Spectrum bar gradient from low to high level.
+    pub levels: [Color; 3],
+    /// Brightest color: peak caps and near stars.
+    pub highlight: Color,
+    /// Faint trails and idle cells.
+    pub dim: Color,
+    /// What fading rings blend into.
+    pub background: Color,
+}
+
+impl Default for VisualizerPalette {
+    /// The classic ANSI palette, which reads well on both dark and light terminals.
+    fn default() -> Self {
+        Self {
+            accents: [
+                Color::Cyan,
+                Color::Magenta,
+                Color::LightBlue,
+                Color::LightMagenta,
+                Color::LightCyan,
+                Color::Blue,
+            ],
+            warm: [Color::Yellow, Color::Red, Color::Magenta, Color::LightRed],
+            levels: [Color::Green, Color::Yellow, Color::Magenta],
+            highlight: Color::White,
+            dim: Color::DarkGray,
+            background: Color::Black,
+        }
+    }
+}
 
 /// Named color slots used by the UI chrome (borders, text, status indicators).
 #[derive(Debug, Clone)]
@@ -31,6 +89,8 @@ pub struct Theme {
     pub success: Color,
     /// Buffering indicator color.
     pub buffering: Color,
+    /// Visualizer colors.
+    pub visualizer: VisualizerPalette,
 }
 
 impl Theme {
@@ -47,6 +107,7 @@ impl Theme {
             warning: Color::Yellow,
             success: Color::Green,
             buffering: Color::Yellow,
+            visualizer: VisualizerPalette::default(),
         }
     }
 
@@ -63,12 +124,113 @@ impl Theme {
             warning: Color::Rgb(180, 120, 0),
             success: Color::Rgb(0, 140, 60),
             buffering: Color::Rgb(180, 120, 0),
+            visualizer: VisualizerPalette::default(),
+        }
+    }
+
+    /// Tokyo Night ("night" variant).
+    pub fn tokyo_night() -> Self {
+        Self {
+            primary: rgb(0x7aa2f7),
+            secondary: rgb(0xbb9af7),
+            text: rgb(0xc0caf5),
+            text_dim: rgb(0x565f89),
+            accent: rgb(0xe0af68),
+            selection_bg: rgb(0x283457),
+            border: rgb(0x3b4261),
+            error: rgb(0xf7768e),
+            warning: rgb(0xe0af68),
+            success: rgb(0x9ece6a),
+            buffering: rgb(0xff9e64),
+            visualizer: VisualizerPalette {
+                accents: [
+                    rgb(0x7dcfff),
+                    rgb(0xbb9af7),
+                    rgb(0x7aa2f7),
+                    rgb(0x9d7cd8),
+                    rgb(0x2ac3de),
+                    rgb(0x3d59a1),
+                ],
+                warm: [rgb(0xe0af68), rgb(0xf7768e), rgb(0xbb9af7), rgb(0xff9e64)],
+                levels: [rgb(0x9ece6a), rgb(0xe0af68), rgb(0xf7768e)],
+                highlight: rgb(0xc0caf5),
+                dim: rgb(0x565f89),
+                background: rgb(0x1a1b26),
+            },
+        }
+    }
+
+    /// Gruvbox (dark, medium contrast).
+    pub fn gruvbox() -> Self {
+        Self {
+            primary: rgb(0xfe8019),
+            secondary: rgb(0x8ec07c),
+            text: rgb(0xebdbb2),
+            text_dim: rgb(0x928374),
+            accent: rgb(0xfabd2f),
+            selection_bg: rgb(0x3c3836),
+            border: rgb(0x504945),
+            error: rgb(0xfb4934),
+            warning: rgb(0xfabd2f),
+            success: rgb(0xb8bb26),
+            buffering: rgb(0xfe8019),
+            visualizer: VisualizerPalette {
+                accents: [
+                    rgb(0x8ec07c),
+                    rgb(0xd3869b),
+                    rgb(0x83a598),
+                    rgb(0xb16286),
+                    rgb(0x689d6a),
+                    rgb(0x458588),
+                ],
+                warm: [rgb(0xfabd2f), rgb(0xfb4934), rgb(0xd3869b), rgb(0xfe8019)],
+                levels: [rgb(0xb8bb26), rgb(0xfabd2f), rgb(0xfb4934)],
+                highlight: rgb(0xebdbb2),
+                dim: rgb(0x928374),
+                background: rgb(0x282828),
+            },
         }
     }
 
+    /// Catppuccin Mocha.
+    pub fn catppuccin() -> Self {
+        Self {
+            primary: rgb(0xcba6f7),
+            secondary: rgb(0xf5c2e7),
+            text: rgb(0xcdd6f4),
+            text_dim: rgb(0x6c7086),
+            accent: rgb(0xf9e2af),
+            selection_bg: rgb(0x313244),
+            border: rgb(0x45475a),
+            error: rgb(0xf38ba8),
+            warning: rgb(0xf9e2af),
+            success: rgb(0xa6e3a1),
+            buffering: rgb(0xfab387),
+            visualizer: VisualizerPalette {
+                accents: [
+                    rgb(0x89dceb),
+                    rgb(0xcba6f7),
+                    rgb(0x89b4fa),
+                    rgb(0xf5c2e7),
+                    rgb(0x94e2d5),
+                    rgb(0x74c7ec),
+                ],
+                warm: [rgb(0xf9e2af), rgb(0xf38ba8), rgb(0xcba6f7), rgb(0xfab387)],
+                levels: [rgb(0xa6e3a1), rgb(0xf9e2af), rgb(0xf38ba8)],
+                highlight: rgb(0xcdd6f4),
+                dim: rgb(0x6c7086),
+                background: rgb(0x1e1e2e),
+            },
+        }
+    }
+
+    /// Look up a preset by config name; unknown names fall back to dark.
     pub fn from_name(name: &str) -> Self {
         match name {
             THEME_LIGHT => Self::light(),
+            THEME_TOKYO_NIGHT => Self::tokyo_night(),
+            THEME_GRUVBOX => Self::gruvbox(),
+            THEME_CATPPUCCIN => Self::catppuccin(),
             _ => Self::dark(),
         }
     }
@@ -79,3 +241,166 @@ impl Default for Theme {
         Self::dark()
     }
 }
+
+/// How many colors the terminal can show. Detected from the environment
+/// unless `colors` is set under `[general]`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ColorSupport {
+    /// 24-bit RGB: colors are drawn as they are.
+    #[serde(rename = "truecolor")]
+    TrueColor,
+    /// The xterm 256-color palette.
+    #[serde(rename = "256")]
+    Ansi256,
+    /// Only the 16 ANSI colors.
+    #[serde(rename = "16")]
+    Ansi16,
+}
+
+// The xterm 256-color cube's channel levels.
+const CUBE_LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+
+const ANSI_16: [Color; 16] = [
+    Color::Black,
+    Color::Red,
+    Color::Green,
+    Color::Yellow,
+    Color::Blue,
+    Color::Magenta,
+    Color::Cyan,
+    Color::Gray,
+    Color::DarkGray,
+    Color::LightRed,
+    Color::LightGreen,
+    Color::LightYellow,
+    Color::LightBlue,
+    Color::LightMagenta,
+    Color::LightCyan,
+    Color::White,
+];
+
+impl ColorSupport {
+    /// What the terminal we run in supports, going by `COLORTERM` and
+    /// `TERM`.
+    pub fn detect() -> Self {
+        Self::from_env(
+            std::env::var("COLORTERM").ok().as_deref(),
+            std::env::var("TERM").ok().as_deref(),
+        )
+    }
+
+    /// `COLORTERM=truecolor` (or `24bit`) and `TERM`s naming direct color
+    /// mean RGB, a `256color` TERM the 256 palette, and any other TERM 16
+    /// colors. Without a TERM (the Windows terminals) RGB is assumed.
+    pub fn from_env(colorterm: Option<&str>, term: Option<&str>) -> Self {
+        let colorterm = colorterm.unwrap_or_default().to_ascii_lowercase();
+        if colorterm == "truecolor" || colorterm == "24bit" {
+            return Self::TrueColor;
+        }
+        let Some(term) = term.map(str::to_ascii_lowercase) else {
+            return Self::TrueColor;
+        };
+        if ["truecolor", "24bit", "direct"]
+            .iter()
+            .any(|name| term.contains(name))
+        {
+            Self::TrueColor
+        } else if term.contains("256color") {
+            Self::Ansi256
+        } else {
+            Self::Ansi16
+        }
+    }
+
+    /// The closest color to `color` the terminal can show. Named colors are
+    /// kept as they are.
+    pub fn quantize(self, color: Color) -> Color {
+        match (self, color) {
+            (Self::TrueColor, _) => color,
+            (Self::Ansi256, Color::Rgb(r, g, b)) => Color::Indexed(nearest_256(r, g, b)),
+            (Self::Ansi16, Color::Rgb(..)) | (Self::Ansi16, Color::Indexed(16..)) => {
+                let rgb = color_to_rgb(color);
+                ANSI_16
+                    .into_iter()
+                    .min_by_key(|c| distance(rgb, color_to_rgb(*c)))
+                    .unwrap_or(color)
+            }
+            _ => color,
+        }
+    }
+
+    /// Quantize every color in a drawn frame.
+    pub fn quantize_buffer(self, buffer: &mut Buffer) {
+        if self == Self::TrueColor {
+            return;
+        }
+        for cell in &mut buffer.content {
+            cell.fg = self.quantize(cell.fg);
+            cell.bg = self.quantize(cell.bg);
+        }
+    }
+}
+
+/// Index of the closest xterm 256-palette color: the nearer of the 6x6x6
+/// cube entry and the gray ramp entry.
+fn nearest_256(r: u8, g: u8, b: u8) -> u8 {
+    let level = |v: u8| {
+        (0..CUBE_LEVELS.len())
+            .min_by_key(|&i| CUBE_LEVELS[i].abs_diff(v))
+            .unwrap_or(0)
+    };
+    let (ri, gi, bi) = (level(r), level(g), level(b));
+    let cube = (CUBE_LEVELS[ri], CUBE_LEVELS[gi], CUBE_LEVELS[bi]);
+    let average = (u16::from(r) + u16::from(g) + u16::from(b)) / 3;
+    // Gray ramp 232–255 runs from 8 to 238 in steps of 10.
+    let step = ((average.saturating_sub(3)) / 10).min(23) as u8;
+    let gray = 8 + step * 10;
+    if distance((r, g, b), (gray, gray, gray)) < distance((r, g, b), cube) {
+        232 + step
+    } else {
+        16 + 36 * ri as u8 + 6 * gi as u8 + bi as u8
+    }
+}
+
+fn distance(a: (u8, u8, u8), b: (u8, u8, u8)) -> u32 {
+    let d = |x: u8, y: u8| u32::from(x.abs_diff(y)).pow(2);
+    d(a.0, b.0) + d(a.1, b.1) + d(a.2, b.2)
+}
+
+/// RGB components of a ratatui Color: named colors as typical terminals
+/// show them, indexed ones from the xterm palette.
+pub fn color_to_rgb(c: Color) -> (u8, u8, u8) {
+    match c {
+        Color::Rgb(r, g, b) => (r, g, b),
+        Color::Black => (0, 0, 0),
+        Color::Red => (205, 49, 49),
+        Color::Green => (13, 188, 121),
+        Color::Yellow => (229, 229, 16),
+        Color::Blue => (36, 114, 200),
+        Color::Magenta => (188, 63, 188),
+        Color::Cyan => (17, 168, 205),
+        Color::Gray => (204, 204, 204),
+        Color::White => (229, 229, 229),
+        Color::DarkGray => (118, 118, 118),
+        Color::LightRed => (241, 76, 76),
+        Color::LightGreen => (35, 209, 139),
+        Color::LightYellow => (245, 245, 67),
+        Color::LightBlue => (59, 142, 234),
+        Color::LightMagenta => (214, 112, 214),
+        Color::LightCyan => (41, 184, 219),
+        Color::Indexed(i @ 0..=15) => color_to_rgb(ANSI_16[usize::from(i)]),
+        Color::Indexed(i @ 16..=231) => {
+            let i = usize::from(i - 16);
+            (
+                CUBE_LEVELS[i / 36],
+                CUBE_LEVELS[i / 6 % 6],
+                CUBE_LEVELS[i % 6],
+            )
+        }
+        Color::Indexed(i) => {
+            let v = 8 + (i - 232) * 10;
+            (v, v, v)
+        }
+        _ => (180, 180, 180),
+    }
+}
@@ -0,0 +1,163 @@
+// Action traces for reproducing bugs. `clisten --record FILE` writes every
+// action the app handles to FILE, one JSON line each with the milliseconds
+// since startup; `clisten --replay FILE` feeds them back through
+// handle_action against a FakePlayer, a fixture API, and an in-memory
+// database, then prints the screen it ends on.
+
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::Path;
+use std::time::Instant;
+
+use serde::{Deserialize, Serialize};
+
+use crate::action::Action;
+use crate::api::fixture::FixtureApi;
+use crate::app::App;
+use crate::config::Config;
+use crate::db::Database;
+use crate::player::fake::FakePlayer;
+
+// Screen size for the final frame when stdout isn't a terminal.
+const REPLAY_SCREEN: (u16, u16) = (100, 30);
+
+/// One line of a trace.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TraceEntry {
+    /// Milliseconds since recording started.
+    pub at_ms: u64,
+    pub action: Action,
+}
+
+/// Appends the actions the app handles to a trace file.
+pub struct ActionRecorder {
+    writer: BufWriter<File>,
+    started: Instant,
+}
+
+impl ActionRecorder {
+    /// Start a trace at `path`, replacing any file there.
+    pub fn create(path: &Path) -> anyhow::Result<Self> {
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+        Ok(Self {
+            writer: BufWriter::new(File::create(path)?),
+            started: Instant::now(),
+        })
+    }
+
+    /// Write `action` out, flushed so a crash keeps everything up to it.
+    /// Audio levels are left out: they only feed the visualizer and arrive
+    /// many times a second.
+    pub fn record(&mut self, action: &Action) {
+        if matches!(action, Action::AudioLevels { .. } | Action::AudioBands(_)) {
+            return;
+        }
+        let entry = TraceEntry {
+            at_ms: self.started.elapsed().as_millis() as u64,
+            action: action.clone(),
+        };
+        if let Ok(line) = serde_json::to_string(&entry) {
+            let _ = writeln!(self.writer, "{line}");
+            let _ = self.writer.flush();
+        }
+    }
+}
+
+/// Read a trace, skipping lines that don't parse (e.g. the last one, cut off
+/// by a crash).
+pub fn read_trace(path: &Path) -> anyhow::Result<Vec<TraceEntry>> {
+    let text = std::fs::read_to_string(path)
+        .map_err(|e| anyhow::anyhow!("can't read {}: {e}", path.display()))?;
+    Ok(text
+        .lines()
+        .filter_map(|line| serde_json::from_str(line).ok())
+        .collect())
+}
+
+/// What a replay did.
+pub struct Replay {
+    /// The app after the last action.
+    pub app: App,
+    pub applied: usize,
+    /// Actions left out because they reach outside the app.
+    pub skipped: usize,
+    /// (entry index, error) of the actions that failed.
+    pub failures: Vec<(usize, String)>,
+}
+
+/// Whether replaying `action` would open a browser or touch the clipboard.
+fn reaches_outside(action: &Action) -> bool {
+    matches!(
+        action,
+        Action::OpenInBrowser(_) | Action::CopyToClipboard(_)
+    )
+}
+
+/// Feed `entries` through a fresh app with default settings, a FakePlayer,
+/// an API that has nothing, and an empty in-memory database. Actions the app
+/// queues itself are dropped: the trace already holds the ones it handled.
+pub async fn replay(entries: &[TraceEntry]) -> anyhow::Result<Replay> {
+    let app = App::with_db(Config::default(), Database::open_in_memory()?)?
+        .with_player(FakePlayer::new())
+        .with_nts_api(FixtureApi::new());
+    let mut replay = Replay {
+        app,
+        applied: 0,
+        skipped: 0,
+        failures: Vec::new(),
+    };
+    for (index, entry) in entries.iter().enumerate() {
+        if reaches_outside(&entry.action) {
+            replay.skipped += 1;
+            continue;
+        }
+        if let Err(e) = replay.app.replay_action(entry.action.clone()).await {
+            replay.failures.push((index, e.to_string()));
+        }
+        replay.applied += 1;
+    }
+    Ok(replay)
+}
+
+/// `clisten --replay FILE`. Returns whether every action replayed cleanly.
+pub async fn run_replay(path: Option<&String>) -> bool {
+    let Some(path) = path else {
+        eprintln!("Error: usage: clisten --replay FILE");
+        return false;
+    };
+    let result = async {
+        let entries = read_trace(Path::new(path))?;
+        let replay = replay(&entries).await?;
+        let (width, height) = crossterm::terminal::size().unwrap_or(REPLAY_SCREEN);
+        let screen = replay.app.render_to_string(width, height)?;
+        anyhow::Ok((entries, replay, screen))
+    }
+    .await;
+    match result {
+        Ok((entries, replay, screen)) => {
+            println!("{screen}");
+            for (index, error) in &replay.failures {
+                let entry = &entries[*index];
+                println!(
+                    "entry {} (+{}ms) {:?}: {error}",
+                    index + 1,
+                    entry.at_ms,
+                    entry.action
+                );
+            }
+            println!(
+                "Replayed {} actions ({} skipped, {} failed)",
+                replay.applied,
+                replay.skipped,
+                replay.failures.len()
+            );
+            replay.failures.is_empty()
+        }
+        Err(e) => {
+            eprintln!("Error: {e}");
+            false
+        }
+    }
+}
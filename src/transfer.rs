@@ -0,0 +1,246 @@
+// Export and import of favorites, play history, and the queue as one JSON
+// file, for moving data between machines. `clisten export` / `clisten import`
+// on the command line; `E` / `I` in the app. Given a .m3u, .m3u8, or .opml
+// path, the CLI writes or reads a playlist instead (see playlist.rs).
+
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use crate::api::models::DiscoveryItem;
+use crate::db::{self, Database, ExportedFavorite, ExportedPlay};
+use crate::player::queue::QueueItem;
+use crate::playlist::{self, PlaylistFormat};
+
+/// Bumped when the file layout changes incompatibly.
+pub const FORMAT_VERSION: u32 = 1;
+
+/// A queued item as written to an export file.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExportedQueueItem {
+    pub item: DiscoveryItem,
+    pub url: String,
+}
+
+/// Contents of an export file.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExportFile {
+    pub version: u32,
+    #[serde(default)]
+    pub favorites: Vec<ExportedFavorite>,
+    #[serde(default)]
+    pub history: Vec<ExportedPlay>,
+    #[serde(default)]
+    pub queue: Vec<ExportedQueueItem>,
+}
+
+/// How many favorites, plays, and queue items were exported or imported.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct TransferCounts {
+    pub favorites: usize,
+    pub plays: usize,
+    pub queued: usize,
+}
+
+impl fmt::Display for TransferCounts {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} favorites, {} plays, {} queue items",
+            self.favorites, self.plays, self.queued
+        )
+    }
+}
+
+/// Where the CLI exports to and imports from without a path.
+pub fn default_path() -> PathBuf {
+    db::data_dir().join("export.json")
+}
+
+impl ExportFile {
+    /// Snapshot the database's favorites and history plus `queue`.
+    pub fn collect(db: &Database, queue: &[QueueItem]) -> anyhow::Result<Self> {
+        Ok(Self {
+            version: FORMAT_VERSION,
+            favorites: db.export_favorites()?,
+            history: db.export_history()?,
+            queue: queue
+                .iter()
+                .map(|qi| ExportedQueueItem {
+                    item: qi.item.clone(),
+                    url: qi.url.clone(),
+                })
+                .collect(),
+        })
+    }
+
+    pub fn counts(&self) -> TransferCounts {
+        TransferCounts {
+            favorites: self.favorites.len(),
+            plays: self.history.len(),
+            queued: self.queue.len(),
+        }
+    }
+
+    pub fn write(&self, path: &Path) -> anyhow::Result<()> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(path, serde_json::to_string_pretty(self)?)?;
+        Ok(())
+    }
+
+    pub fn read(path: &Path) -> anyhow::Result<Self> {
+        let file: Self = serde_json::from_str(&std::fs::read_to_string(path)?)?;
+        if file.version > FORMAT_VERSION {
+            anyhow::bail!(
+                "{} was written by a newer clisten (format {})",
+                path.display(),
+                file.version
+            );
+        }
+        Ok(file)
+    }
+
+    /// Merge into `db`, keyed on each item's favorite_key(): existing
+    /// favorites and plays are kept as they are. Returns what was added and
+    /// the queue items not already in `queue`, for the caller to append.
+    pub fn import_into(
+        &self,
+        db: &Database,
+        queue: &[QueueItem],
+    ) -> anyhow::Result<(TransferCounts, Vec<QueueItem>)> {
+        let mut counts = TransferCounts::default();
+        for favorite in &self.favorites {
+            if db.import_favorite(favorite)? {
+                counts.favorites += 1;
+            }
+        }
+        for play in &self.history {
+            if db.import_play(play)? {
+                counts.plays += 1;
+            }
+        }
+        let mut keys: Vec<String> = queue.iter().map(|qi| qi.item.favorite_key()).collect();
+        let mut new_items = Vec::new();
+        for queued in &self.queue {
+            let key = queued.item.favorite_key();
+            if keys.contains(&key) {
+                continue;
+            }
+            keys.push(key);
+            new_items.push(QueueItem {
+                item: queued.item.clone(),
+                url: queued.url.clone(),
+                stream_metadata: None,
+            });
+        }
+        counts.queued = new_items.len();
+        Ok((counts, new_items))
+    }
+}
+
+/// Append items whose key isn't in `queue` yet; returns how many were added.
+pub fn append_new(queue: &mut Vec<QueueItem>, items: Vec<DiscoveryItem>) -> usize {
+    let before = queue.len();
+    for item in items {
+        let key = item.favorite_key();
+        if queue.iter().any(|qi| qi.item.favorite_key() == key) {
+            continue;
+        }
+        let Some(url) = item.playback_url() else {
+            continue;
+        };
+        queue.push(QueueItem {
+            item,
+            url,
+            stream_metadata: None,
+        });
+    }
+    queue.len() - before
+}
+
+/// `clisten export [--favorites] [--out FILE]` and `clisten import [FILE]`.
+/// Returns whether the command succeeded.
+pub fn run_cli(args: &[String]) -> bool {
+    let result = match args.first().map(String::as_str) {
+        Some("export") => export_cli(&args[1..]),
+        Some("import") => import_cli(&args[1..]),
+        _ => Err(anyhow::anyhow!("expected `export` or `import`")),
+    };
+    match result {
+        Ok(message) => {
+            println!("{message}");
+            true
+        }
+        Err(e) => {
+            eprintln!("Error: {e}");
+            false
+        }
+    }
+}
+
+fn export_cli(args: &[String]) -> anyhow::Result<String> {
+    const USAGE: &str = "usage: clisten export [--favorites] [--out FILE]";
+    let mut path = default_path();
+    let mut favorites = false;
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--out" | "-o" => match args.next() {
+                Some(p) => path = PathBuf::from(p),
+                None => anyhow::bail!(USAGE),
+            },
+            "--favorites" => favorites = true,
+            _ => anyhow::bail!(USAGE),
+        }
+    }
+    let db = Database::open()?;
+    let (queue, _) = db.load_queue()?;
+
+    // Playlists hold either the queue or the favorites.
+    if PlaylistFormat::from_path(&path).is_some() {
+        let (items, title): (Vec<DiscoveryItem>, _) = if favorites {
+            let items = db.favorites()?.into_iter().map(|f| f.item).collect();
+            (items, "clisten favorites")
+        } else {
+            (
+                queue.into_iter().map(|qi| qi.item).collect(),
+                "clisten queue",
+            )
+        };
+        let count = playlist::write(&path, &items, title)?;
+        return Ok(format!("Wrote {} entries to {}", count, path.display()));
+    }
+    if favorites {
+        anyhow::bail!("--favorites needs a .m3u, .m3u8, or .opml output file");
+    }
+    let file = ExportFile::collect(&db, &queue)?;
+    file.write(&path)?;
+    Ok(format!("Exported {} to {}", file.counts(), path.display()))
+}
+
+fn import_cli(args: &[String]) -> anyhow::Result<String> {
+    let path = match args {
+        [] => default_path(),
+        [path] => PathBuf::from(path),
+        _ => anyhow::bail!("usage: clisten import [FILE]"),
+    };
+    let db = Database::open()?;
+    let (mut queue, current) = db.load_queue()?;
+
+    // An M3U playlist is appended to the queue.
+    if PlaylistFormat::from_path(&path).is_some() {
+        let items = playlist::read_m3u(&path)?;
+        let added = append_new(&mut queue, items);
+        db.save_queue(&queue, current)?;
+        return Ok(format!("Queued {} entries from {}", added, path.display()));
+    }
+
+    let file = ExportFile::read(&path)?;
+    let (counts, new_items) = file.import_into(&db, &queue)?;
+    queue.extend(new_items);
+    db.save_queue(&queue, current)?;
+    Ok(format!("Imported {} from {}", counts, path.display()))
+}
@@ -1,15 +1,19 @@
 // Terminal backend: raw-mode setup, event polling, and tick generation.
-// Wraps crossterm + ratatui so the rest of the app just sees key/resize/tick events.
+// Wraps crossterm + ratatui so the rest of the app just sees key/mouse/resize/tick events.
 
 use crossterm::{
-    event::{self, Event as CrosstermEvent, KeyEvent, KeyEventKind},
+    event::{
+        self, DisableMouseCapture, EnableMouseCapture, Event as CrosstermEvent, KeyEvent,
+        KeyEventKind, MouseEvent, MouseEventKind,
+    },
     execute,
-    terminal::{self, EnterAlternateScreen, LeaveAlternateScreen},
+    terminal::{self, EnterAlternateScreen, LeaveAlternateScreen, SetTitle},
 };
 use futures_util::StreamExt;
 use ratatui::{backend::CrosstermBackend, Terminal};
+use std::io::Write;
 use std::time::Duration;
-use tokio::sync::mpsc;
+use tokio::sync::{mpsc, watch};
 
 type CrosstermTerminal = Terminal<CrosstermBackend<std::io::Stderr>>;
 
@@ -17,12 +21,14 @@ pub struct Tui {
     terminal: CrosstermTerminal,
     pub event_rx: mpsc::UnboundedReceiver<TuiEvent>,
     event_tx: mpsc::UnboundedSender<TuiEvent>,
-    frame_rate: f64,
+    /// Ticks per second; the polling task picks up changes.
+    frame_rate: watch::Sender<f64>,
 }
 
 #[derive(Debug)]
 pub enum TuiEvent {
     Key(KeyEvent),
+    Mouse(MouseEvent),
     Resize,
     Tick,
 }
@@ -36,13 +42,13 @@ impl Tui {
             terminal,
             event_rx,
             event_tx,
-            frame_rate,
+            frame_rate: watch::Sender::new(frame_rate),
         })
     }
 
     pub fn enter(&mut self) -> anyhow::Result<()> {
         terminal::enable_raw_mode()?;
-        execute!(std::io::stderr(), EnterAlternateScreen)?;
+        execute!(std::io::stderr(), EnterAlternateScreen, EnableMouseCapture)?;
         self.terminal.hide_cursor()?;
         self.terminal.clear()?;
         self.start_event_polling();
@@ -51,27 +57,59 @@ impl Tui {
 
     pub fn exit(&mut self) -> anyhow::Result<()> {
         terminal::disable_raw_mode()?;
-        execute!(std::io::stderr(), LeaveAlternateScreen)?;
+        execute!(std::io::stderr(), DisableMouseCapture, LeaveAlternateScreen)?;
         self.terminal.show_cursor()?;
         Ok(())
     }
 
+    /// Set the window title (OSC 0), which tmux also uses as the pane title.
+    pub fn set_title(&mut self, title: &str) -> anyhow::Result<()> {
+        execute!(std::io::stderr(), SetTitle(title))?;
+        Ok(())
+    }
+
+    /// Save the terminal's current title on its title stack (XTWINOPS 22).
+    /// Terminals without a title stack ignore this.
+    pub fn push_title(&mut self) -> anyhow::Result<()> {
+        let mut err = std::io::stderr();
+        err.write_all(b"\x1b[22;0t")?;
+        err.flush()?;
+        Ok(())
+    }
+
+    /// Restore the title saved by `push_title` (XTWINOPS 23).
+    pub fn pop_title(&mut self) -> anyhow::Result<()> {
+        let mut err = std::io::stderr();
+        err.write_all(b"\x1b[23;0t")?;
+        err.flush()?;
+        Ok(())
+    }
+
+    /// Tick `frame_rate` times a second from now on.
+    pub fn set_frame_rate(&mut self, frame_rate: f64) {
+        self.frame_rate.send_replace(frame_rate);
+    }
+
     fn start_event_polling(&self) {
         let tx = self.event_tx.clone();
-        let tick_rate = Duration::from_secs_f64(1.0 / self.frame_rate);
+        let mut frame_rate = self.frame_rate.subscribe();
+        let tick_period = |rate: f64| Duration::from_secs_f64(1.0 / rate);
 
         tokio::spawn(async move {
             let mut reader = event::EventStream::new();
-            let mut tick_interval = tokio::time::interval(tick_rate);
+            let mut tick_interval = tokio::time::interval(tick_period(*frame_rate.borrow()));
 
             loop {
                 tokio::select! {
                     event = reader.next() => {
                         match event {
-                            Some(Ok(CrosstermEvent::Key(key))) => {
-                                if key.kind == KeyEventKind::Press {
-                                    tx.send(TuiEvent::Key(key)).ok();
-                                }
+                            Some(Ok(CrosstermEvent::Key(key))) if key.kind == KeyEventKind::Press => {
+                                tx.send(TuiEvent::Key(key)).ok();
+                            }
+                            // Capture reports every pointer move; nothing
+                            // uses them, and each would cost a redraw.
+                            Some(Ok(CrosstermEvent::Mouse(mouse))) if mouse.kind != MouseEventKind::Moved => {
+                                tx.send(TuiEvent::Mouse(mouse)).ok();
                             }
                             Some(Ok(CrosstermEvent::Resize(..))) => {
                                 tx.send(TuiEvent::Resize).ok();
@@ -83,6 +121,13 @@ impl Tui {
                     _ = tick_interval.tick() => {
                         tx.send(TuiEvent::Tick).ok();
                     }
+                    Ok(()) = frame_rate.changed() => {
+                        let period = tick_period(*frame_rate.borrow_and_update());
+                        tick_interval = tokio::time::interval_at(
+                            tokio::time::Instant::now() + period,
+                            period,
+                        );
+                    }
                 }
             }
         });
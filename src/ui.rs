@@ -1,5 +1,7 @@
 // Layout and rendering: splits the terminal into panels, draws dividers,
-// and composites overlays (help, direct-play modal, error bar).
+// and composites overlays (help, direct-play modal, notifications). In a
+// short terminal, or after `Z`, the panels give way to a one-row player;
+// after `Alt+v`, to the visualizer above it.
 
 use ratatui::layout::{Alignment, Constraint, Layout, Rect};
 use ratatui::style::{Modifier, Style};
@@ -7,16 +9,34 @@ use ratatui::text::{Line, Span};
 use ratatui::widgets::{Block, Borders, Clear, Paragraph};
 use ratatui::Frame;
 
+use crate::components::about::AboutView;
+use crate::components::confirm_modal::ConfirmModal;
+use crate::components::context_menu::ContextMenu;
 use crate::components::direct_play_modal::DirectPlayModal;
 use crate::components::discovery_list::DiscoveryList;
+use crate::components::equalizer_modal::EqualizerModal;
+use crate::components::favorites_view::FavoritesView;
+use crate::components::file_picker::FilePicker;
+use crate::components::history_view::HistoryView;
+use crate::components::info_view::InfoView;
+use crate::components::log_view::LogView;
+use crate::components::notifications::Notifications;
 use crate::components::now_playing::NowPlaying;
 use crate::components::nts::NtsTab;
 use crate::components::onboarding::Onboarding;
 use crate::components::play_controls::PlayControls;
+use crate::components::playlists_view::PlaylistsView;
 use crate::components::search_bar::SearchBar;
 use crate::components::seek_modal::SeekModal;
+use crate::components::stats::StatsView;
+use crate::components::tracklist_view::TracklistView;
 use crate::components::{centered_overlay, Component};
-use crate::theme::Theme;
+use crate::config::{LayoutConfig, QueuePosition};
+use crate::keymap::{Command, KeyMap};
+use crate::theme::{ColorSupport, Theme};
+
+/// Terminals shorter than this get the compact layout.
+pub const COMPACT_HEIGHT: u16 = 16;
 
 /// Snapshot of all component state needed to render a single frame.
 pub struct DrawState<'a> {
@@ -27,15 +47,37 @@ pub struct DrawState<'a> {
     pub play_controls: &'a PlayControls,
     pub direct_play_modal: &'a DirectPlayModal,
     pub seek_modal: &'a SeekModal,
+    pub equalizer_modal: &'a EqualizerModal,
     pub onboarding: &'a Onboarding,
-    pub error_message: &'a Option<String>,
+    pub notifications: &'a Notifications,
+    pub stats_view: &'a StatsView,
+    pub log_view: &'a LogView,
+    pub context_menu: &'a ContextMenu,
+    pub confirm_modal: &'a ConfirmModal,
+    pub about_view: &'a AboutView,
+    pub favorites_view: &'a FavoritesView,
+    pub history_view: &'a HistoryView,
+    pub file_picker: &'a FilePicker,
+    pub playlists_view: &'a PlaylistsView,
+    pub tracklist_view: &'a TracklistView,
+    pub info_view: &'a InfoView,
     pub show_help: bool,
+    /// The compact layout was switched on.
+    pub compact: bool,
+    pub layout: &'a LayoutConfig,
+    pub keymap: &'a KeyMap,
     pub theme: &'a Theme,
+    pub colors: ColorSupport,
 }
 
-/// Render the full TUI layout: left panel (tabs + list + search), right panel
-/// (now playing), bottom bar (controls), and any active overlays.
+/// Render the TUI: the full layout (or the compact one), then any active
+/// overlays, in the colors the terminal can show.
 pub fn draw(frame: &mut Frame, state: &DrawState) {
+    draw_screen(frame, state);
+    state.colors.quantize_buffer(frame.buffer_mut());
+}
+
+fn draw_screen(frame: &mut Frame, state: &DrawState) {
     let theme = state.theme;
 
     // Onboarding takes over the entire screen
@@ -44,11 +86,95 @@ pub fn draw(frame: &mut Frame, state: &DrawState) {
         return;
     }
 
-    let error_height = if state.error_message.is_some() { 1 } else { 0 };
+    if state.now_playing.is_fullscreen() {
+        draw_fullscreen(frame, state);
+    } else if state.compact || frame.area().height < COMPACT_HEIGHT {
+        draw_compact(frame, state);
+    } else {
+        draw_panels(frame, state);
+    }
+
+    if state.direct_play_modal.is_visible() {
+        state.direct_play_modal.draw(frame, frame.area(), theme);
+    }
+
+    if state.seek_modal.is_visible() {
+        state.seek_modal.draw(frame, frame.area(), theme);
+    }
+
+    if state.equalizer_modal.is_visible() {
+        state.equalizer_modal.draw(frame, frame.area(), theme);
+    }
+
+    if state.favorites_view.is_visible() {
+        state.favorites_view.draw(frame, frame.area(), theme);
+    }
+
+    if state.history_view.is_visible() {
+        state.history_view.draw(frame, frame.area(), theme);
+    }
+
+    if state.playlists_view.is_visible() {
+        state.playlists_view.draw(frame, frame.area(), theme);
+    }
+
+    if state.tracklist_view.is_visible() {
+        state.tracklist_view.draw(frame, frame.area(), theme);
+    }
+
+    if state.info_view.is_visible() {
+        state.info_view.draw(frame, frame.area(), theme);
+    }
+
+    if state.file_picker.is_visible() {
+        state.file_picker.draw(frame, frame.area(), theme);
+    }
+
+    if state.stats_view.is_visible() {
+        state.stats_view.draw(frame, frame.area(), theme);
+    }
+
+    if state.about_view.is_visible() {
+        state.about_view.draw(frame, frame.area(), theme);
+    }
+
+    if state.context_menu.is_visible() {
+        state.context_menu.draw(frame, frame.area(), theme);
+    }
+
+    if state.log_view.is_visible() {
+        state.log_view.draw(frame, frame.area(), theme);
+    }
+
+    if state.confirm_modal.is_visible() {
+        state.confirm_modal.draw(frame, frame.area(), theme);
+    }
+
+    if state.notifications.is_history_visible() {
+        state.notifications.draw_history(frame, frame.area(), theme);
+    }
+
+    if state.show_help {
+        draw_help_overlay(frame, state.keymap, theme);
+    }
+}
+
+/// The full layout: tabs, list, and search on the left, now playing on the
+/// right (or the other way around), notifications and the controls bar
+/// along the bottom, as `[layout]` arranges them.
+fn draw_panels(frame: &mut Frame, state: &DrawState) {
+    let theme = state.theme;
+    let layout = state.layout;
+
+    let controls_height = if layout.hide_play_controls {
+        0
+    } else {
+        state.play_controls.height()
+    };
     let outer = Layout::vertical([
         Constraint::Min(0),
-        Constraint::Length(error_height),
-        Constraint::Length(4),
+        Constraint::Length(state.notifications.height()),
+        Constraint::Length(controls_height),
     ])
     .split(frame.area());
 
@@ -58,18 +184,45 @@ pub fn draw(frame: &mut Frame, state: &DrawState) {
     let content_area = outer_block.inner(outer[0]);
     frame.render_widget(outer_block, outer[0]);
 
-    let main = Layout::horizontal([Constraint::Percentage(60), Constraint::Percentage(40)])
-        .split(content_area);
+    let list_percent = layout.list_percent();
+    let (browse, playing) = if layout.swap_panels {
+        let [playing, rest] = Layout::horizontal([
+            Constraint::Percentage(100 - list_percent),
+            Constraint::Percentage(list_percent),
+        ])
+        .areas(content_area);
+        // The divider takes the list panel's first column.
+        let browse = Rect {
+            x: rest.x + 1,
+            width: rest.width.saturating_sub(1),
+            ..rest
+        };
+        (browse, playing)
+    } else {
+        Layout::horizontal([
+            Constraint::Percentage(list_percent),
+            Constraint::Percentage(100 - list_percent),
+        ])
+        .areas(content_area)
+        .into()
+    };
 
     let left = Layout::vertical([
         Constraint::Length(2),
         Constraint::Min(0),
         Constraint::Length(2),
     ])
-    .split(main[0]);
+    .split(browse);
 
     state.nts_tab.draw(frame, left[0], theme);
-    state.discovery_list.draw(frame, left[1], theme);
+    if layout.queue == QueuePosition::Left && state.now_playing.has_queue() {
+        let [list, queue] =
+            Layout::vertical([Constraint::Min(0), Constraint::Percentage(40)]).areas(left[1]);
+        state.discovery_list.draw(frame, list, theme);
+        state.now_playing.draw_queue(frame, queue, theme);
+    } else {
+        state.discovery_list.draw(frame, left[1], theme);
+    }
 
     let search_input_area = Rect {
         x: left[2].x,
@@ -78,43 +231,69 @@ pub fn draw(frame: &mut Frame, state: &DrawState) {
         height: 1,
     };
     state.search_bar.draw(frame, search_input_area, theme);
-    state.now_playing.draw(frame, main[1], theme);
+    state
+        .search_bar
+        .draw_suggestions(frame, left[1], search_input_area, theme);
+    state.now_playing.draw(frame, playing, theme);
 
-    draw_dividers(frame, content_area, main[0], left[2].y, theme);
+    let divider_x = if layout.swap_panels {
+        browse.x - 1
+    } else {
+        browse.right()
+    };
+    draw_dividers(frame, content_area, browse, divider_x, left[2].y, theme);
 
-    if let Some(ref msg) = state.error_message {
-        let error_line = Line::from(vec![
-            Span::styled(" ⚠ ", Style::default().fg(theme.error)),
-            Span::styled(msg.as_str(), Style::default().fg(theme.warning)),
-            Span::styled("  Press r to retry.", Style::default().fg(theme.text_dim)),
-        ]);
-        frame.render_widget(Paragraph::new(error_line), outer[1]);
-    }
+    state.notifications.draw(frame, outer[1], theme);
 
     state.play_controls.draw(frame, outer[2], theme);
+}
 
-    if state.direct_play_modal.is_visible() {
-        state.direct_play_modal.draw(frame, frame.area(), theme);
-    }
-
-    if state.seek_modal.is_visible() {
-        state.seek_modal.draw(frame, frame.area(), theme);
-    }
+/// The player on one row, notifications under it.
+fn draw_compact(frame: &mut Frame, state: &DrawState) {
+    let [bar, notifications] = Layout::vertical([
+        Constraint::Length(1),
+        Constraint::Length(state.notifications.height()),
+    ])
+    .areas(frame.area());
+    let toggle_key = state.keymap.label(Command::CompactLayout);
+    state.play_controls.draw_compact(
+        frame,
+        bar,
+        &[("Space", " ⏯"), ("n", " next"), (&toggle_key, " full")],
+        state.theme,
+    );
+    state.notifications.draw(frame, notifications, state.theme);
+}
 
-    if state.show_help {
-        draw_help_overlay(frame, theme);
-    }
+/// The visualizer over the whole terminal, with the one-row player and
+/// notifications along the bottom.
+fn draw_fullscreen(frame: &mut Frame, state: &DrawState) {
+    let [visualizer, bar, notifications] = Layout::vertical([
+        Constraint::Min(0),
+        Constraint::Length(1),
+        Constraint::Length(state.notifications.height()),
+    ])
+    .areas(frame.area());
+    state
+        .now_playing
+        .draw_visualizer(frame, visualizer, state.theme);
+    state
+        .play_controls
+        .draw_compact(frame, bar, &[("any key", " back")], state.theme);
+    state.notifications.draw(frame, notifications, state.theme);
 }
 
+/// The line between the panels at `divider_x`, and the one above the
+/// search bar across the list panel, `browse`.
 fn draw_dividers(
     frame: &mut Frame,
     content_area: Rect,
-    left_panel: Rect,
+    browse: Rect,
+    divider_x: u16,
     search_sep_y: u16,
     theme: &Theme,
 ) {
     let buf = frame.buffer_mut();
-    let divider_x = left_panel.x + left_panel.width;
     let border_color = theme.border;
 
     if divider_x < content_area.x + content_area.width {
@@ -137,57 +316,174 @@ fn draw_dividers(
         }
     }
 
-    // Horizontal divider above search bar
-    let left_x = content_area.x.saturating_sub(1);
-    if let Some(cell) = buf.cell_mut((left_x, search_sep_y)) {
+    // Horizontal divider above search bar, from the line on the list's left
+    // (the outer border or the divider) to the one on its right.
+    if let Some(cell) = buf.cell_mut((browse.x.saturating_sub(1), search_sep_y)) {
         cell.set_char('├');
         cell.set_fg(border_color);
     }
-    for x in content_area.x..left_panel.x + left_panel.width {
+    for x in browse.x..browse.right() {
         if let Some(cell) = buf.cell_mut((x, search_sep_y)) {
             cell.set_char('─');
             cell.set_fg(border_color);
         }
     }
-    if divider_x < content_area.x + content_area.width {
-        if let Some(cell) = buf.cell_mut((divider_x, search_sep_y)) {
-            cell.set_char('┤');
-            cell.set_fg(border_color);
-        }
+    if let Some(cell) = buf.cell_mut((browse.right(), search_sep_y)) {
+        cell.set_char('┤');
+        cell.set_fg(border_color);
     }
 }
 
-fn draw_help_overlay(frame: &mut Frame, theme: &Theme) {
-    let overlay_area = centered_overlay(frame.area(), 58, 33);
+fn draw_help_overlay(frame: &mut Frame, keymap: &KeyMap, theme: &Theme) {
+    let overlay_area = centered_overlay(frame.area(), 58, 58);
 
     frame.render_widget(Clear, overlay_area);
 
-    let keybindings = [
-        ("q", "Quit"),
-        ("1–3", "Switch sub-tab"),
-        ("Tab", "Next sub-tab"),
-        ("Shift+Tab", "Previous sub-tab"),
-        ("j / Down", "Scroll down"),
-        ("k / Up", "Scroll up"),
-        ("Enter", "Play / select genre"),
-        ("a", "Add to queue"),
-        ("A", "Add to queue next (after current)"),
-        ("Space", "Toggle play/pause"),
-        ("n", "Next track in queue"),
-        ("p", "Previous track in queue"),
-        ("s", "Stop playback"),
-        ("o", "Open URL (direct play)"),
-        ("v", "Cycle visualizer"),
-        ("i", "Toggle skip NTS intro"),
-        ("← →", "Seek ±5s (accelerates)"),
-        ("t", "Open seek timeline"),
-        ("/", "Focus search bar"),
-        ("Escape", "Unfocus search / go back"),
-        ("d", "Remove current from queue"),
-        ("c", "Clear queue"),
-        ("[ ]", "Volume down/up"),
-        ("?", "Toggle this help overlay"),
-        ("r", "Retry failed request"),
+    // Each row lists one or more commands; their bound keys come from the keymap.
+    let keybindings: &[(&[Command], &str)] = &[
+        (&[Command::Quit], "Quit"),
+        (
+            &[
+                Command::LiveTab,
+                Command::PicksTab,
+                Command::SearchTab,
+                Command::FollowingTab,
+                Command::FavoritesTab,
+                Command::HistoryTab,
+                Command::PlaylistsTab,
+                Command::ForYouTab,
+                Command::PodcastsTab,
+            ],
+            "Switch sub-tab",
+        ),
+        (&[Command::NextTab], "Next sub-tab"),
+        (&[Command::PrevTab], "Previous sub-tab"),
+        (&[Command::ScrollDown], "Scroll down"),
+        (&[Command::ScrollUp], "Scroll up"),
+        (&[Command::Select], "Play / select genre"),
+        (&[Command::AddToQueue], "Add to queue"),
+        (
+            &[Command::AddToQueueNext],
+            "Add to queue next (after current)",
+        ),
+        (&[Command::ToggleMark], "Mark / unmark item"),
+        (&[Command::MarkRange], "Mark range from last mark"),
+        (
+            &[Command::ToggleFavorite],
+            "Favorite (all marked) / unfavorite",
+        ),
+        (&[Command::ToggleFollow], "Follow / unfollow episode's show"),
+        (&[Command::Favorites], "Manage favorites"),
+        (&[Command::History], "Play history"),
+        (&[Command::Playlists], "Manage playlists"),
+        (
+            &[Command::AddToPlaylist],
+            "Add item (all marked) to playlist",
+        ),
+        (&[Command::QueueToPlaylist], "Add whole queue to playlist"),
+        (
+            &[Command::ExportData],
+            "Export favorites, history & queue to…",
+        ),
+        (
+            &[Command::ImportData],
+            "Import (merge) exported data or M3U",
+        ),
+        (&[Command::PlayPause], "Toggle play/pause"),
+        (&[Command::NextTrack], "Next track in queue"),
+        (&[Command::PrevTrack], "Previous track in queue"),
+        (&[Command::Stop], "Stop playback"),
+        (
+            &[Command::Preview],
+            "Preview item for 15s over the current track",
+        ),
+        (
+            &[Command::DirectPlay],
+            "Open URL (direct play; feed URL on Podcasts)",
+        ),
+        (&[Command::CycleVisualizer], "Cycle visualizer"),
+        (
+            &[Command::VisualizerFullscreen],
+            "Fullscreen visualizer (any key returns)",
+        ),
+        (&[Command::ToggleSkipIntro], "Toggle skip NTS intro"),
+        (&[Command::RadioMode], "Radio mode (keep the queue filled)"),
+        (
+            &[Command::LowDataMode],
+            "Low data mode (metered connections)",
+        ),
+        (&[Command::CycleBufferProfile], "Cycle buffer profile"),
+        (&[Command::Equalizer], "Equalizer presets"),
+        (&[Command::CycleSort], "Cycle sort order"),
+        (
+            &[Command::SeekBackward, Command::SeekForward],
+            "Seek ±5s (accelerates)",
+        ),
+        (
+            &[Command::SeekBackwardFine, Command::SeekForwardFine],
+            "Seek ±1s",
+        ),
+        (
+            &[Command::SeekBackwardCoarse, Command::SeekForwardCoarse],
+            "Seek ±1m",
+        ),
+        (&[Command::SeekTimeline], "Open seek timeline"),
+        (&[Command::CompactLayout], "Compact one-line player"),
+        (
+            &[Command::ShrinkSplit, Command::GrowSplit],
+            "Narrow / widen the list panel",
+        ),
+        (&[Command::Bookmark], "Bookmark this point in the episode"),
+        (
+            &[Command::PrevBookmark, Command::NextBookmark],
+            "Jump to the previous / next bookmark",
+        ),
+        (&[Command::Tracklist], "Episode tracklist"),
+        (&[Command::Info], "Description & details"),
+        (
+            &[Command::ContextMenu],
+            "Menu of actions for the selected item",
+        ),
+        (
+            &[Command::MoreLikeThis],
+            "More like this (unplayed episodes with the same genres & moods)",
+        ),
+        (&[Command::OpenInBrowser], "Open web page in browser"),
+        (&[Command::CopyLink], "Copy share link"),
+        (&[Command::CopyStreamUrl], "Copy stream URL"),
+        (&[Command::JumpToPlaying], "Jump to playing item in list"),
+        (
+            &[Command::ScrollTop, Command::ScrollBottom],
+            "Top / bottom of list (also gg; 12G goes to row 12)",
+        ),
+        (
+            &[Command::HalfPageDown, Command::HalfPageUp],
+            "Half a page down/up (counts work: 5j)",
+        ),
+        (&[Command::FindInList], "Find in list (n/N next/prev match)"),
+        (
+            &[Command::JumpToLetter],
+            "Then a letter: jump to rows starting with it",
+        ),
+        (&[Command::GroupGenres], "Group genres by top-level genre"),
+        (&[Command::ToggleMoods], "Browse moods instead of genres"),
+        (
+            &[Command::TogglePlaces],
+            "Browse places (cities) instead of genres",
+        ),
+        (
+            &[Command::FocusSearch],
+            "Filter as you type, Enter to search",
+        ),
+        (&[Command::Back], "Unfocus search / go back"),
+        (&[Command::RemoveFromQueue], "Remove current from queue"),
+        (&[Command::ClearQueue], "Clear queue"),
+        (&[Command::VolumeDown, Command::VolumeUp], "Volume down/up"),
+        (&[Command::Help], "Toggle this help overlay"),
+        (&[Command::Retry], "Retry failed request / search pages"),
+        (&[Command::NotificationHistory], "Notification history"),
+        (&[Command::Stats], "Usage stats (opt-in)"),
+        (&[Command::Logs], "Log viewer (l level, v debug logging)"),
     ];
 
     let mut lines: Vec<Line> = vec![
@@ -199,20 +495,48 @@ fn draw_help_overlay(frame: &mut Frame, theme: &Theme) {
         )),
         Line::from(""),
     ];
-    for (key, desc) in &keybindings {
+    let rows: Vec<(String, &str)> = keybindings
+        .iter()
+        .map(|(commands, desc)| {
+            let keys = commands
+                .iter()
+                .map(|c| keymap.label(*c))
+                .collect::<Vec<_>>()
+                .join(" ");
+            (keys, *desc)
+        })
+        .collect();
+    // Wide enough for the longest key list, with a space after it.
+    let key_width = rows
+        .iter()
+        .map(|(keys, _)| keys.chars().count() + 1)
+        .max()
+        .unwrap_or(0)
+        .max(16);
+    for (keys, desc) in rows {
         lines.push(Line::from(vec![
-            Span::styled(format!("  {:12}", key), Style::default().fg(theme.accent)),
-            Span::raw(*desc),
+            Span::styled(
+                format!("  {:key_width$}", keys),
+                Style::default().fg(theme.accent),
+            ),
+            Span::raw(desc),
         ]));
     }
     lines.push(Line::from(""));
     lines.push(Line::from(vec![
         Span::styled(
-            format!("  {:12}", "Enter"),
+            format!("  {:key_width$}", "Enter"),
             Style::default().fg(theme.accent),
         ),
         Span::raw("Restart onboarding wizard"),
     ]));
+    lines.push(Line::from(vec![
+        Span::styled(
+            format!("  {:key_width$}", "a"),
+            Style::default().fg(theme.accent),
+        ),
+        Span::raw("About & diagnostics"),
+    ]));
     lines.push(Line::from(""));
     lines.push(Line::from(Span::styled(
         "  Press any other key to close",
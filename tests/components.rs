@@ -1,4 +1,4 @@
-// Config parsing, component state, and key event handling.
+// Config parsing, component state, key event handling, and playlist files.
 
 use clisten::config::Config;
 
@@ -10,6 +10,13 @@ fn test_config_default_values() {
     assert_eq!(config.general.frame_rate, 30.0);
 }
 
+#[test]
+fn test_config_terminal_title_toggle() {
+    assert!(Config::default().general.terminal_title);
+    let config: Config = toml::from_str("[general]\nterminal_title = false\n").unwrap();
+    assert!(!config.general.terminal_title);
+}
+
 #[test]
 fn test_config_parse_toml() {
     let toml_str = r#"
@@ -26,6 +33,511 @@ fn test_config_missing_file_uses_defaults() {
     assert_eq!(config.general.frame_rate, 30.0);
 }
 
+#[test]
+fn test_config_keeps_the_oscilloscope_visualizer() {
+    use clisten::components::visualizers::VisualizerKind;
+
+    assert_eq!(VisualizerKind::Rings.next(), VisualizerKind::Oscilloscope);
+    assert_eq!(VisualizerKind::Oscilloscope.next(), VisualizerKind::Marquee);
+    let config: Config = toml::from_str("[general]\nvisualizer = \"Oscilloscope\"\n").unwrap();
+    assert_eq!(config.general.visualizer, VisualizerKind::Oscilloscope);
+    assert!(toml::to_string_pretty(&config)
+        .unwrap()
+        .contains("visualizer = \"Oscilloscope\""));
+}
+
+#[test]
+fn test_config_keeps_the_marquee_visualizer() {
+    use clisten::components::visualizers::VisualizerKind;
+
+    assert_eq!(VisualizerKind::Marquee.next(), VisualizerKind::Blob);
+    let config: Config = toml::from_str("[general]\nvisualizer = \"Marquee\"\n").unwrap();
+    assert_eq!(config.general.visualizer, VisualizerKind::Marquee);
+    assert!(toml::to_string_pretty(&config)
+        .unwrap()
+        .contains("visualizer = \"Marquee\""));
+}
+
+#[test]
+fn test_config_buffer_profile_defaults_to_mpv_defaults() {
+    let config = Config::default();
+    assert!(config.player.buffer_settings().mpv_args().is_empty());
+}
+
+#[test]
+fn test_config_buffer_profile_with_override() {
+    use clisten::player::buffer::BufferProfile;
+
+    let toml_str = r#"
+[player]
+buffer_profile = "stable"
+cache_secs = 20
+"#;
+    let config: Config = toml::from_str(toml_str).unwrap();
+    assert_eq!(config.player.buffer_profile, BufferProfile::Stable);
+    let args = config.player.buffer_settings().mpv_args();
+    assert!(args.contains(&"--cache-secs=20".to_string()));
+    assert!(args.contains(&"--demuxer-max-bytes=64MiB".to_string()));
+}
+
+#[test]
+fn test_buffer_default_profile_restores_mpv_defaults_when_applied() {
+    use clisten::player::buffer::{BufferProfile, BufferSettings};
+    use serde_json::json;
+
+    let stable = BufferSettings::resolve(BufferProfile::Stable, None, None);
+    assert_eq!(
+        stable.ipc_properties(),
+        [
+            ("cache", Some(json!("yes"))),
+            ("cache-secs", Some(json!(60))),
+            ("demuxer-max-bytes", Some(json!("64MiB"))),
+        ]
+    );
+    // Cycling back to Default asks mpv for its own values.
+    let default = BufferSettings::resolve(BufferProfile::Default, None, None);
+    assert_eq!(
+        default.ipc_properties(),
+        [
+            ("cache", None),
+            ("cache-secs", None),
+            ("demuxer-max-bytes", None),
+        ]
+    );
+}
+
+#[test]
+fn test_config_equalizer_presets_build_mpv_filters() {
+    use clisten::player::equalizer::EqualizerPreset;
+
+    assert_eq!(Config::default().player.equalizer, EqualizerPreset::Flat);
+    assert_eq!(Config::default().player.equalizer_filter(), None);
+
+    let config: Config = toml::from_str("[player]\nequalizer = \"bass-boost\"\n").unwrap();
+    assert_eq!(
+        config.player.equalizer_filter().as_deref(),
+        Some("@eq:lavfi=[equalizer=f=60:t=o:w=1.5:g=6,equalizer=f=230:t=o:w=1.5:g=4]")
+    );
+
+    // Custom gains are clamped, padded to five bands, and flat bands skipped.
+    let toml_str = r#"
+[player]
+equalizer = "custom"
+custom_eq_gains = [0, -30, 2.5]
+"#;
+    let config: Config = toml::from_str(toml_str).unwrap();
+    assert_eq!(config.player.equalizer_gains(), [0.0, -12.0, 2.5, 0.0, 0.0]);
+    assert_eq!(
+        config.player.equalizer_filter().as_deref(),
+        Some("@eq:lavfi=[equalizer=f=230:t=o:w=1.5:g=-12,equalizer=f=910:t=o:w=1.5:g=2.5]")
+    );
+}
+
+#[test]
+fn test_config_custom_mpv_args_are_validated() {
+    let toml_str = r#"
+[player]
+mpv_args = ["--http-proxy=http://proxy:3128", "--idle=no", "volume=50", "--af=lavfi=[loudnorm]"]
+ytdl_format = "bestaudio[abr<=128]"
+"#;
+    let mut config: Config = toml::from_str(toml_str).unwrap();
+    let problems = config.player.sanitize();
+    assert_eq!(problems.len(), 3, "{problems:?}");
+    assert!(problems[1].contains("must start with --"));
+    assert_eq!(
+        config.player.extra_mpv_args(),
+        [
+            "--http-proxy=http://proxy:3128",
+            "--ytdl-format=bestaudio[abr<=128]"
+        ]
+    );
+
+    let mut config: Config = toml::from_str("[player]\nytdl_format = \"best audio\"\n").unwrap();
+    assert_eq!(config.player.sanitize().len(), 1);
+    assert!(config.player.extra_mpv_args().is_empty());
+    assert!(Config::default().player.sanitize().is_empty());
+}
+
+#[test]
+fn test_config_network_proxy_and_user_agent() {
+    let mut config = Config::default();
+    assert!(config.sanitize().is_empty());
+    assert!(config.extra_mpv_args().is_empty());
+    assert!(!toml::to_string_pretty(&config)
+        .unwrap()
+        .contains("[network]"));
+
+    let toml_str = r#"
+[player]
+mpv_args = ["--volume=50"]
+
+[network]
+proxy = "http://proxy.corp:3128"
+user_agent = "Mozilla/5.0 clisten"
+"#;
+    let mut config: Config = toml::from_str(toml_str).unwrap();
+    assert!(config.sanitize().is_empty());
+    assert_eq!(
+        config.extra_mpv_args(),
+        [
+            "--http-proxy=http://proxy.corp:3128",
+            "--ytdl-raw-options-append=proxy=http://proxy.corp:3128",
+            "--user-agent=Mozilla/5.0 clisten",
+            "--ytdl-raw-options-append=user-agent=Mozilla/5.0 clisten",
+            "--volume=50",
+        ]
+    );
+
+    let toml_str = r#"
+[network]
+proxy = "socks5://127.0.0.1:1080"
+user_agent = " "
+"#;
+    let mut config: Config = toml::from_str(toml_str).unwrap();
+    let problems = config.sanitize();
+    assert_eq!(problems.len(), 2, "{problems:?}");
+    assert!(problems[0].contains("SOCKS"));
+    assert!(config.extra_mpv_args().is_empty());
+
+    let mut config: Config = toml::from_str("[network]\nproxy = \"proxy:3128\"\n").unwrap();
+    assert!(config.sanitize()[0].contains("http://"));
+}
+
+#[test]
+fn test_config_layout_parses_and_keeps_the_split_in_range() {
+    use clisten::config::QueuePosition;
+
+    assert!(!toml::to_string_pretty(&Config::default())
+        .unwrap()
+        .contains("[layout]"));
+    let toml_str = r#"
+[layout]
+split = 95
+swap_panels = true
+queue = "hidden"
+hide_visualizer = true
+"#;
+    let mut config: Config = toml::from_str(toml_str).unwrap();
+    let layout = &mut config.layout;
+    assert!(layout.swap_panels && layout.hide_visualizer && !layout.hide_play_controls);
+    assert_eq!(layout.queue, QueuePosition::Hidden);
+    assert_eq!(layout.list_percent(), 80);
+    assert_eq!(layout.resize_split(5), 80);
+    assert_eq!(layout.resize_split(-5), 75);
+    assert!(toml::to_string_pretty(&config)
+        .unwrap()
+        .contains("split = 75"));
+}
+
+#[test]
+fn test_config_crossfade_and_phases() {
+    use clisten::player::crossfade::{self, CrossfadePhase, PRELOAD_LEAD_SECS};
+
+    assert_eq!(Config::default().player.crossfade_secs(), 0.0);
+    let config: Config = toml::from_str("[player]\ncrossfade_secs = 90\n").unwrap();
+    assert_eq!(config.player.crossfade_secs(), 30.0);
+
+    let phase = |pos| crossfade::phase(pos, 600.0, 6.0);
+    assert_eq!(phase(500.0), CrossfadePhase::Playing);
+    assert_eq!(
+        phase(600.0 - 6.0 - PRELOAD_LEAD_SECS),
+        CrossfadePhase::Preload
+    );
+    assert_eq!(phase(595.0), CrossfadePhase::Fade);
+    // Off, and tracks too short to hold the fade.
+    assert_eq!(crossfade::phase(595.0, 600.0, 0.0), CrossfadePhase::Playing);
+    assert_eq!(crossfade::phase(15.0, 16.0, 6.0), CrossfadePhase::Playing);
+
+    let (out_start, in_start) = crossfade::fade_volumes(80.0, 0.0);
+    let (out_end, in_end) = crossfade::fade_volumes(80.0, 1.0);
+    assert_eq!((out_start, in_start), (80.0, 0.0));
+    assert!(out_end.abs() < 1e-9 && (in_end - 80.0).abs() < 1e-9);
+}
+
+#[test]
+fn test_config_nts_relays_and_api_base() {
+    let config = Config::default();
+    assert_eq!(config.nts.relays(1).len(), 2);
+    assert_eq!(config.nts.client().base(), "https://www.nts.live");
+    assert!(!toml::to_string_pretty(&config).unwrap().contains("[nts]"));
+
+    let toml_str = r#"
+[nts]
+channel_2_relays = ["https://mirror.example/stream2", "https://other.example/stream2"]
+api_base = "http://localhost:8080/"
+"#;
+    let config: Config = toml::from_str(toml_str).unwrap();
+    assert_eq!(
+        config.nts.relays(2),
+        vec![
+            "https://mirror.example/stream2",
+            "https://other.example/stream2"
+        ]
+    );
+    assert_eq!(config.nts.relays(1), Config::default().nts.relays(1));
+    assert!(config.nts.relays(3).is_empty());
+    assert_eq!(config.nts.client().base(), "http://localhost:8080");
+}
+
+#[tokio::test]
+async fn test_config_hooks_run_with_track_env() {
+    use clisten::api::models::DiscoveryItem;
+    use clisten::hooks::{self, HookEvent, HookTrack};
+    use clisten::player::StreamMetadata;
+
+    assert!(!toml::to_string_pretty(&Config::default())
+        .unwrap()
+        .contains("[hooks]"));
+    let dir = tempfile::tempdir().unwrap();
+    let out = dir.path().join("hook.out");
+    let toml_str = format!(
+        "[hooks]\nmetadata_changed = 'echo \"$CLISTEN_EVENT|$CLISTEN_ARTIST|$CLISTEN_TITLE|$CLISTEN_URL\" > {}'\ntrack_started = \"  \"\n",
+        out.display()
+    );
+    let config: Config = toml::from_str(&toml_str).unwrap();
+    assert_eq!(config.hooks.command(HookEvent::TrackStarted), None);
+    assert_eq!(config.hooks.command(HookEvent::TrackFinished), None);
+
+    let item = DiscoveryItem::DirectUrl {
+        url: "http://radio".to_string(),
+        title: Some("Radio".to_string()),
+        meta: None,
+    };
+    assert_eq!(HookTrack::new(&item, "http://radio", None).title, "Radio");
+    let metadata = StreamMetadata {
+        title: Some("Song".to_string()),
+        artist: Some("Band".to_string()),
+        ..Default::default()
+    };
+    let track = HookTrack::new(&item, "http://radio", Some(&metadata));
+    hooks::run(&config.hooks, HookEvent::MetadataChanged, &track);
+
+    let mut written = String::new();
+    for _ in 0..100 {
+        written = std::fs::read_to_string(&out).unwrap_or_default();
+        if written.ends_with('\n') {
+            break;
+        }
+        tokio::time::sleep(std::time::Duration::from_millis(20)).await;
+    }
+    assert_eq!(written, "metadata_changed|Band|Song|http://radio\n");
+}
+
+#[test]
+fn test_doctor_config_check() {
+    use clisten::doctor::{check_config_at, Status};
+
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("config.toml");
+    assert_eq!(check_config_at(&path).status, Status::Pass);
+
+    std::fs::write(&path, "[general]\nframe_rate = 60.0\n").unwrap();
+    assert_eq!(check_config_at(&path).status, Status::Pass);
+
+    std::fs::write(&path, "[player]\nmpv_args = [\"--no-terminal\"]\n").unwrap();
+    let check = check_config_at(&path);
+    assert_eq!(check.status, Status::Warn);
+    assert!(check.detail.contains("managed by clisten"));
+
+    std::fs::write(&path, "[general]\nframe_rate = \"fast\"\n").unwrap();
+    let check = check_config_at(&path);
+    assert_eq!(check.status, Status::Fail);
+    assert!(check.fix.is_some());
+}
+
+#[test]
+fn test_health_log_round_trips_and_skips_torn_lines() {
+    use clisten::health::{read, HealthLog};
+
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("playback-health.log");
+    let mut log = HealthLog::create(&path);
+    assert!(log.is_enabled());
+    log.track_started();
+    log.set_buffered(20.0);
+    log.sample(Some("Show".to_string()), 5.0, false);
+    log.record_reconnect();
+    log.sample(Some("Show".to_string()), 9.0, false);
+    std::fs::OpenOptions::new()
+        .append(true)
+        .open(&path)
+        .and_then(|mut f| std::io::Write::write_all(&mut f, b"{\"elapsed_se"))
+        .unwrap();
+
+    let samples = read(&path).unwrap();
+    assert_eq!(samples.len(), 2);
+    assert_eq!(samples[0].position_delta, None);
+    assert_eq!(samples[0].cache_secs, Some(15.0));
+    assert_eq!(samples[1].position_delta, Some(4.0));
+    assert_eq!(samples[1].reconnects, 1);
+
+    // A new session replaces the previous log.
+    let _ = HealthLog::create(&path);
+    assert!(read(&path).unwrap().is_empty());
+    assert!(!HealthLog::disabled().is_enabled());
+}
+
+#[test]
+fn test_health_summary_finds_stalls_and_report_names_them() {
+    use clisten::doctor::playback_report;
+    use clisten::health::{summarize, HealthSample};
+
+    let sample = |elapsed: f64, delta: Option<f64>, cache: f64, paused: bool| HealthSample {
+        elapsed_secs: elapsed,
+        title: Some("Floating Points".to_string()),
+        position_secs: elapsed,
+        position_delta: delta,
+        cache_secs: Some(cache),
+        paused,
+        reconnects: if elapsed >= 25.0 { 1 } else { 0 },
+    };
+    let samples = vec![
+        sample(0.0, None, 10.0, false),
+        sample(5.0, Some(5.0), 12.0, false),
+        sample(10.0, Some(0.0), 0.0, false),
+        sample(15.0, Some(0.2), 0.5, false),
+        sample(20.0, Some(5.0), 8.0, false),
+        // Paused, then resumed: neither is a stall.
+        sample(25.0, Some(0.0), 8.0, true),
+        sample(30.0, Some(1.0), 8.0, false),
+        // Seeking backwards isn't a stall either.
+        sample(35.0, Some(-60.0), 8.0, false),
+    ];
+    let summary = summarize(&samples);
+    assert_eq!(summary.samples, 8);
+    assert_eq!(summary.span_secs, 35.0);
+    assert_eq!(summary.tracks, 1);
+    assert_eq!(summary.reconnects, 1);
+    assert_eq!(summary.min_cache_secs, Some(0.0));
+    assert_eq!(summary.stalls.len(), 1);
+    assert_eq!(summary.stalls[0].elapsed_secs, 5.0);
+    assert_eq!(summary.stalls[0].duration_secs, 10.0);
+    assert_eq!(summary.stalls[0].cache_secs, Some(0.0));
+
+    let report = playback_report(&summary);
+    assert!(report.contains("Stalls       1"));
+    assert!(report.contains("stuck 10s, cache 0.0s  Floating Points"));
+    assert!(report.contains("cache ran dry"));
+
+    let quiet = summarize(&samples[..2]);
+    assert!(playback_report(&quiet).contains("No dropouts recorded."));
+}
+
+// ── Keymap ──
+
+mod keymap_tests {
+    use clisten::config::Config;
+    use clisten::keymap::{Command, KeyChord, KeyMap};
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+    #[test]
+    fn test_default_keymap_matches_builtin_layout() {
+        let map = KeyMap::default();
+        let key = |code, mods| KeyEvent::new(code, mods);
+        assert_eq!(
+            map.resolve(&key(KeyCode::Char('q'), KeyModifiers::NONE)),
+            Some(Command::Quit)
+        );
+        // Uppercase letters resolve whether or not the terminal reports Shift.
+        assert_eq!(
+            map.resolve(&key(KeyCode::Char('B'), KeyModifiers::SHIFT)),
+            Some(Command::CycleBufferProfile)
+        );
+        assert_eq!(
+            map.resolve(&key(KeyCode::BackTab, KeyModifiers::SHIFT)),
+            Some(Command::PrevTab)
+        );
+        assert_eq!(
+            map.resolve(&key(KeyCode::Down, KeyModifiers::NONE)),
+            Some(Command::ScrollDown)
+        );
+        assert_eq!(map.label(Command::ScrollDown), "j / Down");
+        assert_eq!(
+            map.resolve(&key(KeyCode::Char('i'), KeyModifiers::NONE)),
+            Some(Command::Info)
+        );
+        assert_eq!(
+            map.resolve(&key(KeyCode::Char('i'), KeyModifiers::ALT)),
+            Some(Command::ToggleSkipIntro)
+        );
+    }
+
+    #[test]
+    fn test_keymap_config_overrides() {
+        let toml_str = r#"
+[keys]
+quit = "x"
+scroll_down = ["ctrl+n", "j"]
+play_pause = "n"
+"#;
+        let config: Config = toml::from_str(toml_str).unwrap();
+        let map = KeyMap::new(&config.keys);
+        let key = |code, mods| KeyEvent::new(code, mods);
+
+        assert_eq!(
+            map.resolve(&key(KeyCode::Char('x'), KeyModifiers::NONE)),
+            Some(Command::Quit)
+        );
+        assert_eq!(
+            map.resolve(&key(KeyCode::Char('q'), KeyModifiers::NONE)),
+            None
+        );
+        assert_eq!(
+            map.resolve(&key(KeyCode::Char('n'), KeyModifiers::CONTROL)),
+            Some(Command::ScrollDown)
+        );
+        // Taking a default key away from another command leaves it unbound.
+        assert_eq!(
+            map.resolve(&key(KeyCode::Char('n'), KeyModifiers::NONE)),
+            Some(Command::PlayPause)
+        );
+        assert!(map.chords(Command::NextTrack).is_empty());
+        assert_eq!(map.label(Command::ScrollDown), "Ctrl+n / j");
+    }
+
+    #[test]
+    fn test_keymap_rejects_invalid_config() {
+        assert!(toml::from_str::<Config>("[keys]\nquit = \"hyper+q\"\n").is_err());
+        assert!(toml::from_str::<Config>("[keys]\nlaunch_rockets = \"x\"\n").is_err());
+    }
+
+    #[test]
+    fn test_key_chord_round_trips_through_config_spelling() {
+        for s in [
+            "q",
+            "A",
+            "ctrl+d",
+            "shift+tab",
+            "space",
+            "alt+left",
+            "f5",
+            "+",
+        ] {
+            let chord: KeyChord = s.parse().unwrap();
+            let spelled: String = chord.into();
+            assert_eq!(spelled.parse::<KeyChord>().unwrap(), chord, "{}", s);
+        }
+    }
+
+    #[test]
+    fn test_repeat_limiter_drops_fast_volume_repeats() {
+        use clisten::keymap::RepeatLimiter;
+        use std::time::{Duration, Instant};
+
+        let mut limiter = RepeatLimiter::default();
+        let t0 = Instant::now();
+        assert!(limiter.allow(Command::VolumeUp, t0));
+        assert!(!limiter.allow(Command::VolumeUp, t0 + Duration::from_millis(30)));
+        // Each command is limited separately.
+        assert!(limiter.allow(Command::VolumeDown, t0 + Duration::from_millis(30)));
+        assert!(limiter.allow(Command::VolumeUp, t0 + Duration::from_millis(100)));
+        // Cheap commands like scrolling are never limited.
+        for _ in 0..5 {
+            assert!(limiter.allow(Command::ScrollDown, t0));
+        }
+    }
+}
+
 // ── Components ──
 
 mod component_tests {
@@ -146,6 +658,46 @@ mod component_tests {
         assert_eq!(bar.input(), "abc");
     }
 
+    #[test]
+    fn test_search_bar_suggests_past_queries() {
+        let (tx, mut rx) = mpsc::unbounded_channel::<Action>();
+        let mut bar = SearchBar::new();
+        bar.register_action_handler(tx);
+        bar.set_history(vec![
+            "deep house".to_string(),
+            "jazz".to_string(),
+            "house".to_string(),
+        ]);
+        assert!(bar.suggestions().is_empty(), "only while focused");
+
+        bar.update(&Action::FocusSearch).unwrap();
+        assert_eq!(bar.suggestions(), ["deep house", "jazz", "house"]);
+        for c in "HOU".chars() {
+            bar.handle_key_event(make_key(KeyCode::Char(c))).unwrap();
+        }
+        assert_eq!(bar.suggestions(), ["deep house", "house"]);
+
+        // Up wraps to the last one; Down comes back round.
+        assert!(bar.handle_key_event(make_key(KeyCode::Up)).unwrap());
+        assert_eq!(bar.selected_suggestion(), Some("house"));
+        bar.handle_key_event(make_key(KeyCode::Down)).unwrap();
+        assert_eq!(bar.selected_suggestion(), Some("deep house"));
+
+        // Ctrl+D forgets it.
+        bar.handle_key_event(KeyEvent::new(KeyCode::Char('d'), KeyModifiers::CONTROL))
+            .unwrap();
+        assert!(matches!(rx.try_recv(), Ok(Action::DeleteSearch(q)) if q == "deep house"));
+        assert_eq!(bar.suggestions(), ["house"]);
+        assert_eq!(bar.selected_suggestion(), Some("house"));
+        assert_eq!(bar.input(), "HOU");
+
+        // Enter searches the highlighted one.
+        bar.handle_key_event(make_key(KeyCode::Enter)).unwrap();
+        assert_eq!(bar.input(), "house");
+        assert!(matches!(rx.try_recv(), Ok(Action::SearchSubmit)));
+        assert!(bar.suggestions().is_empty());
+    }
+
     #[test]
     fn test_now_playing_initial_state() {
         let (tx, _rx) = mpsc::unbounded_channel::<Action>();
@@ -165,6 +717,311 @@ mod component_tests {
         assert!(!pc.is_paused());
         assert_eq!(pc.queue_len(), 0);
     }
+
+    #[test]
+    fn test_onboarding_theme_screen_selects_preset() {
+        use clisten::components::onboarding::{Onboarding, SCREEN_THEME};
+
+        let (tx, mut rx) = mpsc::unbounded_channel::<Action>();
+        let mut onboarding = Onboarding::new();
+        onboarding.register_action_handler(tx);
+        onboarding.activate(vec![SCREEN_THEME]);
+        onboarding
+            .handle_key_event(make_key(KeyCode::Char('j')))
+            .unwrap();
+        onboarding
+            .handle_key_event(make_key(KeyCode::Char('j')))
+            .unwrap();
+        onboarding
+            .handle_key_event(make_key(KeyCode::Enter))
+            .unwrap();
+        match rx.try_recv() {
+            Ok(Action::OnboardingComplete { theme, .. }) => assert_eq!(theme, "tokyo-night"),
+            other => panic!("expected OnboardingComplete, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_theme_presets_resolve_by_name() {
+        use clisten::theme::{Theme, THEME_PRESETS};
+
+        let dark = Theme::dark();
+        for (name, _) in THEME_PRESETS.iter().skip(1) {
+            assert_ne!(Theme::from_name(name).primary, dark.primary, "{}", name);
+        }
+        assert_eq!(Theme::from_name("no-such-theme").primary, dark.primary);
+        assert_ne!(
+            Theme::from_name("gruvbox").visualizer.accents,
+            dark.visualizer.accents
+        );
+    }
+
+    #[test]
+    fn test_color_support_detection_and_quantizing() {
+        use clisten::theme::ColorSupport;
+        use ratatui::style::Color;
+
+        let detect = ColorSupport::from_env;
+        assert_eq!(
+            detect(Some("truecolor"), Some("xterm-256color")),
+            ColorSupport::TrueColor
+        );
+        assert_eq!(detect(None, Some("xterm-256color")), ColorSupport::Ansi256);
+        assert_eq!(detect(Some(""), Some("screen")), ColorSupport::Ansi16);
+        assert_eq!(detect(None, Some("linux")), ColorSupport::Ansi16);
+
+        // Tokyo Night's blue lands on the cube, a dark gray on the ramp.
+        let blue = Color::Rgb(0x7a, 0xa2, 0xf7);
+        assert_eq!(ColorSupport::TrueColor.quantize(blue), blue);
+        assert_eq!(ColorSupport::Ansi256.quantize(blue), Color::Indexed(111));
+        assert_eq!(
+            ColorSupport::Ansi256.quantize(Color::Rgb(30, 30, 40)),
+            Color::Indexed(235)
+        );
+        assert_eq!(ColorSupport::Ansi16.quantize(blue), Color::LightBlue);
+        assert_eq!(
+            ColorSupport::Ansi16.quantize(Color::Indexed(196)),
+            Color::Red
+        );
+        // Named colors are the terminal's own and stay.
+        assert_eq!(ColorSupport::Ansi16.quantize(Color::Cyan), Color::Cyan);
+        assert_eq!(ColorSupport::Ansi256.quantize(Color::Reset), Color::Reset);
+
+        let config: clisten::config::Config =
+            toml::from_str("[general]\ncolors = \"256\"\n").unwrap();
+        assert_eq!(config.general.colors, Some(ColorSupport::Ansi256));
+    }
+
+    #[test]
+    fn test_notifications_stack_is_capped_and_deduplicated() {
+        use clisten::components::notifications::{Notification, Notifications};
+
+        let mut notes = Notifications::new();
+        for i in 0..5 {
+            notes.push(Notification::info(format!("msg {}", i)));
+        }
+        notes.push(Notification::info("msg 4"));
+        let active: Vec<_> = notes.active().map(|n| n.message.as_str()).collect();
+        assert_eq!(active, ["msg 2", "msg 3", "msg 4"]);
+        assert_eq!(notes.height(), 3);
+        // History keeps everything except the repeat, newest first.
+        let history: Vec<_> = notes.history().map(|n| n.message.as_str()).collect();
+        assert_eq!(history, ["msg 4", "msg 3", "msg 2", "msg 1", "msg 0"]);
+    }
+
+    #[test]
+    fn test_notifications_expire_per_ttl() {
+        use clisten::components::notifications::{Notification, Notifications, NotifyLevel};
+        use std::time::{Duration, Instant};
+
+        let mut notes = Notifications::new();
+        notes.push(Notification::warn("short").with_ttl(Duration::from_secs(1)));
+        notes.push(Notification::error("long"));
+        notes.expire(Instant::now() + Duration::from_secs(2));
+        let active: Vec<_> = notes.active().map(|n| n.level).collect();
+        assert_eq!(active, [NotifyLevel::Error]);
+        notes.expire(Instant::now() + Duration::from_secs(60));
+        assert_eq!(notes.height(), 0);
+    }
+
+    #[test]
+    fn test_log_buffer_layer_collects_events_with_fields() {
+        use clisten::logging::Logs;
+        use tracing::Level;
+        use tracing_subscriber::prelude::*;
+
+        let logs = Logs::new();
+        let subscriber = tracing_subscriber::registry().with(logs.layer());
+        tracing::subscriber::with_default(subscriber, || {
+            tracing::info!(channel = 2, "switched channel");
+            tracing::warn!("cache miss");
+        });
+        let lines = logs.lines();
+        assert_eq!(lines.len(), 2);
+        assert_eq!(lines[0].level, Level::INFO);
+        assert_eq!(lines[0].message, "switched channel channel=2");
+        assert_eq!(lines[1].level, Level::WARN);
+        assert_eq!(lines[1].message, "cache miss");
+        assert!(lines[1].target.starts_with("components"));
+    }
+
+    #[test]
+    fn test_log_view_filters_by_level() {
+        use clisten::components::log_view::LogView;
+        use clisten::logging::{LogLine, Logs};
+        use std::time::SystemTime;
+        use tracing::Level;
+
+        let logs = Logs::new();
+        for level in [Level::TRACE, Level::DEBUG, Level::INFO, Level::WARN] {
+            logs.push(LogLine {
+                time: SystemTime::now(),
+                level,
+                target: "clisten".to_string(),
+                message: level.to_string(),
+            });
+        }
+        let mut view = LogView::new();
+        view.set_logs(logs);
+        view.show();
+        // Debug and above to start with.
+        assert_eq!(view.level(), Level::DEBUG);
+        assert_eq!(view.visible_lines().len(), 3);
+        view.handle_key_event(make_key(KeyCode::Char('l'))).unwrap();
+        assert_eq!(view.level(), Level::INFO);
+        assert_eq!(view.visible_lines().len(), 2);
+        view.handle_key_event(make_key(KeyCode::Char('l'))).unwrap();
+        view.handle_key_event(make_key(KeyCode::Char('l'))).unwrap();
+        assert_eq!(view.level(), Level::ERROR);
+        assert!(view.visible_lines().is_empty());
+        // Wraps round to everything.
+        view.handle_key_event(make_key(KeyCode::Char('l'))).unwrap();
+        assert_eq!(view.visible_lines().len(), 4);
+        view.handle_key_event(make_key(KeyCode::Esc)).unwrap();
+        assert!(!view.is_visible());
+    }
+
+    #[test]
+    fn test_components_animate_only_while_something_moves() {
+        use clisten::api::models::DiscoveryItem;
+
+        let mut controls = PlayControls::new();
+        assert!(!controls.is_animating());
+        controls.update(&Action::PlaybackLoading).unwrap();
+        assert!(controls.is_animating(), "the spinner turns");
+        controls
+            .update(&Action::PlaybackStarted {
+                title: "one".to_string(),
+            })
+            .unwrap();
+        controls.update(&Action::PauseChanged(true)).unwrap();
+        assert!(!controls.is_animating());
+
+        let mut now_playing = NowPlaying::new(Default::default());
+        assert!(!now_playing.is_animating());
+        now_playing.set_buffering(DiscoveryItem::DirectUrl {
+            url: "http://one".to_string(),
+            title: None,
+            meta: None,
+        });
+        assert!(now_playing.is_animating(), "the visualizer moves");
+
+        let mut list = DiscoveryList::new();
+        assert!(!list.is_animating());
+        list.set_loading(true);
+        assert!(list.is_animating());
+    }
+
+    #[test]
+    fn test_format_age() {
+        use clisten::components::format_age;
+        use std::time::Duration;
+
+        assert_eq!(format_age(Duration::from_secs(12)), "12s ago");
+        assert_eq!(format_age(Duration::from_secs(185)), "3m ago");
+        assert_eq!(format_age(Duration::from_secs(7300)), "2h ago");
+        assert_eq!(format_age(Duration::from_secs(3 * 86_400 + 5)), "3d ago");
+    }
+
+    #[test]
+    fn test_text_truncate_by_display_width() {
+        use clisten::components::text::{display_width, fit, truncate};
+
+        assert_eq!(display_width("東京 Tokyo"), 10);
+        assert_eq!(truncate("Tokyo", 5), "Tokyo");
+        assert_eq!(truncate("Tokyo Nights", 6), "Tokyo…");
+        // Two cells per CJK character; the one that would straddle the edge goes.
+        assert_eq!(truncate("東京の夜", 6), "東京…");
+        assert_eq!(truncate("東京の夜", 5), "東京…");
+        assert_eq!(truncate("🎧 mix", 2), "…");
+        assert_eq!(truncate("anything", 0), "");
+
+        assert_eq!(fit("東京", 6), "東京  ");
+        assert_eq!(display_width(&fit("東京の夜", 5)), 5);
+    }
+}
+
+// ── Spectrum analysis ──
+
+mod spectrum_tests {
+    use clisten::player::spectrum::{self, BAND_CENTERS_HZ};
+    use serde_json::{json, Map, Value};
+
+    fn band_metadata(levels: &[&str]) -> Map<String, Value> {
+        levels
+            .iter()
+            .enumerate()
+            .map(|(i, db)| (format!("lavfi.astats.{}.RMS_level", i + 1), json!(db)))
+            .collect()
+    }
+
+    #[test]
+    fn test_parse_bands_scales_db() {
+        let mut levels = vec!["-35.0"; BAND_CENTERS_HZ.len()];
+        levels[0] = "0.0";
+        levels[1] = "-inf";
+        levels[2] = "-90.5";
+        let bands = spectrum::parse_bands(&band_metadata(&levels)).unwrap();
+        assert_eq!(bands.len(), BAND_CENTERS_HZ.len());
+        assert_eq!(bands[0], 1.0);
+        assert_eq!(bands[1], 0.0, "silence is the floor");
+        assert_eq!(bands[2], 0.0, "below the floor clamps");
+        assert!((bands[3] - 0.5).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_level_meter_attacks_at_once_and_releases_slowly() {
+        use clisten::player::levels::{db_to_linear, LevelMeter};
+        use std::time::Duration;
+
+        let poll = Duration::from_millis(50);
+        let mut meter = LevelMeter::default();
+        assert_eq!(
+            meter.update(f64::NEG_INFINITY, f64::NEG_INFINITY, poll),
+            (0.0, 0.0)
+        );
+
+        let (rms, peak) = meter.update(-12.0, -3.0, poll);
+        assert_eq!(rms, db_to_linear(-12.0));
+        assert_eq!(peak, db_to_linear(-3.0));
+
+        // A quiet frame right after a loud one: the readings fall back
+        // gradually, the peak slower than the RMS.
+        let (rms, peak) = meter.update(-40.0, -40.0, poll);
+        assert!(rms < db_to_linear(-12.0) && rms > db_to_linear(-14.0));
+        assert!(peak < db_to_linear(-3.0) && peak > db_to_linear(-4.0));
+
+        // Long enough and they reach the quiet level.
+        assert_eq!(
+            meter.update(-40.0, -40.0, Duration::from_secs(10)),
+            (db_to_linear(-40.0), db_to_linear(-40.0))
+        );
+    }
+
+    #[test]
+    fn test_parse_bands_requires_every_band() {
+        let levels = vec!["-20.0"; BAND_CENTERS_HZ.len() - 1];
+        assert!(spectrum::parse_bands(&band_metadata(&levels)).is_none());
+        assert!(spectrum::parse_bands(&Map::new()).is_none());
+    }
+
+    #[test]
+    fn test_filter_arg_is_length_quoted() {
+        let graph = spectrum::filter_graph();
+        for hz in BAND_CENTERS_HZ {
+            assert!(graph.contains(&format!("bandpass=f={}:", hz)));
+        }
+        assert!(graph.ends_with(&format!(
+            "pan=stereo|c0=c{}|c1=c{}",
+            BAND_CENTERS_HZ.len(),
+            BAND_CENTERS_HZ.len() + 1
+        )));
+        let arg = spectrum::mpv_filter_arg();
+        assert_eq!(
+            arg,
+            format!("@spectrum:lavfi=graph=%{}%{}", graph.len(), graph)
+        );
+    }
 }
 
 // ── App actions ──
@@ -210,3 +1067,354 @@ mod app_action_tests {
         assert_eq!(bar.input(), "q");
     }
 }
+
+// ── Playlists ──
+
+mod playlist_tests {
+    use clisten::api::models::DiscoveryItem;
+    use clisten::playlist::{self, PlaylistEntry, PlaylistFormat};
+
+    #[test]
+    fn test_m3u_roundtrip() {
+        let entries = vec![
+            PlaylistEntry {
+                title: "Morning\nShow".to_string(),
+                url: "https://a.example/stream".to_string(),
+            },
+            PlaylistEntry {
+                title: "B".to_string(),
+                url: "https://b.example/ep.mp3".to_string(),
+            },
+        ];
+        let text = playlist::to_m3u(&entries);
+        assert!(text.starts_with("#EXTM3U\n#EXTINF:-1,Morning Show\n"));
+        let parsed = playlist::parse_m3u(&text);
+        assert_eq!(parsed.len(), 2);
+        assert_eq!(parsed[0].title, "Morning Show");
+        assert_eq!(parsed[1], entries[1]);
+    }
+
+    #[test]
+    fn test_parse_plain_m3u_keeps_only_web_urls() {
+        let text = "\u{feff}# comment\n/local/file.mp3\nhttp://radio.example/live\n\n";
+        let parsed = playlist::parse_m3u(text);
+        assert_eq!(
+            parsed,
+            [PlaylistEntry {
+                title: "http://radio.example/live".to_string(),
+                url: "http://radio.example/live".to_string(),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_opml_lists_only_streams_and_escapes() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("radio.OPML");
+        assert_eq!(PlaylistFormat::from_path(&path), Some(PlaylistFormat::Opml));
+        let items = vec![
+            DiscoveryItem::DirectUrl {
+                url: "https://r.example/?a=1&b=2".to_string(),
+                title: Some("R & B".to_string()),
+                meta: None,
+            },
+            DiscoveryItem::NtsGenre {
+                name: "Ambient".to_string(),
+                genre_id: "ambient".to_string(),
+            },
+        ];
+        assert_eq!(playlist::write(&path, &items, "Stations").unwrap(), 1);
+        let text = std::fs::read_to_string(&path).unwrap();
+        assert!(text.contains("text=\"R &amp; B\""));
+        assert!(text.contains("URL=\"https://r.example/?a=1&amp;b=2\""));
+        assert!(playlist::write(&dir.path().join("x.txt"), &items, "x").is_err());
+    }
+
+    #[tokio::test]
+    async fn test_direct_play_queues_m3u_file() {
+        use clisten::action::Action;
+        use clisten::db::Database;
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("list.m3u8");
+        std::fs::write(
+            &path,
+            "#EXTM3U\n#EXTINF:-1,One\nhttps://one.example\nhttps://two.example\nhttps://one.example\n",
+        )
+        .unwrap();
+        let db = Database::open_at(&dir.path().join("test.db")).unwrap();
+        let mut app = clisten::app::App::with_db(clisten::config::Config::default(), db).unwrap();
+        app.queue.clear();
+
+        app.handle_action(Action::ImportPlaylist(path))
+            .await
+            .unwrap();
+        let titles: Vec<_> = app
+            .queue
+            .items()
+            .iter()
+            .map(|qi| qi.item.title().to_string())
+            .collect();
+        assert_eq!(titles, ["One", "https://two.example"]);
+    }
+}
+
+mod file_picker_tests {
+    use clisten::action::Action;
+    use clisten::components::file_picker::{FilePicker, PickPurpose, PickerEntry};
+    use clisten::components::Component;
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+    use tokio::sync::mpsc;
+
+    fn press(picker: &mut FilePicker, code: KeyCode) {
+        picker
+            .handle_key_event(KeyEvent::new(code, KeyModifiers::NONE))
+            .unwrap();
+    }
+
+    fn names(picker: &FilePicker) -> Vec<&str> {
+        picker.entries().iter().map(|e| e.name.as_str()).collect()
+    }
+
+    fn sample_dir() -> tempfile::TempDir {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::create_dir(dir.path().join("b_dir")).unwrap();
+        std::fs::create_dir(dir.path().join("A_dir")).unwrap();
+        std::fs::create_dir(dir.path().join(".hidden")).unwrap();
+        std::fs::write(dir.path().join("export.json"), "{}").unwrap();
+        std::fs::write(dir.path().join("mix.m3u"), "").unwrap();
+        std::fs::write(dir.path().join("notes.txt"), "").unwrap();
+        dir
+    }
+
+    #[test]
+    fn test_lists_directories_first_and_filters_files() {
+        let dir = sample_dir();
+        let mut picker = FilePicker::new();
+        picker.show(PickPurpose::Import, dir.path());
+        assert_eq!(names(&picker), ["A_dir", "b_dir", "export.json", "mix.m3u"]);
+        assert!(picker.entries()[0].is_dir);
+
+        picker.show(PickPurpose::Export, dir.path());
+        assert_eq!(names(&picker), ["A_dir", "b_dir"]);
+    }
+
+    #[test]
+    fn test_hidden_toggle_and_navigation() {
+        let dir = sample_dir();
+        let mut picker = FilePicker::new();
+        picker.show(PickPurpose::Export, dir.path());
+
+        press(&mut picker, KeyCode::Char('.'));
+        assert_eq!(names(&picker), [".hidden", "A_dir", "b_dir"]);
+
+        press(&mut picker, KeyCode::Char('j'));
+        press(&mut picker, KeyCode::Enter);
+        assert_eq!(picker.current_dir(), dir.path().join("A_dir"));
+
+        // Going up reselects the directory we came from.
+        press(&mut picker, KeyCode::Char('h'));
+        assert_eq!(picker.current_dir(), dir.path());
+        press(&mut picker, KeyCode::Enter);
+        assert_eq!(picker.current_dir(), dir.path().join("A_dir"));
+    }
+
+    #[test]
+    fn test_create_directory_enters_it() {
+        let dir = sample_dir();
+        let mut picker = FilePicker::new();
+        picker.show(PickPurpose::Export, dir.path());
+
+        press(&mut picker, KeyCode::Char('n'));
+        for c in "backups".chars() {
+            press(&mut picker, KeyCode::Char(c));
+        }
+        press(&mut picker, KeyCode::Enter);
+        assert!(dir.path().join("backups").is_dir());
+        assert_eq!(picker.current_dir(), dir.path().join("backups"));
+        assert!(picker.entries().is_empty());
+
+        // Path separators aren't allowed in the name.
+        press(&mut picker, KeyCode::Char('n'));
+        for c in "a/b".chars() {
+            press(&mut picker, KeyCode::Char(c));
+        }
+        press(&mut picker, KeyCode::Enter);
+        assert!(picker.error().is_some());
+        assert!(!dir.path().join("backups/a").exists());
+    }
+
+    #[test]
+    fn test_export_picks_current_directory() {
+        let dir = sample_dir();
+        let (tx, mut rx) = mpsc::unbounded_channel::<Action>();
+        let mut picker = FilePicker::new();
+        picker.register_action_handler(tx);
+        picker.show(PickPurpose::Export, dir.path());
+
+        press(&mut picker, KeyCode::Char('s'));
+        assert!(!picker.is_visible());
+        match rx.try_recv().unwrap() {
+            Action::ExportData(path) => assert_eq!(path, dir.path().join("export.json")),
+            other => panic!("unexpected action {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_import_sends_action_by_file_type() {
+        let dir = sample_dir();
+        let (tx, mut rx) = mpsc::unbounded_channel::<Action>();
+        let mut picker = FilePicker::new();
+        picker.register_action_handler(tx);
+
+        picker.show(PickPurpose::Import, dir.path());
+        for _ in 0..2 {
+            press(&mut picker, KeyCode::Char('j'));
+        }
+        assert_eq!(
+            picker.entries()[2],
+            PickerEntry {
+                name: "export.json".to_string(),
+                is_dir: false
+            }
+        );
+        press(&mut picker, KeyCode::Enter);
+        assert!(
+            matches!(rx.try_recv().unwrap(), Action::ImportData(p) if p.ends_with("export.json"))
+        );
+
+        picker.show(PickPurpose::Import, dir.path());
+        for _ in 0..3 {
+            press(&mut picker, KeyCode::Char('j'));
+        }
+        press(&mut picker, KeyCode::Enter);
+        assert!(
+            matches!(rx.try_recv().unwrap(), Action::ImportPlaylist(p) if p.ends_with("mix.m3u"))
+        );
+    }
+
+    #[test]
+    fn test_missing_start_dir_falls_back_to_ancestor() {
+        let dir = sample_dir();
+        let mut picker = FilePicker::new();
+        picker.show(PickPurpose::Export, &dir.path().join("not/there"));
+        assert_eq!(picker.current_dir(), dir.path());
+    }
+}
+
+mod about_tests {
+    use clisten::about::{self, AboutSection};
+    use clisten::action::Action;
+    use clisten::clipboard;
+    use clisten::components::about::AboutView;
+    use clisten::components::Component;
+    use clisten::config::Config;
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+    use tokio::sync::mpsc;
+
+    #[test]
+    fn test_report_covers_version_paths_and_api() {
+        let sections = about::report(&Config::default(), "https://example.test/api");
+        let text = about::to_text(&sections);
+        assert!(text.contains(env!("CARGO_PKG_VERSION")));
+        assert!(text.contains("clisten.db"));
+        assert!(text.contains("clisten.log"));
+        assert!(text.contains("config.toml"));
+        assert!(text.contains("https://example.test/api"));
+        assert!(text.contains("ratatui"));
+        assert!(text.contains("sqlite (bundled)"));
+    }
+
+    #[test]
+    fn test_report_shows_the_configured_mpv() {
+        let config: Config = toml::from_str("[player]\nmpv_path = \"/opt/no-such/mpv\"").unwrap();
+        let text = about::to_text(&about::report(&config, "https://example.test/api"));
+        assert!(text.contains("/opt/no-such/mpv not found"));
+    }
+
+    #[test]
+    fn test_dependency_versions_reads_plain_table_and_target_specs() {
+        let manifest = r#"
+            [dependencies]
+            toml = "0.8"
+            tokio = { version = "1", features = ["rt"] }
+            local = { path = "../local" }
+
+            [target.'cfg(unix)'.dependencies]
+            zbus = { version = "5" }
+        "#;
+        assert_eq!(
+            about::dependency_versions(manifest),
+            [
+                ("tokio".to_string(), "1".to_string()),
+                ("toml".to_string(), "0.8".to_string()),
+                ("zbus".to_string(), "5".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_to_text_aligns_labels() {
+        let sections = vec![AboutSection {
+            title: "Paths",
+            rows: vec![
+                ("Log".to_string(), "/a".to_string()),
+                ("Config".to_string(), "/b".to_string()),
+            ],
+        }];
+        assert_eq!(
+            about::to_text(&sections),
+            "Paths\n  Log     /a\n  Config  /b\n"
+        );
+    }
+
+    #[test]
+    fn test_base64_and_osc52() {
+        assert_eq!(clipboard::base64(b""), "");
+        assert_eq!(clipboard::base64(b"f"), "Zg==");
+        assert_eq!(clipboard::base64(b"fo"), "Zm8=");
+        assert_eq!(clipboard::base64(b"foo"), "Zm9v");
+        assert_eq!(clipboard::base64(b"foobar"), "Zm9vYmFy");
+        assert_eq!(clipboard::osc52("hi"), "\x1b]52;c;aGk=\x07");
+    }
+
+    #[test]
+    fn test_browser_openers() {
+        use clisten::browser;
+
+        let url = "https://www.nts.live/shows/a/episodes/b";
+        assert_eq!(
+            browser::opener_commands(url, None, "macos"),
+            vec![vec!["open".to_string(), url.to_string()]]
+        );
+        let linux = browser::opener_commands(url, Some("firefox --new-tab:lynx %s"), "linux");
+        assert_eq!(linux[0], ["firefox", "--new-tab", url]);
+        assert_eq!(linux[1], ["lynx", url]);
+        assert_eq!(linux[2], ["xdg-open", url]);
+        assert_eq!(
+            browser::opener_commands(url, Some(""), "windows"),
+            vec![vec!["cmd", "/C", "start", "", url]]
+        );
+        assert!(browser::open("file:///etc/passwd").is_err());
+    }
+
+    #[test]
+    fn test_copy_key_sends_report_text() {
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        let mut view = AboutView::new();
+        view.register_action_handler(tx);
+        let sections = about::report(&Config::default(), "https://example.test/api");
+        view.show(sections.clone());
+
+        view.handle_key_event(KeyEvent::new(KeyCode::Char('c'), KeyModifiers::NONE))
+            .unwrap();
+        assert!(
+            matches!(rx.try_recv().unwrap(), Action::CopyToClipboard(text) if text == about::to_text(&sections))
+        );
+        assert!(view.is_visible());
+
+        view.handle_key_event(KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE))
+            .unwrap();
+        assert!(!view.is_visible());
+    }
+}
@@ -1,5 +1,7 @@
-// SQLite database: queue persistence tests.
+// SQLite database: queue persistence, source preference, favorites, followed
+// shows, play history, resume position, usage metrics, and export/import tests.
 
+use clisten::action::Action;
 use clisten::api::models::DiscoveryItem;
 use clisten::db::Database;
 use clisten::player::queue::QueueItem;
@@ -23,6 +25,7 @@ fn make_episode(name: &str, alias: &str) -> DiscoveryItem {
         genres: vec!["Ambient".to_string()],
         location: Some("London".to_string()),
         audio_url: Some(format!("https://soundcloud.com/ntslive/{}", alias)),
+        broadcast_date: None,
     }
 }
 
@@ -139,6 +142,7 @@ fn test_save_queue_with_direct_url() {
         item: DiscoveryItem::DirectUrl {
             url: "https://youtube.com/watch?v=123".to_string(),
             title: Some("My Video".to_string()),
+            meta: None,
         },
         url: "https://youtube.com/watch?v=123".to_string(),
         stream_metadata: None,
@@ -152,6 +156,855 @@ fn test_save_queue_with_direct_url() {
     assert!(matches!(loaded[0].item, DiscoveryItem::DirectUrl { .. }));
 }
 
+#[test]
+fn test_database_uses_wal_mode() {
+    let (_db, dir) = open_temp_db();
+    let conn = rusqlite::Connection::open(dir.path().join("test.db")).unwrap();
+    let mode: String = conn
+        .query_row("PRAGMA journal_mode", [], |row| row.get(0))
+        .unwrap();
+    assert_eq!(mode, "wal");
+}
+
+#[test]
+fn test_save_queue_waits_for_concurrent_writer() {
+    let (db, dir) = open_temp_db();
+    let path = dir.path().join("test.db");
+
+    // Another connection holds the write lock briefly; save_queue should wait
+    // for it instead of failing with SQLITE_BUSY.
+    let (locked_tx, locked_rx) = std::sync::mpsc::channel();
+    let writer = std::thread::spawn(move || {
+        let conn = rusqlite::Connection::open(path).unwrap();
+        conn.execute_batch("BEGIN IMMEDIATE").unwrap();
+        locked_tx.send(()).unwrap();
+        std::thread::sleep(std::time::Duration::from_millis(300));
+        conn.execute_batch("COMMIT").unwrap();
+    });
+    locked_rx.recv().unwrap();
+
+    let items = vec![QueueItem {
+        item: make_episode("Ep", "ep-1"),
+        url: "https://a".to_string(),
+        stream_metadata: None,
+    }];
+    db.save_queue(&items, Some(0)).unwrap();
+    writer.join().unwrap();
+
+    let (loaded, _) = db.load_queue().unwrap();
+    assert_eq!(loaded.len(), 1);
+}
+
+// ── Source preferences ───────────────────────────────────────────────────────
+
+#[test]
+fn test_preferred_source_roundtrip() {
+    let (db, _dir) = open_temp_db();
+    let key = "nts:episode:show:ep";
+    assert_eq!(db.preferred_source(key).unwrap(), None);
+
+    db.set_preferred_source(key, "https://a").unwrap();
+    db.set_preferred_source(key, "https://b").unwrap();
+    assert_eq!(
+        db.preferred_source(key).unwrap().as_deref(),
+        Some("https://b")
+    );
+}
+
+// ── Favorites ────────────────────────────────────────────────────────────────
+
+#[test]
+fn test_favorites_add_remove() {
+    let (db, _dir) = open_temp_db();
+    let ep = make_episode("Ep", "ep-1");
+    let key = ep.favorite_key();
+    assert!(!db.is_favorite(&key).unwrap());
+
+    assert!(db.add_favorite(&ep).unwrap());
+    assert!(!db.add_favorite(&ep).unwrap(), "second add is a no-op");
+    assert!(db.is_favorite(&key).unwrap());
+    assert!(db.favorite_keys().unwrap().contains(&key));
+
+    db.remove_favorite(&key).unwrap();
+    assert!(!db.is_favorite(&key).unwrap());
+}
+
+#[test]
+fn test_favorites_order_pin_and_delete() {
+    let (db, _dir) = open_temp_db();
+    for alias in ["a", "b", "c"] {
+        db.add_favorite(&make_episode(alias, alias)).unwrap();
+    }
+    db.add_favorite(&DiscoveryItem::DirectUrl {
+        url: "https://radio.example".to_string(),
+        title: None,
+        meta: None,
+    })
+    .unwrap();
+    assert_eq!(db.favorite_count().unwrap(), 4);
+
+    let titles = |db: &Database| -> Vec<String> {
+        db.favorites()
+            .unwrap()
+            .iter()
+            .map(|f| f.item.title().to_string())
+            .collect()
+    };
+    // Newest first until reordered.
+    assert_eq!(titles(&db)[1..], ["c", "b", "a"]);
+    let favorites = db.favorites().unwrap();
+    assert_eq!(favorites[0].item_type, "url");
+    assert!(favorites.iter().all(|f| f.age_secs < 60));
+
+    let mut ids: Vec<i64> = favorites.iter().map(|f| f.id).collect();
+    ids.reverse();
+    db.set_favorite_positions(&ids).unwrap();
+    assert_eq!(titles(&db)[..3], ["a", "b", "c"]);
+
+    // Pinning floats an entry to the top; unpinning keeps its position.
+    let c = db.favorites().unwrap()[2].id;
+    db.set_favorite_pinned(c, true).unwrap();
+    assert_eq!(titles(&db)[0], "c");
+    assert!(db.favorites().unwrap()[0].pinned);
+    db.set_favorite_pinned(c, false).unwrap();
+    assert_eq!(titles(&db)[..3], ["a", "b", "c"]);
+
+    db.delete_favorite(c).unwrap();
+    assert_eq!(db.favorite_count().unwrap(), 3);
+    assert!(!titles(&db).contains(&"c".to_string()));
+}
+
+#[tokio::test]
+async fn test_favorites_view_deletes_and_filters() {
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+    let mut app = test_app();
+    app.handle_action(clisten::action::Action::FavoriteItems(vec![
+        make_episode("Ambient Hour", "ep-1"),
+        make_episode("Jazz Night", "ep-2"),
+    ]))
+    .await
+    .unwrap();
+    let press = |c| KeyEvent::new(KeyCode::Char(c), KeyModifiers::NONE);
+
+    app.handle_key(press('*')).unwrap();
+    app.flush_actions().await;
+    assert!(app.favorites_view.is_visible());
+    assert_eq!(app.favorites_view.visible().len(), 2);
+
+    // Text filter narrows the list; keys go to the filter while typing.
+    for c in "/jazz".chars() {
+        app.handle_key(press(c)).unwrap();
+    }
+    app.handle_key(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE))
+        .unwrap();
+    assert_eq!(app.favorites_view.visible().len(), 1);
+
+    app.handle_key(press('x')).unwrap();
+    app.flush_actions().await;
+    assert!(app.favorites_view.visible().is_empty());
+
+    app.handle_key(KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE))
+        .unwrap();
+    assert!(!app.favorites_view.is_visible());
+
+    // Reopening clears the filter and shows what's left.
+    app.handle_key(press('*')).unwrap();
+    app.flush_actions().await;
+    let left: Vec<_> = app
+        .favorites_view
+        .visible()
+        .iter()
+        .map(|f| f.item.title().to_string())
+        .collect();
+    assert_eq!(left, ["Ambient Hour"]);
+}
+
+#[tokio::test]
+async fn test_favorites_and_history_tabs_list_items_with_counts() {
+    use clisten::action::Action;
+    use clisten::components::nts::NtsSubTab;
+
+    let mut app = test_app();
+    assert_eq!(app.nts_tab.count(NtsSubTab::Favorites), 0);
+    app.handle_action(Action::SwitchSubTab(4)).await.unwrap();
+    app.flush_actions().await;
+    assert_eq!(app.nts_tab.active_sub(), NtsSubTab::Favorites);
+    assert!(app.discovery_list.visible_items().is_empty());
+
+    // Favoriting while on the tab refreshes the list and the count.
+    app.handle_action(Action::FavoriteItems(vec![
+        make_episode("Ambient Hour", "ep-1"),
+        make_episode("Jazz Night", "ep-2"),
+    ]))
+    .await
+    .unwrap();
+    assert_eq!(app.nts_tab.count(NtsSubTab::Favorites), 2);
+    assert_eq!(app.discovery_list.visible_items().len(), 2);
+
+    app.queue.clear();
+    app.queue.add(QueueItem {
+        item: make_episode("Ambient Hour", "ep-1"),
+        url: "https://a".to_string(),
+        stream_metadata: None,
+    });
+    app.handle_action(Action::PlaybackStarted {
+        title: "Ambient Hour".to_string(),
+    })
+    .await
+    .unwrap();
+    assert_eq!(app.nts_tab.count(NtsSubTab::History), 1);
+
+    app.handle_action(Action::SwitchSubTab(5)).await.unwrap();
+    app.flush_actions().await;
+    let titles: Vec<_> = app
+        .discovery_list
+        .visible_items()
+        .iter()
+        .map(|i| i.title().to_string())
+        .collect();
+    assert_eq!(titles, ["Ambient Hour"]);
+}
+
+// ── Play history ─────────────────────────────────────────────────────────────
+
+#[test]
+fn test_history_groups_plays_by_item() {
+    let (db, _dir) = open_temp_db();
+    let first = db.record_play(&make_episode("A", "a")).unwrap();
+    db.set_play_duration(first, 30.0).unwrap();
+    db.record_play(&make_episode("B", "b")).unwrap();
+    let again = db.record_play(&make_episode("A", "a")).unwrap();
+    db.set_play_duration(again, 15.5).unwrap();
+
+    let history = db.history(10).unwrap();
+    let titles: Vec<_> = history.iter().map(|h| h.item.title().to_string()).collect();
+    assert_eq!(titles, ["A", "B"], "most recently played first");
+    assert_eq!(history[0].play_count, 2);
+    assert!((history[0].listened_secs - 45.5).abs() < 1e-6);
+    assert!(history[0].age_secs < 60);
+    assert_eq!(db.history(1).unwrap().len(), 1);
+
+    db.delete_history(&make_episode("A", "a").favorite_key())
+        .unwrap();
+    assert_eq!(db.history(10).unwrap().len(), 1);
+    db.clear_history().unwrap();
+    assert!(db.history(10).unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_history_view_records_and_clears_with_confirm() {
+    use clisten::action::Action;
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+    let mut app = test_app();
+    app.queue.clear();
+    app.queue.add(QueueItem {
+        item: make_episode("Ep", "ep"),
+        url: "https://a".to_string(),
+        stream_metadata: None,
+    });
+    // A restart of the same item (e.g. reconnect) keeps one open play.
+    for _ in 0..2 {
+        app.handle_action(Action::PlaybackStarted {
+            title: "Ep".to_string(),
+        })
+        .await
+        .unwrap();
+    }
+    app.handle_action(Action::Stop).await.unwrap();
+
+    let press = |c| KeyEvent::new(KeyCode::Char(c), KeyModifiers::NONE);
+    app.handle_key(KeyEvent::new(KeyCode::Char('H'), KeyModifiers::SHIFT))
+        .unwrap();
+    app.flush_actions().await;
+    assert!(app.history_view.is_visible());
+    assert_eq!(app.history_view.entries().len(), 1);
+    assert_eq!(app.history_view.entries()[0].play_count, 1);
+
+    // Anything but `y` cancels the clear.
+    app.handle_key(KeyEvent::new(KeyCode::Char('C'), KeyModifiers::SHIFT))
+        .unwrap();
+    app.flush_actions().await;
+    assert_eq!(app.confirm_modal.prompt(), Some("Clear all play history?"));
+    app.handle_key(press('n')).unwrap();
+    app.flush_actions().await;
+    assert!(!app.confirm_modal.is_visible());
+    assert_eq!(app.history_view.entries().len(), 1);
+
+    app.handle_key(KeyEvent::new(KeyCode::Char('C'), KeyModifiers::SHIFT))
+        .unwrap();
+    app.flush_actions().await;
+    app.handle_key(press('y')).unwrap();
+    app.flush_actions().await;
+    assert!(app.history_view.entries().is_empty());
+}
+
+// ── Playlists ────────────────────────────────────────────────────────────────
+
+#[test]
+fn test_playlists_create_rename_add_and_delete() {
+    let (db, _dir) = open_temp_db();
+    let mix = db.create_playlist("  Late mix ").unwrap();
+    let ambient = db.create_playlist("ambient").unwrap();
+    assert!(db.create_playlist("Late mix").is_err());
+    assert!(db.create_playlist("   ").is_err());
+
+    let genre = DiscoveryItem::NtsGenre {
+        name: "Jazz".to_string(),
+        genre_id: "jazz".to_string(),
+    };
+    let added = db
+        .add_to_playlist(
+            mix,
+            &[
+                make_episode("One", "ep-1"),
+                genre,
+                make_episode("Two", "ep-2"),
+            ],
+        )
+        .unwrap();
+    assert_eq!(added, 2);
+    // Items already in the playlist are skipped; new ones go to the end.
+    let added = db
+        .add_to_playlist(
+            mix,
+            &[make_episode("Three", "ep-3"), make_episode("One", "ep-1")],
+        )
+        .unwrap();
+    assert_eq!(added, 1);
+    let titles: Vec<_> = db
+        .playlist_items(mix)
+        .unwrap()
+        .iter()
+        .map(|i| i.title().to_string())
+        .collect();
+    assert_eq!(titles, ["One", "Two", "Three"]);
+
+    assert!(db.rename_playlist(ambient, "Late mix").is_err());
+    db.rename_playlist(ambient, "Ambient").unwrap();
+    let listed: Vec<_> = db
+        .playlists()
+        .unwrap()
+        .into_iter()
+        .map(|p| (p.name, p.item_count))
+        .collect();
+    assert_eq!(
+        listed,
+        [("Ambient".to_string(), 0), ("Late mix".to_string(), 3)]
+    );
+
+    db.delete_playlist(mix).unwrap();
+    assert_eq!(db.playlists().unwrap().len(), 1);
+    assert!(db.playlist_items(mix).unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_playlist_manager_adds_opens_and_loads() {
+    use clisten::action::Action;
+    use clisten::components::nts::NtsSubTab;
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+    let press = |code| KeyEvent::new(code, KeyModifiers::NONE);
+    let mut app = test_app();
+    app.discovery_list.set_items(vec![
+        make_episode("One", "ep-1"),
+        make_episode("Two", "ep-2"),
+    ]);
+
+    // `+` with no playlists yet goes straight to naming a new one.
+    app.handle_key(KeyEvent::new(KeyCode::Char('+'), KeyModifiers::NONE))
+        .unwrap();
+    app.flush_actions().await;
+    assert!(app.playlists_view.is_visible() && app.playlists_view.is_adding());
+    for c in "Mix".chars() {
+        app.handle_key(press(KeyCode::Char(c))).unwrap();
+    }
+    app.handle_key(press(KeyCode::Enter)).unwrap();
+    app.flush_actions().await;
+    assert_eq!(app.playlists_view.playlists()[0].name, "Mix");
+    app.handle_key(press(KeyCode::Enter)).unwrap();
+    app.flush_actions().await;
+    assert!(!app.playlists_view.is_visible());
+    assert_eq!(app.nts_tab.count(NtsSubTab::Playlists), 1);
+
+    // Opening it from the manager switches to the Playlists tab.
+    app.handle_action(Action::ShowPlaylists).await.unwrap();
+    app.handle_key(press(KeyCode::Enter)).unwrap();
+    app.flush_actions().await;
+    assert_eq!(app.nts_tab.active_sub(), NtsSubTab::Playlists);
+    let titles: Vec<_> = app
+        .discovery_list
+        .visible_items()
+        .iter()
+        .map(|i| i.title().to_string())
+        .collect();
+    assert_eq!(titles, ["One"]);
+    assert!(app.discovery_list.heading().unwrap().starts_with("Mix"));
+
+    // Loading replaces the queue.
+    app.queue.clear();
+    app.queue.add(QueueItem {
+        item: make_episode("Old", "old"),
+        url: "https://old".to_string(),
+        stream_metadata: None,
+    });
+    let id = app.playlists_view.playlists()[0].id;
+    app.handle_action(Action::LoadPlaylist(id)).await.unwrap();
+    let queued: Vec<_> = app
+        .queue
+        .items()
+        .iter()
+        .map(|qi| qi.item.title().to_string())
+        .collect();
+    assert_eq!(queued, ["One"]);
+}
+
+// ── Snapshots ────────────────────────────────────────────────────────────────
+
+#[tokio::test]
+async fn test_cached_live_snapshot_shown_until_fresh_data() {
+    use clisten::action::Action;
+
+    let live = |name: &str| DiscoveryItem::NtsLiveChannel {
+        channel: 1,
+        show_name: name.to_string(),
+        genres: vec![],
+    };
+    let (db, _dir) = open_temp_db();
+    assert!(db.snapshot("live").unwrap().is_none());
+    db.save_snapshot("live", &[live("Older")]).unwrap();
+    db.save_snapshot("live", &[live("Yesterday")]).unwrap();
+    let (items, age) = db.snapshot("live").unwrap().unwrap();
+    assert_eq!(items.len(), 1);
+    assert!(age < 60);
+
+    // Fresh data is saved as the snapshot.
+    let mut app = test_app();
+    app.handle_action(Action::NtsLiveLoaded(vec![live("Yesterday")]))
+        .await
+        .unwrap();
+
+    // An empty Live list shows the snapshot, marked as cached...
+    app.discovery_list.set_items(vec![]);
+    app.handle_action(Action::LoadNtsLive).await.unwrap();
+    assert_eq!(app.discovery_list.visible_items()[0].title(), "Yesterday");
+    assert!(app.discovery_list.heading().unwrap().starts_with("Cached"));
+
+    // ...until the fetch returns.
+    app.handle_action(Action::NtsLiveLoaded(vec![live("Today")]))
+        .await
+        .unwrap();
+    assert_eq!(app.discovery_list.visible_items()[0].title(), "Today");
+    assert!(app.discovery_list.heading().is_none());
+}
+
+#[tokio::test]
+async fn test_stale_list_shown_only_on_its_tab_with_offline_heading() {
+    use clisten::action::Action;
+    use clisten::components::nts::NtsSubTab;
+    use std::time::Duration;
+
+    let live = DiscoveryItem::NtsLiveChannel {
+        channel: 1,
+        show_name: "Cached Show".to_string(),
+        genres: vec![],
+    };
+    let mut app = test_app();
+
+    // Picks isn't the open tab: nothing changes.
+    app.handle_action(Action::StaleListLoaded {
+        tab: NtsSubTab::Picks,
+        items: vec![make_episode("pick", "Pick")],
+        age: Duration::from_secs(600),
+    })
+    .await
+    .unwrap();
+    assert!(app.discovery_list.heading().is_none());
+
+    app.handle_action(Action::StaleListLoaded {
+        tab: NtsSubTab::Live,
+        items: vec![live],
+        age: Duration::from_secs(600),
+    })
+    .await
+    .unwrap();
+    assert_eq!(app.discovery_list.visible_items()[0].title(), "Cached Show");
+    assert_eq!(
+        app.discovery_list.heading(),
+        Some("Offline — cached 10m ago")
+    );
+}
+
+// ── Followed shows ───────────────────────────────────────────────────────────
+
+fn dated_episode(alias: &str, date: &str) -> DiscoveryItem {
+    let mut ep = make_episode(alias, alias);
+    if let DiscoveryItem::NtsEpisode { broadcast_date, .. } = &mut ep {
+        *broadcast_date = Some(date.to_string());
+    }
+    ep
+}
+
+#[test]
+fn test_follow_show_roundtrip() {
+    let (db, _dir) = open_temp_db();
+    assert!(db.follow_show("test-show", "Ep").unwrap());
+    assert!(
+        !db.follow_show("test-show", "Ep").unwrap(),
+        "already followed"
+    );
+
+    let shows = db.followed_shows().unwrap();
+    assert_eq!(shows.len(), 1);
+    assert_eq!(shows[0].show_alias, "test-show");
+    assert_eq!(shows[0].seen_until, None);
+
+    db.set_show_seen_until("test-show", "2024-03-01").unwrap();
+    assert_eq!(
+        db.followed_shows().unwrap()[0].seen_until.as_deref(),
+        Some("2024-03-01")
+    );
+
+    db.unfollow_show("test-show").unwrap();
+    assert!(db.followed_shows().unwrap().is_empty());
+}
+
+#[test]
+fn test_search_history_keeps_recent_queries_once() {
+    let (db, _dir) = open_temp_db();
+    db.record_search("ambient").unwrap();
+    db.record_search("jazz").unwrap();
+    db.record_search("ambient").unwrap();
+    assert_eq!(db.recent_searches(10).unwrap(), ["ambient", "jazz"]);
+    assert_eq!(db.recent_searches(1).unwrap(), ["ambient"]);
+
+    db.delete_search("ambient").unwrap();
+    assert_eq!(db.recent_searches(10).unwrap(), ["jazz"]);
+}
+
+#[test]
+fn test_bookmarks_are_kept_per_episode_in_position_order() {
+    let (db, _dir) = open_temp_db();
+    let late = db
+        .add_bookmark("nts:show/ep", 5400.0, "Closing track")
+        .unwrap();
+    db.add_bookmark("nts:show/ep", 1200.5, "Bookmark 2")
+        .unwrap();
+    db.add_bookmark("nts:show/other", 60.0, "Elsewhere")
+        .unwrap();
+
+    let bookmarks = db.bookmarks("nts:show/ep").unwrap();
+    let labels: Vec<&str> = bookmarks.iter().map(|b| b.label.as_str()).collect();
+    assert_eq!(labels, ["Bookmark 2", "Closing track"]);
+    assert_eq!(bookmarks[0].position_secs, 1200.5);
+
+    db.delete_bookmark(late).unwrap();
+    assert_eq!(db.bookmarks("nts:show/ep").unwrap().len(), 1);
+    assert!(db.bookmarks("nts:nothing").unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_followed_show_new_episodes_badge_and_markers() {
+    use clisten::action::Action;
+
+    let mut app = test_app();
+    let old = dated_episode("old", "2024-01-01T10:00:00Z");
+    app.handle_action(Action::ToggleFollow(old.clone()))
+        .await
+        .unwrap();
+
+    // First check only records the baseline: nothing is new yet.
+    app.handle_action(Action::FollowedEpisodesLoaded(vec![(
+        "test-show".to_string(),
+        vec![old.clone()],
+    )]))
+    .await
+    .unwrap();
+    assert_eq!(app.nts_tab.following_badge(), 0);
+
+    // A later check with a newer episode raises the badge and says so, once.
+    let new = dated_episode("new", "2024-02-01T10:00:00Z");
+    for _ in 0..2 {
+        app.handle_action(Action::FollowedEpisodesLoaded(vec![(
+            "test-show".to_string(),
+            vec![new.clone(), old.clone()],
+        )]))
+        .await
+        .unwrap();
+    }
+    assert_eq!(app.nts_tab.following_badge(), 1);
+    let announced = app
+        .notifications
+        .history()
+        .filter(|n| n.message == "New episode: new")
+        .count();
+    assert_eq!(announced, 1);
+
+    // Viewing the Following tab lists the episodes, flags the new one, and
+    // clears the badge.
+    app.nts_tab.switch_sub_tab(3);
+    app.handle_action(Action::FollowedEpisodesLoaded(vec![]))
+        .await
+        .unwrap();
+    let titles: Vec<&str> = app
+        .discovery_list
+        .visible_items()
+        .iter()
+        .map(|i| i.title())
+        .collect();
+    assert_eq!(titles, ["new", "old"]);
+    assert!(app.discovery_list.is_fresh(&new));
+    assert!(!app.discovery_list.is_fresh(&old));
+    assert_eq!(app.nts_tab.following_badge(), 0);
+
+    // Seen now, so the same episodes are no longer new.
+    app.handle_action(Action::FollowedEpisodesLoaded(vec![(
+        "test-show".to_string(),
+        vec![new, old],
+    )]))
+    .await
+    .unwrap();
+    assert_eq!(app.nts_tab.following_badge(), 0);
+}
+
+// ── Resume positions ─────────────────────────────────────────────────────────
+
+#[test]
+fn test_resume_position_roundtrip() {
+    let (db, _dir) = open_temp_db();
+    let key = "nts:episode:show:ep";
+    assert_eq!(db.resume_position(key).unwrap(), None);
+
+    db.set_resume_position(key, "https://a", 120.0).unwrap();
+    db.set_resume_position(key, "https://a", 240.5).unwrap();
+    assert_eq!(db.resume_position(key).unwrap(), Some(240.5));
+
+    db.clear_resume_position(key).unwrap();
+    assert_eq!(db.resume_position(key).unwrap(), None);
+}
+
+#[test]
+fn test_import_resume_position_only_once() {
+    let (db, _dir) = open_temp_db();
+    let key = "nts:episode:show:ep";
+    assert!(db
+        .import_resume_position("ABC", key, "https://a", 90.0)
+        .unwrap());
+    assert_eq!(db.resume_position(key).unwrap(), Some(90.0));
+
+    // A cleared position is not re-imported from the same entry.
+    db.clear_resume_position(key).unwrap();
+    assert!(!db
+        .import_resume_position("ABC", key, "https://a", 90.0)
+        .unwrap());
+    assert_eq!(db.resume_position(key).unwrap(), None);
+
+    // A fresh entry never overrides a position clisten recorded itself.
+    db.set_resume_position(key, "https://a", 300.0).unwrap();
+    assert!(!db
+        .import_resume_position("DEF", key, "https://a", 90.0)
+        .unwrap());
+    assert_eq!(db.resume_position(key).unwrap(), Some(300.0));
+}
+
+#[tokio::test]
+async fn test_resume_position_saved_periodically_and_on_pause() {
+    use clisten::action::Action;
+
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("test.db");
+    let config = clisten::config::Config::default();
+    let ticks_per_save = (config.general.frame_rate * 12.0) as u32;
+    let mut app = clisten::app::App::with_db(config, Database::open_at(&path).unwrap()).unwrap();
+    let item = make_episode("Ep", "ep");
+    let key = item.favorite_key();
+    app.queue.clear();
+    app.queue.add(QueueItem {
+        item,
+        url: "https://a".to_string(),
+        stream_metadata: None,
+    });
+    app.handle_action(Action::PlayQueueIndex(0)).await.unwrap();
+    app.handle_action(Action::PlaybackDuration(Some(3600.0)))
+        .await
+        .unwrap();
+    app.handle_action(Action::PlaybackPosition(100.0))
+        .await
+        .unwrap();
+    let stored = || {
+        Database::open_at(&path)
+            .unwrap()
+            .resume_position(&key)
+            .unwrap()
+    };
+
+    // Position updates alone don't write; the periodic tick does.
+    for _ in 1..ticks_per_save {
+        app.handle_action(Action::Tick).await.unwrap();
+    }
+    assert_eq!(stored(), None);
+    app.handle_action(Action::Tick).await.unwrap();
+    assert_eq!(stored(), Some(100.0));
+
+    // Pausing writes straight away.
+    app.handle_action(Action::PlaybackPosition(250.0))
+        .await
+        .unwrap();
+    app.handle_action(Action::TogglePlayPause).await.unwrap();
+    assert_eq!(stored(), Some(250.0));
+}
+
+// ── Usage metrics ────────────────────────────────────────────────────────────
+
+#[test]
+fn test_metric_counts_accumulate_and_clear() {
+    use std::collections::BTreeMap;
+
+    let (db, _dir) = open_temp_db();
+    let batch = BTreeMap::from([
+        ("play.nts_live".to_string(), 2),
+        ("key.cycle_sort".to_string(), 1),
+    ]);
+    db.add_metric_counts(&batch).unwrap();
+    db.add_metric_counts(&BTreeMap::from([("play.nts_live".to_string(), 3)]))
+        .unwrap();
+    assert_eq!(
+        db.metric_counts().unwrap(),
+        vec![
+            ("play.nts_live".to_string(), 5),
+            ("key.cycle_sort".to_string(), 1)
+        ]
+    );
+    db.clear_metrics().unwrap();
+    assert!(db.metric_counts().unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_metrics_are_opt_in_and_flushed_on_quit() {
+    use clisten::action::Action;
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+    for enabled in [false, true] {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("test.db");
+        let mut config = clisten::config::Config::default();
+        config.general.metrics = enabled;
+        let mut app =
+            clisten::app::App::with_db(config, Database::open_at(&path).unwrap()).unwrap();
+        app.queue.clear();
+        app.queue.add(QueueItem {
+            item: make_episode("Ep", "ep"),
+            url: "https://a".to_string(),
+            stream_metadata: None,
+        });
+        app.handle_action(Action::PlaybackStarted {
+            title: "Ep".to_string(),
+        })
+        .await
+        .unwrap();
+        app.handle_key(KeyEvent::new(KeyCode::Char('S'), KeyModifiers::SHIFT))
+            .unwrap();
+        app.handle_action(Action::Quit).await.unwrap();
+
+        let counts = Database::open_at(&path).unwrap().metric_counts().unwrap();
+        if enabled {
+            assert!(counts.contains(&("play.nts_episode".to_string(), 1)));
+            assert!(counts.contains(&("key.cycle_sort".to_string(), 1)));
+        } else {
+            assert!(counts.is_empty());
+        }
+    }
+}
+
+#[test]
+fn test_import_watch_later_seeds_queued_items() {
+    use clisten::player::watch_later;
+
+    let dir = tempfile::tempdir().expect("tempdir");
+    let db_path = dir.path().join("test.db");
+    let watch_dir = dir.path().join("watch_later");
+    std::fs::create_dir_all(&watch_dir).unwrap();
+
+    let item = make_episode("Ep", "ep-1");
+    let url = item.playback_url().unwrap();
+    let db = Database::open_at(&db_path).unwrap();
+    db.save_queue(
+        &[QueueItem {
+            item: item.clone(),
+            url: url.clone(),
+            stream_metadata: None,
+        }],
+        Some(0),
+    )
+    .unwrap();
+    std::fs::write(
+        watch_dir.join(watch_later::entry_name(&url)),
+        "# some comment\nstart=1234.500000\nvolume=80\n",
+    )
+    .unwrap();
+
+    let app = clisten::app::App::with_db(clisten::config::Config::default(), db).unwrap();
+    assert_eq!(app.import_watch_later(std::slice::from_ref(&watch_dir)), 1);
+    assert_eq!(app.import_watch_later(std::slice::from_ref(&watch_dir)), 0);
+
+    let db = Database::open_at(&db_path).unwrap();
+    assert_eq!(
+        db.resume_position(&item.favorite_key()).unwrap(),
+        Some(1234.5)
+    );
+}
+
+#[test]
+fn test_watch_later_parse_start() {
+    use clisten::player::watch_later::{entry_name, parse_start};
+
+    assert_eq!(parse_start("start=42.000000\npause=no\n"), Some(42.0));
+    assert_eq!(parse_start("# url\nvolume=50\n"), None);
+    assert_eq!(parse_start("start=0.000000\n"), None);
+    // mpv names entries by the uppercase MD5 of the path or URL.
+    assert_eq!(entry_name(""), "D41D8CD98F00B204E9800998ECF8427E");
+}
+
+// ── Diagnostics ──────────────────────────────────────────────────────────────
+
+#[test]
+fn test_doctor_database_check_passes_on_fresh_db() {
+    use clisten::doctor::{check_database_with, Status};
+
+    let (db, _dir) = open_temp_db();
+    assert_eq!(db.integrity_check().unwrap(), "ok");
+    assert_eq!(check_database_with(&db).status, Status::Pass);
+}
+
+// ── Headless playback ────────────────────────────────────────────────────────
+
+#[test]
+fn test_headless_resolves_urls_and_favorites() {
+    use clisten::headless::resolve_target;
+
+    let (db, _dir) = open_temp_db();
+    let url = "https://example.com/stream.mp3";
+    assert!(matches!(
+        resolve_target(&db, url).unwrap(),
+        DiscoveryItem::DirectUrl { url: u, title: None, .. } if u == url
+    ));
+
+    db.add_favorite(&make_episode("Morning Ambient", "ep-1"))
+        .unwrap();
+    db.add_favorite(&make_episode("Evening Ambient", "ep-2"))
+        .unwrap();
+    let by_key = resolve_target(&db, "nts:episode:test-show:ep-2").unwrap();
+    assert_eq!(by_key.title(), "Evening Ambient");
+    let by_title = resolve_target(&db, "morning").unwrap();
+    assert_eq!(by_title.title(), "Morning Ambient");
+
+    let ambiguous = resolve_target(&db, "ambient").unwrap_err().to_string();
+    assert!(ambiguous.contains("matches 2 favorites"), "{ambiguous}");
+    assert!(ambiguous.contains("nts:episode:test-show:ep-1"));
+    assert!(resolve_target(&db, "jazz").is_err());
+}
+
 // ── Number keys for sub-tabs ─────────────────────────────────────────────────
 
 #[test]
@@ -281,6 +1134,26 @@ async fn test_tab_cycles_sub_tabs() {
     app.flush_actions().await;
     assert_eq!(app.nts_tab.active_sub(), NtsSubTab::Search);
 
+    // Tab → Following
+    let key = KeyEvent::new(KeyCode::Tab, KeyModifiers::NONE);
+    app.handle_key(key).unwrap();
+    app.flush_actions().await;
+    assert_eq!(app.nts_tab.active_sub(), NtsSubTab::Following);
+
+    // Tab → Favorites → History → Playlists → For You → Podcasts
+    for expected in [
+        NtsSubTab::Favorites,
+        NtsSubTab::History,
+        NtsSubTab::Playlists,
+        NtsSubTab::ForYou,
+        NtsSubTab::Podcasts,
+    ] {
+        let key = KeyEvent::new(KeyCode::Tab, KeyModifiers::NONE);
+        app.handle_key(key).unwrap();
+        app.flush_actions().await;
+        assert_eq!(app.nts_tab.active_sub(), expected);
+    }
+
     // Tab → wraps to Live
     let key = KeyEvent::new(KeyCode::Tab, KeyModifiers::NONE);
     app.handle_key(key).unwrap();
@@ -296,15 +1169,155 @@ async fn test_backtab_cycles_sub_tabs_reverse() {
     let mut app = test_app();
     assert_eq!(app.nts_tab.active_sub(), NtsSubTab::Live);
 
-    // BackTab → wraps to Search
+    // BackTab → wraps to Podcasts, then For You
     let key = KeyEvent::new(KeyCode::BackTab, KeyModifiers::SHIFT);
     app.handle_key(key).unwrap();
     app.flush_actions().await;
-    assert_eq!(app.nts_tab.active_sub(), NtsSubTab::Search);
+    assert_eq!(app.nts_tab.active_sub(), NtsSubTab::Podcasts);
 
-    // BackTab → Picks
     let key = KeyEvent::new(KeyCode::BackTab, KeyModifiers::SHIFT);
     app.handle_key(key).unwrap();
     app.flush_actions().await;
-    assert_eq!(app.nts_tab.active_sub(), NtsSubTab::Picks);
+    assert_eq!(app.nts_tab.active_sub(), NtsSubTab::ForYou);
+
+    // BackTab → Playlists
+    let key = KeyEvent::new(KeyCode::BackTab, KeyModifiers::SHIFT);
+    app.handle_key(key).unwrap();
+    app.flush_actions().await;
+    assert_eq!(app.nts_tab.active_sub(), NtsSubTab::Playlists);
+
+    // BackTab → History
+    let key = KeyEvent::new(KeyCode::BackTab, KeyModifiers::SHIFT);
+    app.handle_key(key).unwrap();
+    app.flush_actions().await;
+    assert_eq!(app.nts_tab.active_sub(), NtsSubTab::History);
+
+    // BackTab → Favorites
+    let key = KeyEvent::new(KeyCode::BackTab, KeyModifiers::SHIFT);
+    app.handle_key(key).unwrap();
+    app.flush_actions().await;
+    assert_eq!(app.nts_tab.active_sub(), NtsSubTab::Favorites);
+}
+
+#[test]
+fn test_taste_ranks_genres_and_shows_by_plays_and_favorites() {
+    use clisten::api::genres::genre_categories;
+    use clisten::recommend::{genre_id_by_name, taste};
+
+    let (db, _dir) = open_temp_db();
+    let jazz = DiscoveryItem::NtsEpisode {
+        name: "Jazz Hour".to_string(),
+        show_alias: "jazz-show".to_string(),
+        episode_alias: "one".to_string(),
+        genres: vec!["Jazz".to_string()],
+        location: None,
+        audio_url: None,
+        broadcast_date: None,
+    };
+    for _ in 0..2 {
+        db.record_play(&make_episode("Drift", "drift")).unwrap();
+    }
+    db.record_play(&jazz).unwrap();
+    // A favorite outweighs the two ambient plays.
+    db.add_favorite(&jazz).unwrap();
+
+    let taste = taste(&db.history(100).unwrap(), &db.favorites().unwrap());
+    assert_eq!(taste.genres, ["Jazz", "Ambient"]);
+    assert_eq!(taste.shows, ["jazz-show", "test-show"]);
+
+    let categories = genre_categories();
+    assert_eq!(genre_id_by_name(&categories, "jazz"), Some("jazz"));
+    assert_eq!(
+        genre_id_by_name(&categories, "Detroit House"),
+        Some("housetechno-house-detroithouse")
+    );
+    assert_eq!(genre_id_by_name(&categories, "Polka"), None);
+}
+
+#[tokio::test]
+async fn test_for_you_tab_without_history_says_how_to_fill_it() {
+    use clisten::components::nts::NtsSubTab;
+
+    let mut app = test_app();
+    app.handle_action(Action::SwitchSubTab(7)).await.unwrap();
+    app.flush_actions().await;
+    assert_eq!(app.nts_tab.active_sub(), NtsSubTab::ForYou);
+    assert!(app.discovery_list.visible_items().is_empty());
+    assert!(!app.discovery_list.is_loading());
+    assert_eq!(app.discovery_list.heading(), None);
+}
+
+// ── Export / import ──────────────────────────────────────────────────────────
+
+#[tokio::test]
+async fn test_export_import_merges_on_key() {
+    use clisten::transfer::{ExportFile, TransferCounts};
+
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("export.json");
+
+    let mut source = test_app();
+    source
+        .handle_action(clisten::action::Action::FavoriteItems(vec![
+            make_episode("A", "a"),
+            make_episode("B", "b"),
+        ]))
+        .await
+        .unwrap();
+    source.queue.clear();
+    source.queue.add(QueueItem {
+        item: make_episode("Q", "q"),
+        url: "https://q".to_string(),
+        stream_metadata: None,
+    });
+    source
+        .handle_action(clisten::action::Action::PlaybackStarted {
+            title: "Q".to_string(),
+        })
+        .await
+        .unwrap();
+    source.export_data(&path);
+    let file = ExportFile::read(&path).unwrap();
+    assert_eq!(
+        file.counts(),
+        TransferCounts {
+            favorites: 2,
+            plays: 1,
+            queued: 1
+        }
+    );
+
+    // The target already has one of the favorites and the queued item.
+    let (db, _db_dir) = open_temp_db();
+    db.add_favorite(&make_episode("A", "a")).unwrap();
+    let queue = vec![QueueItem {
+        item: make_episode("Q", "q"),
+        url: "https://q".to_string(),
+        stream_metadata: None,
+    }];
+    let (counts, new_items) = file.import_into(&db, &queue).unwrap();
+    assert_eq!(
+        counts,
+        TransferCounts {
+            favorites: 1,
+            plays: 1,
+            queued: 0
+        }
+    );
+    assert!(new_items.is_empty());
+    assert_eq!(db.favorite_count().unwrap(), 2);
+
+    // Importing the same file again adds nothing.
+    let (counts, _) = file.import_into(&db, &[]).unwrap();
+    assert_eq!(counts.favorites + counts.plays, 0);
+    assert_eq!(counts.queued, 1);
+    assert_eq!(db.history(10).unwrap()[0].play_count, 1);
+}
+
+#[test]
+fn test_import_rejects_newer_format() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("export.json");
+    std::fs::write(&path, r#"{"version": 99}"#).unwrap();
+    assert!(clisten::transfer::ExportFile::read(&path).is_err());
 }
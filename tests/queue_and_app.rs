@@ -1,8 +1,13 @@
 // Queue operations, action dispatch, error/help overlays, and keybinding integration.
 
+use std::time::Duration;
+
 use clisten::action::Action;
+use clisten::api::fixture::FixtureApi;
 use clisten::api::models::DiscoveryItem;
+use clisten::components::notifications::Notification;
 use clisten::db::Database;
+use clisten::player::fake::FakePlayer;
 use clisten::player::queue::{Queue, QueueItem};
 
 // ── Helpers ──────────────────────────────────────────────────────────────────
@@ -17,6 +22,24 @@ fn test_app() -> clisten::app::App {
     clisten::app::App::with_db(clisten::config::Config::default(), db).unwrap()
 }
 
+/// Like `test_app`, with NTS requests answered by `api`.
+fn fixture_app(api: FixtureApi) -> clisten::app::App {
+    test_app().with_nts_api(api)
+}
+
+/// Let spawned fetches finish and handle the actions they send.
+async fn settle(app: &mut clisten::app::App) {
+    for _ in 0..10 {
+        tokio::task::yield_now().await;
+        app.flush_actions().await;
+    }
+}
+
+/// Message of the newest on-screen notification.
+fn latest_message(app: &clisten::app::App) -> Option<String> {
+    app.notifications.active().last().map(|n| n.message.clone())
+}
+
 fn make_queue_item(title: &str, url: &str) -> QueueItem {
     QueueItem {
         item: DiscoveryItem::NtsEpisode {
@@ -26,6 +49,7 @@ fn make_queue_item(title: &str, url: &str) -> QueueItem {
             genres: vec![],
             location: None,
             audio_url: Some(url.to_string()),
+            broadcast_date: None,
         },
         url: url.to_string(),
         stream_metadata: None,
@@ -40,6 +64,7 @@ fn make_item(title: &str) -> DiscoveryItem {
         genres: vec![],
         location: None,
         audio_url: Some(format!("http://{}", title)),
+        broadcast_date: None,
     }
 }
 
@@ -118,6 +143,20 @@ fn test_queue_remove_current_adjusts_index() {
     assert_eq!(q.current_index(), Some(0));
 }
 
+#[test]
+fn test_queue_insert_keeps_cursor_on_current_track() {
+    let mut q = Queue::new();
+    q.add(make_queue_item("Track 1", "http://a"));
+    q.add(make_queue_item("Track 2", "http://b"));
+    q.advance();
+    q.insert(0, make_queue_item("Track 0", "http://z"));
+    assert_eq!(q.current_index(), Some(2));
+    assert_eq!(q.current().unwrap().url, "http://b");
+    q.insert(99, make_queue_item("Track 3", "http://c"));
+    assert_eq!(q.items()[3].url, "http://c");
+    assert_eq!(q.current_index(), Some(2));
+}
+
 #[test]
 fn test_queue_remove_all_clears_index() {
     let mut q = Queue::new();
@@ -325,12 +364,13 @@ fn test_queue_action_variants_exist() {
     let _prev = Action::PrevTrack;
 }
 
-// ── Error handling ───────────────────────────────────────────────────────────
+// ── Notifications ────────────────────────────────────────────────────────────
 
 #[test]
-fn test_show_error_action_exists() {
-    let _a = Action::ShowError("test error".to_string());
-    let _b = Action::ClearError;
+fn test_notify_action_variants_exist() {
+    let _a = Action::Notify(Notification::error("test error"));
+    let _b = Action::ShowNotificationHistory;
+    let _c = Action::HideNotificationHistory;
 }
 
 // ── Help overlay ─────────────────────────────────────────────────────────────
@@ -344,22 +384,31 @@ fn test_help_action_variants_exist() {
 // ── App integration tests ─────────────────────────────────────────────────────
 
 #[tokio::test]
-async fn test_show_error_sets_message() {
+async fn test_notify_sets_message() {
     let mut app = test_app();
-    app.handle_action(Action::ShowError("test error".to_string()))
+    app.handle_action(Action::Notify(Notification::error("test error")))
         .await
         .unwrap();
-    assert_eq!(app.error_message.as_deref(), Some("test error"));
+    assert_eq!(latest_message(&app).as_deref(), Some("test error"));
+    assert!(app.notifications.has_error());
 }
 
 #[tokio::test]
-async fn test_clear_error_clears_message() {
+async fn test_tick_expires_notifications() {
     let mut app = test_app();
-    app.handle_action(Action::ShowError("err".to_string()))
+    app.handle_action(Action::Notify(
+        Notification::error("err").with_ttl(Duration::ZERO),
+    ))
+    .await
+    .unwrap();
+    app.handle_action(Action::Notify(Notification::info("still here")))
         .await
         .unwrap();
-    app.handle_action(Action::ClearError).await.unwrap();
-    assert!(app.error_message.is_none());
+    app.handle_action(Action::Tick).await.unwrap();
+    assert!(!app.notifications.has_error());
+    assert_eq!(latest_message(&app).as_deref(), Some("still here"));
+    // Expired messages stay in the history.
+    assert_eq!(app.notifications.history().count(), 2);
 }
 
 #[tokio::test]
@@ -421,16 +470,427 @@ async fn test_key_a_adds_to_queue() {
 }
 
 #[tokio::test]
-async fn test_key_c_clears_queue() {
+async fn test_marked_items_are_enqueued_together() {
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+    let mut app = test_app();
+    app.queue.clear();
+    app.discovery_list.set_items(vec![
+        make_item("track1"),
+        make_item("track2"),
+        make_item("track3"),
+        make_item("track4"),
+    ]);
+    let press = |c| KeyEvent::new(KeyCode::Char(c), KeyModifiers::NONE);
+
+    // Mark track1 (cursor moves to track2), move to track3, range-mark back.
+    app.handle_key(press('m')).unwrap();
+    app.handle_key(press('j')).unwrap();
+    app.handle_key(press('V')).unwrap();
+    assert_eq!(app.discovery_list.marked_count(), 3);
+
+    app.handle_key(press('a')).unwrap();
+    app.flush_actions().await;
+    let urls: Vec<_> = app.queue.items().iter().map(|q| q.url.as_str()).collect();
+    assert_eq!(urls, ["http://track1", "http://track2", "http://track3"]);
+    assert_eq!(app.discovery_list.marked_count(), 0);
+    assert_eq!(latest_message(&app).as_deref(), Some("Queued 3 items"));
+}
+
+#[tokio::test]
+async fn test_context_menu_acts_on_all_marked_items() {
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+    let mut app = test_app();
+    app.queue.clear();
+    app.discovery_list
+        .set_items(vec![make_item("track1"), make_item("track2")]);
+    let press = |code| KeyEvent::new(code, KeyModifiers::NONE);
+    app.handle_action(Action::FavoriteItems(vec![make_item("track2")]))
+        .await
+        .unwrap();
+
+    app.handle_key(press(KeyCode::Char('m'))).unwrap();
+    app.handle_key(press(KeyCode::Char('m'))).unwrap();
+    app.handle_key(press(KeyCode::Char('.'))).unwrap();
+    assert_eq!(
+        app.context_menu.labels(),
+        ["Play all next", "Add all to queue", "Favorite all"]
+    );
+    app.handle_key(press(KeyCode::Char('j'))).unwrap();
+    app.handle_key(press(KeyCode::Char('j'))).unwrap();
+    app.handle_key(press(KeyCode::Enter)).unwrap();
+    app.flush_actions().await;
+    assert_eq!(app.discovery_list.marked_count(), 0);
+    assert_eq!(
+        latest_message(&app).as_deref(),
+        Some("Added 1 of 2 favorites (the rest already were)")
+    );
+    assert!(app.queue.is_empty());
+}
+
+#[tokio::test]
+async fn test_escape_clears_marks() {
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+    let mut app = test_app();
+    app.discovery_list
+        .set_items(vec![make_item("track1"), make_item("track2")]);
+    app.handle_key(KeyEvent::new(KeyCode::Char('m'), KeyModifiers::NONE))
+        .unwrap();
+    assert_eq!(app.discovery_list.marked_count(), 1);
+
+    app.handle_key(KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE))
+        .unwrap();
+    app.flush_actions().await;
+    assert_eq!(app.discovery_list.marked_count(), 0);
+}
+
+#[tokio::test]
+async fn test_vim_motions_move_the_list_selection() {
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+    let mut app = test_app();
+    let items = (1..=20)
+        .map(|i| make_item(&format!("track{}", i)))
+        .collect();
+    app.discovery_list.set_items(items);
+    let press = |c| KeyEvent::new(KeyCode::Char(c), KeyModifiers::NONE);
+
+    app.handle_key(press('5')).unwrap();
+    app.handle_key(press('j')).unwrap();
+    assert_eq!(app.discovery_list.selected_index(), Some(5));
+
+    app.handle_key(KeyEvent::new(KeyCode::Char('G'), KeyModifiers::SHIFT))
+        .unwrap();
+    assert_eq!(app.discovery_list.selected_index(), Some(19));
+    app.handle_key(press('g')).unwrap();
+    app.handle_key(press('g')).unwrap();
+    assert_eq!(app.discovery_list.selected_index(), Some(0));
+
+    app.handle_key(press('1')).unwrap();
+    app.handle_key(press('2')).unwrap();
+    app.handle_key(KeyEvent::new(KeyCode::Char('G'), KeyModifiers::SHIFT))
+        .unwrap();
+    assert_eq!(app.discovery_list.selected_index(), Some(11));
+
+    let half = app.discovery_list.half_page();
+    app.handle_key(KeyEvent::new(KeyCode::Char('u'), KeyModifiers::CONTROL))
+        .unwrap();
+    assert_eq!(app.discovery_list.selected_index(), Some(11 - half));
+    // Tab jumps on the digit keys never ran.
+    app.flush_actions().await;
+    assert_eq!(app.nts_tab.active_index(), 0);
+}
+
+#[tokio::test]
+async fn test_lone_digit_still_switches_tab_after_the_prefix_timeout() {
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+    let mut app = test_app();
+    app.handle_key(KeyEvent::new(KeyCode::Char('2'), KeyModifiers::NONE))
+        .unwrap();
+    app.flush_actions().await;
+    assert_eq!(app.nts_tab.active_index(), 0);
+
+    tokio::time::sleep(Duration::from_millis(550)).await;
+    app.handle_action(Action::Tick).await.unwrap();
+    app.flush_actions().await;
+    assert_eq!(app.nts_tab.active_index(), 1);
+}
+
+#[tokio::test]
+async fn test_find_in_list_jumps_between_matches() {
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+    let mut app = test_app();
+    app.discovery_list.set_items(vec![
+        make_item("alpha"),
+        make_item("beta"),
+        make_item("gamma"),
+        make_item("alphabet"),
+    ]);
+    let press = |c| KeyEvent::new(KeyCode::Char(c), KeyModifiers::NONE);
+
+    app.handle_key(press('j')).unwrap();
+    app.handle_key(KeyEvent::new(KeyCode::Char('f'), KeyModifiers::CONTROL))
+        .unwrap();
+    app.handle_key(press('a')).unwrap();
+    app.handle_key(press('l')).unwrap();
+    // Incremental: the first match from where the find started.
+    assert_eq!(app.discovery_list.selected_index(), Some(3));
+    app.handle_key(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE))
+        .unwrap();
+    assert_eq!(app.discovery_list.find_query(), Some("al"));
+
+    app.handle_key(press('n')).unwrap();
+    assert_eq!(app.discovery_list.selected_index(), Some(0));
+    app.handle_key(KeyEvent::new(KeyCode::Char('N'), KeyModifiers::SHIFT))
+        .unwrap();
+    assert_eq!(app.discovery_list.selected_index(), Some(3));
+    // A huge count goes no further than round the list.
+    for c in "999999999999n".chars() {
+        app.handle_key(press(c)).unwrap();
+    }
+    assert_eq!(app.discovery_list.selected_index(), Some(3));
+    // Every row still shows; find only moves the cursor.
+    assert_eq!(app.discovery_list.visible_items().len(), 4);
+
+    app.handle_key(KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE))
+        .unwrap();
+    app.flush_actions().await;
+    assert_eq!(app.discovery_list.find_query(), None);
+}
+
+#[tokio::test]
+async fn test_grouped_genres_fold_and_jump_by_letter() {
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+    let mut config = clisten::config::Config::default();
+    config.nts.group_genres = Some(true);
+    // Nothing listens here, so the built-in genre list stays up.
+    config.nts.api_base = Some("http://127.0.0.1:9".to_string());
+    let dir = tempfile::tempdir().unwrap();
+    let db = Database::open_at(&dir.path().join("test.db")).unwrap();
+    let mut app = clisten::app::App::with_db(config, db).unwrap();
+    app.handle_action(Action::SwitchSubTab(2)).await.unwrap();
+    app.flush_actions().await;
+
+    let headers = app.discovery_list.visible_items().len();
+    assert!(app
+        .discovery_list
+        .visible_items()
+        .iter()
+        .all(|item| matches!(
+            item,
+            DiscoveryItem::NtsGenreCategory {
+                expanded: false,
+                ..
+            }
+        )));
+
+    // Enter on a header unfolds it and keeps it selected.
+    app.handle_key(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE))
+        .unwrap();
+    app.flush_actions().await;
+    assert!(app.discovery_list.visible_items().len() > headers);
+    assert!(matches!(
+        app.discovery_list.selected_item(),
+        Some(DiscoveryItem::NtsGenreCategory { expanded: true, .. })
+    ));
+
+    app.handle_key(KeyEvent::new(KeyCode::Char('\''), KeyModifiers::NONE))
+        .unwrap();
+    app.handle_key(KeyEvent::new(KeyCode::Char('j'), KeyModifiers::NONE))
+        .unwrap();
+    assert_eq!(
+        app.discovery_list.selected_item().map(|item| item.title()),
+        Some("Jazz")
+    );
+}
+
+#[tokio::test]
+async fn test_genre_tree_and_counts_from_the_api_replace_the_builtin_list() {
+    use clisten::api::genres::GenreCategory;
+
+    let mut app = test_app();
+    app.handle_action(Action::SwitchSubTab(2)).await.unwrap();
+    app.flush_actions().await;
+    assert!(app.discovery_list.visible_items().len() > 100);
+
+    let tree = vec![GenreCategory {
+        id: "jazz".to_string(),
+        name: "Jazz".to_string(),
+        genres: vec![("jazz-spiritual".to_string(), "Spiritual Jazz".to_string())],
+    }];
+    app.handle_action(Action::GenreTreeLoaded(tree))
+        .await
+        .unwrap();
+    let titles: Vec<&str> = app
+        .discovery_list
+        .visible_items()
+        .iter()
+        .map(|item| item.title())
+        .collect();
+    assert_eq!(titles, ["Jazz", "Spiritual Jazz"]);
+
+    app.handle_action(Action::GenreCountLoaded {
+        genre_id: "jazz-spiritual".to_string(),
+        count: 321,
+    })
+    .await
+    .unwrap();
+    let spiritual = &app.discovery_list.visible_items()[1];
+    assert_eq!(app.discovery_list.genre_count(spiritual), Some(321));
+}
+
+#[tokio::test]
+async fn test_moods_replace_genres_on_the_search_tab() {
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+    let dir = tempfile::tempdir().unwrap();
+    let db = Database::open_at(&dir.path().join("test.db")).unwrap();
+    let mut config = clisten::config::Config::default();
+    config.nts.api_base = Some("http://127.0.0.1:9".to_string());
+    let mut app = clisten::app::App::with_db(config, db).unwrap();
+    app.handle_action(Action::SwitchSubTab(2)).await.unwrap();
+    app.flush_actions().await;
+    app.handle_action(Action::ToggleMoods).await.unwrap();
+    app.handle_action(Action::MoodsLoaded(vec![DiscoveryItem::NtsMood {
+        name: "Late Night".to_string(),
+        mood_id: "mood-late-night".to_string(),
+    }]))
+    .await
+    .unwrap();
+    let titles: Vec<&str> = app
+        .discovery_list
+        .visible_items()
+        .iter()
+        .map(|item| item.title())
+        .collect();
+    assert_eq!(titles, ["Late Night"]);
+    assert_eq!(app.discovery_list.heading(), Some("Moods"));
+
+    // Enter searches the mood's episodes instead of playing it.
+    app.handle_key(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE))
+        .unwrap();
+    app.flush_actions().await;
+    assert!(app.discovery_list.is_loading());
+    assert!(app.discovery_list.visible_items().is_empty());
+    assert!(app.queue.is_empty());
+}
+
+#[tokio::test]
+async fn test_places_list_on_the_search_tab_and_mood_toggle_leaves_them() {
+    let dir = tempfile::tempdir().unwrap();
+    let db = Database::open_at(&dir.path().join("test.db")).unwrap();
+    let mut config = clisten::config::Config::default();
+    config.nts.api_base = Some("http://127.0.0.1:9".to_string());
+    let mut app = clisten::app::App::with_db(config, db).unwrap();
+    app.handle_action(Action::SwitchSubTab(2)).await.unwrap();
+    app.flush_actions().await;
+    app.handle_action(Action::TogglePlaces).await.unwrap();
+    let places = vec![DiscoveryItem::NtsPlace {
+        name: "Berlin".to_string(),
+        episodes: 2,
+    }];
+    app.handle_action(Action::PlacesLoaded(places.clone()))
+        .await
+        .unwrap();
+    assert_eq!(app.discovery_list.visible_items()[0].title(), "Berlin");
+    assert!(app.discovery_list.heading().unwrap().starts_with("Places"));
+
+    // Moods take over from places; places arriving late are dropped.
+    app.handle_action(Action::ToggleMoods).await.unwrap();
+    app.flush_actions().await;
+    app.handle_action(Action::PlacesLoaded(places))
+        .await
+        .unwrap();
+    assert!(app
+        .discovery_list
+        .visible_items()
+        .iter()
+        .all(|item| !matches!(item, DiscoveryItem::NtsPlace { .. })));
+
+    app.handle_action(Action::SearchByPlace {
+        place: "Berlin".to_string(),
+    })
+    .await
+    .unwrap();
+    assert!(app.discovery_list.is_loading());
+    assert_eq!(
+        app.discovery_list.heading(),
+        Some("Berlin · recent episodes")
+    );
+}
+
+#[tokio::test]
+async fn test_more_like_this_leaves_out_the_episode_and_played_ones() {
+    let api = FixtureApi::new().with_tagged(
+        "ambient",
+        vec![make_item("heard"), make_item("this"), make_item("new")],
+    );
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("test.db");
+    let db = Database::open_at(&path).unwrap();
+    Database::open_at(&path)
+        .unwrap()
+        .record_play(&make_item("heard"))
+        .unwrap();
+    let mut app = clisten::app::App::with_db(clisten::config::Config::default(), db)
+        .unwrap()
+        .with_nts_api(api);
+
+    app.handle_action(Action::SearchRelated {
+        title: "This".to_string(),
+        key: make_item("this").favorite_key(),
+        genre_ids: vec!["ambient".to_string()],
+        mood_ids: vec![],
+    })
+    .await
+    .unwrap();
+    settle(&mut app).await;
+    assert_eq!(app.discovery_list.heading(), Some("More like This"));
+    let titles: Vec<&str> = app
+        .discovery_list
+        .visible_items()
+        .iter()
+        .map(|item| item.title())
+        .collect();
+    assert_eq!(titles, ["new"]);
+
+    // Nothing to go on: say so and leave the list alone.
+    app.handle_action(Action::SearchRelated {
+        title: "Untagged".to_string(),
+        key: String::new(),
+        genre_ids: vec![],
+        mood_ids: vec![],
+    })
+    .await
+    .unwrap();
+    assert_eq!(app.discovery_list.visible_items().len(), 1);
+    assert!(latest_message(&app).unwrap().contains("no genres or moods"));
+}
+
+#[tokio::test]
+async fn test_key_c_clears_queue_once_confirmed() {
     use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
     let mut app = test_app();
     app.handle_action(Action::AddToQueue(make_item("track1")))
         .await
         .unwrap();
+    let press = |c| KeyEvent::new(KeyCode::Char(c), KeyModifiers::NONE);
 
-    let key = KeyEvent::new(KeyCode::Char('c'), KeyModifiers::NONE);
-    app.handle_key(key).unwrap();
+    // A stray `c` asks first, and any other key keeps the queue.
+    app.handle_key(press('c')).unwrap();
+    app.flush_actions().await;
+    assert_eq!(
+        app.confirm_modal.prompt(),
+        Some("Clear the queue (1 entry)?")
+    );
+    let screen = app.render_to_string(100, 30).unwrap();
+    assert!(screen.contains("y / Enter confirm"), "{screen}");
+    app.handle_key(press('j')).unwrap();
     app.flush_actions().await;
+    assert!(!app.confirm_modal.is_visible());
+    assert_eq!(app.queue.len(), 1);
+
+    app.handle_key(press('c')).unwrap();
+    app.flush_actions().await;
+    app.handle_key(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE))
+        .unwrap();
+    app.flush_actions().await;
+    assert!(app.queue.is_empty());
+
+    // With confirmations off, it clears at once.
+    let dir = tempfile::tempdir().unwrap();
+    let mut config = clisten::config::Config::default();
+    config.general.confirm_destructive = false;
+    let mut app = clisten::app::App::with_db(
+        config,
+        Database::open_at(&dir.path().join("test.db")).unwrap(),
+    )
+    .unwrap();
+    app.handle_action(Action::AddToQueue(make_item("track1")))
+        .await
+        .unwrap();
+    app.handle_key(press('c')).unwrap();
+    app.flush_actions().await;
+    assert!(!app.confirm_modal.is_visible());
     assert!(app.queue.is_empty());
 }
 
@@ -479,138 +939,1768 @@ async fn test_question_mark_toggles_help() {
 
 #[tokio::test]
 async fn test_retry_key_resends_load() {
+    use clisten::components::nts::NtsSubTab;
     use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
     let mut app = test_app();
-    app.error_message = Some("some error".to_string());
+    app.handle_action(Action::FetchFailed {
+        tab: NtsSubTab::Picks,
+        error: "timed out".to_string(),
+    })
+    .await
+    .unwrap();
+    assert_eq!(app.failed_fetches(), [NtsSubTab::Picks]);
+    assert_eq!(
+        latest_message(&app).as_deref(),
+        Some("timed out — press r to retry")
+    );
 
     let key = KeyEvent::new(KeyCode::Char('r'), KeyModifiers::NONE);
     app.handle_key(key).unwrap();
-    // error_message should be cleared after 'r' press
-    assert!(app.error_message.is_none());
+    // the failed load was sent again and its error dismissed
+    assert!(app.failed_fetches().is_empty());
+    assert!(!app.notifications.has_error());
 }
 
 #[tokio::test]
-async fn test_retry_key_ignored_without_error() {
+async fn test_retry_key_leaves_errors_without_a_failed_load() {
     use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
     let mut app = test_app();
-    assert!(app.error_message.is_none());
+    app.notifications.push(Notification::error("mpv crashed"));
 
-    // 'r' without error — should not panic or crash
     let key = KeyEvent::new(KeyCode::Char('r'), KeyModifiers::NONE);
     app.handle_key(key).unwrap();
-    // still no error
-    assert!(app.error_message.is_none());
+    assert!(app.notifications.has_error());
 }
 
-// ── NtsLiveLoaded refreshes queue ─────────────────────────────────────────────
-
 #[tokio::test]
-async fn test_nts_live_loaded_updates_queue_items() {
+async fn test_loaded_list_clears_its_failure_without_leaving_the_open_tab() {
+    use clisten::components::nts::NtsSubTab;
     let mut app = test_app();
-    app.queue.clear();
-
-    // Add a live channel to the queue with stale metadata
-    app.queue
-        .add(make_live_queue_item(1, "Old Show", vec!["Jazz"]));
-
-    // Simulate NtsLiveLoaded with fresh data
-    let fresh = vec![
-        make_live_item(1, "Fresh Show", vec!["Techno", "House"]),
-        make_live_item(2, "Channel 2 Show", vec!["Ambient"]),
-    ];
-    app.handle_action(Action::NtsLiveLoaded(fresh))
+    for tab in [NtsSubTab::Picks, NtsSubTab::Live, NtsSubTab::Picks] {
+        app.handle_action(Action::FetchFailed {
+            tab,
+            error: "offline".to_string(),
+        })
         .await
         .unwrap();
-
-    // Queue should be updated with fresh metadata
-    if let DiscoveryItem::NtsLiveChannel {
-        show_name, genres, ..
-    } = &app.queue.items()[0].item
-    {
-        assert_eq!(show_name, "Fresh Show");
-        assert_eq!(genres, &["Techno", "House"]);
-    } else {
-        panic!("expected NtsLiveChannel");
     }
-}
+    assert_eq!(app.failed_fetches(), [NtsSubTab::Picks, NtsSubTab::Live]);
 
-// ── Live channel queue deduplication ─────────────────────────────────────────
+    // Picks arrives while Live is open: recorded, but the list stays.
+    app.discovery_list
+        .set_items(vec![make_live_item(1, "Live", vec![])]);
+    app.handle_action(Action::NtsPicksLoaded(vec![make_item("Pick")]))
+        .await
+        .unwrap();
+    assert_eq!(app.failed_fetches(), [NtsSubTab::Live]);
+    assert_eq!(app.discovery_list.visible_items()[0].title(), "Live");
+}
 
 #[tokio::test]
-async fn test_enqueue_live_channel_skips_duplicate() {
+async fn test_retry_key_ignored_without_error() {
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
     let mut app = test_app();
-    app.queue.clear();
+    app.notifications
+        .push(Notification::info("Added 2 favorites"));
 
-    let ch1_a = make_live_item(1, "Show A", vec!["Jazz"]);
-    let ch1_b = make_live_item(1, "Show B", vec!["Techno"]);
+    // 'r' without an error — should not panic or touch other notifications
+    let key = KeyEvent::new(KeyCode::Char('r'), KeyModifiers::NONE);
+    app.handle_key(key).unwrap();
+    assert!(!app.notifications.has_error());
+    assert_eq!(latest_message(&app).as_deref(), Some("Added 2 favorites"));
+}
 
-    app.handle_action(Action::AddToQueue(ch1_a)).await.unwrap();
-    assert_eq!(app.queue.len(), 1);
+#[tokio::test]
+async fn test_notification_history_overlay_toggles() {
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+    let mut app = test_app();
 
-    // Adding the same channel again should be skipped
-    app.handle_action(Action::AddToQueue(ch1_b)).await.unwrap();
-    assert_eq!(app.queue.len(), 1);
+    let key = KeyEvent::new(KeyCode::Char('N'), KeyModifiers::SHIFT);
+    app.handle_key(key).unwrap();
+    app.flush_actions().await;
+    assert!(app.notifications.is_history_visible());
+
+    // Any key closes it without acting on the key
+    let key = KeyEvent::new(KeyCode::Char('q'), KeyModifiers::NONE);
+    app.handle_key(key).unwrap();
+    app.flush_actions().await;
+    assert!(!app.notifications.is_history_visible());
 }
 
 #[tokio::test]
-async fn test_enqueue_different_live_channels_allowed() {
+async fn test_queue_index_actions() {
     let mut app = test_app();
     app.queue.clear();
+    for name in ["track1", "track2", "track3"] {
+        app.handle_action(Action::AddToQueue(make_item(name)))
+            .await
+            .unwrap();
+    }
+    app.handle_action(Action::PlayQueueIndex(2)).await.unwrap();
+    assert_eq!(app.queue.current_index(), Some(2));
+
+    app.handle_action(Action::InsertQueueUrl {
+        url: "http://inserted".to_string(),
+        index: 1,
+        play: false,
+    })
+    .await
+    .unwrap();
+    assert_eq!(app.queue.items()[1].url, "http://inserted");
+    assert_eq!(app.queue.current_index(), Some(3));
+
+    app.handle_action(Action::RemoveQueueIndex(0))
+        .await
+        .unwrap();
+    assert_eq!(app.queue.len(), 3);
+    assert_eq!(app.queue.current().unwrap().url, "http://track3");
 
-    let ch1 = make_live_item(1, "Show A", vec!["Jazz"]);
-    let ch2 = make_live_item(2, "Show B", vec!["Ambient"]);
-
-    app.handle_action(Action::AddToQueue(ch1)).await.unwrap();
-    app.handle_action(Action::AddToQueue(ch2)).await.unwrap();
-    assert_eq!(app.queue.len(), 2);
+    // Out-of-range indices are ignored.
+    app.handle_action(Action::PlayQueueIndex(7)).await.unwrap();
+    app.handle_action(Action::RemoveQueueIndex(7))
+        .await
+        .unwrap();
+    assert_eq!(app.queue.len(), 3);
+    assert_eq!(app.queue.current_index(), Some(2));
 }
 
-// ── Error display ────────────────────────────────────────────────────────────
-
+#[cfg(target_os = "linux")]
 #[tokio::test]
-async fn test_error_displayed_in_status() {
-    // When error_message is Some, the app should hold it for rendering
+async fn test_mpris_state_mirrors_queue() {
+    use clisten::mpris::{track_id, track_index, MprisStatus};
+
     let mut app = test_app();
-    assert!(app.error_message.is_none());
-    app.handle_action(Action::ShowError("network timeout".to_string()))
+    app.queue.clear();
+    app.handle_action(Action::AddToQueue(make_item("track1")))
+        .await
+        .unwrap();
+    app.handle_action(Action::AddToQueue(make_item("track2")))
         .await
         .unwrap();
-    assert_eq!(app.error_message.as_deref(), Some("network timeout"));
+    let state = app.mpris_state();
+    assert_eq!(state.tracks.len(), 2);
+    assert!(state.tracks[1].title.contains("track2"));
+    assert_eq!(state.tracks[1].url, "http://track2");
+    assert_eq!(state.current, Some(0));
+    assert_ne!(state.status, MprisStatus::Paused);
+
+    assert_eq!(track_index(&track_id(4)), Some(4));
+    assert_eq!(
+        track_index("/org/mpris/MediaPlayer2/TrackList/NoTrack"),
+        None
+    );
 }
 
-// ── Any key dismisses help overlay ───────────────────────────────────────────
+// ── Fixture API ──────────────────────────────────────────────────────────────
 
 #[tokio::test]
-async fn test_any_key_dismisses_help() {
-    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
-    let mut app = test_app();
-    app.show_help = true;
+async fn test_picks_tab_shows_what_the_api_returns() {
+    let api = FixtureApi::new().with_picks(vec![make_item("first"), make_item("second")]);
+    let mut app = fixture_app(api);
+
+    app.handle_action(Action::SwitchSubTab(1)).await.unwrap();
+    settle(&mut app).await;
+    let titles: Vec<&str> = app
+        .discovery_list
+        .visible_items()
+        .iter()
+        .map(|item| item.title())
+        .collect();
+    assert_eq!(titles, ["first", "second"]);
+    assert!(latest_message(&app).is_none());
+}
 
-    // Press 'j' — should dismiss help, not scroll
-    let key = KeyEvent::new(KeyCode::Char('j'), KeyModifiers::NONE);
-    app.handle_key(key).unwrap();
+#[tokio::test]
+async fn test_failed_load_offers_a_retry() {
+    let mut app = fixture_app(FixtureApi::new());
+
+    app.handle_action(Action::SwitchSubTab(1)).await.unwrap();
+    settle(&mut app).await;
+    assert!(app.discovery_list.visible_items().is_empty());
+    assert_eq!(
+        latest_message(&app).as_deref(),
+        Some("no fixture for the picks — press r to retry")
+    );
+}
+
+#[tokio::test]
+async fn test_mood_search_pages_through_the_api() {
+    let episodes: Vec<DiscoveryItem> = (0..30).map(|i| make_item(&format!("ep{i}"))).collect();
+    let mut app = fixture_app(FixtureApi::new().with_tagged("late-night", episodes));
+
+    app.handle_action(Action::SearchByMood {
+        mood_id: "late-night".to_string(),
+    })
+    .await
+    .unwrap();
+    settle(&mut app).await;
+    assert!(!app.discovery_list.is_loading());
+    assert_eq!(app.discovery_list.visible_items().len(), 30);
+    assert_eq!(app.discovery_list.visible_items()[29].title(), "ep29");
+}
+
+// ── Fake player ──────────────────────────────────────────────────────────────
+
+/// An App with `titles` queued, playing the first through a FakePlayer the
+/// test keeps a handle to.
+async fn play_queue_on_fake_player(
+    config: clisten::config::Config,
+    titles: &[&str],
+) -> (clisten::app::App, FakePlayer) {
+    let dir = tempfile::tempdir().unwrap();
+    let db = Database::open_at(&dir.path().join("test.db")).unwrap();
+    std::mem::forget(dir);
+    let player = FakePlayer::new();
+    let mut app = clisten::app::App::with_db(config, db)
+        .unwrap()
+        .with_player(player.clone());
+    for title in titles {
+        app.handle_action(Action::AddToQueue(make_item(title)))
+            .await
+            .unwrap();
+    }
+    app.handle_action(Action::PlayQueueIndex(0)).await.unwrap();
     app.flush_actions().await;
-    assert!(!app.show_help);
+    (app, player)
 }
 
 #[tokio::test]
-async fn test_help_overlay_dismisses_on_escape() {
-    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
-    let mut app = test_app();
-    app.show_help = true;
+async fn test_queue_advances_when_a_track_finishes() {
+    let config = clisten::config::Config::default();
+    let (mut app, player) = play_queue_on_fake_player(config, &["one", "two"]).await;
+    assert_eq!(player.state().played, ["http://one"]);
+    assert!(app.now_playing.is_playing());
+
+    player.play_to(600.0);
+    player.finish_track();
+    app.flush_actions().await;
+    assert_eq!(player.state().played, ["http://one", "http://two"]);
+    assert_eq!(app.queue.current_index(), Some(1));
 
-    let key = KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE);
-    app.handle_key(key).unwrap();
+    // The end of the queue: nothing more is played.
+    player.play_to(600.0);
+    player.finish_track();
     app.flush_actions().await;
-    assert!(!app.show_help);
+    assert_eq!(player.state().played.len(), 2);
+    assert_eq!(player.state().current, None);
 }
 
-// ── Dependency check ─────────────────────────────────────────────────────────
+#[tokio::test]
+async fn test_clean_end_without_a_length_advances_and_dropouts_retry_in_place() {
+    let dir = tempfile::tempdir().unwrap();
+    let db = Database::open_at(&dir.path().join("test.db")).unwrap();
+    let player = FakePlayer::new().with_duration(None);
+    let mut app = clisten::app::App::with_db(clisten::config::Config::default(), db)
+        .unwrap()
+        .with_player(player.clone());
+    for title in ["one", "two"] {
+        app.handle_action(Action::AddToQueue(make_item(title)))
+            .await
+            .unwrap();
+    }
+    app.handle_action(Action::PlayQueueIndex(0)).await.unwrap();
+    app.flush_actions().await;
 
-#[test]
-#[ignore = "requires mpv to be installed"]
-fn test_check_mpv_present() {
-    // Integration test — mpv must be installed in the environment
-    let result = which::which("mpv");
-    assert!(result.is_ok(), "mpv must be installed for clisten to work");
+    // mpv never gave a length, but the file ended without an error.
+    player.play_to(1800.0);
+    player.finish_track();
+    app.flush_actions().await;
+    assert_eq!(player.state().played, ["http://one", "http://two"]);
+    assert_eq!(app.queue.current_index(), Some(1));
+    assert!(latest_message(&app).is_none_or(|m| !m.contains("sources failed")));
+
+    // A known-length track dropping midway restarts where it dropped.
+    let (mut app, player) =
+        play_queue_on_fake_player(clisten::config::Config::default(), &["one", "two"]).await;
+    player.play_to(200.0);
+    player.finish_track();
+    app.flush_actions().await;
+    assert_eq!(player.state().played, ["http://one", "http://one"]);
+    app.handle_action(Action::PlaybackDuration(Some(600.0)))
+        .await
+        .unwrap();
+    app.flush_actions().await;
+    assert_eq!(player.state().position, 200.0);
+}
+
+#[tokio::test]
+async fn test_seeking_moves_the_player_within_the_track() {
+    let config = clisten::config::Config::default();
+    let (mut app, player) = play_queue_on_fake_player(config, &["one"]).await;
+
+    app.handle_action(Action::SeekRelative(30.0)).await.unwrap();
+    app.flush_actions().await;
+    assert_eq!(player.state().position, 30.0);
+    assert_eq!(app.now_playing.position_secs(), 30.0);
+
+    app.handle_action(Action::SeekRelative(-60.0))
+        .await
+        .unwrap();
+    app.handle_action(Action::SeekAbsolute(1000.0))
+        .await
+        .unwrap();
+    app.flush_actions().await;
+    assert_eq!(player.state().position, 600.0, "clamped to the track");
+}
+
+#[tokio::test]
+async fn test_skip_intro_uses_the_detected_start_and_per_show_overrides() {
+    let mut config = clisten::config::Config::default();
+    config.general.skip_nts_intro = true;
+    config.nts.intro_secs.insert("two".to_string(), 8.0);
+    config.nts.intro_secs.insert("three".to_string(), 0.0);
+    let (mut app, player) = play_queue_on_fake_player(config, &["one", "two", "three"]).await;
+    // mpv reports the duration once the file has loaded, after the start.
+    let loaded = Action::PlaybackDuration(Some(600.0));
+    app.handle_action(loaded.clone()).await.unwrap();
+    app.flush_actions().await;
+    assert_eq!(player.state().position, 3.0, "the fallback right away");
+
+    // An answer for another track is ignored; this one's moves to the content.
+    for (url, start) in [("http://two", 9.0), ("http://one", 5.5)] {
+        app.handle_action(Action::IntroDetected {
+            url: url.to_string(),
+            start: Some(start),
+        })
+        .await
+        .unwrap();
+    }
+    app.flush_actions().await;
+    assert_eq!(player.state().position, 5.5);
+
+    player.play_to(600.0);
+    player.finish_track();
+    app.flush_actions().await;
+    app.handle_action(loaded.clone()).await.unwrap();
+    app.flush_actions().await;
+    assert_eq!(player.state().current.as_deref(), Some("http://two"));
+    assert_eq!(player.state().position, 8.0, "the show's own intro length");
+
+    player.play_to(600.0);
+    player.finish_track();
+    app.flush_actions().await;
+    app.handle_action(loaded.clone()).await.unwrap();
+    app.flush_actions().await;
+    assert_eq!(player.state().current.as_deref(), Some("http://three"));
+    assert_eq!(player.state().position, 0.0, "0 keeps the intro");
+}
+
+#[tokio::test]
+async fn test_bookmarks_are_set_and_jumped_between() {
+    let config = clisten::config::Config::default();
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+    let (mut app, player) = play_queue_on_fake_player(config, &["one", "two"]).await;
+    let press = |c| KeyEvent::new(KeyCode::Char(c), KeyModifiers::NONE);
+
+    for position in [400.0, 100.0] {
+        player.play_to(position);
+        app.flush_actions().await;
+        app.handle_key(press('b')).unwrap();
+    }
+    assert_eq!(
+        latest_message(&app).as_deref(),
+        Some("Bookmarked 1:40 — Bookmark 2")
+    );
+
+    app.handle_action(Action::SeekAbsolute(0.0)).await.unwrap();
+    app.flush_actions().await;
+    app.handle_key(press('}')).unwrap();
+    app.flush_actions().await;
+    assert_eq!(player.state().position, 100.0);
+    app.handle_key(press('}')).unwrap();
+    app.flush_actions().await;
+    assert_eq!(player.state().position, 400.0);
+    app.handle_key(press('}')).unwrap();
+    app.flush_actions().await;
+    assert_eq!(player.state().position, 400.0);
+    assert_eq!(
+        latest_message(&app).as_deref(),
+        Some("No bookmark after this point")
+    );
+    app.handle_key(press('{')).unwrap();
+    app.flush_actions().await;
+    assert_eq!(player.state().position, 100.0);
+
+    // The next episode has none of its own.
+    player.play_to(600.0);
+    player.finish_track();
+    app.flush_actions().await;
+    app.handle_key(press('}')).unwrap();
+    assert_eq!(
+        latest_message(&app).as_deref(),
+        Some("No bookmarks in this episode (b adds one)")
+    );
+}
+
+#[tokio::test]
+async fn test_ab_loop_reaches_the_player_and_ends_with_the_track() {
+    let config = clisten::config::Config::default();
+    let (mut app, player) = play_queue_on_fake_player(config, &["one", "two"]).await;
+
+    app.handle_action(Action::SetAbLoop(Some((90.0, 150.0))))
+        .await
+        .unwrap();
+    assert_eq!(player.state().ab_loop, Some((90.0, 150.0)));
+    assert_eq!(latest_message(&app).as_deref(), Some("Looping 1:30–2:30"));
+    app.handle_action(Action::SetAbLoop(None)).await.unwrap();
+    assert_eq!(player.state().ab_loop, None);
+    assert_eq!(latest_message(&app).as_deref(), Some("Stopped looping"));
+
+    app.handle_action(Action::SetAbLoop(Some((90.0, 150.0))))
+        .await
+        .unwrap();
+    app.handle_action(Action::NextTrack).await.unwrap();
+    app.flush_actions().await;
+    assert_eq!(player.state().current.as_deref(), Some("http://two"));
+    assert_eq!(player.state().ab_loop, None);
+}
+
+#[tokio::test]
+async fn test_preview_ducks_the_current_track_and_stops_by_itself() {
+    let config = clisten::config::Config::default();
+    let (mut app, player) = play_queue_on_fake_player(config, &["one"]).await;
+
+    app.handle_action(Action::Preview(make_item("other")))
+        .await
+        .unwrap();
+    assert_eq!(player.state().preview.as_deref(), Some("http://other"));
+    assert!(
+        player.state().volume < 100.0,
+        "the current track is turned down"
+    );
+    assert_eq!(player.state().current.as_deref(), Some("http://one"));
+
+    // The same item again stops it.
+    app.handle_action(Action::Preview(make_item("other")))
+        .await
+        .unwrap();
+    assert_eq!(player.state().preview, None);
+    assert_eq!(player.state().volume, 100.0);
+
+    app.handle_action(Action::Preview(make_item("other")))
+        .await
+        .unwrap();
+    let mut ticks = 0;
+    while player.state().preview.is_some() && ticks < 10_000 {
+        app.handle_action(Action::Tick).await.unwrap();
+        ticks += 1;
+    }
+    assert_eq!(player.state().preview, None, "stopped after {ticks} ticks");
+    assert!(ticks > 1);
+    assert_eq!(player.state().volume, 100.0);
+    assert_eq!(player.state().current.as_deref(), Some("http://one"));
+}
+
+#[tokio::test]
+async fn test_pausing_reaches_the_player_and_the_controls() {
+    let config = clisten::config::Config::default();
+    let (mut app, player) = play_queue_on_fake_player(config, &["one"]).await;
+
+    app.handle_action(Action::TogglePlayPause).await.unwrap();
+    app.flush_actions().await;
+    assert!(player.state().paused);
+    assert!(app.now_playing.is_paused());
+
+    app.handle_action(Action::TogglePlayPause).await.unwrap();
+    app.flush_actions().await;
+    assert!(!player.state().paused);
+    assert!(!app.now_playing.is_paused());
+}
+
+#[tokio::test]
+async fn test_crossfade_preloads_then_hands_over_to_the_next_track() {
+    let mut config = clisten::config::Config::default();
+    config.player.crossfade_secs = Some(5.0);
+    let (mut app, player) = play_queue_on_fake_player(config, &["one", "two"]).await;
+
+    player.play_to(100.0);
+    app.flush_actions().await;
+    assert_eq!(player.state().preloaded, None);
+
+    // Shortly before the fade the next track is preloaded...
+    player.play_to(590.0);
+    app.flush_actions().await;
+    assert_eq!(player.state().preloaded.as_deref(), Some("http://two"));
+
+    // ...and the fade starts it without waiting for the end.
+    player.play_to(596.0);
+    app.flush_actions().await;
+    assert_eq!(player.state().played, ["http://one", "http://two"]);
+    assert_eq!(app.queue.current_index(), Some(1));
+}
+
+#[tokio::test]
+async fn test_low_data_mode_lowers_the_bitrate_and_skips_preloading() {
+    let mut config = clisten::config::Config::default();
+    config.player.crossfade_secs = Some(5.0);
+    config.player.ytdl_format = Some("bestaudio".to_string());
+    let mut low_data = config.clone();
+    low_data.general.low_data = true;
+    assert_eq!(
+        low_data.ytdl_format(),
+        "bestaudio[abr<=64]/worstaudio/worst"
+    );
+    assert_eq!(
+        low_data.extra_mpv_args().last().map(String::as_str),
+        Some("--ytdl-format=bestaudio[abr<=64]/worstaudio/worst")
+    );
+    let (mut app, player) = play_queue_on_fake_player(config, &["one", "two"]).await;
+
+    app.handle_action(Action::ToggleLowData).await.unwrap();
+    assert!(latest_message(&app)
+        .unwrap()
+        .starts_with("Low data mode on"));
+    assert_eq!(
+        player.state().ytdl_format.as_deref(),
+        Some(clisten::player::args::LOW_DATA_YTDL_FORMAT)
+    );
+
+    // Nothing is fetched ahead, so the next track waits for the end.
+    player.play_to(590.0);
+    app.flush_actions().await;
+    assert_eq!(player.state().preloaded, None);
+    player.play_to(596.0);
+    app.flush_actions().await;
+    assert_eq!(player.state().played, ["http://one"]);
+
+    // Off again, the configured format is back.
+    app.handle_action(Action::ToggleLowData).await.unwrap();
+    assert_eq!(latest_message(&app).as_deref(), Some("Low data mode off"));
+    assert_eq!(player.state().ytdl_format.as_deref(), Some("bestaudio"));
+
+    // Stopped, the idle mpv that plays the next track gets it too.
+    app.handle_action(Action::Stop).await.unwrap();
+    app.handle_action(Action::ToggleLowData).await.unwrap();
+    assert_eq!(
+        player.state().ytdl_format.as_deref(),
+        Some(clisten::player::args::LOW_DATA_YTDL_FORMAT)
+    );
+}
+
+#[tokio::test]
+async fn test_session_restore_reopens_the_tab_selection_and_paused_track() {
+    use clisten::components::nts::NtsSubTab;
+
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("test.db");
+    let mut config = clisten::config::Config::default();
+    config.general.restore_session = true;
+    config.general.restore_playback = true;
+
+    let db = Database::open_at(&path).unwrap();
+    for title in ["alpha one", "alpha two", "beta"] {
+        db.add_favorite(&make_item(title)).unwrap();
+    }
+    let mut app = clisten::app::App::with_db(config.clone(), db)
+        .unwrap()
+        .with_player(FakePlayer::new());
+    app.handle_action(Action::SwitchSubTab(4)).await.unwrap();
+    settle(&mut app).await;
+    app.discovery_list.set_filter(Some("alpha".to_string()));
+    assert!(app
+        .discovery_list
+        .select_key(&make_item("alpha two").favorite_key()));
+    app.handle_action(Action::AddToQueue(make_item("tune")))
+        .await
+        .unwrap();
+    app.handle_action(Action::PlayQueueIndex(0)).await.unwrap();
+    app.flush_actions().await;
+    app.handle_action(Action::PlaybackPosition(200.0))
+        .await
+        .unwrap();
+    app.handle_action(Action::Quit).await.unwrap();
+    drop(app);
+
+    let player = FakePlayer::new();
+    let mut app = clisten::app::App::with_db(config, Database::open_at(&path).unwrap())
+        .unwrap()
+        .with_player(player.clone());
+    app.handle_action(Action::RestoreSession).await.unwrap();
+    settle(&mut app).await;
+
+    assert_eq!(app.nts_tab.active_sub(), NtsSubTab::Favorites);
+    assert_eq!(app.discovery_list.visible_items().len(), 2);
+    assert_eq!(
+        app.discovery_list.selected_item().map(|item| item.title()),
+        Some("alpha two")
+    );
+    let state = player.state();
+    assert_eq!(state.current.as_deref(), Some("http://tune"));
+    assert!(state.paused);
+    assert!(app.now_playing.is_paused());
+
+    // Once mpv knows the length, it seeks to where the track was left —
+    // to that place, not that far on from wherever mpv started.
+    player.play_to(30.0);
+    app.flush_actions().await;
+    app.handle_action(Action::PlaybackDuration(Some(600.0)))
+        .await
+        .unwrap();
+    app.flush_actions().await;
+    assert_eq!(player.state().position, 200.0);
+    assert!(player.state().paused);
+}
+
+#[test]
+fn test_sleep_clock_notices_the_wall_clock_jumping_ahead() {
+    use clisten::power::SleepClock;
+    use std::time::{Instant, SystemTime};
+
+    let (wall, monotonic) = (SystemTime::now(), Instant::now());
+    let mut clock = SleepClock::new();
+    clock.check_at(wall, monotonic);
+    let second = Duration::from_secs(1);
+    assert_eq!(clock.check_at(wall + second, monotonic + second), None);
+    // Five minutes asleep: the wall clock moved on, the monotonic one didn't.
+    let asleep = clock.check_at(wall + second * 302, monotonic + second * 2);
+    assert_eq!(asleep, Some(second * 300));
+}
+
+#[tokio::test]
+async fn test_system_sleep_pauses_and_waking_reloads_the_track() {
+    let config = clisten::config::Config::default();
+    let (mut app, player) = play_queue_on_fake_player(config, &["one"]).await;
+    app.handle_action(Action::PlaybackPosition(120.0))
+        .await
+        .unwrap();
+
+    app.handle_action(Action::SystemSleep).await.unwrap();
+    app.flush_actions().await;
+    assert!(player.state().paused);
+    assert!(app.now_playing.is_paused());
+    assert_eq!(
+        latest_message(&app).as_deref(),
+        Some("Paused for system sleep")
+    );
+
+    // After waking the track is loaded again, still paused, at its place.
+    app.handle_action(Action::SystemWake).await.unwrap();
+    app.flush_actions().await;
+    app.handle_action(Action::PlaybackDuration(Some(600.0)))
+        .await
+        .unwrap();
+    app.flush_actions().await;
+    let state = player.state();
+    assert_eq!(state.played, ["http://one", "http://one"]);
+    assert!(state.paused);
+    assert_eq!(state.position, 120.0);
+    assert!(app.now_playing.is_paused());
+}
+
+#[tokio::test]
+async fn test_losing_an_audio_output_pauses_only_when_asked_to() {
+    let config = clisten::config::Config::default();
+    let (mut app, player) = play_queue_on_fake_player(config, &["one"]).await;
+    app.handle_action(Action::AudioDeviceRemoved("Headphones".to_string()))
+        .await
+        .unwrap();
+    assert!(!player.state().paused);
+
+    let mut config = clisten::config::Config::default();
+    config.general.pause_on_device_loss = true;
+    let (mut app, player) = play_queue_on_fake_player(config, &["one"]).await;
+    app.handle_action(Action::AudioDeviceRemoved("Headphones".to_string()))
+        .await
+        .unwrap();
+    assert!(player.state().paused);
+    assert_eq!(
+        latest_message(&app).as_deref(),
+        Some("Paused: Headphones went away")
+    );
+}
+
+#[tokio::test]
+async fn test_player_failure_is_reported() {
+    let player = FakePlayer::new().failing("mpv fell over");
+    let mut app = test_app().with_player(player.clone());
+    app.handle_action(Action::PlayItem(make_item("one")))
+        .await
+        .unwrap();
+    app.flush_actions().await;
+    assert!(player.state().played.is_empty());
+    assert!(latest_message(&app).unwrap().contains("mpv fell over"));
+}
+
+#[tokio::test]
+async fn test_frames_are_drawn_only_after_a_change() {
+    let mut app = test_app();
+    assert!(app.needs_draw(), "the first frame");
+    app.frame_drawn();
+    assert!(!app.needs_draw());
+
+    app.handle_action(Action::ShowHelp).await.unwrap();
+    assert!(app.needs_draw());
+    app.frame_drawn();
+    assert!(!app.needs_draw());
+}
+
+#[tokio::test]
+async fn test_full_tick_rate_while_playing() {
+    let config = clisten::config::Config::default();
+    let frame_rate = config.general.frame_rate;
+    let (mut app, _player) = play_queue_on_fake_player(config, &["one"]).await;
+    assert_eq!(app.wanted_tick_rate(), frame_rate);
+
+    // The note blinks while playing, so every tick is drawn.
+    app.frame_drawn();
+    app.handle_action(Action::Tick).await.unwrap();
+    assert!(app.needs_draw());
+}
+
+// ── Traces ───────────────────────────────────────────────────────────────────
+
+#[tokio::test]
+async fn test_recorded_session_replays_to_the_same_state() {
+    use clisten::trace::{self, ActionRecorder};
+
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("trace.jsonl");
+    let player = FakePlayer::new();
+    let mut app = test_app()
+        .with_player(player.clone())
+        .with_recorder(ActionRecorder::create(&path).unwrap());
+    for title in ["one", "two", "three"] {
+        app.handle_action(Action::AddToQueue(make_item(title)))
+            .await
+            .unwrap();
+    }
+    app.handle_action(Action::PlayQueueIndex(0)).await.unwrap();
+    app.flush_actions().await;
+    player.play_to(600.0);
+    player.finish_track();
+    app.flush_actions().await;
+    app.handle_action(Action::RemoveQueueIndex(2))
+        .await
+        .unwrap();
+    app.handle_action(Action::CopyToClipboard("http://two".to_string()))
+        .await
+        .ok();
+    app.flush_actions().await;
+
+    let mut text = std::fs::read_to_string(&path).unwrap();
+    // A crash can leave the last line half written.
+    text.push_str("{\"at_ms\":9");
+    std::fs::write(&path, text).unwrap();
+    let entries = trace::read_trace(&path).unwrap();
+    assert!(matches!(entries[0].action, Action::AddToQueue(_)));
+    assert!(entries.windows(2).all(|w| w[0].at_ms <= w[1].at_ms));
+
+    let replay = trace::replay(&entries).await.unwrap();
+    assert!(replay.failures.is_empty(), "{:?}", replay.failures);
+    assert_eq!(replay.skipped, 1, "the clipboard is left alone");
+    assert_eq!(replay.app.queue.len(), app.queue.len());
+    assert_eq!(replay.app.queue.current_index(), Some(1));
+    assert!(replay.app.now_playing.is_playing());
+    let titles = |app: &clisten::app::App| {
+        app.queue
+            .items()
+            .iter()
+            .map(|qi| qi.item.display_title())
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(titles(&replay.app), titles(&app));
+}
+
+// ── Remote control ───────────────────────────────────────────────────────────
+
+#[test]
+fn test_remote_commands_map_to_actions() {
+    use clisten::remote::{handle_command, RemoteState, RemoteStatus, RemoteTrack};
+
+    let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+    let mut state = RemoteState {
+        tracks: vec![RemoteTrack {
+            title: "Show".to_string(),
+            subtitle: String::new(),
+            url: "http://show".to_string(),
+        }],
+        current: Some(0),
+        status: RemoteStatus::Stopped,
+        position_secs: 12.0,
+        duration_secs: Some(60.0),
+    };
+
+    let reply = handle_command("status", &state, &tx);
+    assert_eq!(reply["status"], "stopped");
+    assert_eq!(reply["track"]["title"], "Show");
+    assert_eq!(handle_command("queue", &state, &tx)["current"], 0);
+    assert!(rx.try_recv().is_err());
+
+    // Play resumes a stopped queue at its current track; pause does nothing.
+    assert_eq!(handle_command("play", &state, &tx)["ok"], true);
+    assert!(matches!(rx.try_recv(), Ok(Action::PlayQueueIndex(0))));
+    handle_command("pause", &state, &tx);
+    assert!(rx.try_recv().is_err());
+
+    state.status = RemoteStatus::Playing;
+    handle_command("pause", &state, &tx);
+    assert!(matches!(rx.try_recv(), Ok(Action::TogglePlayPause)));
+    handle_command("play", &state, &tx);
+    assert!(rx.try_recv().is_err());
+    handle_command("next", &state, &tx);
+    assert!(matches!(rx.try_recv(), Ok(Action::NextTrack)));
+
+    let reply = handle_command("shuffle", &state, &tx);
+    assert_eq!(reply["ok"], false);
+    assert!(reply["error"].as_str().unwrap().contains("shuffle"));
+}
+
+#[test]
+fn test_remote_http_requires_method_and_token() {
+    use clisten::remote::{http_response, parse_request, RemoteState};
+
+    let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+    let state = RemoteState::default();
+    let request = |head: &str| parse_request(head).unwrap();
+
+    let status = request("GET /?token=abc HTTP/1.1\r\nHost: x\r\n");
+    assert_eq!(status.path, "/");
+    assert_eq!(status.token.as_deref(), Some("abc"));
+    assert_eq!(http_response(&status, Some("abc"), &state, &tx).0, 200);
+    assert_eq!(http_response(&status, Some("xyz"), &state, &tx).0, 401);
+
+    let bearer = request("POST /next HTTP/1.1\r\nauthorization: Bearer xyz\r\n");
+    assert_eq!(http_response(&bearer, Some("xyz"), &state, &tx).0, 200);
+    assert!(matches!(rx.try_recv(), Ok(Action::NextTrack)));
+
+    // Commands that change playback need POST, queries need GET.
+    let get_next = request("GET /next HTTP/1.1\r\n");
+    assert_eq!(http_response(&get_next, None, &state, &tx).0, 405);
+    assert!(rx.try_recv().is_err());
+    let post_queue = request("POST /queue HTTP/1.1\r\n");
+    assert_eq!(http_response(&post_queue, None, &state, &tx).0, 405);
+    let unknown = request("GET /nope HTTP/1.1\r\n");
+    assert_eq!(http_response(&unknown, None, &state, &tx).0, 404);
+    assert!(parse_request("").is_none());
+}
+
+#[tokio::test]
+async fn test_remote_socket_round_trip() {
+    use clisten::config::RemoteConfig;
+    use clisten::remote::{describe, request, spawn, RemoteState, RemoteStatus, RemoteTrack};
+
+    let dir = tempfile::tempdir().unwrap();
+    let socket = dir.path().join("remote.sock");
+    let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+    let handle = spawn(&RemoteConfig::default(), &socket, tx.clone())
+        .await
+        .unwrap();
+    handle.update(RemoteState {
+        tracks: vec![RemoteTrack {
+            title: "Night Shift".to_string(),
+            subtitle: String::new(),
+            url: "http://night".to_string(),
+        }],
+        current: Some(0),
+        status: RemoteStatus::Playing,
+        position_secs: 65.0,
+        duration_secs: Some(3600.0),
+    });
+
+    let status = request(&socket, "status").await.unwrap();
+    assert_eq!(
+        describe("status", &status),
+        "playing: Night Shift  1:05 / 60:00\n"
+    );
+    let queue = request(&socket, "queue").await.unwrap();
+    assert_eq!(describe("queue", &queue), "▶  1. Night Shift\n");
+    assert_eq!(request(&socket, "toggle").await.unwrap()["ok"], true);
+    assert!(matches!(rx.recv().await, Some(Action::TogglePlayPause)));
+
+    // A second server refuses to take over a live socket.
+    assert!(spawn(&RemoteConfig::default(), &socket, tx).await.is_err());
+}
+
+// ── Copy links ───────────────────────────────────────────────────────────────
+
+#[test]
+fn test_copy_link_prefers_web_page_and_playing_source() {
+    let mut app = test_app();
+    app.queue.clear();
+
+    let episode = make_item("show");
+    assert_eq!(
+        app.link_to_copy(&episode, false).as_deref(),
+        Some("https://www.nts.live/shows/show/episodes/show")
+    );
+    assert_eq!(
+        app.link_to_copy(&episode, true).as_deref(),
+        Some("http://show")
+    );
+
+    // The playing track reports the source actually in use.
+    let mut live = make_live_queue_item(2, "Show", vec![]);
+    live.url = "http://mirror/stream2".to_string();
+    let live_item = live.item.clone();
+    app.queue.add(live);
+    assert_eq!(
+        app.link_to_copy(&live_item, true).as_deref(),
+        Some("http://mirror/stream2")
+    );
+    let other_channel = make_live_item(1, "Other", vec![]);
+    assert_eq!(
+        app.link_to_copy(&other_channel, true),
+        other_channel.playback_url()
+    );
+
+    let genre = DiscoveryItem::NtsGenre {
+        name: "Jazz".to_string(),
+        genre_id: "jazz".to_string(),
+    };
+    assert_eq!(app.link_to_copy(&genre, false), None);
+}
+
+// ── Terminal title ───────────────────────────────────────────────────────────
+
+#[tokio::test]
+async fn test_terminal_title_follows_playback() {
+    use clisten::player::StreamMetadata;
+
+    let mut app = test_app();
+    app.queue.clear();
+    assert_eq!(app.terminal_title(), "clisten");
+
+    app.queue.add(QueueItem {
+        item: DiscoveryItem::DirectUrl {
+            url: "http://radio".to_string(),
+            title: None,
+            meta: None,
+        },
+        url: "http://radio".to_string(),
+        stream_metadata: None,
+    });
+    app.handle_action(Action::PlayQueueIndex(0)).await.unwrap();
+    app.queue.set_current_stream_metadata(StreamMetadata {
+        station_name: Some("Radio X".to_string()),
+        title: Some("Song\x1b]0;evil\x07".to_string()),
+        ..Default::default()
+    });
+    assert_eq!(app.terminal_title(), "clisten — Radio X · Song]0;evil");
+
+    app.handle_action(Action::Stop).await.unwrap();
+    app.handle_action(Action::PlaybackFinished).await.unwrap();
+    assert_eq!(app.terminal_title(), "clisten");
+}
+
+// ── NtsLiveLoaded refreshes queue ─────────────────────────────────────────────
+
+#[tokio::test]
+async fn test_nts_live_loaded_updates_queue_items() {
+    let mut app = test_app();
+    app.queue.clear();
+
+    // Add a live channel to the queue with stale metadata
+    app.queue
+        .add(make_live_queue_item(1, "Old Show", vec!["Jazz"]));
+
+    // Simulate NtsLiveLoaded with fresh data
+    let fresh = vec![
+        make_live_item(1, "Fresh Show", vec!["Techno", "House"]),
+        make_live_item(2, "Channel 2 Show", vec!["Ambient"]),
+    ];
+    app.handle_action(Action::NtsLiveLoaded(fresh))
+        .await
+        .unwrap();
+
+    // Queue should be updated with fresh metadata
+    if let DiscoveryItem::NtsLiveChannel {
+        show_name, genres, ..
+    } = &app.queue.items()[0].item
+    {
+        assert_eq!(show_name, "Fresh Show");
+        assert_eq!(genres, &["Techno", "House"]);
+    } else {
+        panic!("expected NtsLiveChannel");
+    }
+}
+
+// ── Per-tab state ────────────────────────────────────────────────────────────
+
+#[tokio::test]
+async fn test_switching_back_restores_list_and_selection() {
+    use clisten::components::nts::NtsSubTab;
+
+    let mut app = test_app();
+    let live = vec![
+        make_live_item(1, "One", vec![]),
+        make_live_item(2, "Two", vec![]),
+    ];
+    app.handle_action(Action::NtsLiveLoaded(live))
+        .await
+        .unwrap();
+    app.discovery_list.next();
+    assert_eq!(app.discovery_list.selected_index(), Some(1));
+
+    app.handle_action(Action::SwitchSubTab(1)).await.unwrap();
+    assert!(app.has_stashed_tab(NtsSubTab::Live));
+    assert_eq!(app.discovery_list.total_item_count(), 0);
+    app.handle_action(Action::NtsPicksLoaded(vec![make_item("Pick")]))
+        .await
+        .unwrap();
+
+    app.handle_action(Action::SwitchSubTab(0)).await.unwrap();
+    assert!(!app.discovery_list.is_loading());
+    assert_eq!(app.discovery_list.total_item_count(), 2);
+    assert_eq!(app.discovery_list.selected_index(), Some(1));
+    assert!(app.has_stashed_tab(NtsSubTab::Picks));
+}
+
+#[tokio::test]
+async fn test_live_refresh_on_another_tab_updates_its_stashed_list() {
+    let mut app = test_app();
+    app.handle_action(Action::NtsLiveLoaded(vec![make_live_item(
+        1,
+        "Old",
+        vec![],
+    )]))
+    .await
+    .unwrap();
+    app.handle_action(Action::SwitchSubTab(1)).await.unwrap();
+    app.handle_action(Action::NtsPicksLoaded(vec![make_item("Pick")]))
+        .await
+        .unwrap();
+
+    let fresh = vec![
+        make_live_item(1, "New", vec![]),
+        make_live_item(2, "Other", vec![]),
+    ];
+    app.handle_action(Action::NtsLiveLoaded(fresh))
+        .await
+        .unwrap();
+    // The open Picks list is untouched.
+    assert_eq!(app.discovery_list.total_item_count(), 1);
+
+    app.handle_action(Action::SwitchSubTab(0)).await.unwrap();
+    assert_eq!(app.discovery_list.total_item_count(), 2);
+}
+
+#[tokio::test]
+async fn test_live_refetch_scheduled_after_the_show_on_air_ends() {
+    use std::collections::BTreeMap;
+    use std::time::{SystemTime, UNIX_EPOCH};
+
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_secs();
+    let mut app = test_app();
+    assert_eq!(app.live_refresh_due(), None);
+
+    // Shortly after the show ends, with a little slack for the API.
+    app.handle_action(Action::LiveScheduleLoaded {
+        next_change: Some(now + 600),
+        slots: BTreeMap::new(),
+    })
+    .await
+    .unwrap();
+    let due = app.live_refresh_due().unwrap();
+    assert!(due > now + 600 && due <= now + 660);
+
+    // The API still lists a show past its end: retry soon.
+    app.handle_action(Action::LiveScheduleLoaded {
+        next_change: Some(now - 60),
+        slots: BTreeMap::new(),
+    })
+    .await
+    .unwrap();
+    let due = app.live_refresh_due().unwrap();
+    assert!(due > now && due <= now + 60);
+
+    app.handle_action(Action::LiveScheduleLoaded {
+        next_change: None,
+        slots: BTreeMap::new(),
+    })
+    .await
+    .unwrap();
+    assert_eq!(app.live_refresh_due(), None);
+}
+
+#[tokio::test]
+async fn test_live_show_times_reach_the_live_rows() {
+    use clisten::api::models::ShowSlot;
+
+    let slot = ShowSlot {
+        start: 1_000,
+        end: 8_200,
+    };
+    let mut app = test_app();
+    app.handle_action(Action::LiveScheduleLoaded {
+        next_change: Some(slot.end),
+        slots: [(2, slot)].into(),
+    })
+    .await
+    .unwrap();
+
+    let list = &app.discovery_list;
+    assert_eq!(
+        list.live_slot(&make_live_item(2, "Show", vec![])),
+        Some(&slot)
+    );
+    assert_eq!(list.live_slot(&make_live_item(1, "Show", vec![])), None);
+    assert_eq!(list.live_slot(&make_item("Episode")), None);
+}
+
+#[tokio::test]
+async fn test_dropped_live_stream_reconnects_until_attempts_run_out() {
+    let mut app = test_app();
+    app.queue.clear();
+    app.queue.add(make_live_queue_item(1, "Live Show", vec![]));
+    app.queue
+        .add(make_queue_item("Next", "https://example.com/next.mp3"));
+
+    // Every drop schedules another restart instead of moving on.
+    for attempt in 1..=6 {
+        app.handle_action(Action::PlaybackFinished).await.unwrap();
+        assert_eq!(app.reconnect_attempts(), Some(attempt));
+        assert_eq!(app.queue.current_index(), Some(0));
+    }
+    assert!(app
+        .notifications
+        .active()
+        .any(|n| n.message.contains("reconnecting")));
+
+    // Then it gives up and the queue advances as usual.
+    app.handle_action(Action::PlaybackFinished).await.unwrap();
+    assert_eq!(app.reconnect_attempts(), None);
+    assert_eq!(app.queue.current_index(), Some(1));
+    assert!(app
+        .notifications
+        .active()
+        .any(|n| n.message.contains("gave up")));
+}
+
+#[tokio::test]
+async fn test_stop_cancels_live_reconnect_and_episodes_never_reconnect() {
+    let mut app = test_app();
+    app.queue.clear();
+    app.queue.add(make_live_queue_item(2, "Live Show", vec![]));
+    app.handle_action(Action::PlaybackFinished).await.unwrap();
+    assert_eq!(app.reconnect_attempts(), Some(1));
+    app.handle_action(Action::Stop).await.unwrap();
+    assert_eq!(app.reconnect_attempts(), None);
+
+    app.queue.clear();
+    app.queue
+        .add(make_queue_item("Episode", "https://example.com/ep.mp3"));
+    app.handle_action(Action::PlaybackFinished).await.unwrap();
+    assert_eq!(app.reconnect_attempts(), None);
+}
+
+#[tokio::test]
+async fn test_stuck_playback_is_restarted_a_few_times_then_left() {
+    let mut config = clisten::config::Config::default();
+    config.general.frame_rate = 1.0;
+    config.player.stall_timeout_secs = Some(3);
+    // Something that always starts, so the test doesn't depend on mpv.
+    config.player.mpv_path = Some("true".to_string());
+    let dir = tempfile::tempdir().unwrap();
+    let db = Database::open_at(&dir.path().join("test.db")).unwrap();
+    let mut app = clisten::app::App::with_db(config, db).unwrap();
+    app.queue.clear();
+    let item = make_queue_item("Episode", "https://example.com/ep.mp3").item;
+    app.handle_action(Action::PlayItem(item)).await.unwrap();
+
+    // Moving playback keeps the watchdog quiet.
+    for pos in 0..10 {
+        app.handle_action(Action::PlaybackPosition(f64::from(pos)))
+            .await
+            .unwrap();
+        app.handle_action(Action::Tick).await.unwrap();
+    }
+    assert_eq!(app.stall_restarts(), 0);
+
+    // Stuck for the timeout: restart, up to three times in a row.
+    for restarts in 1..=3 {
+        for _ in 0..3 {
+            app.handle_action(Action::Tick).await.unwrap();
+        }
+        assert_eq!(app.stall_restarts(), restarts);
+        assert!(latest_message(&app).is_some_and(|m| m.contains("stuck for 3s")));
+    }
+    for _ in 0..3 {
+        app.handle_action(Action::Tick).await.unwrap();
+    }
+    assert!(latest_message(&app).is_some_and(|m| m.contains("still stuck")));
+    for _ in 0..10 {
+        app.handle_action(Action::Tick).await.unwrap();
+    }
+    assert_eq!(app.stall_restarts(), 3);
+
+    // Once it plays again the count starts over.
+    app.handle_action(Action::PlaybackPosition(10.0))
+        .await
+        .unwrap();
+    app.handle_action(Action::PlaybackPosition(11.0))
+        .await
+        .unwrap();
+    assert_eq!(app.stall_restarts(), 0);
+}
+
+#[tokio::test]
+async fn test_missing_mpv_explains_how_to_install_and_stops_reconnecting() {
+    let mut config = clisten::config::Config::default();
+    config.player.mpv_path = Some("clisten-test-no-such-mpv".to_string());
+    let dir = tempfile::tempdir().unwrap();
+    let db = Database::open_at(&dir.path().join("test.db")).unwrap();
+    let mut app = clisten::app::App::with_db(config, db).unwrap();
+    app.queue.clear();
+    app.queue.add(make_live_queue_item(1, "Live Show", vec![]));
+
+    app.handle_action(Action::PlayQueueIndex(0)).await.unwrap();
+    let message = latest_message(&app).unwrap();
+    assert!(
+        message.contains("mpv not found (clisten-test-no-such-mpv)"),
+        "{message}"
+    );
+    assert!(message.contains("install with:"), "{message}");
+
+    // A dropped stream's restart finds mpv gone and gives up at once.
+    app.handle_action(Action::PlaybackFinished).await.unwrap();
+    assert_eq!(app.reconnect_attempts(), Some(1));
+    for _ in 0..200 {
+        app.handle_action(Action::Tick).await.unwrap();
+    }
+    assert_eq!(app.reconnect_attempts(), None);
+}
+
+// ── Live tracklist ───────────────────────────────────────────────────────────
+
+#[tokio::test]
+async fn test_live_tracks_follow_the_playing_channel() {
+    use clisten::api::models::LiveTrack;
+
+    let track = |title: &str| LiveTrack {
+        artist: "Artist".to_string(),
+        title: title.to_string(),
+    };
+    let mut app = test_app();
+    app.queue.clear();
+    app.queue.add(make_live_queue_item(1, "Show", vec![]));
+    app.handle_action(Action::PlayQueueIndex(0)).await.unwrap();
+
+    app.handle_action(Action::LiveTracksLoaded {
+        channel: 1,
+        tracks: vec![track("B"), track("A")],
+    })
+    .await
+    .unwrap();
+    app.handle_action(Action::LiveTracksLoaded {
+        channel: 1,
+        tracks: vec![track("C"), track("B")],
+    })
+    .await
+    .unwrap();
+    assert_eq!(
+        app.shown_live_tracks(),
+        &[track("C"), track("B"), track("A")]
+    );
+
+    // Another channel's songs are kept but not shown.
+    app.handle_action(Action::LiveTracksLoaded {
+        channel: 2,
+        tracks: vec![track("X")],
+    })
+    .await
+    .unwrap();
+    assert_eq!(app.shown_live_tracks().len(), 3);
+
+    app.handle_action(Action::Stop).await.unwrap();
+    app.handle_action(Action::PlaybackFinished).await.unwrap();
+    assert!(app.shown_live_tracks().is_empty());
+}
+
+// ── Live channel queue deduplication ─────────────────────────────────────────
+
+#[tokio::test]
+async fn test_enqueue_live_channel_skips_duplicate() {
+    let mut app = test_app();
+    app.queue.clear();
+
+    let ch1_a = make_live_item(1, "Show A", vec!["Jazz"]);
+    let ch1_b = make_live_item(1, "Show B", vec!["Techno"]);
+
+    app.handle_action(Action::AddToQueue(ch1_a)).await.unwrap();
+    assert_eq!(app.queue.len(), 1);
+
+    // Adding the same channel again should be skipped
+    app.handle_action(Action::AddToQueue(ch1_b)).await.unwrap();
+    assert_eq!(app.queue.len(), 1);
+}
+
+#[tokio::test]
+async fn test_enqueue_different_live_channels_allowed() {
+    let mut app = test_app();
+    app.queue.clear();
+
+    let ch1 = make_live_item(1, "Show A", vec!["Jazz"]);
+    let ch2 = make_live_item(2, "Show B", vec!["Ambient"]);
+
+    app.handle_action(Action::AddToQueue(ch1)).await.unwrap();
+    app.handle_action(Action::AddToQueue(ch2)).await.unwrap();
+    assert_eq!(app.queue.len(), 2);
+}
+
+// ── Error display ────────────────────────────────────────────────────────────
+
+#[tokio::test]
+async fn test_error_displayed_in_status() {
+    // Active notifications are what the status stack renders
+    let mut app = test_app();
+    assert_eq!(app.notifications.height(), 0);
+    app.handle_action(Action::Notify(Notification::error("network timeout")))
+        .await
+        .unwrap();
+    assert_eq!(app.notifications.height(), 1);
+    assert_eq!(latest_message(&app).as_deref(), Some("network timeout"));
+}
+
+// ── Any key dismisses help overlay ───────────────────────────────────────────
+
+#[tokio::test]
+async fn test_any_key_dismisses_help() {
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+    let mut app = test_app();
+    app.show_help = true;
+
+    // Press 'j' — should dismiss help, not scroll
+    let key = KeyEvent::new(KeyCode::Char('j'), KeyModifiers::NONE);
+    app.handle_key(key).unwrap();
+    app.flush_actions().await;
+    assert!(!app.show_help);
+}
+
+#[tokio::test]
+async fn test_help_overlay_dismisses_on_escape() {
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+    let mut app = test_app();
+    app.show_help = true;
+
+    let key = KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE);
+    app.handle_key(key).unwrap();
+    app.flush_actions().await;
+    assert!(!app.show_help);
+}
+
+#[tokio::test]
+async fn test_focus_follows_the_topmost_overlay_then_search_then_list() {
+    use clisten::app::{Focus, Modal};
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+    let mut app = test_app();
+    assert_eq!(app.focus(), Focus::List);
+
+    app.handle_action(Action::FocusSearch).await.unwrap();
+    assert_eq!(app.focus(), Focus::Search);
+    // Typed keys go to the search bar, not the keymap ('?' is help).
+    app.handle_key(KeyEvent::new(KeyCode::Char('?'), KeyModifiers::NONE))
+        .unwrap();
+    app.flush_actions().await;
+    assert!(!app.show_help);
+    assert_eq!(app.focus(), Focus::Search);
+
+    app.handle_action(Action::OpenDirectPlay).await.unwrap();
+    assert_eq!(app.focus(), Focus::Modal(Modal::DirectPlay));
+    assert!(app.focus().is_overlay());
+    app.show_help = true;
+    assert_eq!(app.focus(), Focus::Help);
+
+    app.handle_key(KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE))
+        .unwrap();
+    app.flush_actions().await;
+    assert_eq!(app.focus(), Focus::Modal(Modal::DirectPlay));
+}
+
+#[tokio::test]
+async fn test_typing_in_the_search_bar_filters_the_list_after_a_pause() {
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+    let mut app = test_app();
+    app.discovery_list.set_items(vec![
+        make_item("Jazz Sessions"),
+        make_item("Dub Plates"),
+        make_item("Jazz Cafe"),
+    ]);
+    app.handle_action(Action::FocusSearch).await.unwrap();
+    for c in "jazz".chars() {
+        app.handle_key(KeyEvent::new(KeyCode::Char(c), KeyModifiers::NONE))
+            .unwrap();
+    }
+    // Nothing is filtered while the keys keep coming.
+    app.flush_actions().await;
+    assert_eq!(app.discovery_list.visible_items().len(), 3);
+
+    tokio::time::sleep(std::time::Duration::from_millis(200)).await;
+    app.flush_actions().await;
+    assert_eq!(app.discovery_list.visible_items().len(), 2);
+    assert_eq!(app.search_bar.match_count(), Some(2));
+    let screen = app.render_to_string(100, 30).unwrap();
+    assert!(screen.contains("/ jazz_   2 matches"), "{screen}");
+
+    // A timer for text that has since changed does nothing.
+    app.handle_action(Action::FilterList("dub".to_string()))
+        .await
+        .unwrap();
+    assert_eq!(app.discovery_list.visible_items().len(), 2);
+
+    app.handle_key(KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE))
+        .unwrap();
+    app.flush_actions().await;
+    assert_eq!(app.discovery_list.visible_items().len(), 3);
+    assert_eq!(app.search_bar.match_count(), None);
+    assert!(!app.search_bar.is_focused());
+}
+
+#[tokio::test]
+async fn test_context_menu_lists_what_applies_and_runs_the_choice() {
+    use clisten::app::{Focus, Modal};
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+    let mut app = test_app();
+    app.discovery_list
+        .set_items(vec![make_item("Late Night Jazz")]);
+    let key = |code| KeyEvent::new(code, KeyModifiers::NONE);
+
+    app.handle_key(key(KeyCode::Char('.'))).unwrap();
+    app.flush_actions().await;
+    assert_eq!(app.focus(), Focus::Modal(Modal::ContextMenu));
+    assert_eq!(
+        app.context_menu.labels(),
+        [
+            "Play now",
+            "Play next",
+            "Add to queue",
+            "Favorite",
+            "View info",
+            "Open in browser",
+            "Copy link",
+            "Copy stream URL",
+        ]
+    );
+
+    app.handle_key(key(KeyCode::Char('j'))).unwrap();
+    app.handle_key(key(KeyCode::Char('j'))).unwrap();
+    app.handle_key(key(KeyCode::Enter)).unwrap();
+    app.flush_actions().await;
+    assert_eq!(app.focus(), Focus::List);
+    assert_eq!(app.queue.len(), 1);
+    assert_eq!(app.queue.items()[0].item.title(), "Late Night Jazz");
+}
+
+// ── Dependency check ─────────────────────────────────────────────────────────
+
+#[test]
+#[ignore = "requires mpv to be installed"]
+fn test_check_mpv_present() {
+    // Integration test — mpv must be installed in the environment
+    let result = which::which("mpv");
+    assert!(result.is_ok(), "mpv must be installed for clisten to work");
+}
+
+// ── Partial search results ───────────────────────────────────────────────────
+
+#[tokio::test]
+async fn test_failed_search_pages_are_retried_individually() {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+    // Three full pages; the middle one fails on its first request.
+    let calls = Arc::new(AtomicUsize::new(0));
+    let fetch_calls = calls.clone();
+    let fetch: clisten::api::nts::PageFetcher = Arc::new(move |offset, limit| {
+        let first_try_of_middle = offset == 12 && fetch_calls.fetch_add(1, Ordering::SeqCst) == 0;
+        Box::pin(async move {
+            if first_try_of_middle {
+                anyhow::bail!("page {} timed out", offset);
+            }
+            let count = if offset < 36 { limit } else { 0 };
+            Ok(clisten::api::nts::SearchPage {
+                items: (offset..offset + count)
+                    .map(|i| make_item(&format!("Ep {}", i)))
+                    .collect(),
+                total: Some(36),
+                stale: None,
+            })
+        })
+    });
+
+    async fn settle(app: &mut clisten::app::App) {
+        for _ in 0..100 {
+            tokio::time::sleep(Duration::from_millis(5)).await;
+            app.flush_actions().await;
+            if !app.discovery_list.is_loading() {
+                return;
+            }
+        }
+        panic!("search never finished");
+    }
+
+    let mut app = test_app();
+    app.start_paged_search(fetch);
+    settle(&mut app).await;
+    assert_eq!(app.discovery_list.total_item_count(), 24);
+    assert_eq!(
+        app.discovery_list.search_status().as_deref(),
+        Some("Loaded 24 of ~36 results")
+    );
+    assert!(app
+        .discovery_list
+        .footer()
+        .is_some_and(|f| f.contains("press r")));
+
+    app.handle_key(KeyEvent::new(KeyCode::Char('r'), KeyModifiers::NONE))
+        .unwrap();
+    app.flush_actions().await;
+    settle(&mut app).await;
+    assert_eq!(app.discovery_list.total_item_count(), 36);
+    assert_eq!(
+        app.discovery_list.search_status().as_deref(),
+        Some("36 results")
+    );
+    assert_eq!(app.discovery_list.footer(), None);
+    assert_eq!(
+        calls.load(Ordering::SeqCst),
+        2,
+        "only the failed page is refetched"
+    );
+}
+
+#[tokio::test]
+async fn test_queue_shows_lengths_and_time_left() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("test.db");
+    let mut config = clisten::config::Config::default();
+    config.nts.api_base = Some("http://127.0.0.1:9".to_string());
+    let mut app = clisten::app::App::with_db(config.clone(), Database::open_at(&path).unwrap())
+        .unwrap()
+        .with_player(FakePlayer::new().with_duration(Some(600.0)));
+    for title in ["one", "two", "three"] {
+        app.handle_action(Action::AddToQueue(make_item(title)))
+            .await
+            .unwrap();
+    }
+    app.handle_action(Action::PlayQueueIndex(0)).await.unwrap();
+    app.flush_actions().await;
+    app.handle_action(Action::TrackLengthEstimated {
+        key: make_item("two").favorite_key(),
+        secs: 3540.0,
+    })
+    .await
+    .unwrap();
+
+    let screen = app.render_to_string(120, 30).unwrap();
+    assert!(screen.contains("one  10:00"), "{screen}");
+    assert!(screen.contains("two  ~59:00"), "{screen}");
+    // Ten minutes of "one" and the estimate for "two"; "three" is unknown.
+    assert!(screen.contains("Queue (3) · ~1:09:00 left"), "{screen}");
+    assert!(screen.contains("Track 1/3 · ~1:09:00 left"), "{screen}");
+
+    // What mpv measured is remembered; estimates aren't.
+    drop(app);
+    let app = clisten::app::App::with_db(config, Database::open_at(&path).unwrap()).unwrap();
+    let screen = app.render_to_string(120, 30).unwrap();
+    assert!(screen.contains("one  10:00"), "{screen}");
+    assert!(!screen.contains("~59:00"), "{screen}");
+}
+
+#[tokio::test]
+async fn test_radio_mode_queues_unplayed_episodes_of_the_same_genre() {
+    let ambient = |name: &str| {
+        serde_json::from_str(&format!(
+            r#"{{"name": "{name}", "genres": [{{"id": "ambient", "value": "Ambient"}}]}}"#
+        ))
+        .unwrap()
+    };
+    let tagged = ["heard", "one", "a", "b", "c", "d"].map(make_item).to_vec();
+    let api = FixtureApi::new()
+        .with_episode("one", "one", ambient("one"))
+        .with_episode("c", "c", ambient("c"))
+        .with_episode("d", "d", ambient("d"))
+        .with_tagged("ambient", tagged);
+    let dir = tempfile::tempdir().unwrap();
+    let db = Database::open_at(&dir.path().join("test.db")).unwrap();
+    db.record_play(&make_item("heard")).unwrap();
+    let mut config = clisten::config::Config::default();
+    config.general.radio_mode = true;
+    let mut app = clisten::app::App::with_db(config, db)
+        .unwrap()
+        .with_nts_api(api)
+        .with_player(FakePlayer::new());
+
+    app.handle_action(Action::AddToQueue(make_item("one")))
+        .await
+        .unwrap();
+    app.handle_action(Action::PlayQueueIndex(0)).await.unwrap();
+    settle(&mut app).await;
+    let titles: Vec<&str> = app.queue.items().iter().map(|qi| qi.item.title()).collect();
+    assert_eq!(titles, ["one", "a", "b", "c"]);
+    assert!(latest_message(&app)
+        .unwrap()
+        .contains("queued 3 more Ambient episodes"));
+    let screen = app.render_to_string(120, 30).unwrap();
+    assert!(screen.contains("↻ NTS Radio: a"), "{screen}");
+    assert!(screen.contains("↻ Radio"), "{screen}");
+
+    // Reaching the last entry tops the queue up again.
+    app.handle_action(Action::PlayQueueIndex(3)).await.unwrap();
+    settle(&mut app).await;
+    assert_eq!(app.queue.len(), 5);
+
+    // Once everything of the genre is queued or played, say so.
+    app.handle_action(Action::PlayQueueIndex(4)).await.unwrap();
+    settle(&mut app).await;
+    assert_eq!(app.queue.len(), 5);
+    assert!(latest_message(&app)
+        .unwrap()
+        .contains("no unplayed Ambient"));
+
+    // Turned off, the queue is left to run out.
+    app.handle_action(Action::ToggleRadio).await.unwrap();
+    app.handle_action(Action::AddToQueue(make_item("one")))
+        .await
+        .unwrap();
+    app.handle_action(Action::PlayQueueIndex(5)).await.unwrap();
+    settle(&mut app).await;
+    assert_eq!(app.queue.len(), 6);
+}
+
+#[tokio::test]
+async fn test_resolved_url_details_reach_the_queue_history_and_favorites() {
+    use clisten::api::models::UrlMeta;
+
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("test.db");
+    let mut app = clisten::app::App::with_db(
+        clisten::config::Config::default(),
+        Database::open_at(&path).unwrap(),
+    )
+    .unwrap()
+    .with_player(FakePlayer::new());
+    let url = "https://artist.bandcamp.com/track/song";
+    let item = DiscoveryItem::DirectUrl {
+        url: url.to_string(),
+        title: None,
+        meta: None,
+    };
+    app.handle_action(Action::PlayItem(item.clone()))
+        .await
+        .unwrap();
+    app.handle_action(Action::ToggleFavorite(item))
+        .await
+        .unwrap();
+    app.flush_actions().await;
+
+    app.handle_action(Action::UrlResolved {
+        url: url.to_string(),
+        title: Some("Song".to_string()),
+        meta: UrlMeta {
+            artist: Some("Artist".to_string()),
+            duration_secs: Some(245.0),
+            thumbnail: None,
+            site: Some("Bandcamp".to_string()),
+        },
+    })
+    .await
+    .unwrap();
+
+    assert_eq!(app.queue.items()[0].item.title(), "Song");
+    let screen = app.render_to_string(120, 30).unwrap();
+    // mpv's measured length wins over what the page says.
+    assert!(
+        screen.contains("Song - Artist · Bandcamp  10:00"),
+        "{screen}"
+    );
+    let controls = screen.lines().find(|l| l.contains("Play/Pause")).unwrap();
+    assert!(controls.contains("Song") && !controls.contains("bandcamp.com"));
+
+    let db = Database::open_at(&path).unwrap();
+    let favorite = &db.favorites().unwrap()[0];
+    assert_eq!(favorite.item.title(), "Song");
+    assert_eq!(favorite.item.subtitle(), "Artist · Bandcamp");
+    assert_eq!(db.history(10).unwrap()[0].item.title(), "Song");
+}
+
+#[tokio::test]
+async fn test_podcast_subscription_lists_episodes_and_tracks_played_ones() {
+    use clisten::components::nts::NtsSubTab;
+
+    let url = "https://fieldnotes.example/feed";
+    let feed = r#"<rss version="2.0" xmlns:itunes="http://www.itunes.com/dtds/podcast-1.0.dtd">
+        <channel>
+          <title>Field Notes</title>
+          <item>
+            <title>Older</title><guid>ep-1</guid>
+            <pubDate>Mon, 02 Jun 2025 08:00:00 +0000</pubDate>
+            <enclosure url="https://cdn.example/1.mp3" type="audio/mpeg"/>
+            <itunes:duration>45:00</itunes:duration>
+          </item>
+          <item>
+            <title>Newer</title><guid>ep-2</guid>
+            <pubDate>Mon, 09 Jun 2025 08:00:00 +0000</pubDate>
+            <enclosure url="https://cdn.example/2.mp3" type="audio/mpeg"/>
+            <itunes:duration>3600</itunes:duration>
+          </item>
+        </channel>
+      </rss>"#;
+    let mut app =
+        fixture_app(FixtureApi::new().with_feed(url, feed)).with_player(FakePlayer::new());
+
+    app.handle_action(Action::SwitchSubTab(8)).await.unwrap();
+    settle(&mut app).await;
+    let screen = app.render_to_string(120, 30).unwrap();
+    assert!(screen.contains("No podcasts yet"), "{screen}");
+
+    app.handle_action(Action::SubscribePodcast(url.to_string()))
+        .await
+        .unwrap();
+    settle(&mut app).await;
+    let titles: Vec<&str> = app
+        .discovery_list
+        .visible_items()
+        .iter()
+        .map(|item| item.title())
+        .collect();
+    assert_eq!(titles, ["Newer", "Older"]);
+    assert_eq!(app.nts_tab.count(NtsSubTab::Podcasts), 2);
+    let screen = app.render_to_string(120, 30).unwrap();
+    assert!(screen.contains("Subscribed to Field Notes"), "{screen}");
+    assert!(screen.contains("Newer ●"), "{screen}");
+    assert!(
+        screen.contains("Field Notes · 2025-06-09 · 1h 00m"),
+        "{screen}"
+    );
+
+    // The enclosure plays, and playing it marks it played.
+    let newer = app.discovery_list.visible_items()[0].clone();
+    app.handle_action(Action::PlayItem(newer.clone()))
+        .await
+        .unwrap();
+    settle(&mut app).await;
+    assert_eq!(
+        app.queue.current().unwrap().url,
+        "https://cdn.example/2.mp3"
+    );
+    assert_eq!(app.nts_tab.count(NtsSubTab::Podcasts), 1);
+    let screen = app.render_to_string(120, 30).unwrap();
+    assert!(!screen.contains("Newer ●"), "{screen}");
+
+    app.handle_action(Action::SetPodcastPlayed {
+        key: newer.favorite_key(),
+        played: false,
+    })
+    .await
+    .unwrap();
+    assert_eq!(app.nts_tab.count(NtsSubTab::Podcasts), 2);
+
+    app.handle_action(Action::UnsubscribePodcast(url.to_string()))
+        .await
+        .unwrap();
+    settle(&mut app).await;
+    assert!(latest_message(&app)
+        .unwrap()
+        .contains("Unsubscribed from Field Notes"));
+    assert_eq!(app.nts_tab.count(NtsSubTab::Podcasts), 0);
+    assert!(app.discovery_list.visible_items().is_empty());
 }
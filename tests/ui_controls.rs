@@ -1,4 +1,5 @@
-// Sub-tab switching, list filtering, search bar UX, volume, and direct play modal.
+// Sub-tab switching, list filtering, search bar UX, volume, direct play modal,
+// and golden snapshots of the rendered screen.
 
 use clisten::action::Action;
 use clisten::api::models::DiscoveryItem;
@@ -16,6 +17,19 @@ fn make_item(title: &str, subtitle: &str) -> DiscoveryItem {
         genres: vec![subtitle.to_string()],
         location: None,
         audio_url: Some(format!("http://{}", title)),
+        broadcast_date: None,
+    }
+}
+
+fn make_dated_item(title: &str, date: Option<&str>) -> DiscoveryItem {
+    DiscoveryItem::NtsEpisode {
+        name: title.to_string(),
+        show_alias: title.to_string(),
+        episode_alias: title.to_string(),
+        genres: vec![],
+        location: None,
+        audio_url: None,
+        broadcast_date: date.map(String::from),
     }
 }
 
@@ -70,6 +84,28 @@ fn test_discovery_list_filter() {
             || i.subtitle().to_lowercase().contains("jazz")));
 }
 
+#[test]
+fn test_discovery_list_filter_ignores_accents() {
+    let mut list = DiscoveryList::new();
+    list.set_items(vec![
+        make_item("Café Mix", "lounge"),
+        make_item("Cafe\u{301} Society", "jazz"),
+        make_item("Rock Classics", "rock music"),
+        make_item("Late Night", "musique concrète"),
+    ]);
+
+    list.set_filter(Some("cafe".to_string()));
+    assert_eq!(list.visible_items().len(), 2);
+
+    list.set_filter(Some("CAFÉ".to_string()));
+    assert_eq!(list.visible_items().len(), 2);
+
+    list.set_filter(Some("concrete".to_string()));
+    let visible = list.visible_items();
+    assert_eq!(visible.len(), 1);
+    assert_eq!(visible[0].title(), "Late Night");
+}
+
 #[test]
 fn test_discovery_list_clear_filter() {
     let mut list = DiscoveryList::new();
@@ -90,6 +126,92 @@ fn test_discovery_list_clear_filter() {
     );
 }
 
+#[test]
+fn test_discovery_list_sort_cycle() {
+    use clisten::components::discovery_list::SortOrder;
+
+    let mut list = DiscoveryList::new();
+    list.set_items(vec![
+        make_dated_item("Beta", Some("2024-03-01")),
+        make_dated_item("alpha", None),
+        make_dated_item("Gamma", Some("2025-01-15")),
+    ]);
+    let titles = |list: &DiscoveryList| {
+        list.visible_items()
+            .iter()
+            .map(|i| i.title().to_string())
+            .collect::<Vec<_>>()
+    };
+
+    assert_eq!(list.sort_order(), SortOrder::Default);
+    assert_eq!(titles(&list), ["Beta", "alpha", "Gamma"]);
+
+    list.cycle_sort();
+    assert_eq!(list.sort_order(), SortOrder::NewestFirst);
+    assert_eq!(titles(&list), ["Gamma", "Beta", "alpha"]);
+
+    list.cycle_sort();
+    assert_eq!(titles(&list), ["Beta", "Gamma", "alpha"]);
+
+    list.cycle_sort();
+    assert_eq!(titles(&list), ["alpha", "Beta", "Gamma"]);
+
+    list.cycle_sort();
+    assert_eq!(list.sort_order(), SortOrder::Default);
+    assert_eq!(titles(&list), ["Beta", "alpha", "Gamma"]);
+}
+
+#[test]
+fn test_huge_list_filters_appends_and_draws_the_rows_on_screen() {
+    use ratatui::{backend::TestBackend, Terminal};
+
+    let mut list = DiscoveryList::new();
+    let page = |from: usize| -> Vec<DiscoveryItem> {
+        (from..from + 2500)
+            .map(|i| {
+                make_item(
+                    &format!("Show {i}"),
+                    if i % 2 == 0 { "jazz" } else { "dub" },
+                )
+            })
+            .collect()
+    };
+    list.set_items(page(0));
+    list.set_filter(Some("JAZZ".to_string()));
+    list.append_items(page(2500));
+    assert_eq!(list.total_item_count(), 5000);
+    assert_eq!(list.visible_items().len(), 2500);
+    assert_eq!(list.visible_items()[1250].title(), "Show 2500");
+
+    let theme = clisten::theme::Theme::default();
+    let mut terminal = Terminal::new(TestBackend::new(40, 10)).unwrap();
+    let mut render = |list: &DiscoveryList| {
+        terminal
+            .draw(|frame| list.draw(frame, frame.area(), &theme))
+            .unwrap();
+        terminal
+            .backend()
+            .buffer()
+            .content()
+            .iter()
+            .map(|c| c.symbol())
+            .collect::<String>()
+    };
+
+    list.select_last();
+    let rendered = render(&list);
+    assert!(rendered.contains("2500 Show 4998"), "{rendered}");
+    assert!(!rendered.contains("Show 4988"), "{rendered}");
+    let titles: Vec<&str> = list.rows_on_screen().iter().map(|i| i.title()).collect();
+    assert_eq!(titles.first(), Some(&"Show 4990"));
+    assert_eq!(titles.len(), 5);
+
+    list.select_number(1001);
+    let rendered = render(&list);
+    assert!(rendered.contains("1001 Show 2000"), "{rendered}");
+    assert_eq!(list.selected_item().unwrap().title(), "Show 2000");
+}
+
 // ── Search bar UX ────────────────────────────────────────────────────────────
 
 #[test]
@@ -161,3 +283,687 @@ fn test_direct_play_modal_visibility() {
     modal.hide();
     assert!(!modal.is_visible());
 }
+
+// ── Seek bar ─────────────────────────────────────────────────────────────────
+
+#[test]
+fn test_parse_timestamp() {
+    use clisten::components::parse_timestamp;
+    assert_eq!(parse_timestamp("1:30"), Some(90.0));
+    assert_eq!(parse_timestamp("1:02:03"), Some(3723.0));
+    assert_eq!(parse_timestamp("45"), Some(2700.0));
+    assert_eq!(parse_timestamp("1:75"), None);
+    assert_eq!(parse_timestamp(""), None);
+    assert_eq!(parse_timestamp("1::2"), None);
+}
+
+#[test]
+fn test_seek_bar_click_and_drag_seeks_on_release() {
+    use clisten::components::play_controls::PlayControls;
+    use crossterm::event::{KeyModifiers, MouseButton, MouseEvent, MouseEventKind};
+    use ratatui::{backend::TestBackend, Terminal};
+
+    let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+    let mut controls = PlayControls::new();
+    controls.register_action_handler(tx);
+    controls
+        .update(&Action::PlaybackDuration(Some(600.0)))
+        .unwrap();
+    controls.update(&Action::PlaybackPosition(60.0)).unwrap();
+    assert_eq!(controls.height(), 5);
+
+    let theme = clisten::theme::Theme::default();
+    let mut terminal = Terminal::new(TestBackend::new(80, 5)).unwrap();
+    terminal
+        .draw(|frame| controls.draw(frame, frame.area(), &theme))
+        .unwrap();
+    let rendered: String = terminal
+        .backend()
+        .buffer()
+        .content()
+        .iter()
+        .map(|c| c.symbol())
+        .collect();
+    assert!(rendered.contains("1:00"), "elapsed time shown");
+    assert!(rendered.contains("10:00"), "total time shown");
+    assert!(rendered.contains("10%"), "percentage shown");
+
+    let mouse = |kind, column| MouseEvent {
+        kind,
+        column,
+        row: 1,
+        modifiers: KeyModifiers::NONE,
+    };
+    // Clicking off the bar row does nothing.
+    assert!(!controls.handle_mouse_event(MouseEvent {
+        row: 3,
+        ..mouse(MouseEventKind::Down(MouseButton::Left), 40)
+    }));
+
+    assert!(controls.handle_mouse_event(mouse(MouseEventKind::Down(MouseButton::Left), 20)));
+    assert!(controls.handle_mouse_event(mouse(MouseEventKind::Drag(MouseButton::Left), 200)));
+    assert_eq!(controls.scrub_position(), Some(600.0), "drag clamps to end");
+    assert!(rx.try_recv().is_err(), "no seek until release");
+
+    assert!(controls.handle_mouse_event(mouse(MouseEventKind::Up(MouseButton::Left), 200)));
+    assert!(matches!(rx.try_recv(), Ok(Action::SeekAbsolute(s)) if s == 600.0));
+    assert_eq!(controls.scrub_position(), None);
+}
+
+#[test]
+fn test_play_controls_show_reconnect_attempt() {
+    use clisten::components::play_controls::PlayControls;
+    use ratatui::{backend::TestBackend, Terminal};
+
+    let mut controls = PlayControls::new();
+    controls
+        .update(&Action::PlaybackStarted {
+            title: "NTS 1".to_string(),
+        })
+        .unwrap();
+    let theme = clisten::theme::Theme::default();
+    let mut terminal = Terminal::new(TestBackend::new(100, 4)).unwrap();
+    let mut rendered = |controls: &PlayControls| -> String {
+        terminal
+            .draw(|frame| controls.draw(frame, frame.area(), &theme))
+            .unwrap();
+        terminal
+            .backend()
+            .buffer()
+            .content()
+            .iter()
+            .map(|c| c.symbol())
+            .collect()
+    };
+    assert!(rendered(&controls).contains("NTS 1"));
+
+    controls.set_reconnecting(Some(2));
+    let text = rendered(&controls);
+    assert!(text.contains("Reconnecting… (attempt 2)"));
+    assert!(!text.contains("NTS 1"));
+
+    controls.set_reconnecting(None);
+    assert!(rendered(&controls).contains("NTS 1"));
+}
+
+#[test]
+fn test_pinned_track_shows_while_scrolled_away_and_jumps_back() {
+    use clisten::components::discovery_list::PinnedTrack;
+    use crossterm::event::{KeyModifiers, MouseButton, MouseEvent, MouseEventKind};
+    use ratatui::{backend::TestBackend, Terminal};
+
+    let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+    let mut list = DiscoveryList::new();
+    list.register_action_handler(tx);
+    let items: Vec<DiscoveryItem> = (0..30)
+        .map(|i| make_item(&format!("Show {i}"), "Jazz"))
+        .collect();
+    let playing_key = items[0].favorite_key();
+    list.set_items(items);
+    list.set_pinned(Some(PinnedTrack {
+        key: playing_key,
+        title: "Show 0".to_string(),
+        position_secs: 75.0,
+    }));
+
+    let theme = clisten::theme::Theme::default();
+    let mut terminal = Terminal::new(TestBackend::new(60, 10)).unwrap();
+    let mut render = |list: &DiscoveryList| {
+        terminal
+            .draw(|frame| list.draw(frame, frame.area(), &theme))
+            .unwrap();
+        terminal
+            .backend()
+            .buffer()
+            .content()
+            .iter()
+            .map(|c| c.symbol())
+            .collect::<String>()
+    };
+
+    // At the top the playing item is on screen, so nothing is pinned.
+    render(&list);
+    assert!(!list.is_pin_shown());
+
+    for _ in 0..20 {
+        list.next();
+    }
+    render(&list);
+    assert!(list.is_pin_shown());
+    let rendered = render(&list);
+    assert!(rendered.contains("Now: Show 0 — 1:15"), "{rendered}");
+
+    // Clicking the pinned row asks to jump; jumping selects the item.
+    assert!(list.handle_mouse_event(MouseEvent {
+        kind: MouseEventKind::Down(MouseButton::Left),
+        column: 10,
+        row: 0,
+        modifiers: KeyModifiers::NONE,
+    }));
+    assert!(matches!(rx.try_recv(), Ok(Action::JumpToPlaying)));
+    assert!(list.select_pinned());
+    assert_eq!(list.selected_index(), Some(0));
+    render(&list);
+    assert!(!list.is_pin_shown());
+
+    list.set_pinned(None);
+    assert!(!list.select_pinned());
+}
+
+#[test]
+fn test_equalizer_modal_switches_presets_and_tunes_custom_bands() {
+    use clisten::components::equalizer_modal::EqualizerModal;
+    use clisten::player::equalizer::EqualizerPreset;
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+    let key = |code| KeyEvent::new(code, KeyModifiers::NONE);
+    let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+    let mut modal = EqualizerModal::new();
+    modal.register_action_handler(tx);
+    modal.show(EqualizerPreset::Flat, [0.0; 5]);
+
+    modal.handle_key_event(key(KeyCode::Char('j'))).unwrap();
+    assert!(matches!(
+        rx.try_recv(),
+        Ok(Action::SetEqualizer {
+            preset: EqualizerPreset::BassBoost,
+            ..
+        })
+    ));
+
+    // Adjusting a band starts a custom curve from the current preset.
+    modal.handle_key_event(key(KeyCode::Char('l'))).unwrap();
+    modal.handle_key_event(key(KeyCode::Char('+'))).unwrap();
+    assert_eq!(modal.preset(), EqualizerPreset::Custom);
+    assert!(matches!(
+        rx.try_recv(),
+        Ok(Action::SetEqualizer { preset: EqualizerPreset::Custom, custom_gains })
+            if custom_gains == [6.0, 5.0, 0.0, 0.0, 0.0]
+    ));
+
+    // Moving past the last preset does nothing.
+    modal.handle_key_event(key(KeyCode::Char('j'))).unwrap();
+    assert!(rx.try_recv().is_err());
+
+    modal.handle_key_event(key(KeyCode::Esc)).unwrap();
+    assert!(!modal.is_visible());
+}
+
+#[test]
+fn test_tracklist_view_marks_current_song_and_seeks() {
+    use clisten::api::models::EpisodeTrack;
+    use clisten::components::tracklist_view::TracklistView;
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+    let key = |code| KeyEvent::new(code, KeyModifiers::NONE);
+    let song = |title: &str, offset: Option<f64>| EpisodeTrack {
+        artist: "Artist".to_string(),
+        title: title.to_string(),
+        offset,
+        duration: None,
+    };
+    let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+    let mut view = TracklistView::new();
+    view.register_action_handler(tx);
+    assert!(!view.show(), "nothing to show before an episode plays");
+
+    view.begin("ep:a".to_string(), "Episode A".to_string());
+    assert!(view.tracks().is_none());
+    // A late response for a previous episode is dropped.
+    view.set_tracks("ep:old", vec![song("Old", None)]);
+    assert!(view.tracks().is_none());
+    view.set_tracks(
+        "ep:a",
+        vec![
+            song("One", Some(0.0)),
+            song("Two", Some(300.0)),
+            song("Three", None),
+        ],
+    );
+    view.set_position(420.0);
+    assert_eq!(view.playing_index(), Some(1));
+
+    assert!(view.show());
+    view.handle_key_event(key(KeyCode::Char('k'))).unwrap();
+    view.handle_key_event(key(KeyCode::Enter)).unwrap();
+    assert!(matches!(rx.try_recv(), Ok(Action::SeekAbsolute(s)) if s == 0.0));
+    assert!(!view.is_visible());
+
+    // Songs without a timestamp can't be seeked to.
+    view.show();
+    view.handle_key_event(key(KeyCode::Char('j'))).unwrap();
+    view.handle_key_event(key(KeyCode::Enter)).unwrap();
+    assert!(rx.try_recv().is_err());
+    assert!(view.is_visible());
+
+    view.clear();
+    assert!(!view.is_visible() && !view.show());
+}
+
+#[test]
+fn test_info_view_fills_in_details_for_the_open_item() {
+    use clisten::api::models::ItemInfo;
+    use clisten::components::info_view::InfoView;
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+    let info = |title: &str, description: Option<&str>| ItemInfo {
+        title: title.to_string(),
+        description: description.map(String::from),
+        ..Default::default()
+    };
+    let mut view = InfoView::new();
+    view.show("ep:a".to_string(), info("A", None), true);
+    assert!(view.is_visible() && view.is_loading());
+
+    // Details for an item that's no longer open are dropped.
+    view.set_info("ep:old", info("Old", Some("stale")));
+    assert_eq!(view.info().title, "A");
+    view.set_info("ep:a", info("A", Some("Long text")));
+    assert!(!view.is_loading());
+    assert_eq!(view.info().description.as_deref(), Some("Long text"));
+
+    view.handle_key_event(KeyEvent::new(KeyCode::Char('i'), KeyModifiers::NONE))
+        .unwrap();
+    assert!(!view.is_visible());
+}
+
+#[test]
+fn test_seek_modal_jump_input() {
+    use clisten::components::seek_modal::SeekModal;
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+    let key = |code| KeyEvent::new(code, KeyModifiers::NONE);
+    let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+    let mut modal = SeekModal::new();
+    modal.register_action_handler(tx);
+    modal.show(10.0, 3600.0);
+
+    modal.handle_key_event(key(KeyCode::Char('g'))).unwrap();
+    assert_eq!(modal.jump_input(), Some(""));
+    for c in "12:3x4".chars() {
+        modal.handle_key_event(key(KeyCode::Char(c))).unwrap();
+    }
+    assert_eq!(modal.jump_input(), Some("12:34"), "non-digits are ignored");
+
+    modal.handle_key_event(key(KeyCode::Enter)).unwrap();
+    assert!(matches!(rx.try_recv(), Ok(Action::SeekAbsolute(s)) if s == 754.0));
+    assert!(matches!(rx.try_recv(), Ok(Action::CloseSeekModal)));
+    assert_eq!(modal.jump_input(), None);
+
+    // Esc leaves jump mode without closing the modal.
+    modal.handle_key_event(key(KeyCode::Char('g'))).unwrap();
+    modal.handle_key_event(key(KeyCode::Esc)).unwrap();
+    assert_eq!(modal.jump_input(), None);
+    assert!(rx.try_recv().is_err());
+}
+
+#[test]
+fn test_seek_modal_moves_between_and_removes_bookmarks() {
+    use clisten::components::seek_modal::SeekModal;
+    use clisten::db::Bookmark;
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+    let key = |c| KeyEvent::new(KeyCode::Char(c), KeyModifiers::NONE);
+    let bookmark = |id, position_secs| Bookmark {
+        id,
+        position_secs,
+        label: format!("cue {id}"),
+    };
+    let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+    let mut modal = SeekModal::new();
+    modal.register_action_handler(tx);
+    modal.set_bookmarks(vec![bookmark(1, 600.0), bookmark(2, 1800.0)]);
+    modal.show(1000.0, 3600.0);
+
+    modal.handle_key_event(key('}')).unwrap();
+    assert_eq!(modal.cursor_position(), 1800.0);
+    modal.handle_key_event(key('}')).unwrap();
+    assert_eq!(
+        modal.cursor_position(),
+        1800.0,
+        "no bookmark after the last"
+    );
+    modal.handle_key_event(key('{')).unwrap();
+    assert_eq!(modal.cursor_position(), 600.0);
+
+    modal.handle_key_event(key('x')).unwrap();
+    assert!(matches!(rx.try_recv(), Ok(Action::DeleteBookmark(1))));
+    modal.handle_key_event(key('0')).unwrap();
+    modal.handle_key_event(key('x')).unwrap();
+    assert!(rx.try_recv().is_err(), "no bookmark under the cursor");
+}
+
+#[test]
+fn test_seek_modal_sets_and_clears_an_ab_loop() {
+    use clisten::components::seek_modal::SeekModal;
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+    let press = |code| KeyEvent::new(code, KeyModifiers::NONE);
+    let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+    let mut modal = SeekModal::new();
+    modal.register_action_handler(tx);
+    modal.show(600.0, 3600.0);
+
+    modal.handle_key_event(press(KeyCode::Char('a'))).unwrap();
+    assert!(
+        matches!(rx.try_recv(), Ok(Action::SetAbLoop(None))),
+        "only A so far"
+    );
+    modal.handle_key_event(press(KeyCode::Char('l'))).unwrap();
+    modal.handle_key_event(press(KeyCode::Char('b'))).unwrap();
+    assert!(matches!(
+        rx.try_recv(),
+        Ok(Action::SetAbLoop(Some((600.0, 630.0))))
+    ));
+
+    // A start past the end drops the end, which stops the loop.
+    modal.handle_key_event(press(KeyCode::Right)).unwrap();
+    modal.handle_key_event(press(KeyCode::Char('a'))).unwrap();
+    assert!(matches!(rx.try_recv(), Ok(Action::SetAbLoop(None))));
+    assert_eq!(modal.ab_loop(), None);
+
+    modal.handle_key_event(press(KeyCode::Char('$'))).unwrap();
+    modal.handle_key_event(press(KeyCode::Char('b'))).unwrap();
+    assert_eq!(modal.ab_loop(), Some((635.0, 3600.0)));
+    rx.try_recv().unwrap();
+    modal.handle_key_event(press(KeyCode::Char('c'))).unwrap();
+    assert!(matches!(rx.try_recv(), Ok(Action::SetAbLoop(None))));
+    assert_eq!(modal.ab_loop(), None);
+}
+
+// ── Snapshots ────────────────────────────────────────────────────────────────
+
+const SNAPSHOT_WIDTH: u16 = 100;
+const SNAPSHOT_HEIGHT: u16 = 30;
+
+/// An App on a throwaway database whose network calls fail fast.
+fn snapshot_app() -> clisten::app::App {
+    let dir = tempfile::tempdir().unwrap();
+    let db = clisten::db::Database::open_at(&dir.path().join("test.db")).unwrap();
+    std::mem::forget(dir);
+    let mut config = clisten::config::Config::default();
+    config.nts.api_base = Some("http://127.0.0.1:9".to_string());
+    clisten::app::App::with_db(config, db).unwrap()
+}
+
+/// Compare the rendered screen with `tests/snapshots/{name}.txt`. Run with
+/// `UPDATE_SNAPSHOTS=1` to write the file instead after an intended change.
+fn assert_snapshot(app: &clisten::app::App, name: &str) {
+    let rendered = app
+        .render_to_string(SNAPSHOT_WIDTH, SNAPSHOT_HEIGHT)
+        .unwrap();
+    let path = std::path::Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/snapshots")
+        .join(format!("{name}.txt"));
+    if std::env::var_os("UPDATE_SNAPSHOTS").is_some() {
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(&path, format!("{rendered}\n")).unwrap();
+        return;
+    }
+    let expected = std::fs::read_to_string(&path).unwrap_or_else(|_| {
+        panic!(
+            "no snapshot at {}; run with UPDATE_SNAPSHOTS=1 to create it",
+            path.display()
+        )
+    });
+    let mismatch = expected
+        .trim_end_matches('\n')
+        .lines()
+        .zip(rendered.lines())
+        .position(|(want, got)| want != got);
+    assert!(
+        expected.trim_end_matches('\n') == rendered,
+        "{name} differs from its snapshot (first at row {mismatch:?}); \
+         run with UPDATE_SNAPSHOTS=1 if the change is intended\n{rendered}"
+    );
+}
+
+fn live_channels() -> Vec<DiscoveryItem> {
+    vec![
+        DiscoveryItem::NtsLiveChannel {
+            channel: 1,
+            show_name: "Breakfast Show".to_string(),
+            genres: vec!["Ambient".to_string(), "Jazz".to_string()],
+        },
+        DiscoveryItem::NtsLiveChannel {
+            channel: 2,
+            show_name: "Late Night".to_string(),
+            genres: vec!["Techno".to_string()],
+        },
+    ]
+}
+
+#[tokio::test]
+async fn test_snapshot_main_layout() {
+    let mut app = snapshot_app();
+    app.handle_action(Action::NtsLiveLoaded(live_channels()))
+        .await
+        .unwrap();
+    assert_snapshot(&app, "main_layout");
+}
+
+#[tokio::test]
+async fn test_compact_layout_in_a_short_terminal_or_on_z() {
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+    let mut app = snapshot_app();
+    app.handle_action(Action::PlaybackStarted {
+        title: "Floating Points - Silhouettes".to_string(),
+    })
+    .await
+    .unwrap();
+
+    let short = app.render_to_string(100, 6).unwrap();
+    let first = short.lines().next().unwrap();
+    assert!(first.contains("Floating Points - Silhouettes"), "{short}");
+    assert!(first.ends_with("Z full"), "{short}");
+    assert!(!short.contains("Now Playing"));
+
+    let tall = app.render_to_string(100, 30).unwrap();
+    assert!(tall.contains("Now Playing"));
+    app.handle_key(KeyEvent::new(KeyCode::Char('Z'), KeyModifiers::SHIFT))
+        .unwrap();
+    let compact = app.render_to_string(100, 30).unwrap();
+    assert!(!compact.contains("Now Playing"));
+    assert!(compact.lines().next().unwrap().contains("Silhouettes"));
+}
+
+#[tokio::test]
+async fn test_fullscreen_visualizer_until_any_key() {
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+    let mut app = snapshot_app();
+    app.handle_action(Action::PlaybackStarted {
+        title: "Floating Points - Silhouettes".to_string(),
+    })
+    .await
+    .unwrap();
+
+    app.handle_key(KeyEvent::new(KeyCode::Char('v'), KeyModifiers::ALT))
+        .unwrap();
+    let fullscreen = app.render_to_string(100, 30).unwrap();
+    assert!(!fullscreen.contains("Now Playing"), "{fullscreen}");
+    let status = fullscreen.lines().last().unwrap();
+    assert!(status.contains("Silhouettes"), "{fullscreen}");
+    assert!(status.ends_with("any key back"), "{fullscreen}");
+
+    app.handle_key(KeyEvent::new(KeyCode::Char('x'), KeyModifiers::NONE))
+        .unwrap();
+    assert!(app
+        .render_to_string(100, 30)
+        .unwrap()
+        .contains("Now Playing"));
+}
+
+#[tokio::test]
+async fn test_layout_config_swaps_panels_and_hides_controls() {
+    use clisten::config::QueuePosition;
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+    let dir = tempfile::tempdir().unwrap();
+    let db = clisten::db::Database::open_at(&dir.path().join("test.db")).unwrap();
+    let mut config = clisten::config::Config::default();
+    config.nts.api_base = Some("http://127.0.0.1:9".to_string());
+    config.layout.swap_panels = true;
+    config.layout.split = 50;
+    config.layout.queue = QueuePosition::Left;
+    config.layout.hide_play_controls = true;
+    let mut app = clisten::app::App::with_db(config, db).unwrap();
+    app.handle_action(Action::AddToQueue(make_item("Queued Show", "Somewhere")))
+        .await
+        .unwrap();
+
+    let screen = app.render_to_string(100, 30).unwrap();
+    let rows: Vec<&str> = screen.lines().collect();
+    assert!(rows[1].starts_with("│ Now Playing"), "{screen}");
+    let tabs = rows[1].find("Live").unwrap();
+    assert!(tabs > 50, "the list is on the right: {screen}");
+    assert!(screen.contains("Queue (1)"));
+    assert!(!screen.contains("Play/Pause"), "no controls bar");
+
+    app.handle_key(KeyEvent::new(KeyCode::Char('>'), KeyModifiers::SHIFT))
+        .unwrap();
+    let wider = app.render_to_string(100, 30).unwrap();
+    assert!(wider.lines().nth(1).unwrap().find("Live").unwrap() < tabs);
+}
+
+#[tokio::test]
+async fn test_snapshot_help_overlay() {
+    let mut app = snapshot_app();
+    app.handle_action(Action::ShowHelp).await.unwrap();
+    assert_snapshot(&app, "help_overlay");
+}
+
+#[tokio::test]
+async fn test_snapshot_direct_play_modal() {
+    let mut app = snapshot_app();
+    app.handle_action(Action::OpenDirectPlay).await.unwrap();
+    assert_snapshot(&app, "direct_play_modal");
+}
+
+#[tokio::test]
+async fn test_snapshot_equalizer_modal() {
+    let mut app = snapshot_app();
+    app.handle_action(Action::OpenEqualizer).await.unwrap();
+    assert_snapshot(&app, "equalizer_modal");
+}
+
+#[tokio::test]
+async fn test_snapshot_log_viewer() {
+    use clisten::logging::{LogLine, Logs};
+    use std::time::{Duration, UNIX_EPOCH};
+    use tracing::Level;
+
+    let logs = Logs::new();
+    let lines = [
+        (Level::INFO, "clisten::app", "starting up"),
+        (
+            Level::DEBUG,
+            "clisten::api::nts",
+            "GET /api/v2/live status=200",
+        ),
+        (
+            Level::WARN,
+            "clisten::player",
+            "mpv socket not ready, retrying",
+        ),
+        (
+            Level::ERROR,
+            "clisten::app",
+            "playback failed: stream ended",
+        ),
+    ];
+    for (i, (level, target, message)) in lines.into_iter().enumerate() {
+        logs.push(LogLine {
+            time: UNIX_EPOCH + Duration::from_secs(3600 * 13 + 5 * i as u64),
+            level,
+            target: target.to_string(),
+            message: message.to_string(),
+        });
+    }
+    let mut app = snapshot_app().with_logs(logs);
+    app.handle_action(Action::ShowLogs).await.unwrap();
+    assert_snapshot(&app, "log_viewer");
+}
+
+#[test]
+fn test_oscilloscope_traces_a_figure_only_while_playing() {
+    use clisten::components::visualizers::{create_visualizer, VisualizerKind};
+    use ratatui::{backend::TestBackend, Terminal};
+
+    let palette = clisten::theme::Theme::default().visualizer;
+    let mut scope = create_visualizer(VisualizerKind::Oscilloscope);
+    let mut terminal = Terminal::new(TestBackend::new(30, 10)).unwrap();
+    let braille_cells = |terminal: &Terminal<TestBackend>| {
+        terminal
+            .backend()
+            .buffer()
+            .content()
+            .iter()
+            .filter(|c| {
+                c.symbol()
+                    .chars()
+                    .all(|ch| ('\u{2801}'..='\u{28ff}').contains(&ch))
+            })
+            .count()
+    };
+
+    scope.tick(false, false, false, 0.0, 0.0, 0.0);
+    terminal
+        .draw(|frame| scope.draw(frame, frame.area(), &palette))
+        .unwrap();
+    assert_eq!(braille_cells(&terminal), 0, "nothing drawn when stopped");
+
+    for _ in 0..30 {
+        scope.tick(true, false, false, 0.0, 0.5, 0.9);
+    }
+    terminal
+        .draw(|frame| scope.draw(frame, frame.area(), &palette))
+        .unwrap();
+    assert!(braille_cells(&terminal) > 20, "figure drawn while playing");
+}
+
+#[test]
+fn test_marquee_spells_the_title_and_scrolls_long_ones() {
+    use clisten::components::visualizers::{create_visualizer, VisualizerKind};
+    use ratatui::{backend::TestBackend, Terminal};
+
+    let palette = clisten::theme::Theme::default().visualizer;
+    let mut marquee = create_visualizer(VisualizerKind::Marquee);
+    let mut terminal = Terminal::new(TestBackend::new(40, 4)).unwrap();
+    let mut render = |marquee: &dyn clisten::components::visualizers::Visualizer| {
+        terminal
+            .draw(|frame| marquee.draw(frame, frame.area(), &palette))
+            .unwrap();
+        terminal
+            .backend()
+            .buffer()
+            .content()
+            .iter()
+            .map(|c| c.symbol().to_string())
+            .collect::<String>()
+    };
+
+    for _ in 0..30 {
+        marquee.tick(true, false, false, 0.0, 0.5, 0.5);
+    }
+    assert!(
+        !render(&*marquee).contains('\u{28ff}'),
+        "nothing without a title"
+    );
+
+    // Short titles sit still in the middle; accents are folded away.
+    marquee.set_title("Café");
+    let first = render(&*marquee);
+    assert!(first
+        .chars()
+        .any(|c| ('\u{2801}'..='\u{28ff}').contains(&c)));
+    marquee.tick(true, false, false, 0.0, 0.5, 0.5);
+    assert_eq!(render(&*marquee), first);
+
+    marquee.set_title("The Breakfast Show with Flo - Underworld");
+    let first = render(&*marquee);
+    marquee.tick(true, false, false, 0.0, 0.5, 0.5);
+    marquee.tick(true, false, false, 0.0, 0.5, 0.5);
+    assert_ne!(render(&*marquee), first, "long titles scroll");
+}